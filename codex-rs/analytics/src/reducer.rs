@@ -2237,6 +2237,9 @@ fn file_change_review_result(
         FileChangeApprovalDecision::AcceptForSession => {
             (ReviewStatus::Approved, ReviewResolution::SessionApproval)
         }
+        FileChangeApprovalDecision::AcceptSelected { .. } => {
+            (ReviewStatus::Approved, ReviewResolution::None)
+        }
         FileChangeApprovalDecision::Decline => (ReviewStatus::Denied, ReviewResolution::None),
         FileChangeApprovalDecision::Cancel => (ReviewStatus::Aborted, ReviewResolution::None),
     }
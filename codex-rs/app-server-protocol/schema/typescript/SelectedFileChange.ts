@@ -0,0 +1,14 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file the user accepted from a proposed patch, optionally narrowed to some
+ * of its hunks.
+ */
+export type SelectedFileChange = { path: string,
+/**
+ * Zero-based indices of the accepted `@@` hunks in the file's unified
+ * diff. `None` accepts every change to the file.
+ */
+hunks?: Array<number>, };
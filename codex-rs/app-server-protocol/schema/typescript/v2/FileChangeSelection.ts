@@ -0,0 +1,17 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A file accepted from a proposed file change, optionally narrowed to some of its hunks.
+ */
+export type FileChangeSelection = {
+/**
+ * Path as reported in `FileUpdateChange.path`.
+ */
+path: string,
+/**
+ * Zero-based indices of the accepted `@@` hunks in the file's diff. Omit to accept every
+ * change to the file.
+ */
+hunks?: Array<number> | null, };
@@ -613,6 +613,36 @@ client_request_definitions! {
         serialization: thread_id(params.thread_id),
         response: v2::ThreadBackgroundTerminalsTerminateResponse,
     },
+    #[experimental("thread/checkpoint/list")]
+    ThreadCheckpointList => "thread/checkpoint/list" {
+        params: v2::ThreadCheckpointListParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadCheckpointListResponse,
+    },
+    #[experimental("thread/checkpoint/restore")]
+    ThreadCheckpointRestore => "thread/checkpoint/restore" {
+        params: v2::ThreadCheckpointRestoreParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadCheckpointRestoreResponse,
+    },
+    #[experimental("thread/items/pin")]
+    ThreadItemsPin => "thread/items/pin" {
+        params: v2::ThreadItemsPinParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadItemsPinResponse,
+    },
+    #[experimental("thread/items/unpin")]
+    ThreadItemsUnpin => "thread/items/unpin" {
+        params: v2::ThreadItemsUnpinParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadItemsUnpinResponse,
+    },
+    #[experimental("thread/items/pinned/list")]
+    ThreadItemsPinnedList => "thread/items/pinned/list" {
+        params: v2::ThreadItemsPinnedListParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadItemsPinnedListResponse,
+    },
     ThreadRollback => "thread/rollback" {
         params: v2::ThreadRollbackParams,
         serialization: thread_id(params.thread_id),
@@ -647,6 +677,12 @@ client_request_definitions! {
         serialization: thread_id(params.thread_id),
         response: v2::ThreadReadResponse,
     },
+    #[experimental("thread/export")]
+    ThreadExport => "thread/export" {
+        params: v2::ThreadExportParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::ThreadExportResponse,
+    },
     #[experimental("thread/turns/list")]
     ThreadTurnsList => "thread/turns/list" {
         params: v2::ThreadTurnsListParams,
@@ -1011,6 +1047,20 @@ client_request_definitions! {
         response: v2::McpServerToolCallResponse,
     },
 
+    #[experimental("mcpServer/prompt/list")]
+    McpServerPromptList => "mcpServer/prompt/list" {
+        params: v2::McpServerPromptListParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::McpServerPromptListResponse,
+    },
+
+    #[experimental("mcpServer/prompt/get")]
+    McpServerPromptGet => "mcpServer/prompt/get" {
+        params: v2::McpServerPromptGetParams,
+        serialization: thread_id(params.thread_id),
+        response: v2::McpServerPromptGetResponse,
+    },
+
     WindowsSandboxSetupStart => "windowsSandbox/setupStart" {
         params: v2::WindowsSandboxSetupStartParams,
         serialization: global("windows-sandbox-setup"),
@@ -1781,6 +1831,7 @@ mod tests {
     use codex_utils_absolute_path::test_support::test_path_buf;
     use pretty_assertions::assert_eq;
     use serde_json::json;
+    use std::collections::BTreeMap;
     use std::path::PathBuf;
 
     fn absolute_path_string(path: &str) -> String {
@@ -3367,6 +3418,109 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn serialize_thread_checkpoint_restore() -> Result<()> {
+        let request = ClientRequest::ThreadCheckpointRestore {
+            request_id: RequestId::Integer(8),
+            params: v2::ThreadCheckpointRestoreParams {
+                thread_id: "thr_123".to_string(),
+                turn_id: "turn_456".to_string(),
+                dry_run: Some(true),
+            },
+        };
+        assert_eq!(
+            json!({
+                "method": "thread/checkpoint/restore",
+                "id": 8,
+                "params": {
+                    "threadId": "thr_123",
+                    "turnId": "turn_456",
+                    "dryRun": true
+                }
+            }),
+            serde_json::to_value(&request)?,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_thread_export() -> Result<()> {
+        let request = ClientRequest::ThreadExport {
+            request_id: RequestId::Integer(10),
+            params: v2::ThreadExportParams {
+                thread_id: "thr_123".to_string(),
+                format: v2::ThreadExportFormat::Html,
+                omit_tool_output: true,
+                redact_secrets: false,
+            },
+        };
+        assert_eq!(
+            json!({
+                "method": "thread/export",
+                "id": 10,
+                "params": {
+                    "threadId": "thr_123",
+                    "format": "html",
+                    "omitToolOutput": true
+                }
+            }),
+            serde_json::to_value(&request)?,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_thread_items_pin() -> Result<()> {
+        let request = ClientRequest::ThreadItemsPin {
+            request_id: RequestId::Integer(9),
+            params: v2::ThreadItemsPinParams {
+                thread_id: "thr_123".to_string(),
+                text: Some("The prod DB is read-only.".to_string()),
+                item_id: None,
+            },
+        };
+        assert_eq!(
+            json!({
+                "method": "thread/items/pin",
+                "id": 9,
+                "params": {
+                    "threadId": "thr_123",
+                    "text": "The prod DB is read-only.",
+                    "itemId": null
+                }
+            }),
+            serde_json::to_value(&request)?,
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn serialize_mcp_server_prompt_get() -> Result<()> {
+        let request = ClientRequest::McpServerPromptGet {
+            request_id: RequestId::Integer(10),
+            params: v2::McpServerPromptGetParams {
+                thread_id: "thr_123".to_string(),
+                server: "docs".to_string(),
+                name: "summarize".to_string(),
+                arguments: BTreeMap::from([("topic".to_string(), "release notes".to_string())]),
+            },
+        };
+        assert_eq!(
+            json!({
+                "method": "mcpServer/prompt/get",
+                "id": 10,
+                "params": {
+                    "threadId": "thr_123",
+                    "server": "docs",
+                    "name": "summarize",
+                    "arguments": { "topic": "release notes" }
+                }
+            }),
+            serde_json::to_value(&request)?,
+        );
+        Ok(())
+    }
+
     #[test]
     fn serialize_thread_background_terminals_terminate() -> Result<()> {
         let request = ClientRequest::ThreadBackgroundTerminalsTerminate {
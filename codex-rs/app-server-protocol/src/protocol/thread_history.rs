@@ -2108,6 +2108,7 @@ mod tests {
             exit_code: Some(0),
             duration: Some(Duration::from_millis(12)),
             formatted_output: Some("hello world\n".to_string()),
+            redacted: false,
         });
         let events = vec![
             EventMsg::TurnStarted(TurnStartedEvent {
@@ -2718,6 +2719,7 @@ mod tests {
                 duration: Duration::from_millis(12),
                 formatted_output: String::new(),
                 status: CoreExecCommandStatus::Completed,
+                redacted: false,
             }),
             EventMsg::McpToolCallEnd(McpToolCallEndEvent {
                 call_id: "mcp-1".into(),
@@ -2734,6 +2736,7 @@ mod tests {
                 plugin_id: None,
                 duration: Duration::from_millis(8),
                 result: Err("boom".into()),
+                redacted: false,
             }),
         ];
 
@@ -2832,6 +2835,7 @@ mod tests {
                         "ui/resourceUri": "ui://widget/lookup.html"
                     })),
                 }),
+                redacted: false,
             }),
         ];
 
@@ -2993,6 +2997,7 @@ mod tests {
                 duration: Duration::ZERO,
                 formatted_output: String::new(),
                 status: CoreExecCommandStatus::Declined,
+                redacted: false,
             }),
             EventMsg::PatchApplyEnd(PatchApplyEndEvent {
                 call_id: "patch-declined".into(),
@@ -3263,6 +3268,7 @@ mod tests {
                 duration: Duration::from_millis(5),
                 formatted_output: "done\n".into(),
                 status: CoreExecCommandStatus::Completed,
+                redacted: false,
             }),
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-b".into(),
@@ -3365,6 +3371,7 @@ mod tests {
                 duration: Duration::from_millis(5),
                 formatted_output: "done\n".into(),
                 status: CoreExecCommandStatus::Completed,
+                redacted: false,
             }),
             EventMsg::TurnComplete(TurnCompleteEvent {
                 turn_id: "turn-b".into(),
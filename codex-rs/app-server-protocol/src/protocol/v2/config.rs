@@ -494,10 +494,28 @@ pub enum ConfiguredHookHandler {
     },
     #[serde(rename = "prompt")]
     #[ts(rename = "prompt")]
-    Prompt {},
+    Prompt {
+        prompt: String,
+        model: Option<String>,
+        #[serde(rename = "timeoutSec")]
+        #[ts(rename = "timeoutSec")]
+        timeout_sec: Option<u64>,
+        #[serde(rename = "statusMessage")]
+        #[ts(rename = "statusMessage")]
+        status_message: Option<String>,
+    },
     #[serde(rename = "agent")]
     #[ts(rename = "agent")]
-    Agent {},
+    Agent {
+        prompt: String,
+        model: Option<String>,
+        #[serde(rename = "timeoutSec")]
+        #[ts(rename = "timeoutSec")]
+        timeout_sec: Option<u64>,
+        #[serde(rename = "statusMessage")]
+        #[ts(rename = "statusMessage")]
+        status_message: Option<String>,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
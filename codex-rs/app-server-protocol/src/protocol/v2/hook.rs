@@ -113,6 +113,8 @@ pub struct HookRunSummary {
     pub completed_at: Option<i64>,
     pub duration_ms: Option<i64>,
     pub entries: Vec<HookOutputEntry>,
+    /// Thread spawned to evaluate an `agent` hook, kept so the run can be inspected later.
+    pub agent_thread_id: Option<String>,
 }
 
 impl From<CoreHookRunSummary> for HookRunSummary {
@@ -132,6 +134,7 @@ impl From<CoreHookRunSummary> for HookRunSummary {
             completed_at: value.completed_at,
             duration_ms: value.duration_ms,
             entries: value.entries.into_iter().map(Into::into).collect(),
+            agent_thread_id: value.agent_thread_id.map(|thread_id| thread_id.to_string()),
         }
     }
 }
@@ -94,8 +94,9 @@ impl From<CoreReviewDecision> for CommandExecutionApprovalDecision {
                 network_policy_amendment: network_policy_amendment.into(),
             },
             CoreReviewDecision::Abort => Self::Cancel,
-            CoreReviewDecision::Denied { .. } => Self::Decline,
-            CoreReviewDecision::TimedOut => Self::Decline,
+            CoreReviewDecision::ApprovedSelectedChanges { .. }
+            | CoreReviewDecision::Denied { .. }
+            | CoreReviewDecision::TimedOut => Self::Decline,
         }
     }
 }
@@ -108,12 +109,28 @@ pub enum FileChangeApprovalDecision {
     Accept,
     /// User approved the file changes and future changes to the same files should run without prompting.
     AcceptForSession,
+    /// User approved only some of the file changes. Files that are not listed are rejected, and
+    /// the agent is told which changes were not applied.
+    AcceptSelected { changes: Vec<FileChangeSelection> },
     /// User denied the file changes. The agent will continue the turn.
     Decline,
     /// User denied the file changes. The turn will also be immediately interrupted.
     Cancel,
 }
 
+/// A file accepted from a proposed file change, optionally narrowed to some of its hunks.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct FileChangeSelection {
+    /// Path as reported in `FileUpdateChange.path`.
+    pub path: String,
+    /// Zero-based indices of the accepted `@@` hunks in the file's diff. Omit to accept every
+    /// change to the file.
+    #[ts(optional = nullable)]
+    pub hunks: Option<Vec<u32>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(tag = "type", rename_all = "camelCase")]
 #[ts(tag = "type")]
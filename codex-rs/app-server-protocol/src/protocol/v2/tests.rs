@@ -2689,6 +2689,7 @@ fn core_turn_item_into_thread_item_converts_supported_variants() {
         exit_code: Some(0),
         duration: Some(Duration::from_millis(5)),
         formatted_output: Some("done\n".to_string()),
+        redacted: false,
     });
 
     assert_eq!(
@@ -2910,6 +2911,7 @@ fn core_turn_item_into_thread_item_converts_supported_variants() {
         result: None,
         error: None,
         duration: None,
+        redacted: false,
     });
 
     assert_eq!(
@@ -2955,6 +2957,7 @@ fn core_turn_item_into_thread_item_converts_supported_variants() {
         }),
         error: None,
         duration: Some(Duration::from_millis(42)),
+        redacted: false,
     });
 
     assert_eq!(
@@ -1065,6 +1065,179 @@ pub struct ThreadBackgroundTerminalsTerminateResponse {
     pub terminated: bool,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadCheckpointListParams {
+    pub thread_id: String,
+}
+
+/// Workspace snapshot taken before a turn started.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadCheckpoint {
+    /// The turn this checkpoint precedes. Restoring it undoes that turn and every later one.
+    pub turn_id: String,
+    /// Directory the checkpoint covers: the enclosing git repository, or the turn cwd.
+    pub workspace: AbsolutePathBuf,
+    /// Unix timestamp (in seconds) when the checkpoint was taken.
+    pub created_at: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadCheckpointListResponse {
+    /// Checkpoints ordered newest first.
+    pub data: Vec<ThreadCheckpoint>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadCheckpointRestoreParams {
+    pub thread_id: String,
+    /// Restore files to how they were before this turn started.
+    pub turn_id: String,
+    /// Report the paths the restore would rewrite and delete without changing any files.
+    #[ts(optional = nullable)]
+    pub dry_run: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadCheckpointRestoreResponse {
+    pub checkpoint: ThreadCheckpoint,
+    /// Workspace-relative paths rewritten with their checkpointed contents.
+    pub restored_paths: Vec<String>,
+    /// Workspace-relative paths deleted because they did not exist at the checkpoint.
+    pub removed_paths: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(rename_all = "camelCase", export_to = "v2/")]
+pub enum PinnedItemSource {
+    User,
+    Model,
+}
+
+/// Text re-injected verbatim into the model context after every compaction.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct PinnedItem {
+    pub id: String,
+    pub text: String,
+    pub source: PinnedItemSource,
+    /// Approximate tokens this pin uses from the thread's pinned context budget.
+    pub tokens: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsPinParams {
+    pub thread_id: String,
+    /// Text to pin, such as a file excerpt. Exactly one of `text` and `itemId`
+    /// must be set.
+    #[ts(optional = nullable)]
+    pub text: Option<String>,
+    /// Id of a user message, agent message, or plan in this thread whose text
+    /// should be pinned.
+    #[ts(optional = nullable)]
+    pub item_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsPinResponse {
+    pub item: PinnedItem,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsUnpinParams {
+    pub thread_id: String,
+    pub pin_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsUnpinResponse {
+    /// The removed pin, or `null` when no pin had that id.
+    pub item: Option<PinnedItem>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsPinnedListParams {
+    pub thread_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadItemsPinnedListResponse {
+    /// Pins in the order they were added.
+    pub data: Vec<PinnedItem>,
+    /// Token budget shared by all pins in the thread.
+    pub token_budget: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(rename_all = "camelCase", export_to = "v2/")]
+pub enum ThreadExportFormat {
+    #[default]
+    Markdown,
+    /// Standalone HTML page with inline styles and no scripts.
+    Html,
+    /// Versioned transcript JSON that is independent of the rollout format.
+    Json,
+}
+
+impl ThreadExportFormat {
+    /// File extension conventionally used for this format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+            Self::Json => "json",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadExportParams {
+    pub thread_id: String,
+    #[serde(default)]
+    pub format: ThreadExportFormat,
+    /// When true, commands and tool calls are listed without their output.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub omit_tool_output: bool,
+    /// When true, likely secrets such as API keys and bearer tokens are masked.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redact_secrets: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadExportResponse {
+    pub format: ThreadExportFormat,
+    /// The rendered transcript.
+    pub content: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -1074,7 +1247,7 @@ pub struct ThreadRollbackParams {
     /// The number of turns to drop from the end of the thread. Must be >= 1.
     ///
     /// This only modifies the thread's history and does not revert local file changes
-    /// that have been made by the agent. Clients are responsible for reverting these changes.
+    /// that have been made by the agent. Use `thread/checkpoint/restore` to revert them.
     pub num_turns: u32,
 }
 
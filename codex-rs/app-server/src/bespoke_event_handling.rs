@@ -100,6 +100,7 @@ use codex_protocol::protocol::ExecApprovalRequestEvent;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::RealtimeEvent;
 use codex_protocol::protocol::ReviewDecision;
+use codex_protocol::protocol::SelectedFileChange;
 use codex_protocol::protocol::SubAgentActivityKind;
 use codex_protocol::protocol::TokenCountEvent;
 use codex_protocol::protocol::TurnAbortedEvent;
@@ -115,6 +116,7 @@ use codex_shell_command::parse_command::shlex_join;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_path_uri::LegacyAppPathString;
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
@@ -1886,6 +1888,17 @@ fn map_file_change_approval_decision(decision: FileChangeApprovalDecision) -> Re
     match decision {
         FileChangeApprovalDecision::Accept => ReviewDecision::Approved,
         FileChangeApprovalDecision::AcceptForSession => ReviewDecision::ApprovedForSession,
+        FileChangeApprovalDecision::AcceptSelected { changes } => {
+            ReviewDecision::ApprovedSelectedChanges {
+                changes: changes
+                    .into_iter()
+                    .map(|change| SelectedFileChange {
+                        path: PathBuf::from(change.path),
+                        hunks: change.hunks,
+                    })
+                    .collect(),
+            }
+        }
         FileChangeApprovalDecision::Decline => ReviewDecision::denied("rejected by user"),
         FileChangeApprovalDecision::Cancel => ReviewDecision::Abort,
     }
@@ -2084,6 +2097,7 @@ mod tests {
     use anyhow::bail;
     use chrono::Utc;
     use codex_app_server_protocol::AutoReviewDecisionSource;
+    use codex_app_server_protocol::FileChangeSelection;
     use codex_app_server_protocol::GuardianApprovalReviewStatus;
     use codex_app_server_protocol::JSONRPCErrorError;
     use codex_app_server_protocol::ServerRequest;
@@ -2840,6 +2854,26 @@ mod tests {
         assert_eq!(decision, ReviewDecision::ApprovedForSession);
     }
 
+    #[test]
+    fn file_change_accept_selected_maps_to_selected_changes() {
+        let decision =
+            map_file_change_approval_decision(FileChangeApprovalDecision::AcceptSelected {
+                changes: vec![FileChangeSelection {
+                    path: "/repo/src/lib.rs".to_string(),
+                    hunks: Some(vec![0, 2]),
+                }],
+            });
+        assert_eq!(
+            decision,
+            ReviewDecision::ApprovedSelectedChanges {
+                changes: vec![SelectedFileChange {
+                    path: PathBuf::from("/repo/src/lib.rs"),
+                    hunks: Some(vec![0, 2]),
+                }],
+            }
+        );
+    }
+
     #[test]
     fn mcp_server_elicitation_turn_transition_error_maps_to_cancel() {
         let error = JSONRPCErrorError {
@@ -1141,6 +1141,23 @@ impl MessageProcessor {
                     .thread_background_terminals_terminate(params)
                     .await
             }
+            ClientRequest::ThreadCheckpointList { params, .. } => {
+                self.thread_processor.thread_checkpoint_list(params).await
+            }
+            ClientRequest::ThreadCheckpointRestore { params, .. } => {
+                self.thread_processor
+                    .thread_checkpoint_restore(params)
+                    .await
+            }
+            ClientRequest::ThreadItemsPin { params, .. } => {
+                self.thread_processor.thread_items_pin(params).await
+            }
+            ClientRequest::ThreadItemsUnpin { params, .. } => {
+                self.thread_processor.thread_items_unpin(params).await
+            }
+            ClientRequest::ThreadItemsPinnedList { params, .. } => {
+                self.thread_processor.thread_items_pinned_list(params).await
+            }
             ClientRequest::ThreadRollback { params, .. } => {
                 self.thread_processor
                     .thread_rollback(&request_id, params, app_server_client_name.as_deref())
@@ -1163,6 +1180,9 @@ impl MessageProcessor {
             ClientRequest::ThreadRead { params, .. } => {
                 self.thread_processor.thread_read(params).await
             }
+            ClientRequest::ThreadExport { params, .. } => {
+                self.thread_processor.thread_export(params).await
+            }
             ClientRequest::ThreadTurnsList { params, .. } => {
                 self.thread_processor.thread_turns_list(params).await
             }
@@ -1341,6 +1361,16 @@ impl MessageProcessor {
                     .mcp_server_tool_call(&request_id, params)
                     .await
             }
+            ClientRequest::McpServerPromptList { params, .. } => {
+                self.mcp_processor
+                    .mcp_server_prompt_list(&request_id, params)
+                    .await
+            }
+            ClientRequest::McpServerPromptGet { params, .. } => {
+                self.mcp_processor
+                    .mcp_server_prompt_get(&request_id, params)
+                    .await
+            }
             ClientRequest::WindowsSandboxSetupStart { params, .. } => {
                 self.windows_sandbox_processor
                     .windows_sandbox_setup_start(&request_id, params)
@@ -101,6 +101,7 @@ impl AppsRequestProcessor {
                         /*elicitation_reviewer*/ None,
                         /*elicitation_lifecycle*/ None,
                         codex_mcp::ElicitationRequestRouter::default(),
+                        /*sampler*/ None,
                     )
                     .await;
 
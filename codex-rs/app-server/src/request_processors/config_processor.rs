@@ -469,8 +469,28 @@ fn map_hook_handler_to_api(handler: CoreHookHandlerConfig) -> ConfiguredHookHand
             status_message,
             additional_context_limit,
         },
-        CoreHookHandlerConfig::Prompt {} => ConfiguredHookHandler::Prompt {},
-        CoreHookHandlerConfig::Agent {} => ConfiguredHookHandler::Agent {},
+        CoreHookHandlerConfig::Prompt {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => ConfiguredHookHandler::Prompt {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        },
+        CoreHookHandlerConfig::Agent {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        } => ConfiguredHookHandler::Agent {
+            prompt,
+            model,
+            timeout_sec,
+            status_message,
+        },
     }
 }
 
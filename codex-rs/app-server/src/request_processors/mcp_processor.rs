@@ -74,6 +74,26 @@ impl McpRequestProcessor {
             .map(|()| None)
     }
 
+    pub(crate) async fn mcp_server_prompt_list(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptListParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.list_mcp_server_prompts(request_id, params)
+            .await
+            .map(|()| None)
+    }
+
+    pub(crate) async fn mcp_server_prompt_get(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptGetParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.get_mcp_server_prompt(request_id, params)
+            .await
+            .map(|()| None)
+    }
+
     async fn mcp_server_refresh_response(
         &self,
         _params: Option<()>,
@@ -476,6 +496,91 @@ impl McpRequestProcessor {
         });
         Ok(())
     }
+
+    async fn list_mcp_server_prompts(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptListParams,
+    ) -> Result<(), JSONRPCErrorError> {
+        let outgoing = Arc::clone(&self.outgoing);
+        let (_, thread) = self.load_thread(&params.thread_id).await?;
+        let request_id = request_id.clone();
+
+        tokio::spawn(async move {
+            let mut data = thread
+                .list_mcp_prompts()
+                .await
+                .into_iter()
+                .flat_map(|(server, prompts)| {
+                    prompts.into_iter().map(move |prompt| McpServerPrompt {
+                        server: server.clone(),
+                        name: prompt.name,
+                        title: prompt.title,
+                        description: prompt.description,
+                        arguments: prompt
+                            .arguments
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|argument| McpServerPromptArgument {
+                                name: argument.name,
+                                description: argument.description,
+                                required: argument.required.unwrap_or(false),
+                            })
+                            .collect(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            data.sort_by(|left, right| {
+                (&left.server, &left.name).cmp(&(&right.server, &right.name))
+            });
+            outgoing
+                .send_result(request_id, Ok(McpServerPromptListResponse { data }))
+                .await;
+        });
+        Ok(())
+    }
+
+    async fn get_mcp_server_prompt(
+        &self,
+        request_id: &ConnectionRequestId,
+        params: McpServerPromptGetParams,
+    ) -> Result<(), JSONRPCErrorError> {
+        let outgoing = Arc::clone(&self.outgoing);
+        let McpServerPromptGetParams {
+            thread_id,
+            server,
+            name,
+            arguments,
+        } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let request_id = request_id.clone();
+        let arguments = (!arguments.is_empty()).then(|| {
+            arguments
+                .into_iter()
+                .map(|(key, value)| (key, serde_json::Value::String(value)))
+                .collect::<serde_json::Map<_, _>>()
+        });
+
+        tokio::spawn(async move {
+            let result = thread
+                .get_mcp_prompt(&server, &name, arguments)
+                .await
+                .and_then(|result| {
+                    let messages = result
+                        .messages
+                        .iter()
+                        .map(serde_json::to_value)
+                        .collect::<Result<Vec<_>, _>>()?;
+                    Ok(McpServerPromptGetResponse {
+                        description: result.description,
+                        messages,
+                    })
+                })
+                .map_err(|error| internal_error(format!("{error:#}")));
+            outgoing.send_result(request_id, result).await;
+        });
+        Ok(())
+    }
 }
 
 fn with_mcp_tool_call_thread_id_meta(
@@ -25,6 +25,48 @@ struct ThreadListFilters {
     relation_filter: Option<StoreThreadRelationFilter>,
 }
 
+fn thread_checkpoint_from_core(
+    checkpoint: WorkspaceCheckpoint,
+) -> Result<ThreadCheckpoint, JSONRPCErrorError> {
+    let workspace = AbsolutePathBuf::from_absolute_path(&checkpoint.workspace)
+        .map_err(|err| internal_error(format!("checkpoint has invalid workspace: {err}")))?;
+    Ok(ThreadCheckpoint {
+        turn_id: checkpoint.turn_id,
+        workspace,
+        created_at: checkpoint.created_at,
+    })
+}
+
+fn pinned_item_from_core(item: PinnedContextItem) -> PinnedItem {
+    PinnedItem {
+        id: item.id,
+        text: item.text,
+        source: match item.source {
+            PinnedContextSource::User => PinnedItemSource::User,
+            PinnedContextSource::Model => PinnedItemSource::Model,
+        },
+        tokens: i64::try_from(item.tokens).unwrap_or(i64::MAX),
+    }
+}
+
+/// Text a client can pin by item id: the text parts of a user message, or the
+/// text of an agent message or plan.
+fn pinnable_item_text(item: &ThreadItem) -> Option<String> {
+    let text = match item {
+        ThreadItem::UserMessage { content, .. } => content
+            .iter()
+            .filter_map(|input| match input {
+                V2UserInput::Text { text, .. } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        ThreadItem::AgentMessage { text, .. } | ThreadItem::Plan { text, .. } => text.clone(),
+        _ => return None,
+    };
+    (!text.trim().is_empty()).then_some(text)
+}
+
 fn collect_resume_override_mismatches(
     request: &ThreadResumeParams,
     config_snapshot: &ThreadConfigSnapshot,
@@ -668,6 +710,51 @@ impl ThreadRequestProcessor {
             .map(|response| Some(response.into()))
     }
 
+    pub(crate) async fn thread_checkpoint_list(
+        &self,
+        params: ThreadCheckpointListParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_checkpoint_list_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
+    pub(crate) async fn thread_checkpoint_restore(
+        &self,
+        params: ThreadCheckpointRestoreParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_checkpoint_restore_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
+    pub(crate) async fn thread_items_pin(
+        &self,
+        params: ThreadItemsPinParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_items_pin_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
+    pub(crate) async fn thread_items_unpin(
+        &self,
+        params: ThreadItemsUnpinParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_items_unpin_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
+    pub(crate) async fn thread_items_pinned_list(
+        &self,
+        params: ThreadItemsPinnedListParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_items_pinned_list_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
     pub(crate) async fn thread_rollback(
         &self,
         request_id: &ConnectionRequestId,
@@ -740,6 +827,15 @@ impl ThreadRequestProcessor {
             .map(|response| Some(response.into()))
     }
 
+    pub(crate) async fn thread_export(
+        &self,
+        params: ThreadExportParams,
+    ) -> Result<Option<ClientResponsePayload>, JSONRPCErrorError> {
+        self.thread_export_response_inner(params)
+            .await
+            .map(|response| Some(response.into()))
+    }
+
     pub(crate) async fn thread_turns_list(
         &self,
         params: ThreadTurnsListParams,
@@ -1941,6 +2037,141 @@ impl ThreadRequestProcessor {
         Ok(ThreadBackgroundTerminalsTerminateResponse { terminated })
     }
 
+    async fn thread_checkpoint_list_inner(
+        &self,
+        params: ThreadCheckpointListParams,
+    ) -> Result<ThreadCheckpointListResponse, JSONRPCErrorError> {
+        let ThreadCheckpointListParams { thread_id } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let data = thread
+            .list_checkpoints()
+            .await
+            .map_err(|err| internal_error(format!("failed to list checkpoints: {err:#}")))?
+            .into_iter()
+            .map(thread_checkpoint_from_core)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ThreadCheckpointListResponse { data })
+    }
+
+    async fn thread_checkpoint_restore_inner(
+        &self,
+        params: ThreadCheckpointRestoreParams,
+    ) -> Result<ThreadCheckpointRestoreResponse, JSONRPCErrorError> {
+        let ThreadCheckpointRestoreParams {
+            thread_id,
+            turn_id,
+            dry_run,
+        } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let restore = if dry_run.unwrap_or(false) {
+            thread.preview_checkpoint_restore(&turn_id).await
+        } else {
+            thread.restore_checkpoint(&turn_id).await
+        }
+        .map_err(|err| invalid_request(format!("failed to restore checkpoint: {err:#}")))?;
+        Ok(ThreadCheckpointRestoreResponse {
+            checkpoint: thread_checkpoint_from_core(restore.checkpoint)?,
+            restored_paths: restore.restored,
+            removed_paths: restore.removed,
+        })
+    }
+
+    async fn thread_items_pin_inner(
+        &self,
+        params: ThreadItemsPinParams,
+    ) -> Result<ThreadItemsPinResponse, JSONRPCErrorError> {
+        let ThreadItemsPinParams {
+            thread_id,
+            text,
+            item_id,
+        } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let text = match (text, item_id) {
+            (Some(text), None) => text,
+            (None, Some(item_id)) => self.thread_item_text(&thread_id, &item_id).await?,
+            _ => {
+                return Err(invalid_request(
+                    "thread/items/pin needs exactly one of `text` and `itemId`",
+                ));
+            }
+        };
+        let item = thread
+            .pin_context(&text)
+            .await
+            .map_err(|err| invalid_request(format!("failed to pin item: {err}")))?;
+        Ok(ThreadItemsPinResponse {
+            item: pinned_item_from_core(item),
+        })
+    }
+
+    /// Finds `item_id` in the thread's turns and returns the text to pin for it.
+    async fn thread_item_text(
+        &self,
+        thread_id: &str,
+        item_id: &str,
+    ) -> Result<String, JSONRPCErrorError> {
+        let mut cursor = None;
+        loop {
+            let page = self
+                .thread_turns_list_response_inner(ThreadTurnsListParams {
+                    thread_id: thread_id.to_string(),
+                    cursor,
+                    limit: None,
+                    sort_direction: Some(SortDirection::Desc),
+                    items_view: Some(TurnItemsView::Full),
+                })
+                .await?;
+            if let Some(item) = page
+                .data
+                .iter()
+                .flat_map(|turn| &turn.items)
+                .find(|item| item.id() == item_id)
+            {
+                return pinnable_item_text(item)
+                    .ok_or_else(|| invalid_request(format!("item {item_id} has no text to pin")));
+            }
+            match page.next_cursor {
+                Some(next_cursor) => cursor = Some(next_cursor),
+                None => {
+                    return Err(invalid_request(format!(
+                        "no item {item_id} in thread {thread_id}"
+                    )));
+                }
+            }
+        }
+    }
+
+    async fn thread_items_unpin_inner(
+        &self,
+        params: ThreadItemsUnpinParams,
+    ) -> Result<ThreadItemsUnpinResponse, JSONRPCErrorError> {
+        let ThreadItemsUnpinParams { thread_id, pin_id } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let item = thread.unpin_context(&pin_id).await;
+        Ok(ThreadItemsUnpinResponse {
+            item: item.map(pinned_item_from_core),
+        })
+    }
+
+    async fn thread_items_pinned_list_inner(
+        &self,
+        params: ThreadItemsPinnedListParams,
+    ) -> Result<ThreadItemsPinnedListResponse, JSONRPCErrorError> {
+        let ThreadItemsPinnedListParams { thread_id } = params;
+        let (_, thread) = self.load_thread(&thread_id).await?;
+        let token_budget = thread.config().await.pinned_context_token_budget;
+        let data = thread
+            .pinned_context()
+            .await
+            .into_iter()
+            .map(pinned_item_from_core)
+            .collect();
+        Ok(ThreadItemsPinnedListResponse {
+            data,
+            token_budget: i64::try_from(token_budget).unwrap_or(i64::MAX),
+        })
+    }
+
     async fn thread_shell_command_inner(
         &self,
         request_id: &ConnectionRequestId,
@@ -2286,6 +2517,50 @@ impl ThreadRequestProcessor {
         Ok(ThreadReadResponse { thread })
     }
 
+    async fn thread_export_response_inner(
+        &self,
+        params: ThreadExportParams,
+    ) -> Result<ThreadExportResponse, JSONRPCErrorError> {
+        let ThreadExportParams {
+            thread_id,
+            format,
+            omit_tool_output,
+            redact_secrets,
+        } = params;
+        let thread_uuid = ThreadId::from_string(&thread_id)
+            .map_err(|err| invalid_request(format!("invalid thread id: {err}")))?;
+
+        // Loaded threads buffer rollout writes; flush so the export includes the latest items.
+        if let Ok(thread) = self.thread_manager.get_thread(thread_uuid).await
+            && let Err(err) = thread.flush_rollout().await
+        {
+            return Err(internal_error(format!(
+                "failed to flush thread {thread_uuid} before export: {err}"
+            )));
+        }
+        let content = codex_thread_store::export_thread(
+            self.thread_store.as_ref(),
+            StoreExportThreadParams {
+                thread_id: thread_uuid,
+                format,
+                include_tool_output: !omit_tool_output,
+                redact_secrets,
+            },
+        )
+        .await
+        .map_err(|err| match err {
+            ThreadStoreError::InvalidRequest { message } => invalid_request(message),
+            ThreadStoreError::Unsupported { operation } => {
+                unsupported_thread_store_operation(operation)
+            }
+            ThreadStoreError::ThreadNotFound { thread_id } => {
+                invalid_request(format!("no rollout found for thread id {thread_id}"))
+            }
+            err => internal_error(format!("failed to export thread: {err}")),
+        })?;
+        Ok(ThreadExportResponse { format, content })
+    }
+
     /// Builds the API view for `thread/read` from persisted metadata plus optional live state.
     async fn read_thread_view(
         &self,
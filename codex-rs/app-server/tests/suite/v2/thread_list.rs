@@ -724,7 +724,8 @@ async fn thread_search_returns_content_matches() -> Result<()> {
         codex_home.path(),
         "2025-01-02T10-00-00",
         "2025-01-02T10:00:00Z",
-        "match: needle",
+        // Longer than the newer match so relevance ranking and recency agree on the order.
+        "match: needle, somewhere in a much longer message",
         Some("mock_provider"),
         /*git_info*/ None,
     )?;
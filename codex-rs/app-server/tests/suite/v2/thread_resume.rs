@@ -1346,6 +1346,7 @@ fn append_resume_redaction_history(
                 is_error: Some(false),
                 meta: Some(json!({"secret":"meta"})),
             }),
+            redacted: false,
         }),
         EventMsg::ImageGenerationEnd(ImageGenerationEndEvent {
             call_id: "ig-1".to_string(),
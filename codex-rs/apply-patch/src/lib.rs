@@ -1,6 +1,7 @@
 mod invocation;
 mod parser;
 mod seek_sequence;
+mod selection;
 mod standalone_executable;
 mod streaming_parser;
 
@@ -21,6 +22,10 @@ pub use parser::ParseError;
 use parser::ParseError::*;
 pub use parser::UpdateFileChunk;
 pub use parser::parse_patch;
+pub use selection::PatchSelection;
+pub use selection::RejectedChange;
+pub use selection::apply_selected_changes;
+pub use selection::select_patch_changes;
 use similar::TextDiff;
 pub use streaming_parser::StreamingPatchParser;
 use thiserror::Error;
@@ -0,0 +1,215 @@
+//! Narrows a verified patch to the files and hunks a reviewer accepted.
+//!
+//! Hunk indices refer to the `@@` hunks of each file's unified diff in
+//! [`ApplyPatchFileChange::Update`], which is what approval UIs render. Each
+//! accepted diff hunk becomes an [`UpdateFileChunk`] whose old lines are the
+//! hunk's context and deletions, so it applies on its own without the hunks
+//! that were left out.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use codex_exec_server::ExecutorFileSystem;
+use codex_exec_server::FileSystemSandboxContext;
+
+use crate::AppliedPatchDelta;
+use crate::ApplyPatchAction;
+use crate::ApplyPatchError;
+use crate::ApplyPatchFailure;
+use crate::ApplyPatchFileChange;
+use crate::Hunk;
+use crate::UpdateFileChunk;
+use crate::apply_hunks;
+use crate::parse_patch;
+
+/// The accepted part of a patch, plus what was left out.
+#[derive(Debug, Default, PartialEq)]
+pub struct PatchSelection {
+    /// Hunks to pass to [`crate::apply_hunks`].
+    pub hunks: Vec<Hunk>,
+    pub rejected: Vec<RejectedChange>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RejectedChange {
+    /// Every change to the file was rejected.
+    File { path: PathBuf },
+    /// One `@@` hunk of an updated file was rejected.
+    Hunk {
+        path: PathBuf,
+        /// Zero-based index of the hunk in the file's unified diff.
+        index: usize,
+        /// The rejected hunk, including its `@@` header.
+        diff: String,
+    },
+}
+
+/// Keeps only the accepted changes of `action`.
+///
+/// `accepted` is keyed by the resolved path of each changed file, as reported
+/// by [`ApplyPatchAction::changes`]. A `None` entry accepts the whole file, and
+/// a list of indices accepts only those hunks of an updated file. Files that
+/// are missing from `accepted` are rejected.
+pub fn select_patch_changes(
+    action: &ApplyPatchAction,
+    accepted: &HashMap<PathBuf, Option<Vec<u32>>>,
+) -> Result<PatchSelection, ApplyPatchError> {
+    let mut selection = PatchSelection::default();
+    for hunk in parse_patch(&action.patch)?.hunks {
+        let resolved = hunk.resolve_path(&action.cwd)?;
+        let display_path = source_path(&hunk).to_path_buf();
+        let Some(accepted_hunks) = accepted.get(&resolved.to_path_buf()) else {
+            selection
+                .rejected
+                .push(RejectedChange::File { path: display_path });
+            continue;
+        };
+        let (Some(indices), Some(ApplyPatchFileChange::Update { unified_diff, .. })) =
+            (accepted_hunks, action.changes().get(&resolved))
+        else {
+            selection.hunks.push(hunk);
+            continue;
+        };
+        let Hunk::UpdateFile {
+            path, move_path, ..
+        } = hunk
+        else {
+            selection.hunks.push(hunk);
+            continue;
+        };
+
+        let mut chunks = Vec::new();
+        for (index, diff_hunk) in unified_diff_hunks(unified_diff).into_iter().enumerate() {
+            if u32::try_from(index).is_ok_and(|index| indices.contains(&index)) {
+                chunks.push(diff_hunk.chunk);
+            } else {
+                selection.rejected.push(RejectedChange::Hunk {
+                    path: display_path.clone(),
+                    index,
+                    diff: diff_hunk.text,
+                });
+            }
+        }
+        // Rejecting every hunk of a moved file still keeps the move itself.
+        if !chunks.is_empty() || move_path.is_some() {
+            selection.hunks.push(Hunk::UpdateFile {
+                path,
+                move_path,
+                chunks,
+            });
+        }
+    }
+    Ok(selection)
+}
+
+/// Applies the accepted part of `action` and lists the rejected changes on
+/// `stdout` after the usual summary, so they reach whoever asked for the patch.
+pub async fn apply_selected_changes(
+    action: &ApplyPatchAction,
+    accepted: &HashMap<PathBuf, Option<Vec<u32>>>,
+    stdout: &mut impl std::io::Write,
+    stderr: &mut impl std::io::Write,
+    fs: &dyn ExecutorFileSystem,
+    sandbox: Option<&FileSystemSandboxContext>,
+) -> Result<AppliedPatchDelta, ApplyPatchFailure> {
+    let selection = match select_patch_changes(action, accepted) {
+        Ok(selection) => selection,
+        Err(error) => {
+            writeln!(stderr, "{error}")
+                .map_err(ApplyPatchError::from)
+                .map_err(ApplyPatchFailure::without_delta)?;
+            return Err(ApplyPatchFailure::without_delta(error));
+        }
+    };
+    let delta = if selection.hunks.is_empty() {
+        writeln!(stdout, "No changes were applied.")
+            .map_err(ApplyPatchError::from)
+            .map_err(ApplyPatchFailure::without_delta)?;
+        AppliedPatchDelta::empty()
+    } else {
+        apply_hunks(&selection.hunks, &action.cwd, stdout, stderr, fs, sandbox).await?
+    };
+    print_rejected_changes(&selection.rejected, stdout)
+        .map_err(|error| ApplyPatchFailure::new(ApplyPatchError::from(error), delta.clone()))?;
+    Ok(delta)
+}
+
+fn print_rejected_changes(
+    rejected: &[RejectedChange],
+    out: &mut impl std::io::Write,
+) -> std::io::Result<()> {
+    if rejected.is_empty() {
+        return Ok(());
+    }
+    writeln!(
+        out,
+        "The user rejected these changes, so they were not applied:"
+    )?;
+    for change in rejected {
+        match change {
+            RejectedChange::File { path } => {
+                writeln!(out, "- {} (all changes)", path.display())?;
+            }
+            RejectedChange::Hunk { path, index, diff } => {
+                writeln!(out, "- {} hunk {}:", path.display(), index + 1)?;
+                write!(out, "{diff}")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+struct DiffHunk {
+    text: String,
+    chunk: UpdateFileChunk,
+}
+
+fn unified_diff_hunks(unified_diff: &str) -> Vec<DiffHunk> {
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    for line in unified_diff.lines() {
+        if line.starts_with("@@") {
+            hunks.push(DiffHunk {
+                text: String::new(),
+                chunk: UpdateFileChunk {
+                    change_context: None,
+                    old_lines: Vec::new(),
+                    new_lines: Vec::new(),
+                    is_end_of_file: false,
+                },
+            });
+        }
+        // Skip the `---`/`+++` file headers that precede the first hunk.
+        let Some(hunk) = hunks.last_mut() else {
+            continue;
+        };
+        hunk.text.push_str(line);
+        hunk.text.push('\n');
+        let chunk = &mut hunk.chunk;
+        match line.split_at_checked(1) {
+            Some(("-", text)) => chunk.old_lines.push(text.to_string()),
+            Some(("+", text)) => chunk.new_lines.push(text.to_string()),
+            Some((" ", text)) => {
+                chunk.old_lines.push(text.to_string());
+                chunk.new_lines.push(text.to_string());
+            }
+            None => {
+                chunk.old_lines.push(String::new());
+                chunk.new_lines.push(String::new());
+            }
+            // `@@` headers and `\ No newline at end of file` markers.
+            Some(_) => {}
+        }
+    }
+    hunks
+}
+
+fn source_path(hunk: &Hunk) -> &std::path::Path {
+    match hunk {
+        Hunk::UpdateFile { path, .. } => path,
+        Hunk::AddFile { .. } | Hunk::DeleteFile { .. } => hunk.path(),
+    }
+}
+
+#[cfg(test)]
+#[path = "selection_tests.rs"]
+mod tests;
@@ -0,0 +1,138 @@
+use super::*;
+use crate::MaybeApplyPatchVerified;
+use crate::verify_apply_patch_args;
+use codex_exec_server::LOCAL_FS;
+use codex_utils_path_uri::PathUri;
+use pretty_assertions::assert_eq;
+use std::fs;
+use tempfile::TempDir;
+
+async fn verified_action(cwd: &PathUri, patch: &str) -> ApplyPatchAction {
+    let args = parse_patch(patch).expect("patch should parse");
+    match verify_apply_patch_args(args, cwd, LOCAL_FS.as_ref(), /*sandbox*/ None).await {
+        MaybeApplyPatchVerified::Body(action) => action,
+        other => panic!("patch should verify: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn applies_only_accepted_hunks_and_reports_the_rest() {
+    let dir = TempDir::new().expect("tempdir");
+    let cwd = PathUri::from_host_native_path(dir.path()).expect("absolute test path");
+    let original = (1..=10)
+        .map(|line| format!("line {line}\n"))
+        .collect::<String>();
+    fs::write(dir.path().join("notes.txt"), &original).expect("write notes");
+    let action = verified_action(
+        &cwd,
+        "*** Begin Patch\n*** Update File: notes.txt\n@@\n-line 2\n+line two\n@@\n-line 9\n+line nine\n*** Add File: extra.txt\n+extra\n*** End Patch",
+    )
+    .await;
+
+    let accepted = HashMap::from([(dir.path().join("notes.txt"), Some(vec![1]))]);
+    let selection = select_patch_changes(&action, &accepted).expect("select changes");
+
+    assert_eq!(
+        selection.rejected,
+        vec![
+            RejectedChange::Hunk {
+                path: PathBuf::from("notes.txt"),
+                index: 0,
+                diff: "@@ -1,3 +1,3 @@\n line 1\n-line 2\n+line two\n line 3\n".to_string(),
+            },
+            RejectedChange::File {
+                path: PathBuf::from("extra.txt"),
+            },
+        ]
+    );
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    apply_selected_changes(
+        &action,
+        &accepted,
+        &mut stdout,
+        &mut stderr,
+        LOCAL_FS.as_ref(),
+        /*sandbox*/ None,
+    )
+    .await
+    .expect("apply selected changes");
+
+    assert_eq!(
+        String::from_utf8(stdout).expect("utf8 stdout"),
+        "Success. Updated the following files:\nM notes.txt\n\
+         The user rejected these changes, so they were not applied:\n\
+         - notes.txt hunk 1:\n@@ -1,3 +1,3 @@\n line 1\n-line 2\n+line two\n line 3\n\
+         - extra.txt (all changes)\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.path().join("notes.txt")).expect("read notes"),
+        original.replace("line 9\n", "line nine\n")
+    );
+    assert!(!dir.path().join("extra.txt").exists());
+}
+
+#[tokio::test]
+async fn rejecting_every_hunk_of_a_moved_file_keeps_the_move() {
+    let dir = TempDir::new().expect("tempdir");
+    let cwd = PathUri::from_host_native_path(dir.path()).expect("absolute test path");
+    fs::write(dir.path().join("old.txt"), "one\ntwo\n").expect("write old");
+    let action = verified_action(
+        &cwd,
+        "*** Begin Patch\n*** Update File: old.txt\n*** Move to: new.txt\n@@\n-two\n+2\n*** End Patch",
+    )
+    .await;
+
+    let accepted = HashMap::from([(dir.path().join("old.txt"), Some(Vec::new()))]);
+    let mut stdout = Vec::new();
+    let mut stderr = Vec::new();
+    apply_selected_changes(
+        &action,
+        &accepted,
+        &mut stdout,
+        &mut stderr,
+        LOCAL_FS.as_ref(),
+        /*sandbox*/ None,
+    )
+    .await
+    .expect("apply selected changes");
+
+    assert!(!dir.path().join("old.txt").exists());
+    assert_eq!(
+        fs::read_to_string(dir.path().join("new.txt")).expect("read new"),
+        "one\ntwo\n"
+    );
+    assert!(
+        String::from_utf8(stdout)
+            .expect("utf8 stdout")
+            .contains("- old.txt hunk 1:\n"),
+    );
+}
+
+#[tokio::test]
+async fn whole_file_acceptance_keeps_the_original_hunk() {
+    let dir = TempDir::new().expect("tempdir");
+    let cwd = PathUri::from_host_native_path(dir.path()).expect("absolute test path");
+    fs::write(dir.path().join("old.txt"), "gone\n").expect("write old");
+    let action = verified_action(
+        &cwd,
+        "*** Begin Patch\n*** Delete File: old.txt\n*** Add File: new.txt\n+hello\n*** End Patch",
+    )
+    .await;
+
+    let accepted = HashMap::from([
+        (dir.path().join("old.txt"), None),
+        (dir.path().join("new.txt"), None),
+    ]);
+    let selection = select_patch_changes(&action, &accepted).expect("select changes");
+
+    assert_eq!(
+        selection,
+        PatchSelection {
+            hunks: parse_patch(&action.patch)
+                .expect("patch should parse")
+                .hunks,
+            rejected: Vec::new(),
+        }
+    );
+}
@@ -23,8 +23,14 @@ use codex_exec::Cli as ExecCli;
 use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
 use codex_execpolicy::ExecPolicyCheckCommand;
+use codex_execpolicy::ExecPolicyTestCommand;
 use codex_responses_api_proxy::Args as ResponsesApiProxyArgs;
+use codex_rollout_trace::CHROME_TRACE_FILE_NAME;
+use codex_rollout_trace::HTML_REPORT_FILE_NAME;
 use codex_rollout_trace::REDUCED_STATE_FILE_NAME;
+use codex_rollout_trace::RolloutTrace;
+use codex_rollout_trace::chrome_trace_events;
+use codex_rollout_trace::html_report;
 use codex_rollout_trace::replay_bundle;
 use codex_state::StateRuntime;
 use codex_state::memories_db_path;
@@ -56,6 +62,7 @@ mod plugin_cmd;
 mod remote_control_cmd;
 #[cfg(target_os = "windows")]
 mod sandbox_setup;
+mod secrets_cmd;
 mod state_db_recovery;
 #[cfg(not(windows))]
 mod wsl_paths;
@@ -64,6 +71,7 @@ use crate::mcp_cmd::McpCli;
 use crate::plugin_cmd::PluginCli;
 use crate::plugin_cmd::PluginSubcommand;
 use crate::remote_control_cmd::RemoteControlCommand;
+use crate::secrets_cmd::SecretsCli;
 use doctor::DoctorCommand;
 use state_db_recovery as local_state_db;
 
@@ -141,6 +149,9 @@ enum Subcommand {
     /// Manage Codex plugins.
     Plugin(PluginCli),
 
+    /// Manage secrets that commands run by Codex can receive as environment variables.
+    Secrets(SecretsCli),
+
     /// Start Codex as an MCP server (stdio).
     McpServer(McpServerCommand),
 
@@ -189,6 +200,9 @@ enum Subcommand {
     /// Unarchive a saved session by id or session name.
     Unarchive(SessionArchiveCommand),
 
+    /// Export a saved session as a Markdown, HTML, or JSON transcript.
+    Export(SessionExportCommand),
+
     /// Fork a previous interactive session (picker by default; use --last to fork the most recent).
     Fork(ForkCommand),
 
@@ -239,6 +253,10 @@ enum DebugSubcommand {
     #[clap(hide = true)]
     TraceReduce(DebugTraceReduceCommand),
 
+    /// Export a rollout trace as a Perfetto timeline or a static HTML report.
+    #[clap(hide = true)]
+    TraceExport(DebugTraceExportCommand),
+
     /// Internal: reset local memory state for a fresh start.
     #[clap(hide = true)]
     ClearMemories,
@@ -308,6 +326,29 @@ struct DebugTraceReduceCommand {
     output: Option<PathBuf>,
 }
 
+#[derive(Debug, Parser)]
+struct DebugTraceExportCommand {
+    /// Trace bundle directory, or a reduced state.json written by `trace-reduce`.
+    #[arg(value_name = "TRACE")]
+    trace: PathBuf,
+
+    /// Output format.
+    #[arg(long = "format", value_enum, default_value_t = TraceExportFormat::Perfetto)]
+    format: TraceExportFormat,
+
+    /// Output path. Defaults to timeline.json or report.html next to TRACE.
+    #[arg(long = "output", short = 'o', value_name = "FILE")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum TraceExportFormat {
+    /// Chrome trace-event JSON, viewable in Perfetto or chrome://tracing.
+    Perfetto,
+    /// Self-contained static HTML report.
+    Html,
+}
+
 #[derive(Debug, Parser)]
 struct ResumeCommand {
     /// Session id (UUID) or session name. UUIDs take precedence if it parses.
@@ -370,6 +411,46 @@ struct DeleteCommand {
     force: bool,
 }
 
+#[derive(Debug, Args)]
+struct SessionExportCommand {
+    #[clap(flatten)]
+    session: SessionArchiveCommand,
+
+    /// Transcript format.
+    #[arg(long = "format", value_enum, default_value_t = SessionExportFormat::Markdown)]
+    format: SessionExportFormat,
+
+    /// Write the transcript to FILE instead of stdout.
+    #[arg(long = "output", short = 'o', value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Leave command output, tool results, and diffs out of the transcript.
+    #[arg(long = "no-tool-output", default_value_t = false)]
+    no_tool_output: bool,
+
+    /// Replace API keys, tokens, and other detected secrets with a placeholder.
+    #[arg(long = "redact-secrets", default_value_t = false)]
+    redact_secrets: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum SessionExportFormat {
+    #[value(alias = "md")]
+    Markdown,
+    Html,
+    Json,
+}
+
+impl From<SessionExportFormat> for codex_app_server_protocol::ThreadExportFormat {
+    fn from(format: SessionExportFormat) -> Self {
+        match format {
+            SessionExportFormat::Markdown => Self::Markdown,
+            SessionExportFormat::Html => Self::Html,
+            SessionExportFormat::Json => Self::Json,
+        }
+    }
+}
+
 #[derive(Debug, Parser)]
 struct ForkCommand {
     /// Conversation/session id (UUID). When provided, forks this session.
@@ -454,6 +535,10 @@ enum ExecpolicySubcommand {
     /// Check execpolicy files against a command.
     #[clap(name = "check")]
     Check(ExecPolicyCheckCommand),
+
+    /// Run a file of commands against execpolicy files and report rule coverage.
+    #[clap(name = "test")]
+    Test(ExecPolicyTestCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -819,37 +904,103 @@ fn run_execpolicycheck(cmd: ExecPolicyCheckCommand) -> anyhow::Result<()> {
     cmd.run()
 }
 
+fn run_execpolicytest(cmd: ExecPolicyTestCommand) -> anyhow::Result<()> {
+    cmd.run()
+}
+
 async fn run_session_archive_cli_command(
     action: codex_tui::SessionArchiveAction,
     cmd: SessionArchiveCommand,
-    mut interactive: TuiCli,
+    interactive: TuiCli,
     root_config_overrides: CliConfigOverrides,
     root_remote: Option<String>,
     root_remote_auth_token_env: Option<String>,
     arg0_paths: Arg0DispatchPaths,
 ) -> anyhow::Result<String> {
+    let (target, options) = session_archive_command_options(
+        cmd,
+        interactive,
+        root_config_overrides,
+        root_remote,
+        root_remote_auth_token_env,
+        arg0_paths,
+    )?;
+    codex_tui::run_session_archive_command(action, target, options)
+        .await
+        .map_err(|err| anyhow::anyhow!("{err}"))
+}
+
+async fn run_session_export_cli_command(
+    cmd: SessionExportCommand,
+    interactive: TuiCli,
+    root_config_overrides: CliConfigOverrides,
+    root_remote: Option<String>,
+    root_remote_auth_token_env: Option<String>,
+    arg0_paths: Arg0DispatchPaths,
+) -> anyhow::Result<()> {
+    let SessionExportCommand {
+        session,
+        format,
+        output,
+        no_tool_output,
+        redact_secrets,
+    } = cmd;
+    let (target, options) = session_archive_command_options(
+        session,
+        interactive,
+        root_config_overrides,
+        root_remote,
+        root_remote_auth_token_env,
+        arg0_paths,
+    )?;
+    let content = codex_tui::run_session_export_command(
+        target,
+        codex_tui::SessionExportOptions {
+            format: format.into(),
+            omit_tool_output: no_tool_output,
+            redact_secrets,
+        },
+        options,
+    )
+    .await
+    .map_err(|err| anyhow::anyhow!("{err}"))?;
+    match output {
+        Some(output) => {
+            tokio::fs::write(&output, content).await?;
+            println!("{}", output.display());
+        }
+        None => print!("{content}"),
+    }
+    Ok(())
+}
+
+fn session_archive_command_options(
+    cmd: SessionArchiveCommand,
+    interactive: TuiCli,
+    root_config_overrides: CliConfigOverrides,
+    root_remote: Option<String>,
+    root_remote_auth_token_env: Option<String>,
+    arg0_paths: Arg0DispatchPaths,
+) -> anyhow::Result<(String, codex_tui::SessionArchiveCommandOptions)> {
     let SessionArchiveCommand {
         target,
         remote,
         config_overrides,
     } = cmd;
-    interactive =
+    let interactive =
         finalize_session_archive_interactive(interactive, root_config_overrides, config_overrides);
     let explicit_remote_endpoint = resolve_remote_endpoint(
         remote.remote.or(root_remote),
         remote.remote_auth_token_env.or(root_remote_auth_token_env),
     )?;
-    codex_tui::run_session_archive_command(
-        action,
+    Ok((
         target,
         codex_tui::SessionArchiveCommandOptions {
             cli: interactive,
             arg0_paths,
             explicit_remote_endpoint,
         },
-    )
-    .await
-    .map_err(|err| anyhow::anyhow!("{err}"))
+    ))
 }
 
 fn delete_action(target: &str, force: bool) -> anyhow::Result<codex_tui::SessionArchiveAction> {
@@ -1097,6 +1248,14 @@ async fn cli_main(
                 }
             }
         }
+        Some(Subcommand::Secrets(secrets_cli)) => {
+            reject_remote_mode_for_subcommand(
+                root_remote.as_deref(),
+                root_remote_auth_token_env.as_deref(),
+                "secrets",
+            )?;
+            secrets_cli.run()?;
+        }
         Some(Subcommand::AppServer(app_server_cli)) => {
             let AppServerCommand {
                 subcommand,
@@ -1309,6 +1468,17 @@ async fn cli_main(
             .await?;
             println!("{output}");
         }
+        Some(Subcommand::Export(cmd)) => {
+            run_session_export_cli_command(
+                cmd,
+                interactive,
+                root_config_overrides.clone(),
+                root_remote.clone(),
+                root_remote_auth_token_env.clone(),
+                arg0_paths.clone(),
+            )
+            .await?;
+        }
         Some(Subcommand::Fork(ForkCommand {
             session_id,
             last,
@@ -1526,6 +1696,14 @@ async fn cli_main(
                 )?;
                 run_debug_trace_reduce_command(cmd).await?;
             }
+            DebugSubcommand::TraceExport(cmd) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "debug trace-export",
+                )?;
+                run_debug_trace_export_command(cmd).await?;
+            }
             DebugSubcommand::ClearMemories => {
                 reject_remote_mode_for_subcommand(
                     root_remote.as_deref(),
@@ -1544,6 +1722,14 @@ async fn cli_main(
                 )?;
                 run_execpolicycheck(cmd)?
             }
+            ExecpolicySubcommand::Test(cmd) => {
+                reject_remote_mode_for_subcommand(
+                    root_remote.as_deref(),
+                    root_remote_auth_token_env.as_deref(),
+                    "execpolicy test",
+                )?;
+                run_execpolicytest(cmd)?
+            }
         },
         Some(Subcommand::Apply(mut apply_cli)) => {
             reject_remote_mode_for_subcommand(
@@ -1662,6 +1848,7 @@ fn profile_v2_for_subcommand<'a>(
         | Subcommand::Archive(_)
         | Subcommand::Delete(_)
         | Subcommand::Unarchive(_)
+        | Subcommand::Export(_)
         | Subcommand::Fork(_)
         | Subcommand::Mcp(_)
         | Subcommand::Sandbox(_)
@@ -1669,7 +1856,7 @@ fn profile_v2_for_subcommand<'a>(
             subcommand: DebugSubcommand::PromptInput(_),
         }) => Ok(Some(profile_v2)),
         _ => anyhow::bail!(
-            "--profile only applies to runtime commands and `codex mcp`: `codex`, `codex exec`, `codex review`, `codex resume`, `codex archive`, `codex delete`, `codex unarchive`, `codex fork`, `codex mcp`, `codex sandbox`, and `codex debug prompt-input`."
+            "--profile only applies to runtime commands and `codex mcp`: `codex`, `codex exec`, `codex review`, `codex resume`, `codex archive`, `codex delete`, `codex unarchive`, `codex export`, `codex fork`, `codex mcp`, `codex sandbox`, and `codex debug prompt-input`."
         ),
     }
 }
@@ -1908,6 +2095,36 @@ async fn run_debug_trace_reduce_command(cmd: DebugTraceReduceCommand) -> anyhow:
     Ok(())
 }
 
+async fn run_debug_trace_export_command(cmd: DebugTraceExportCommand) -> anyhow::Result<()> {
+    let (trace, bundle_dir) = if cmd.trace.is_dir() {
+        (replay_bundle(&cmd.trace)?, cmd.trace.clone())
+    } else {
+        let reduced_json = tokio::fs::read(&cmd.trace).await?;
+        let trace: RolloutTrace = serde_json::from_slice(&reduced_json)?;
+        let bundle_dir = cmd
+            .trace
+            .parent()
+            .map(std::path::Path::to_path_buf)
+            .unwrap_or_default();
+        (trace, bundle_dir)
+    };
+
+    let (contents, default_file_name) = match cmd.format {
+        TraceExportFormat::Perfetto => (
+            serde_json::to_vec(&chrome_trace_events(&trace))?,
+            CHROME_TRACE_FILE_NAME,
+        ),
+        TraceExportFormat::Html => (html_report(&trace).into_bytes(), HTML_REPORT_FILE_NAME),
+    };
+    let output = cmd
+        .output
+        .unwrap_or_else(|| bundle_dir.join(default_file_name));
+    tokio::fs::write(&output, contents).await?;
+    println!("{}", output.display());
+
+    Ok(())
+}
+
 async fn run_debug_prompt_input_command(
     cmd: DebugPromptInputCommand,
     root_config_overrides: CliConfigOverrides,
@@ -2115,6 +2332,7 @@ fn unsupported_subcommand_name_for_strict_config(
         | Some(Subcommand::Archive(_))
         | Some(Subcommand::Delete(_))
         | Some(Subcommand::Unarchive(_))
+        | Some(Subcommand::Export(_))
         | Some(Subcommand::Fork(_))
         | Some(Subcommand::Doctor(_)) => None,
         Some(Subcommand::AppServer(app_server)) if app_server.subcommand.is_none() => None,
@@ -2124,6 +2342,7 @@ fn unsupported_subcommand_name_for_strict_config(
         Some(Subcommand::RemoteControl(remote_control)) => Some(remote_control.subcommand_name()),
         Some(Subcommand::Mcp(_)) => Some("mcp"),
         Some(Subcommand::Plugin(_)) => Some("plugin"),
+        Some(Subcommand::Secrets(_)) => Some("secrets"),
         #[cfg(any(target_os = "macos", target_os = "windows"))]
         Some(Subcommand::App(_)) => Some("app"),
         Some(Subcommand::Login(_)) => Some("login"),
@@ -2867,6 +3086,40 @@ mod tests {
         assert!(matches!(cli.subcommand, Some(Subcommand::Plugin(_))));
     }
 
+    #[test]
+    fn secrets_set_parses_scope_flags() {
+        let cli = MultitoolCli::try_parse_from([
+            "codex",
+            "secrets",
+            "set",
+            "GITHUB_TOKEN",
+            "--env",
+            "repo",
+        ])
+        .expect("parse");
+
+        let Some(Subcommand::Secrets(SecretsCli {
+            subcommand: secrets_cmd::SecretsSubcommand::Set(args),
+        })) = cli.subcommand
+        else {
+            panic!("expected secrets set");
+        };
+        assert_eq!(args.name, "GITHUB_TOKEN");
+        assert_eq!(args.scope.env.as_deref(), Some("repo"));
+        assert!(
+            MultitoolCli::try_parse_from([
+                "codex",
+                "secrets",
+                "get",
+                "TOKEN",
+                "--env",
+                "repo",
+                "--project",
+            ])
+            .is_err()
+        );
+    }
+
     #[test]
     fn plugin_marketplace_upgrade_parses_under_plugin() {
         let cli =
@@ -2956,6 +3209,31 @@ mod tests {
         assert!(interactive.bypass_hook_trust);
     }
 
+    #[test]
+    fn export_parses_format_and_transcript_flags() {
+        let cli = MultitoolCli::try_parse_from([
+            "codex",
+            "export",
+            "--format",
+            "md",
+            "-o",
+            "session.md",
+            "--no-tool-output",
+            "--redact-secrets",
+            "my-thread",
+        ])
+        .expect("parse");
+        let Some(Subcommand::Export(cmd)) = cli.subcommand else {
+            panic!("expected export subcommand");
+        };
+
+        assert_eq!(cmd.session.target, "my-thread");
+        assert_eq!(cmd.format, SessionExportFormat::Markdown);
+        assert_eq!(cmd.output, Some(PathBuf::from("session.md")));
+        assert!(cmd.no_tool_output);
+        assert!(cmd.redact_secrets);
+    }
+
     #[test]
     fn delete_force_requires_uuid() {
         assert!(delete_action("123e4567-e89b-12d3-a456-426614174000", true).is_ok());
@@ -0,0 +1,270 @@
+use std::io::IsTerminal;
+use std::io::Read;
+use std::io::Write;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use clap::ArgGroup;
+use codex_core::config::find_codex_home;
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsBackendKind;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+use crossterm::event::Event;
+use crossterm::event::KeyCode;
+use crossterm::event::KeyEvent;
+use crossterm::event::KeyEventKind;
+use crossterm::event::KeyModifiers;
+
+/// Subcommands:
+/// - `set`    — store a secret (value piped on stdin or typed at a hidden prompt)
+/// - `get`    — print a secret value
+/// - `list`   — list stored secret names (with `--json`)
+/// - `delete` — remove a secret
+///
+/// Secrets are global unless scoped with `--env <ID>` or `--project`. Commands run by the agent
+/// may request them by name, preferring the project scope over the global one.
+#[derive(Debug, clap::Parser)]
+pub struct SecretsCli {
+    #[command(subcommand)]
+    pub subcommand: SecretsSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum SecretsSubcommand {
+    Set(SetArgs),
+    Get(GetArgs),
+    List(ListArgs),
+    Delete(DeleteArgs),
+}
+
+#[derive(Debug, clap::Args)]
+#[command(group(ArgGroup::new("scope").args(["env", "project"]).multiple(false)))]
+pub struct ScopeArgs {
+    /// Scope the secret to an environment id instead of storing it globally.
+    #[arg(long, value_name = "ID")]
+    pub env: Option<String>,
+
+    /// Scope the secret to the environment of the current directory (its git repository name).
+    #[arg(long)]
+    pub project: bool,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct SetArgs {
+    /// Secret name; uppercase letters, digits, and underscores, as in `GITHUB_TOKEN`.
+    pub name: String,
+
+    #[command(flatten)]
+    pub scope: ScopeArgs,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct GetArgs {
+    /// Name of the secret to print.
+    pub name: String,
+
+    #[command(flatten)]
+    pub scope: ScopeArgs,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct ListArgs {
+    /// Output the secret names and scopes as JSON.
+    #[arg(long)]
+    pub json: bool,
+
+    #[command(flatten)]
+    pub scope: ScopeArgs,
+}
+
+#[derive(Debug, clap::Parser)]
+pub struct DeleteArgs {
+    /// Name of the secret to delete.
+    pub name: String,
+
+    #[command(flatten)]
+    pub scope: ScopeArgs,
+}
+
+impl SecretsCli {
+    pub fn run(self) -> Result<()> {
+        let codex_home = find_codex_home().context("failed to resolve CODEX_HOME")?;
+        let manager = SecretsManager::new(codex_home.to_path_buf(), SecretsBackendKind::Local);
+        match self.subcommand {
+            SecretsSubcommand::Set(args) => run_set(&manager, args),
+            SecretsSubcommand::Get(args) => run_get(&manager, args),
+            SecretsSubcommand::List(args) => run_list(&manager, args),
+            SecretsSubcommand::Delete(args) => run_delete(&manager, args),
+        }
+    }
+}
+
+fn run_set(manager: &SecretsManager, args: SetArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let scope = args.scope.resolve()?;
+    let value = read_secret_value(&name)?;
+    if value.is_empty() {
+        bail!("refusing to store an empty value for `{name}`");
+    }
+    manager
+        .set(&scope, &name, &value)
+        .with_context(|| format!("failed to store secret `{name}`"))?;
+    println!("Stored secret `{name}` ({}).", describe_scope(&scope));
+    Ok(())
+}
+
+fn run_get(manager: &SecretsManager, args: GetArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let scope = args.scope.resolve()?;
+    match manager
+        .get(&scope, &name)
+        .with_context(|| format!("failed to read secret `{name}`"))?
+    {
+        Some(value) => {
+            println!("{value}");
+            Ok(())
+        }
+        None => bail!("no secret named `{name}` ({})", describe_scope(&scope)),
+    }
+}
+
+fn run_list(manager: &SecretsManager, args: ListArgs) -> Result<()> {
+    let scope = if args.scope.env.is_some() || args.scope.project {
+        Some(args.scope.resolve()?)
+    } else {
+        None
+    };
+    let mut entries = manager
+        .list(scope.as_ref())
+        .context("failed to list secrets")?;
+    entries.sort_by(|left, right| {
+        describe_scope(&left.scope)
+            .cmp(&describe_scope(&right.scope))
+            .then_with(|| left.name.as_str().cmp(right.name.as_str()))
+    });
+
+    if args.json {
+        let json_entries = entries
+            .iter()
+            .map(|entry| {
+                let environment = match &entry.scope {
+                    SecretScope::Global => None,
+                    SecretScope::Environment(environment_id) => Some(environment_id),
+                };
+                serde_json::json!({
+                    "name": entry.name.as_str(),
+                    "environment": environment,
+                })
+            })
+            .collect::<Vec<_>>();
+        println!("{}", serde_json::to_string_pretty(&json_entries)?);
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        println!("No secrets stored. Add one with `codex secrets set <NAME>`.");
+        return Ok(());
+    }
+    let name_width = entries
+        .iter()
+        .map(|entry| entry.name.as_str().len())
+        .max()
+        .unwrap_or_default();
+    for entry in &entries {
+        println!(
+            "{:<name_width$}  {}",
+            entry.name.as_str(),
+            describe_scope(&entry.scope)
+        );
+    }
+    Ok(())
+}
+
+fn run_delete(manager: &SecretsManager, args: DeleteArgs) -> Result<()> {
+    let name = SecretName::new(&args.name)?;
+    let scope = args.scope.resolve()?;
+    let removed = manager
+        .delete(&scope, &name)
+        .with_context(|| format!("failed to delete secret `{name}`"))?;
+    if !removed {
+        bail!("no secret named `{name}` ({})", describe_scope(&scope));
+    }
+    println!("Deleted secret `{name}` ({}).", describe_scope(&scope));
+    Ok(())
+}
+
+impl ScopeArgs {
+    fn resolve(&self) -> Result<SecretScope> {
+        if let Some(environment_id) = &self.env {
+            return SecretScope::environment(environment_id.clone());
+        }
+        if self.project {
+            let cwd = std::env::current_dir().context("failed to resolve current directory")?;
+            return SecretScope::environment(environment_id_from_cwd(&cwd));
+        }
+        Ok(SecretScope::Global)
+    }
+}
+
+fn describe_scope(scope: &SecretScope) -> String {
+    match scope {
+        SecretScope::Global => "global".to_string(),
+        SecretScope::Environment(environment_id) => format!("env {environment_id}"),
+    }
+}
+
+/// Reads the value from stdin when it is piped, otherwise prompts for it without echoing, so the
+/// value never appears in argv or shell history.
+fn read_secret_value(name: &SecretName) -> Result<String> {
+    let mut stdin = std::io::stdin();
+    if stdin.is_terminal() {
+        return prompt_secret_value(name);
+    }
+    let mut buffer = String::new();
+    stdin
+        .read_to_string(&mut buffer)
+        .context("failed to read secret value from stdin")?;
+    Ok(buffer.trim_end_matches(['\r', '\n']).to_string())
+}
+
+fn prompt_secret_value(name: &SecretName) -> Result<String> {
+    eprint!("Value for {name} (input hidden): ");
+    std::io::stderr().flush()?;
+    crossterm::terminal::enable_raw_mode().context("failed to enable raw terminal mode")?;
+    let value = read_hidden_line();
+    let _ = crossterm::terminal::disable_raw_mode();
+    eprintln!();
+    value
+}
+
+fn read_hidden_line() -> Result<String> {
+    let mut value = String::new();
+    loop {
+        let Event::Key(KeyEvent {
+            code,
+            modifiers,
+            kind,
+            ..
+        }) = crossterm::event::read().context("failed to read from the terminal")?
+        else {
+            continue;
+        };
+        if kind == KeyEventKind::Release {
+            continue;
+        }
+        match code {
+            KeyCode::Enter => return Ok(value),
+            KeyCode::Char('c') if modifiers.contains(KeyModifiers::CONTROL) => {
+                bail!("cancelled");
+            }
+            KeyCode::Char(ch) => value.push(ch),
+            KeyCode::Backspace => {
+                value.pop();
+            }
+            _ => {}
+        }
+    }
+}
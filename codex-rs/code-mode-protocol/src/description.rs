@@ -7,6 +7,7 @@ use std::collections::BTreeMap;
 use crate::PUBLIC_TOOL_NAME;
 
 const MAX_JS_SAFE_INTEGER: u64 = (1_u64 << 53) - 1;
+const MAX_SAVED_MODULE_NAME_CHARS: usize = 64;
 const DEFERRED_NESTED_TOOLS_GUIDANCE: &str = r#"Some deferred nested tools may be omitted from this description. They are still available on the global `tools` object and listed in `ALL_TOOLS`.
 To find one, filter `ALL_TOOLS` by `name` and `description`."#;
 const EXEC_DESCRIPTION_TEMPLATE: &str = r#"Run JavaScript code to orchestrate/compose tool calls
@@ -19,6 +20,8 @@ const EXEC_DESCRIPTION_TEMPLATE: &str = r#"Run JavaScript code to orchestrate/co
 - You may optionally start the tool input with a first-line pragma like `// @exec: {"yield_time_ms": 10000, "max_output_tokens": 1000}`.
 - `yield_time_ms` asks `exec` to yield early if the script is still running. Defaults to 10000 ms.
 - `max_output_tokens` sets the token budget for direct `exec` results. Defaults to 10000 tokens.
+- `save_as` (letters, digits, `_`, `-`) saves the source as the module `lib/<save_as>.js` once the script completes successfully, so later `exec` calls can reuse it. Export the reusable functions from it.
+- Scripts can `import` saved modules, for example `import { triage } from "lib/triage.js";`, and helper modules shipped with skills as `skills/<skill>/<path>`. Relative imports resolve against the importing module. Nothing else can be imported.
 - When the JS code is fully evaluated, the isolate's lifetime ends and unawaited promises are silently discarded.
 
 - Global helpers:
@@ -152,6 +155,8 @@ struct CodeModeExecPragma {
     yield_time_ms: Option<u64>,
     #[serde(default)]
     max_output_tokens: Option<usize>,
+    #[serde(default)]
+    save_as: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -159,6 +164,8 @@ pub struct ParsedExecSource {
     pub code: String,
     pub yield_time_ms: Option<u64>,
     pub max_output_tokens: Option<usize>,
+    /// Name under which the source is saved as a reusable module once it completes successfully.
+    pub save_as: Option<String>,
 }
 
 pub fn parse_exec_source(input: &str) -> Result<ParsedExecSource, String> {
@@ -172,6 +179,7 @@ pub fn parse_exec_source(input: &str) -> Result<ParsedExecSource, String> {
         code: input.to_string(),
         yield_time_ms: None,
         max_output_tokens: None,
+        save_as: None,
     };
 
     let mut lines = input.splitn(2, '\n');
@@ -191,26 +199,26 @@ pub fn parse_exec_source(input: &str) -> Result<ParsedExecSource, String> {
     let directive = pragma.trim();
     if directive.is_empty() {
         return Err(
-            "exec pragma must be a JSON object with supported fields `yield_time_ms` and `max_output_tokens`"
+            "exec pragma must be a JSON object with supported fields `yield_time_ms`, `max_output_tokens`, and `save_as`"
                 .to_string(),
         );
     }
 
     let value: serde_json::Value = serde_json::from_str(directive).map_err(|err| {
         format!(
-            "exec pragma must be valid JSON with supported fields `yield_time_ms` and `max_output_tokens`: {err}"
+            "exec pragma must be valid JSON with supported fields `yield_time_ms`, `max_output_tokens`, and `save_as`: {err}"
         )
     })?;
     let object = value.as_object().ok_or_else(|| {
-        "exec pragma must be a JSON object with supported fields `yield_time_ms` and `max_output_tokens`"
+        "exec pragma must be a JSON object with supported fields `yield_time_ms`, `max_output_tokens`, and `save_as`"
             .to_string()
     })?;
     for key in object.keys() {
         match key.as_str() {
-            "yield_time_ms" | "max_output_tokens" => {}
+            "yield_time_ms" | "max_output_tokens" | "save_as" => {}
             _ => {
                 return Err(format!(
-                    "exec pragma only supports `yield_time_ms` and `max_output_tokens`; got `{key}`"
+                    "exec pragma only supports `yield_time_ms`, `max_output_tokens`, and `save_as`; got `{key}`"
                 ));
             }
         }
@@ -218,7 +226,7 @@ pub fn parse_exec_source(input: &str) -> Result<ParsedExecSource, String> {
 
     let pragma: CodeModeExecPragma = serde_json::from_value(value).map_err(|err| {
         format!(
-            "exec pragma fields `yield_time_ms` and `max_output_tokens` must be non-negative safe integers: {err}"
+            "exec pragma fields `yield_time_ms` and `max_output_tokens` must be non-negative safe integers and `save_as` a string: {err}"
         )
     })?;
     if pragma
@@ -239,12 +247,31 @@ pub fn parse_exec_source(input: &str) -> Result<ParsedExecSource, String> {
         );
     }
 
+    if pragma
+        .save_as
+        .as_deref()
+        .is_some_and(|name| !is_valid_saved_module_name(name))
+    {
+        return Err(format!(
+            "exec pragma field `save_as` must be 1-{MAX_SAVED_MODULE_NAME_CHARS} letters, digits, `_`, or `-`"
+        ));
+    }
+
     args.code = rest.to_string();
     args.yield_time_ms = pragma.yield_time_ms;
     args.max_output_tokens = pragma.max_output_tokens;
+    args.save_as = pragma.save_as;
     Ok(args)
 }
 
+fn is_valid_saved_module_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_SAVED_MODULE_NAME_CHARS
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+}
+
 pub fn is_code_mode_nested_tool(tool_name: &str) -> bool {
     tool_name != crate::PUBLIC_TOOL_NAME && tool_name != crate::WAIT_TOOL_NAME
 }
@@ -754,6 +781,7 @@ mod tests {
                 code: "text('hi')".to_string(),
                 yield_time_ms: None,
                 max_output_tokens: None,
+                save_as: None,
             }
         );
     }
@@ -766,8 +794,24 @@ mod tests {
                 code: "text('hi')".to_string(),
                 yield_time_ms: Some(10),
                 max_output_tokens: None,
+                save_as: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_exec_source_with_save_as_pragma() {
+        assert_eq!(
+            parse_exec_source("// @exec: {\"save_as\": \"triage-issues\"}\nexport const x = 1;")
+                .unwrap(),
+            ParsedExecSource {
+                code: "export const x = 1;".to_string(),
+                yield_time_ms: None,
+                max_output_tokens: None,
+                save_as: Some("triage-issues".to_string()),
             }
         );
+        assert!(parse_exec_source("// @exec: {\"save_as\": \"../escape\"}\ntext('hi')").is_err());
     }
 
     #[test]
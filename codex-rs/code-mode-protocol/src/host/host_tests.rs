@@ -102,6 +102,7 @@ fn execute_request() -> WireExecuteRequest {
         source: "text('hello');".to_string(),
         yield_time_ms: Some(25),
         max_output_tokens: Some(100),
+        modules: Default::default(),
     }
 }
 
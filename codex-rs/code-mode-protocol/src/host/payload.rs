@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::num::TryFromIntError;
 
 use codex_protocol::ToolName;
@@ -145,6 +146,8 @@ pub struct WireExecuteRequest {
     pub source: String,
     pub yield_time_ms: Option<u64>,
     pub max_output_tokens: Option<i32>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub modules: BTreeMap<String, String>,
 }
 
 impl TryFrom<ExecuteRequest> for WireExecuteRequest {
@@ -157,6 +160,7 @@ impl TryFrom<ExecuteRequest> for WireExecuteRequest {
             source: value.source,
             yield_time_ms: value.yield_time_ms,
             max_output_tokens: value.max_output_tokens.map(i32::try_from).transpose()?,
+            modules: value.modules,
         })
     }
 }
@@ -171,6 +175,7 @@ impl TryFrom<WireExecuteRequest> for ExecuteRequest {
             source: value.source,
             yield_time_ms: value.yield_time_ms,
             max_output_tokens: value.max_output_tokens.map(usize::try_from).transpose()?,
+            modules: value.modules,
         })
     }
 }
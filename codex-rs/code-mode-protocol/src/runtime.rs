@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use codex_protocol::ToolName;
 use serde::Deserialize;
 use serde::Serialize;
@@ -19,6 +21,9 @@ pub struct ExecuteRequest {
     pub source: String,
     pub yield_time_ms: Option<u64>,
     pub max_output_tokens: Option<usize>,
+    /// Module sources the cell may import, keyed by specifier such as `lib/search.js`.
+    #[serde(default)]
+    pub modules: BTreeMap<String, String>,
 }
 
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
@@ -34,6 +34,7 @@ pub(super) fn runtime_request(request: CellRequest) -> ExecuteRequest {
         source: request.source,
         yield_time_ms: None,
         max_output_tokens: None,
+        modules: request.modules,
     }
 }
 
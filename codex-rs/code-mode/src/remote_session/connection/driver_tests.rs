@@ -127,6 +127,7 @@ impl DriverHarness {
                     source: "await new Promise(() => {})".to_string(),
                     yield_time_ms: Some(1),
                     max_output_tokens: None,
+                    modules: Default::default(),
                 },
                 caller_cancellation: CancellationToken::new(),
                 response_tx,
@@ -387,6 +388,7 @@ async fn dropped_open_waiter_shuts_down_committed_session() {
                 source: "text('ok')".to_string(),
                 yield_time_ms: None,
                 max_output_tokens: None,
+                modules: Default::default(),
             },
             caller_cancellation: CancellationToken::new(),
             response_tx: execute_tx,
@@ -1076,6 +1078,7 @@ async fn abandoned_execute_is_tracked_and_terminated_after_admission() {
                 source: "await new Promise(() => {})".to_string(),
                 yield_time_ms: Some(1),
                 max_output_tokens: None,
+                modules: Default::default(),
             },
             caller_cancellation: cancellation.clone(),
             response_tx: execute_tx,
@@ -1171,6 +1174,7 @@ async fn delivered_but_unclaimed_execute_is_terminated_when_the_caller_is_cancel
                 source: "await new Promise(() => {})".to_string(),
                 yield_time_ms: Some(1),
                 max_output_tokens: None,
+                modules: Default::default(),
             },
             caller_cancellation: cancellation.clone(),
             response_tx: execute_tx,
@@ -1325,6 +1329,7 @@ async fn connection_failure_closes_every_live_cell_once() {
                 source: "await new Promise(() => {})".to_string(),
                 yield_time_ms: Some(1),
                 max_output_tokens: None,
+                modules: Default::default(),
             },
             caller_cancellation: CancellationToken::new(),
             response_tx: execute_tx,
@@ -1493,6 +1498,7 @@ async fn dropped_shutdown_waiter_does_not_abort_remote_cleanup() {
                 source: "text('unreachable')".to_string(),
                 yield_time_ms: None,
                 max_output_tokens: None,
+                modules: Default::default(),
             },
             caller_cancellation: CancellationToken::new(),
             response_tx: execute_tx,
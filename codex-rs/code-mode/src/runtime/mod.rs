@@ -4,6 +4,7 @@ mod module_loader;
 mod timers;
 mod value;
 
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::panic::AssertUnwindSafe;
 use std::panic::catch_unwind;
@@ -99,6 +100,7 @@ pub(crate) fn spawn_runtime(
         tool_call_id: request.tool_call_id,
         enabled_tools,
         source: request.source,
+        modules: request.modules,
         stored_values,
     };
 
@@ -140,6 +142,7 @@ struct RuntimeConfig {
     tool_call_id: String,
     enabled_tools: Vec<EnabledToolMetadata>,
     source: String,
+    modules: BTreeMap<String, String>,
     stored_values: HashMap<String, JsonValue>,
 }
 
@@ -150,6 +153,10 @@ pub(super) struct RuntimeState {
     stored_values: HashMap<String, JsonValue>,
     stored_value_writes: HashMap<String, JsonValue>,
     enabled_tools: Vec<EnabledToolMetadata>,
+    /// Importable module sources keyed by specifier.
+    modules: BTreeMap<String, String>,
+    /// Modules compiled so far, keyed by the specifier they were loaded from.
+    loaded_modules: Vec<(String, v8::Global<v8::Module>)>,
     next_tool_call_id: u64,
     next_timeout_id: u64,
     tool_call_id: String,
@@ -192,6 +199,8 @@ fn run_runtime(
         stored_values: config.stored_values,
         stored_value_writes: HashMap::new(),
         enabled_tools: config.enabled_tools,
+        modules: config.modules,
+        loaded_modules: Vec::new(),
         next_tool_call_id: 1,
         next_timeout_id: 1,
         tool_call_id: config.tool_call_id,
@@ -329,6 +338,7 @@ fn send_result(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::collections::HashMap;
     use std::time::Duration;
 
@@ -351,6 +361,7 @@ mod tests {
             source: source.to_string(),
             yield_time_ms: Some(1),
             max_output_tokens: None,
+            modules: Default::default(),
         }
     }
 
@@ -498,4 +509,92 @@ await new Promise(() => {});
             .send(RuntimeControlCommand::Terminate)
             .unwrap();
     }
+
+    async fn run_to_result(request: ExecuteRequest) -> (Vec<String>, Option<String>) {
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let (_runtime_tx, _runtime_control_tx, _runtime_terminate_handle) = spawn_runtime(
+            HashMap::new(),
+            request,
+            event_tx,
+            PendingRuntimeMode::Continue,
+            /*task_failure_handler*/ None,
+        )
+        .unwrap();
+
+        let mut texts = Vec::new();
+        loop {
+            match tokio::time::timeout(Duration::from_secs(1), event_rx.recv())
+                .await
+                .unwrap()
+                .unwrap()
+            {
+                RuntimeEvent::ContentItem(FunctionCallOutputContentItem::InputText { text }) => {
+                    texts.push(text);
+                }
+                RuntimeEvent::Result { error_text, .. } => return (texts, error_text),
+                _ => {}
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn imports_modules_statically_and_dynamically() {
+        let request = ExecuteRequest {
+            modules: BTreeMap::from([
+                (
+                    "lib/greet.js".to_string(),
+                    r#"import { suffix } from "./util.js"; export const greet = (name) => `hi ${name}${suffix}`;"#
+                        .to_string(),
+                ),
+                (
+                    "lib/util.js".to_string(),
+                    r#"export const suffix = "!";"#.to_string(),
+                ),
+                (
+                    "skills/triage/code-mode/count.mjs".to_string(),
+                    "export default 3;".to_string(),
+                ),
+            ]),
+            ..execute_request(
+                r#"
+import { greet } from "lib/greet";
+text(greet("ada"));
+const count = await import("./skills/triage/code-mode/count.mjs");
+text(String(count.default));
+"#,
+            )
+        };
+
+        assert_eq!(
+            run_to_result(request).await,
+            (vec!["hi ada!".to_string(), "3".to_string()], None)
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_modules_are_reported_by_specifier() {
+        let (texts, error_text) = run_to_result(execute_request(
+            r#"
+try {
+  await import("lib/missing.js");
+} catch (error) {
+  text(String(error));
+}
+"#,
+        ))
+        .await;
+        assert_eq!(error_text, None);
+        assert_eq!(texts.len(), 1);
+        assert!(
+            texts[0].starts_with("Cannot find module `lib/missing.js` in exec"),
+            "{texts:?}"
+        );
+
+        let (_, error_text) = run_to_result(execute_request(r#"import "node:fs";"#)).await;
+        let error_text = error_text.expect("static import of node:fs should fail");
+        assert!(
+            error_text.contains("Cannot find module `node:fs` in exec"),
+            "{error_text}"
+        );
+    }
 }
@@ -6,21 +6,20 @@ use super::RuntimeState;
 use super::value::json_to_v8;
 use super::value::value_to_error_text;
 
+/// Resource name of the cell source. Relative imports from it resolve against the module root.
+const MAIN_MODULE_NAME: &str = "exec_main.mjs";
+
+/// Suffixes tried, in order, when resolving a specifier against the importable modules.
+const MODULE_EXTENSIONS: [&str; 3] = ["", ".js", ".mjs"];
+
 pub(super) fn evaluate_main_module(
     scope: &mut v8::PinScope<'_, '_>,
     source_text: &str,
 ) -> Result<Option<v8::Global<v8::Promise>>, String> {
+    let module = compile_module(scope, MAIN_MODULE_NAME, source_text)?;
     let tc = std::pin::pin!(v8::TryCatch::new(scope));
     let mut tc = tc.init();
-    let source = v8::String::new(&tc, source_text)
-        .ok_or_else(|| "failed to allocate exec source".to_string())?;
-    let origin = script_origin(&mut tc, "exec_main.mjs")?;
-    let mut source = v8::script_compiler::Source::new(source, Some(&origin));
-    let module = v8::script_compiler::compile_module(&tc, &mut source).ok_or_else(|| {
-        tc.exception()
-            .map(|exception| value_to_error_text(&mut tc, exception))
-            .unwrap_or_else(|| "unknown code mode exception".to_string())
-    })?;
+    let module = v8::Local::new(&tc, module);
     module
         .instantiate_module(&tc, resolve_module_callback)
         .ok_or_else(|| {
@@ -161,75 +160,215 @@ fn script_origin<'s>(
     ))
 }
 
+fn compile_module(
+    scope: &mut v8::PinScope<'_, '_>,
+    name: &str,
+    source_text: &str,
+) -> Result<v8::Global<v8::Module>, String> {
+    let tc = std::pin::pin!(v8::TryCatch::new(scope));
+    let mut tc = tc.init();
+    let source = v8::String::new(&tc, source_text)
+        .ok_or_else(|| "failed to allocate exec source".to_string())?;
+    let origin = script_origin(&mut tc, name)?;
+    let mut source = v8::script_compiler::Source::new(source, Some(&origin));
+    let module = v8::script_compiler::compile_module(&tc, &mut source).ok_or_else(|| {
+        tc.exception()
+            .map(|exception| value_to_error_text(&mut tc, exception))
+            .unwrap_or_else(|| "unknown code mode exception".to_string())
+    })?;
+    Ok(v8::Global::new(&tc, module))
+}
+
 fn resolve_module_callback<'s>(
     context: v8::Local<'s, v8::Context>,
     specifier: v8::Local<'s, v8::String>,
     _import_attributes: v8::Local<'s, v8::FixedArray>,
-    _referrer: v8::Local<'s, v8::Module>,
+    referrer: v8::Local<'s, v8::Module>,
 ) -> Option<v8::Local<'s, v8::Module>> {
     v8::callback_scope!(unsafe scope, context);
     let specifier = specifier.to_rust_string_lossy(scope);
-    resolve_module(scope, &specifier)
+    let referrer = v8::Global::new(scope, referrer);
+    let referrer = scope
+        .get_slot::<RuntimeState>()
+        .and_then(|state| {
+            state
+                .loaded_modules
+                .iter()
+                .find(|(_, module)| *module == referrer)
+                .map(|(name, _)| name.clone())
+        })
+        .unwrap_or_else(|| MAIN_MODULE_NAME.to_string());
+    match resolve_module(scope, &referrer, &specifier) {
+        Ok(module) => Some(module),
+        Err(error_text) => {
+            throw_error(scope, &error_text);
+            None
+        }
+    }
 }
 
 pub(super) fn dynamic_import_callback<'s>(
     scope: &mut v8::PinScope<'s, '_>,
     _host_defined_options: v8::Local<'s, v8::Data>,
-    _resource_name: v8::Local<'s, v8::Value>,
+    resource_name: v8::Local<'s, v8::Value>,
     specifier: v8::Local<'s, v8::String>,
     _import_attributes: v8::Local<'s, v8::FixedArray>,
 ) -> Option<v8::Local<'s, v8::Promise>> {
     let specifier = specifier.to_rust_string_lossy(scope);
+    let referrer = resource_name.to_rust_string_lossy(scope);
     let resolver = v8::PromiseResolver::new(scope)?;
 
-    match resolve_module(scope, &specifier) {
-        Some(module) => {
-            if module.get_status() == v8::ModuleStatus::Uninstantiated
-                && module
-                    .instantiate_module(scope, resolve_module_callback)
-                    .is_none()
-            {
-                let error = v8::String::new(scope, "failed to instantiate module")
-                    .map(Into::into)
-                    .unwrap_or_else(|| v8::undefined(scope).into());
-                resolver.reject(scope, error);
-                return Some(resolver.get_promise(scope));
-            }
-            if matches!(
-                module.get_status(),
-                v8::ModuleStatus::Instantiated | v8::ModuleStatus::Evaluated
-            ) && module.evaluate(scope).is_none()
-            {
-                let error = v8::String::new(scope, "failed to evaluate module")
-                    .map(Into::into)
-                    .unwrap_or_else(|| v8::undefined(scope).into());
-                resolver.reject(scope, error);
-                return Some(resolver.get_promise(scope));
-            }
+    let loaded = resolve_module(scope, &referrer, &specifier).and_then(|module| {
+        link_and_evaluate(scope, module)?;
+        Ok(module)
+    });
+    match loaded {
+        Ok(module) => {
             let namespace = module.get_module_namespace();
             resolver.resolve(scope, namespace);
-            Some(resolver.get_promise(scope))
         }
-        None => {
-            let error = v8::String::new(scope, "unsupported import in exec")
+        Err(error_text) => {
+            let error = v8::String::new(scope, &error_text)
                 .map(Into::into)
                 .unwrap_or_else(|| v8::undefined(scope).into());
             resolver.reject(scope, error);
-            Some(resolver.get_promise(scope))
         }
     }
+    Some(resolver.get_promise(scope))
 }
 
+fn link_and_evaluate(
+    scope: &mut v8::PinScope<'_, '_>,
+    module: v8::Local<'_, v8::Module>,
+) -> Result<(), String> {
+    let tc = std::pin::pin!(v8::TryCatch::new(scope));
+    let mut tc = tc.init();
+    if module.get_status() == v8::ModuleStatus::Uninstantiated
+        && module
+            .instantiate_module(&tc, resolve_module_callback)
+            .is_none()
+    {
+        return Err(tc
+            .exception()
+            .map(|exception| value_to_error_text(&mut tc, exception))
+            .unwrap_or_else(|| "unknown code mode exception".to_string()));
+    }
+    if matches!(
+        module.get_status(),
+        v8::ModuleStatus::Instantiated | v8::ModuleStatus::Evaluated
+    ) {
+        let Some(result) = module.evaluate(&tc) else {
+            return Err(tc
+                .exception()
+                .map(|exception| value_to_error_text(&mut tc, exception))
+                .unwrap_or_else(|| "unknown code mode exception".to_string()));
+        };
+        if let Ok(promise) = v8::Local::<v8::Promise>::try_from(result)
+            && promise.state() == v8::PromiseState::Rejected
+        {
+            let error = promise.result(&tc);
+            return Err(value_to_error_text(&mut tc, error));
+        }
+    }
+    Ok(())
+}
+
+/// Resolves `specifier`, imported from the module named `referrer`, against the importable
+/// module sources of the cell. Nothing outside those sources can be imported.
 fn resolve_module<'s>(
     scope: &mut v8::PinScope<'s, '_>,
+    referrer: &str,
     specifier: &str,
-) -> Option<v8::Local<'s, v8::Module>> {
-    if let Some(message) =
-        v8::String::new(scope, &format!("Unsupported import in exec: {specifier}"))
-    {
+) -> Result<v8::Local<'s, v8::Module>, String> {
+    let not_found = || {
+        format!(
+            "Cannot find module `{specifier}` in exec; saved modules import as `lib/<name>.js` and skill modules as `skills/<skill>/<path>`"
+        )
+    };
+    let key = module_key(referrer, specifier).ok_or_else(not_found)?;
+    let (name, source, loaded) = {
+        let state = scope
+            .get_slot::<RuntimeState>()
+            .ok_or_else(|| "runtime state unavailable".to_string())?;
+        let (name, source) = MODULE_EXTENSIONS
+            .iter()
+            .find_map(|extension| {
+                let name = format!("{key}{extension}");
+                let source = state.modules.get(&name)?.clone();
+                Some((name, source))
+            })
+            .ok_or_else(not_found)?;
+        let loaded = state
+            .loaded_modules
+            .iter()
+            .find(|(loaded, _)| *loaded == name)
+            .map(|(_, module)| module.clone());
+        (name, source, loaded)
+    };
+    if let Some(module) = loaded {
+        return Ok(v8::Local::new(scope, module));
+    }
+
+    let module = compile_module(scope, &name, &source)
+        .map_err(|error_text| format!("failed to load module `{name}`: {error_text}"))?;
+    if let Some(state) = scope.get_slot_mut::<RuntimeState>() {
+        state.loaded_modules.push((name, module.clone()));
+    }
+    Ok(v8::Local::new(scope, module))
+}
+
+/// Joins a relative specifier onto the directory of its referrer. Bare specifiers are taken
+/// as-is from the module root; absolute paths, URLs, and paths escaping the root are rejected.
+fn module_key(referrer: &str, specifier: &str) -> Option<String> {
+    let mut segments = Vec::new();
+    if specifier.starts_with("./") || specifier.starts_with("../") {
+        segments.extend(referrer.split('/'));
+        segments.pop();
+    } else if specifier.starts_with('/') || specifier.contains(':') || specifier.contains('\\') {
+        return None;
+    }
+    for segment in specifier.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                segments.pop()?;
+            }
+            segment => segments.push(segment),
+        }
+    }
+    (!segments.is_empty()).then(|| segments.join("/"))
+}
+
+fn throw_error(scope: &mut v8::PinScope<'_, '_>, error_text: &str) {
+    if let Some(message) = v8::String::new(scope, error_text) {
         scope.throw_exception(message.into());
     } else {
         scope.throw_exception(v8::undefined(scope).into());
     }
-    None
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::module_key;
+
+    #[test]
+    fn module_key_resolves_relative_and_bare_specifiers() {
+        assert_eq!(
+            module_key("exec_main.mjs", "./lib/search.js"),
+            Some("lib/search.js".to_string())
+        );
+        assert_eq!(
+            module_key("skills/triage/code-mode/index.js", "../shared/util"),
+            Some("skills/triage/shared/util".to_string())
+        );
+        assert_eq!(
+            module_key("lib/report.js", "lib/search.js"),
+            Some("lib/search.js".to_string())
+        );
+        assert_eq!(module_key("exec_main.mjs", "../escape.js"), None);
+        assert_eq!(module_key("lib/report.js", "/etc/passwd"), None);
+        assert_eq!(module_key("lib/report.js", "node:fs"), None);
+    }
 }
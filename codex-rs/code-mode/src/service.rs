@@ -332,6 +332,7 @@ fn runtime_request(request: ExecuteRequest) -> runtime::CreateCellRequest {
             })
             .collect(),
         source: request.source,
+        modules: request.modules,
     }
 }
 
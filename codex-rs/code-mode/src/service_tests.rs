@@ -180,6 +180,7 @@ fn execute_request(source: &str) -> ExecuteRequest {
         source: source.to_string(),
         yield_time_ms: Some(1),
         max_output_tokens: None,
+        modules: Default::default(),
     }
 }
 
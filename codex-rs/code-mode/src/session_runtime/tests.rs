@@ -151,6 +151,7 @@ async fn termination_rejects_a_waiting_store_commit_before_the_next_cell_can_loa
                 tool_call_id: "reader".to_string(),
                 enabled_tools: Vec::new(),
                 source: r#"text(String(load("candidate")));"#.to_string(),
+                modules: Default::default(),
             },
             ObserveMode::YieldAfter(Duration::from_secs(1)),
         )
@@ -173,6 +174,7 @@ fn execute_request(source: &str) -> CreateCellRequest {
         tool_call_id: "call-1".to_string(),
         enabled_tools: Vec::new(),
         source: source.to_string(),
+        modules: Default::default(),
     }
 }
 
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt;
 use std::future::Future;
 use std::time::Duration;
@@ -82,6 +83,8 @@ pub(crate) struct CreateCellRequest {
     pub(crate) tool_call_id: String,
     pub(crate) enabled_tools: Vec<ToolDefinition>,
     pub(crate) source: String,
+    /// Module sources the cell may import, keyed by specifier.
+    pub(crate) modules: BTreeMap<String, String>,
 }
 
 /// Tool metadata exposed to code running inside a cell.
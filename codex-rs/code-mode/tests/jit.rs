@@ -17,6 +17,7 @@ async fn code_mode_runs_with_jit_disabled() {
             source: "21 * 2;".to_string(),
             yield_time_ms: None,
             max_output_tokens: None,
+            modules: Default::default(),
         })
         .await
         .expect("start code-mode cell");
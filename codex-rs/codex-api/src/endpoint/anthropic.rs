@@ -0,0 +1,107 @@
+use crate::auth::SharedAuthProvider;
+use crate::common::ResponseStream;
+use crate::common::ResponsesApiRequest;
+use crate::endpoint::ResponsesOptions;
+use crate::endpoint::session::EndpointSession;
+use crate::error::ApiError;
+use crate::provider::Provider;
+use crate::requests::anthropic::build_anthropic_request;
+use crate::sse::spawn_anthropic_stream;
+use crate::telemetry::SseTelemetry;
+use codex_client::EncodedJsonBody;
+use codex_client::HttpTransport;
+use codex_client::RequestTelemetry;
+use http::HeaderValue;
+use http::Method;
+use std::sync::Arc;
+use tracing::instrument;
+
+const ANTHROPIC_VERSION_HEADER: &str = "anthropic-version";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Streams turns from providers configured with `wire_api = "anthropic"`.
+///
+/// Takes the same request as [`crate::ResponsesClient`] and translates it to
+/// and from the Anthropic Messages API.
+pub struct AnthropicClient<T: HttpTransport> {
+    session: EndpointSession<T>,
+    sse_telemetry: Option<Arc<dyn SseTelemetry>>,
+}
+
+impl<T: HttpTransport> AnthropicClient<T> {
+    pub fn new(transport: T, provider: Provider, auth: SharedAuthProvider) -> Self {
+        Self {
+            session: EndpointSession::new(transport, provider, auth),
+            sse_telemetry: None,
+        }
+    }
+
+    pub fn with_telemetry(
+        self,
+        request: Option<Arc<dyn RequestTelemetry>>,
+        sse: Option<Arc<dyn SseTelemetry>>,
+    ) -> Self {
+        Self {
+            session: self.session.with_request_telemetry(request),
+            sse_telemetry: sse,
+        }
+    }
+
+    fn path() -> &'static str {
+        "messages"
+    }
+
+    #[instrument(
+        name = "anthropic.stream_request",
+        level = "info",
+        skip_all,
+        fields(
+            transport = "anthropic_http",
+            http.method = "POST",
+            api.path = "messages"
+        )
+    )]
+    pub async fn stream_request(
+        &self,
+        request: &ResponsesApiRequest,
+        options: ResponsesOptions,
+    ) -> Result<ResponseStream, ApiError> {
+        // Session, thread, and turn-state headers are Responses API concepts,
+        // and the Messages API does not accept compressed request bodies.
+        let ResponsesOptions { extra_headers, .. } = options;
+
+        let anthropic_request = build_anthropic_request(request);
+        let body = EncodedJsonBody::encode(&anthropic_request.body)
+            .map_err(|e| ApiError::Stream(format!("failed to encode anthropic request: {e}")))?;
+
+        let stream_response = self
+            .session
+            .stream_encoded_json_with(
+                Method::POST,
+                Self::path(),
+                extra_headers,
+                Some(body),
+                |req| {
+                    req.headers.insert(
+                        http::header::ACCEPT,
+                        HeaderValue::from_static("text/event-stream"),
+                    );
+                    // Provider `http_headers` may pin another API version.
+                    if !req.headers.contains_key(ANTHROPIC_VERSION_HEADER) {
+                        req.headers.insert(
+                            ANTHROPIC_VERSION_HEADER,
+                            HeaderValue::from_static(ANTHROPIC_VERSION),
+                        );
+                    }
+                },
+            )
+            .await?;
+
+        Ok(spawn_anthropic_stream(
+            stream_response,
+            self.session.provider().stream_idle_timeout,
+            self.sse_telemetry.clone(),
+            anthropic_request.namespaced_tools,
+        ))
+    }
+}
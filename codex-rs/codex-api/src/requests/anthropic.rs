@@ -0,0 +1,520 @@
+use crate::common::ResponsesApiRequest;
+use crate::requests::tools::FunctionTool;
+use crate::requests::tools::NamespacedTools;
+use crate::requests::tools::flatten_function_tools;
+use crate::requests::tools::flattened_call_name;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::openai_models::ReasoningEffort;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+/// `max_tokens` is required by the Messages API. This fits every current
+/// Claude model that supports extended thinking.
+const DEFAULT_MAX_TOKENS: u64 = 32_000;
+
+/// Anthropic Messages request body derived from a Responses API request.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct AnthropicRequest {
+    pub body: Value,
+    /// The Messages API has no tool namespaces.
+    pub namespaced_tools: NamespacedTools,
+}
+
+/// Builds a streaming Messages request.
+///
+/// Prompt-caching breakpoints are placed on the last tool definition, the
+/// system prompt, and the last message, so each turn re-reads the prefix the
+/// previous turn wrote.
+pub(crate) fn build_anthropic_request(request: &ResponsesApiRequest) -> AnthropicRequest {
+    let (function_tools, namespaced_tools) =
+        flatten_function_tools(request.tools.as_deref().unwrap_or_default());
+    let mut tools = function_tools
+        .iter()
+        .map(anthropic_tool)
+        .collect::<Vec<_>>();
+    if let Some(last) = tools.last_mut() {
+        add_cache_breakpoint(last);
+    }
+
+    let mut messages = anthropic_messages(&request.input);
+    if let Some(last) = messages
+        .last_mut()
+        .and_then(|message| message.get_mut("content"))
+        .and_then(Value::as_array_mut)
+        .and_then(|content| content.last_mut())
+    {
+        add_cache_breakpoint(last);
+    }
+
+    let mut body = Map::new();
+    body.insert("model".to_string(), Value::String(request.model.clone()));
+    let max_tokens = request.max_output_tokens.unwrap_or(DEFAULT_MAX_TOKENS);
+    body.insert("max_tokens".to_string(), json!(max_tokens));
+    if !request.instructions.is_empty() {
+        let mut system = json!({ "type": "text", "text": request.instructions });
+        add_cache_breakpoint(&mut system);
+        body.insert("system".to_string(), json!([system]));
+    }
+    body.insert("messages".to_string(), Value::Array(messages));
+    if !tools.is_empty() {
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert(
+            "tool_choice".to_string(),
+            anthropic_tool_choice(&request.tool_choice, request.parallel_tool_calls),
+        );
+    }
+    if let Some(budget_tokens) = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort.as_ref())
+        .and_then(thinking_budget)
+        // The thinking budget is part of `max_tokens`, so a smaller output
+        // limit turns thinking off.
+        .filter(|budget_tokens| *budget_tokens < max_tokens)
+    {
+        body.insert(
+            "thinking".to_string(),
+            json!({ "type": "enabled", "budget_tokens": budget_tokens }),
+        );
+    }
+    body.insert("stream".to_string(), Value::Bool(true));
+
+    AnthropicRequest {
+        body: Value::Object(body),
+        namespaced_tools,
+    }
+}
+
+/// Maps reasoning effort onto an extended-thinking budget. Efforts that ask
+/// for no reasoning turn thinking off.
+fn thinking_budget(effort: &ReasoningEffort) -> Option<u64> {
+    match effort {
+        ReasoningEffort::None | ReasoningEffort::Minimal => None,
+        ReasoningEffort::Low => Some(4_000),
+        ReasoningEffort::Medium | ReasoningEffort::Custom(_) => Some(10_000),
+        ReasoningEffort::High => Some(20_000),
+        ReasoningEffort::XHigh | ReasoningEffort::Max | ReasoningEffort::Ultra => Some(28_000),
+    }
+}
+
+fn add_cache_breakpoint(block: &mut Value) {
+    if let Some(block) = block.as_object_mut() {
+        block.insert("cache_control".to_string(), json!({ "type": "ephemeral" }));
+    }
+}
+
+fn anthropic_tool(tool: &FunctionTool<'_>) -> Value {
+    let mut definition = Map::new();
+    definition.insert("name".to_string(), Value::String(tool.name.clone()));
+    if let Some(description) = tool.field("description") {
+        definition.insert("description".to_string(), description.clone());
+    }
+    definition.insert(
+        "input_schema".to_string(),
+        tool.field("parameters")
+            .cloned()
+            .unwrap_or_else(|| json!({ "type": "object" })),
+    );
+    Value::Object(definition)
+}
+
+fn anthropic_tool_choice(tool_choice: &str, parallel_tool_calls: bool) -> Value {
+    let mut choice = match tool_choice {
+        "required" => json!({ "type": "any" }),
+        "none" => return json!({ "type": "none" }),
+        _ => json!({ "type": "auto" }),
+    };
+    if !parallel_tool_calls && let Some(choice) = choice.as_object_mut() {
+        choice.insert("disable_parallel_tool_use".to_string(), Value::Bool(true));
+    }
+    choice
+}
+
+fn anthropic_messages(input: &[ResponseItem]) -> Vec<Value> {
+    let mut messages = Vec::new();
+    for item in input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let role = match role.as_str() {
+                    "assistant" => "assistant",
+                    // Mid-conversation developer context has no system slot in
+                    // the Messages API, so it is sent as user content.
+                    _ => "user",
+                };
+                push_blocks(
+                    &mut messages,
+                    role,
+                    content.iter().filter_map(content_block).collect(),
+                );
+            }
+            ResponseItem::Reasoning {
+                content,
+                encrypted_content,
+                ..
+            } => {
+                if let Some(block) =
+                    thinking_block(content.as_deref(), encrypted_content.as_deref())
+                {
+                    push_blocks(&mut messages, "assistant", vec![block]);
+                }
+            }
+            ResponseItem::FunctionCall {
+                name,
+                namespace,
+                arguments,
+                call_id,
+                ..
+            } => {
+                let input = serde_json::from_str::<Value>(arguments)
+                    .ok()
+                    .filter(Value::is_object)
+                    .unwrap_or_else(|| json!({}));
+                push_blocks(
+                    &mut messages,
+                    "assistant",
+                    vec![json!({
+                        "type": "tool_use",
+                        "id": call_id,
+                        "name": flattened_call_name(namespace.as_deref(), name),
+                        "input": input,
+                    })],
+                );
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => push_blocks(
+                &mut messages,
+                "assistant",
+                vec![json!({
+                    "type": "tool_use",
+                    "id": call_id,
+                    "name": name,
+                    "input": { "input": input },
+                })],
+            ),
+            ResponseItem::FunctionCallOutput {
+                call_id, output, ..
+            }
+            | ResponseItem::CustomToolCallOutput {
+                call_id, output, ..
+            } => push_blocks(
+                &mut messages,
+                "user",
+                vec![json!({
+                    "type": "tool_result",
+                    "tool_use_id": call_id,
+                    "content": tool_result_content(output),
+                })],
+            ),
+            // The remaining items have no Messages API representation.
+            _ => {}
+        }
+    }
+    messages
+}
+
+/// Appends content blocks, merging into the previous message when it has the
+/// same role; the Messages API requires roles to alternate.
+fn push_blocks(messages: &mut Vec<Value>, role: &str, blocks: Vec<Value>) {
+    if blocks.is_empty() {
+        return;
+    }
+    if let Some(last) = messages.last_mut()
+        && last.get("role").and_then(Value::as_str) == Some(role)
+        && let Some(content) = last.get_mut("content").and_then(Value::as_array_mut)
+    {
+        content.extend(blocks);
+        return;
+    }
+    messages.push(json!({ "role": role, "content": blocks }));
+}
+
+fn content_block(item: &ContentItem) -> Option<Value> {
+    match item {
+        ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+            (!text.is_empty()).then(|| json!({ "type": "text", "text": text }))
+        }
+        ContentItem::InputImage { image_url, .. } => Some(image_block(image_url)),
+        ContentItem::InputAudio { .. } => None,
+    }
+}
+
+fn image_block(image_url: &str) -> Value {
+    let source = match image_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+    {
+        Some((media_type, data)) => json!({
+            "type": "base64",
+            "media_type": media_type,
+            "data": data,
+        }),
+        None => json!({ "type": "url", "url": image_url }),
+    };
+    json!({ "type": "image", "source": source })
+}
+
+/// Thinking blocks keep their signature in `encrypted_content`; redacted
+/// thinking has no readable content, only the encrypted payload.
+fn thinking_block(
+    content: Option<&[ReasoningItemContent]>,
+    encrypted_content: Option<&str>,
+) -> Option<Value> {
+    let encrypted_content = encrypted_content?;
+    let Some(content) = content else {
+        return Some(json!({ "type": "redacted_thinking", "data": encrypted_content }));
+    };
+    let thinking = content
+        .iter()
+        .map(|content| match content {
+            ReasoningItemContent::ReasoningText { text } | ReasoningItemContent::Text { text } => {
+                text.as_str()
+            }
+        })
+        .collect::<String>();
+    Some(json!({
+        "type": "thinking",
+        "thinking": thinking,
+        "signature": encrypted_content,
+    }))
+}
+
+fn tool_result_content(output: &FunctionCallOutputPayload) -> Value {
+    if let Some(text) = output.text_content() {
+        return Value::String(text.to_string());
+    }
+    Value::Array(
+        output
+            .content_items()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|item| match item {
+                FunctionCallOutputContentItem::InputText { text } => {
+                    Some(json!({ "type": "text", "text": text }))
+                }
+                FunctionCallOutputContentItem::InputImage { image_url, .. } => {
+                    Some(image_block(image_url))
+                }
+                FunctionCallOutputContentItem::InputAudio { .. }
+                | FunctionCallOutputContentItem::EncryptedContent { .. } => None,
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Reasoning;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn request(input: Vec<ResponseItem>, tools: Vec<Value>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "claude-sonnet-4-5".to_string(),
+            instructions: "Be helpful.".to_string(),
+            input,
+            tools: Some(tools),
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: true,
+            reasoning: Some(Reasoning {
+                effort: Some(ReasoningEffort::Low),
+                summary: None,
+                context: None,
+            }),
+            store: false,
+            stream: true,
+            stream_options: None,
+            include: Vec::new(),
+            service_tier: None,
+            prompt_cache_key: None,
+            text: None,
+            max_output_tokens: None,
+            client_metadata: None,
+        }
+    }
+
+    fn message(role: &str, content: ContentItem) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: role.to_string(),
+            content: vec![content],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        }
+    }
+
+    #[test]
+    fn maps_tool_round_trip_thinking_and_cache_breakpoints() {
+        let input = vec![
+            message(
+                "developer",
+                ContentItem::InputText {
+                    text: "<permissions>".to_string(),
+                },
+            ),
+            message(
+                "user",
+                ContentItem::InputText {
+                    text: "search the docs".to_string(),
+                },
+            ),
+            ResponseItem::Reasoning {
+                id: None,
+                summary: Vec::new(),
+                content: Some(vec![ReasoningItemContent::ReasoningText {
+                    text: "Use the docs tool.".to_string(),
+                }]),
+                encrypted_content: Some("sig-1".to_string()),
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "search".to_string(),
+                namespace: Some("mcp__docs__".to_string()),
+                arguments: r#"{"q":"rust"}"#.to_string(),
+                call_id: "toolu_1".to_string(),
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::FunctionCallOutput {
+                id: None,
+                call_id: "toolu_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("found".to_string()),
+                internal_chat_message_metadata_passthrough: None,
+            },
+        ];
+        let tools = vec![
+            json!({
+                "type": "function",
+                "name": "shell",
+                "description": "Run a command",
+                "strict": false,
+                "parameters": {"type": "object"},
+            }),
+            json!({
+                "type": "namespace",
+                "name": "mcp__docs__",
+                "description": "Docs",
+                "tools": [{
+                    "type": "function",
+                    "name": "search",
+                    "description": "Search docs",
+                    "strict": false,
+                    "parameters": {"type": "object"},
+                }],
+            }),
+        ];
+
+        let request = build_anthropic_request(&request(input, tools));
+
+        assert_eq!(
+            request.body,
+            json!({
+                "model": "claude-sonnet-4-5",
+                "max_tokens": DEFAULT_MAX_TOKENS,
+                "system": [{
+                    "type": "text",
+                    "text": "Be helpful.",
+                    "cache_control": {"type": "ephemeral"},
+                }],
+                "messages": [
+                    {
+                        "role": "user",
+                        "content": [
+                            {"type": "text", "text": "<permissions>"},
+                            {"type": "text", "text": "search the docs"},
+                        ],
+                    },
+                    {
+                        "role": "assistant",
+                        "content": [
+                            {"type": "thinking", "thinking": "Use the docs tool.", "signature": "sig-1"},
+                            {"type": "tool_use", "id": "toolu_1", "name": "mcp__docs__search", "input": {"q": "rust"}},
+                        ],
+                    },
+                    {
+                        "role": "user",
+                        "content": [{
+                            "type": "tool_result",
+                            "tool_use_id": "toolu_1",
+                            "content": "found",
+                            "cache_control": {"type": "ephemeral"},
+                        }],
+                    },
+                ],
+                "tools": [
+                    {
+                        "name": "shell",
+                        "description": "Run a command",
+                        "input_schema": {"type": "object"},
+                    },
+                    {
+                        "name": "mcp__docs__search",
+                        "description": "Search docs",
+                        "input_schema": {"type": "object"},
+                        "cache_control": {"type": "ephemeral"},
+                    },
+                ],
+                "tool_choice": {"type": "auto"},
+                "thinking": {"type": "enabled", "budget_tokens": 4_000},
+                "stream": true,
+            })
+        );
+        assert_eq!(
+            request.namespaced_tools,
+            HashMap::from([(
+                "mcp__docs__search".to_string(),
+                ("mcp__docs__".to_string(), "search".to_string()),
+            )])
+        );
+    }
+
+    #[test]
+    fn sends_data_url_images_as_base64_sources() {
+        let input = vec![message(
+            "user",
+            ContentItem::InputImage {
+                image_url: "data:image/png;base64,AAAA".to_string(),
+                detail: None,
+            },
+        )];
+
+        let request = build_anthropic_request(&request(input, Vec::new()));
+
+        assert_eq!(
+            request.body["messages"][0]["content"][0],
+            json!({
+                "type": "image",
+                "source": {"type": "base64", "media_type": "image/png", "data": "AAAA"},
+                "cache_control": {"type": "ephemeral"},
+            })
+        );
+    }
+
+    #[test]
+    fn output_limit_below_thinking_budget_turns_thinking_off() {
+        let mut api_request = request(
+            vec![message(
+                "user",
+                ContentItem::InputText {
+                    text: "hi".to_string(),
+                },
+            )],
+            Vec::new(),
+        );
+        api_request.max_output_tokens = Some(1_000);
+
+        let request = build_anthropic_request(&api_request);
+
+        assert_eq!(request.body["max_tokens"], json!(1_000));
+        assert_eq!(request.body.get("thinking"), None);
+    }
+}
@@ -0,0 +1,419 @@
+use crate::common::ResponsesApiRequest;
+use crate::requests::tools::FunctionTool;
+use crate::requests::tools::NamespacedTools;
+use crate::requests::tools::flatten_function_tools;
+use crate::requests::tools::flattened_call_name;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ResponseItem;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+/// Chat Completions request body derived from a Responses API request.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ChatCompletionsRequest {
+    pub body: Value,
+    /// Chat Completions has no tool namespaces.
+    pub namespaced_tools: NamespacedTools,
+}
+
+pub(crate) fn build_chat_request(request: &ResponsesApiRequest) -> ChatCompletionsRequest {
+    let (function_tools, namespaced_tools) =
+        flatten_function_tools(request.tools.as_deref().unwrap_or_default());
+    let tools = function_tools
+        .iter()
+        .map(chat_function_tool)
+        .collect::<Vec<_>>();
+
+    let mut body = Map::new();
+    body.insert("model".to_string(), Value::String(request.model.clone()));
+    body.insert(
+        "messages".to_string(),
+        Value::Array(chat_messages(&request.instructions, &request.input)),
+    );
+    body.insert("stream".to_string(), Value::Bool(true));
+    body.insert(
+        "stream_options".to_string(),
+        json!({ "include_usage": true }),
+    );
+    if !tools.is_empty() {
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert(
+            "tool_choice".to_string(),
+            Value::String(request.tool_choice.clone()),
+        );
+        body.insert(
+            "parallel_tool_calls".to_string(),
+            Value::Bool(request.parallel_tool_calls),
+        );
+    }
+    if let Some(max_output_tokens) = request.max_output_tokens {
+        body.insert("max_tokens".to_string(), json!(max_output_tokens));
+    }
+    if let Some(effort) = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort.as_ref())
+    {
+        body.insert(
+            "reasoning_effort".to_string(),
+            Value::String(effort.as_str().to_string()),
+        );
+    }
+    if let Some(format) = request.text.as_ref().and_then(|text| text.format.as_ref()) {
+        body.insert(
+            "response_format".to_string(),
+            json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": format.name,
+                    "schema": format.schema,
+                    "strict": format.strict,
+                },
+            }),
+        );
+    }
+
+    ChatCompletionsRequest {
+        body: Value::Object(body),
+        namespaced_tools,
+    }
+}
+
+fn chat_function_tool(tool: &FunctionTool<'_>) -> Value {
+    let mut function = Map::new();
+    function.insert("name".to_string(), Value::String(tool.name.clone()));
+    for key in ["description", "parameters", "strict"] {
+        if let Some(value) = tool.field(key) {
+            function.insert(key.to_string(), value.clone());
+        }
+    }
+    json!({ "type": "function", "function": function })
+}
+
+fn chat_messages(instructions: &str, input: &[ResponseItem]) -> Vec<Value> {
+    let mut messages = Vec::new();
+    if !instructions.is_empty() {
+        messages.push(json!({ "role": "system", "content": instructions }));
+    }
+    for item in input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let role = match role.as_str() {
+                    // Most Chat Completions servers only know `system`.
+                    "developer" => "system",
+                    role => role,
+                };
+                messages.push(json!({ "role": role, "content": chat_content(content) }));
+            }
+            ResponseItem::FunctionCall {
+                name,
+                namespace,
+                arguments,
+                call_id,
+                ..
+            } => {
+                let name = flattened_call_name(namespace.as_deref(), name);
+                push_tool_call(&mut messages, call_id, &name, arguments);
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => push_tool_call(&mut messages, call_id, name, input),
+            ResponseItem::FunctionCallOutput {
+                call_id, output, ..
+            }
+            | ResponseItem::CustomToolCallOutput {
+                call_id, output, ..
+            } => {
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": tool_output_text(output),
+                }));
+            }
+            // Reasoning is not replayed, and the remaining items have no
+            // Chat Completions representation.
+            _ => {}
+        }
+    }
+    messages
+}
+
+/// Text-only content is sent as a plain string, which every Chat Completions
+/// server accepts; content parts are only used when images are attached.
+fn chat_content(content: &[ContentItem]) -> Value {
+    let has_images = content
+        .iter()
+        .any(|item| matches!(item, ContentItem::InputImage { .. }));
+    if !has_images {
+        let text = content
+            .iter()
+            .filter_map(|item| match item {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                    Some(text.as_str())
+                }
+                ContentItem::InputImage { .. } | ContentItem::InputAudio { .. } => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return Value::String(text);
+    }
+
+    Value::Array(
+        content
+            .iter()
+            .filter_map(|item| match item {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                    Some(json!({ "type": "text", "text": text }))
+                }
+                ContentItem::InputImage { image_url, .. } => Some(json!({
+                    "type": "image_url",
+                    "image_url": { "url": image_url },
+                })),
+                ContentItem::InputAudio { .. } => None,
+            })
+            .collect(),
+    )
+}
+
+/// Appends a tool call to the preceding assistant message, so an assistant
+/// turn that mixes text and parallel calls stays a single message.
+fn push_tool_call(messages: &mut Vec<Value>, call_id: &str, name: &str, arguments: &str) {
+    let tool_call = json!({
+        "id": call_id,
+        "type": "function",
+        "function": { "name": name, "arguments": arguments },
+    });
+    if let Some(last) = messages.last_mut()
+        && last.get("role").and_then(Value::as_str) == Some("assistant")
+        && let Some(last) = last.as_object_mut()
+    {
+        match last.get_mut("tool_calls").and_then(Value::as_array_mut) {
+            Some(tool_calls) => tool_calls.push(tool_call),
+            None => {
+                last.insert("tool_calls".to_string(), json!([tool_call]));
+            }
+        }
+        return;
+    }
+    messages.push(json!({
+        "role": "assistant",
+        "content": null,
+        "tool_calls": [tool_call],
+    }));
+}
+
+fn tool_output_text(output: &FunctionCallOutputPayload) -> String {
+    if let Some(text) = output.text_content() {
+        return text.to_string();
+    }
+    output
+        .content_items()
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|item| match item {
+            FunctionCallOutputContentItem::InputText { text } => Some(text.as_str()),
+            FunctionCallOutputContentItem::InputImage { .. }
+            | FunctionCallOutputContentItem::InputAudio { .. }
+            | FunctionCallOutputContentItem::EncryptedContent { .. } => None,
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Reasoning;
+    use codex_protocol::openai_models::ReasoningEffort;
+    use pretty_assertions::assert_eq;
+    use std::collections::HashMap;
+
+    fn request(input: Vec<ResponseItem>, tools: Vec<Value>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "qwen3".to_string(),
+            instructions: "Be helpful.".to_string(),
+            input,
+            tools: Some(tools),
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: true,
+            reasoning: Some(Reasoning {
+                effort: Some(ReasoningEffort::High),
+                summary: None,
+                context: None,
+            }),
+            store: false,
+            stream: true,
+            stream_options: None,
+            include: Vec::new(),
+            service_tier: None,
+            prompt_cache_key: None,
+            text: None,
+            max_output_tokens: None,
+            client_metadata: None,
+        }
+    }
+
+    #[test]
+    fn converts_history_and_tools() {
+        let input = vec![
+            ResponseItem::Message {
+                id: None,
+                role: "developer".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "<permissions>".to_string(),
+                }],
+                phase: None,
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "list files".to_string(),
+                }],
+                phase: None,
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "Listing.".to_string(),
+                }],
+                phase: None,
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                namespace: None,
+                arguments: r#"{"command":["ls"]}"#.to_string(),
+                call_id: "call_1".to_string(),
+                internal_chat_message_metadata_passthrough: None,
+            },
+            ResponseItem::FunctionCallOutput {
+                id: None,
+                call_id: "call_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("README.md".to_string()),
+                internal_chat_message_metadata_passthrough: None,
+            },
+        ];
+        let tools = vec![
+            json!({
+                "type": "function",
+                "name": "shell",
+                "description": "Run a command",
+                "strict": false,
+                "parameters": {"type": "object"},
+            }),
+            json!({
+                "type": "namespace",
+                "name": "mcp__docs__",
+                "description": "Docs",
+                "tools": [{
+                    "type": "function",
+                    "name": "search",
+                    "description": "Search docs",
+                    "strict": false,
+                    "parameters": {"type": "object"},
+                }],
+            }),
+            json!({"type": "web_search"}),
+        ];
+
+        let request = build_chat_request(&request(input, tools));
+
+        assert_eq!(
+            request.body,
+            json!({
+                "model": "qwen3",
+                "messages": [
+                    {"role": "system", "content": "Be helpful."},
+                    {"role": "system", "content": "<permissions>"},
+                    {"role": "user", "content": "list files"},
+                    {
+                        "role": "assistant",
+                        "content": "Listing.",
+                        "tool_calls": [{
+                            "id": "call_1",
+                            "type": "function",
+                            "function": {"name": "shell", "arguments": "{\"command\":[\"ls\"]}"},
+                        }],
+                    },
+                    {"role": "tool", "tool_call_id": "call_1", "content": "README.md"},
+                ],
+                "stream": true,
+                "stream_options": {"include_usage": true},
+                "tools": [
+                    {
+                        "type": "function",
+                        "function": {
+                            "name": "shell",
+                            "description": "Run a command",
+                            "parameters": {"type": "object"},
+                            "strict": false,
+                        },
+                    },
+                    {
+                        "type": "function",
+                        "function": {
+                            "name": "mcp__docs__search",
+                            "description": "Search docs",
+                            "parameters": {"type": "object"},
+                            "strict": false,
+                        },
+                    },
+                ],
+                "tool_choice": "auto",
+                "parallel_tool_calls": true,
+                "reasoning_effort": "high",
+            })
+        );
+        assert_eq!(
+            request.namespaced_tools,
+            HashMap::from([(
+                "mcp__docs__search".to_string(),
+                ("mcp__docs__".to_string(), "search".to_string()),
+            )])
+        );
+    }
+
+    #[test]
+    fn uses_content_parts_only_for_images() {
+        let input = vec![ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![
+                ContentItem::InputText {
+                    text: "what is this?".to_string(),
+                },
+                ContentItem::InputImage {
+                    image_url: "data:image/png;base64,AAAA".to_string(),
+                    detail: None,
+                },
+            ],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        }];
+
+        let request = build_chat_request(&request(input, Vec::new()));
+
+        assert_eq!(
+            request.body["messages"][1],
+            json!({
+                "role": "user",
+                "content": [
+                    {"type": "text", "text": "what is this?"},
+                    {"type": "image_url", "image_url": {"url": "data:image/png;base64,AAAA"}},
+                ],
+            })
+        );
+        assert_eq!(request.body.get("tools"), None);
+    }
+}
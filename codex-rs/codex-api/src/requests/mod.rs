@@ -1,4 +1,7 @@
+pub(crate) mod anthropic;
+pub(crate) mod chat;
 pub(crate) mod headers;
 pub(crate) mod responses;
+pub(crate) mod tools;
 
 pub use responses::Compression;
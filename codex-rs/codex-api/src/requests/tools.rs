@@ -0,0 +1,95 @@
+use codex_protocol::models::ResponseItem;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Maps a flattened `<namespace><name>` tool name back to `(namespace, name)`.
+///
+/// APIs without tool namespaces receive namespaced tools under the flattened
+/// name; streamed calls use this to restore the namespace.
+pub(crate) type NamespacedTools = HashMap<String, (String, String)>;
+
+/// A Responses function tool, possibly lifted out of a namespace.
+pub(crate) struct FunctionTool<'a> {
+    pub name: String,
+    pub definition: &'a Value,
+}
+
+impl FunctionTool<'_> {
+    pub(crate) fn field(&self, key: &str) -> Option<&Value> {
+        self.definition.get(key)
+    }
+}
+
+/// Collects the function tools from Responses tool definitions.
+///
+/// Hosted and freeform tools have no equivalent outside the Responses API and
+/// are dropped.
+pub(crate) fn flatten_function_tools(tools: &[Value]) -> (Vec<FunctionTool<'_>>, NamespacedTools) {
+    let mut function_tools = Vec::new();
+    let mut namespaced_tools = HashMap::new();
+    for tool in tools {
+        match tool.get("type").and_then(Value::as_str) {
+            Some("function") => {
+                if let Some(name) = tool.get("name").and_then(Value::as_str) {
+                    function_tools.push(FunctionTool {
+                        name: name.to_string(),
+                        definition: tool,
+                    });
+                }
+            }
+            Some("namespace") => {
+                let Some(namespace) = tool.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                for tool in tool
+                    .get("tools")
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                {
+                    let Some(name) = tool.get("name").and_then(Value::as_str) else {
+                        continue;
+                    };
+                    let flattened = format!("{namespace}{name}");
+                    namespaced_tools
+                        .insert(flattened.clone(), (namespace.to_string(), name.to_string()));
+                    function_tools.push(FunctionTool {
+                        name: flattened,
+                        definition: tool,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+    (function_tools, namespaced_tools)
+}
+
+/// Name a function call from history is sent under.
+pub(crate) fn flattened_call_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{namespace}{name}"),
+        None => name.to_string(),
+    }
+}
+
+/// Builds a function call item, restoring the namespace of flattened names.
+pub(crate) fn function_call_item(
+    namespaced_tools: &NamespacedTools,
+    call_id: &str,
+    name: &str,
+    arguments: String,
+) -> ResponseItem {
+    let (namespace, name) = match namespaced_tools.get(name) {
+        Some((namespace, name)) => (Some(namespace.clone()), name.clone()),
+        None => (None, name.to_string()),
+    };
+    ResponseItem::FunctionCall {
+        id: None,
+        name,
+        namespace,
+        arguments,
+        call_id: call_id.to_string(),
+        internal_chat_message_metadata_passthrough: None,
+    }
+}
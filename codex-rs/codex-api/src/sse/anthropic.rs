@@ -0,0 +1,607 @@
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::requests::tools::NamespacedTools;
+use crate::requests::tools::function_call_item;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+
+const REQUEST_ID_HEADER: &str = "request-id";
+
+/// Streams an Anthropic Messages response as the same [`ResponseEvent`]s the
+/// Responses API produces.
+pub(crate) fn spawn_anthropic_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    namespaced_tools: NamespacedTools,
+) -> ResponseStream {
+    let upstream_request_id = stream_response
+        .headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(process_anthropic_sse(
+        stream_response.bytes,
+        tx_event,
+        idle_timeout,
+        telemetry,
+        namespaced_tools,
+    ));
+
+    ResponseStream {
+        rx_event,
+        upstream_request_id,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicStreamEvent {
+    MessageStart {
+        message: AnthropicMessageStart,
+    },
+    ContentBlockStart {
+        content_block: AnthropicContentBlock,
+    },
+    ContentBlockDelta {
+        delta: AnthropicBlockDelta,
+    },
+    ContentBlockStop,
+    MessageDelta {
+        #[serde(default)]
+        usage: Option<AnthropicUsage>,
+    },
+    MessageStop,
+    Ping,
+    Error {
+        error: AnthropicError,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicMessageStart {
+    id: String,
+    #[serde(default)]
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicContentBlock {
+    Text,
+    Thinking,
+    RedactedThinking {
+        data: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicBlockDelta {
+    TextDelta {
+        text: String,
+    },
+    ThinkingDelta {
+        thinking: String,
+    },
+    SignatureDelta {
+        signature: String,
+    },
+    InputJsonDelta {
+        partial_json: String,
+    },
+    #[serde(other)]
+    Unknown,
+}
+
+/// `message_start` carries the input counts and `message_delta` the running
+/// output count; later values replace earlier ones.
+#[derive(Debug, Default, Deserialize)]
+struct AnthropicUsage {
+    #[serde(default)]
+    input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_creation_input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<i64>,
+    #[serde(default)]
+    output_tokens: Option<i64>,
+}
+
+impl AnthropicUsage {
+    fn merge(&mut self, update: AnthropicUsage) {
+        self.input_tokens = update.input_tokens.or(self.input_tokens);
+        self.cache_creation_input_tokens = update
+            .cache_creation_input_tokens
+            .or(self.cache_creation_input_tokens);
+        self.cache_read_input_tokens = update
+            .cache_read_input_tokens
+            .or(self.cache_read_input_tokens);
+        self.output_tokens = update.output_tokens.or(self.output_tokens);
+    }
+}
+
+impl From<AnthropicUsage> for TokenUsage {
+    fn from(val: AnthropicUsage) -> Self {
+        let cache_write = val.cache_creation_input_tokens.unwrap_or(0);
+        let cache_read = val.cache_read_input_tokens.unwrap_or(0);
+        // Anthropic reports cached tokens separately from `input_tokens`.
+        let input_tokens = val.input_tokens.unwrap_or(0) + cache_write + cache_read;
+        let output_tokens = val.output_tokens.unwrap_or(0);
+        TokenUsage {
+            input_tokens,
+            cached_input_tokens: cache_read,
+            cache_write_input_tokens: cache_write,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: input_tokens + output_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicError {
+    r#type: String,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl AnthropicError {
+    fn into_api_error(self) -> ApiError {
+        let message = self
+            .message
+            .unwrap_or_else(|| format!("anthropic stream error: {}", self.r#type));
+        match self.r#type.as_str() {
+            "overloaded_error" => ApiError::ServerOverloaded,
+            "rate_limit_error" => ApiError::RateLimit(message),
+            "invalid_request_error" if message.contains("prompt is too long") => {
+                ApiError::ContextWindowExceeded
+            }
+            "invalid_request_error" => ApiError::InvalidRequest { message },
+            "api_error" => ApiError::Retryable {
+                message,
+                delay: None,
+            },
+            _ => ApiError::Stream(message),
+        }
+    }
+}
+
+/// The content block currently streaming. The Messages API streams blocks one
+/// at a time, each between `content_block_start` and `content_block_stop`.
+enum OpenBlock {
+    Text(String),
+    Thinking {
+        text: String,
+        signature: String,
+    },
+    RedactedThinking(String),
+    ToolUse {
+        call_id: String,
+        name: String,
+        input: String,
+    },
+    Ignored,
+}
+
+struct AnthropicStreamState {
+    namespaced_tools: NamespacedTools,
+    response_id: String,
+    usage: Option<AnthropicUsage>,
+    open_block: Option<OpenBlock>,
+}
+
+impl AnthropicStreamState {
+    fn new(namespaced_tools: NamespacedTools) -> Self {
+        Self {
+            namespaced_tools,
+            response_id: String::new(),
+            usage: None,
+            open_block: None,
+        }
+    }
+
+    fn handle_event(
+        &mut self,
+        event: AnthropicStreamEvent,
+    ) -> Result<Vec<ResponseEvent>, ApiError> {
+        let mut events = Vec::new();
+        match event {
+            AnthropicStreamEvent::MessageStart { message } => {
+                self.response_id = message.id;
+                if let Some(usage) = message.usage {
+                    self.merge_usage(usage);
+                }
+                events.push(ResponseEvent::Created);
+            }
+            AnthropicStreamEvent::ContentBlockStart { content_block } => {
+                let block = match content_block {
+                    AnthropicContentBlock::Text => OpenBlock::Text(String::new()),
+                    AnthropicContentBlock::Thinking => OpenBlock::Thinking {
+                        text: String::new(),
+                        signature: String::new(),
+                    },
+                    AnthropicContentBlock::RedactedThinking { data } => {
+                        OpenBlock::RedactedThinking(data)
+                    }
+                    AnthropicContentBlock::ToolUse { id, name } => OpenBlock::ToolUse {
+                        call_id: id,
+                        name,
+                        input: String::new(),
+                    },
+                    AnthropicContentBlock::Unknown => OpenBlock::Ignored,
+                };
+                if let Some(item) = self.block_item(&block) {
+                    events.push(ResponseEvent::OutputItemAdded(item));
+                }
+                self.open_block = Some(block);
+            }
+            AnthropicStreamEvent::ContentBlockDelta { delta } => {
+                match (self.open_block.as_mut(), delta) {
+                    (
+                        Some(OpenBlock::Text(text)),
+                        AnthropicBlockDelta::TextDelta { text: delta },
+                    ) => {
+                        text.push_str(&delta);
+                        events.push(ResponseEvent::OutputTextDelta(delta));
+                    }
+                    (
+                        Some(OpenBlock::Thinking { text, .. }),
+                        AnthropicBlockDelta::ThinkingDelta { thinking },
+                    ) => {
+                        text.push_str(&thinking);
+                        events.push(ResponseEvent::ReasoningContentDelta {
+                            delta: thinking,
+                            content_index: 0,
+                        });
+                    }
+                    (
+                        Some(OpenBlock::Thinking { signature, .. }),
+                        AnthropicBlockDelta::SignatureDelta { signature: delta },
+                    ) => signature.push_str(&delta),
+                    (
+                        Some(OpenBlock::ToolUse { call_id, input, .. }),
+                        AnthropicBlockDelta::InputJsonDelta { partial_json },
+                    ) => {
+                        input.push_str(&partial_json);
+                        events.push(ResponseEvent::ToolCallInputDelta {
+                            item_id: call_id.clone(),
+                            call_id: Some(call_id.clone()),
+                            delta: partial_json,
+                        });
+                    }
+                    (_, delta) => debug!("ignoring unexpected Anthropic delta: {delta:?}"),
+                }
+            }
+            AnthropicStreamEvent::ContentBlockStop => {
+                if let Some(block) = self.open_block.take()
+                    && let Some(item) = self.block_item(&block)
+                {
+                    events.push(ResponseEvent::OutputItemDone(item));
+                }
+            }
+            AnthropicStreamEvent::MessageDelta { usage } => {
+                if let Some(usage) = usage {
+                    self.merge_usage(usage);
+                }
+            }
+            AnthropicStreamEvent::MessageStop => {
+                events.push(ResponseEvent::Completed {
+                    response_id: std::mem::take(&mut self.response_id),
+                    token_usage: self.usage.take().map(TokenUsage::from),
+                    end_turn: None,
+                });
+            }
+            AnthropicStreamEvent::Error { error } => return Err(error.into_api_error()),
+            AnthropicStreamEvent::Ping | AnthropicStreamEvent::Unknown => {}
+        }
+        Ok(events)
+    }
+
+    fn merge_usage(&mut self, usage: AnthropicUsage) {
+        self.usage.get_or_insert_with(Default::default).merge(usage);
+    }
+
+    fn block_item(&self, block: &OpenBlock) -> Option<ResponseItem> {
+        match block {
+            OpenBlock::Text(text) => Some(ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText { text: text.clone() }],
+                phase: None,
+                internal_chat_message_metadata_passthrough: None,
+            }),
+            OpenBlock::Thinking { text, signature } => Some(ResponseItem::Reasoning {
+                id: None,
+                summary: Vec::new(),
+                content: Some(vec![ReasoningItemContent::ReasoningText {
+                    text: text.clone(),
+                }]),
+                // The signature must be sent back with the thinking block.
+                encrypted_content: (!signature.is_empty()).then(|| signature.clone()),
+                internal_chat_message_metadata_passthrough: None,
+            }),
+            OpenBlock::RedactedThinking(data) => Some(ResponseItem::Reasoning {
+                id: None,
+                summary: Vec::new(),
+                content: None,
+                encrypted_content: Some(data.clone()),
+                internal_chat_message_metadata_passthrough: None,
+            }),
+            OpenBlock::ToolUse {
+                call_id,
+                name,
+                input,
+            } => Some(function_call_item(
+                &self.namespaced_tools,
+                call_id,
+                name,
+                // Tools without parameters stream no input at all.
+                if input.is_empty() {
+                    "{}".to_string()
+                } else {
+                    input.clone()
+                },
+            )),
+            OpenBlock::Ignored => None,
+        }
+    }
+}
+
+async fn process_anthropic_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    namespaced_tools: NamespacedTools,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = AnthropicStreamState::new(namespaced_tools);
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            Ok(None) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream(
+                        "stream closed before message_stop".into(),
+                    )))
+                    .await;
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("SSE event: {}", &sse.data);
+
+        let event: AnthropicStreamEvent = match serde_json::from_str(&sse.data) {
+            Ok(event) => event,
+            Err(e) => {
+                debug!(
+                    "Failed to parse Anthropic SSE event: {e}, data: {}",
+                    &sse.data
+                );
+                continue;
+            }
+        };
+        let events = match state.handle_event(event) {
+            Ok(events) => events,
+            Err(error) => {
+                let _ = tx_event.send(Err(error)).await;
+                return;
+            }
+        };
+        for event in events {
+            let is_completed = matches!(event, ResponseEvent::Completed { .. });
+            if tx_event.send(Ok(event)).await.is_err() || is_completed {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::Value;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_anthropic_sse(events: Vec<Value>) -> Vec<Result<ResponseEvent, ApiError>> {
+        let mut body = String::new();
+        for event in events {
+            let kind = event
+                .get("type")
+                .and_then(Value::as_str)
+                .unwrap_or_else(|| panic!("fixture event missing type: {event}"))
+                .to_string();
+            body.push_str(&format!("event: {kind}\ndata: {event}\n\n"));
+        }
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(32);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        tokio::spawn(process_anthropic_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            /*telemetry*/ None,
+            HashMap::from([(
+                "mcp__docs__search".to_string(),
+                ("mcp__docs__".to_string(), "search".to_string()),
+            )]),
+        ));
+
+        let mut out = Vec::new();
+        while let Some(ev) = rx.recv().await {
+            out.push(ev);
+        }
+        out
+    }
+
+    #[tokio::test]
+    async fn maps_thinking_text_tool_use_and_usage() {
+        let events = run_anthropic_sse(vec![
+            json!({"type": "message_start", "message": {
+                "id": "msg_1",
+                "usage": {"input_tokens": 10, "cache_creation_input_tokens": 20, "cache_read_input_tokens": 30, "output_tokens": 1},
+            }}),
+            json!({"type": "content_block_start", "index": 0, "content_block": {"type": "thinking", "thinking": ""}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "thinking_delta", "thinking": "Search."}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "signature_delta", "signature": "sig-1"}}),
+            json!({"type": "content_block_stop", "index": 0}),
+            json!({"type": "content_block_start", "index": 1, "content_block": {"type": "text", "text": ""}}),
+            json!({"type": "ping"}),
+            json!({"type": "content_block_delta", "index": 1, "delta": {"type": "text_delta", "text": "Looking."}}),
+            json!({"type": "content_block_stop", "index": 1}),
+            json!({"type": "content_block_start", "index": 2, "content_block": {"type": "tool_use", "id": "toolu_1", "name": "mcp__docs__search", "input": {}}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "{\"q\":"}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "\"rust\"}"}}),
+            json!({"type": "content_block_stop", "index": 2}),
+            json!({"type": "message_delta", "delta": {"stop_reason": "tool_use"}, "usage": {"output_tokens": 42}}),
+            json!({"type": "message_stop"}),
+        ])
+        .await;
+        let events = events
+            .into_iter()
+            .map(|event| event.map_err(|err| err.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|err| panic!("unexpected stream error: {err}"));
+
+        assert_eq!(events.len(), 12);
+        assert_matches!(&events[0], ResponseEvent::Created);
+        assert_matches!(
+            &events[1],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { .. })
+        );
+        assert_matches!(&events[2], ResponseEvent::ReasoningContentDelta { delta, .. } if delta == "Search.");
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(ResponseItem::Reasoning { encrypted_content: Some(signature), .. })
+                if signature == "sig-1"
+        );
+        assert_matches!(
+            &events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { .. })
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Looking.");
+        assert_matches!(
+            &events[6],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { .. })
+        );
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemAdded(ResponseItem::FunctionCall { .. })
+        );
+        assert_matches!(
+            &events[8],
+            ResponseEvent::ToolCallInputDelta { call_id: Some(call_id), delta, .. }
+                if call_id == "toolu_1" && delta == "{\"q\":"
+        );
+        match &events[10] {
+            ResponseEvent::OutputItemDone(item) => assert_eq!(
+                item,
+                &ResponseItem::FunctionCall {
+                    id: None,
+                    name: "search".to_string(),
+                    namespace: Some("mcp__docs__".to_string()),
+                    arguments: "{\"q\":\"rust\"}".to_string(),
+                    call_id: "toolu_1".to_string(),
+                    internal_chat_message_metadata_passthrough: None,
+                }
+            ),
+            other => panic!("unexpected event: {other:?}"),
+        }
+        match &events[11] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage,
+                ..
+            } => {
+                assert_eq!(response_id, "msg_1");
+                assert_eq!(
+                    token_usage,
+                    &Some(TokenUsage {
+                        input_tokens: 60,
+                        cached_input_tokens: 30,
+                        cache_write_input_tokens: 20,
+                        output_tokens: 42,
+                        reasoning_output_tokens: 0,
+                        total_tokens: 102,
+                    })
+                );
+            }
+            other => panic!("unexpected last event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn maps_overloaded_error() {
+        let events = run_anthropic_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_2"}}),
+            json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}),
+        ])
+        .await;
+
+        assert_matches!(
+            events.as_slice(),
+            [Ok(ResponseEvent::Created), Err(ApiError::ServerOverloaded)]
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_when_stream_closes_before_message_stop() {
+        let events = run_anthropic_sse(vec![json!({
+            "type": "message_start",
+            "message": {"id": "msg_3"},
+        })])
+        .await;
+
+        assert_matches!(events.last(), Some(Err(ApiError::Stream(_))));
+    }
+}
@@ -0,0 +1,673 @@
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::rate_limits::parse_all_rate_limits;
+use crate::requests::tools::NamespacedTools;
+use crate::requests::tools::function_call_item;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+use uuid::Uuid;
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+const DONE_SENTINEL: &str = "[DONE]";
+
+/// Streams a Chat Completions response as the same [`ResponseEvent`]s the
+/// Responses API produces.
+pub(crate) fn spawn_chat_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    namespaced_tools: NamespacedTools,
+) -> ResponseStream {
+    let rate_limit_snapshots = parse_all_rate_limits(&stream_response.headers);
+    let upstream_request_id = stream_response
+        .headers
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(async move {
+        for snapshot in rate_limit_snapshots {
+            let _ = tx_event.send(Ok(ResponseEvent::RateLimits(snapshot))).await;
+        }
+        process_chat_sse(
+            stream_response.bytes,
+            tx_event,
+            idle_timeout,
+            telemetry,
+            namespaced_tools,
+        )
+        .await;
+    });
+
+    ResponseStream {
+        rx_event,
+        upstream_request_id,
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+    #[serde(default)]
+    error: Option<ChatError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    #[serde(default)]
+    delta: Option<ChatDelta>,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ChatDelta {
+    #[serde(default)]
+    content: Option<String>,
+    /// vLLM, SGLang, and DeepSeek stream reasoning as `reasoning_content`;
+    /// OpenRouter and Ollama use `reasoning`.
+    #[serde(default)]
+    reasoning_content: Option<String>,
+    #[serde(default)]
+    reasoning: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ChatToolCallDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatToolCallDelta {
+    #[serde(default)]
+    index: usize,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    function: Option<ChatFunctionDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatFunctionDelta {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    prompt_tokens: i64,
+    completion_tokens: i64,
+    total_tokens: i64,
+    #[serde(default)]
+    prompt_tokens_details: Option<ChatPromptTokensDetails>,
+    #[serde(default)]
+    completion_tokens_details: Option<ChatCompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatPromptTokensDetails {
+    #[serde(default)]
+    cached_tokens: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: Option<i64>,
+}
+
+impl From<ChatUsage> for TokenUsage {
+    fn from(val: ChatUsage) -> Self {
+        TokenUsage {
+            input_tokens: val.prompt_tokens,
+            cached_input_tokens: val
+                .prompt_tokens_details
+                .and_then(|details| details.cached_tokens)
+                .unwrap_or(0),
+            cache_write_input_tokens: 0,
+            output_tokens: val.completion_tokens,
+            reasoning_output_tokens: val
+                .completion_tokens_details
+                .and_then(|details| details.reasoning_tokens)
+                .unwrap_or(0),
+            total_tokens: val.total_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatError {
+    #[serde(default)]
+    message: Option<String>,
+    /// Numeric on some servers, a string on others.
+    #[serde(default)]
+    code: Option<Value>,
+}
+
+impl ChatError {
+    fn into_api_error(self) -> ApiError {
+        if self.code.as_ref().and_then(Value::as_str) == Some("context_length_exceeded") {
+            return ApiError::ContextWindowExceeded;
+        }
+        ApiError::Stream(
+            self.message
+                .unwrap_or_else(|| "chat completion stream returned an error".to_string()),
+        )
+    }
+}
+
+/// Text or reasoning currently streaming. Chat Completions interleaves them
+/// freely in one message, so each switch closes one item and opens another.
+enum OpenItem {
+    Reasoning(String),
+    Message(String),
+}
+
+impl OpenItem {
+    fn into_response_item(self) -> ResponseItem {
+        match self {
+            Self::Reasoning(text) => ResponseItem::Reasoning {
+                id: None,
+                summary: Vec::new(),
+                content: Some(vec![ReasoningItemContent::ReasoningText { text }]),
+                encrypted_content: None,
+                internal_chat_message_metadata_passthrough: None,
+            },
+            Self::Message(text) => ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText { text }],
+                phase: None,
+                internal_chat_message_metadata_passthrough: None,
+            },
+        }
+    }
+}
+
+#[derive(Default)]
+struct PendingToolCall {
+    call_id: String,
+    name: String,
+    arguments: String,
+    added: bool,
+}
+
+struct ChatStreamState {
+    namespaced_tools: NamespacedTools,
+    response_id: Option<String>,
+    open_item: Option<OpenItem>,
+    tool_calls: BTreeMap<usize, PendingToolCall>,
+    token_usage: Option<TokenUsage>,
+    finished: bool,
+}
+
+impl ChatStreamState {
+    fn new(namespaced_tools: NamespacedTools) -> Self {
+        Self {
+            namespaced_tools,
+            response_id: None,
+            open_item: None,
+            tool_calls: BTreeMap::new(),
+            token_usage: None,
+            finished: false,
+        }
+    }
+
+    fn handle_chunk(&mut self, chunk: ChatCompletionChunk) -> Vec<ResponseEvent> {
+        let mut events = Vec::new();
+        if self.response_id.is_none() {
+            self.response_id = chunk.id;
+        }
+        if let Some(usage) = chunk.usage {
+            self.token_usage = Some(usage.into());
+        }
+        // Codex always requests a single choice.
+        let Some(choice) = chunk.choices.into_iter().next() else {
+            return events;
+        };
+        let delta = choice.delta.unwrap_or_default();
+        if let Some(reasoning) = delta
+            .reasoning_content
+            .or(delta.reasoning)
+            .filter(|reasoning| !reasoning.is_empty())
+        {
+            if !matches!(self.open_item, Some(OpenItem::Reasoning(_))) {
+                self.close_open_item(&mut events);
+                events.push(ResponseEvent::OutputItemAdded(
+                    OpenItem::Reasoning(String::new()).into_response_item(),
+                ));
+                self.open_item = Some(OpenItem::Reasoning(String::new()));
+            }
+            if let Some(OpenItem::Reasoning(text)) = self.open_item.as_mut() {
+                text.push_str(&reasoning);
+            }
+            events.push(ResponseEvent::ReasoningContentDelta {
+                delta: reasoning,
+                content_index: 0,
+            });
+        }
+        if let Some(content) = delta.content.filter(|content| !content.is_empty()) {
+            if !matches!(self.open_item, Some(OpenItem::Message(_))) {
+                self.close_open_item(&mut events);
+                events.push(ResponseEvent::OutputItemAdded(
+                    OpenItem::Message(String::new()).into_response_item(),
+                ));
+                self.open_item = Some(OpenItem::Message(String::new()));
+            }
+            if let Some(OpenItem::Message(text)) = self.open_item.as_mut() {
+                text.push_str(&content);
+            }
+            events.push(ResponseEvent::OutputTextDelta(content));
+        }
+        for tool_call in delta.tool_calls {
+            self.close_open_item(&mut events);
+            self.handle_tool_call_delta(tool_call, &mut events);
+        }
+        if choice.finish_reason.is_some() {
+            self.finished = true;
+        }
+        events
+    }
+
+    fn handle_tool_call_delta(
+        &mut self,
+        tool_call: ChatToolCallDelta,
+        events: &mut Vec<ResponseEvent>,
+    ) {
+        let ChatToolCallDelta {
+            index,
+            id,
+            function,
+        } = tool_call;
+        let pending = self
+            .tool_calls
+            .entry(index)
+            .or_insert_with(|| PendingToolCall {
+                // Some servers omit call ids; the call still needs one to pair
+                // with its output.
+                call_id: id.unwrap_or_else(|| format!("call_{}", Uuid::new_v4().simple())),
+                ..Default::default()
+            });
+        let (name, arguments) = match function {
+            Some(function) => (function.name, function.arguments),
+            None => (None, None),
+        };
+        if let Some(name) = name {
+            pending.name.push_str(&name);
+        }
+        if let Some(arguments) = arguments.as_deref() {
+            pending.arguments.push_str(arguments);
+        }
+        if !pending.added && !pending.name.is_empty() {
+            pending.added = true;
+            let item = function_call_item(
+                &self.namespaced_tools,
+                &pending.call_id,
+                &pending.name,
+                String::new(),
+            );
+            events.push(ResponseEvent::OutputItemAdded(item));
+            // Arguments that arrived before the name are replayed as one delta.
+            if !pending.arguments.is_empty() {
+                events.push(ResponseEvent::ToolCallInputDelta {
+                    item_id: pending.call_id.clone(),
+                    call_id: Some(pending.call_id.clone()),
+                    delta: pending.arguments.clone(),
+                });
+            }
+            return;
+        }
+        if pending.added
+            && let Some(delta) = arguments.filter(|arguments| !arguments.is_empty())
+        {
+            events.push(ResponseEvent::ToolCallInputDelta {
+                item_id: pending.call_id.clone(),
+                call_id: Some(pending.call_id.clone()),
+                delta,
+            });
+        }
+    }
+
+    fn close_open_item(&mut self, events: &mut Vec<ResponseEvent>) {
+        if let Some(item) = self.open_item.take() {
+            events.push(ResponseEvent::OutputItemDone(item.into_response_item()));
+        }
+    }
+
+    fn finish(mut self) -> Vec<ResponseEvent> {
+        let mut events = Vec::new();
+        self.close_open_item(&mut events);
+        for pending in std::mem::take(&mut self.tool_calls).into_values() {
+            events.push(ResponseEvent::OutputItemDone(function_call_item(
+                &self.namespaced_tools,
+                &pending.call_id,
+                &pending.name,
+                pending.arguments,
+            )));
+        }
+        events.push(ResponseEvent::Completed {
+            response_id: self.response_id.unwrap_or_default(),
+            token_usage: self.token_usage,
+            end_turn: None,
+        });
+        events
+    }
+}
+
+async fn process_chat_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    namespaced_tools: NamespacedTools,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = ChatStreamState::new(namespaced_tools);
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            // Not every server sends `[DONE]`; a finish reason is enough.
+            Ok(None) if state.finished => break,
+            Ok(None) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream(
+                        "stream closed before chat completion finished".into(),
+                    )))
+                    .await;
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("SSE event: {}", &sse.data);
+        if sse.data.trim() == DONE_SENTINEL {
+            break;
+        }
+
+        let chunk: ChatCompletionChunk = match serde_json::from_str(&sse.data) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                debug!(
+                    "Failed to parse chat completion chunk: {e}, data: {}",
+                    &sse.data
+                );
+                continue;
+            }
+        };
+        if let Some(error) = chunk.error {
+            let _ = tx_event.send(Err(error.into_api_error())).await;
+            return;
+        }
+        for event in state.handle_chunk(chunk) {
+            if tx_event.send(Ok(event)).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    for event in state.finish() {
+        if tx_event.send(Ok(event)).await.is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_chat_sse(
+        chunks: Vec<Value>,
+        done: bool,
+        namespaced_tools: NamespacedTools,
+    ) -> Vec<Result<ResponseEvent, ApiError>> {
+        let mut body = String::new();
+        for chunk in chunks {
+            body.push_str(&format!("data: {chunk}\n\n"));
+        }
+        if done {
+            body.push_str("data: [DONE]\n\n");
+        }
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(16);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        tokio::spawn(process_chat_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            /*telemetry*/ None,
+            namespaced_tools,
+        ));
+
+        let mut out = Vec::new();
+        while let Some(ev) = rx.recv().await {
+            out.push(ev);
+        }
+        out
+    }
+
+    fn delta(id: &str, delta: Value) -> Value {
+        json!({"id": id, "choices": [{"index": 0, "delta": delta, "finish_reason": null}]})
+    }
+
+    #[tokio::test]
+    async fn maps_reasoning_text_and_usage() {
+        let events = run_chat_sse(
+            vec![
+                delta("chatcmpl-1", json!({"role": "assistant", "reasoning_content": "Think"})),
+                delta("chatcmpl-1", json!({"reasoning_content": "ing."})),
+                delta("chatcmpl-1", json!({"content": "Hel"})),
+                delta("chatcmpl-1", json!({"content": "lo"})),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {}, "finish_reason": "stop"}]}),
+                json!({
+                    "id": "chatcmpl-1",
+                    "choices": [],
+                    "usage": {
+                        "prompt_tokens": 10,
+                        "completion_tokens": 5,
+                        "total_tokens": 15,
+                        "prompt_tokens_details": {"cached_tokens": 4},
+                        "completion_tokens_details": {"reasoning_tokens": 2},
+                    },
+                }),
+            ],
+            /*done*/ true,
+            HashMap::new(),
+        )
+        .await;
+        let events = events
+            .into_iter()
+            .map(|event| event.map_err(|err| err.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|err| panic!("unexpected stream error: {err}"));
+
+        assert_eq!(events.len(), 9);
+        assert_matches!(
+            &events[0],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { .. })
+        );
+        assert_matches!(
+            &events[1],
+            ResponseEvent::ReasoningContentDelta { delta, content_index: 0 } if delta == "Think"
+        );
+        assert_matches!(&events[2], ResponseEvent::ReasoningContentDelta { delta, .. } if delta == "ing.");
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(ResponseItem::Reasoning { content: Some(content), .. })
+                if content == &vec![ReasoningItemContent::ReasoningText { text: "Thinking.".to_string() }]
+        );
+        assert_matches!(
+            &events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { .. })
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Hel");
+        assert_matches!(&events[6], ResponseEvent::OutputTextDelta(delta) if delta == "lo");
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { role, content, .. })
+                if role == "assistant"
+                    && content == &vec![ContentItem::OutputText { text: "Hello".to_string() }]
+        );
+        match &events[8] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage,
+                end_turn,
+            } => {
+                assert_eq!(response_id, "chatcmpl-1");
+                assert_eq!(
+                    token_usage,
+                    &Some(TokenUsage {
+                        input_tokens: 10,
+                        cached_input_tokens: 4,
+                        cache_write_input_tokens: 0,
+                        output_tokens: 5,
+                        reasoning_output_tokens: 2,
+                        total_tokens: 15,
+                    })
+                );
+                assert_eq!(end_turn, &None);
+            }
+            other => panic!("unexpected last event: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn streams_tool_call_argument_deltas() {
+        let events = run_chat_sse(
+            vec![
+                delta(
+                    "chatcmpl-2",
+                    json!({"tool_calls": [{
+                        "index": 0,
+                        "id": "call_a",
+                        "type": "function",
+                        "function": {"name": "mcp__docs__search", "arguments": ""},
+                    }]}),
+                ),
+                delta(
+                    "chatcmpl-2",
+                    json!({"tool_calls": [{"index": 0, "function": {"arguments": "{\"q\":"}}]}),
+                ),
+                delta(
+                    "chatcmpl-2",
+                    json!({"tool_calls": [{"index": 0, "function": {"arguments": "\"rust\"}"}}]}),
+                ),
+                json!({"id": "chatcmpl-2", "choices": [{"index": 0, "delta": {}, "finish_reason": "tool_calls"}]}),
+            ],
+            // Stream ends after the finish reason without `[DONE]`.
+            /*done*/ false,
+            HashMap::from([(
+                "mcp__docs__search".to_string(),
+                ("mcp__docs__".to_string(), "search".to_string()),
+            )]),
+        )
+        .await;
+        let events = events
+            .into_iter()
+            .map(|event| event.map_err(|err| err.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_or_else(|err| panic!("unexpected stream error: {err}"));
+
+        assert_eq!(events.len(), 5);
+        assert_matches!(
+            &events[0],
+            ResponseEvent::OutputItemAdded(ResponseItem::FunctionCall { call_id, .. })
+                if call_id == "call_a"
+        );
+        assert_matches!(
+            &events[1],
+            ResponseEvent::ToolCallInputDelta { call_id: Some(call_id), delta, .. }
+                if call_id == "call_a" && delta == "{\"q\":"
+        );
+        assert_matches!(
+            &events[2],
+            ResponseEvent::ToolCallInputDelta { delta, .. } if delta == "\"rust\"}"
+        );
+        match &events[3] {
+            ResponseEvent::OutputItemDone(item) => assert_eq!(
+                item,
+                &ResponseItem::FunctionCall {
+                    id: None,
+                    name: "search".to_string(),
+                    namespace: Some("mcp__docs__".to_string()),
+                    arguments: "{\"q\":\"rust\"}".to_string(),
+                    call_id: "call_a".to_string(),
+                    internal_chat_message_metadata_passthrough: None,
+                }
+            ),
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert_matches!(&events[4], ResponseEvent::Completed { .. });
+    }
+
+    #[tokio::test]
+    async fn errors_when_stream_closes_before_finish() {
+        let events = run_chat_sse(
+            vec![delta("chatcmpl-3", json!({"content": "partial"}))],
+            /*done*/ false,
+            HashMap::new(),
+        )
+        .await;
+
+        assert_matches!(events.last(), Some(Err(ApiError::Stream(_))));
+    }
+
+    #[tokio::test]
+    async fn maps_context_length_error() {
+        let events = run_chat_sse(
+            vec![json!({"error": {"message": "too long", "code": "context_length_exceeded"}})],
+            /*done*/ false,
+            HashMap::new(),
+        )
+        .await;
+
+        assert_matches!(events.as_slice(), [Err(ApiError::ContextWindowExceeded)]);
+    }
+}
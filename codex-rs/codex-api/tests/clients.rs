@@ -8,6 +8,7 @@ use bytes::Bytes;
 use codex_api::ApiError;
 use codex_api::AuthError;
 use codex_api::AuthProvider;
+use codex_api::ChatClient;
 use codex_api::Compression;
 use codex_api::Provider;
 use codex_api::ResponsesApiRequest;
@@ -327,6 +328,7 @@ async fn responses_client_stream_request_preserves_item_ids() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        max_output_tokens: None,
         client_metadata: None,
     };
     let expected = serde_json::to_value(&request)?;
@@ -351,6 +353,58 @@ async fn responses_client_stream_request_preserves_item_ids() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn chat_client_uses_chat_completions_path() -> Result<()> {
+    let state = RecordingState::default();
+    let transport = RecordingTransport::new(state.clone());
+    let client = ChatClient::new(transport, provider("vllm"), Arc::new(NoAuth));
+    let request = ResponsesApiRequest {
+        model: "qwen3".into(),
+        instructions: "Say hi".into(),
+        input: vec![ResponseItem::Message {
+            id: None,
+            role: "user".into(),
+            content: vec![ContentItem::InputText { text: "hi".into() }],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        }],
+        tools: None,
+        tool_choice: "auto".into(),
+        parallel_tool_calls: false,
+        reasoning: None,
+        store: false,
+        stream: true,
+        stream_options: None,
+        include: Vec::new(),
+        service_tier: None,
+        prompt_cache_key: None,
+        text: None,
+        max_output_tokens: None,
+        client_metadata: None,
+    };
+
+    let _stream = client
+        .stream_request(&request, ResponsesOptions::default())
+        .await?;
+
+    let requests = state.take_stream_requests();
+    assert_path_ends_with(&requests, "/chat/completions");
+    let body: serde_json::Value = serde_json::from_slice(request_body_bytes(&requests[0]))?;
+    assert_eq!(
+        body,
+        serde_json::json!({
+            "model": "qwen3",
+            "messages": [
+                {"role": "system", "content": "Say hi"},
+                {"role": "user", "content": "hi"},
+            ],
+            "stream": true,
+            "stream_options": {"include_usage": true},
+        })
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn streaming_client_adds_auth_headers() -> Result<()> {
     let state = RecordingState::default();
@@ -414,6 +468,7 @@ async fn streaming_client_retries_on_transport_error() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        max_output_tokens: None,
         client_metadata: None,
     };
     let client = ResponsesClient::new(transport.clone(), provider, Arc::new(NoAuth));
@@ -534,6 +589,7 @@ async fn azure_store_sends_ids_and_headers() -> Result<()> {
         service_tier: None,
         prompt_cache_key: None,
         text: None,
+        max_output_tokens: None,
         client_metadata: None,
     };
 
@@ -104,6 +104,7 @@ async fn models_client_hits_models_endpoint() {
             auto_review_model_override: None,
             tool_mode: None,
             multi_agent_version: None,
+            tokenizer: None,
         }],
     };
 
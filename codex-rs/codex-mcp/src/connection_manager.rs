@@ -30,6 +30,9 @@ use crate::runtime::McpRuntimeContext;
 use crate::runtime::emit_duration;
 use crate::server::EffectiveMcpServer;
 use crate::server::McpServerMetadata;
+use crate::server_notifications::McpResourceUpdate;
+use crate::server_requests::McpSamplerHandle;
+use crate::server_requests::McpServerRequests;
 use crate::tool_catalog_cache::McpToolCatalogCache;
 use crate::tools::ToolInfo;
 use crate::tools::filter_tools;
@@ -65,10 +68,14 @@ use codex_rmcp_client::ElicitationResponse;
 use codex_rmcp_client::McpAuthState;
 use codex_rmcp_client::McpLoginRequirement;
 use codex_rmcp_client::determine_streamable_http_auth_status_from_credentials;
+use codex_utils_path_uri::PathUri;
 use rmcp::model::ElicitationCapability;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::Prompt;
 use rmcp::model::ReadResourceRequestParams;
 use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
@@ -120,6 +127,7 @@ pub struct McpConnectionManager {
     tool_plugin_provenance: Arc<ToolPluginProvenance>,
     prefix_mcp_tool_names: bool,
     elicitation_requests: ElicitationRequestManager,
+    server_requests: McpServerRequests,
     startup_cancellation_token: CancellationToken,
 }
 
@@ -150,6 +158,7 @@ impl McpConnectionManager {
         elicitation_reviewer: Option<ElicitationReviewerHandle>,
         elicitation_lifecycle: Option<crate::ElicitationLifecycle>,
         elicitation_router: ElicitationRequestRouter,
+        sampler: Option<McpSamplerHandle>,
     ) -> Self {
         let mut required_servers = mcp_servers
             .iter()
@@ -167,6 +176,7 @@ impl McpConnectionManager {
             elicitation_lifecycle,
             elicitation_router,
         );
+        let server_requests = McpServerRequests::new(sampler, initial_mcp_roots(&runtime_context));
         let tool_plugin_provenance = Arc::new(tool_plugin_provenance);
         let startup_submit_id = submit_id.clone();
         let static_chatgpt_auth_provider = auth
@@ -264,6 +274,7 @@ impl McpConnectionManager {
                 cancel_token.clone(),
                 tx_event.clone(),
                 elicitation_requests.clone(),
+                server_requests.clone(),
                 codex_apps_tools_cache_context,
                 tool_catalog_cache_context,
                 Arc::clone(&tool_plugin_provenance),
@@ -363,6 +374,7 @@ impl McpConnectionManager {
             tool_plugin_provenance,
             prefix_mcp_tool_names,
             elicitation_requests: elicitation_requests.clone(),
+            server_requests,
             startup_cancellation_token: startup_cancellation_token.clone(),
         };
         tokio::spawn(async move {
@@ -456,6 +468,7 @@ impl McpConnectionManager {
                 /*lifecycle*/ None,
                 ElicitationRequestRouter::default(),
             ),
+            server_requests: McpServerRequests::new(/*sampler*/ None, Vec::new()),
             startup_cancellation_token: CancellationToken::new(),
         }
     }
@@ -539,6 +552,28 @@ impl McpConnectionManager {
         }
     }
 
+    /// Updates the workspace roots reported through `roots/list` and notifies
+    /// ready servers when they change.
+    pub async fn set_roots(&self, roots: Vec<PathUri>) {
+        if !self.server_requests.set_roots(roots) {
+            return;
+        }
+        for (server_name, async_managed_client) in &self.clients {
+            if !async_managed_client
+                .startup_complete
+                .load(Ordering::Acquire)
+            {
+                continue;
+            }
+            let Ok(managed_client) = async_managed_client.client().await else {
+                continue;
+            };
+            if let Err(err) = managed_client.client.notify_roots_list_changed().await {
+                warn!("Failed to notify MCP server '{server_name}' of roots change: {err:#}");
+            }
+        }
+    }
+
     pub fn elicitations_auto_deny(&self) -> bool {
         self.elicitation_requests.auto_deny()
     }
@@ -581,6 +616,8 @@ impl McpConnectionManager {
         let mut unavailable_server_count = 0;
         for (server_name, managed_client) in &self.clients {
             managed_client.reconnect_failed_startup().await;
+            self.refresh_tools_if_changed(server_name, managed_client)
+                .await;
             let has_cached_tools = managed_client.has_cached_tools();
             let startup_complete = managed_client
                 .startup_complete
@@ -621,6 +658,29 @@ impl McpConnectionManager {
         tools
     }
 
+    /// Re-lists tools for a server that sent `notifications/tools/list_changed`
+    /// since the tool list was last built.
+    async fn refresh_tools_if_changed(&self, server_name: &str, client: &AsyncManagedClient) {
+        if !self
+            .server_requests
+            .notifications()
+            .take_tool_list_changed(server_name)
+        {
+            return;
+        }
+        let result = if server_name == CODEX_APPS_MCP_SERVER_NAME {
+            self.hard_refresh_codex_apps_tools_cache().await.map(drop)
+        } else {
+            match client.client().await {
+                Ok(managed_client) => managed_client.refresh_tools(server_name).await,
+                Err(_) => return,
+            }
+        };
+        if let Err(err) = result {
+            warn!("Failed to refresh tools for MCP server '{server_name}': {err:#}");
+        }
+    }
+
     /// Returns one tool from the current live connection.
     pub async fn tool_info(&self, server: &str, tool: &str) -> Option<ToolInfo> {
         let client = self.clients.get(server)?;
@@ -769,6 +829,89 @@ impl McpConnectionManager {
         aggregated
     }
 
+    /// Returns prompts from every ready server that advertises the prompts
+    /// capability. Each key is the server name.
+    pub async fn list_all_prompts(&self) -> HashMap<String, Vec<Prompt>> {
+        let mut join_set = JoinSet::new();
+
+        for (server_name, async_managed_client) in &self.clients {
+            let server_name = server_name.clone();
+            let Ok(managed_client) = async_managed_client.client().await else {
+                continue;
+            };
+            if !managed_client.server_supports_prompts {
+                continue;
+            }
+            let timeout = managed_client.tool_timeout;
+            let client = managed_client.client.clone();
+
+            join_set.spawn(async move {
+                let mut collected: Vec<Prompt> = Vec::new();
+                let mut cursor: Option<String> = None;
+
+                loop {
+                    let params = cursor.as_ref().map(|next| {
+                        PaginatedRequestParams::default().with_cursor(Some(next.clone()))
+                    });
+                    let response = match client.list_prompts(params, timeout).await {
+                        Ok(result) => result,
+                        Err(err) => return (server_name, Err(err)),
+                    };
+
+                    collected.extend(response.prompts);
+
+                    match response.next_cursor {
+                        Some(next) => {
+                            if cursor.as_ref() == Some(&next) {
+                                return (
+                                    server_name,
+                                    Err(anyhow!("prompts/list returned duplicate cursor")),
+                                );
+                            }
+                            cursor = Some(next);
+                        }
+                        None => return (server_name, Ok(collected)),
+                    }
+                }
+            });
+        }
+
+        let mut aggregated: HashMap<String, Vec<Prompt>> = HashMap::new();
+
+        while let Some(join_res) = join_set.join_next().await {
+            match join_res {
+                Ok((server_name, Ok(prompts))) => {
+                    aggregated.insert(server_name, prompts);
+                }
+                Ok((server_name, Err(err))) => {
+                    warn!("Failed to list prompts for MCP server '{server_name}': {err:#}");
+                }
+                Err(err) => {
+                    warn!("Task panic when listing prompts for MCP server: {err:#}");
+                }
+            }
+        }
+
+        aggregated
+    }
+
+    /// Renders a prompt from a specific server.
+    pub async fn get_prompt(
+        &self,
+        server: &str,
+        name: String,
+        arguments: Option<JsonObject>,
+    ) -> Result<GetPromptResult> {
+        let managed = self.client_by_name(server).await?;
+        let client = managed.client.clone();
+        let timeout = managed.tool_timeout;
+
+        client
+            .get_prompt(name.clone(), arguments, timeout)
+            .await
+            .with_context(|| format!("prompts/get failed for `{server}` ({name})"))
+    }
+
     /// Returns resource templates from servers selected by `include_server`.
     /// Each key is the server name and the value is a vector of templates.
     pub async fn list_all_resource_templates(
@@ -941,6 +1084,34 @@ impl McpConnectionManager {
             .with_context(|| format!("resources/read failed for `{server}` ({uri})"))
     }
 
+    /// Subscribes to updates for a resource the model has read. Servers that
+    /// do not advertise `resources.subscribe` are skipped, and each resource is
+    /// subscribed at most once per connection manager.
+    pub async fn subscribe_resource(&self, server: &str, uri: &str) {
+        let Ok(managed) = self.client_by_name(server).await else {
+            return;
+        };
+        let notifications = self.server_requests.notifications();
+        if !managed.server_supports_resource_subscribe
+            || !notifications.mark_subscribed(server, uri)
+        {
+            return;
+        }
+        if let Err(err) = managed
+            .client
+            .subscribe_resource(uri.to_string(), managed.tool_timeout)
+            .await
+        {
+            notifications.unmark_subscribed(server, uri);
+            warn!("Failed to subscribe to MCP resource `{uri}` on server '{server}': {err:#}");
+        }
+    }
+
+    /// Drains subscribed resources that changed since the last call.
+    pub fn take_resource_updates(&self) -> Vec<McpResourceUpdate> {
+        self.server_requests.notifications().take_resource_updates()
+    }
+
     /// Returns presentation metadata from the current connection.
     /// Codex Apps metadata may come from its existing cache; regular MCP server information is
     /// connection-specific, so pending regular clients are awaited.
@@ -1012,6 +1183,15 @@ impl Drop for McpConnectionManager {
     }
 }
 
+/// Seeds the advertised roots with the local fallback cwd until the owner
+/// publishes the thread's workspace roots.
+fn initial_mcp_roots(runtime_context: &McpRuntimeContext) -> Vec<PathUri> {
+    PathUri::from_host_native_path(runtime_context.local_stdio_fallback_cwd())
+        .ok()
+        .into_iter()
+        .collect()
+}
+
 /// Makes ChatGPT authentication available to servers that explicitly opt in.
 /// The HTTP transport applies it only when no configured authorization resolves.
 fn chatgpt_auth_provider_for_server(
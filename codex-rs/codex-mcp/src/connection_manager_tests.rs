@@ -124,11 +124,13 @@ async fn create_test_managed_client(tools: Vec<ToolInfo>) -> ManagedClient {
                 .expect("create in-process RMCP client"),
         ),
         server_info: create_test_server_info("Ready"),
-        tools,
+        tools: Arc::new(std::sync::RwLock::new(tools)),
         tool_filter: ToolFilter::default(),
         tool_timeout: None,
         server_instructions: None,
         server_supports_sandbox_state_meta_capability: false,
+        server_supports_prompts: false,
+        server_supports_resource_subscribe: false,
         codex_apps_tools_cache_context: None,
     }
 }
@@ -994,7 +996,7 @@ async fn cancelling_startup_does_not_disable_a_ready_client() {
         .await
         .expect("startup cancellation should not disable a ready client");
     assert_eq!(
-        model_tool_names(&managed.tools),
+        model_tool_names(&managed.listed_tools()),
         HashSet::from([ToolName::namespaced("ready", "search")])
     );
 }
@@ -1625,6 +1627,7 @@ async fn no_local_runtime_fails_local_stdio_but_keeps_local_http_server() {
         /*elicitation_reviewer*/ None,
         /*elicitation_lifecycle*/ None,
         ElicitationRequestRouter::default(),
+        /*sampler*/ None,
     )
     .await;
 
@@ -13,6 +13,9 @@ pub use rmcp_client::MCP_SANDBOX_STATE_META_CAPABILITY;
 pub use runtime::McpRuntime;
 pub use runtime::McpRuntimeContext;
 pub use runtime::SandboxState;
+pub use server_notifications::McpResourceUpdate;
+pub use server_requests::McpSampler;
+pub use server_requests::McpSamplerHandle;
 pub use tool_catalog_cache::McpToolCatalogCache;
 pub use tools::ToolInfo;
 
@@ -93,5 +96,7 @@ mod resource_client;
 pub(crate) mod rmcp_client;
 pub(crate) mod runtime;
 pub(crate) mod server;
+mod server_notifications;
+mod server_requests;
 mod tool_catalog_cache;
 pub(crate) mod tools;
@@ -13,6 +13,7 @@ use std::env;
 use std::ffi::OsString;
 use std::sync::Arc;
 use std::sync::Mutex as StdMutex;
+use std::sync::RwLock as StdRwLock;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
@@ -30,6 +31,7 @@ use crate::runtime::McpRuntimeContext;
 use crate::runtime::emit_duration;
 use crate::server::EffectiveMcpServer;
 use crate::server::McpServerLaunch;
+use crate::server_requests::McpServerRequests;
 use crate::tool_catalog_cache::McpToolCatalogCacheContext;
 use crate::tool_catalog_cache::McpToolCatalogFetchTicket;
 use crate::tools::ToolFilter;
@@ -108,11 +110,14 @@ const UNTRUSTED_CONNECTOR_META_KEYS: &[&str] = &[
 pub(crate) struct ManagedClient {
     pub(crate) client: Arc<RmcpClient>,
     pub(crate) server_info: McpServerInfo,
-    pub(crate) tools: Vec<ToolInfo>,
+    /// Shared so a refresh after `notifications/tools/list_changed` reaches every clone.
+    pub(crate) tools: Arc<StdRwLock<Vec<ToolInfo>>>,
     pub(crate) tool_filter: ToolFilter,
     pub(crate) tool_timeout: Option<Duration>,
     pub(crate) server_instructions: Option<String>,
     pub(crate) server_supports_sandbox_state_meta_capability: bool,
+    pub(crate) server_supports_prompts: bool,
+    pub(crate) server_supports_resource_subscribe: bool,
     pub(crate) codex_apps_tools_cache_context: Option<ConnectorRuntimeContext<ToolInfo>>,
 }
 
@@ -140,7 +145,28 @@ impl ManagedClient {
             );
         }
 
-        self.tools.clone()
+        self.tools
+            .read()
+            .map(|tools| tools.clone())
+            .unwrap_or_default()
+    }
+
+    /// Re-lists the server's tools after it announced a tool list change.
+    pub(crate) async fn refresh_tools(&self, server_name: &str) -> Result<()> {
+        let tools = list_tools_for_client_uncached(
+            server_name,
+            /*is_codex_apps_mcp_server*/ false,
+            /*codex_apps_refresh_trigger*/ "list_changed",
+            &self.client,
+            self.tool_timeout,
+            self.server_instructions.as_deref(),
+        )
+        .await?;
+        let tools = filter_tools(tools, &self.tool_filter);
+        if let Ok(mut current) = self.tools.write() {
+            *current = tools;
+        }
+        Ok(())
     }
 }
 
@@ -279,6 +305,7 @@ struct ManagedClientStartup {
     keyring_backend_kind: AuthKeyringBackendKind,
     tx_event: Option<Sender<Event>>,
     elicitation_requests: ElicitationRequestManager,
+    server_requests: McpServerRequests,
     codex_apps_tools_cache_context: Option<ConnectorRuntimeContext<ToolInfo>>,
     tool_catalog_cache_context: Option<McpToolCatalogCacheContext>,
     runtime_context: McpRuntimeContext,
@@ -299,6 +326,7 @@ impl ManagedClientStartup {
             keyring_backend_kind,
             tx_event,
             elicitation_requests,
+            server_requests,
             codex_apps_tools_cache_context,
             tool_catalog_cache_context,
             runtime_context,
@@ -363,6 +391,7 @@ impl ManagedClientStartup {
                         tool_filter,
                         tx_event,
                         elicitation_requests,
+                        server_requests,
                         codex_apps_tools_cache_context,
                         tool_catalog_cache_context,
                         tool_catalog_fetch_ticket,
@@ -425,6 +454,7 @@ impl AsyncManagedClient {
         cancel_token: CancellationToken,
         tx_event: Option<Sender<Event>>,
         elicitation_requests: ElicitationRequestManager,
+        server_requests: McpServerRequests,
         codex_apps_tools_cache_context: Option<ConnectorRuntimeContext<ToolInfo>>,
         tool_catalog_cache_context: Option<McpToolCatalogCacheContext>,
         tool_plugin_provenance: Arc<ToolPluginProvenance>,
@@ -456,6 +486,7 @@ impl AsyncManagedClient {
             keyring_backend_kind,
             tx_event,
             elicitation_requests,
+            server_requests,
             codex_apps_tools_cache_context: codex_apps_tools_cache_context.clone(),
             tool_catalog_cache_context: tool_catalog_cache_context.clone(),
             runtime_context,
@@ -875,6 +906,7 @@ async fn start_server_task(
         tool_filter,
         tx_event,
         elicitation_requests,
+        server_requests,
         codex_apps_tools_cache_context,
         tool_catalog_cache_context,
         tool_catalog_fetch_ticket,
@@ -884,11 +916,21 @@ async fn start_server_task(
     let params = mcp_initialize_request_params(
         client_elicitation_capability,
         supports_openai_form_elicitation,
+        server_requests.supports_sampling(),
+    );
+    let server_request_handlers = server_requests.make_handlers(
+        server_name.clone(),
+        elicitation_requests.make_sender(server_name.clone(), tx_event.clone()),
     );
     let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event);
 
     let initialize_result = client
-        .initialize(params, startup_timeout, send_elicitation)
+        .initialize(
+            params,
+            startup_timeout,
+            send_elicitation,
+            server_request_handlers,
+        )
         .await
         .map_err(StartupOutcomeError::from)?;
 
@@ -911,6 +953,13 @@ async fn start_server_task(
         .as_ref()
         .and_then(|exp| exp.get(MCP_SANDBOX_STATE_META_CAPABILITY))
         .is_some();
+    let server_supports_prompts = initialize_result.capabilities.prompts.is_some();
+    let server_supports_resource_subscribe = initialize_result
+        .capabilities
+        .resources
+        .as_ref()
+        .and_then(|resources| resources.subscribe)
+        == Some(true);
     let list_start = Instant::now();
     let fetch_ticket = codex_apps_tools_cache_context
         .as_ref()
@@ -952,11 +1001,13 @@ async fn start_server_task(
     let managed = ManagedClient {
         client: Arc::clone(&client),
         server_info,
-        tools,
+        tools: Arc::new(StdRwLock::new(tools)),
         tool_timeout: Some(tool_timeout),
         tool_filter,
         server_instructions: initialize_result.instructions,
         server_supports_sandbox_state_meta_capability,
+        server_supports_prompts,
+        server_supports_resource_subscribe,
         codex_apps_tools_cache_context,
     };
 
@@ -966,8 +1017,20 @@ async fn start_server_task(
 fn mcp_initialize_request_params(
     client_elicitation_capability: ElicitationCapability,
     supports_openai_form_elicitation: bool,
+    supports_sampling: bool,
 ) -> InitializeRequestParams {
-    let mut capabilities = ClientCapabilities::default();
+    let mut capabilities = if supports_sampling {
+        ClientCapabilities::builder()
+            .enable_roots()
+            .enable_roots_list_changed()
+            .enable_sampling()
+            .build()
+    } else {
+        ClientCapabilities::builder()
+            .enable_roots()
+            .enable_roots_list_changed()
+            .build()
+    };
     capabilities.elicitation = Some(client_elicitation_capability);
     if supports_openai_form_elicitation {
         capabilities.extensions = Some(BTreeMap::from([(
@@ -1005,6 +1068,7 @@ struct StartServerTaskParams {
     tool_filter: ToolFilter,
     tx_event: Option<Sender<Event>>,
     elicitation_requests: ElicitationRequestManager,
+    server_requests: McpServerRequests,
     codex_apps_tools_cache_context: Option<ConnectorRuntimeContext<ToolInfo>>,
     tool_catalog_cache_context: Option<McpToolCatalogCacheContext>,
     tool_catalog_fetch_ticket: Option<McpToolCatalogFetchTicket>,
@@ -1124,12 +1188,14 @@ mod tests {
         let unsupported = mcp_initialize_request_params(
             ElicitationCapability::default(),
             /*supports_openai_form_elicitation*/ false,
+            /*supports_sampling*/ false,
         );
         assert_eq!(unsupported.capabilities.extensions, None);
 
         let supported = mcp_initialize_request_params(
             ElicitationCapability::default(),
             /*supports_openai_form_elicitation*/ true,
+            /*supports_sampling*/ false,
         );
         assert_eq!(
             supported.capabilities.extensions,
@@ -1140,6 +1206,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mcp_initialize_advertises_roots_and_sampling_only_with_sampler() {
+        let without_sampler = mcp_initialize_request_params(
+            ElicitationCapability::default(),
+            /*supports_openai_form_elicitation*/ false,
+            /*supports_sampling*/ false,
+        );
+        assert_eq!(
+            without_sampler
+                .capabilities
+                .roots
+                .as_ref()
+                .and_then(|roots| roots.list_changed),
+            Some(true)
+        );
+        assert!(without_sampler.capabilities.sampling.is_none());
+
+        let with_sampler = mcp_initialize_request_params(
+            ElicitationCapability::default(),
+            /*supports_openai_form_elicitation*/ false,
+            /*supports_sampling*/ true,
+        );
+        assert!(with_sampler.capabilities.sampling.is_some());
+    }
+
     fn tool_with_connector_meta() -> RmcpTool {
         RmcpTool::new(
             "capture_file_upload",
@@ -0,0 +1,107 @@
+//! Change notifications sent by MCP servers.
+//!
+//! Servers announce `notifications/tools/list_changed` and
+//! `notifications/resources/updated` at any time. The handlers only record
+//! what changed; the connection manager re-lists tools lazily the next time it
+//! builds the tool list, and the session drains resource updates at the start
+//! of the next turn.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+
+use codex_rmcp_client::McpServerNotification;
+use codex_rmcp_client::OnServerNotification;
+
+/// A subscribed MCP resource that changed after the model read it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct McpResourceUpdate {
+    pub server: String,
+    pub uri: String,
+}
+
+#[derive(Default)]
+struct NotificationState {
+    stale_tool_lists: HashSet<String>,
+    subscriptions: HashSet<(String, String)>,
+    resource_updates: Vec<McpResourceUpdate>,
+}
+
+/// Shared notification state, cloned into every client started by one
+/// [`crate::McpConnectionManager`].
+#[derive(Clone, Default)]
+pub(crate) struct McpServerNotifications {
+    state: Arc<StdMutex<NotificationState>>,
+}
+
+impl McpServerNotifications {
+    pub(crate) fn make_handler(&self, server_name: String) -> OnServerNotification {
+        let notifications = self.clone();
+        Box::new(move |notification| notifications.record(&server_name, notification))
+    }
+
+    fn record(&self, server_name: &str, notification: McpServerNotification) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        match notification {
+            McpServerNotification::ToolListChanged => {
+                state.stale_tool_lists.insert(server_name.to_string());
+            }
+            McpServerNotification::ResourceUpdated { uri } => {
+                // Servers may broadcast updates for resources nobody asked about.
+                if !state
+                    .subscriptions
+                    .contains(&(server_name.to_string(), uri.clone()))
+                {
+                    return;
+                }
+                let update = McpResourceUpdate {
+                    server: server_name.to_string(),
+                    uri,
+                };
+                if !state.resource_updates.contains(&update) {
+                    state.resource_updates.push(update);
+                }
+            }
+            McpServerNotification::ResourceListChanged
+            | McpServerNotification::PromptListChanged => {}
+        }
+    }
+
+    /// Records a subscription and returns whether it is new.
+    pub(crate) fn mark_subscribed(&self, server_name: &str, uri: &str) -> bool {
+        self.state.lock().is_ok_and(|mut state| {
+            state
+                .subscriptions
+                .insert((server_name.to_string(), uri.to_string()))
+        })
+    }
+
+    /// Forgets a subscription so a later read retries it.
+    pub(crate) fn unmark_subscribed(&self, server_name: &str, uri: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state
+                .subscriptions
+                .remove(&(server_name.to_string(), uri.to_string()));
+        }
+    }
+
+    /// Clears and returns whether `server_name` announced a tool list change.
+    pub(crate) fn take_tool_list_changed(&self, server_name: &str) -> bool {
+        self.state
+            .lock()
+            .is_ok_and(|mut state| state.stale_tool_lists.remove(server_name))
+    }
+
+    pub(crate) fn take_resource_updates(&self) -> Vec<McpResourceUpdate> {
+        self.state
+            .lock()
+            .map(|mut state| std::mem::take(&mut state.resource_updates))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+#[path = "server_notifications_tests.rs"]
+mod tests;
@@ -0,0 +1,41 @@
+use super::McpResourceUpdate;
+use super::McpServerNotifications;
+use codex_rmcp_client::McpServerNotification;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn resource_updates_are_recorded_only_for_subscriptions() {
+    let notifications = McpServerNotifications::default();
+    let handler = notifications.make_handler("docs".to_string());
+    assert!(notifications.mark_subscribed("docs", "file:///guide.md"));
+    assert!(!notifications.mark_subscribed("docs", "file:///guide.md"));
+
+    handler(McpServerNotification::ResourceUpdated {
+        uri: "file:///guide.md".to_string(),
+    });
+    handler(McpServerNotification::ResourceUpdated {
+        uri: "file:///guide.md".to_string(),
+    });
+    handler(McpServerNotification::ResourceUpdated {
+        uri: "file:///other.md".to_string(),
+    });
+
+    assert_eq!(
+        notifications.take_resource_updates(),
+        vec![McpResourceUpdate {
+            server: "docs".to_string(),
+            uri: "file:///guide.md".to_string(),
+        }]
+    );
+    assert_eq!(notifications.take_resource_updates(), Vec::new());
+}
+
+#[test]
+fn tool_list_changes_are_tracked_per_server() {
+    let notifications = McpServerNotifications::default();
+    notifications.make_handler("docs".to_string())(McpServerNotification::ToolListChanged);
+
+    assert!(!notifications.take_tool_list_changed("search"));
+    assert!(notifications.take_tool_list_changed("docs"));
+    assert!(!notifications.take_tool_list_changed("docs"));
+}
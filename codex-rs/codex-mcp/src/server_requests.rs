@@ -0,0 +1,168 @@
+//! Server-initiated MCP requests other than elicitation.
+//!
+//! `sampling/createMessage` is gated behind an approval prompt that reuses the
+//! elicitation flow, then forwarded to the owner-provided [`McpSampler`].
+//! `roots/list` reports the shared workspace roots, which the owner updates
+//! when the thread's working directory or writable roots change. Change
+//! notifications are recorded in the shared [`McpServerNotifications`].
+
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+
+use anyhow::Result;
+use anyhow::anyhow;
+use codex_rmcp_client::Elicitation;
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::McpRoot;
+use codex_rmcp_client::SamplingMessage;
+use codex_rmcp_client::SamplingRequest;
+use codex_rmcp_client::SamplingResponse;
+use codex_rmcp_client::SendElicitation;
+use codex_rmcp_client::SendSampling;
+use codex_rmcp_client::ServerRequestHandlers;
+use codex_utils_path_uri::PathUri;
+
+use crate::server_notifications::McpServerNotifications;
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use rmcp::model::CreateElicitationRequestParams;
+use rmcp::model::ElicitationSchema;
+
+/// Longest prompt excerpt shown in the sampling approval prompt.
+const SAMPLING_APPROVAL_PROMPT_MAX_CHARS: usize = 500;
+
+/// Runs model completions requested by MCP servers through `sampling/createMessage`.
+pub trait McpSampler: Send + Sync {
+    fn create_message(
+        &self,
+        server_name: String,
+        request: SamplingRequest,
+    ) -> BoxFuture<'static, Result<SamplingResponse>>;
+}
+
+pub type McpSamplerHandle = Arc<dyn McpSampler>;
+
+/// Shared handler state for server-to-client requests, cloned into every
+/// client started by one [`crate::McpConnectionManager`].
+#[derive(Clone)]
+pub(crate) struct McpServerRequests {
+    sampler: Option<McpSamplerHandle>,
+    roots: Arc<StdMutex<Vec<PathUri>>>,
+    notifications: McpServerNotifications,
+}
+
+impl McpServerRequests {
+    pub(crate) fn new(sampler: Option<McpSamplerHandle>, roots: Vec<PathUri>) -> Self {
+        Self {
+            sampler,
+            roots: Arc::new(StdMutex::new(roots)),
+            notifications: McpServerNotifications::default(),
+        }
+    }
+
+    pub(crate) fn supports_sampling(&self) -> bool {
+        self.sampler.is_some()
+    }
+
+    pub(crate) fn notifications(&self) -> &McpServerNotifications {
+        &self.notifications
+    }
+
+    /// Replaces the advertised roots and returns whether they changed.
+    pub(crate) fn set_roots(&self, roots: Vec<PathUri>) -> bool {
+        let Ok(mut current) = self.roots.lock() else {
+            return false;
+        };
+        if *current == roots {
+            return false;
+        }
+        *current = roots;
+        true
+    }
+
+    pub(crate) fn roots(&self) -> Vec<McpRoot> {
+        self.roots
+            .lock()
+            .map(|roots| roots.iter().map(mcp_root).collect())
+            .unwrap_or_default()
+    }
+
+    /// Builds the RMCP handlers for one server. `approval` prompts the user
+    /// before each sampling request is sent to the model.
+    pub(crate) fn make_handlers(
+        &self,
+        server_name: String,
+        approval: SendElicitation,
+    ) -> ServerRequestHandlers {
+        let roots = self.clone();
+        let on_notification = self.notifications.make_handler(server_name.clone());
+        ServerRequestHandlers {
+            send_sampling: self
+                .sampler
+                .clone()
+                .map(|sampler| make_send_sampling(server_name, sampler, approval)),
+            list_roots: Some(Box::new(move || roots.roots())),
+            on_notification: Some(on_notification),
+        }
+    }
+}
+
+fn make_send_sampling(
+    server_name: String,
+    sampler: McpSamplerHandle,
+    approval: SendElicitation,
+) -> SendSampling {
+    let approval = Arc::new(approval);
+    Box::new(move |id, request| {
+        let server_name = server_name.clone();
+        let sampler = Arc::clone(&sampler);
+        let approval = Arc::clone(&approval);
+        async move {
+            let response = approval(id, sampling_approval(&server_name, &request)?).await?;
+            if !matches!(response.action, ElicitationAction::Accept) {
+                return Err(anyhow!("user rejected the sampling request"));
+            }
+            sampler.create_message(server_name, request).await
+        }
+        .boxed()
+    })
+}
+
+fn sampling_approval(server_name: &str, request: &SamplingRequest) -> Result<Elicitation> {
+    let prompt = request
+        .messages
+        .last()
+        .map(SamplingMessage::text)
+        .unwrap_or_default();
+    let mut excerpt = prompt
+        .chars()
+        .take(SAMPLING_APPROVAL_PROMPT_MAX_CHARS)
+        .collect::<String>();
+    if excerpt.len() < prompt.len() {
+        excerpt.push('…');
+    }
+    let requested_schema = ElicitationSchema::builder()
+        .build()
+        .map_err(|err| anyhow!("failed to build sampling approval schema: {err}"))?;
+    Ok(Elicitation::Mcp(
+        CreateElicitationRequestParams::FormElicitationParams {
+            meta: None,
+            message: format!(
+                "MCP server `{server_name}` wants to use the model (up to {} tokens):\n\n{excerpt}",
+                request.max_tokens
+            ),
+            requested_schema,
+        },
+    ))
+}
+
+fn mcp_root(root: &PathUri) -> McpRoot {
+    McpRoot {
+        uri: root.to_string(),
+        name: root.basename(),
+    }
+}
+
+#[cfg(test)]
+#[path = "server_requests_tests.rs"]
+mod tests;
@@ -0,0 +1,176 @@
+use super::McpSampler;
+use super::McpSamplerHandle;
+use super::McpServerRequests;
+use anyhow::Result;
+use codex_rmcp_client::Elicitation;
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::McpRoot;
+use codex_rmcp_client::SamplingMessage;
+use codex_rmcp_client::SamplingRequest;
+use codex_rmcp_client::SamplingResponse;
+use codex_rmcp_client::SamplingRole;
+use codex_rmcp_client::SendElicitation;
+use codex_utils_path_uri::PathUri;
+use futures::future::BoxFuture;
+use futures::future::FutureExt;
+use pretty_assertions::assert_eq;
+use rmcp::model::NumberOrString;
+use rmcp::model::RequestId;
+use serde_json::json;
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+
+struct RecordingSampler {
+    calls: StdMutex<Vec<String>>,
+}
+
+impl McpSampler for RecordingSampler {
+    fn create_message(
+        &self,
+        server_name: String,
+        request: SamplingRequest,
+    ) -> BoxFuture<'static, Result<SamplingResponse>> {
+        if let Ok(mut calls) = self.calls.lock() {
+            calls.push(server_name);
+        }
+        let text = format!("echo: {}", request.messages[0].text());
+        async move {
+            Ok(SamplingResponse {
+                model: "test-model".to_string(),
+                text,
+                stop_reason: Some("endTurn".to_string()),
+            })
+        }
+        .boxed()
+    }
+}
+
+fn approval(accept: bool, prompts: Arc<StdMutex<Vec<String>>>) -> SendElicitation {
+    Box::new(move |_, elicitation| {
+        if let Elicitation::Mcp(
+            rmcp::model::CreateElicitationRequestParams::FormElicitationParams { message, .. },
+        ) = elicitation
+            && let Ok(mut prompts) = prompts.lock()
+        {
+            prompts.push(message);
+        }
+        let action = if accept {
+            ElicitationAction::Accept
+        } else {
+            ElicitationAction::Decline
+        };
+        async move {
+            Ok(ElicitationResponse {
+                action,
+                content: Some(json!({})),
+                meta: None,
+            })
+        }
+        .boxed()
+    })
+}
+
+fn sampling_request() -> SamplingRequest {
+    SamplingRequest {
+        messages: vec![SamplingMessage {
+            role: SamplingRole::User,
+            content: json!({ "type": "text", "text": "hello" }),
+        }],
+        system_prompt: None,
+        max_tokens: 64,
+        temperature: None,
+        stop_sequences: None,
+        model_preferences: None,
+    }
+}
+
+fn request_id() -> RequestId {
+    NumberOrString::Number(1)
+}
+
+#[test]
+fn set_roots_reports_changes_and_lists_named_roots() {
+    let repo = PathUri::parse("file:///work/repo").expect("repo URI");
+    let requests = McpServerRequests::new(/*sampler*/ None, vec![repo.clone()]);
+
+    assert!(!requests.set_roots(vec![repo.clone()]));
+    assert!(requests.set_roots(vec![
+        repo,
+        PathUri::parse("file:///tmp/scratch").expect("scratch URI"),
+    ]));
+    assert_eq!(
+        requests.roots(),
+        vec![
+            McpRoot {
+                uri: "file:///work/repo".to_string(),
+                name: Some("repo".to_string()),
+            },
+            McpRoot {
+                uri: "file:///tmp/scratch".to_string(),
+                name: Some("scratch".to_string()),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn approved_sampling_request_reaches_sampler() {
+    let sampler = Arc::new(RecordingSampler {
+        calls: StdMutex::new(Vec::new()),
+    });
+    let prompts = Arc::new(StdMutex::new(Vec::new()));
+    let requests = McpServerRequests::new(Some(sampler.clone() as McpSamplerHandle), Vec::new());
+    let handlers = requests.make_handlers(
+        "docs".to_string(),
+        approval(/*accept*/ true, Arc::clone(&prompts)),
+    );
+    let send_sampling = handlers.send_sampling.expect("sampling handler");
+
+    let response = send_sampling(request_id(), sampling_request())
+        .await
+        .expect("sampling should succeed");
+
+    assert_eq!(response.text, "echo: hello");
+    assert_eq!(
+        *sampler.calls.lock().expect("calls lock"),
+        vec!["docs".to_string()]
+    );
+    assert_eq!(
+        *prompts.lock().expect("prompts lock"),
+        vec!["MCP server `docs` wants to use the model (up to 64 tokens):\n\nhello".to_string()]
+    );
+}
+
+#[tokio::test]
+async fn declined_sampling_request_does_not_reach_sampler() {
+    let sampler = Arc::new(RecordingSampler {
+        calls: StdMutex::new(Vec::new()),
+    });
+    let requests = McpServerRequests::new(Some(sampler.clone() as McpSamplerHandle), Vec::new());
+    let handlers = requests.make_handlers(
+        "docs".to_string(),
+        approval(/*accept*/ false, Arc::new(StdMutex::new(Vec::new()))),
+    );
+    let send_sampling = handlers.send_sampling.expect("sampling handler");
+
+    let error = send_sampling(request_id(), sampling_request())
+        .await
+        .expect_err("declined sampling should fail");
+
+    assert_eq!(error.to_string(), "user rejected the sampling request");
+    assert!(sampler.calls.lock().expect("calls lock").is_empty());
+}
+
+#[test]
+fn sampling_handler_is_absent_without_sampler() {
+    let requests = McpServerRequests::new(/*sampler*/ None, Vec::new());
+    let handlers = requests.make_handlers(
+        "docs".to_string(),
+        approval(/*accept*/ true, Arc::new(StdMutex::new(Vec::new()))),
+    );
+
+    assert!(handlers.send_sampling.is_none());
+    assert!(handlers.list_roots.is_some());
+    assert!(handlers.on_notification.is_some());
+}
@@ -154,6 +154,8 @@ pub enum HookHandlerConfig {
         command_windows: Option<String>,
         #[serde(default, rename = "timeout")]
         timeout_sec: Option<u64>,
+        /// Run the hook in the background. Async hooks never delay or influence
+        /// the event; their completion is reported separately.
         #[serde(default)]
         r#async: bool,
         #[serde(default, rename = "statusMessage")]
@@ -170,9 +172,33 @@ pub enum HookHandlerConfig {
         additional_context_limit: Option<usize>,
     },
     #[serde(rename = "prompt")]
-    Prompt {},
+    Prompt {
+        /// Instructions sent to the model together with the hook event payload. The model
+        /// answers with an allow/deny decision, an optional reason, and optional additional
+        /// context for the main agent.
+        prompt: String,
+        /// Model slug used to evaluate the prompt. Unset uses the session model.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+        #[serde(default, rename = "timeout")]
+        timeout_sec: Option<u64>,
+        #[serde(default, rename = "statusMessage")]
+        status_message: Option<String>,
+    },
     #[serde(rename = "agent")]
-    Agent {},
+    Agent {
+        /// Task for a short-lived, read-only sub-agent. The sub-agent receives the hook event
+        /// payload, may inspect the workspace, and answers with the same allow/deny decision
+        /// contract as `prompt` hooks. Only `Stop` and `PostToolUse` events support agent hooks.
+        prompt: String,
+        /// Model slug used by the sub-agent. Unset uses the session model.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        model: Option<String>,
+        #[serde(default, rename = "timeout")]
+        timeout_sec: Option<u64>,
+        #[serde(default, rename = "statusMessage")]
+        status_message: Option<String>,
+    },
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
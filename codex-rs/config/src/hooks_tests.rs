@@ -288,3 +288,77 @@ fn hook_handler_omits_unset_additional_context_limit() {
 
     assert_eq!(serialized.get("additionalContextLimit"), None);
 }
+
+#[test]
+fn hooks_file_deserializes_prompt_handler() {
+    let parsed: HooksFile = serde_json::from_str(
+        r#"{
+  "hooks": {
+    "PreToolUse": [
+      {
+        "matcher": "^apply_patch$",
+        "hooks": [
+          {
+            "type": "prompt",
+            "prompt": "Deny edits to migrations/ unless the user asked for them.",
+            "model": "gpt-5.4-mini",
+            "timeout": 20
+          }
+        ]
+      }
+    ]
+  }
+}"#,
+    )
+    .expect("hooks.json should deserialize");
+
+    assert_eq!(
+        parsed.hooks.pre_tool_use,
+        vec![MatcherGroup {
+            matcher: Some("^apply_patch$".to_string()),
+            hooks: vec![HookHandlerConfig::Prompt {
+                prompt: "Deny edits to migrations/ unless the user asked for them.".to_string(),
+                model: Some("gpt-5.4-mini".to_string()),
+                timeout_sec: Some(20),
+                status_message: None,
+            }],
+        }]
+    );
+}
+
+#[test]
+fn hooks_file_deserializes_agent_handler() {
+    let parsed: HooksFile = serde_json::from_str(
+        r#"{
+  "hooks": {
+    "Stop": [
+      {
+        "hooks": [
+          {
+            "type": "agent",
+            "prompt": "Verify that the tests the agent claims to have run actually pass.",
+            "timeout": 300,
+            "statusMessage": "verifying tests"
+          }
+        ]
+      }
+    ]
+  }
+}"#,
+    )
+    .expect("hooks.json should deserialize");
+
+    assert_eq!(
+        parsed.hooks.stop,
+        vec![MatcherGroup {
+            matcher: None,
+            hooks: vec![HookHandlerConfig::Agent {
+                prompt: "Verify that the tests the agent claims to have run actually pass."
+                    .to_string(),
+                model: None,
+                timeout_sec: Some(300),
+                status_message: Some("verifying tests".to_string()),
+            }],
+        }]
+    );
+}
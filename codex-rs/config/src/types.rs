@@ -213,6 +213,23 @@ pub enum HistoryPersistence {
     None,
 }
 
+// ===== Redaction configuration =====
+
+/// Masks credentials out of tool output before it reaches the model or the rollout.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct RedactionToml {
+    /// When `true`, shell output, MCP tool results, and file reads are scanned for credentials.
+    /// Defaults to `false`.
+    pub enabled: Option<bool>,
+
+    /// When `false`, long random-looking tokens are not masked. Defaults to `true`.
+    pub entropy: Option<bool>,
+
+    /// Additional regular expressions whose matches are masked.
+    pub patterns: Option<Vec<String>>,
+}
+
 // ===== Analytics configuration =====
 
 /// Analytics settings loaded from config.toml. Fields are optional so we can apply defaults.
@@ -543,6 +560,20 @@ pub enum OtelExporterKind {
         #[serde(default)]
         tls: Option<OtelTlsConfig>,
     },
+    /// Append OTLP-JSON lines to rotating files under `$CODEX_HOME/otel`.
+    OtlpFile {
+        /// Rotate a file once it would grow past this many bytes. Defaults to 10 MiB.
+        #[serde(default)]
+        max_bytes: Option<u64>,
+        /// Number of rotated files to keep per signal. Defaults to 5.
+        #[serde(default)]
+        max_files: Option<usize>,
+    },
+    /// Serve metrics for Prometheus to scrape. Only valid for `metrics_exporter`.
+    Prometheus {
+        /// Address for the `/metrics` endpoint, for example `127.0.0.1:9464`.
+        listen_addr: String,
+    },
 }
 
 /// OTEL settings loaded from config.toml. Fields are optional so we can apply defaults.
@@ -82,12 +82,17 @@ impl HostSkillsSnapshot {
     }
 
     pub async fn read_skill_text(&self, skill: &SkillMetadata) -> io::Result<String> {
-        let fs = self
-            .outcome
-            .file_system_for_skill(skill)
-            .unwrap_or_else(|| Arc::clone(&LOCAL_FS));
         let path = PathUri::from_abs_path(&skill.path_to_skills_md);
-        fs.read_file_text(&path, /*sandbox*/ None).await
+        self.skill_file_system(skill)
+            .read_file_text(&path, /*sandbox*/ None)
+            .await
+    }
+
+    /// Filesystem holding the files of `skill`, which may belong to a remote environment.
+    pub fn skill_file_system(&self, skill: &SkillMetadata) -> Arc<dyn ExecutorFileSystem> {
+        self.outcome
+            .file_system_for_skill(skill)
+            .unwrap_or_else(|| Arc::clone(&LOCAL_FS))
     }
 }
 
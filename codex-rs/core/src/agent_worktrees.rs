@@ -0,0 +1,111 @@
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_git_utils::ISOLATED_WORKTREES_SUBDIR;
+use codex_git_utils::IsolatedWorktree;
+use codex_git_utils::create_isolated_worktree;
+use codex_git_utils::find_isolated_worktree;
+use codex_git_utils::isolated_worktree_diff;
+use codex_protocol::protocol::TurnEnvironmentSelection;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use codex_utils_output_truncation::TruncationPolicy;
+use codex_utils_output_truncation::truncate_text;
+use codex_utils_path_uri::PathUri;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::config::Config;
+use crate::function_tool::FunctionCallError;
+use crate::session::turn_context::TurnContext;
+
+/// Diffs longer than this are truncated in the completion message sent to the parent.
+const COMPLETION_DIFF_MAX_TOKENS: usize = 4_000;
+
+/// Directory holding the isolated worktrees of spawned agents.
+pub(crate) fn agent_worktrees_dir(codex_home: &Path) -> PathBuf {
+    codex_home.join(ISOLATED_WORKTREES_SUBDIR)
+}
+
+/// Creates an isolated worktree of the turn's local workspace for a child agent and points the
+/// child's cwd, workspace roots, and local environment selection at the checkout.
+pub(crate) async fn isolate_spawned_agent(
+    turn: &TurnContext,
+    config: &mut Config,
+    environments: &mut [TurnEnvironmentSelection],
+) -> Result<IsolatedWorktree, FunctionCallError> {
+    let Some(environment) = turn.environments.local() else {
+        return Err(FunctionCallError::RespondToModel(
+            "isolation `worktree` needs a local environment".to_string(),
+        ));
+    };
+    let cwd = environment.cwd().to_abs_path().map_err(|err| {
+        FunctionCallError::RespondToModel(format!("isolation `worktree` is unavailable: {err}"))
+    })?;
+    let root =
+        agent_worktrees_dir(turn.config.codex_home.as_path()).join(Uuid::now_v7().to_string());
+    let worktree = create_isolated_worktree(&root, cwd.as_path())
+        .await
+        .map_err(|err| {
+            FunctionCallError::RespondToModel(format!(
+                "failed to create an isolated worktree: {err:#}"
+            ))
+        })?;
+
+    let map = |path: &AbsolutePathBuf| {
+        worktree
+            .checkout_path(path.as_path())
+            .and_then(|path| AbsolutePathBuf::from_absolute_path(path).ok())
+            .unwrap_or_else(|| path.clone())
+    };
+    let map_uri = |uri: &PathUri| match uri.to_abs_path() {
+        Ok(path) => PathUri::from_abs_path(&map(&path)),
+        Err(_) => uri.clone(),
+    };
+    config.cwd = map(&config.cwd);
+    config.workspace_roots = config.workspace_roots.iter().map(map).collect();
+    for selection in environments
+        .iter_mut()
+        .filter(|selection| selection.environment_id == environment.environment_id)
+    {
+        selection.cwd = map_uri(&selection.cwd);
+        selection.workspace_roots = selection.workspace_roots.iter().map(map_uri).collect();
+    }
+    Ok(worktree)
+}
+
+/// Describes the pending changes of a child agent that ran in an isolated worktree, for the
+/// completion message sent to its parent. Returns `None` when the child was not isolated.
+pub(crate) async fn worktree_completion_report(
+    codex_home: &Path,
+    child_cwd: &Path,
+) -> Option<String> {
+    let worktree = match find_isolated_worktree(&agent_worktrees_dir(codex_home), child_cwd).await {
+        Ok(worktree) => worktree?,
+        Err(err) => {
+            warn!(
+                "failed to look up isolated worktree for {}: {err:#}",
+                child_cwd.display()
+            );
+            return None;
+        }
+    };
+    let diff = match isolated_worktree_diff(&worktree).await {
+        Ok(diff) => diff,
+        Err(err) => {
+            warn!(
+                "failed to diff isolated worktree {}: {err:#}",
+                worktree.root.display()
+            );
+            return None;
+        }
+    };
+    if diff.is_empty() {
+        return Some("The agent made no changes in its isolated worktree.".to_string());
+    }
+    let diff = truncate_text(&diff, TruncationPolicy::Tokens(COMPLETION_DIFF_MAX_TOKENS));
+    Some(format!(
+        "The agent worked in an isolated worktree, so these changes are not in your workspace yet. \
+         Use merge_agent_changes to merge all of them or selected files and hunks, or to discard them.\n\n\
+         ```diff\n{diff}\n```"
+    ))
+}
@@ -0,0 +1,48 @@
+use codex_features::Feature;
+use codex_git_utils::create_workspace_checkpoint;
+use codex_git_utils::get_git_repo_root_with_fs;
+use codex_git_utils::workspace_checkpoint_store;
+use tracing::warn;
+
+use crate::session::session::Session;
+use crate::session::turn_context::TurnContext;
+
+/// Snapshots the turn's local workspace so its file changes can be undone later.
+///
+/// The snapshot covers the enclosing git repository when there is one, and the turn cwd
+/// otherwise. Failures only skip the checkpoint; they never fail the turn.
+///
+/// This runs before the turn can edit anything, so the snapshot never includes the turn's own
+/// changes. Files unchanged since the previous checkpoint are recognized from their size and
+/// modification time and are not read again, which keeps the wait short on later turns.
+pub(crate) async fn record_turn_checkpoint(sess: &Session, turn_context: &TurnContext) {
+    if !turn_context
+        .config
+        .features
+        .enabled(Feature::WorkspaceCheckpoints)
+    {
+        return;
+    }
+    let Some(environment) = turn_context.environments.local() else {
+        return;
+    };
+    let Ok(cwd) = environment.cwd().to_abs_path() else {
+        return;
+    };
+    let workspace =
+        get_git_repo_root_with_fs(environment.environment.get_filesystem().as_ref(), &cwd)
+            .await
+            .unwrap_or(cwd);
+
+    let store =
+        workspace_checkpoint_store(turn_context.config.codex_home.as_path(), sess.thread_id());
+    if let Err(err) =
+        create_workspace_checkpoint(&store, &turn_context.sub_id, workspace.as_path()).await
+    {
+        warn!(
+            "failed to checkpoint {} before turn {}: {err:#}",
+            workspace.display(),
+            turn_context.sub_id
+        );
+    }
+}
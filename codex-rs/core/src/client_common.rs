@@ -33,6 +33,9 @@ pub struct Prompt {
 
     /// Whether the Responses API should strictly validate `output_schema`.
     pub output_schema_strict: bool,
+
+    /// Upper bound on the tokens the model may generate, reasoning included.
+    pub(crate) max_output_tokens: Option<u64>,
 }
 
 impl Default for Prompt {
@@ -44,6 +47,7 @@ impl Default for Prompt {
             base_instructions: BaseInstructions::default(),
             output_schema: None,
             output_schema_strict: true,
+            max_output_tokens: None,
         }
     }
 }
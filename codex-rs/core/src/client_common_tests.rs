@@ -124,6 +124,7 @@ fn serializes_text_verbosity_when_set() {
             verbosity: Some(OpenAiVerbosity::Low),
             format: None,
         }),
+        max_output_tokens: None,
         client_metadata: None,
     };
 
@@ -169,6 +170,7 @@ fn serializes_text_schema_with_strict_format() {
         prompt_cache_key: None,
         service_tier: None,
         text: Some(text_controls),
+        max_output_tokens: None,
         client_metadata: None,
     };
 
@@ -231,11 +233,13 @@ fn omits_text_when_not_set() {
         prompt_cache_key: None,
         service_tier: None,
         text: None,
+        max_output_tokens: None,
         client_metadata: None,
     };
 
     let v = serde_json::to_value(&req).expect("json");
     assert!(v.get("text").is_none());
+    assert!(v.get("max_output_tokens").is_none());
 }
 
 #[test]
@@ -255,6 +259,7 @@ fn serializes_flex_service_tier_when_set() {
         prompt_cache_key: None,
         service_tier: Some(ServiceTier::Flex.to_string()),
         text: None,
+        max_output_tokens: None,
         client_metadata: None,
     };
 
@@ -264,3 +269,32 @@ fn serializes_flex_service_tier_when_set() {
         Some("flex")
     );
 }
+
+#[test]
+fn serializes_max_output_tokens_when_set() {
+    let req = ResponsesApiRequest {
+        model: "gpt-5.4".to_string(),
+        instructions: "i".to_string(),
+        input: vec![],
+        tools: None,
+        tool_choice: "auto".to_string(),
+        parallel_tool_calls: false,
+        reasoning: None,
+        store: false,
+        stream: true,
+        stream_options: None,
+        include: vec![],
+        prompt_cache_key: None,
+        service_tier: None,
+        text: None,
+        max_output_tokens: Some(64),
+        client_metadata: None,
+    };
+
+    let v = serde_json::to_value(&req).expect("json");
+    assert_eq!(
+        v.get("max_output_tokens")
+            .and_then(serde_json::Value::as_u64),
+        Some(64)
+    );
+}
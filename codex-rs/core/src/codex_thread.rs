@@ -1,12 +1,21 @@
 use crate::agent::AgentStatus;
 use crate::config::ConstraintResult;
 use crate::elicitation::ElicitationRegistration;
+use crate::pinned_context::PinContextError;
+use crate::pinned_context::PinnedContextItem;
+use crate::pinned_context::PinnedContextSource;
 use crate::session::SessionIo;
 use crate::session::SessionSettingsUpdate;
 use crate::session::SteerInputError;
 use crate::session::session::Session;
 use codex_exec_server::SelectedCapabilityRootsStatus;
 use codex_features::Feature;
+use codex_git_utils::WorkspaceCheckpoint;
+use codex_git_utils::WorkspaceCheckpointRestore;
+use codex_git_utils::list_workspace_checkpoints;
+use codex_git_utils::preview_workspace_checkpoint_restore;
+use codex_git_utils::restore_workspace_checkpoint;
+use codex_git_utils::workspace_checkpoint_store;
 use codex_otel::SessionTelemetry;
 use codex_protocol::ThreadId;
 use codex_protocol::config_types::ApprovalsReviewer;
@@ -48,6 +57,9 @@ use codex_thread_store::ThreadStoreResult;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_path_uri::LegacyAppPathString;
 use codex_utils_path_uri::PathUri;
+use rmcp::model::GetPromptResult;
+use rmcp::model::JsonObject;
+use rmcp::model::Prompt as McpPrompt;
 use rmcp::model::ReadResourceRequestParams;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
@@ -434,6 +446,61 @@ impl CodexThread {
         self.session.terminate_background_terminal(process_id).await
     }
 
+    /// Pins `text` so it is re-injected verbatim after every compaction.
+    pub async fn pin_context(&self, text: &str) -> Result<PinnedContextItem, PinContextError> {
+        self.session
+            .pin_context(text, PinnedContextSource::User)
+            .await
+    }
+
+    /// Removes the pin with `id`, returning it when it existed.
+    pub async fn unpin_context(&self, id: &str) -> Option<PinnedContextItem> {
+        self.session.unpin_context(id).await
+    }
+
+    /// Lists this thread's pins in the order they were added.
+    pub async fn pinned_context(&self) -> Vec<PinnedContextItem> {
+        self.session.pinned_context_items().await
+    }
+
+    /// Lists the workspace checkpoints taken before this thread's turns, newest first.
+    pub async fn list_checkpoints(&self) -> anyhow::Result<Vec<WorkspaceCheckpoint>> {
+        list_workspace_checkpoints(&self.checkpoint_store_path().await).await
+    }
+
+    /// Reports the files [`Self::restore_checkpoint`] would rewrite and delete for `turn_id`,
+    /// without changing anything.
+    pub async fn preview_checkpoint_restore(
+        &self,
+        turn_id: &str,
+    ) -> anyhow::Result<WorkspaceCheckpointRestore> {
+        preview_workspace_checkpoint_restore(&self.checkpoint_store_path().await, turn_id).await
+    }
+
+    /// Restores workspace files to the checkpoint taken before `turn_id` started.
+    ///
+    /// Thread history is left unchanged; checkpoints for `turn_id` and later turns are dropped.
+    /// The active-turn slot stays locked for the whole restore, so no turn can start and write
+    /// to the workspace while files are being rewritten.
+    pub async fn restore_checkpoint(
+        &self,
+        turn_id: &str,
+    ) -> anyhow::Result<WorkspaceCheckpointRestore> {
+        let store = self.checkpoint_store_path().await;
+        let active_turn = self.session.active_turn.lock().await;
+        if active_turn.is_some() {
+            anyhow::bail!("cannot restore a checkpoint while a turn is running");
+        }
+        let restore = restore_workspace_checkpoint(&store, turn_id).await;
+        drop(active_turn);
+        restore
+    }
+
+    async fn checkpoint_store_path(&self) -> PathBuf {
+        let config = self.config().await;
+        workspace_checkpoint_store(config.codex_home.as_path(), self.session.thread_id())
+    }
+
     pub(crate) fn subscribe_status(&self) -> watch::Receiver<AgentStatus> {
         self.io.agent_status.clone()
     }
@@ -642,6 +709,28 @@ impl CodexThread {
         Ok(serde_json::to_value(result)?)
     }
 
+    /// Lists prompts from every ready MCP server that supports them, keyed by server name.
+    pub async fn list_mcp_prompts(&self) -> HashMap<String, Vec<McpPrompt>> {
+        self.current_mcp_runtime()
+            .await
+            .manager_arc()
+            .list_all_prompts()
+            .await
+    }
+
+    pub async fn get_mcp_prompt(
+        &self,
+        server: &str,
+        name: &str,
+        arguments: Option<JsonObject>,
+    ) -> anyhow::Result<GetPromptResult> {
+        self.current_mcp_runtime()
+            .await
+            .manager_arc()
+            .get_prompt(server, name.to_string(), arguments)
+            .await
+    }
+
     pub async fn call_mcp_tool(
         &self,
         server: &str,
@@ -83,6 +83,7 @@ pub(super) async fn run_remote_compact_v2_attempt(
         base_instructions,
         output_schema: None,
         output_schema_strict: true,
+        max_output_tokens: None,
     };
 
     let window_id = sess.current_window_id().await;
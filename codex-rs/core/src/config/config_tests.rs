@@ -8802,6 +8802,52 @@ async fn trace_exporter_defaults_to_none_when_log_exporter_is_set() -> std::io::
     Ok(())
 }
 
+#[tokio::test]
+async fn load_config_parses_local_otel_exporters() -> std::io::Result<()> {
+    let mut fixture = create_test_fixture()?;
+    fixture.cfg = toml::from_str(
+        r#"
+[otel]
+exporter = { otlp-file = {} }
+trace_exporter = { otlp-file = { max_bytes = 1024, max_files = 2 } }
+metrics_exporter = { prometheus = { listen_addr = "127.0.0.1:9464" } }
+"#,
+    )
+    .expect("TOML deserialization should succeed");
+
+    let config = Config::load_from_base_config_with_overrides(
+        fixture.cfg.clone(),
+        ConfigOverrides {
+            cwd: Some(fixture.cwd_path()),
+            ..Default::default()
+        },
+        fixture.codex_home(),
+    )
+    .await?;
+
+    assert_eq!(
+        config.otel.exporter,
+        OtelExporterKind::OtlpFile {
+            max_bytes: None,
+            max_files: None,
+        }
+    );
+    assert_eq!(
+        config.otel.trace_exporter,
+        OtelExporterKind::OtlpFile {
+            max_bytes: Some(1024),
+            max_files: Some(2),
+        }
+    );
+    assert_eq!(
+        config.otel.metrics_exporter,
+        OtelExporterKind::Prometheus {
+            listen_addr: "127.0.0.1:9464".to_string(),
+        }
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn load_config_applies_otel_trace_metadata() -> std::io::Result<()> {
     let mut fixture = create_test_fixture()?;
@@ -1,5 +1,6 @@
 use crate::config::edit::ConfigEdit;
 use crate::config::edit::ConfigEditsBuilder;
+use crate::pinned_context::DEFAULT_PINNED_CONTEXT_TOKEN_BUDGET;
 use crate::path_utils::normalize_for_native_workdir;
 use crate::unified_exec::DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS;
 use crate::unified_exec::MIN_EMPTY_YIELD_TIME_MS;
@@ -111,6 +112,8 @@ use codex_protocol::permissions::NetworkSandboxPolicy;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::MultiAgentVersion;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_secrets::RedactionOptions;
+use codex_secrets::SecretRedactor;
 pub use codex_thread_store::ExtraConfig;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_absolute_path::AbsolutePathBufGuard;
@@ -868,6 +871,9 @@ pub struct Config {
     /// Token budget applied when storing tool/function outputs in the context manager.
     pub tool_output_token_limit: Option<usize>,
 
+    /// Token budget shared by all items pinned to a thread's context.
+    pub pinned_context_token_budget: usize,
+
     /// Whether multi-agent tools are enabled through `[agents]`.
     pub agents_enabled: bool,
 
@@ -919,6 +925,12 @@ pub struct Config {
     /// Settings that govern if and what will be written to `~/.codex/history.jsonl`.
     pub history: History,
 
+    /// Credential redaction applied to tool output, or `None` when disabled.
+    pub redaction: Option<RedactionOptions>,
+
+    /// Names of stored secrets that shell commands may request as environment variables.
+    pub command_secrets: Vec<String>,
+
     /// When true, session is not persisted on disk. Default to `false`
     pub ephemeral: bool,
 
@@ -3553,6 +3565,22 @@ impl Config {
         let allow_login_shell = cfg.allow_login_shell.unwrap_or(true);
 
         let history = cfg.history.unwrap_or_default();
+        let redaction = cfg
+            .redaction
+            .as_ref()
+            .filter(|redaction| redaction.enabled.unwrap_or(false))
+            .map(|redaction| RedactionOptions {
+                detect_high_entropy: redaction.entropy.unwrap_or(true),
+                patterns: redaction.patterns.clone().unwrap_or_default(),
+            });
+        if let Some(redaction) = &redaction {
+            SecretRedactor::new(redaction).map_err(|err| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!("invalid redaction.patterns entry: {err}"),
+                )
+            })?;
+        }
 
         if multi_agent_v2.max_concurrent_threads_per_session == 0 {
             return Err(std::io::Error::new(
@@ -3950,6 +3978,9 @@ impl Config {
                 })
                 .collect(),
             tool_output_token_limit: cfg.tool_output_token_limit,
+            pinned_context_token_budget: cfg
+                .pinned_context_token_budget
+                .unwrap_or(DEFAULT_PINNED_CONTEXT_TOKEN_BUDGET),
             agents_enabled,
             agent_max_threads,
             agent_default_subagent_model,
@@ -3981,6 +4012,8 @@ impl Config {
             config_lock_toml: None,
             config_layer_stack,
             history,
+            redaction,
+            command_secrets: cfg.command_secrets.unwrap_or_default(),
             ephemeral: ephemeral.unwrap_or_default(),
             extra_config: None,
             bypass_hook_trust,
@@ -263,6 +263,7 @@ pub async fn list_accessible_connectors_from_mcp_tools_with_mcp_manager(
         /*elicitation_reviewer*/ None,
         /*elicitation_lifecycle*/ None,
         codex_mcp::ElicitationRequestRouter::default(),
+        /*sampler*/ None,
     )
     .await;
 
@@ -0,0 +1,45 @@
+use super::ContextualUserFragment;
+use codex_mcp::McpResourceUpdate;
+
+/// Tells the model that MCP resources it read earlier have changed.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct McpResourcesUpdated {
+    updates: Vec<McpResourceUpdate>,
+}
+
+impl McpResourcesUpdated {
+    pub(crate) fn new(updates: Vec<McpResourceUpdate>) -> Self {
+        Self { updates }
+    }
+}
+
+impl ContextualUserFragment for McpResourcesUpdated {
+    fn role(&self) -> &'static str {
+        "developer"
+    }
+
+    fn markers(&self) -> (&'static str, &'static str) {
+        Self::type_markers()
+    }
+
+    fn type_markers() -> (&'static str, &'static str) {
+        ("", "")
+    }
+
+    fn body(&self) -> String {
+        let lines = self
+            .updates
+            .iter()
+            .map(|update| {
+                format!(
+                    "MCP resource `{}` from server `{}` changed since it was read.",
+                    update.uri, update.server
+                )
+            })
+            .collect::<Vec<_>>();
+        format!(
+            "{}\nRead it again with `read_mcp_resource` if it is still relevant.",
+            lines.join("\n")
+        )
+    }
+}
@@ -15,11 +15,13 @@ mod internal_model_context;
 mod legacy_apply_patch_exec_command_warning;
 mod legacy_model_mismatch_warning;
 mod legacy_unified_exec_process_limit_warning;
+mod mcp_resources_updated;
 mod model_switch_instructions;
 mod multi_agent_mode_instructions;
 mod network_rule_saved;
 mod permissions_instructions;
 mod personality_spec_instructions;
+mod pinned_context;
 mod plugin_instructions;
 mod realtime_delegation;
 mod realtime_end_instructions;
@@ -57,12 +59,14 @@ pub use internal_model_context::InvalidInternalContextSource;
 pub(crate) use legacy_apply_patch_exec_command_warning::LegacyApplyPatchExecCommandWarning;
 pub(crate) use legacy_model_mismatch_warning::LegacyModelMismatchWarning;
 pub(crate) use legacy_unified_exec_process_limit_warning::LegacyUnifiedExecProcessLimitWarning;
+pub(crate) use mcp_resources_updated::McpResourcesUpdated;
 pub(crate) use model_switch_instructions::ModelSwitchInstructions;
 pub(crate) use multi_agent_mode_instructions::MultiAgentModeInstructions;
 pub(crate) use network_rule_saved::NetworkRuleSaved;
 pub use permissions_instructions::ApprovalPromptContext;
 pub use permissions_instructions::PermissionsInstructions;
 pub(crate) use personality_spec_instructions::PersonalitySpecInstructions;
+pub(crate) use pinned_context::PinnedContextInstructions;
 pub(crate) use plugin_instructions::PluginInstructions;
 pub(crate) use realtime_delegation::RealtimeDelegation;
 pub(crate) use realtime_delegation::RealtimeDelegationSource;
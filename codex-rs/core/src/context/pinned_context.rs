@@ -0,0 +1,49 @@
+use codex_protocol::protocol::PINNED_CONTEXT_CLOSE_TAG;
+use codex_protocol::protocol::PINNED_CONTEXT_OPEN_TAG;
+
+use super::ContextualUserFragment;
+use crate::pinned_context::PinnedContextItem;
+
+/// Pinned items, rendered verbatim so they survive compaction.
+///
+/// An empty list tells the model that earlier pins no longer apply.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct PinnedContextInstructions {
+    items: Vec<PinnedContextItem>,
+}
+
+impl PinnedContextInstructions {
+    pub(crate) fn new(items: Vec<PinnedContextItem>) -> Self {
+        Self { items }
+    }
+}
+
+impl ContextualUserFragment for PinnedContextInstructions {
+    fn role(&self) -> &'static str {
+        "developer"
+    }
+
+    fn markers(&self) -> (&'static str, &'static str) {
+        Self::type_markers()
+    }
+
+    fn type_markers() -> (&'static str, &'static str) {
+        (PINNED_CONTEXT_OPEN_TAG, PINNED_CONTEXT_CLOSE_TAG)
+    }
+
+    fn body(&self) -> String {
+        if self.items.is_empty() {
+            return "\nAll previously pinned items were unpinned and no longer apply.\n".to_string();
+        }
+        let mut body = "\nThese items were pinned so they stay in context for the rest of the thread, including after compaction. Treat them as standing facts. This list replaces any previously pinned items.\n".to_string();
+        for item in &self.items {
+            body.push_str(&format!(
+                "\n<pin id=\"{}\" source=\"{}\">\n{}\n</pin>\n",
+                item.id,
+                item.source.as_str(),
+                item.text
+            ));
+        }
+        body
+    }
+}
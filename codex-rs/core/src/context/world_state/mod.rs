@@ -4,6 +4,7 @@ mod collaboration_mode;
 mod environment;
 mod environments_instructions;
 mod permissions;
+mod pinned_context;
 mod plugins_instructions;
 mod realtime;
 #[cfg(test)]
@@ -31,6 +32,7 @@ pub(crate) use collaboration_mode::CollaborationModeState;
 pub(crate) use environment::EnvironmentsState;
 pub(crate) use environments_instructions::EnvironmentsInstructionsState;
 pub(crate) use permissions::PermissionsState;
+pub(crate) use pinned_context::PinnedContextState;
 pub(crate) use plugins_instructions::PluginsInstructionsState;
 pub(crate) use realtime::RealtimeState;
 
@@ -0,0 +1,54 @@
+use super::PreviousSectionState;
+use super::WorldStateSection;
+use crate::context::ContextualUserFragment;
+use crate::context::PinnedContextInstructions;
+use crate::pinned_context::PinnedContextItem;
+
+/// The items currently pinned to the thread.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct PinnedContextState {
+    items: Vec<PinnedContextItem>,
+}
+
+impl PinnedContextState {
+    pub(crate) fn new(items: Vec<PinnedContextItem>) -> Self {
+        Self { items }
+    }
+}
+
+impl WorldStateSection for PinnedContextState {
+    const ID: &'static str = "pinned_context";
+    type Snapshot = Vec<PinnedContextItem>;
+
+    fn snapshot(&self) -> Self::Snapshot {
+        self.items.clone()
+    }
+
+    fn has_retained_fragment_matcher() -> bool {
+        true
+    }
+
+    fn matches_retained_fragment(role: &str, text: &str) -> bool {
+        role == "developer" && PinnedContextInstructions::matches_text(text)
+    }
+
+    fn render_diff(
+        &self,
+        previous: PreviousSectionState<'_, Self::Snapshot>,
+    ) -> Option<Box<dyn ContextualUserFragment>> {
+        let previous_has_pins = match previous {
+            PreviousSectionState::Known(previous) if previous == &self.items => return None,
+            PreviousSectionState::Known(previous) => !previous.is_empty(),
+            PreviousSectionState::Unknown => true,
+            PreviousSectionState::Absent => false,
+        };
+        if self.items.is_empty() && !previous_has_pins {
+            return None;
+        }
+        Some(Box::new(PinnedContextInstructions::new(self.items.clone())))
+    }
+}
+
+#[cfg(test)]
+#[path = "pinned_context_tests.rs"]
+mod tests;
@@ -0,0 +1,70 @@
+use super::*;
+use crate::context::world_state::WorldState;
+use crate::pinned_context::PinnedContextSource;
+use codex_protocol::models::ResponseItem;
+use pretty_assertions::assert_eq;
+
+fn pin(id: &str, text: &str) -> PinnedContextItem {
+    PinnedContextItem {
+        id: id.to_string(),
+        text: text.to_string(),
+        source: PinnedContextSource::User,
+        tokens: 1,
+    }
+}
+
+#[test]
+fn renders_pins_verbatim_and_only_when_changed() {
+    let pins = vec![pin("pin-1", "The prod DB is read-only.\nNever run migrations.")];
+    let state = PinnedContextState::new(pins.clone());
+
+    let rendered = state
+        .render_diff(PreviousSectionState::Absent)
+        .expect("pins render")
+        .render();
+    assert_eq!(
+        rendered,
+        "<pinned_context>\nThese items were pinned so they stay in context for the rest of the thread, including after compaction. Treat them as standing facts. This list replaces any previously pinned items.\n\n<pin id=\"pin-1\" source=\"user\">\nThe prod DB is read-only.\nNever run migrations.\n</pin>\n</pinned_context>"
+    );
+    assert!(
+        state
+            .render_diff(PreviousSectionState::Known(&pins))
+            .is_none()
+    );
+}
+
+#[test]
+fn unpinning_everything_tells_the_model_once() {
+    let empty = PinnedContextState::default();
+
+    assert!(empty.render_diff(PreviousSectionState::Absent).is_none());
+    assert!(empty.render_diff(PreviousSectionState::Known(&Vec::new())).is_none());
+    let rendered = empty
+        .render_diff(PreviousSectionState::Known(&vec![pin("pin-1", "old")]))
+        .expect("removal renders")
+        .render();
+    assert_eq!(
+        rendered,
+        "<pinned_context>\nAll previously pinned items were unpinned and no longer apply.\n</pinned_context>"
+    );
+}
+
+#[test]
+fn pins_are_reinjected_when_missing_from_retained_history() {
+    let mut world_state = WorldState::default();
+    world_state.add_section(PinnedContextState::new(vec![pin("pin-1", "keep me")]));
+    let snapshot = world_state.snapshot();
+    let retained: ResponseItem = ContextualUserFragment::into(PinnedContextInstructions::new(
+        vec![pin("pin-1", "keep me")],
+    ));
+
+    assert_eq!(
+        world_state.render_history_diff(Some(&snapshot), &[]).len(),
+        1
+    );
+    assert!(
+        world_state
+            .render_history_diff(Some(&snapshot), &[retained])
+            .is_empty()
+    );
+}
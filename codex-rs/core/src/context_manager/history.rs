@@ -15,8 +15,11 @@ use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::FunctionCallOutputContentItem;
 use codex_protocol::models::FunctionCallOutputPayload;
 use codex_protocol::models::ImageDetail;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ReasoningItemReasoningSummary;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::openai_models::InputModality;
+use codex_protocol::openai_models::TokenizerEncoding;
 use codex_protocol::protocol::InterAgentCommunication;
 use codex_protocol::protocol::TokenUsage;
 use codex_protocol::protocol::TokenUsageInfo;
@@ -30,6 +33,7 @@ use codex_utils_output_truncation::approx_token_count;
 use codex_utils_output_truncation::approx_tokens_from_byte_count_i64;
 use codex_utils_output_truncation::truncate_function_output_items_with_policy;
 use codex_utils_output_truncation::truncate_text;
+use codex_utils_tokenizer::Tokenizer;
 use std::num::NonZeroUsize;
 use std::ops::Deref;
 use std::sync::Arc;
@@ -41,6 +45,9 @@ pub(crate) struct ContextManager {
     /// The oldest items are at the beginning of the vector. Snapshots share the vector until a
     /// caller needs to mutate it, avoiding deep copies for read-only history consumers.
     items: Arc<Vec<ResponseItem>>,
+    /// Estimated tokens for each entry of `items`, counted when the item is recorded so that
+    /// usage estimates never re-tokenize the whole history.
+    item_tokens: Arc<Vec<i64>>,
     /// Bumped whenever history is rewritten, such as compaction or rollback.
     history_version: u64,
     token_info: Option<TokenUsageInfo>,
@@ -57,21 +64,31 @@ pub(crate) struct ContextManager {
     reference_context_item: Option<TurnContextItem>,
     /// World state most recently appended to model-visible history.
     world_state_baseline: Option<WorldStateSnapshot>,
+    /// Tokenizer for the current model. When `None`, token counts use the
+    /// byte-length heuristics.
+    tokenizer: Option<Tokenizer>,
 }
 
 impl ContextManager {
     pub(crate) fn new() -> Self {
         Self {
             items: Arc::new(Vec::new()),
+            item_tokens: Arc::new(Vec::new()),
             history_version: 0,
             token_info: TokenUsageInfo::new_or_append(
                 &None, &None, /*model_context_window*/ None,
             ),
             reference_context_item: None,
             world_state_baseline: None,
+            tokenizer: None,
         }
     }
 
+    pub(crate) fn set_tokenizer(&mut self, tokenizer: Option<Tokenizer>) {
+        self.tokenizer = tokenizer;
+        self.recount_item_tokens();
+    }
+
     pub(crate) fn token_info(&self) -> Option<TokenUsageInfo> {
         self.token_info.clone()
     }
@@ -133,7 +150,9 @@ impl ContextManager {
             }
 
             let processed = Self::process_item(item_ref, policy);
+            let tokens = self.estimate_item_tokens(&processed);
             Arc::make_mut(&mut self.items).push(processed);
+            Arc::make_mut(&mut self.item_tokens).push(tokens);
         }
     }
 
@@ -159,8 +178,8 @@ impl ContextManager {
         self.history_version
     }
 
-    // Estimate token usage using byte-based heuristics from the truncation helpers.
-    // This is a coarse lower bound, not a tokenizer-accurate count.
+    // Estimate token usage with the model's tokenizer, or with byte-based heuristics from the
+    // truncation helpers when the model has none. The heuristic is a coarse lower bound.
     pub(crate) fn estimate_token_count(&self, turn_context: &TurnContext) -> Option<i64> {
         let model_info = &turn_context.model_info;
         let personality = turn_context.personality.or(turn_context.config.personality);
@@ -174,14 +193,9 @@ impl ContextManager {
         &self,
         base_instructions: &BaseInstructions,
     ) -> Option<i64> {
-        let base_tokens =
-            i64::try_from(approx_token_count(&base_instructions.text)).unwrap_or(i64::MAX);
+        let base_tokens = self.estimate_text_tokens(&base_instructions.text);
 
-        let items_tokens = self
-            .items
-            .iter()
-            .map(estimate_item_token_count)
-            .fold(0i64, i64::saturating_add);
+        let items_tokens = sum_tokens(&self.item_tokens);
 
         Some(base_tokens.saturating_add(items_tokens))
     }
@@ -197,11 +211,13 @@ impl ContextManager {
             // running a full normalization pass.
             normalize::remove_corresponding_for(items, &removed);
             self.world_state_baseline = None;
+            self.recount_item_tokens();
         }
     }
 
     pub(crate) fn replace(&mut self, items: Vec<ResponseItem>) {
         self.items = Arc::new(items);
+        self.recount_item_tokens();
         self.history_version = self.history_version.saturating_add(1);
         self.world_state_baseline = None;
     }
@@ -267,8 +283,9 @@ impl ContextManager {
 
         self.items
             .iter()
+            .zip(self.item_tokens.iter())
             .take(last_user_index)
-            .filter(|item| {
+            .filter(|(item, _)| {
                 matches!(
                     item,
                     ResponseItem::Reasoning {
@@ -277,19 +294,43 @@ impl ContextManager {
                     }
                 )
             })
-            .map(estimate_item_token_count)
+            .map(|(_, tokens)| *tokens)
             .fold(0i64, i64::saturating_add)
     }
 
-    // These are local items added after the most recent model-emitted item.
+    fn estimate_text_tokens(&self, text: &str) -> i64 {
+        match &self.tokenizer {
+            Some(tokenizer) => count_tokens_cached(tokenizer, text),
+            None => i64::try_from(approx_token_count(text)).unwrap_or(i64::MAX),
+        }
+    }
+
+    fn estimate_item_tokens(&self, item: &ResponseItem) -> i64 {
+        match &self.tokenizer {
+            Some(tokenizer) => estimate_item_token_count_with_tokenizer(item, tokenizer),
+            None => estimate_item_token_count(item),
+        }
+    }
+
+    /// Re-estimates every item after history is rewritten or the tokenizer changes.
+    fn recount_item_tokens(&mut self) {
+        self.item_tokens = Arc::new(
+            self.items
+                .iter()
+                .map(|item| self.estimate_item_tokens(item))
+                .collect(),
+        );
+    }
+
+    // Token counts of the local items added after the most recent model-emitted item.
     // They are not reflected in `last_token_usage.total_tokens`.
-    fn items_after_last_model_generated_item(&self) -> &[ResponseItem] {
+    fn item_tokens_after_last_model_generated_item(&self) -> &[i64] {
         let start = self
             .items
             .iter()
             .rposition(is_model_generated_item)
             .map_or(self.items.len(), |index| index.saturating_add(1));
-        &self.items[start..]
+        &self.item_tokens[start..]
     }
 
     /// When true, the server already accounted for past reasoning tokens and
@@ -300,11 +341,8 @@ impl ContextManager {
             .as_ref()
             .map(|info| info.last_token_usage.total_tokens)
             .unwrap_or(0);
-        let items_after_last_model_generated_tokens = self
-            .items_after_last_model_generated_item()
-            .iter()
-            .map(estimate_item_token_count)
-            .fold(0i64, i64::saturating_add);
+        let items_after_last_model_generated_tokens =
+            sum_tokens(self.item_tokens_after_last_model_generated_item());
         if server_reasoning_included {
             last_tokens.saturating_add(items_after_last_model_generated_tokens)
         } else {
@@ -315,10 +353,7 @@ impl ContextManager {
     }
 
     pub(crate) fn estimated_tokens_after_last_model_generated_item(&self) -> i64 {
-        self.items_after_last_model_generated_item()
-            .iter()
-            .map(estimate_item_token_count)
-            .fold(0i64, i64::saturating_add)
+        sum_tokens(self.item_tokens_after_last_model_generated_item())
     }
 
     /// This function enforces a couple of invariants on the in-memory history:
@@ -456,6 +491,10 @@ pub(crate) fn truncate_function_output_payload(
 /// API messages include every non-system item (user/assistant messages, reasoning,
 /// tool calls, tool outputs, shell calls, web-search calls, and image-generation
 /// calls).
+fn sum_tokens(tokens: &[i64]) -> i64 {
+    tokens.iter().copied().fold(0i64, i64::saturating_add)
+}
+
 fn is_api_message(message: &ResponseItem) -> bool {
     match message {
         ResponseItem::Message { role, .. } => role.as_str() != "system",
@@ -499,6 +538,179 @@ pub(crate) fn estimate_item_token_count(item: &ResponseItem) -> i64 {
     approx_tokens_from_byte_count_i64(model_visible_bytes)
 }
 
+/// Tokenizer-backed variant of [`estimate_item_token_count`].
+///
+/// Only the text the model reads is tokenized: message text, reasoning text, tool call names and
+/// arguments, and tool output text. Images, audio, and encrypted tool output keep their
+/// per-modality heuristics. Items without such text fields are tokenized as serialized JSON with
+/// inline base64 payloads removed, and items that are only an encrypted blob fall back to the
+/// heuristic entirely.
+pub(crate) fn estimate_item_token_count_with_tokenizer(
+    item: &ResponseItem,
+    tokenizer: &Tokenizer,
+) -> i64 {
+    match item {
+        ResponseItem::Reasoning {
+            encrypted_content: Some(_),
+            ..
+        }
+        | ResponseItem::Compaction { .. }
+        | ResponseItem::ContextCompaction {
+            encrypted_content: Some(_),
+            ..
+        } => return estimate_item_token_count(item),
+        _ => {}
+    }
+    let text_tokens = match model_visible_texts(item) {
+        Some(texts) => texts
+            .into_iter()
+            .map(|text| count_tokens_cached(tokenizer, text))
+            .fold(0i64, i64::saturating_add),
+        None => {
+            let Ok(mut serialized) = serde_json::to_string(item) else {
+                return 0;
+            };
+            for payload in inline_payloads(item) {
+                serialized = serialized.replacen(payload, "", 1);
+            }
+            count_tokens_cached(tokenizer, &serialized)
+        }
+    };
+    let (_, image_replacement_bytes) = image_data_url_estimate_adjustment(item);
+    let (_, audio_replacement_bytes) = audio_data_url_estimate_adjustment(item);
+    let (_, encrypted_replacement_bytes) = encrypted_function_output_estimate_adjustment(item);
+    let replacement_bytes = image_replacement_bytes
+        .saturating_add(audio_replacement_bytes)
+        .saturating_add(encrypted_replacement_bytes);
+    text_tokens.saturating_add(approx_tokens_from_byte_count_i64(replacement_bytes))
+}
+
+/// Text fields of `item` that reach the model as tokens, or `None` when the item has no
+/// plain-text form to count.
+fn model_visible_texts(item: &ResponseItem) -> Option<Vec<&str>> {
+    match item {
+        ResponseItem::Message { content, .. } => Some(
+            content
+                .iter()
+                .filter_map(|content_item| match content_item {
+                    ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                        Some(text.as_str())
+                    }
+                    ContentItem::InputImage { .. } | ContentItem::InputAudio { .. } => None,
+                })
+                .collect(),
+        ),
+        ResponseItem::Reasoning {
+            summary, content, ..
+        } => Some(
+            summary
+                .iter()
+                .map(|summary_item| match summary_item {
+                    ReasoningItemReasoningSummary::SummaryText { text } => text.as_str(),
+                })
+                .chain(
+                    content
+                        .iter()
+                        .flatten()
+                        .map(|content_item| match content_item {
+                            ReasoningItemContent::ReasoningText { text }
+                            | ReasoningItemContent::Text { text } => text.as_str(),
+                        }),
+                )
+                .collect(),
+        ),
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => Some(vec![name.as_str(), arguments.as_str()]),
+        ResponseItem::CustomToolCall { name, input, .. } => {
+            Some(vec![name.as_str(), input.as_str()])
+        }
+        ResponseItem::FunctionCallOutput { output, .. }
+        | ResponseItem::CustomToolCallOutput { output, .. } => match &output.body {
+            FunctionCallOutputBody::Text(text) => Some(vec![text.as_str()]),
+            FunctionCallOutputBody::ContentItems(items) => Some(
+                items
+                    .iter()
+                    .filter_map(|content_item| match content_item {
+                        FunctionCallOutputContentItem::InputText { text } => Some(text.as_str()),
+                        // Only `function_call_output` encrypted content has its own heuristic.
+                        FunctionCallOutputContentItem::EncryptedContent { encrypted_content }
+                            if matches!(item, ResponseItem::CustomToolCallOutput { .. }) =>
+                        {
+                            Some(encrypted_content.as_str())
+                        }
+                        FunctionCallOutputContentItem::InputImage { .. }
+                        | FunctionCallOutputContentItem::InputAudio { .. }
+                        | FunctionCallOutputContentItem::EncryptedContent { .. } => None,
+                    })
+                    .collect(),
+            ),
+        },
+        _ => None,
+    }
+}
+
+const TOKEN_COUNT_CACHE_SIZE: usize = 1024;
+
+/// Tokenizing is much slower than the byte heuristic, and history estimates re-count the same
+/// items every turn, so counts are memoized by content digest.
+static TOKEN_COUNT_CACHE: LazyLock<BlockingLruCache<(TokenizerEncoding, [u8; 20]), i64>> =
+    LazyLock::new(|| {
+        BlockingLruCache::new(
+            NonZeroUsize::new(TOKEN_COUNT_CACHE_SIZE).unwrap_or(NonZeroUsize::MIN),
+        )
+    });
+
+fn count_tokens_cached(tokenizer: &Tokenizer, text: &str) -> i64 {
+    let key = (tokenizer.encoding(), sha1_digest(text.as_bytes()));
+    TOKEN_COUNT_CACHE.get_or_insert_with(key, || {
+        i64::try_from(tokenizer.count_tokens(text)).unwrap_or(i64::MAX)
+    })
+}
+
+/// Inline payloads whose cost is estimated per modality rather than by tokenizing the base64.
+fn inline_payloads(item: &ResponseItem) -> Vec<&str> {
+    let mut payloads = Vec::new();
+    match item {
+        ResponseItem::Message { content, .. } => {
+            for content_item in content {
+                match content_item {
+                    ContentItem::InputImage { image_url, .. } => {
+                        payloads.extend(parse_base64_image_data_url(image_url));
+                    }
+                    ContentItem::InputAudio { audio_url } => {
+                        payloads.extend(parse_base64_audio_data_url(audio_url));
+                    }
+                    _ => {}
+                }
+            }
+        }
+        ResponseItem::FunctionCallOutput { output, .. }
+        | ResponseItem::CustomToolCallOutput { output, .. } => {
+            if let FunctionCallOutputBody::ContentItems(items) = &output.body {
+                for content_item in items {
+                    match content_item {
+                        FunctionCallOutputContentItem::InputImage { image_url, .. } => {
+                            payloads.extend(parse_base64_image_data_url(image_url));
+                        }
+                        FunctionCallOutputContentItem::InputAudio { audio_url } => {
+                            payloads.extend(parse_base64_audio_data_url(audio_url));
+                        }
+                        FunctionCallOutputContentItem::EncryptedContent { encrypted_content }
+                            if matches!(item, ResponseItem::FunctionCallOutput { .. }) =>
+                        {
+                            payloads.push(encrypted_content);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        _ => {}
+    }
+    payloads
+}
+
 /// Approximate model-visible byte cost for one image input.
 ///
 /// The estimator later converts bytes to tokens using a 4-bytes/token heuristic
@@ -20,6 +20,7 @@ use codex_protocol::models::LocalShellStatus;
 use codex_protocol::models::ReasoningItemContent;
 use codex_protocol::models::ReasoningItemReasoningSummary;
 use codex_protocol::openai_models::InputModality;
+use codex_protocol::openai_models::TokenizerEncoding;
 use codex_protocol::openai_models::default_input_modalities;
 use codex_protocol::protocol::APPS_INSTRUCTIONS_OPEN_TAG;
 use codex_protocol::protocol::AskForApproval;
@@ -405,11 +406,7 @@ fn items_after_last_model_generated_tokens_include_user_and_tool_output() {
     );
 
     assert_eq!(
-        history
-            .items_after_last_model_generated_item()
-            .iter()
-            .map(estimate_item_token_count)
-            .fold(0i64, i64::saturating_add),
+        history.estimated_tokens_after_last_model_generated_item(),
         expected_tokens
     );
 }
@@ -419,12 +416,51 @@ fn items_after_last_model_generated_tokens_are_zero_without_model_generated_item
     let history = create_history_with_items(vec![user_msg("no model output yet")]);
 
     assert_eq!(
-        history
-            .items_after_last_model_generated_item()
+        history.estimated_tokens_after_last_model_generated_item(),
+        0
+    );
+}
+
+#[test]
+fn item_token_counts_follow_history_rewrites() {
+    let mut history = create_history_with_items(vec![
+        assistant_msg("already counted by API"),
+        user_msg("first user message"),
+        user_msg("second user message"),
+    ]);
+    let tail_tokens = |items: &[ResponseItem]| {
+        items
             .iter()
             .map(estimate_item_token_count)
-            .fold(0i64, i64::saturating_add),
-        0
+            .fold(0i64, i64::saturating_add)
+    };
+    assert_eq!(
+        history.item_tokens.len(),
+        history.raw_items().len(),
+        "every recorded item has a cached count"
+    );
+    assert_eq!(
+        history.estimated_tokens_after_last_model_generated_item(),
+        tail_tokens(&[
+            user_msg("first user message"),
+            user_msg("second user message")
+        ])
+    );
+
+    history.replace(vec![
+        assistant_msg("summary"),
+        user_msg("only user message"),
+    ]);
+    assert_eq!(
+        history.estimated_tokens_after_last_model_generated_item(),
+        tail_tokens(&[user_msg("only user message")])
+    );
+
+    history.remove_first_item();
+    assert_eq!(history.item_tokens.len(), history.raw_items().len());
+    assert_eq!(
+        history.estimated_tokens_after_last_model_generated_item(),
+        tail_tokens(&[user_msg("only user message")])
     );
 }
 
@@ -842,6 +878,96 @@ fn estimate_token_count_with_base_instructions_uses_provided_text() {
     assert_eq!(long_estimate - short_estimate, expected_delta);
 }
 
+#[test]
+fn estimate_token_count_uses_tokenizer_when_set() {
+    let tokenizer = Tokenizer::new(TokenizerEncoding::O200kBase);
+    let mut history = create_history_with_items(vec![assistant_msg("hello from history")]);
+    history.set_tokenizer(Some(tokenizer));
+    let short_base = BaseInstructions {
+        text: "short".to_string(),
+    };
+    let long_base = BaseInstructions {
+        text: "the quick brown fox jumps over the lazy dog. ".repeat(50),
+    };
+
+    let short_estimate = history
+        .estimate_token_count_with_base_instructions(&short_base)
+        .expect("token estimate");
+    let long_estimate = history
+        .estimate_token_count_with_base_instructions(&long_base)
+        .expect("token estimate");
+
+    let expected_delta = i64::try_from(tokenizer.count_tokens(&long_base.text))
+        .expect("fits in i64")
+        - i64::try_from(tokenizer.count_tokens(&short_base.text)).expect("fits in i64");
+    assert_eq!(long_estimate - short_estimate, expected_delta);
+}
+
+#[test]
+fn tokenizer_estimate_excludes_inline_image_payload() {
+    let tokenizer = Tokenizer::new(TokenizerEncoding::O200kBase);
+    let image_msg = |image_url: String| ResponseItem::Message {
+        id: None,
+        role: "user".to_string(),
+        content: vec![ContentItem::InputImage {
+            image_url,
+            detail: None,
+        }],
+        phase: None,
+        internal_chat_message_metadata_passthrough: None,
+    };
+
+    let large = estimate_item_token_count_with_tokenizer(
+        &image_msg(format!("data:image/png;base64,{}", "A".repeat(100_000))),
+        &tokenizer,
+    );
+    let empty = estimate_item_token_count_with_tokenizer(
+        &image_msg("data:image/png;base64,".to_string()),
+        &tokenizer,
+    );
+
+    assert_eq!(large, empty);
+}
+
+#[test]
+fn tokenizer_estimate_counts_only_model_visible_text() {
+    let tokenizer = Tokenizer::new(TokenizerEncoding::O200kBase);
+    let count = |text: &str| i64::try_from(tokenizer.count_tokens(text)).expect("fits in i64");
+    let arguments = r#"{"cmd":"cargo test -p codex-core","yield_time_ms":1000}"#;
+    let output = "test result: ok. 12 passed; 0 failed";
+
+    assert_eq!(
+        estimate_item_token_count_with_tokenizer(&assistant_msg("hello from history"), &tokenizer),
+        count("hello from history")
+    );
+    assert_eq!(
+        estimate_item_token_count_with_tokenizer(
+            &ResponseItem::FunctionCall {
+                id: None,
+                name: "exec_command".to_string(),
+                namespace: None,
+                arguments: arguments.to_string(),
+                call_id: "call-1".to_string(),
+                internal_chat_message_metadata_passthrough: None,
+            },
+            &tokenizer
+        ),
+        count("exec_command") + count(arguments)
+    );
+    assert_eq!(
+        estimate_item_token_count_with_tokenizer(
+            &ResponseItem::FunctionCallOutput {
+                id: None,
+                call_id: "call-1".to_string(),
+                output: FunctionCallOutputPayload::from_text(output.to_string()),
+                internal_chat_message_metadata_passthrough: None,
+            },
+            &tokenizer
+        ),
+        count(output)
+    );
+}
+
 #[test]
 fn remove_first_item_removes_matching_output_for_function_call() {
     let items = vec![
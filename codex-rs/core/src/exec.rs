@@ -7,6 +7,7 @@ use std::io;
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::ExitStatus;
+use std::sync::Arc;
 use std::time::Duration;
 use std::time::Instant;
 
@@ -17,6 +18,8 @@ use tokio::io::BufReader;
 use tokio::process::Child;
 use tokio_util::sync::CancellationToken;
 
+use crate::output_redactor::OutputRedactor;
+use crate::output_redactor::OutputStreamRedactor;
 use crate::sandboxing::ExecOptions;
 use crate::sandboxing::ExecRequest;
 use crate::sandboxing::SandboxPermissions;
@@ -291,6 +294,8 @@ pub struct StdoutStream {
     pub sub_id: String,
     pub call_id: String,
     pub tx_event: Sender<Event>,
+    /// Masks secrets out of the streamed output deltas.
+    pub(crate) output_redactor: Arc<OutputRedactor>,
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -1111,6 +1116,11 @@ async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
     );
     let mut tmp = [0u8; READ_CHUNK_SIZE];
     let mut emitted_deltas: usize = 0;
+    // Deltas go straight to the event channel, so they are redacted here.
+    let mut stream = stream.map(|stream| {
+        let redactor = OutputStreamRedactor::new(Arc::clone(&stream.output_redactor));
+        (stream, redactor)
+    });
 
     loop {
         let n = reader.read(&mut tmp).await?;
@@ -1118,26 +1128,14 @@ async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
             break;
         }
 
-        if let Some(stream) = &stream
+        if let Some((stream, redactor)) = stream.as_mut()
             && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
         {
-            let chunk = tmp[..n].to_vec();
-            let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
-                call_id: stream.call_id.clone(),
-                stream: if is_stderr {
-                    ExecOutputStream::Stderr
-                } else {
-                    ExecOutputStream::Stdout
-                },
-                chunk,
-            });
-            let event = Event {
-                id: stream.sub_id.clone(),
-                msg,
-            };
-            #[allow(clippy::let_unit_value)]
-            let _ = stream.tx_event.send(event).await;
-            emitted_deltas += 1;
+            let chunk = redactor.push(&tmp[..n]);
+            if !chunk.is_empty() {
+                send_output_delta(stream, is_stderr, chunk).await;
+                emitted_deltas += 1;
+            }
         }
 
         if let Some(max_bytes) = max_bytes {
@@ -1147,6 +1145,14 @@ async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
         }
         // Continue reading to EOF to avoid back-pressure
     }
+    if let Some((stream, redactor)) = stream
+        && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL
+    {
+        let chunk = redactor.finish();
+        if !chunk.is_empty() {
+            send_output_delta(&stream, is_stderr, chunk).await;
+        }
+    }
 
     Ok(StreamOutput {
         text: buf,
@@ -1154,6 +1160,24 @@ async fn read_output<R: AsyncRead + Unpin + Send + 'static>(
     })
 }
 
+async fn send_output_delta(stream: &StdoutStream, is_stderr: bool, chunk: Vec<u8>) {
+    let msg = EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
+        call_id: stream.call_id.clone(),
+        stream: if is_stderr {
+            ExecOutputStream::Stderr
+        } else {
+            ExecOutputStream::Stdout
+        },
+        chunk,
+    });
+    let event = Event {
+        id: stream.sub_id.clone(),
+        msg,
+    };
+    #[allow(clippy::let_unit_value)]
+    let _ = stream.tx_event.send(event).await;
+}
+
 #[cfg(unix)]
 fn synthetic_exit_status(code: i32) -> ExitStatus {
     use std::os::unix::process::ExitStatusExt;
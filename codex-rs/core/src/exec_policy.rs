@@ -201,6 +201,34 @@ pub(crate) fn child_uses_parent_exec_policy(parent_config: &Config, child_config
             == child_config.config_layer_stack.requirements().exec_policy
 }
 
+/// Match options for a command that runs in `cwd` under `permission_profile`.
+pub(crate) fn match_options_for_command(
+    cwd: Option<AbsolutePathBuf>,
+    permission_profile: &PermissionProfile,
+) -> MatchOptions {
+    let writable_roots = cwd
+        .as_ref()
+        .map(|cwd| {
+            let file_system_policy = permission_profile.file_system_sandbox_policy();
+            if file_system_policy.has_full_disk_write_access() {
+                // Everything is writable, so the filesystem root covers every path.
+                cwd.ancestors().last().into_iter().collect()
+            } else {
+                file_system_policy
+                    .get_writable_roots_with_cwd(cwd.as_path())
+                    .into_iter()
+                    .map(|writable_root| writable_root.root)
+                    .collect()
+            }
+        })
+        .unwrap_or_default();
+    MatchOptions {
+        resolve_host_executables: true,
+        cwd,
+        writable_roots,
+    }
+}
+
 fn is_policy_match(rule_match: &RuleMatch) -> bool {
     match rule_match {
         RuleMatch::PrefixRuleMatch { .. } => true,
@@ -281,6 +309,8 @@ pub(crate) struct ExecPolicyManager {
 
 pub(crate) struct ExecApprovalRequest<'a> {
     pub(crate) command: &'a [String],
+    /// Working directory the command runs in, used by rules with `path_args`.
+    pub(crate) cwd: Option<AbsolutePathBuf>,
     pub(crate) approval_policy: AskForApproval,
     pub(crate) permission_profile: PermissionProfile,
     pub(crate) windows_sandbox_level: WindowsSandboxLevel,
@@ -315,6 +345,7 @@ impl ExecPolicyManager {
     ) -> ExecApprovalRequirement {
         let ExecApprovalRequest {
             command,
+            cwd,
             approval_policy,
             permission_profile,
             windows_sandbox_level,
@@ -344,9 +375,7 @@ impl ExecPolicyManager {
                 },
             )
         };
-        let match_options = MatchOptions {
-            resolve_host_executables: true,
-        };
+        let match_options = match_options_for_command(cwd, &permission_profile);
         let evaluation = exec_policy.check_multiple_with_options(
             commands.iter(),
             &exec_policy_fallback,
@@ -464,6 +493,7 @@ impl ExecPolicyManager {
         let current_policy = self.current();
         let match_options = MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         };
         let existing_evaluation = current_policy.check_multiple_with_options(
             [&amendment.command],
@@ -1385,6 +1385,7 @@ async fn mixed_rule_and_sandbox_prompt_prioritizes_rule_for_rejection_decision()
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::Granular(GranularApprovalConfig {
                 sandbox_approval: true,
                 rules: true,
@@ -1405,6 +1406,58 @@ async fn mixed_rule_and_sandbox_prompt_prioritizes_rule_for_rejection_decision()
     ));
 }
 
+#[tokio::test]
+async fn path_args_rules_match_only_paths_under_the_command_cwd() {
+    let policy_src = r#"prefix_rule(pattern=["rm"], path_args="cwd")"#;
+    let mut parser = PolicyParser::new();
+    parser
+        .parse("test.rules", policy_src)
+        .expect("parse policy");
+    let manager = ExecPolicyManager::new(Arc::new(parser.build()));
+    let workspace = tempdir().expect("create workspace");
+    let cwd = AbsolutePathBuf::from_absolute_path(workspace.path()).expect("absolute cwd");
+    let requirement_for = |command: Vec<String>| {
+        let manager = &manager;
+        let cwd = cwd.clone();
+        async move {
+            manager
+                .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+                    command: &command,
+                    cwd: Some(cwd),
+                    approval_policy: AskForApproval::OnRequest,
+                    permission_profile: PermissionProfile::read_only(),
+                    windows_sandbox_level: WindowsSandboxLevel::Disabled,
+                    sandbox_permissions: SandboxPermissions::UseDefault,
+                    prefix_rule: None,
+                })
+                .await
+        }
+    };
+
+    let inside = requirement_for(vec![
+        "rm".to_string(),
+        "-f".to_string(),
+        "build/out.txt".to_string(),
+    ])
+    .await;
+    let outside = requirement_for(vec!["rm".to_string(), "../secrets.txt".to_string()]).await;
+
+    assert!(matches!(
+        inside,
+        ExecApprovalRequirement::Skip {
+            bypass_sandbox: true,
+            ..
+        }
+    ));
+    assert!(!matches!(
+        outside,
+        ExecApprovalRequirement::Skip {
+            bypass_sandbox: true,
+            ..
+        }
+    ));
+}
+
 #[tokio::test]
 async fn forced_rm_preserves_rule_rejection_when_granular_rules_are_disabled() {
     let policy_src = r#"prefix_rule(pattern=["git"], decision="prompt")"#;
@@ -1422,6 +1475,7 @@ async fn forced_rm_preserves_rule_rejection_when_granular_rules_are_disabled() {
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::Granular(GranularApprovalConfig {
                 sandbox_approval: true,
                 rules: false,
@@ -1452,6 +1506,7 @@ async fn exec_approval_requirement_falls_back_to_heuristics() {
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::UnlessTrusted,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -1477,6 +1532,7 @@ async fn empty_bash_lc_script_falls_back_to_original_command() {
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::UnlessTrusted,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -1506,6 +1562,7 @@ async fn whitespace_bash_lc_script_falls_back_to_original_command() {
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::UnlessTrusted,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -1535,6 +1592,7 @@ async fn request_rule_uses_prefix_rule() {
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -1567,6 +1625,7 @@ async fn request_rule_falls_back_when_prefix_rule_does_not_approve_all_commands(
     let requirement = manager
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::Disabled,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -1606,6 +1665,7 @@ async fn heuristics_apply_when_other_commands_match_policy() {
         ExecPolicyManager::new(policy)
             .create_exec_approval_requirement_for_command(ExecApprovalRequest {
                 command: &command,
+                cwd: None,
                 approval_policy: AskForApproval::UnlessTrusted,
                 permission_profile: PermissionProfile::Disabled,
                 windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -2084,6 +2144,7 @@ async fn forced_rm_requires_approval_or_specific_rejection_on_all_platforms() {
         policy
             .create_exec_approval_requirement_for_command(ExecApprovalRequest {
                 command: &dangerous_command,
+                cwd: None,
                 approval_policy: AskForApproval::OnRequest,
                 permission_profile: PermissionProfile::read_only(),
                 windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -2105,6 +2166,7 @@ async fn forced_rm_requires_approval_or_specific_rejection_on_all_platforms() {
         policy
             .create_exec_approval_requirement_for_command(ExecApprovalRequest {
                 command: &dangerous_command,
+                cwd: None,
                 approval_policy: AskForApproval::Never,
                 permission_profile: PermissionProfile::read_only(),
                 windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -2163,6 +2225,7 @@ async fn verify_approval_requirement_for_unsafe_powershell_command() {
         policy
             .create_exec_approval_requirement_for_command(ExecApprovalRequest {
                 command: &sneaky_command,
+                cwd: None,
                 approval_policy: AskForApproval::OnRequest,
                 permission_profile: PermissionProfile::read_only(),
                 windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -2255,6 +2318,7 @@ async fn exec_approval_requirement_for_command(
     ExecPolicyManager::new(policy)
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy,
             permission_profile,
             windows_sandbox_level: WindowsSandboxLevel::RestrictedToken,
@@ -0,0 +1,186 @@
+use std::sync::Arc;
+use std::sync::Weak;
+
+use codex_features::Feature;
+use codex_hooks::AgentHookEvaluator;
+use codex_hooks::AgentHookRequest;
+use codex_hooks::AgentHookResponse;
+use codex_protocol::ThreadId;
+use codex_protocol::models::PermissionProfile;
+use codex_protocol::protocol::AskForApproval;
+use codex_protocol::user_input::UserInput;
+use tokio::sync::RwLock;
+use tracing::warn;
+
+use crate::agent::AgentStatus;
+use crate::agent::control::SpawnAgentOptions;
+use crate::agent::exceeds_thread_spawn_depth_limit;
+use crate::agent::next_thread_spawn_depth;
+use crate::agent::status::is_final;
+use crate::config::Config;
+use crate::config::Constrained;
+use crate::session::session::Session;
+use crate::tools::handlers::multi_agents_common::apply_requested_spawn_agent_model_overrides;
+use crate::tools::handlers::multi_agents_common::build_agent_spawn_config;
+use crate::tools::handlers::multi_agents_common::thread_spawn_source;
+
+/// Builds the evaluator used by `agent` hooks.
+///
+/// Like prompt hooks, the evaluator is created before the session exists and
+/// resolves it through a back-reference filled in after construction.
+pub(crate) fn build_agent_hook_evaluator(
+    session: Arc<RwLock<Weak<Session>>>,
+) -> Arc<dyn AgentHookEvaluator> {
+    Arc::new(move |request: AgentHookRequest| {
+        let session = Arc::clone(&session);
+        async move {
+            let Some(session) = session.read().await.upgrade() else {
+                return AgentHookResponse {
+                    thread_id: None,
+                    result: Err("session is no longer running".to_string()),
+                };
+            };
+            run_agent_hook(&session, request).await
+        }
+    })
+}
+
+async fn run_agent_hook(sess: &Session, request: AgentHookRequest) -> AgentHookResponse {
+    let AgentHookRequest {
+        model,
+        input,
+        timeout,
+        ..
+    } = request;
+    let turn = sess.new_default_turn().await;
+    let child_depth = next_thread_spawn_depth(&turn.session_source);
+    if exceeds_thread_spawn_depth_limit(child_depth, turn.config.agent_max_depth) {
+        return AgentHookResponse {
+            thread_id: None,
+            result: Err("agent depth limit reached".to_string()),
+        };
+    }
+
+    let mut config = match build_agent_spawn_config(&sess.get_base_instructions().await, &turn) {
+        Ok(config) => config,
+        Err(err) => {
+            return AgentHookResponse {
+                thread_id: None,
+                result: Err(err.to_string()),
+            };
+        }
+    };
+    if let Err(err) = apply_requested_spawn_agent_model_overrides(
+        sess,
+        &turn,
+        &mut config,
+        model.as_deref(),
+        /*requested_reasoning_effort*/ None,
+    )
+    .await
+    {
+        return AgentHookResponse {
+            thread_id: None,
+            result: Err(err.to_string()),
+        };
+    }
+    if let Err(err) = restrict_agent_hook_config(&mut config) {
+        return AgentHookResponse {
+            thread_id: None,
+            result: Err(err),
+        };
+    }
+    let session_source = match thread_spawn_source(
+        sess.thread_id,
+        &turn.session_source,
+        child_depth,
+        /*agent_role*/ None,
+        /*task_name*/ None,
+    ) {
+        Ok(session_source) => session_source,
+        Err(err) => {
+            return AgentHookResponse {
+                thread_id: None,
+                result: Err(err.to_string()),
+            };
+        }
+    };
+
+    let agent_control = &sess.services.agent_control;
+    let spawned = match Box::pin(agent_control.spawn_agent_with_metadata(
+        config,
+        vec![UserInput::Text {
+            text: input,
+            text_elements: Vec::new(),
+        }],
+        Some(session_source),
+        SpawnAgentOptions {
+            parent_thread_id: Some(sess.thread_id),
+            environments: Some(turn.environments.to_selections()),
+            ..Default::default()
+        },
+    ))
+    .await
+    {
+        Ok(spawned) => spawned,
+        Err(err) => {
+            return AgentHookResponse {
+                thread_id: None,
+                result: Err(format!("failed to spawn sub-agent: {err}")),
+            };
+        }
+    };
+    let thread_id = spawned.thread_id;
+
+    let result = match tokio::time::timeout(timeout, wait_for_final_status(sess, thread_id)).await {
+        Ok(AgentStatus::Completed(Some(message))) => Ok(message),
+        Ok(AgentStatus::Completed(None)) => Err("sub-agent finished without a message".to_string()),
+        Ok(AgentStatus::Errored(err)) => Err(format!("sub-agent failed: {err}")),
+        Ok(status) => Err(format!("sub-agent stopped with status {status:?}")),
+        Err(_) => Err(format!("timed out after {}s", timeout.as_secs())),
+    };
+    // The sub-agent's rollout stays on disk for inspection; only the live thread is released.
+    if let Err(err) = agent_control.close_agent(thread_id).await {
+        warn!("failed to close agent hook sub-agent {thread_id}: {err}");
+    }
+    AgentHookResponse {
+        thread_id: Some(thread_id),
+        result,
+    }
+}
+
+/// Agent hooks inspect the workspace but never change it, never ask the user,
+/// and never fan out into further agents or hooks.
+fn restrict_agent_hook_config(config: &mut Config) -> Result<(), String> {
+    config.permissions.approval_policy = Constrained::allow_only(AskForApproval::Never);
+    config
+        .permissions
+        .set_permission_profile(PermissionProfile::read_only())
+        .map_err(|err| format!("agent hook could not set permission profile: {err}"))?;
+    for feature in [Feature::Collab, Feature::MultiAgentV2, Feature::CodexHooks] {
+        config.features.disable(feature).map_err(|err| {
+            format!(
+                "agent hook could not disable `features.{}`: {err}",
+                feature.key()
+            )
+        })?;
+    }
+    Ok(())
+}
+
+async fn wait_for_final_status(sess: &Session, thread_id: ThreadId) -> AgentStatus {
+    let agent_control = &sess.services.agent_control;
+    let mut status_rx = match agent_control.subscribe_status(thread_id).await {
+        Ok(status_rx) => status_rx,
+        Err(_) => return agent_control.get_status(thread_id).await,
+    };
+    loop {
+        let status = status_rx.borrow().clone();
+        if is_final(&status) {
+            return status;
+        }
+        if status_rx.changed().await.is_err() {
+            return agent_control.get_status(thread_id).await;
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use std::sync::Weak;
+
+use codex_hooks::PromptHookEvaluator;
+use codex_hooks::PromptHookRequest;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_rollout_trace::InferenceTraceContext;
+use futures::prelude::*;
+use tokio::sync::RwLock;
+
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::compact::content_items_to_text;
+use crate::responses_metadata::CodexResponsesRequestKind;
+use crate::session::session::Session;
+
+/// Builds the evaluator used by `prompt` hooks.
+///
+/// Hooks are built before the session exists, so the evaluator holds a
+/// back-reference that is filled in once session construction completes.
+pub(crate) fn build_prompt_hook_evaluator(
+    session: Arc<RwLock<Weak<Session>>>,
+) -> Arc<dyn PromptHookEvaluator> {
+    Arc::new(move |request: PromptHookRequest| {
+        let session = Arc::clone(&session);
+        async move {
+            let Some(session) = session.read().await.upgrade() else {
+                return Err("session is no longer running".to_string());
+            };
+            evaluate_prompt_hook(&session, request).await
+        }
+    })
+}
+
+async fn evaluate_prompt_hook(
+    sess: &Session,
+    request: PromptHookRequest,
+) -> Result<String, String> {
+    let turn_context = sess.new_default_turn().await;
+    let model_info = match request.model.as_deref() {
+        Some(model) if model != turn_context.model_info.slug => {
+            sess.services
+                .models_manager
+                .get_model_info(model, &turn_context.config.to_models_manager_config())
+                .await
+        }
+        _ => turn_context.model_info.clone(),
+    };
+    let prompt = Prompt {
+        input: vec![ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: request.input,
+            }],
+            phase: None,
+            internal_chat_message_metadata_passthrough: None,
+        }],
+        base_instructions: BaseInstructions {
+            text: request.instructions,
+        },
+        output_schema: Some(request.output_schema),
+        output_schema_strict: true,
+        ..Default::default()
+    };
+    let window_id = sess.current_window_id().await;
+    let responses_metadata = turn_context.turn_metadata_state.to_responses_metadata(
+        sess.installation_id.clone(),
+        window_id,
+        CodexResponsesRequestKind::Hook,
+    );
+
+    let mut client_session = sess.services.model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &model_info,
+            &turn_context.session_telemetry,
+            turn_context.reasoning_effort.clone(),
+            turn_context.reasoning_summary,
+            turn_context.config.service_tier.clone(),
+            &responses_metadata,
+            &InferenceTraceContext::disabled(),
+        )
+        .await
+        .map_err(|err| err.to_string())?;
+
+    let mut result = String::new();
+    while let Some(event) = stream.next().await {
+        match event.map_err(|err| err.to_string())? {
+            ResponseEvent::OutputTextDelta(delta) => result.push_str(&delta),
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. }) => {
+                if result.is_empty()
+                    && let Some(text) = content_items_to_text(&content)
+                {
+                    result.push_str(&text);
+                }
+            }
+            ResponseEvent::Completed { .. } => return Ok(result),
+            _ => {}
+        }
+    }
+    Err("stream closed before response.completed".to_string())
+}
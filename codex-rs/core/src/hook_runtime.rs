@@ -1,10 +1,12 @@
 use std::future::Future;
 use std::sync::Arc;
+use std::sync::Weak;
 use std::time::Duration;
 
 use codex_analytics::CompactionTrigger;
 use codex_analytics::HookRunFact;
 use codex_analytics::build_track_events_context;
+use codex_hooks::AsyncHookCompletionSink;
 use codex_hooks::PermissionRequestDecision;
 use codex_hooks::PermissionRequestOutcome;
 use codex_hooks::PermissionRequestRequest;
@@ -21,11 +23,13 @@ use codex_hooks::UserPromptSubmitOutcome;
 use codex_hooks::UserPromptSubmitRequest;
 use codex_otel::HOOK_RUN_DURATION_METRIC;
 use codex_otel::HOOK_RUN_METRIC;
+use codex_otel::SessionTelemetry;
 use codex_protocol::items::TurnItem;
 use codex_protocol::items::UserMessageItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::HookCompletedEvent;
 use codex_protocol::protocol::HookEventName;
@@ -37,6 +41,7 @@ use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::SubAgentSource;
 use codex_thread_store::ReadThreadParams;
 use serde_json::Value;
+use tokio::sync::RwLock;
 use tracing::instrument;
 
 use crate::context::ContextualUserFragment;
@@ -669,22 +674,44 @@ pub(crate) async fn emit_hook_completed_events(
     completed_events: Vec<HookCompletedEvent>,
 ) {
     for completed in completed_events {
-        emit_hook_completed_metrics(turn_context, &completed);
+        emit_hook_completed_metrics(&turn_context.session_telemetry, &completed);
         track_hook_completed_analytics(sess, turn_context, &completed);
         sess.send_event(turn_context, EventMsg::HookCompleted(completed))
             .await;
     }
 }
 
-fn emit_hook_completed_metrics(turn_context: &TurnContext, completed: &HookCompletedEvent) {
+/// Reports `async` hook completions, which arrive after the event that started
+/// the hook has already returned.
+pub(crate) fn build_async_hook_completion_sink(
+    session: Arc<RwLock<Weak<Session>>>,
+) -> Arc<dyn AsyncHookCompletionSink> {
+    Arc::new(move |completed: HookCompletedEvent| {
+        let session = Arc::clone(&session);
+        tokio::spawn(async move {
+            let Some(sess) = session.read().await.upgrade() else {
+                return;
+            };
+            emit_hook_completed_metrics(&sess.services.session_telemetry, &completed);
+            sess.send_event_raw(Event {
+                id: completed.turn_id.clone().unwrap_or_default(),
+                msg: EventMsg::HookCompleted(completed),
+            })
+            .await;
+        });
+    })
+}
+
+fn emit_hook_completed_metrics(
+    session_telemetry: &SessionTelemetry,
+    completed: &HookCompletedEvent,
+) {
     let tags = hook_run_metric_tags(&completed.run);
-    turn_context
-        .session_telemetry
-        .counter(HOOK_RUN_METRIC, /*inc*/ 1, &tags);
+    session_telemetry.counter(HOOK_RUN_METRIC, /*inc*/ 1, &tags);
     if let Some(duration_ms) = completed.run.duration_ms
         && let Ok(duration_ms) = u64::try_from(duration_ms)
     {
-        turn_context.session_telemetry.record_duration(
+        session_telemetry.record_duration(
             HOOK_RUN_DURATION_METRIC,
             Duration::from_millis(duration_ms),
             &tags,
@@ -954,6 +981,7 @@ mod tests {
             completed_at: Some(37),
             duration_ms: Some(27),
             entries: Vec::new(),
+            agent_thread_id: None,
         }
     }
 }
@@ -34,7 +34,9 @@ pub use codex_thread::TryStartTurnIfIdleRejectionReason;
 pub use session::turn_context::TurnContext;
 mod agent;
 mod agent_communication;
+mod agent_worktrees;
 mod attestation;
+mod checkpoints;
 mod codex_delegate;
 mod command_canonicalization;
 pub mod config;
@@ -50,16 +52,25 @@ mod exec_policy;
 #[cfg(test)]
 mod git_info_tests;
 mod guardian;
+mod hook_agent_evaluator;
+mod hook_prompt_evaluator;
 mod hook_runtime;
 mod image_preparation;
 mod installation_id;
 pub(crate) mod mcp;
+mod mcp_sampling;
 mod mcp_skill_dependencies;
 mod mcp_tool_approval_templates;
 mod mcp_tool_exposure;
 mod network_policy_decision;
 pub use mcp::McpManager;
 mod original_image_detail;
+mod output_redactor;
+mod pinned_context;
+pub use pinned_context::DEFAULT_PINNED_CONTEXT_TOKEN_BUDGET;
+pub use pinned_context::PinContextError;
+pub use pinned_context::PinnedContextItem;
+pub use pinned_context::PinnedContextSource;
 pub use codex_mcp::CodexAppsToolsCache;
 pub use codex_mcp::SandboxState;
 mod mcp_openai_file;
@@ -0,0 +1,130 @@
+use std::sync::Arc;
+use std::sync::Weak;
+
+use codex_mcp::McpSampler;
+use codex_mcp::McpSamplerHandle;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_rmcp_client::SamplingMessage;
+use codex_rmcp_client::SamplingRequest;
+use codex_rmcp_client::SamplingResponse;
+use codex_rmcp_client::SamplingRole;
+use codex_rollout_trace::InferenceTraceContext;
+use futures::future::BoxFuture;
+use futures::prelude::*;
+use tokio::sync::RwLock;
+
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::compact::content_items_to_text;
+use crate::responses_metadata::CodexResponsesRequestKind;
+use crate::session::session::Session;
+
+/// Instructions used when a sampling request does not carry a system prompt.
+const DEFAULT_SAMPLING_INSTRUCTIONS: &str =
+    "You are answering a request made by a tool server on the user's behalf.";
+
+/// Builds the sampler that answers MCP `sampling/createMessage` requests with
+/// the session's model provider.
+///
+/// MCP connections are started before the session exists, so the sampler
+/// holds a back-reference that is filled in once session construction completes.
+pub(crate) fn build_mcp_sampler(session: Arc<RwLock<Weak<Session>>>) -> McpSamplerHandle {
+    Arc::new(SessionMcpSampler { session })
+}
+
+struct SessionMcpSampler {
+    session: Arc<RwLock<Weak<Session>>>,
+}
+
+impl McpSampler for SessionMcpSampler {
+    fn create_message(
+        &self,
+        server_name: String,
+        request: SamplingRequest,
+    ) -> BoxFuture<'static, anyhow::Result<SamplingResponse>> {
+        let session = Arc::clone(&self.session);
+        async move {
+            let Some(session) = session.read().await.upgrade() else {
+                anyhow::bail!("session is no longer running");
+            };
+            tracing::debug!("running MCP sampling request for server `{server_name}`");
+            sample(&session, request).await
+        }
+        .boxed()
+    }
+}
+
+async fn sample(sess: &Session, request: SamplingRequest) -> anyhow::Result<SamplingResponse> {
+    let turn_context = sess.new_default_turn().await;
+    let model_info = turn_context.model_info.clone();
+    let prompt = Prompt {
+        input: request.messages.iter().map(sampling_message_item).collect(),
+        base_instructions: BaseInstructions {
+            text: request
+                .system_prompt
+                .unwrap_or_else(|| DEFAULT_SAMPLING_INSTRUCTIONS.to_string()),
+        },
+        max_output_tokens: Some(u64::from(request.max_tokens)),
+        ..Default::default()
+    };
+    let window_id = sess.current_window_id().await;
+    let responses_metadata = turn_context.turn_metadata_state.to_responses_metadata(
+        sess.installation_id.clone(),
+        window_id,
+        CodexResponsesRequestKind::McpSampling,
+    );
+
+    let mut client_session = sess.services.model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &model_info,
+            &turn_context.session_telemetry,
+            turn_context.reasoning_effort.clone(),
+            turn_context.reasoning_summary,
+            turn_context.config.service_tier.clone(),
+            &responses_metadata,
+            &InferenceTraceContext::disabled(),
+        )
+        .await?;
+
+    let mut text = String::new();
+    while let Some(event) = stream.next().await {
+        match event? {
+            ResponseEvent::OutputTextDelta(delta) => text.push_str(&delta),
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. }) => {
+                if text.is_empty()
+                    && let Some(message) = content_items_to_text(&content)
+                {
+                    text.push_str(&message);
+                }
+            }
+            ResponseEvent::Completed { .. } => {
+                return Ok(SamplingResponse {
+                    model: model_info.slug,
+                    text,
+                    stop_reason: Some("endTurn".to_string()),
+                });
+            }
+            _ => {}
+        }
+    }
+    anyhow::bail!("stream closed before response.completed")
+}
+
+fn sampling_message_item(message: &SamplingMessage) -> ResponseItem {
+    let text = message.text();
+    let (role, content) = match message.role {
+        SamplingRole::User => ("user", ContentItem::InputText { text }),
+        SamplingRole::Assistant => ("assistant", ContentItem::OutputText { text }),
+    };
+    ResponseItem::Message {
+        id: None,
+        role: role.to_string(),
+        content: vec![content],
+        phase: None,
+        internal_chat_message_metadata_passthrough: None,
+    }
+}
@@ -915,6 +915,7 @@ async fn notify_mcp_tool_call_started(
         result: None,
         error: None,
         duration: None,
+        redacted: false,
     });
     sess.emit_turn_item_started(turn_context, &item).await;
 }
@@ -959,6 +960,7 @@ async fn notify_mcp_tool_call_completed(
         result,
         error,
         duration: Some(duration),
+        redacted: false,
     });
     sess.emit_turn_item_completed(turn_context, item).await;
 }
@@ -1468,6 +1470,9 @@ fn mcp_tool_approval_decision_from_guardian(decision: ReviewDecision) -> McpTool
         | ReviewDecision::ApprovedExecpolicyAmendment { .. }
         | ReviewDecision::NetworkPolicyAmendment { .. } => McpToolApprovalDecision::Accept,
         ReviewDecision::ApprovedForSession => McpToolApprovalDecision::AcceptForSession,
+        ReviewDecision::ApprovedSelectedChanges { .. } => McpToolApprovalDecision::Decline {
+            message: Some(ReviewDecision::SELECTED_CHANGES_REJECTION.to_string()),
+        },
         ReviewDecision::Denied { rejection } => McpToolApprovalDecision::Decline {
             message: Some(rejection),
         },
@@ -1486,6 +1486,7 @@ async fn host_owned_codex_apps_manager(
         /*elicitation_reviewer*/ None,
         /*elicitation_lifecycle*/ None,
         codex_mcp::ElicitationRequestRouter::default(),
+        /*sampler*/ None,
     )
     .await;
     Arc::new(manager)
@@ -1930,6 +1931,14 @@ fn guardian_review_decision_maps_to_mcp_tool_decision() {
         mcp_tool_approval_decision_from_guardian(ReviewDecision::Abort),
         McpToolApprovalDecision::Decline { message: None }
     );
+    assert_eq!(
+        mcp_tool_approval_decision_from_guardian(ReviewDecision::ApprovedSelectedChanges {
+            changes: Vec::new(),
+        }),
+        McpToolApprovalDecision::Decline {
+            message: Some(ReviewDecision::SELECTED_CHANGES_REJECTION.to_string()),
+        }
+    );
 }
 
 #[test]
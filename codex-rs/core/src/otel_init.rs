@@ -3,6 +3,8 @@ use codex_config::types::OtelExporterKind as Kind;
 use codex_config::types::OtelHttpProtocol as Protocol;
 use codex_features::Feature;
 use codex_login::default_client::originator;
+use codex_otel::DEFAULT_OTLP_FILE_MAX_BYTES;
+use codex_otel::DEFAULT_OTLP_FILE_MAX_FILES;
 use codex_otel::OtelExporter;
 use codex_otel::OtelHttpProtocol;
 use codex_otel::OtelProvider;
@@ -63,6 +65,17 @@ pub fn build_provider(
                 client_private_key: config.client_private_key.clone(),
             }),
         },
+        Kind::OtlpFile {
+            max_bytes,
+            max_files,
+        } => OtelExporter::OtlpFile {
+            directory: config.codex_home.join("otel").to_path_buf(),
+            max_bytes: max_bytes.unwrap_or(DEFAULT_OTLP_FILE_MAX_BYTES),
+            max_files: max_files.unwrap_or(DEFAULT_OTLP_FILE_MAX_FILES),
+        },
+        Kind::Prometheus { listen_addr } => OtelExporter::Prometheus {
+            listen_addr: listen_addr.clone(),
+        },
     };
 
     let exporter = to_otel_exporter(&config.otel.exporter);
@@ -0,0 +1,501 @@
+//! Masks secret values out of tool output before it is recorded, persisted, or sent to the model.
+//!
+//! Two passes run over each piece of output: exact values of the secrets handed to commands, then
+//! the configurable [`SecretRedactor`] (well-known credential shapes, high-entropy tokens, and
+//! user patterns) when `redaction.enabled` is set.
+//!
+//! Streamed command output goes through an [`OutputStreamRedactor`], which holds back the end of a
+//! chunk until the next one shows whether it starts a secret. Everything persisted or sent to the
+//! model is redacted again from the aggregated text, and the command or MCP tool call item and its
+//! end event are flagged as `redacted`.
+
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::sync::RwLock;
+
+use codex_protocol::items::TurnItem;
+use codex_protocol::mcp::CallToolResult;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_secrets::RedactionOptions;
+use codex_secrets::SecretRedactor;
+use tracing::warn;
+
+/// Values shorter than this are not redacted; masking every occurrence of a very short string
+/// would mangle unrelated output without protecting anything.
+const MIN_REDACTED_VALUE_CHARS: usize = 4;
+/// Longest trailing token a stream holds back for the configured patterns; longer runs are
+/// emitted as they arrive.
+const MAX_HELD_TOKEN_BYTES: usize = 256;
+
+/// Session-wide set of secret values that must never reach the model or the rollout.
+#[derive(Default)]
+pub(crate) struct OutputRedactor {
+    secrets: RwLock<Vec<RegisteredSecret>>,
+    patterns: Option<SecretRedactor>,
+}
+
+struct RegisteredSecret {
+    name: String,
+    value: String,
+}
+
+impl OutputRedactor {
+    pub(crate) fn new(options: Option<&RedactionOptions>) -> Self {
+        let patterns = options.and_then(|options| match SecretRedactor::new(options) {
+            Ok(redactor) => Some(redactor),
+            Err(err) => {
+                // Config loading validates the patterns, so this only guards direct callers.
+                warn!("ignoring invalid redaction patterns: {err}");
+                None
+            }
+        });
+        Self {
+            secrets: RwLock::default(),
+            patterns,
+        }
+    }
+
+    /// Registers a secret value so that later output containing it is masked.
+    pub(crate) fn register_secret(&self, name: &str, value: &str) {
+        if value.chars().count() < MIN_REDACTED_VALUE_CHARS {
+            return;
+        }
+        let mut secrets = match self.secrets.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        if secrets.iter().any(|secret| secret.value == value) {
+            return;
+        }
+        secrets.push(RegisteredSecret {
+            name: name.to_string(),
+            value: value.to_string(),
+        });
+        // Longer values first so a secret that contains another is masked as a whole.
+        secrets.sort_by_key(|secret| std::cmp::Reverse(secret.value.len()));
+    }
+
+    /// Replaces every registered secret value in `text` with a marker naming the secret, then
+    /// masks anything the configured patterns flag with a marker naming the rule.
+    pub(crate) fn redact_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let secrets = match self.secrets.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut text = Cow::Borrowed(text);
+        for secret in secrets.iter() {
+            if text.contains(secret.value.as_str()) {
+                text = Cow::Owned(text.replace(secret.value.as_str(), &marker(&secret.name)));
+            }
+        }
+        if let Some(patterns) = &self.patterns {
+            let redacted = match patterns.redact(&text).0 {
+                Cow::Owned(redacted) => Some(redacted),
+                Cow::Borrowed(_) => None,
+            };
+            if let Some(redacted) = redacted {
+                text = Cow::Owned(redacted);
+            }
+        }
+        text
+    }
+
+    /// Byte-level variant of [`Self::redact_text`] for raw output.
+    fn redact_bytes(&self, bytes: &mut Vec<u8>) {
+        let secrets = match self.secrets.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        for secret in secrets.iter() {
+            let value = secret.value.as_bytes();
+            if !bytes.windows(value.len()).any(|window| window == value) {
+                continue;
+            }
+            let marker = marker(&secret.name).into_bytes();
+            let mut redacted = Vec::with_capacity(bytes.len());
+            let mut index = 0;
+            while index < bytes.len() {
+                if bytes[index..].starts_with(value) {
+                    redacted.extend_from_slice(&marker);
+                    index += value.len();
+                } else {
+                    redacted.push(bytes[index]);
+                    index += 1;
+                }
+            }
+            *bytes = redacted;
+        }
+        drop(secrets);
+        // Patterns only apply to output that decodes cleanly; a chunk ending mid-character is
+        // left for the aggregated output.
+        if let Some(patterns) = &self.patterns
+            && let Ok(text) = std::str::from_utf8(bytes)
+            && let (Cow::Owned(redacted), _) = patterns.redact(text)
+        {
+            *bytes = redacted.into_bytes();
+        }
+    }
+
+    /// Redacts the command output carried by an event before it is persisted or delivered.
+    pub(crate) fn redact_event_msg(&self, msg: &mut EventMsg) {
+        if self.is_empty() {
+            return;
+        }
+        // Output deltas arrive already redacted by the `OutputStreamRedactor` of their stream.
+        match msg {
+            EventMsg::ExecCommandEnd(event) => {
+                let mut redacted = false;
+                for text in [
+                    &mut event.stdout,
+                    &mut event.stderr,
+                    &mut event.aggregated_output,
+                    &mut event.formatted_output,
+                ] {
+                    redacted |= self.redact_string(text);
+                }
+                event.redacted |= redacted;
+            }
+            EventMsg::McpToolCallEnd(event) => {
+                event.redacted |= match &mut event.result {
+                    Ok(result) => self.redact_call_tool_result(result),
+                    Err(message) => self.redact_string(message),
+                };
+            }
+            EventMsg::ItemStarted(event) => self.redact_turn_item(&mut event.item),
+            EventMsg::ItemCompleted(event) => self.redact_turn_item(&mut event.item),
+            _ => {}
+        }
+    }
+
+    /// Redacts tool outputs in items about to be recorded in history.
+    pub(crate) fn redact_response_items(&self, items: &mut [ResponseItem]) {
+        if self.is_empty() {
+            return;
+        }
+        for item in items {
+            match item {
+                ResponseItem::FunctionCallOutput { output, .. }
+                | ResponseItem::CustomToolCallOutput { output, .. } => {
+                    self.redact_payload(output);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Redacts rollout items before they are written. The items are only copied when one of them
+    /// contains something to redact.
+    pub(crate) fn redact_rollout_items<'a>(
+        &self,
+        items: &'a [RolloutItem],
+    ) -> Cow<'a, [RolloutItem]> {
+        if self.is_empty() {
+            return Cow::Borrowed(items);
+        }
+        let Some(first) = items
+            .iter()
+            .position(|item| self.rollout_item_needs_redaction(item))
+        else {
+            return Cow::Borrowed(items);
+        };
+        let mut items = items.to_vec();
+        for item in &mut items[first..] {
+            match item {
+                RolloutItem::ResponseItem(item) => {
+                    self.redact_response_items(std::slice::from_mut(item));
+                }
+                RolloutItem::EventMsg(msg) => self.redact_event_msg(msg),
+                _ => {}
+            }
+        }
+        Cow::Owned(items)
+    }
+
+    fn rollout_item_needs_redaction(&self, item: &RolloutItem) -> bool {
+        match item {
+            RolloutItem::ResponseItem(
+                ResponseItem::FunctionCallOutput { output, .. }
+                | ResponseItem::CustomToolCallOutput { output, .. },
+            ) => self.payload_needs_redaction(output),
+            RolloutItem::EventMsg(msg) => self.event_msg_needs_redaction(msg),
+            _ => false,
+        }
+    }
+
+    fn event_msg_needs_redaction(&self, msg: &EventMsg) -> bool {
+        match msg {
+            EventMsg::ExecCommandEnd(event) => [
+                &event.stdout,
+                &event.stderr,
+                &event.aggregated_output,
+                &event.formatted_output,
+            ]
+            .into_iter()
+            .any(|text| self.text_needs_redaction(text)),
+            EventMsg::McpToolCallEnd(event) => match &event.result {
+                Ok(result) => self.call_tool_result_needs_redaction(result),
+                Err(message) => self.text_needs_redaction(message),
+            },
+            EventMsg::ItemStarted(event) => self.turn_item_needs_redaction(&event.item),
+            EventMsg::ItemCompleted(event) => self.turn_item_needs_redaction(&event.item),
+            _ => false,
+        }
+    }
+
+    fn turn_item_needs_redaction(&self, item: &TurnItem) -> bool {
+        match item {
+            TurnItem::CommandExecution(item) => [
+                &item.stdout,
+                &item.stderr,
+                &item.aggregated_output,
+                &item.formatted_output,
+            ]
+            .into_iter()
+            .flatten()
+            .any(|text| self.text_needs_redaction(text)),
+            TurnItem::McpToolCall(item) => {
+                item.result
+                    .as_ref()
+                    .is_some_and(|result| self.call_tool_result_needs_redaction(result))
+                    || item
+                        .error
+                        .as_ref()
+                        .is_some_and(|error| self.text_needs_redaction(&error.message))
+            }
+            _ => false,
+        }
+    }
+
+    fn call_tool_result_needs_redaction(&self, result: &CallToolResult) -> bool {
+        result
+            .content
+            .iter()
+            .chain(result.structured_content.as_ref())
+            .any(|value| self.json_needs_redaction(value))
+    }
+
+    fn json_needs_redaction(&self, value: &serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::String(text) => self.text_needs_redaction(text),
+            serde_json::Value::Array(values) => {
+                values.iter().any(|value| self.json_needs_redaction(value))
+            }
+            serde_json::Value::Object(map) => {
+                map.values().any(|value| self.json_needs_redaction(value))
+            }
+            serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+                false
+            }
+        }
+    }
+
+    fn payload_needs_redaction(&self, payload: &FunctionCallOutputPayload) -> bool {
+        match &payload.body {
+            FunctionCallOutputBody::Text(text) => self.text_needs_redaction(text),
+            FunctionCallOutputBody::ContentItems(items) => items.iter().any(|item| {
+                matches!(
+                    item,
+                    FunctionCallOutputContentItem::InputText { text }
+                        if self.text_needs_redaction(text)
+                )
+            }),
+        }
+    }
+
+    /// Match-only counterpart of [`Self::redact_text`], so scanning rollout items does not build
+    /// redacted copies that are thrown away.
+    fn text_needs_redaction(&self, text: &str) -> bool {
+        let secrets = match self.secrets.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        secrets
+            .iter()
+            .any(|secret| text.contains(secret.value.as_str()))
+            || self
+                .patterns
+                .as_ref()
+                .is_some_and(|patterns| patterns.is_match(text))
+    }
+
+    /// Number of trailing bytes of `bytes` to hold back because the next chunk could complete a
+    /// secret that starts there.
+    fn held_suffix_len(&self, bytes: &[u8]) -> usize {
+        let secrets = match self.secrets.read() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let mut held = secrets
+            .iter()
+            .filter_map(|secret| {
+                let value = secret.value.as_bytes();
+                (1..value.len().min(bytes.len() + 1))
+                    .rev()
+                    .find(|len| bytes.ends_with(&value[..*len]))
+            })
+            .max()
+            .unwrap_or(0);
+        drop(secrets);
+        // Pattern matches never span whitespace, so an unfinished trailing token is enough.
+        if self.patterns.is_some() {
+            let token = bytes
+                .iter()
+                .rev()
+                .take_while(|byte| !byte.is_ascii_whitespace())
+                .count();
+            if token <= MAX_HELD_TOKEN_BYTES {
+                held = held.max(token);
+            }
+        }
+        held
+    }
+
+    fn is_empty(&self) -> bool {
+        if self.patterns.is_some() {
+            return false;
+        }
+        match self.secrets.read() {
+            Ok(guard) => guard.is_empty(),
+            Err(poisoned) => poisoned.into_inner().is_empty(),
+        }
+    }
+
+    fn redact_turn_item(&self, item: &mut TurnItem) {
+        match item {
+            TurnItem::CommandExecution(item) => {
+                let mut redacted = false;
+                for text in [
+                    &mut item.stdout,
+                    &mut item.stderr,
+                    &mut item.aggregated_output,
+                    &mut item.formatted_output,
+                ]
+                .into_iter()
+                .flatten()
+                {
+                    redacted |= self.redact_string(text);
+                }
+                item.redacted |= redacted;
+            }
+            TurnItem::McpToolCall(item) => {
+                let mut redacted = false;
+                if let Some(result) = &mut item.result {
+                    redacted |= self.redact_call_tool_result(result);
+                }
+                if let Some(error) = &mut item.error {
+                    redacted |= self.redact_string(&mut error.message);
+                }
+                item.redacted |= redacted;
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns whether anything in `result` was masked.
+    fn redact_call_tool_result(&self, result: &mut CallToolResult) -> bool {
+        let mut redacted = false;
+        for value in result
+            .content
+            .iter_mut()
+            .chain(result.structured_content.as_mut())
+        {
+            redacted |= self.redact_json(value);
+        }
+        redacted
+    }
+
+    fn redact_json(&self, value: &mut serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::String(text) => self.redact_string(text),
+            serde_json::Value::Array(values) => values
+                .iter_mut()
+                .fold(false, |redacted, value| self.redact_json(value) | redacted),
+            serde_json::Value::Object(map) => map
+                .values_mut()
+                .fold(false, |redacted, value| self.redact_json(value) | redacted),
+            serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+                false
+            }
+        }
+    }
+
+    fn redact_payload(&self, payload: &mut FunctionCallOutputPayload) {
+        match &mut payload.body {
+            FunctionCallOutputBody::Text(text) => {
+                self.redact_string(text);
+            }
+            FunctionCallOutputBody::ContentItems(items) => {
+                for item in items {
+                    if let FunctionCallOutputContentItem::InputText { text } = item {
+                        self.redact_string(text);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Redacts `text` in place and returns whether anything was masked.
+    fn redact_string(&self, text: &mut String) -> bool {
+        match self.redact_text(text) {
+            Cow::Owned(redacted) => {
+                *text = redacted;
+                true
+            }
+            Cow::Borrowed(_) => false,
+        }
+    }
+}
+
+/// Redacts one command output stream as it arrives.
+///
+/// The end of each chunk that could be the start of a secret is held back and redacted together
+/// with the next chunk, so a value split across chunks is still masked.
+pub(crate) struct OutputStreamRedactor {
+    redactor: Arc<OutputRedactor>,
+    held: Vec<u8>,
+}
+
+impl OutputStreamRedactor {
+    pub(crate) fn new(redactor: Arc<OutputRedactor>) -> Self {
+        Self {
+            redactor,
+            held: Vec::new(),
+        }
+    }
+
+    /// Adds `chunk` to the stream and returns the redacted output that is ready to emit, which
+    /// may be empty.
+    pub(crate) fn push(&mut self, chunk: &[u8]) -> Vec<u8> {
+        if self.redactor.is_empty() {
+            let mut ready = std::mem::take(&mut self.held);
+            ready.extend_from_slice(chunk);
+            return ready;
+        }
+        self.held.extend_from_slice(chunk);
+        let held = self.redactor.held_suffix_len(&self.held);
+        let rest = self.held.split_off(self.held.len() - held);
+        let mut ready = std::mem::replace(&mut self.held, rest);
+        self.redactor.redact_bytes(&mut ready);
+        ready
+    }
+
+    /// Returns the redacted output still held back once the stream has ended.
+    pub(crate) fn finish(self) -> Vec<u8> {
+        let mut rest = self.held;
+        self.redactor.redact_bytes(&mut rest);
+        rest
+    }
+}
+
+fn marker(name: &str) -> String {
+    format!("[REDACTED_SECRET:{name}]")
+}
+
+#[cfg(test)]
+#[path = "output_redactor_tests.rs"]
+mod tests;
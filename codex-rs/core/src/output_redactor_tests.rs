@@ -0,0 +1,193 @@
+use super::*;
+use codex_protocol::protocol::McpInvocation;
+use codex_protocol::protocol::McpToolCallEndEvent;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+#[test]
+fn redacts_registered_values_longest_first() {
+    let redactor = OutputRedactor::default();
+    redactor.register_secret("TOKEN", "abcd1234");
+    redactor.register_secret("TOKEN_SUFFIX", "abcd1234-extra");
+    redactor.register_secret("PIN", "123");
+
+    assert_eq!(
+        redactor.redact_text("a=abcd1234 b=abcd1234-extra pin=123"),
+        "a=[REDACTED_SECRET:TOKEN] b=[REDACTED_SECRET:TOKEN_SUFFIX] pin=123"
+    );
+    assert!(matches!(redactor.redact_text("clean"), Cow::Borrowed(_)));
+}
+
+#[test]
+fn redacts_output_chunks_and_tool_outputs() {
+    let redactor = OutputRedactor::default();
+    redactor.register_secret("API_KEY", "sk-live-value");
+
+    let mut chunk = b"key: sk-live-value\n".to_vec();
+    redactor.redact_bytes(&mut chunk);
+    assert_eq!(chunk, b"key: [REDACTED_SECRET:API_KEY]\n".to_vec());
+
+    let mut items = vec![ResponseItem::FunctionCallOutput {
+        id: None,
+        call_id: "call-1".to_string(),
+        output: FunctionCallOutputPayload::from_text("printed sk-live-value".to_string()),
+        internal_chat_message_metadata_passthrough: None,
+    }];
+    redactor.redact_response_items(&mut items);
+    assert_eq!(
+        items,
+        vec![ResponseItem::FunctionCallOutput {
+            id: None,
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text(
+                "printed [REDACTED_SECRET:API_KEY]".to_string()
+            ),
+            internal_chat_message_metadata_passthrough: None,
+        }]
+    );
+}
+
+#[test]
+fn configured_patterns_redact_text_chunks_and_rollout_items() {
+    let redactor = OutputRedactor::new(Some(&RedactionOptions {
+        detect_high_entropy: true,
+        patterns: vec![r"ticket-\d{6}".to_string()],
+    }));
+
+    assert_eq!(
+        redactor.redact_text("export OPENAI_API_KEY=sk-abcdefghijklmnopqrstuvwx"),
+        "export OPENAI_API_KEY=[REDACTED_SECRET:openai_key]"
+    );
+
+    let mut chunk = b"see ticket-123456\n".to_vec();
+    redactor.redact_bytes(&mut chunk);
+    assert_eq!(chunk, b"see [REDACTED_SECRET:pattern]\n".to_vec());
+
+    let items = vec![RolloutItem::ResponseItem(
+        ResponseItem::FunctionCallOutput {
+            id: None,
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text(
+                "token Zq8vN3xK9pL2mR7tW4yB6cD1fH5jG0sA".to_string(),
+            ),
+            internal_chat_message_metadata_passthrough: None,
+        },
+    )];
+    let redacted = redactor.redact_rollout_items(&items);
+    let [RolloutItem::ResponseItem(item)] = redacted.as_ref() else {
+        panic!("expected a single response item, got {redacted:?}");
+    };
+    assert_eq!(
+        item,
+        &ResponseItem::FunctionCallOutput {
+            id: None,
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text(
+                "token [REDACTED_SECRET:high_entropy]".to_string()
+            ),
+            internal_chat_message_metadata_passthrough: None,
+        }
+    );
+}
+
+#[test]
+fn stream_redactor_masks_values_split_across_chunks() {
+    let redactor = Arc::new(OutputRedactor::default());
+    redactor.register_secret("API_KEY", "sk-live-value");
+    let mut stream = OutputStreamRedactor::new(Arc::clone(&redactor));
+
+    let mut output = stream.push(b"key: sk-li");
+    assert_eq!(output, b"key: ".to_vec());
+    output.extend(stream.push(b"ve-value\nnext sk-l"));
+    output.extend(stream.finish());
+
+    assert_eq!(
+        String::from_utf8(output).expect("utf8 output"),
+        "key: [REDACTED_SECRET:API_KEY]\nnext sk-l"
+    );
+}
+
+#[test]
+fn stream_redactor_masks_pattern_matches_split_across_chunks() {
+    let redactor = Arc::new(OutputRedactor::new(Some(&RedactionOptions {
+        detect_high_entropy: false,
+        patterns: vec![r"ticket-\d{6}".to_string()],
+    })));
+    let mut stream = OutputStreamRedactor::new(redactor);
+
+    let mut output = stream.push(b"see ticket-12");
+    output.extend(stream.push(b"3456 done\n"));
+    output.extend(stream.finish());
+
+    assert_eq!(
+        String::from_utf8(output).expect("utf8 output"),
+        "see [REDACTED_SECRET:pattern] done\n"
+    );
+}
+
+#[test]
+fn rollout_items_are_borrowed_when_nothing_matches() {
+    let redactor = OutputRedactor::default();
+    redactor.register_secret("API_KEY", "sk-live-value");
+    let items = vec![RolloutItem::ResponseItem(
+        ResponseItem::FunctionCallOutput {
+            id: None,
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("nothing secret".to_string()),
+            internal_chat_message_metadata_passthrough: None,
+        },
+    )];
+
+    assert!(matches!(
+        redactor.redact_rollout_items(&items),
+        Cow::Borrowed(_)
+    ));
+}
+
+#[test]
+fn redacted_events_are_flagged() {
+    let redactor = OutputRedactor::default();
+    redactor.register_secret("API_KEY", "sk-live-value");
+    let end_event = |text: &str| McpToolCallEndEvent {
+        call_id: "call-1".to_string(),
+        invocation: McpInvocation {
+            server: "docs".to_string(),
+            tool: "lookup".to_string(),
+            arguments: None,
+        },
+        connector_id: None,
+        mcp_app_resource_uri: None,
+        link_id: None,
+        app_name: None,
+        action_name: None,
+        plugin_id: None,
+        duration: Duration::from_millis(1),
+        result: Ok(CallToolResult {
+            content: vec![serde_json::json!({"type": "text", "text": text})],
+            structured_content: None,
+            is_error: Some(false),
+            meta: None,
+        }),
+        redacted: false,
+    };
+    let redact = |event: McpToolCallEndEvent| {
+        let mut msg = EventMsg::McpToolCallEnd(event);
+        redactor.redact_event_msg(&mut msg);
+        let EventMsg::McpToolCallEnd(event) = msg else {
+            panic!("expected an MCP tool call end event, got {msg:?}");
+        };
+        event
+    };
+
+    assert_eq!(
+        redact(end_event("key sk-live-value")),
+        McpToolCallEndEvent {
+            redacted: true,
+            ..end_event("key [REDACTED_SECRET:API_KEY]")
+        }
+    );
+    assert_eq!(
+        redact(end_event("nothing secret")),
+        end_event("nothing secret")
+    );
+}
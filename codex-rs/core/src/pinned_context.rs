@@ -0,0 +1,103 @@
+//! Text pinned to a thread's context so it survives compaction.
+//!
+//! Pins are rendered as a `<pinned_context>` world-state fragment, so they are
+//! re-injected verbatim whenever the full context is rebuilt, including after
+//! every compaction.
+
+use codex_protocol::protocol::EventMsg;
+pub use codex_protocol::protocol::PinnedContextItem;
+pub use codex_protocol::protocol::PinnedContextSource;
+use codex_protocol::protocol::RolloutItem;
+use codex_utils_output_truncation::approx_token_count;
+
+/// Token budget shared by all pins in a thread when the config does not say otherwise.
+pub const DEFAULT_PINNED_CONTEXT_TOKEN_BUDGET: usize = 2_000;
+
+#[derive(Debug, PartialEq, Eq, thiserror::Error)]
+pub enum PinContextError {
+    #[error("cannot pin empty text")]
+    Empty,
+    #[error(
+        "pinning this text needs about {needed} tokens, but only {available} of the \
+         {budget}-token pinned context budget are left; unpin something first"
+    )]
+    OverBudget {
+        needed: usize,
+        available: usize,
+        budget: usize,
+    },
+}
+
+/// Pins for one loaded thread, in the order they were added.
+#[derive(Debug, Default)]
+pub(crate) struct PinnedContext {
+    items: Vec<PinnedContextItem>,
+    next_id: u64,
+}
+
+impl PinnedContext {
+    /// Rebuilds pins from the last snapshot recorded in a rollout. New ids
+    /// continue after the highest `pin-N` ever recorded, so an unpinned id is
+    /// never handed out again.
+    pub(crate) fn from_rollout(rollout_items: &[RolloutItem]) -> Self {
+        let mut pins = Self::default();
+        for item in rollout_items {
+            let RolloutItem::EventMsg(EventMsg::PinnedContextUpdated(event)) = item else {
+                continue;
+            };
+            let highest_id = event
+                .items
+                .iter()
+                .filter_map(|item| item.id.strip_prefix("pin-")?.parse::<u64>().ok())
+                .max()
+                .unwrap_or(0);
+            pins.next_id = pins.next_id.max(highest_id);
+            pins.items = event.items.clone();
+        }
+        pins
+    }
+
+    pub(crate) fn items(&self) -> &[PinnedContextItem] {
+        &self.items
+    }
+
+    pub(crate) fn pin(
+        &mut self,
+        text: &str,
+        source: PinnedContextSource,
+        budget: usize,
+    ) -> Result<PinnedContextItem, PinContextError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(PinContextError::Empty);
+        }
+        let needed = approx_token_count(text);
+        let used = self.items.iter().map(|item| item.tokens).sum::<usize>();
+        let available = budget.saturating_sub(used);
+        if needed > available {
+            return Err(PinContextError::OverBudget {
+                needed,
+                available,
+                budget,
+            });
+        }
+        self.next_id += 1;
+        let item = PinnedContextItem {
+            id: format!("pin-{}", self.next_id),
+            text: text.to_string(),
+            source,
+            tokens: needed,
+        };
+        self.items.push(item.clone());
+        Ok(item)
+    }
+
+    pub(crate) fn unpin(&mut self, id: &str) -> Option<PinnedContextItem> {
+        let index = self.items.iter().position(|item| item.id == id)?;
+        Some(self.items.remove(index))
+    }
+}
+
+#[cfg(test)]
+#[path = "pinned_context_tests.rs"]
+mod tests;
@@ -0,0 +1,86 @@
+use super::*;
+use codex_protocol::protocol::PinnedContextUpdatedEvent;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn pin_assigns_sequential_ids_and_trims_text() {
+    let mut pins = PinnedContext::default();
+
+    let first = pins
+        .pin(
+            "  the prod DB is read-only  ",
+            PinnedContextSource::User,
+            /*budget*/ 100,
+        )
+        .expect("first pin");
+    let second = pins
+        .pin("never run migrations", PinnedContextSource::Model, /*budget*/ 100)
+        .expect("second pin");
+
+    assert_eq!(first.id, "pin-1");
+    assert_eq!(first.text, "the prod DB is read-only");
+    assert_eq!(second.id, "pin-2");
+    assert_eq!(pins.items(), &[first, second]);
+}
+
+#[test]
+fn pin_rejects_empty_text_and_text_over_budget() {
+    let mut pins = PinnedContext::default();
+    pins.pin(&"a".repeat(32), PinnedContextSource::User, /*budget*/ 10)
+        .expect("fits in budget");
+
+    assert_eq!(
+        pins.pin("   ", PinnedContextSource::User, /*budget*/ 10),
+        Err(PinContextError::Empty)
+    );
+    assert_eq!(
+        pins.pin(&"b".repeat(16), PinnedContextSource::User, /*budget*/ 10),
+        Err(PinContextError::OverBudget {
+            needed: 4,
+            available: 2,
+            budget: 10,
+        })
+    );
+}
+
+#[test]
+fn unpin_removes_item_without_reusing_its_id() {
+    let mut pins = PinnedContext::default();
+    let first = pins
+        .pin("first", PinnedContextSource::User, /*budget*/ 100)
+        .expect("first pin");
+
+    assert_eq!(pins.unpin(&first.id), Some(first));
+    assert_eq!(pins.unpin("pin-1"), None);
+    let next = pins
+        .pin("second", PinnedContextSource::User, /*budget*/ 100)
+        .expect("second pin");
+    assert_eq!(next.id, "pin-2");
+}
+
+#[test]
+fn from_rollout_restores_last_snapshot_and_continues_ids() {
+    let mut pins = PinnedContext::default();
+    let first = pins
+        .pin("first", PinnedContextSource::User, /*budget*/ 100)
+        .expect("first pin");
+    let second = pins
+        .pin("second", PinnedContextSource::Model, /*budget*/ 100)
+        .expect("second pin");
+    let snapshot = |pins: &PinnedContext| {
+        RolloutItem::EventMsg(EventMsg::PinnedContextUpdated(PinnedContextUpdatedEvent {
+            items: pins.items().to_vec(),
+        }))
+    };
+    let mut rollout = vec![snapshot(&pins)];
+    pins.unpin(&second.id);
+    rollout.push(snapshot(&pins));
+
+    let mut restored = PinnedContext::from_rollout(&rollout);
+
+    assert_eq!(restored.items(), &[first]);
+    let next = restored
+        .pin("third", PinnedContextSource::User, /*budget*/ 100)
+        .expect("third pin");
+    assert_eq!(next.id, "pin-3");
+}
@@ -117,6 +117,8 @@ pub(crate) enum CodexResponsesRequestKind {
     Prewarm,
     Compaction(CompactionTurnMetadata),
     Memory,
+    Hook,
+    McpSampling,
 }
 
 impl CodexResponsesRequestKind {
@@ -126,6 +128,8 @@ impl CodexResponsesRequestKind {
             CodexResponsesRequestKind::Prewarm => ("prewarm", None),
             CodexResponsesRequestKind::Compaction(metadata) => ("compaction", Some(metadata)),
             CodexResponsesRequestKind::Memory => ("memory", None),
+            CodexResponsesRequestKind::Hook => ("hook", None),
+            CodexResponsesRequestKind::McpSampling => ("mcp_sampling", None),
         }
     }
 
@@ -1,5 +1,7 @@
 use super::*;
+use crate::context::McpResourcesUpdated;
 use crate::mcp::McpRuntimeProjection;
+use crate::mcp_sampling::build_mcp_sampler;
 use codex_exec_server::ExecutorCapabilityDiscoveryCache;
 use codex_exec_server::ExecutorCapabilityDiscoverySnapshot;
 use codex_exec_server::MAX_SELECTED_CAPABILITY_ROOTS;
@@ -7,6 +9,7 @@ use codex_exec_server::ResolvedSelectedCapabilityRoot;
 use codex_mcp::ElicitationReviewRequest;
 use codex_mcp::ElicitationReviewer;
 use codex_mcp::ElicitationReviewerHandle;
+use codex_mcp::McpSamplerHandle;
 use codex_protocol::capabilities::CapabilityRootLocation;
 use codex_protocol::capabilities::SelectedCapabilityRoot;
 use codex_protocol::config_types::ApprovalsReviewer;
@@ -113,6 +116,17 @@ impl Session {
         codex_mcp::configured_mcp_servers(&self.runtime_mcp_config(config).await)
     }
 
+    /// Tells the model which subscribed MCP resources changed since it read them.
+    pub(crate) async fn record_mcp_resource_updates(&self, step_context: &StepContext) {
+        let updates = step_context.mcp.manager().take_resource_updates();
+        if updates.is_empty() {
+            return;
+        }
+        let item = ContextualUserFragment::into(McpResourcesUpdated::new(updates));
+        self.record_conversation_items(&step_context.turn, std::slice::from_ref(&item))
+            .await;
+    }
+
     #[expect(
         clippy::await_holding_invalid_type,
         reason = "MCP runtime comparison and publication must remain serialized"
@@ -283,6 +297,10 @@ impl Session {
         Arc::new(GuardianMcpElicitationReviewer::new(self))
     }
 
+    pub(crate) fn mcp_sampler(&self) -> McpSamplerHandle {
+        build_mcp_sampler(Arc::clone(&self.services.hook_session))
+    }
+
     pub(crate) fn mcp_elicitation_lifecycle(&self) -> codex_mcp::ElicitationLifecycle {
         let elicitations = self.services.elicitations.clone();
         codex_mcp::ElicitationLifecycle::new(move || elicitations.register())
@@ -470,6 +488,7 @@ impl Session {
             elicitation_reviewer,
             Some(self.mcp_elicitation_lifecycle()),
             current_runtime.manager().elicitation_router(),
+            Some(self.mcp_sampler()),
         )
         .await;
         refreshed_manager
@@ -893,6 +912,9 @@ fn mcp_elicitation_response_from_guardian_decision(
             content: Some(serde_json::json!({})),
             meta: Some(mcp_elicitation_auto_meta()),
         },
+        ReviewDecision::ApprovedSelectedChanges { .. } => mcp_elicitation_decline_with_message(
+            ReviewDecision::SELECTED_CHANGES_REJECTION.to_string(),
+        ),
         ReviewDecision::Denied { rejection } => mcp_elicitation_decline_with_message(rejection),
         ReviewDecision::TimedOut => {
             mcp_elicitation_decline_with_message(crate::guardian::guardian_timeout_message())
@@ -254,6 +254,19 @@ fn guardian_decisions_map_to_elicitation_responses_without_session_state() {
             })),
         }
     );
+    assert_eq!(
+        mcp_elicitation_response_from_guardian_decision(ReviewDecision::ApprovedSelectedChanges {
+            changes: Vec::new(),
+        }),
+        ElicitationResponse {
+            action: ElicitationAction::Decline,
+            content: None,
+            meta: Some(json!({
+                "approvals_reviewer": ApprovalsReviewer::AutoReview,
+                "message": ReviewDecision::SELECTED_CHANGES_REJECTION,
+            })),
+        }
+    );
     assert_eq!(
         mcp_elicitation_response_from_guardian_decision(ReviewDecision::Abort),
         ElicitationResponse {
@@ -16,6 +16,7 @@ use crate::agent::agent_status_from_event;
 use crate::agent::status::is_final;
 use crate::agent_communication::AgentCommunicationContext;
 use crate::agent_communication::AgentCommunicationKind;
+use crate::agent_worktrees::worktree_completion_report;
 use crate::attestation::AttestationProvider;
 use crate::audio_preparation::prepare_response_items as prepare_audio_response_items;
 use crate::build_available_skills;
@@ -36,6 +37,9 @@ use crate::environment_selection::TurnEnvironmentSnapshot;
 use crate::exec_policy::BANNED_PREFIX_SUGGESTIONS;
 use crate::exec_policy::ExecPolicyManager;
 use crate::exec_policy::default_policy_path;
+use crate::hook_agent_evaluator::build_agent_hook_evaluator;
+use crate::hook_prompt_evaluator::build_prompt_hook_evaluator;
+use crate::hook_runtime::build_async_hook_completion_sink;
 use crate::image_preparation::prepare_response_items as prepare_image_response_items;
 use crate::parse_turn_item;
 use crate::realtime_conversation::RealtimeConversationManager;
@@ -210,6 +214,7 @@ mod input_queue;
 mod mcp;
 mod mcp_runtime;
 pub(crate) mod multi_agents;
+mod pinned_context;
 mod review;
 mod rollout_budget;
 mod rollout_reconstruction;
@@ -1314,6 +1319,7 @@ impl Session {
                     let mut state = self.state.lock().await;
                     state.set_token_info(Some(info));
                 }
+                self.restore_pinned_context(&rollout_items).await;
 
                 // Defer seeding the session's initial context until the first turn starts so
                 // turn/start overrides can be merged before we write to the rollout.
@@ -1335,6 +1341,7 @@ impl Session {
                     let mut state = self.state.lock().await;
                     state.set_token_info(Some(info));
                 }
+                self.restore_pinned_context(&rollout_items).await;
 
                 // Paginated subagents persist inherited model context while creating the live
                 // thread so the copied prefix is not observed as child-owned metadata.
@@ -1607,6 +1614,7 @@ impl Session {
             config.as_ref(),
             self.services.plugins_manager.as_ref(),
             environments.single_local_environment(),
+            Arc::clone(&self.services.hook_session),
         )
         .await;
 
@@ -1745,7 +1753,8 @@ impl Session {
     }
 
     /// Persist the event to rollout and send it to clients.
-    pub(crate) async fn send_event(&self, turn_context: &TurnContext, msg: EventMsg) {
+    pub(crate) async fn send_event(&self, turn_context: &TurnContext, mut msg: EventMsg) {
+        self.services.output_redactor.redact_event_msg(&mut msg);
         let legacy_source = msg.clone();
         if let EventMsg::Error(error) = &legacy_source
             && error
@@ -1769,7 +1778,8 @@ impl Session {
             id: turn_context.sub_id.clone(),
             msg,
         };
-        self.send_event_raw(event).await;
+        self.send_event_raw_with_persistence(event, /*persist*/ true)
+            .await;
         self.maybe_notify_parent_of_terminal_turn(turn_context, &legacy_source)
             .await;
         self.maybe_mirror_event_text_to_realtime(&legacy_source)
@@ -1786,7 +1796,8 @@ impl Session {
                 id: turn_context.sub_id.clone(),
                 msg: legacy,
             };
-            self.send_event_raw(legacy_event).await;
+            self.send_event_raw_with_persistence(legacy_event, /*persist*/ true)
+                .await;
         }
     }
 
@@ -1855,13 +1866,23 @@ impl Session {
             return;
         };
 
-        let Some(message) = format_inter_agent_completion_message(
+        let Some(mut message) = format_inter_agent_completion_message(
             parent_agent_path.clone(),
             child_agent_path.clone(),
             &status,
         ) else {
             return;
         };
+        // Children spawned with `isolation: worktree` report the changes waiting to be merged.
+        if let Some(report) = worktree_completion_report(
+            turn_context.config.codex_home.as_path(),
+            turn_context.config.cwd.as_path(),
+        )
+        .await
+        {
+            message.push_str("\n\n");
+            message.push_str(&report);
+        }
         // `communication` owns the message. Keep a second copy only when the
         // recorder will actually need it after parent delivery succeeds.
         let trace_message = self
@@ -1956,13 +1977,19 @@ impl Session {
         self.conversation.clear_active_handoff().await;
     }
 
-    pub(crate) async fn send_event_raw(&self, event: Event) {
+    pub(crate) async fn send_event_raw(&self, mut event: Event) {
+        self.services
+            .output_redactor
+            .redact_event_msg(&mut event.msg);
         self.send_event_raw_with_persistence(event, /*persist*/ true)
             .await;
     }
 
     /// Delivers an event without creating a local rollout for a thread that has not materialized.
-    pub(crate) async fn send_event_raw_without_materializing_rollout(&self, event: Event) {
+    pub(crate) async fn send_event_raw_without_materializing_rollout(&self, mut event: Event) {
+        self.services
+            .output_redactor
+            .redact_event_msg(&mut event.msg);
         let persist = match self.current_rollout_path().await {
             Ok(Some(path)) => codex_rollout::existing_rollout_path(&path).await.is_some(),
             Ok(None) => true,
@@ -1974,6 +2001,7 @@ impl Session {
         self.send_event_raw_with_persistence(event, persist).await;
     }
 
+    /// Callers redact the event first, so command output never reaches the rollout unmasked.
     async fn send_event_raw_with_persistence(&self, event: Event, persist: bool) {
         // Persist the event into rollout storage; the store applies its persistence policy.
         if persist {
@@ -2402,7 +2430,8 @@ impl Session {
                         strict_auto_review: false,
                     },
                 },
-                ReviewDecision::Abort
+                ReviewDecision::ApprovedSelectedChanges { .. }
+                | ReviewDecision::Abort
                 | ReviewDecision::Denied { .. }
                 | ReviewDecision::TimedOut => RequestPermissionsResponse {
                     permissions: RequestPermissionProfile::default(),
@@ -2787,6 +2816,9 @@ impl Session {
         let mut items = Cow::Borrowed(items);
         prepare_image_response_items(items.to_mut());
         prepare_audio_response_items(items.to_mut());
+        self.services
+            .output_redactor
+            .redact_response_items(items.to_mut());
         // Most response items get their passthrough turn ID at the durable history boundary.
         for item in items.to_mut() {
             item.set_turn_id_if_missing(&turn_context.sub_id);
@@ -3466,8 +3498,9 @@ impl Session {
 
     #[tracing::instrument(level = "trace", skip_all, fields(item_count = items.len()))]
     pub(crate) async fn persist_rollout_items(&self, items: &[RolloutItem]) {
+        let items = self.services.output_redactor.redact_rollout_items(items);
         if let Some(live_thread) = self.live_thread()
-            && let Err(e) = live_thread.append_items(items).await
+            && let Err(e) = live_thread.append_items(&items).await
         {
             error!("failed to record rollout items: {e:#}");
         }
@@ -4013,6 +4046,7 @@ async fn build_hooks_for_config(
     config: &Config,
     plugins_manager: &PluginsManager,
     environment: Option<&TurnEnvironment>,
+    hook_session: Arc<RwLock<std::sync::Weak<Session>>>,
 ) -> Hooks {
     let (hook_shell_program, hook_shell_argv) = environment
         .and_then(|environment| environment.shell.as_ref())
@@ -4036,6 +4070,9 @@ async fn build_hooks_for_config(
         plugin_hook_load_warnings,
         shell_program: hook_shell_program,
         shell_args: hook_shell_argv,
+        prompt_hook_evaluator: Some(build_prompt_hook_evaluator(Arc::clone(&hook_session))),
+        agent_hook_evaluator: Some(build_agent_hook_evaluator(Arc::clone(&hook_session))),
+        async_hook_completion_sink: Some(build_async_hook_completion_sink(hook_session)),
     })
 }
 
@@ -0,0 +1,55 @@
+use super::session::Session;
+use crate::pinned_context::PinContextError;
+use crate::pinned_context::PinnedContext;
+use crate::pinned_context::PinnedContextItem;
+use crate::pinned_context::PinnedContextSource;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::PinnedContextUpdatedEvent;
+use codex_protocol::protocol::RolloutItem;
+
+impl Session {
+    /// Pins `text` to this thread's context. The model sees it from the next step on.
+    pub(crate) async fn pin_context(
+        &self,
+        text: &str,
+        source: PinnedContextSource,
+    ) -> Result<PinnedContextItem, PinContextError> {
+        let budget = self.get_config().await.pinned_context_token_budget;
+        let (item, items) = {
+            let mut state = self.state.lock().await;
+            let item = state.pinned_context.pin(text, source, budget)?;
+            (item, state.pinned_context.items().to_vec())
+        };
+        self.persist_pinned_context(items).await;
+        Ok(item)
+    }
+
+    pub(crate) async fn unpin_context(&self, id: &str) -> Option<PinnedContextItem> {
+        let (item, items) = {
+            let mut state = self.state.lock().await;
+            let item = state.pinned_context.unpin(id)?;
+            (item, state.pinned_context.items().to_vec())
+        };
+        self.persist_pinned_context(items).await;
+        Some(item)
+    }
+
+    pub(crate) async fn pinned_context_items(&self) -> Vec<PinnedContextItem> {
+        let state = self.state.lock().await;
+        state.pinned_context.items().to_vec()
+    }
+
+    /// Rebuilds the pins recorded in a resumed or forked rollout.
+    pub(super) async fn restore_pinned_context(&self, rollout_items: &[RolloutItem]) {
+        let mut state = self.state.lock().await;
+        state.pinned_context = PinnedContext::from_rollout(rollout_items);
+    }
+
+    /// Records the current pins so resume and fork can rebuild them.
+    async fn persist_pinned_context(&self, items: Vec<PinnedContextItem>) {
+        self.persist_rollout_items(&[RolloutItem::EventMsg(EventMsg::PinnedContextUpdated(
+            PinnedContextUpdatedEvent { items },
+        ))])
+        .await;
+    }
+}
@@ -4,6 +4,7 @@ use crate::agents_md_manager::AgentsMdManager;
 use crate::config::ConstraintError;
 use crate::environment_selection::ThreadEnvironments;
 use crate::environment_selection::TurnEnvironmentSnapshot;
+use crate::output_redactor::OutputRedactor;
 use crate::shell_snapshot::ShellSnapshot;
 use crate::skills::SkillError;
 use crate::state::ActiveTurn;
@@ -1014,10 +1015,12 @@ impl Session {
                     (None, None)
                 };
 
+            let hook_session = Arc::new(RwLock::new(std::sync::Weak::<Session>::new()));
             let hooks = build_hooks_for_config(
                 &config,
                 plugins_manager.as_ref(),
                 resolved_environments.single_local_environment(),
+                Arc::clone(&hook_session),
             )
             .await;
             for warning in hooks.startup_warnings() {
@@ -1072,6 +1075,7 @@ impl Session {
                 main_execve_wrapper_exe: config.main_execve_wrapper_exe.clone(),
                 analytics_events_client,
                 hooks: arc_swap::ArcSwap::from_pointee(hooks),
+                hook_session: Arc::clone(&hook_session),
                 rollout_thread_trace,
                 user_shell: Arc::new(default_shell),
                 show_raw_agent_reasoning: config.show_raw_agent_reasoning,
@@ -1142,6 +1146,7 @@ impl Session {
                     &config.features,
                 ),
                 tool_search_handler_cache: Default::default(),
+                output_redactor: Arc::new(OutputRedactor::new(config.redaction.as_ref())),
                 turn_environments: Arc::clone(&turn_environments),
             };
             let sess = Arc::new(Session {
@@ -1165,6 +1170,7 @@ impl Session {
                 let mut guard = network_policy_decider_session.write().await;
                 *guard = Arc::downgrade(&sess);
             }
+            *hook_session.write().await = Arc::downgrade(&sess);
             // Dispatch the SessionConfiguredEvent first and then report any errors.
             // If resuming, include converted initial messages in the payload so UIs can render them immediately.
             let initial_messages = initial_history.get_event_msgs();
@@ -1241,6 +1247,7 @@ impl Session {
                 Some(sess.mcp_elicitation_reviewer()),
                 Some(sess.mcp_elicitation_lifecycle()),
                 codex_mcp::ElicitationRequestRouter::default(),
+                Some(sess.mcp_sampler()),
             )
             .instrument(info_span!(
                 "session_init.mcp_manager_init",
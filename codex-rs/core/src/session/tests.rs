@@ -5331,6 +5331,7 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
             legacy_notify_argv: config.notify.clone(),
             ..HooksConfig::default()
         })),
+        hook_session: Arc::new(RwLock::new(std::sync::Weak::new())),
         rollout_thread_trace: codex_rollout_trace::ThreadTraceContext::disabled(),
         user_shell: Arc::new(default_user_shell()),
         show_raw_agent_reasoning: config.show_raw_agent_reasoning,
@@ -5390,6 +5391,7 @@ pub(crate) async fn make_session_and_context() -> (Session, TurnContext) {
             &config.features,
         ),
         tool_search_handler_cache: Default::default(),
+        output_redactor: Default::default(),
         turn_environments: Arc::clone(&turn_environments),
     };
 
@@ -7489,6 +7491,7 @@ where
             legacy_notify_argv: config.notify.clone(),
             ..HooksConfig::default()
         })),
+        hook_session: Arc::new(RwLock::new(std::sync::Weak::new())),
         rollout_thread_trace: codex_rollout_trace::ThreadTraceContext::disabled(),
         user_shell: Arc::new(default_user_shell()),
         show_raw_agent_reasoning: config.show_raw_agent_reasoning,
@@ -7548,6 +7551,7 @@ where
             &config.features,
         ),
         tool_search_handler_cache: Default::default(),
+        output_redactor: Default::default(),
         turn_environments: Arc::clone(&turn_environments),
     };
 
@@ -10758,6 +10762,7 @@ async fn rejects_escalated_permissions_when_policy_not_on_request() {
         .exec_policy
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &command,
+            cwd: None,
             approval_policy: turn_context.approval_policy.value(),
             permission_profile: turn_context.permission_profile(),
             windows_sandbox_level: turn_context.windows_sandbox_level,
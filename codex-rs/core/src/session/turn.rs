@@ -258,6 +258,7 @@ pub(crate) async fn run_turn(
                 &window_id,
             )
             .await?;
+            sess.record_mcp_resource_updates(step_context.as_ref()).await;
 
             world_state = sess
                 .record_step_world_state_if_changed(&world_state, step_context.as_ref())
@@ -1100,6 +1101,7 @@ pub(crate) fn build_prompt(
         output_schema_strict: !crate::guardian::is_guardian_reviewer_source(
             &turn_context.session_source,
         ),
+        max_output_tokens: None,
     }
 }
 
@@ -1557,6 +1559,7 @@ pub(super) fn realtime_text_for_event(msg: &EventMsg) -> Option<(String, Option<
         | EventMsg::EnvironmentConnected(_)
         | EventMsg::EnvironmentDisconnected(_)
         | EventMsg::ThreadGoalUpdated(_)
+        | EventMsg::PinnedContextUpdated(_)
         | EventMsg::McpStartupUpdate(_)
         | EventMsg::McpStartupComplete(_)
         | EventMsg::McpToolCallBegin(_)
@@ -17,6 +17,7 @@ use codex_sandboxing::compatibility_sandbox_policy_for_permission_profile;
 use codex_sandboxing::policy_transforms::effective_file_system_sandbox_policy;
 use codex_sandboxing::policy_transforms::effective_network_sandbox_policy;
 use codex_utils_path_uri::PathUri;
+use codex_utils_tokenizer::Tokenizer;
 use futures::FutureExt;
 use futures::future::BoxFuture;
 use futures::future::Shared;
@@ -719,6 +720,12 @@ impl Session {
             mcp_connection_manager.set_approval_policy(&session_configuration.approval_policy);
             mcp_connection_manager
                 .set_permission_profile(session_configuration.permission_profile());
+            mcp_connection_manager
+                .set_roots(mcp_workspace_roots(
+                    &cwd,
+                    &session_configuration.permission_profile(),
+                ))
+                .await;
         }
 
         let model_info = self
@@ -732,6 +739,10 @@ impl Session {
         self.services
             .thread_extension_data
             .insert(model_info.clone());
+        self.state
+            .lock()
+            .await
+            .set_history_tokenizer(model_info.tokenizer.map(Tokenizer::new));
 
         let multi_agent_version = match multi_agent_runtime {
             TurnMultiAgentRuntime::ResolveAndStore => {
@@ -858,3 +869,22 @@ impl Session {
         state.session_configuration.clone()
     }
 }
+
+/// Workspace roots advertised to MCP servers: the turn cwd followed by any
+/// additional writable roots granted by the permission profile.
+fn mcp_workspace_roots(
+    cwd: &AbsolutePathBuf,
+    permission_profile: &PermissionProfile,
+) -> Vec<PathUri> {
+    let mut roots = vec![PathUri::from_abs_path(cwd)];
+    for writable_root in permission_profile
+        .file_system_sandbox_policy()
+        .get_writable_roots_with_cwd(cwd.as_path())
+    {
+        let root = PathUri::from_abs_path(&writable_root.root);
+        if !roots.contains(&root) {
+            roots.push(root);
+        }
+    }
+    roots
+}
@@ -8,6 +8,7 @@ use crate::context::world_state::CollaborationModeState;
 use crate::context::world_state::EnvironmentsInstructionsState;
 use crate::context::world_state::EnvironmentsState;
 use crate::context::world_state::PermissionsState;
+use crate::context::world_state::PinnedContextState;
 use crate::context::world_state::PluginsInstructionsState;
 use crate::context::world_state::RealtimeState;
 use crate::context::world_state::WorldState;
@@ -105,6 +106,7 @@ impl Session {
         world_state.add_section(PluginsInstructionsState::new(
             step_context.mcp.plugins_available(),
         ));
+        world_state.add_section(PinnedContextState::new(self.pinned_context_items().await));
         let environments = step_context.environments.to_selections();
         let ready_selected_capability_roots = step_context
             .selected_capability_roots
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use tokio_util::sync::CancellationToken;
 
+use crate::checkpoints::record_turn_checkpoint;
 use crate::session::TurnInput;
 use crate::session::turn::run_turn;
 use crate::session::turn_context::TurnContext;
@@ -55,6 +56,7 @@ impl SessionTask for RegularTask {
                 collaboration_mode_kind: ctx.mode,
             });
             sess.send_event(ctx.as_ref(), event).await;
+            record_turn_checkpoint(sess.as_ref(), ctx.as_ref()).await;
             sess.set_server_reasoning_included(/*included*/ false).await;
             sess.consume_startup_prewarm_for_regular_turn(&cancellation_token)
                 .await
@@ -197,6 +197,7 @@ pub(crate) async fn execute_user_shell_command(
                 exit_code: None,
                 duration: None,
                 formatted_output: None,
+                redacted: false,
             }),
         )
         .await;
@@ -238,6 +239,7 @@ pub(crate) async fn execute_user_shell_command(
         sub_id: turn_context.sub_id.clone(),
         call_id: call_id.clone(),
         tx_event: session.get_tx_event(),
+        output_redactor: Arc::clone(&session.services.output_redactor),
     });
 
     let exec_result = execute_exec_request(exec_env, stdout_stream, /*after_spawn*/ None)
@@ -281,6 +283,7 @@ pub(crate) async fn execute_user_shell_command(
                         exit_code: Some(-1),
                         duration: Some(Duration::ZERO),
                         formatted_output: Some(aborted_message),
+                        redacted: false,
                     }),
                 )
                 .await;
@@ -311,6 +314,7 @@ pub(crate) async fn execute_user_shell_command(
                             &output,
                             turn_context.model_info.truncation_policy.into(),
                         )),
+                        redacted: false,
                     }),
                 )
                 .await;
@@ -350,6 +354,7 @@ pub(crate) async fn execute_user_shell_command(
                             &exec_output,
                             turn_context.model_info.truncation_policy.into(),
                         )),
+                        redacted: false,
                     }),
                 )
                 .await;
@@ -163,7 +163,7 @@ struct ApprovalResolution {
 }
 
 impl ApprovalResolution {
-    fn into_tool_result(self) -> Result<ReviewDecision, ToolError> {
+    fn into_tool_result(self, accepts_selected_changes: bool) -> Result<ReviewDecision, ToolError> {
         let source = self.source;
         match self.decision {
             ReviewDecision::NetworkPolicyAmendment {
@@ -178,6 +178,9 @@ impl ApprovalResolution {
                 };
                 Err(ToolError::Rejected(rejection.to_string()))
             }
+            ReviewDecision::ApprovedSelectedChanges { .. } if !accepts_selected_changes => Err(
+                ToolError::Rejected(ReviewDecision::SELECTED_CHANGES_REJECTION.to_string()),
+            ),
             ReviewDecision::Denied { rejection } => Err(ToolError::Rejected(rejection)),
             ReviewDecision::TimedOut => Err(ToolError::Rejected(guardian_timeout_message())),
             ReviewDecision::Abort => {
@@ -215,7 +218,7 @@ where
                     source: ApprovalResolutionSource::Hook,
                 };
                 record_resolution(otel, tool_ctx, &resolution);
-                return resolution.into_tool_result();
+                return resolution.into_tool_result(tool.accepts_selected_changes());
             }
             Some(PermissionRequestDecision::Deny { message }) => {
                 let resolution = ApprovalResolution {
@@ -223,7 +226,7 @@ where
                     source: ApprovalResolutionSource::Hook,
                 };
                 record_resolution(otel, tool_ctx, &resolution);
-                return resolution.into_tool_result();
+                return resolution.into_tool_result(tool.accepts_selected_changes());
             }
             None => {}
         }
@@ -246,7 +249,7 @@ where
                         source: ApprovalResolutionSource::Guardian,
                     };
                     record_resolution(otel, tool_ctx, &resolution);
-                    return resolution.into_tool_result();
+                    return resolution.into_tool_result(tool.accepts_selected_changes());
                 }
             };
             review_approval_request(
@@ -269,7 +272,7 @@ where
         source,
     };
     record_resolution(otel, tool_ctx, &resolution);
-    resolution.into_tool_result()
+    resolution.into_tool_result(tool.accepts_selected_changes())
 }
 
 fn record_resolution(
@@ -1,6 +1,8 @@
 use super::*;
 use codex_protocol::approvals::NetworkPolicyAmendment;
+use codex_protocol::protocol::SelectedFileChange;
 use pretty_assertions::assert_eq;
+use std::path::PathBuf;
 
 #[test]
 fn approval_resolution_rejects_denied_network_policy_amendment() {
@@ -14,11 +16,36 @@ fn approval_resolution_rejects_denied_network_policy_amendment() {
         source: ApprovalResolutionSource::User,
     };
     assert!(matches!(
-        resolution.into_tool_result(),
+        resolution.into_tool_result(/*accepts_selected_changes*/ false),
         Err(ToolError::Rejected(rejection)) if rejection == "rejected by user"
     ));
 }
 
+#[test]
+fn approval_resolution_rejects_selected_changes_outside_apply_patch() {
+    let decision = ReviewDecision::ApprovedSelectedChanges {
+        changes: vec![SelectedFileChange {
+            path: PathBuf::from("/repo/src/lib.rs"),
+            hunks: None,
+        }],
+    };
+    let resolution = ApprovalResolution {
+        decision: decision.clone(),
+        source: ApprovalResolutionSource::User,
+    };
+    assert!(matches!(
+        resolution.clone().into_tool_result(/*accepts_selected_changes*/ false),
+        Err(ToolError::Rejected(rejection))
+            if rejection == ReviewDecision::SELECTED_CHANGES_REJECTION
+    ));
+    assert_eq!(
+        resolution
+            .into_tool_result(/*accepts_selected_changes*/ true)
+            .ok(),
+        Some(decision)
+    );
+}
+
 #[test]
 fn guardian_cwd_preserves_drive_shaped_local_posix_path() {
     let native_cwd = AbsolutePathBuf::try_from(std::path::PathBuf::from("/C:/workspace"))
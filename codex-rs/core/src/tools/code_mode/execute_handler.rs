@@ -12,6 +12,8 @@ use super::ExecContext;
 use super::PUBLIC_TOOL_NAME;
 use super::handle_runtime_response;
 use super::is_exec_tool_name;
+use super::module_library::PendingModuleSave;
+use super::module_library::load_code_mode_modules;
 
 pub struct CodeModeExecuteHandler {
     spec: ToolSpec,
@@ -38,6 +40,7 @@ impl CodeModeExecuteHandler {
         let exec = ExecContext { session, turn };
         let enabled_tools =
             codex_tools::collect_code_mode_tool_definitions(&self.nested_tool_specs);
+        let modules = load_code_mode_modules(exec.turn.as_ref()).await;
         let started_at = std::time::Instant::now();
         let started_cell = exec
             .session
@@ -49,10 +52,23 @@ impl CodeModeExecuteHandler {
                 source: args.code.clone(),
                 yield_time_ms: args.yield_time_ms,
                 max_output_tokens: args.max_output_tokens,
+                modules,
             })
             .await
             .map_err(FunctionCallError::RespondToModel)?;
         let cell_id = started_cell.cell_id.clone();
+        if let Some(name) = args.save_as {
+            exec.session
+                .services
+                .code_mode_service
+                .save_module_on_success(
+                    cell_id.clone(),
+                    PendingModuleSave {
+                        name,
+                        source: args.code.clone(),
+                    },
+                );
+        }
         let runtime_cell_id = cell_id.to_string();
         let code_cell_trace = exec
             .session
@@ -1,11 +1,14 @@
 mod delegate;
 mod execute_handler;
 pub(crate) mod execute_spec;
+mod module_library;
 mod response_adapter;
 mod wait_handler;
 pub(crate) mod wait_spec;
 
+use std::collections::HashMap;
 use std::sync::Arc;
+use std::sync::Mutex;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::time::Duration;
@@ -48,6 +51,8 @@ use codex_utils_output_truncation::truncate_function_output_items_with_policy;
 use delegate::CodeModeDispatchBroker;
 use delegate::CodeModeDispatchWorker;
 pub(crate) use execute_handler::CodeModeExecuteHandler;
+use module_library::PendingModuleSave;
+use module_library::save_code_mode_module;
 use response_adapter::into_function_call_output_content_items;
 pub(crate) use wait_handler::CodeModeWaitHandler;
 
@@ -78,6 +83,8 @@ pub(crate) struct CodeModeService {
     session_provider: Arc<dyn CodeModeSessionProvider>,
     dispatch_broker: Arc<CodeModeDispatchBroker>,
     default_exec_yield_time_override_ms: Option<u64>,
+    /// Cell sources to save as library modules once their cell completes successfully.
+    pending_module_saves: Mutex<HashMap<CellId, PendingModuleSave>>,
     shutting_down: AtomicBool,
 }
 
@@ -92,6 +99,7 @@ impl CodeModeService {
             session_provider,
             dispatch_broker,
             default_exec_yield_time_override_ms: default_exec_yield_time_override_ms(features),
+            pending_module_saves: Mutex::new(HashMap::new()),
             shutting_down: AtomicBool::new(false),
         }
     }
@@ -149,6 +157,22 @@ impl CodeModeService {
         self.dispatch_broker.close_cell(cell_id);
     }
 
+    fn save_module_on_success(&self, cell_id: CellId, save: PendingModuleSave) {
+        let mut pending_module_saves = match self.pending_module_saves.lock() {
+            Ok(pending_module_saves) => pending_module_saves,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        pending_module_saves.insert(cell_id, save);
+    }
+
+    fn take_pending_module_save(&self, cell_id: &CellId) -> Option<PendingModuleSave> {
+        let mut pending_module_saves = match self.pending_module_saves.lock() {
+            Ok(pending_module_saves) => pending_module_saves,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        pending_module_saves.remove(cell_id)
+    }
+
     pub(crate) fn start_turn_worker(
         &self,
         session: &Arc<Session>,
@@ -212,17 +236,33 @@ pub(super) async fn handle_runtime_response(
             prepend_script_status(&mut content_items, &script_status, started_at.elapsed());
             Ok(FunctionToolOutput::from_content(content_items, Some(true)))
         }
-        RuntimeResponse::Terminated { content_items, .. } => {
+        RuntimeResponse::Terminated {
+            cell_id,
+            content_items,
+        } => {
             let mut content_items = into_function_call_output_content_items(content_items);
             sanitize_runtime_image_detail(exec.turn.as_ref(), &mut content_items);
+            if let Some(save) = exec
+                .session
+                .services
+                .code_mode_service
+                .take_pending_module_save(&cell_id)
+            {
+                content_items.push(FunctionCallOutputContentItem::InputText {
+                    text: format!(
+                        "Not saved as `lib/{}.js` because the script was terminated.",
+                        save.name
+                    ),
+                });
+            }
             content_items = truncate_code_mode_result(content_items, max_output_tokens);
             prepend_script_status(&mut content_items, &script_status, started_at.elapsed());
             Ok(FunctionToolOutput::from_content(content_items, Some(true)))
         }
         RuntimeResponse::Result {
+            cell_id,
             content_items,
             error_text,
-            ..
         } => {
             let mut content_items = into_function_call_output_content_items(content_items);
             sanitize_runtime_image_detail(exec.turn.as_ref(), &mut content_items);
@@ -232,6 +272,27 @@ pub(super) async fn handle_runtime_response(
                     text: format!("Script error:\n{error_text}"),
                 });
             }
+            if let Some(save) = exec
+                .session
+                .services
+                .code_mode_service
+                .take_pending_module_save(&cell_id)
+            {
+                let text = if !success {
+                    format!(
+                        "Not saved as `lib/{}.js` because the script failed.",
+                        save.name
+                    )
+                } else {
+                    match save_code_mode_module(&exec.turn.config.codex_home, &save).await {
+                        Ok(specifier) => {
+                            format!("Saved as module `{specifier}`; later scripts can import it.")
+                        }
+                        Err(err) => format!("Failed to save module `lib/{}.js`: {err}", save.name),
+                    }
+                };
+                content_items.push(FunctionCallOutputContentItem::InputText { text });
+            }
             content_items = truncate_code_mode_result(content_items, max_output_tokens);
             prepend_script_status(&mut content_items, &script_status, started_at.elapsed());
             Ok(FunctionToolOutput::from_content(
@@ -476,6 +537,7 @@ mod tests {
                 source: "text('fallback')".to_string(),
                 yield_time_ms: None,
                 max_output_tokens: None,
+                modules: Default::default(),
             })
             .await
             .expect("missing host should fall back to an in-process session")
@@ -0,0 +1,158 @@
+//! Modules that code-mode cells can import: scripts saved with the `save_as` exec pragma under
+//! `CODEX_HOME/code-mode/lib`, and helpers shipped in the `code-mode/` directory of enabled skills.
+
+use std::collections::BTreeMap;
+use std::io;
+
+use codex_exec_server::ExecutorFileSystem;
+use codex_exec_server::LOCAL_FS;
+use codex_exec_server::WalkEntryKind;
+use codex_exec_server::WalkOptions;
+use codex_utils_absolute_path::AbsolutePathBuf;
+use codex_utils_path_uri::PathUri;
+use tracing::warn;
+
+use crate::session::turn_context::TurnContext;
+
+/// Directory of a skill that holds its importable modules.
+const SKILL_MODULES_DIR: &str = "code-mode";
+const MODULE_EXTENSIONS: [&str; 2] = [".js", ".mjs"];
+const MAX_MODULE_DEPTH: usize = 4;
+const MAX_MODULE_DIRECTORIES: usize = 64;
+const MAX_MODULE_ENTRIES: usize = 512;
+/// Module files larger than this are not offered to cells.
+const MAX_MODULE_BYTES: u64 = 256 * 1024;
+
+/// A cell source waiting to be saved as a library module once the cell completes successfully.
+pub(super) struct PendingModuleSave {
+    pub(super) name: String,
+    pub(super) source: String,
+}
+
+/// Directory holding the modules saved with the `save_as` exec pragma.
+pub(crate) fn code_mode_lib_dir(codex_home: &AbsolutePathBuf) -> AbsolutePathBuf {
+    codex_home.join("code-mode").join("lib")
+}
+
+/// Collects the modules a cell started in `turn` may import, keyed by import specifier.
+pub(super) async fn load_code_mode_modules(turn: &TurnContext) -> BTreeMap<String, String> {
+    let mut modules = BTreeMap::new();
+    let lib_dir = PathUri::from_abs_path(&code_mode_lib_dir(&turn.config.codex_home));
+    collect_modules(LOCAL_FS.as_ref(), &lib_dir, "lib", &mut modules).await;
+
+    let snapshot = &turn.turn_skills.snapshot;
+    let outcome = snapshot.outcome();
+    for skill in outcome
+        .skills
+        .iter()
+        .filter(|skill| outcome.is_skill_enabled(skill))
+    {
+        let Some(skill_dir) = skill.path_to_skills_md.parent() else {
+            continue;
+        };
+        let root = PathUri::from_abs_path(&skill_dir.join(SKILL_MODULES_DIR));
+        // Plugin skills are named `<plugin>:<skill>`; the colon becomes a directory separator.
+        let prefix = format!("skills/{}", skill.name.replace(':', "/"));
+        let file_system = snapshot.skill_file_system(skill);
+        collect_modules(file_system.as_ref(), &root, &prefix, &mut modules).await;
+    }
+    modules
+}
+
+/// Saves a cell source as a library module and returns the specifier that imports it.
+pub(super) async fn save_code_mode_module(
+    codex_home: &AbsolutePathBuf,
+    save: &PendingModuleSave,
+) -> io::Result<String> {
+    let lib_dir = code_mode_lib_dir(codex_home);
+    tokio::fs::create_dir_all(&lib_dir).await?;
+    tokio::fs::write(lib_dir.join(format!("{}.js", save.name)), &save.source).await?;
+    Ok(format!("lib/{}.js", save.name))
+}
+
+async fn collect_modules(
+    file_system: &dyn ExecutorFileSystem,
+    root: &PathUri,
+    prefix: &str,
+    modules: &mut BTreeMap<String, String>,
+) {
+    let walk = match file_system
+        .walk(
+            root,
+            WalkOptions {
+                max_depth: MAX_MODULE_DEPTH,
+                max_directories: MAX_MODULE_DIRECTORIES,
+                max_entries: MAX_MODULE_ENTRIES,
+                follow_directory_symlinks: false,
+                prune_hidden_directories: true,
+            },
+            /*sandbox*/ None,
+        )
+        .await
+    {
+        Ok(walk) => walk,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return,
+        Err(err) => {
+            warn!("failed to list code mode modules in {root}: {err:#}");
+            return;
+        }
+    };
+
+    for entry in walk.entries {
+        if entry.kind != WalkEntryKind::File {
+            continue;
+        }
+        let Some(segments) = relative_segments(&entry.path, root) else {
+            continue;
+        };
+        let is_module = segments.last().is_some_and(|name| {
+            MODULE_EXTENSIONS
+                .iter()
+                .any(|extension| name.ends_with(extension))
+        });
+        if !is_module || segments.iter().any(|segment| segment.starts_with('.')) {
+            continue;
+        }
+        match file_system
+            .get_metadata(&entry.path, /*sandbox*/ None)
+            .await
+        {
+            Ok(metadata) if metadata.size <= MAX_MODULE_BYTES => {}
+            Ok(_) => {
+                warn!(
+                    "skipping code mode module {}: larger than {MAX_MODULE_BYTES} bytes",
+                    entry.path
+                );
+                continue;
+            }
+            Err(err) => {
+                warn!("failed to inspect code mode module {}: {err:#}", entry.path);
+                continue;
+            }
+        }
+        match file_system
+            .read_file_text(&entry.path, /*sandbox*/ None)
+            .await
+        {
+            Ok(source) => {
+                modules.insert(format!("{prefix}/{}", segments.join("/")), source);
+            }
+            Err(err) => warn!("failed to read code mode module {}: {err:#}", entry.path),
+        }
+    }
+}
+
+fn relative_segments(path: &PathUri, root: &PathUri) -> Option<Vec<String>> {
+    let mut segments = Vec::new();
+    let mut current = path.clone();
+    while &current != root {
+        segments.push(current.basename()?);
+        current = current.parent()?;
+    }
+    segments.reverse();
+    Some(segments)
+}
+
+#[cfg(test)]
+#[path = "module_library_tests.rs"]
+mod tests;
@@ -0,0 +1,60 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use std::fs;
+use tempfile::TempDir;
+
+#[tokio::test]
+async fn collect_modules_keys_sources_by_prefixed_relative_path() {
+    let temp = TempDir::new().expect("tempdir");
+    let root = temp.path().join("code-mode");
+    fs::create_dir_all(root.join("shared")).expect("create shared");
+    fs::create_dir_all(root.join(".cache")).expect("create hidden dir");
+    fs::write(root.join("index.js"), "export const a = 1;").expect("write index");
+    fs::write(root.join("shared/util.mjs"), "export const b = 2;").expect("write util");
+    fs::write(root.join("README.md"), "docs").expect("write readme");
+    fs::write(root.join(".cache/stale.js"), "export {};").expect("write hidden");
+    let root =
+        PathUri::from_abs_path(&AbsolutePathBuf::from_absolute_path(&root).expect("absolute root"));
+
+    let mut modules = BTreeMap::new();
+    collect_modules(LOCAL_FS.as_ref(), &root, "skills/triage", &mut modules).await;
+
+    assert_eq!(
+        modules,
+        BTreeMap::from([
+            (
+                "skills/triage/index.js".to_string(),
+                "export const a = 1;".to_string(),
+            ),
+            (
+                "skills/triage/shared/util.mjs".to_string(),
+                "export const b = 2;".to_string(),
+            ),
+        ])
+    );
+}
+
+#[tokio::test]
+async fn saved_modules_are_collected_from_the_library() {
+    let temp = TempDir::new().expect("tempdir");
+    let codex_home = AbsolutePathBuf::from_absolute_path(temp.path()).expect("absolute home");
+    let save = PendingModuleSave {
+        name: "triage-issues".to_string(),
+        source: "export const triage = () => 1;".to_string(),
+    };
+
+    let specifier = save_code_mode_module(&codex_home, &save)
+        .await
+        .expect("save module");
+
+    assert_eq!(specifier, "lib/triage-issues.js");
+    let mut modules = BTreeMap::new();
+    collect_modules(
+        LOCAL_FS.as_ref(),
+        &PathUri::from_abs_path(&code_mode_lib_dir(&codex_home)),
+        "lib",
+        &mut modules,
+    )
+    .await;
+    assert_eq!(modules, BTreeMap::from([(specifier, save.source.clone())]));
+}
@@ -0,0 +1,124 @@
+//! Resolves the stored secrets a shell command requests by name into environment variables.
+//!
+//! Handlers only check the requested names against the `command_secrets` allowlist before the
+//! command is approved. Runtimes read the values once approval has been granted, right before
+//! the process is spawned.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_features::Feature;
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsBackendKind;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+
+use crate::function_tool::FunctionCallError;
+use crate::session::session::Session;
+use crate::session::turn_context::TurnContext;
+use crate::tools::sandboxing::ToolCtx;
+use crate::tools::sandboxing::ToolError;
+
+/// Rejects requested secrets that are invalid or not allowlisted in `command_secrets`, without
+/// reading any secret store.
+pub(crate) fn check_command_secrets(
+    session: &Session,
+    turn: &TurnContext,
+    names: &[String],
+) -> Result<(), FunctionCallError> {
+    if names.is_empty() {
+        return Ok(());
+    }
+    if !session.features().enabled(Feature::CommandSecrets) {
+        return Err(FunctionCallError::RespondToModel(
+            "`secrets` is unavailable because the command_secrets feature is disabled".to_string(),
+        ));
+    }
+    for name in names {
+        SecretName::new(name).map_err(|err| {
+            FunctionCallError::RespondToModel(format!("invalid secret name `{name}`: {err}"))
+        })?;
+        if !turn.config.command_secrets.contains(name) {
+            return Err(FunctionCallError::RespondToModel(format!(
+                "secret `{name}` is not allowed for commands; ask the user to add it to `command_secrets` in config.toml"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Reads the secrets an approved command requested, preferring the scope of the command's
+/// project over the global scope, and exposes them to the command.
+///
+/// Values are registered with the session redactor before they reach any process. They are set
+/// in `env`, and their names are recorded in `explicit_env_overrides` so that sourcing a shell
+/// snapshot cannot clobber them.
+pub(crate) async fn inject_command_secrets(
+    ctx: &ToolCtx,
+    cwd: Option<&Path>,
+    names: &[String],
+    env: &mut HashMap<String, String>,
+    explicit_env_overrides: &mut HashMap<String, String>,
+) -> Result<(), ToolError> {
+    if names.is_empty() {
+        return Ok(());
+    }
+    // Re-check in case the allowlist changed between the tool call and approval.
+    check_command_secrets(ctx.session.as_ref(), ctx.turn.as_ref(), names)
+        .map_err(|err| ToolError::Rejected(err.to_string()))?;
+    let names = names
+        .iter()
+        .map(String::as_str)
+        .map(SecretName::new)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| ToolError::Rejected(format!("invalid secret name: {err}")))?;
+    let codex_home = ctx.turn.config.codex_home.to_path_buf();
+    let project_scope =
+        cwd.and_then(|cwd| SecretScope::environment(environment_id_from_cwd(cwd)).ok());
+
+    // The local backend reads the OS keyring and an encrypted file, both blocking.
+    let resolved = tokio::task::spawn_blocking(move || {
+        lookup_secrets(codex_home, project_scope.as_ref(), &names)
+    })
+    .await
+    .map_err(|err| ToolError::Rejected(format!("secret lookup task failed: {err}")))??;
+
+    for (name, value) in resolved {
+        ctx.session
+            .services
+            .output_redactor
+            .register_secret(&name, &value);
+        explicit_env_overrides.insert(name.clone(), value.clone());
+        env.insert(name, value);
+    }
+    Ok(())
+}
+
+fn lookup_secrets(
+    codex_home: PathBuf,
+    project_scope: Option<&SecretScope>,
+    names: &[SecretName],
+) -> Result<Vec<(String, String)>, ToolError> {
+    let manager = SecretsManager::new(codex_home, SecretsBackendKind::Local);
+    let mut resolved = Vec::with_capacity(names.len());
+    for name in names {
+        let mut value = None;
+        for scope in project_scope.into_iter().chain([&SecretScope::Global]) {
+            value = manager.get(scope, name).map_err(|err| {
+                ToolError::Rejected(format!("failed to read secret `{name}`: {err:#}"))
+            })?;
+            if value.is_some() {
+                break;
+            }
+        }
+        let Some(value) = value else {
+            return Err(ToolError::Rejected(format!(
+                "secret `{name}` is not stored; ask the user to add it with `codex secrets set {name}`"
+            )));
+        };
+        resolved.push((name.as_str().to_string(), value));
+    }
+    Ok(resolved)
+}
@@ -126,6 +126,7 @@ pub(crate) async fn emit_exec_command_begin(
                 exit_code: None,
                 duration: None,
                 formatted_output: None,
+                redacted: false,
             }),
         )
         .await;
@@ -572,6 +573,7 @@ async fn emit_exec_end(
                 exit_code: Some(exec_result.exit_code),
                 duration: Some(exec_result.duration),
                 formatted_output: Some(exec_result.formatted_output),
+                redacted: false,
             }),
         )
         .await;
@@ -231,6 +231,7 @@ async fn emit_tool_call_begin(
         result: None,
         error: None,
         duration: None,
+        redacted: false,
     });
     session.emit_turn_item_started(turn, &item).await;
 }
@@ -274,6 +275,7 @@ async fn emit_tool_call_end(
         result,
         error,
         duration: Some(duration),
+        redacted: false,
     });
     session.emit_turn_item_completed(turn, item).await;
 }
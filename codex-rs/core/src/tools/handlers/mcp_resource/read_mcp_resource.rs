@@ -92,6 +92,8 @@ impl ReadMcpResourceHandler {
                 .map_err(|err| {
                     FunctionCallError::RespondToModel(format!("resources/read failed: {err:#}"))
                 })?;
+            // Announce later changes to this resource on the next turn.
+            manager.subscribe_resource(&server, &uri).await;
 
             Ok(ReadResourcePayload {
                 server,
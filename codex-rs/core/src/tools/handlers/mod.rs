@@ -16,6 +16,8 @@ pub(crate) mod multi_agents_spec;
 pub(crate) mod multi_agents_v2;
 mod new_context_window;
 pub(crate) mod new_context_window_spec;
+mod pin_context;
+pub(crate) mod pin_context_spec;
 mod plan;
 pub(crate) mod plan_spec;
 mod request_permissions;
@@ -64,6 +66,7 @@ pub use mcp_resource::ListMcpResourceTemplatesHandler;
 pub use mcp_resource::ListMcpResourcesHandler;
 pub use mcp_resource::ReadMcpResourceHandler;
 pub use new_context_window::NewContextWindowHandler;
+pub use pin_context::PinContextHandler;
 pub use plan::PlanHandler;
 pub use request_permissions::RequestPermissionsHandler;
 pub use request_plugin_install::RequestPluginInstallHandler;
@@ -31,6 +31,8 @@ pub struct SpawnAgentToolOptions {
     pub expose_spawn_agent_model_overrides: bool,
     pub multi_agent_version: MultiAgentVersion,
     pub usage_hint_text: Option<String>,
+    /// Offer the `isolation` argument that runs the new agent in its own worktree.
+    pub expose_isolation: bool,
 }
 
 impl Default for SpawnAgentToolOptions {
@@ -43,6 +45,7 @@ impl Default for SpawnAgentToolOptions {
             expose_spawn_agent_model_overrides: false,
             multi_agent_version: MultiAgentVersion::Disabled,
             usage_hint_text: None,
+            expose_isolation: false,
         }
     }
 }
@@ -117,6 +120,18 @@ pub fn create_spawn_agent_tool_v2(options: SpawnAgentToolOptions) -> ToolSpec {
         properties.remove("model");
         properties.remove("reasoning_effort");
     }
+    if options.expose_isolation {
+        properties.insert(
+            "isolation".to_string(),
+            JsonSchema::string_enum(
+                vec![json!("none"), json!("worktree")],
+                Some(
+                    "Where the new agent edits files. Defaults to `none`, the shared workspace. `worktree` gives it an isolated copy of the workspace; when it finishes you receive its diff and decide with merge_agent_changes whether to merge or discard it."
+                        .to_string(),
+                ),
+            ),
+        );
+    }
     properties.insert(
         "task_name".to_string(),
         JsonSchema::string(Some(
@@ -357,6 +372,74 @@ pub fn create_interrupt_agent_tool_v2() -> ToolSpec {
     })
 }
 
+pub fn create_merge_agent_changes_tool() -> ToolSpec {
+    let file_properties = BTreeMap::from([
+        (
+            "path".to_string(),
+            JsonSchema::string(Some(
+                "File path as shown in the agent's diff.".to_string(),
+            )),
+        ),
+        (
+            "hunks".to_string(),
+            JsonSchema::array(
+                JsonSchema::integer(/*description*/ None),
+                Some(
+                    "Zero-based indices of the file's `@@` hunks to merge. Omit to merge the whole file."
+                        .to_string(),
+                ),
+            ),
+        ),
+    ]);
+    let properties = BTreeMap::from([
+        (
+            "target".to_string(),
+            JsonSchema::string(Some(
+                "Agent id or canonical task name of an agent spawned with `isolation: worktree`."
+                    .to_string(),
+            )),
+        ),
+        (
+            "action".to_string(),
+            JsonSchema::string_enum(
+                vec![json!("merge"), json!("discard")],
+                Some(
+                    "`merge` applies the agent's pending changes to your workspace; `discard` reverts them in the agent's worktree."
+                        .to_string(),
+                ),
+            ),
+        ),
+        (
+            "files".to_string(),
+            JsonSchema::array(
+                JsonSchema::object(
+                    file_properties,
+                    Some(vec!["path".to_string()]),
+                    Some(false.into()),
+                ),
+                Some(
+                    "Files to merge. Omit to merge every change. Changes that are left out are not offered again."
+                        .to_string(),
+                ),
+            ),
+        ),
+    ]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: "merge_agent_changes".to_string(),
+        description: "Merge or discard the pending changes of an agent that works in an isolated worktree. Merging applies the changes to your workspace, and fails without changing anything when they conflict with your edits."
+            .to_string(),
+        strict: false,
+        defer_loading: None,
+        parameters: JsonSchema::object(
+            properties,
+            Some(vec!["target".to_string(), "action".to_string()]),
+            Some(false.into()),
+        ),
+        output_schema: Some(merge_agent_changes_output_schema()),
+    })
+}
+
 fn agent_status_output_schema() -> Value {
     json!({
         "oneOf": [
@@ -452,6 +535,24 @@ fn send_input_output_schema() -> Value {
     })
 }
 
+fn merge_agent_changes_output_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "action": {
+                "type": "string",
+                "enum": ["merge", "discard"]
+            },
+            "merged_diff": {
+                "type": "string",
+                "description": "The diff that was applied to your workspace. Empty when nothing was merged."
+            }
+        },
+        "required": ["action", "merged_diff"],
+        "additionalProperties": false
+    })
+}
+
 fn list_agents_output_schema() -> Value {
     json!({
         "type": "object",
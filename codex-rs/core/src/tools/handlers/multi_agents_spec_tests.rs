@@ -53,6 +53,7 @@ fn spawn_agent_tool_v2_requires_task_name_and_lists_visible_models() {
         expose_spawn_agent_model_overrides: true,
         multi_agent_version: MultiAgentVersion::V2,
         usage_hint_text: None,
+        expose_isolation: false,
     });
 
     let ToolSpec::Function(ResponsesApiTool {
@@ -134,6 +135,7 @@ fn spawn_agent_tool_v1_keeps_legacy_fork_context_field() {
         expose_spawn_agent_model_overrides: true,
         multi_agent_version: MultiAgentVersion::V1,
         usage_hint_text: None,
+        expose_isolation: false,
     });
 
     let ToolSpec::Namespace(namespace) = tool else {
@@ -199,6 +201,7 @@ fn spawn_agent_tool_caps_visible_model_summaries() {
         expose_spawn_agent_model_overrides: true,
         multi_agent_version: MultiAgentVersion::V2,
         usage_hint_text: None,
+        expose_isolation: false,
     });
 
     let ToolSpec::Function(ResponsesApiTool { description, .. }) = tool else {
@@ -245,6 +248,7 @@ fn spawn_agent_tool_keeps_model_controls_when_spawn_metadata_is_hidden() {
         expose_spawn_agent_model_overrides: true,
         multi_agent_version: MultiAgentVersion::V2,
         usage_hint_text: None,
+        expose_isolation: false,
     });
 
     let ToolSpec::Function(ResponsesApiTool {
@@ -268,6 +272,29 @@ fn spawn_agent_tool_keeps_model_controls_when_spawn_metadata_is_hidden() {
     assert!(description.contains("Available model overrides"));
 }
 
+#[test]
+fn spawn_agent_tool_offers_isolation_only_when_exposed() {
+    for expose_isolation in [false, true] {
+        let tool = create_spawn_agent_tool_v2(SpawnAgentToolOptions {
+            expose_isolation,
+            ..Default::default()
+        });
+
+        let ToolSpec::Function(ResponsesApiTool { parameters, .. }) = tool else {
+            panic!("spawn_agent should be a function tool");
+        };
+        let isolation = parameters
+            .properties
+            .as_ref()
+            .expect("spawn_agent should use object params")
+            .get("isolation");
+        assert_eq!(
+            isolation.and_then(|schema| schema.enum_values.clone()),
+            expose_isolation.then(|| vec![json!("none"), json!("worktree")])
+        );
+    }
+}
+
 #[test]
 fn spawn_agent_tool_hides_model_controls_without_override_exposure() {
     let tool = create_spawn_agent_tool_v2(SpawnAgentToolOptions {
@@ -278,6 +305,7 @@ fn spawn_agent_tool_hides_model_controls_without_override_exposure() {
         expose_spawn_agent_model_overrides: false,
         multi_agent_version: MultiAgentVersion::V2,
         usage_hint_text: None,
+        expose_isolation: false,
     });
 
     let ToolSpec::Function(ResponsesApiTool {
@@ -29,6 +29,7 @@ use serde_json::Value as JsonValue;
 pub(crate) use followup_task::Handler as FollowupTaskHandler;
 pub(crate) use interrupt_agent::Handler as InterruptAgentHandler;
 pub(crate) use list_agents::Handler as ListAgentsHandler;
+pub(crate) use merge_agent_changes::Handler as MergeAgentChangesHandler;
 pub(crate) use send_message::Handler as SendMessageHandler;
 pub(crate) use spawn::Handler as SpawnAgentHandler;
 pub(crate) use wait::Handler as WaitAgentHandler;
@@ -36,6 +37,7 @@ pub(crate) use wait::Handler as WaitAgentHandler;
 mod followup_task;
 mod interrupt_agent;
 mod list_agents;
+mod merge_agent_changes;
 mod message_tool;
 mod send_message;
 mod spawn;
@@ -0,0 +1,156 @@
+use super::*;
+use crate::agent_worktrees::agent_worktrees_dir;
+use crate::tools::handlers::multi_agents_spec::create_merge_agent_changes_tool;
+use codex_git_utils::discard_isolated_worktree_changes;
+use codex_git_utils::find_isolated_worktree;
+use codex_git_utils::merge_isolated_worktree;
+use codex_tools::ToolSpec;
+use std::collections::HashMap;
+
+pub(crate) struct Handler;
+
+impl ToolExecutor<ToolInvocation> for Handler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain("merge_agent_changes")
+    }
+
+    fn spec(&self) -> ToolSpec {
+        create_merge_agent_changes_tool()
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            handle_merge_agent_changes(invocation)
+                .await
+                .map(boxed_tool_output)
+        })
+    }
+}
+
+async fn handle_merge_agent_changes(
+    invocation: ToolInvocation,
+) -> Result<MergeAgentChangesResult, FunctionCallError> {
+    let ToolInvocation {
+        session,
+        turn,
+        payload,
+        ..
+    } = invocation;
+    let arguments = function_arguments(payload)?;
+    let args: MergeAgentChangesArgs = parse_arguments(&arguments)?;
+    let agent_id = resolve_agent_target(&session, &turn, &args.target).await?;
+    let snapshot = session
+        .services
+        .agent_control
+        .get_agent_config_snapshot(agent_id)
+        .await
+        .ok_or_else(|| {
+            FunctionCallError::RespondToModel(format!(
+                "agent `{}` is no longer available",
+                args.target
+            ))
+        })?;
+    let worktree = find_isolated_worktree(
+        &agent_worktrees_dir(turn.config.codex_home.as_path()),
+        snapshot.environments.legacy_fallback_cwd.as_path(),
+    )
+    .await
+    .map_err(|err| FunctionCallError::RespondToModel(format!("{err:#}")))?
+    .ok_or_else(|| {
+        FunctionCallError::RespondToModel(format!(
+            "agent `{}` was not spawned with `isolation: worktree`",
+            args.target
+        ))
+    })?;
+
+    let merged_diff = match args.action {
+        MergeAgentChangesAction::Merge => {
+            let selection = args.files.map(|files| {
+                files
+                    .into_iter()
+                    .map(|file| (file.path, file.hunks))
+                    .collect::<HashMap<_, _>>()
+            });
+            merge_isolated_worktree(&worktree, selection)
+                .await
+                .map_err(|err| {
+                    FunctionCallError::RespondToModel(format!(
+                        "failed to merge agent changes: {err:#}"
+                    ))
+                })?
+        }
+        MergeAgentChangesAction::Discard => {
+            if args.files.is_some() {
+                return Err(FunctionCallError::RespondToModel(
+                    "files only applies to `merge`; `discard` reverts every pending change"
+                        .to_string(),
+                ));
+            }
+            discard_isolated_worktree_changes(&worktree)
+                .await
+                .map_err(|err| {
+                    FunctionCallError::RespondToModel(format!(
+                        "failed to discard agent changes: {err:#}"
+                    ))
+                })?;
+            String::new()
+        }
+    };
+
+    Ok(MergeAgentChangesResult {
+        action: args.action,
+        merged_diff,
+    })
+}
+
+impl CoreToolRuntime for Handler {
+    fn matches_kind(&self, payload: &ToolPayload) -> bool {
+        matches!(payload, ToolPayload::Function { .. })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum MergeAgentChangesAction {
+    Merge,
+    Discard,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MergeAgentChangesArgs {
+    target: String,
+    action: MergeAgentChangesAction,
+    files: Option<Vec<MergeAgentChangesFile>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MergeAgentChangesFile {
+    path: String,
+    hunks: Option<Vec<usize>>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct MergeAgentChangesResult {
+    action: MergeAgentChangesAction,
+    merged_diff: String,
+}
+
+impl ToolOutput for MergeAgentChangesResult {
+    fn log_preview(&self) -> String {
+        tool_output_json_text(self, "merge_agent_changes")
+    }
+
+    fn success_for_logging(&self) -> bool {
+        true
+    }
+
+    fn to_response_item(&self, call_id: &str, payload: &ToolPayload) -> ResponseInputItem {
+        tool_output_response_item(call_id, payload, self, Some(true), "merge_agent_changes")
+    }
+
+    fn code_mode_result(&self, _payload: &ToolPayload) -> JsonValue {
+        tool_output_code_mode_result(self, "merge_agent_changes")
+    }
+}
@@ -5,11 +5,15 @@ use crate::agent::next_thread_spawn_depth;
 use crate::agent::role::DEFAULT_ROLE_NAME;
 use crate::agent_communication::AgentCommunicationContext;
 use crate::agent_communication::AgentCommunicationKind;
+use crate::agent_worktrees::isolate_spawned_agent;
 use crate::tools::handlers::multi_agents_spec::SpawnAgentToolOptions;
 use crate::tools::handlers::multi_agents_spec::create_spawn_agent_tool_v2;
 use crate::tools::handlers::multi_agents_v2::message_tool::message_content;
+use codex_features::Feature;
+use codex_git_utils::remove_isolated_worktree;
 use codex_protocol::AgentPath;
 use codex_tools::ToolSpec;
+use tracing::warn;
 
 #[derive(Default)]
 pub(crate) struct Handler {
@@ -49,6 +53,12 @@ async fn handle_spawn_agent(
     let arguments = function_arguments(payload)?;
     let args: SpawnAgentArgs = parse_arguments(&arguments)?;
     let fork_mode = args.fork_mode()?;
+    let isolate_in_worktree = args.isolate_in_worktree()?;
+    if isolate_in_worktree && !turn.config.features.enabled(Feature::AgentWorktrees) {
+        return Err(FunctionCallError::RespondToModel(
+            "isolation `worktree` is not enabled".to_string(),
+        ));
+    }
     let role_name = args
         .agent_type
         .as_deref()
@@ -86,6 +96,12 @@ async fn handle_spawn_agent(
     )
     .await?;
     apply_spawn_agent_runtime_overrides(&mut config, turn.as_ref())?;
+    let mut environments = turn.environments.to_selections();
+    let worktree = if isolate_in_worktree {
+        Some(isolate_spawned_agent(turn.as_ref(), &mut config, &mut environments).await?)
+    } else {
+        None
+    };
 
     let spawn_source = thread_spawn_source(
         session.thread_id,
@@ -118,12 +134,25 @@ async fn handle_spawn_agent(
                     fork_parent_spawn_call_id: fork_mode.as_ref().map(|_| call_id.clone()),
                     fork_mode,
                     parent_thread_id: Some(session.thread_id),
-                    environments: Some(turn.environments.to_selections()),
+                    environments: Some(environments),
                 },
             ),
     )
-    .await
-    .map_err(collab_spawn_error)?;
+    .await;
+    let spawned_agent = match spawned_agent {
+        Ok(spawned_agent) => spawned_agent,
+        Err(err) => {
+            if let Some(worktree) = worktree
+                && let Err(remove_err) = remove_isolated_worktree(&worktree).await
+            {
+                warn!(
+                    "failed to remove isolated worktree {}: {remove_err:#}",
+                    worktree.root.display()
+                );
+            }
+            return Err(collab_spawn_error(err));
+        }
+    };
     let new_thread_id = spawned_agent.thread_id;
     let agent_snapshot = session
         .services
@@ -181,9 +210,20 @@ struct SpawnAgentArgs {
     service_tier: Option<String>,
     fork_turns: Option<String>,
     fork_context: Option<bool>,
+    isolation: Option<String>,
 }
 
 impl SpawnAgentArgs {
+    fn isolate_in_worktree(&self) -> Result<bool, FunctionCallError> {
+        match self.isolation.as_deref().map(str::trim) {
+            None | Some("") | Some("none") => Ok(false),
+            Some("worktree") => Ok(true),
+            Some(_) => Err(FunctionCallError::RespondToModel(
+                "isolation must be `none` or `worktree`".to_string(),
+            )),
+        }
+    }
+
     fn fork_mode(&self) -> Result<Option<SpawnAgentForkMode>, FunctionCallError> {
         if self.fork_context.is_some() {
             return Err(FunctionCallError::RespondToModel(
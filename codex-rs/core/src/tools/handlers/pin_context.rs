@@ -0,0 +1,57 @@
+use crate::function_tool::FunctionCallError;
+use crate::pinned_context::PinnedContextSource;
+use crate::tools::context::FunctionToolOutput;
+use crate::tools::context::ToolInvocation;
+use crate::tools::context::ToolPayload;
+use crate::tools::context::boxed_tool_output;
+use crate::tools::handlers::parse_arguments;
+use crate::tools::handlers::pin_context_spec::PIN_CONTEXT_TOOL_NAME;
+use crate::tools::handlers::pin_context_spec::create_pin_context_tool;
+use crate::tools::registry::CoreToolRuntime;
+use crate::tools::registry::ToolExecutor;
+use codex_tools::ToolName;
+use codex_tools::ToolSpec;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct PinContextArgs {
+    text: String,
+}
+
+pub struct PinContextHandler;
+
+impl ToolExecutor<ToolInvocation> for PinContextHandler {
+    fn tool_name(&self) -> ToolName {
+        ToolName::plain(PIN_CONTEXT_TOOL_NAME)
+    }
+
+    fn spec(&self) -> ToolSpec {
+        create_pin_context_tool()
+    }
+
+    fn handle(&self, invocation: ToolInvocation) -> codex_tools::ToolExecutorFuture<'_> {
+        Box::pin(async move {
+            let ToolPayload::Function { arguments } = &invocation.payload else {
+                return Err(FunctionCallError::RespondToModel(
+                    "pin_context handler received unsupported payload".to_string(),
+                ));
+            };
+            let args: PinContextArgs = parse_arguments(arguments)?;
+            let item = invocation
+                .session
+                .pin_context(&args.text, PinnedContextSource::Model)
+                .await
+                .map_err(|err| FunctionCallError::RespondToModel(err.to_string()))?;
+
+            Ok(boxed_tool_output(FunctionToolOutput::from_text(
+                format!(
+                    "Pinned as {}. It will be re-injected after every compaction.",
+                    item.id
+                ),
+                Some(true),
+            )))
+        })
+    }
+}
+
+impl CoreToolRuntime for PinContextHandler {}
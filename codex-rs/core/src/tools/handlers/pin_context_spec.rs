@@ -0,0 +1,29 @@
+use codex_tools::JsonSchema;
+use codex_tools::ResponsesApiTool;
+use codex_tools::ToolSpec;
+use std::collections::BTreeMap;
+
+pub(crate) const PIN_CONTEXT_TOOL_NAME: &str = "pin_context";
+
+pub fn create_pin_context_tool() -> ToolSpec {
+    let properties = BTreeMap::from([(
+        "text".to_string(),
+        JsonSchema::string(Some(
+            "The fact or excerpt to keep, written so it stands on its own without the surrounding conversation."
+                .to_string(),
+        )),
+    )]);
+
+    ToolSpec::Function(ResponsesApiTool {
+        name: PIN_CONTEXT_TOOL_NAME.to_string(),
+        description: "Pin a critical fact or short excerpt so it is re-injected verbatim after every context compaction. Use sparingly for constraints you must not lose mid-task, such as \"the prod DB is read-only\". Pins share a small token budget with the user's pins.".to_string(),
+        strict: false,
+        defer_loading: None,
+        parameters: JsonSchema::object(
+            properties,
+            Some(vec!["text".to_string()]),
+            Some(false.into()),
+        ),
+        output_schema: None,
+    })
+}
@@ -52,6 +52,7 @@ struct RunExecLikeArgs {
     shell_type: Option<ShellType>,
     additional_permissions: Option<AdditionalPermissionProfile>,
     prefix_rule: Option<Vec<String>>,
+    secrets: Vec<String>,
     session: Arc<crate::session::session::Session>,
     turn: Arc<TurnContext>,
     turn_environment: TurnEnvironment,
@@ -69,6 +70,7 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         shell_type,
         additional_permissions,
         prefix_rule,
+        secrets,
         session,
         turn,
         turn_environment,
@@ -170,6 +172,7 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         .exec_policy
         .create_exec_approval_requirement_for_command(ExecApprovalRequest {
             command: &exec_params.command,
+            cwd: Some(exec_params.cwd.clone()),
             approval_policy: turn.approval_policy.value(),
             permission_profile: turn.permission_profile(),
             windows_sandbox_level: turn.windows_sandbox_level,
@@ -192,6 +195,7 @@ async fn run_exec_like(args: RunExecLikeArgs) -> Result<FunctionToolOutput, Func
         cancellation_token,
         env: exec_params.env.clone(),
         explicit_env_overrides,
+        secrets,
         network: exec_params.network.clone(),
         sandbox_permissions: effective_additional_permissions.sandbox_permissions,
         additional_permissions: normalized_additional_permissions,
@@ -12,6 +12,7 @@ use crate::maybe_emit_implicit_skill_invocation;
 use crate::session::turn_context::TurnContext;
 use crate::session::turn_context::TurnEnvironment;
 use crate::shell::Shell;
+use crate::tools::command_secrets::check_command_secrets;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolPayload;
 use crate::tools::context::boxed_tool_output;
@@ -49,6 +50,7 @@ pub(crate) struct ShellCommandHandlerOptions {
     pub(crate) backend_config: ShellCommandBackendConfig,
     pub(crate) allow_login_shell: bool,
     pub(crate) exec_permission_approvals_enabled: bool,
+    pub(crate) command_secrets_enabled: bool,
 }
 
 impl ShellCommandHandler {
@@ -133,6 +135,7 @@ impl From<ShellCommandBackendConfig> for ShellCommandHandler {
             backend_config,
             allow_login_shell: false,
             exec_permission_approvals_enabled: false,
+            command_secrets_enabled: false,
         })
     }
 }
@@ -146,6 +149,7 @@ impl ToolExecutor<ToolInvocation> for ShellCommandHandler {
         create_shell_command_tool(CommandToolOptions {
             allow_login_shell: self.options.allow_login_shell,
             exec_permission_approvals_enabled: self.options.exec_permission_approvals_enabled,
+            command_secrets_enabled: self.options.command_secrets_enabled,
         })
     }
 
@@ -203,6 +207,8 @@ impl ShellCommandHandler {
         )
         .await;
         let prefix_rule = params.prefix_rule.clone();
+        let secrets = params.secrets.clone().unwrap_or_default();
+        check_command_secrets(session.as_ref(), turn.as_ref(), &secrets)?;
         let exec_params = Self::to_exec_params(
             &params,
             session.as_ref(),
@@ -225,6 +231,7 @@ impl ShellCommandHandler {
             shell_type,
             additional_permissions: params.additional_permissions.clone(),
             prefix_rule,
+            secrets,
             session,
             turn,
             turn_environment,
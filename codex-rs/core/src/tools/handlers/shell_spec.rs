@@ -9,12 +9,14 @@ use std::collections::BTreeMap;
 pub struct CommandToolOptions {
     pub allow_login_shell: bool,
     pub exec_permission_approvals_enabled: bool,
+    pub command_secrets_enabled: bool,
 }
 
 #[cfg(test)]
 pub fn create_exec_command_tool(options: CommandToolOptions) -> ToolSpec {
     create_exec_command_tool_with_environment_id(
         options, /*include_environment_id*/ false, /*include_shell_parameter*/ true,
+        /*interactive_exec_enabled*/ false,
     )
 }
 
@@ -22,6 +24,7 @@ pub(crate) fn create_exec_command_tool_with_environment_id(
     options: CommandToolOptions,
     include_environment_id: bool,
     include_shell_parameter: bool,
+    interactive_exec_enabled: bool,
 ) -> ToolSpec {
     let yield_time_ms_description = if cfg!(windows) {
         "Maximum time to wait before returning a session ID for a still-running command. Commands that finish sooner return immediately. For ordinary commands, omit this parameter to use the 10000 ms default. Effective range on Windows is 2000-30000 ms. Set a shorter value only when intentionally starting a long-lived or interactive process and you want a session ID promptly."
@@ -84,6 +87,19 @@ pub(crate) fn create_exec_command_tool_with_environment_id(
             )),
         );
     }
+    if options.command_secrets_enabled {
+        properties.insert("secrets".to_string(), command_secrets_schema());
+    }
+    if interactive_exec_enabled {
+        properties.extend(yield_condition_parameters());
+        properties.insert(
+            "screen".to_string(),
+            JsonSchema::boolean(Some(
+                "True runs the command in a PTY and returns the rendered terminal screen instead of raw output, for REPLs and full-screen programs. Later write_stdin calls on the session also return the screen."
+                    .to_string(),
+            )),
+        );
+    }
     properties.extend(create_approval_parameters(
         options.exec_permission_approvals_enabled,
     ));
@@ -110,8 +126,8 @@ pub(crate) fn create_exec_command_tool_with_environment_id(
     })
 }
 
-pub fn create_write_stdin_tool() -> ToolSpec {
-    let properties = BTreeMap::from([
+pub fn create_write_stdin_tool(interactive_exec_enabled: bool) -> ToolSpec {
+    let mut properties = BTreeMap::from([
         (
             "session_id".to_string(),
             JsonSchema::number(Some(
@@ -137,6 +153,9 @@ pub fn create_write_stdin_tool() -> ToolSpec {
             )),
         ),
     ]);
+    if interactive_exec_enabled {
+        properties.extend(yield_condition_parameters());
+    }
 
     ToolSpec::Function(ResponsesApiTool {
         name: "write_stdin".to_string(),
@@ -184,6 +203,9 @@ pub fn create_shell_command_tool(options: CommandToolOptions) -> ToolSpec {
             )),
         );
     }
+    if options.command_secrets_enabled {
+        properties.insert("secrets".to_string(), command_secrets_schema());
+    }
     properties.extend(create_approval_parameters(
         options.exec_permission_approvals_enabled,
     ));
@@ -343,6 +365,35 @@ fn create_approval_parameters(
     properties
 }
 
+fn yield_condition_parameters() -> BTreeMap<String, JsonSchema> {
+    BTreeMap::from([
+        (
+            "yield_on_pattern".to_string(),
+            JsonSchema::string(Some(
+                "Regex that returns early once output from this call matches, e.g. a server's ready line or a REPL prompt."
+                    .to_string(),
+            )),
+        ),
+        (
+            "yield_on_idle_ms".to_string(),
+            JsonSchema::number(Some(
+                "Return early once the process has printed nothing for this long. Minimum 100 ms."
+                    .to_string(),
+            )),
+        ),
+    ])
+}
+
+fn command_secrets_schema() -> JsonSchema {
+    JsonSchema::array(
+        JsonSchema::string(/*description*/ None),
+        Some(
+            "Names of secrets stored with `codex secrets` to set as environment variables of the same name. Their values are masked in output; reference them as variables instead of printing them."
+                .to_string(),
+        ),
+    )
+}
+
 fn permission_profile_schema() -> JsonSchema {
     let mut schema = JsonSchema::object(
         BTreeMap::from([
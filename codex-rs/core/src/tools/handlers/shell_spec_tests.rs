@@ -18,6 +18,7 @@ fn exec_command_tool_matches_expected_spec() {
     let tool = create_exec_command_tool(CommandToolOptions {
         allow_login_shell: true,
         exec_permission_approvals_enabled: false,
+        command_secrets_enabled: false,
     });
 
     let description = if cfg!(windows) {
@@ -104,18 +105,45 @@ fn exec_command_tool_can_hide_shell_parameter() {
         CommandToolOptions {
             allow_login_shell: true,
             exec_permission_approvals_enabled: false,
+            command_secrets_enabled: false,
         },
         /*include_environment_id*/ false,
         /*include_shell_parameter*/ false,
+        /*interactive_exec_enabled*/ false,
     );
 
     assert!(!has_parameter(&tool, "shell"));
     assert!(has_parameter(&tool, "cmd"));
 }
 
+#[test]
+fn interactive_exec_parameters_are_exposed_only_when_enabled() {
+    let options = CommandToolOptions {
+        allow_login_shell: false,
+        exec_permission_approvals_enabled: false,
+        command_secrets_enabled: false,
+    };
+    let exec_command = create_exec_command_tool_with_environment_id(
+        options, /*include_environment_id*/ false, /*include_shell_parameter*/ true,
+        /*interactive_exec_enabled*/ true,
+    );
+    for parameter in ["yield_on_pattern", "yield_on_idle_ms", "screen"] {
+        assert!(has_parameter(&exec_command, parameter));
+        assert!(!has_parameter(
+            &create_exec_command_tool(options),
+            parameter
+        ));
+    }
+
+    let write_stdin = create_write_stdin_tool(/*interactive_exec_enabled*/ true);
+    assert!(has_parameter(&write_stdin, "yield_on_pattern"));
+    assert!(has_parameter(&write_stdin, "yield_on_idle_ms"));
+    assert!(!has_parameter(&write_stdin, "screen"));
+}
+
 #[test]
 fn write_stdin_tool_matches_expected_spec() {
-    let tool = create_write_stdin_tool();
+    let tool = create_write_stdin_tool(/*interactive_exec_enabled*/ false);
 
     let properties = BTreeMap::from([
         (
@@ -202,11 +230,34 @@ fn request_permissions_tool_includes_full_permission_schema() {
     );
 }
 
+#[test]
+fn command_tools_expose_secrets_only_when_enabled() {
+    let options = CommandToolOptions {
+        allow_login_shell: false,
+        exec_permission_approvals_enabled: false,
+        command_secrets_enabled: true,
+    };
+
+    assert!(has_parameter(&create_exec_command_tool(options), "secrets"));
+    assert!(has_parameter(
+        &create_shell_command_tool(options),
+        "secrets"
+    ));
+    assert!(!has_parameter(
+        &create_shell_command_tool(CommandToolOptions {
+            command_secrets_enabled: false,
+            ..options
+        }),
+        "secrets"
+    ));
+}
+
 #[test]
 fn shell_command_tool_matches_expected_spec() {
     let tool = create_shell_command_tool(CommandToolOptions {
         allow_login_shell: true,
         exec_permission_approvals_enabled: false,
+        command_secrets_enabled: false,
     });
 
     let description = if cfg!(windows) {
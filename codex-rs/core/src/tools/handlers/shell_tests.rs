@@ -128,6 +128,7 @@ async fn shell_command_handler_to_exec_params_uses_selected_environment() {
         additional_permissions: None,
         prefix_rule: None,
         justification: justification.clone(),
+        secrets: None,
     };
 
     let exec_params = ShellCommandHandler::to_exec_params(
@@ -209,6 +210,7 @@ async fn shell_command_handler_defaults_to_non_login_when_disallowed() {
         additional_permissions: None,
         prefix_rule: None,
         justification: None,
+        secrets: None,
     };
 
     let exec_params = ShellCommandHandler::to_exec_params(
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::function_tool::FunctionCallError;
 use crate::maybe_emit_implicit_skill_invocation;
+use crate::tools::command_secrets::check_command_secrets;
 use crate::tools::context::ExecCommandToolOutput;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolPayload;
@@ -25,6 +26,7 @@ use crate::unified_exec::ExecCommandRequest;
 use crate::unified_exec::UnifiedExecContext;
 use crate::unified_exec::UnifiedExecError;
 use crate::unified_exec::UnifiedExecProcessManager;
+use crate::unified_exec::YieldConditions;
 use crate::unified_exec::generate_chunk_id;
 use codex_features::Feature;
 use codex_otel::SessionTelemetry;
@@ -50,8 +52,10 @@ use super::shell_mode_for_environment;
 pub(crate) struct ExecCommandHandlerOptions {
     pub(crate) allow_login_shell: bool,
     pub(crate) exec_permission_approvals_enabled: bool,
+    pub(crate) command_secrets_enabled: bool,
     pub(crate) include_environment_id: bool,
     pub(crate) include_shell_parameter: bool,
+    pub(crate) interactive_exec_enabled: bool,
 }
 
 pub struct ExecCommandHandler {
@@ -64,8 +68,10 @@ impl Default for ExecCommandHandler {
             options: ExecCommandHandlerOptions {
                 allow_login_shell: false,
                 exec_permission_approvals_enabled: false,
+                command_secrets_enabled: false,
                 include_environment_id: false,
                 include_shell_parameter: true,
+                interactive_exec_enabled: false,
             },
         }
     }
@@ -87,9 +93,11 @@ impl ToolExecutor<ToolInvocation> for ExecCommandHandler {
             CommandToolOptions {
                 allow_login_shell: self.options.allow_login_shell,
                 exec_permission_approvals_enabled: self.options.exec_permission_approvals_enabled,
+                command_secrets_enabled: self.options.command_secrets_enabled,
             },
             self.options.include_environment_id,
             self.options.include_shell_parameter,
+            self.options.interactive_exec_enabled,
         )
     }
 
@@ -190,6 +198,9 @@ impl ExecCommandHandler {
             }
         };
         let hook_command = args.cmd.clone();
+        let yield_conditions =
+            YieldConditions::new(args.yield_on_pattern.as_deref(), args.yield_on_idle_ms)
+                .map_err(FunctionCallError::RespondToModel)?;
         // TODO(anp) wire PathUri through implicit skills instead of skipping on foreign paths
         if let Some(native_cwd) = native_cwd.as_ref() {
             maybe_emit_implicit_skill_invocation(
@@ -200,6 +211,7 @@ impl ExecCommandHandler {
             )
             .await;
         }
+        check_command_secrets(session.as_ref(), turn.as_ref(), &args.secrets)?;
         let shell_mode =
             shell_mode_for_environment(&turn.unified_exec_shell_mode, environment.as_ref());
         // Remote environments may use a different OS and must build commands with their native
@@ -242,15 +254,20 @@ impl ExecCommandHandler {
 
         let ExecCommandArgs {
             tty,
+            screen,
             yield_time_ms,
             max_output_tokens,
             sandbox_permissions,
             additional_permissions,
             justification,
             prefix_rule,
+            secrets,
             ..
         } = args;
 
+        // Screen mode emulates a terminal, so it always needs a PTY.
+        let tty = tty || screen;
+
         let exec_permission_approvals_enabled =
             session.features().enabled(Feature::ExecPermissionApprovals);
         let requested_additional_permissions = additional_permissions.clone();
@@ -349,6 +366,7 @@ impl ExecCommandHandler {
                     hook_command: hook_command.clone(),
                     process_id,
                     yield_time_ms,
+                    yield_conditions,
                     max_output_tokens,
                     cwd,
                     sandbox_cwd: native_environment_cwd,
@@ -356,12 +374,14 @@ impl ExecCommandHandler {
                     shell_mode,
                     network: context.turn.network.clone(),
                     tty,
+                    screen,
                     sandbox_permissions: effective_additional_permissions.sandbox_permissions,
                     additional_permissions: normalized_additional_permissions,
                     additional_permissions_preapproved: effective_additional_permissions
                         .permissions_preapproved,
                     justification,
                     prefix_rule,
+                    secrets,
                 },
                 &context,
             )
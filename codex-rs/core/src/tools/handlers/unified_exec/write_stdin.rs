@@ -8,6 +8,7 @@ use crate::tools::registry::PostToolUsePayload;
 use crate::tools::registry::PreToolUsePayload;
 use crate::tools::registry::ToolExecutor;
 use crate::unified_exec::WriteStdinRequest;
+use crate::unified_exec::YieldConditions;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::TerminalInteractionEvent;
 use codex_tools::ToolName;
@@ -26,10 +27,25 @@ struct WriteStdinArgs {
     #[serde(default = "super::default_write_stdin_yield_time_ms")]
     yield_time_ms: u64,
     #[serde(default)]
+    yield_on_pattern: Option<String>,
+    #[serde(default)]
+    yield_on_idle_ms: Option<u64>,
+    #[serde(default)]
     max_output_tokens: Option<usize>,
 }
 
-pub struct WriteStdinHandler;
+#[derive(Default)]
+pub struct WriteStdinHandler {
+    interactive_exec_enabled: bool,
+}
+
+impl WriteStdinHandler {
+    pub(crate) fn new(interactive_exec_enabled: bool) -> Self {
+        Self {
+            interactive_exec_enabled,
+        }
+    }
+}
 
 impl ToolExecutor<ToolInvocation> for WriteStdinHandler {
     fn tool_name(&self) -> ToolName {
@@ -37,7 +53,7 @@ impl ToolExecutor<ToolInvocation> for WriteStdinHandler {
     }
 
     fn spec(&self) -> ToolSpec {
-        create_write_stdin_tool()
+        create_write_stdin_tool(self.interactive_exec_enabled)
     }
 
     fn supports_parallel_tool_calls(&self) -> bool {
@@ -71,6 +87,9 @@ impl WriteStdinHandler {
         };
 
         let args: WriteStdinArgs = parse_arguments(&arguments)?;
+        let yield_conditions =
+            YieldConditions::new(args.yield_on_pattern.as_deref(), args.yield_on_idle_ms)
+                .map_err(FunctionCallError::RespondToModel)?;
         let response = session
             .services
             .unified_exec_manager
@@ -78,6 +97,7 @@ impl WriteStdinHandler {
                 process_id: args.session_id,
                 input: &args.chars,
                 yield_time_ms: args.yield_time_ms,
+                yield_conditions,
                 max_output_tokens: args.max_output_tokens,
                 truncation_policy: turn.model_info.truncation_policy.into(),
             })
@@ -1,5 +1,6 @@
 mod approvals;
 pub(crate) mod code_mode;
+pub(crate) mod command_secrets;
 pub(crate) mod context;
 pub(crate) mod events;
 pub(crate) mod handlers;
@@ -771,6 +771,18 @@ impl NetworkApprovalService {
                     PendingApprovalDecision::Deny
                 }
             },
+            ReviewDecision::ApprovedSelectedChanges { .. } => {
+                if let Some(owner_call) = owner_call.as_ref() {
+                    self.record_call_outcome(
+                        &owner_call.registration_id,
+                        NetworkApprovalOutcome::DeniedByApproval(
+                            ReviewDecision::SELECTED_CHANGES_REJECTION.to_string(),
+                        ),
+                    )
+                    .await;
+                }
+                PendingApprovalDecision::Deny
+            }
             ReviewDecision::Denied { rejection } => {
                 if let Some(owner_call) = owner_call.as_ref() {
                     let outcome = if use_guardian {
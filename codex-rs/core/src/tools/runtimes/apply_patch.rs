@@ -28,6 +28,7 @@ use codex_protocol::models::AdditionalPermissionProfile;
 use codex_protocol::protocol::AskForApproval;
 use codex_protocol::protocol::FileChange;
 use codex_protocol::protocol::ReviewDecision;
+use codex_protocol::protocol::SelectedFileChange;
 use codex_sandboxing::SandboxType;
 use codex_sandboxing::SandboxablePreference;
 use codex_sandboxing::policy_transforms::effective_permission_profile;
@@ -56,6 +57,8 @@ pub struct ApplyPatchRequest {
 #[derive(Default)]
 pub struct ApplyPatchRuntime {
     committed_delta: AppliedPatchDelta,
+    /// Files and hunks the user accepted when they approved only part of the patch.
+    selected_changes: Option<Vec<SelectedFileChange>>,
 }
 
 #[derive(Debug)]
@@ -127,6 +130,10 @@ impl Approvable<ApplyPatchRequest> for ApplyPatchRuntime {
             .collect()
     }
 
+    fn accepts_selected_changes(&self) -> bool {
+        true
+    }
+
     fn start_approval_async<'a>(
         &'a mut self,
         req: &'a ApplyPatchRequest,
@@ -138,12 +145,13 @@ impl Approvable<ApplyPatchRequest> for ApplyPatchRuntime {
         let retry_reason = ctx.retry_reason.clone();
         let approval_keys = self.approval_keys(req);
         let changes = req.changes.clone();
+        let selected_changes = &mut self.selected_changes;
         Box::pin(async move {
             if req.permissions_preapproved && retry_reason.is_none() {
                 return ReviewDecision::Approved;
             }
-            if let Some(reason) = retry_reason {
-                return session
+            let decision = if let Some(reason) = retry_reason {
+                session
                     .request_patch_approval(
                         turn,
                         call_id,
@@ -151,22 +159,28 @@ impl Approvable<ApplyPatchRequest> for ApplyPatchRuntime {
                         Some(reason),
                         /*grant_root*/ None,
                     )
-                    .await;
-            }
-
-            with_cached_approval(
-                &session.services,
-                "apply_patch",
-                approval_keys,
-                || async move {
-                    session
-                        .request_patch_approval(
-                            turn, call_id, changes, /*reason*/ None, /*grant_root*/ None,
-                        )
-                        .await
-                },
-            )
-            .await
+                    .await
+            } else {
+                with_cached_approval(
+                    &session.services,
+                    "apply_patch",
+                    approval_keys,
+                    || async move {
+                        session
+                            .request_patch_approval(
+                                turn, call_id, changes, /*reason*/ None,
+                                /*grant_root*/ None,
+                            )
+                            .await
+                    },
+                )
+                .await
+            };
+            *selected_changes = match &decision {
+                ReviewDecision::ApprovedSelectedChanges { changes } => Some(changes.clone()),
+                _ => None,
+            };
+            decision
         })
     }
 
@@ -229,15 +243,34 @@ impl ToolRuntime<ApplyPatchRequest, ApplyPatchRuntimeOutput> for ApplyPatchRunti
         let sandbox = Self::file_system_sandbox_context_for_attempt(req, attempt);
         let mut stdout = Vec::new();
         let mut stderr = Vec::new();
-        let result = codex_apply_patch::apply_patch(
-            &req.action.patch,
-            &req.action.cwd,
-            &mut stdout,
-            &mut stderr,
-            fs.as_ref(),
-            sandbox.as_ref(),
-        )
-        .await;
+        let result = match self.selected_changes.as_ref() {
+            Some(selected_changes) => {
+                let accepted = selected_changes
+                    .iter()
+                    .map(|change| (change.path.clone(), change.hunks.clone()))
+                    .collect();
+                codex_apply_patch::apply_selected_changes(
+                    &req.action,
+                    &accepted,
+                    &mut stdout,
+                    &mut stderr,
+                    fs.as_ref(),
+                    sandbox.as_ref(),
+                )
+                .await
+            }
+            None => {
+                codex_apply_patch::apply_patch(
+                    &req.action.patch,
+                    &req.action.cwd,
+                    &mut stdout,
+                    &mut stderr,
+                    fs.as_ref(),
+                    sandbox.as_ref(),
+                )
+                .await
+            }
+        };
         let stdout = String::from_utf8_lossy(&stdout).into_owned();
         let stderr = String::from_utf8_lossy(&stderr).into_owned();
         let failed = result.is_err();
@@ -16,6 +16,7 @@ use crate::sandboxing::SandboxPermissions;
 use crate::sandboxing::execute_env;
 use crate::session::turn_context::TurnEnvironment;
 use crate::shell::ShellType;
+use crate::tools::command_secrets::inject_command_secrets;
 use crate::tools::flat_tool_name;
 use crate::tools::network_approval::NetworkApprovalMode;
 use crate::tools::network_approval::NetworkApprovalSpec;
@@ -49,6 +50,7 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_path_uri::PathUri;
 use futures::future::BoxFuture;
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
 #[derive(Clone, Debug)]
@@ -62,6 +64,8 @@ pub struct ShellRequest {
     pub cancellation_token: CancellationToken,
     pub env: HashMap<String, String>,
     pub explicit_env_overrides: HashMap<String, String>,
+    /// Stored secrets to expose as environment variables once the command is approved.
+    pub secrets: Vec<String>,
     pub network: Option<NetworkProxy>,
     pub sandbox_permissions: SandboxPermissions,
     pub additional_permissions: Option<AdditionalPermissionProfile>,
@@ -110,6 +114,7 @@ impl ShellRuntime {
             sub_id: ctx.turn.sub_id.clone(),
             call_id: ctx.call_id.clone(),
             tx_event: ctx.session.get_tx_event(),
+            output_redactor: Arc::clone(&ctx.session.services.output_redactor),
         })
     }
 }
@@ -250,6 +255,16 @@ impl ToolRuntime<ShellRequest, ExecToolCallOutput> for ShellRuntime {
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx,
     ) -> Result<ExecToolCallOutput, ToolError> {
+        let mut req = req.clone();
+        inject_command_secrets(
+            ctx,
+            Some(req.cwd.as_path()),
+            &req.secrets,
+            &mut req.env,
+            &mut req.explicit_env_overrides,
+        )
+        .await?;
+        let req = &req;
         let session_shell = ctx.session.user_shell();
         let shell = req
             .turn_environment
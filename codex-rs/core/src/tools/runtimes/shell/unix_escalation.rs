@@ -24,7 +24,6 @@ use crate::tools::sandboxing::sandbox_permissions_preserving_denied_reads;
 use crate::tools::sandboxing::unsandboxed_execution_allowed;
 use codex_execpolicy::Decision;
 use codex_execpolicy::Evaluation;
-use codex_execpolicy::MatchOptions;
 use codex_execpolicy::Policy;
 use codex_execpolicy::RuleMatch;
 use codex_features::Feature;
@@ -562,6 +561,9 @@ impl CoreShellActionProvider {
                                 EscalationDecision::deny(Some("User denied execution".to_string()))
                             }
                         },
+                        ReviewDecision::ApprovedSelectedChanges { .. } => EscalationDecision::deny(
+                            Some(ReviewDecision::SELECTED_CHANGES_REJECTION.to_string()),
+                        ),
                         ReviewDecision::Denied { rejection } => {
                             EscalationDecision::deny(Some(rejection))
                         }
@@ -613,6 +615,7 @@ impl CoreShellActionProvider {
                 program,
                 argv,
                 InterceptedExecPolicyContext {
+                    cwd: Some(workdir.clone()),
                     approval_policy: self.approval_policy,
                     permission_profile: self.permission_profile.clone(),
                     windows_sandbox_level: self.turn.windows_sandbox_level,
@@ -682,6 +685,7 @@ fn evaluate_intercepted_exec_policy(
     context: InterceptedExecPolicyContext,
 ) -> Evaluation {
     let InterceptedExecPolicyContext {
+        cwd,
         approval_policy,
         permission_profile,
         windows_sandbox_level,
@@ -722,14 +726,13 @@ fn evaluate_intercepted_exec_policy(
     policy.check_multiple_with_options(
         commands.iter(),
         &fallback,
-        &MatchOptions {
-            resolve_host_executables: true,
-        },
+        &crate::exec_policy::match_options_for_command(cwd, &permission_profile),
     )
 }
 
 #[derive(Clone)]
 struct InterceptedExecPolicyContext {
+    cwd: Option<AbsolutePathBuf>,
     approval_policy: AskForApproval,
     permission_profile: PermissionProfile,
     windows_sandbox_level: WindowsSandboxLevel,
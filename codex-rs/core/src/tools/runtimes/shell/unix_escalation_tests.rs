@@ -629,6 +629,7 @@ fn evaluate_intercepted_exec_policy_uses_wrapper_command_when_shell_wrapper_pars
             "npm publish".to_string(),
         ],
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -680,6 +681,7 @@ fn evaluate_intercepted_exec_policy_matches_inner_shell_commands_when_enabled()
             "npm publish".to_string(),
         ],
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -722,6 +724,7 @@ host_executable(name = "git", paths = ["{git_path_literal}"])
         &program,
         &["git".to_string(), "status".to_string()],
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -857,6 +860,7 @@ fn intercepted_exec_policy_treats_preapproved_additional_permissions_as_default(
         &program,
         &argv,
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy,
             permission_profile: permission_profile.clone(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -872,6 +876,7 @@ fn intercepted_exec_policy_treats_preapproved_additional_permissions_as_default(
         &program,
         &argv,
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy,
             permission_profile,
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -905,6 +910,7 @@ host_executable(name = "git", paths = ["{allowed_git_literal}"])
         &program,
         &["git".to_string(), "status".to_string()],
         InterceptedExecPolicyContext {
+            cwd: None,
             approval_policy: AskForApproval::OnRequest,
             permission_profile: PermissionProfile::read_only(),
             windows_sandbox_level: WindowsSandboxLevel::Disabled,
@@ -23,6 +23,7 @@ async fn approval_key_includes_environment_id() {
         cancellation_token: CancellationToken::new(),
         env: HashMap::new(),
         explicit_env_overrides: HashMap::new(),
+        secrets: Vec::new(),
         network: None,
         sandbox_permissions: SandboxPermissions::UseDefault,
         additional_permissions: None,
@@ -13,6 +13,7 @@ use crate::sandboxing::ExecServerEnvConfig;
 use crate::sandboxing::SandboxPermissions;
 use crate::session::turn_context::TurnEnvironment;
 use crate::shell::ShellType;
+use crate::tools::command_secrets::inject_command_secrets;
 use crate::tools::flat_tool_name;
 use crate::tools::network_approval::NetworkApprovalMode;
 use crate::tools::network_approval::NetworkApprovalSpec;
@@ -50,6 +51,7 @@ use codex_sandboxing::SandboxCommand;
 use codex_sandboxing::SandboxablePreference;
 use codex_shell_command::powershell::prefix_powershell_script_with_utf8;
 use codex_tools::UnifiedExecShellMode;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_path_uri::PathUri;
 use futures::future::BoxFuture;
 use std::collections::HashMap;
@@ -71,6 +73,8 @@ pub struct UnifiedExecRequest {
     pub env: HashMap<String, String>,
     pub exec_server_env_config: Option<ExecServerEnvConfig>,
     pub explicit_env_overrides: HashMap<String, String>,
+    /// Stored secrets to expose as environment variables once the command is approved.
+    pub secrets: Vec<String>,
     pub network: Option<NetworkProxy>,
     pub tty: bool,
     pub sandbox_permissions: SandboxPermissions,
@@ -300,6 +304,17 @@ impl<'a> ToolRuntime<UnifiedExecRequest, UnifiedExecProcess> for UnifiedExecRunt
         attempt: &SandboxAttempt<'_>,
         ctx: &ToolCtx,
     ) -> Result<UnifiedExecProcess, ToolError> {
+        let mut req = req.clone();
+        let native_cwd = req.cwd.to_abs_path().ok();
+        inject_command_secrets(
+            ctx,
+            native_cwd.as_ref().map(AbsolutePathBuf::as_path),
+            &req.secrets,
+            &mut req.env,
+            &mut req.explicit_env_overrides,
+        )
+        .await?;
+        let req = &req;
         let base_command = &req.command;
         let session_shell = ctx.session.user_shell();
         let shell = req
@@ -603,6 +618,7 @@ mod tests {
             env: HashMap::new(),
             exec_server_env_config: None,
             explicit_env_overrides: HashMap::new(),
+            secrets: Vec::new(),
             network: None,
             tty: false,
             sandbox_permissions: SandboxPermissions::UseDefault,
@@ -705,6 +721,7 @@ mod tests {
             env: HashMap::new(),
             exec_server_env_config: None,
             explicit_env_overrides: HashMap::new(),
+            secrets: Vec::new(),
             network: None,
             tty: false,
             sandbox_permissions,
@@ -357,6 +357,13 @@ pub(crate) trait Approvable<Req> {
         }
     }
 
+    /// Whether this runtime can act on a partial approval. Only apply_patch
+    /// can write a subset of its changes; every other runtime treats
+    /// `ReviewDecision::ApprovedSelectedChanges` as a denial.
+    fn accepts_selected_changes(&self) -> bool {
+        false
+    }
+
     fn start_approval_async<'a>(
         &'a mut self,
         req: &'a Req,
@@ -463,6 +463,22 @@ async fn request_user_input_tool_respects_experimental_config_gate() {
     disabled.assert_registered_lacks(&["request_user_input"]);
 }
 
+#[tokio::test]
+async fn pin_context_tool_respects_feature_gate() {
+    let disabled = probe(|turn| {
+        set_feature(turn, Feature::PinContextTool, /*enabled*/ false);
+    })
+    .await;
+    disabled.assert_visible_lacks(&["pin_context"]);
+
+    let enabled = probe(|turn| {
+        set_feature(turn, Feature::PinContextTool, /*enabled*/ true);
+    })
+    .await;
+    enabled.assert_visible_contains(&["pin_context"]);
+    enabled.assert_registered_contains(&["pin_context"]);
+}
+
 #[tokio::test]
 async fn request_user_input_stays_direct_in_code_mode_only() {
     let plan = probe(|turn| {
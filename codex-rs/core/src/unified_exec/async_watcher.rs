@@ -9,6 +9,7 @@ use tokio::time::Sleep;
 use super::UnifiedExecContext;
 use super::process::UnifiedExecProcess;
 use crate::exec::MAX_EXEC_OUTPUT_DELTAS_PER_CALL;
+use crate::output_redactor::OutputStreamRedactor;
 use crate::session::session::Session;
 use crate::session::turn_context::TurnContext;
 use crate::tools::events::ToolEmitter;
@@ -55,6 +56,8 @@ pub(crate) fn start_streaming_output(
 
         let mut pending = Vec::<u8>::new();
         let mut emitted_deltas: usize = 0;
+        let mut redactor =
+            OutputStreamRedactor::new(Arc::clone(&session_ref.services.output_redactor));
 
         let mut grace_sleep: Option<Pin<Box<Sleep>>> = None;
 
@@ -70,7 +73,6 @@ pub(crate) fn start_streaming_output(
                         sleep.as_mut().await;
                     }
                 }, if grace_sleep.is_some() => {
-                    output_drained.notify_one();
                     break;
                 }
 
@@ -81,7 +83,6 @@ pub(crate) fn start_streaming_output(
                             continue;
                         },
                         Err(RecvError::Closed) => {
-                            output_drained.notify_one();
                             break;
                         }
                     };
@@ -92,12 +93,20 @@ pub(crate) fn start_streaming_output(
                         &call_id,
                         &session_ref,
                         &turn_ref,
+                        &mut redactor,
                         &mut emitted_deltas,
                         chunk,
                     ).await;
                 }
             }
         }
+
+        // Emit what the redactor held back before the end event can go out.
+        let rest = redactor.finish();
+        if !rest.is_empty() && emitted_deltas < MAX_EXEC_OUTPUT_DELTAS_PER_CALL {
+            send_output_delta(&call_id, &session_ref, &turn_ref, rest).await;
+        }
+        output_drained.notify_one();
     });
 }
 
@@ -156,12 +165,14 @@ pub(crate) fn spawn_exit_watcher(
     });
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn process_chunk(
     pending: &mut Vec<u8>,
     transcript: &Arc<Mutex<HeadTailBuffer>>,
     call_id: &str,
     session_ref: &Arc<Session>,
     turn_ref: &Arc<TurnContext>,
+    redactor: &mut OutputStreamRedactor,
     emitted_deltas: &mut usize,
     chunk: Vec<u8>,
 ) {
@@ -176,18 +187,31 @@ async fn process_chunk(
             continue;
         }
 
-        let event = ExecCommandOutputDeltaEvent {
-            call_id: call_id.to_string(),
-            stream: ExecOutputStream::Stdout,
-            chunk: prefix,
-        };
-        session_ref
-            .send_event(turn_ref.as_ref(), EventMsg::ExecCommandOutputDelta(event))
-            .await;
+        let chunk = redactor.push(&prefix);
+        if chunk.is_empty() {
+            continue;
+        }
+        send_output_delta(call_id, session_ref, turn_ref, chunk).await;
         *emitted_deltas += 1;
     }
 }
 
+async fn send_output_delta(
+    call_id: &str,
+    session_ref: &Arc<Session>,
+    turn_ref: &Arc<TurnContext>,
+    chunk: Vec<u8>,
+) {
+    let event = ExecCommandOutputDeltaEvent {
+        call_id: call_id.to_string(),
+        stream: ExecOutputStream::Stdout,
+        chunk,
+    };
+    session_ref
+        .send_event(turn_ref.as_ref(), EventMsg::ExecCommandOutputDelta(event))
+        .await;
+}
+
 /// Emit an ExecCommandEnd event for a unified exec session, using the transcript
 /// as the primary source of aggregated_output and falling back to the provided
 /// text when the transcript is empty.
@@ -21,6 +21,7 @@
 //! - `process.rs`: PTY process lifecycle + output buffering.
 //! - `process_state.rs`: shared exit/failure state for local and remote processes.
 //! - `process_manager.rs`: orchestration (approvals, sandboxing, reuse) and request handling.
+//! - `terminal_screen.rs`: VT100 emulation for screen mode.
 
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -34,7 +35,9 @@ use codex_utils_output_truncation::TruncationPolicy;
 use codex_utils_path_uri::PathUri;
 use rand::Rng;
 use rand::rng;
+use regex_lite::Regex;
 use tokio::sync::Mutex;
+use tokio::time::Duration;
 
 use crate::sandboxing::SandboxPermissions;
 use crate::session::session::Session;
@@ -49,6 +52,7 @@ mod head_tail_buffer;
 mod process;
 mod process_manager;
 mod process_state;
+mod terminal_screen;
 
 pub(crate) fn set_deterministic_process_ids_for_tests(enabled: bool) {
     process_manager::set_deterministic_process_ids_for_tests(enabled);
@@ -60,12 +64,14 @@ pub(crate) use process::NoopSpawnLifecycle;
 pub(crate) use process::SpawnLifecycle;
 pub(crate) use process::SpawnLifecycleHandle;
 pub(crate) use process::UnifiedExecProcess;
+pub(crate) use terminal_screen::TerminalScreen;
 
 pub(crate) const MIN_YIELD_TIME_MS: u64 = 250;
 pub(crate) const WINDOWS_INITIAL_EXEC_YIELD_TIME_FLOOR_MS: u64 = 2_000;
 // Minimum yield time for an empty `write_stdin`.
 pub(crate) const MIN_EMPTY_YIELD_TIME_MS: u64 = 5_000;
 pub(crate) const MAX_YIELD_TIME_MS: u64 = 30_000;
+pub(crate) const MIN_IDLE_YIELD_TIME_MS: u64 = 100;
 pub(crate) const DEFAULT_MAX_BACKGROUND_TERMINAL_TIMEOUT_MS: u64 = 300_000;
 pub(crate) const DEFAULT_MAX_OUTPUT_TOKENS: usize = 10_000;
 pub(crate) const UNIFIED_EXEC_OUTPUT_MAX_BYTES: usize = 1024 * 1024; // 1 MiB
@@ -88,6 +94,32 @@ impl UnifiedExecContext {
     }
 }
 
+/// Conditions that end an output wait before its yield time elapses.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct YieldConditions {
+    /// Yield as soon as output collected during the call (or the rendered
+    /// screen, in screen mode) matches.
+    pub pattern: Option<Regex>,
+    /// Yield once the process has been silent for this long.
+    pub idle: Option<Duration>,
+}
+
+impl YieldConditions {
+    pub(crate) fn new(pattern: Option<&str>, idle_ms: Option<u64>) -> Result<Self, String> {
+        let pattern = pattern
+            .filter(|pattern| !pattern.is_empty())
+            .map(|pattern| {
+                Regex::new(pattern)
+                    .map_err(|err| format!("invalid yield_on_pattern `{pattern}`: {err}"))
+            })
+            .transpose()?;
+        Ok(Self {
+            pattern,
+            idle: idle_ms.map(|idle_ms| Duration::from_millis(idle_ms.max(MIN_IDLE_YIELD_TIME_MS))),
+        })
+    }
+}
+
 #[derive(Debug)]
 pub(crate) struct ExecCommandRequest {
     pub command: Vec<String>,
@@ -95,6 +127,7 @@ pub(crate) struct ExecCommandRequest {
     pub hook_command: String,
     pub process_id: i32,
     pub yield_time_ms: u64,
+    pub yield_conditions: YieldConditions,
     pub max_output_tokens: Option<usize>,
     pub cwd: PathUri,
     pub sandbox_cwd: PathUri,
@@ -102,11 +135,15 @@ pub(crate) struct ExecCommandRequest {
     pub shell_mode: UnifiedExecShellMode,
     pub network: Option<NetworkProxy>,
     pub tty: bool,
+    /// Render output through a terminal emulator and return the screen.
+    pub screen: bool,
     pub sandbox_permissions: SandboxPermissions,
     pub additional_permissions: Option<AdditionalPermissionProfile>,
     pub additional_permissions_preapproved: bool,
     pub justification: Option<String>,
     pub prefix_rule: Option<Vec<String>>,
+    /// Stored secrets to expose as environment variables once the command is approved.
+    pub secrets: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -114,6 +151,7 @@ pub(crate) struct WriteStdinRequest<'a> {
     pub process_id: i32,
     pub input: &'a str,
     pub yield_time_ms: u64,
+    pub yield_conditions: YieldConditions,
     pub max_output_tokens: Option<usize>,
     pub truncation_policy: TruncationPolicy,
 }
@@ -160,6 +198,7 @@ struct ProcessEntry {
     initial_exec_command_active: Arc<std::sync::atomic::AtomicBool>,
     hook_command: String,
     tty: bool,
+    screen: Option<Arc<TerminalScreen>>,
     network_approval: Option<DeferredNetworkApproval>,
     session: Weak<Session>,
     last_used: tokio::time::Instant,
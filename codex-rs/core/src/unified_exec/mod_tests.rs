@@ -136,6 +136,7 @@ async fn exec_command_with_tty(
             initial_exec_command_active: Arc::new(std::sync::atomic::AtomicBool::new(true)),
             hook_command: cmd.to_string(),
             tty,
+            screen: None,
             network_approval: None,
             session: Arc::downgrade(session),
             last_used: started_at,
@@ -164,6 +165,8 @@ async fn exec_command_with_tty(
         &cancellation_token,
         Some(session.subscribe_elicitation_pause_state()),
         deadline,
+        &YieldConditions::default(),
+        /*screen*/ None,
     )
     .await;
     let wall_time = Instant::now().saturating_duration_since(started_at);
@@ -320,6 +323,7 @@ async fn write_stdin(
             process_id,
             input,
             yield_time_ms,
+            yield_conditions: YieldConditions::default(),
             max_output_tokens: None,
             truncation_policy: TruncationPolicy::Tokens(10_000),
         })
@@ -618,6 +622,7 @@ async fn terminating_initial_exec_command_rechecks_initial_response_state() -> a
             initial_exec_command_active: Arc::new(std::sync::atomic::AtomicBool::new(true)),
             hook_command: "sleep 60".to_string(),
             tty: true,
+            screen: None,
             network_approval: None,
             session: Arc::downgrade(&session),
             last_used: Instant::now(),
@@ -691,6 +696,7 @@ async fn terminating_during_stdin_poll_returns_exited_response() -> anyhow::Resu
             initial_exec_command_active: Arc::new(std::sync::atomic::AtomicBool::new(false)),
             hook_command: "sleep 60".to_string(),
             tty: true,
+            screen: None,
             network_approval: None,
             session: Arc::downgrade(&session),
             last_used,
@@ -816,6 +822,8 @@ async fn unified_exec_uses_remote_exec_server_when_configured() -> anyhow::Resul
         &cancellation_token,
         /*pause_state*/ None,
         Instant::now() + Duration::from_millis(2_500),
+        &YieldConditions::default(),
+        /*screen*/ None,
     )
     .await
     .to_bytes_with_omission_marker();
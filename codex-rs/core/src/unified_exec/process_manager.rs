@@ -42,10 +42,12 @@ use crate::unified_exec::MIN_EMPTY_YIELD_TIME_MS;
 use crate::unified_exec::MIN_YIELD_TIME_MS;
 use crate::unified_exec::ProcessEntry;
 use crate::unified_exec::ProcessStore;
+use crate::unified_exec::TerminalScreen;
 use crate::unified_exec::UnifiedExecContext;
 use crate::unified_exec::UnifiedExecError;
 use crate::unified_exec::UnifiedExecProcessManager;
 use crate::unified_exec::WriteStdinRequest;
+use crate::unified_exec::YieldConditions;
 use crate::unified_exec::async_watcher::emit_exec_end_for_unified_exec;
 use crate::unified_exec::async_watcher::emit_failed_exec_end_for_unified_exec;
 use crate::unified_exec::async_watcher::spawn_exit_watcher;
@@ -57,6 +59,7 @@ use crate::unified_exec::process::OutputBuffer;
 use crate::unified_exec::process::OutputHandles;
 use crate::unified_exec::process::SpawnLifecycleHandle;
 use crate::unified_exec::process::UnifiedExecProcess;
+use crate::unified_exec::terminal_screen::SCREEN_MODE_TERM;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::config_types::ShellEnvironmentPolicy;
 use codex_protocol::error::CodexErr;
@@ -66,6 +69,7 @@ use codex_sandboxing::SandboxCommand;
 use codex_tools::ToolName;
 use codex_utils_output_truncation::approx_tokens_from_byte_count;
 use codex_utils_path_uri::PathUri;
+use codex_utils_pty::TerminalSize;
 
 const UNIFIED_EXEC_ENV: [(&str, &str); 10] = [
     ("NO_COLOR", "1"),
@@ -83,6 +87,8 @@ const NETWORK_ACCESS_DENIED_MESSAGE: &str =
     "Network access was denied by the Codex sandbox network proxy.";
 const LATE_NETWORK_DENIAL_GRACE_PERIOD: Duration = Duration::from_millis(100);
 const INTERRUPT: &str = "\u{3}";
+/// Recent output kept for `yield_on_pattern` so matches can span chunks.
+const YIELD_PATTERN_WINDOW_BYTES: usize = 64 * 1024;
 
 /// Test-only override for deterministic unified exec process IDs.
 ///
@@ -197,6 +203,19 @@ fn exec_server_params_for_request(
     }
 }
 
+/// Returns the rendered screen in screen mode, otherwise the collected output
+/// and its omission count.
+fn screen_or_collected_output(
+    screen: Option<&TerminalScreen>,
+    collected: Vec<u8>,
+    output_omitted_bytes: Option<NonZeroUsize>,
+) -> (Vec<u8>, Option<NonZeroUsize>) {
+    match screen {
+        Some(screen) => (screen.render().into_bytes(), None),
+        None => (collected, output_omitted_bytes),
+    }
+}
+
 /// Borrowed process state prepared for a `write_stdin` or poll operation.
 struct PreparedProcessHandles {
     process: Arc<UnifiedExecProcess>,
@@ -212,6 +231,7 @@ struct PreparedProcessHandles {
     hook_command: String,
     process_id: i32,
     tty: bool,
+    screen: Option<Arc<TerminalScreen>>,
 }
 
 struct InitialExecCommandGuard {
@@ -450,6 +470,9 @@ impl UnifiedExecProcessManager {
         emitter.emit(event_ctx, ToolEventStage::Begin).await;
 
         start_streaming_output(&process, context, Arc::clone(&transcript));
+        let screen = request
+            .screen
+            .then(|| Arc::new(TerminalScreen::new(TerminalSize::default())));
         let start = Instant::now();
         // Persist live sessions before the initial yield wait so interrupting the
         // turn cannot drop the last Arc and terminate the background process.
@@ -465,6 +488,7 @@ impl UnifiedExecProcessManager {
                 start,
                 request.process_id,
                 request.tty,
+                screen.clone(),
                 deferred_network_approval.clone(),
                 Arc::clone(&transcript),
                 Arc::clone(&initial_exec_command_active),
@@ -497,6 +521,8 @@ impl UnifiedExecProcessManager {
             &cancellation_token,
             Some(context.session.subscribe_elicitation_pause_state()),
             deadline,
+            &request.yield_conditions,
+            screen.as_deref(),
         )
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
@@ -637,11 +663,13 @@ impl UnifiedExecProcessManager {
             (None, exit_code)
         };
 
+        let (raw_output, output_omitted_bytes) =
+            screen_or_collected_output(screen.as_deref(), collected, output_omitted_bytes);
         let response = ExecCommandToolOutput {
             event_call_id: context.call_id.clone(),
             chunk_id,
             wall_time,
-            raw_output: collected,
+            raw_output,
             truncation_policy: context.turn.model_info.truncation_policy.into(),
             max_output_tokens: request.max_output_tokens,
             process_id: response_process_id,
@@ -687,6 +715,7 @@ impl UnifiedExecProcessManager {
             hook_command,
             process_id,
             tty,
+            screen,
             ..
         } = self
             .prepare_process_handles(process_id, &locked_process)
@@ -743,6 +772,8 @@ impl UnifiedExecProcessManager {
             &cancellation_token,
             pause_state,
             deadline,
+            &request.yield_conditions,
+            screen.as_deref(),
         )
         .await;
         let wall_time = Instant::now().saturating_duration_since(start);
@@ -812,11 +843,13 @@ impl UnifiedExecProcessManager {
             }
         };
 
+        let (raw_output, output_omitted_bytes) =
+            screen_or_collected_output(screen.as_deref(), collected, output_omitted_bytes);
         let response = ExecCommandToolOutput {
             event_call_id,
             chunk_id,
             wall_time,
-            raw_output: collected,
+            raw_output,
             truncation_policy: request.truncation_policy,
             max_output_tokens: request.max_output_tokens,
             process_id,
@@ -896,6 +929,7 @@ impl UnifiedExecProcessManager {
             hook_command: entry.hook_command.clone(),
             process_id: entry.process_id,
             tty: entry.tty,
+            screen: entry.screen.clone(),
         })
     }
 
@@ -910,6 +944,7 @@ impl UnifiedExecProcessManager {
         started_at: Instant,
         process_id: i32,
         tty: bool,
+        screen: Option<Arc<TerminalScreen>>,
         network_approval: Option<DeferredNetworkApproval>,
         transcript: Arc<tokio::sync::Mutex<HeadTailBuffer>>,
         initial_exec_command_active: Arc<AtomicBool>,
@@ -922,6 +957,7 @@ impl UnifiedExecProcessManager {
             initial_exec_command_active,
             hook_command,
             tty,
+            screen,
             network_approval,
             session: Arc::downgrade(&context.session),
             last_used: started_at,
@@ -1081,7 +1117,10 @@ impl UnifiedExecProcessManager {
         );
         let active_permission_profile = context.turn.config.permissions.active_permission_profile();
         inject_permission_profile_env(&mut env, active_permission_profile.as_ref());
-        let env = apply_unified_exec_env(env);
+        let mut env = apply_unified_exec_env(env);
+        if request.screen {
+            env.insert("TERM".to_string(), SCREEN_MODE_TERM.to_string());
+        }
         let exec_server_env_config = ExecServerEnvConfig {
             policy: exec_env_policy_from_shell_policy(
                 &context.turn.config.permissions.shell_environment_policy,
@@ -1096,6 +1135,7 @@ impl UnifiedExecProcessManager {
             .exec_policy
             .create_exec_approval_requirement_for_command(ExecApprovalRequest {
                 command: &request.command,
+                cwd: cwd.to_abs_path().ok(),
                 approval_policy: context.turn.approval_policy.value(),
                 permission_profile: context.turn.permission_profile(),
                 windows_sandbox_level: context.turn.windows_sandbox_level,
@@ -1124,6 +1164,7 @@ impl UnifiedExecProcessManager {
                 .shell_environment_policy
                 .r#set
                 .clone(),
+            secrets: request.secrets.clone(),
             network: request.network.clone(),
             tty: request.tty,
             sandbox_permissions: request.sandbox_permissions,
@@ -1164,6 +1205,10 @@ impl UnifiedExecProcessManager {
             })
     }
 
+    /// Drains process output until `deadline`, process exit, or one of
+    /// `yield_conditions` is met. In screen mode every drained chunk is also
+    /// fed to `screen`.
+    #[allow(clippy::too_many_arguments)]
     pub(super) async fn collect_output_until_deadline(
         output_buffer: &OutputBuffer,
         output_notify: &Arc<Notify>,
@@ -1172,14 +1217,18 @@ impl UnifiedExecProcessManager {
         cancellation_token: &CancellationToken,
         mut pause_state: Option<watch::Receiver<bool>>,
         mut deadline: Instant,
+        yield_conditions: &YieldConditions,
+        screen: Option<&TerminalScreen>,
     ) -> HeadTailBuffer {
         const POST_EXIT_CLOSE_WAIT_CAP: Duration = Duration::from_millis(50);
 
         let mut collected = HeadTailBuffer::default();
         let mut exit_signal_received = cancellation_token.is_cancelled();
         let mut post_exit_deadline: Option<Instant> = None;
+        let mut last_output_at = Instant::now();
+        let mut recent_output = Vec::new();
         loop {
-            Self::extend_deadlines_while_paused(
+            last_output_at += Self::extend_deadlines_while_paused(
                 &mut pause_state,
                 &mut deadline,
                 &mut post_exit_deadline,
@@ -1230,6 +1279,14 @@ impl UnifiedExecProcessManager {
                     continue;
                 }
 
+                let idle_remaining = yield_conditions
+                    .idle
+                    .map(|idle| (last_output_at + idle).saturating_duration_since(Instant::now()));
+                if idle_remaining == Some(Duration::ZERO) {
+                    break;
+                }
+                let wait = idle_remaining.map_or(remaining, |idle| idle.min(remaining));
+
                 let notified = wait_for_output.unwrap_or_else(|| output_notify.notified());
                 tokio::pin!(notified);
                 let exit_notified = cancellation_token.cancelled();
@@ -1237,13 +1294,32 @@ impl UnifiedExecProcessManager {
                 tokio::select! {
                     _ = &mut notified => {}
                     _ = &mut exit_notified => exit_signal_received = true,
-                    _ = tokio::time::sleep(remaining) => break,
+                    _ = tokio::time::sleep(wait) => break,
                     _ = Self::wait_for_pause_change(pause_state.as_ref()) => {}
                 }
                 continue;
             }
 
+            last_output_at = Instant::now();
+            if let Some(screen) = screen {
+                for chunk in drained_output.snapshot_chunks() {
+                    screen.process(&chunk);
+                }
+            }
+            // Match only output drained by this call, even in screen mode: the
+            // rendered screen still shows earlier output such as a stale prompt.
+            let pattern_matched = yield_conditions.pattern.as_ref().is_some_and(|pattern| {
+                recent_output.extend_from_slice(&drained_output.to_bytes());
+                let excess = recent_output
+                    .len()
+                    .saturating_sub(YIELD_PATTERN_WINDOW_BYTES);
+                recent_output.drain(..excess);
+                pattern.is_match(&String::from_utf8_lossy(&recent_output))
+            });
             collected.push_buffer(drained_output);
+            if pattern_matched {
+                break;
+            }
 
             exit_signal_received |= cancellation_token.is_cancelled();
             if Instant::now() >= deadline {
@@ -1254,16 +1330,18 @@ impl UnifiedExecProcessManager {
         collected
     }
 
+    /// Waits out an elicitation pause, pushing the deadlines back by the time
+    /// spent paused. Returns that duration.
     async fn extend_deadlines_while_paused(
         pause_state: &mut Option<watch::Receiver<bool>>,
         deadline: &mut Instant,
         post_exit_deadline: &mut Option<Instant>,
-    ) {
+    ) -> Duration {
         let Some(receiver) = pause_state.as_mut() else {
-            return;
+            return Duration::ZERO;
         };
         if !*receiver.borrow() {
-            return;
+            return Duration::ZERO;
         }
 
         let paused_at = Instant::now();
@@ -1278,6 +1356,7 @@ impl UnifiedExecProcessManager {
         if let Some(post_exit_deadline) = post_exit_deadline.as_mut() {
             *post_exit_deadline += paused_for;
         }
+        paused_for
     }
 
     async fn wait_for_pause_change(pause_state: Option<&watch::Receiver<bool>>) {
@@ -251,6 +251,8 @@ async fn output_collection_stays_bounded_across_repeated_drains() {
         &cancellation_token,
         /*pause_state*/ None,
         Instant::now() + Duration::from_secs(5),
+        &YieldConditions::default(),
+        /*screen*/ None,
     );
     let produce = async {
         for byte in [b'a', b'b', b'c'] {
@@ -316,12 +318,126 @@ async fn output_collection_preserves_omissions_from_drained_buffer() {
         &cancellation_token,
         /*pause_state*/ None,
         Instant::now() + Duration::from_secs(1),
+        &YieldConditions::default(),
+        /*screen*/ None,
     )
     .await;
 
     assert_eq!(collected, expected);
 }
 
+#[tokio::test]
+async fn output_collection_yields_when_pattern_matches_across_chunks() {
+    let output_buffer = Arc::new(tokio::sync::Mutex::new(HeadTailBuffer::default()));
+    let output_notify = Arc::new(Notify::new());
+    let output_closed = Arc::new(AtomicBool::new(false));
+    let output_closed_notify = Arc::new(Notify::new());
+    let cancellation_token = CancellationToken::new();
+    let yield_conditions =
+        YieldConditions::new(Some(r"listening on \d+"), /*idle_ms*/ None).expect("valid regex");
+    let start = Instant::now();
+
+    let collect = UnifiedExecProcessManager::collect_output_until_deadline(
+        &output_buffer,
+        &output_notify,
+        &output_closed,
+        &output_closed_notify,
+        &cancellation_token,
+        /*pause_state*/ None,
+        start + Duration::from_secs(10),
+        &yield_conditions,
+        /*screen*/ None,
+    );
+    let produce = async {
+        for chunk in ["starting\nlisten", "ing on 8080\n"] {
+            output_buffer
+                .lock()
+                .await
+                .push_chunk(chunk.as_bytes().to_vec());
+            output_notify.notify_one();
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+    };
+
+    let (collected, ()) = tokio::join!(collect, produce);
+    assert_eq!(
+        collected.to_bytes(),
+        b"starting\nlistening on 8080\n".to_vec()
+    );
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn screen_mode_pattern_ignores_prompt_already_on_screen() {
+    let output_buffer = Arc::new(tokio::sync::Mutex::new(HeadTailBuffer::default()));
+    let output_notify = Arc::new(Notify::new());
+    let screen = TerminalScreen::new(codex_utils_pty::TerminalSize { rows: 4, cols: 20 });
+    screen.process(b">>> ");
+    let yield_conditions =
+        YieldConditions::new(Some(">>>"), /*idle_ms*/ None).expect("valid regex");
+    let start = Instant::now();
+
+    let collect = UnifiedExecProcessManager::collect_output_until_deadline(
+        &output_buffer,
+        &output_notify,
+        &Arc::new(AtomicBool::new(false)),
+        &Arc::new(Notify::new()),
+        &CancellationToken::new(),
+        /*pause_state*/ None,
+        start + Duration::from_secs(10),
+        &yield_conditions,
+        Some(&screen),
+    );
+    let produce = async {
+        for chunk in ["1+1\r\n2\r\n", ">>> "] {
+            output_buffer
+                .lock()
+                .await
+                .push_chunk(chunk.as_bytes().to_vec());
+            output_notify.notify_one();
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+    };
+
+    let (collected, ()) = tokio::join!(collect, produce);
+    assert_eq!(collected.to_bytes(), b"1+1\r\n2\r\n>>> ".to_vec());
+    assert_eq!(screen.contents(), ">>> 1+1\n2\n>>>");
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn output_collection_yields_after_idle_period() {
+    let mut buffered_output = HeadTailBuffer::default();
+    buffered_output.push_chunk(b">>> ".to_vec());
+    let output_buffer = Arc::new(tokio::sync::Mutex::new(buffered_output));
+    let yield_conditions =
+        YieldConditions::new(/*pattern*/ None, Some(200)).expect("no pattern to compile");
+    let start = Instant::now();
+
+    let collected = UnifiedExecProcessManager::collect_output_until_deadline(
+        &output_buffer,
+        &Arc::new(Notify::new()),
+        &Arc::new(AtomicBool::new(false)),
+        &Arc::new(Notify::new()),
+        &CancellationToken::new(),
+        /*pause_state*/ None,
+        start + Duration::from_secs(10),
+        &yield_conditions,
+        /*screen*/ None,
+    )
+    .await;
+
+    assert_eq!(collected.to_bytes(), b">>> ".to_vec());
+    assert!(start.elapsed() >= Duration::from_millis(200));
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn invalid_yield_pattern_is_reported() {
+    let err = YieldConditions::new(Some("("), /*idle_ms*/ None).expect_err("unbalanced group");
+    assert!(err.starts_with("invalid yield_on_pattern `(`:"), "{err}");
+}
+
 #[tokio::test]
 async fn network_denial_fallback_message_names_sandbox_network_proxy() {
     let message = network_denial_message_for_session(/*session*/ None, /*deferred*/ None).await;
@@ -362,6 +478,7 @@ async fn failed_initial_end_for_unstored_process_uses_fallback_output() {
         hook_command: "echo before".to_string(),
         process_id: 123,
         yield_time_ms: 1000,
+        yield_conditions: YieldConditions::default(),
         max_output_tokens: None,
         #[allow(deprecated)]
         cwd: turn.cwd.clone().into(),
@@ -375,11 +492,13 @@ async fn failed_initial_end_for_unstored_process_uses_fallback_output() {
         shell_mode: codex_tools::UnifiedExecShellMode::Direct,
         network: None,
         tty: true,
+        screen: false,
         sandbox_permissions: crate::sandboxing::SandboxPermissions::UseDefault,
         additional_permissions: None,
         additional_permissions_preapproved: false,
         justification: None,
         prefix_rule: None,
+        secrets: Vec::new(),
     };
 
     let transcript = Arc::new(tokio::sync::Mutex::new(HeadTailBuffer::default()));
@@ -0,0 +1,71 @@
+//! Terminal emulation for unified exec screen mode.
+//!
+//! Full-screen programs (REPLs, `top`, curses installers) redraw in place with
+//! cursor movement and erase sequences, so their raw PTY output is hard to
+//! read. Screen mode feeds that output through a VT100 emulator as it is
+//! collected and returns the rendered grid instead.
+
+use std::sync::Mutex as StdMutex;
+
+use codex_utils_pty::TerminalSize;
+
+/// `TERM` advertised to processes started in screen mode.
+pub(crate) const SCREEN_MODE_TERM: &str = "xterm";
+
+pub(crate) struct TerminalScreen {
+    parser: StdMutex<vt100::Parser>,
+}
+
+impl TerminalScreen {
+    pub(crate) fn new(size: TerminalSize) -> Self {
+        Self {
+            parser: StdMutex::new(vt100::Parser::new(
+                size.rows, size.cols, /*scrollback_len*/ 0,
+            )),
+        }
+    }
+
+    pub(crate) fn process(&self, bytes: &[u8]) {
+        if let Ok(mut parser) = self.parser.lock() {
+            parser.process(bytes);
+        }
+    }
+
+    /// Visible rows with trailing whitespace and trailing blank rows removed.
+    pub(crate) fn contents(&self) -> String {
+        let Ok(parser) = self.parser.lock() else {
+            return String::new();
+        };
+        let screen = parser.screen();
+        let (_, cols) = screen.size();
+        let mut rows = screen
+            .rows(/*start*/ 0, cols)
+            .map(|row| row.trim_end().to_string())
+            .collect::<Vec<_>>();
+        while rows.last().is_some_and(String::is_empty) {
+            rows.pop();
+        }
+        rows.join("\n")
+    }
+
+    /// Renders the screen for the model, including where the cursor sits so it
+    /// can tell where typed input will land.
+    pub(crate) fn render(&self) -> String {
+        let (size, cursor) = match self.parser.lock() {
+            Ok(parser) => (parser.screen().size(), parser.screen().cursor_position()),
+            Err(_) => return String::new(),
+        };
+        let (rows, cols) = size;
+        let (cursor_row, cursor_col) = cursor;
+        format!(
+            "Screen ({rows}x{cols}, cursor at row {}, column {}):\n{}",
+            cursor_row + 1,
+            cursor_col + 1,
+            self.contents()
+        )
+    }
+}
+
+#[cfg(test)]
+#[path = "terminal_screen_tests.rs"]
+mod tests;
@@ -0,0 +1,27 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn screen() -> TerminalScreen {
+    TerminalScreen::new(TerminalSize { rows: 4, cols: 20 })
+}
+
+#[test]
+fn redraws_replace_earlier_output() {
+    let screen = screen();
+    screen.process(b"loading 10%");
+    screen.process(b"\r\x1b[Kloading 100%\r\n>>> ");
+
+    assert_eq!(screen.contents(), "loading 100%\n>>>");
+    assert_eq!(
+        screen.render(),
+        "Screen (4x20, cursor at row 2, column 5):\nloading 100%\n>>>"
+    );
+}
+
+#[test]
+fn cursor_addressing_places_text_on_the_grid() {
+    let screen = screen();
+    screen.process(b"\x1b[2J\x1b[1;1Htop\x1b[3;5Hcpu 42%");
+
+    assert_eq!(screen.contents(), "top\n\n    cpu 42%");
+}
@@ -27,6 +27,7 @@ use codex_protocol::protocol::GranularApprovalConfig;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::ReviewDecision;
 use codex_protocol::protocol::SandboxPolicy;
+use codex_protocol::protocol::SelectedFileChange;
 use codex_protocol::user_input::UserInput;
 use core_test_support::managed_network_requirements_loader;
 use core_test_support::responses::ev_apply_patch_custom_tool_call;
@@ -2131,6 +2132,92 @@ async fn approving_apply_patch_for_session_skips_future_prompts_for_same_file()
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+#[cfg(unix)]
+async fn approving_selected_patch_changes_applies_only_accepted_files() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let approval_policy = AskForApproval::UnlessTrusted;
+    let sandbox_policy = SandboxPolicy::DangerFullAccess;
+    let sandbox_policy_for_config = sandbox_policy.clone();
+
+    let mut builder = test_codex()
+        .with_model("gpt-5.4")
+        .with_config(move |config| {
+            config.permissions.approval_policy = Constrained::allow_any(approval_policy);
+            config
+                .set_legacy_sandbox_policy(sandbox_policy_for_config)
+                .expect("set sandbox policy");
+            config.approvals_reviewer = ApprovalsReviewer::User;
+        });
+    let test = builder.build(&server).await?;
+
+    let kept = test.cwd.path().join("selected_kept.txt");
+    let rejected = test.cwd.path().join("selected_rejected.txt");
+    let patch = "*** Begin Patch\n*** Add File: selected_kept.txt\n+kept\n*** Add File: selected_rejected.txt\n+rejected\n*** End Patch\n";
+    let call_id = "apply_patch_selected_changes";
+
+    let _ = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_response_created("resp-1"),
+            ev_apply_patch_custom_tool_call(call_id, patch),
+            ev_completed("resp-1"),
+        ]),
+    )
+    .await;
+    let results_mock = mount_sse_once(
+        &server,
+        sse(vec![
+            ev_assistant_message("msg-1", "done"),
+            ev_completed("resp-2"),
+        ]),
+    )
+    .await;
+
+    submit_turn(
+        &test,
+        "apply_patch selected changes",
+        approval_policy,
+        sandbox_policy.clone(),
+    )
+    .await?;
+    let approval = expect_patch_approval(&test, call_id).await;
+    let kept_path = approval
+        .changes
+        .keys()
+        .find(|path| path.ends_with("selected_kept.txt"))
+        .cloned()
+        .expect("kept file should be part of the approval request");
+    test.codex
+        .submit(Op::PatchApproval {
+            id: approval.call_id,
+            decision: ReviewDecision::ApprovedSelectedChanges {
+                changes: vec![SelectedFileChange {
+                    path: kept_path,
+                    hunks: None,
+                }],
+            },
+        })
+        .await?;
+    wait_for_completion(&test).await;
+
+    assert_eq!(fs::read_to_string(&kept)?, "kept\n");
+    assert!(!rejected.exists());
+    let (output, _) = results_mock
+        .single_request()
+        .custom_tool_call_output_content_and_success(call_id)
+        .expect("apply_patch output should be sent back to the model");
+    let output = output.expect("apply_patch output should have content");
+    assert!(
+        output.contains("- selected_rejected.txt (all changes)"),
+        "unexpected output: {output}"
+    );
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 #[cfg(unix)]
 async fn approving_execpolicy_amendment_persists_policy_and_skips_future_prompts() -> Result<()> {
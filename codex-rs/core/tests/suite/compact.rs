@@ -930,6 +930,127 @@ async fn manual_compact_uses_custom_prompt() {
     }
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn pinned_context_is_reinjected_after_manual_compact() {
+    skip_if_no_network!();
+
+    let server = start_mock_server().await;
+    let request_log = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_assistant_message("m1", FIRST_REPLY),
+                ev_completed("r1"),
+            ]),
+            sse(vec![
+                ev_assistant_message("m2", SUMMARY_TEXT),
+                ev_completed("r2"),
+            ]),
+            sse(vec![ev_completed("r3")]),
+        ],
+    )
+    .await;
+
+    let model_provider = non_openai_model_provider(&server);
+    let mut builder = test_codex().with_config(move |config| {
+        config.model_provider = model_provider;
+        set_test_compact_prompt(config);
+    });
+    let codex = builder
+        .build(&server)
+        .await
+        .expect("create conversation")
+        .codex;
+    let pin = codex
+        .pin_context("The prod DB is read-only; never run migrations.")
+        .await
+        .expect("pin context");
+
+    for text in ["hello world", THIRD_USER_MSG] {
+        codex
+            .submit(Op::UserInput {
+                items: vec![UserInput::Text {
+                    text: text.to_string(),
+                    text_elements: Vec::new(),
+                }],
+                final_output_json_schema: None,
+                responsesapi_client_metadata: None,
+                additional_context: Default::default(),
+                thread_settings: Default::default(),
+            })
+            .await
+            .expect("submit user input");
+        wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+        if text == "hello world" {
+            codex.submit(Op::Compact).await.expect("submit compact");
+            wait_for_event(&codex, |ev| matches!(ev, EventMsg::TurnComplete(_))).await;
+        }
+    }
+
+    let requests = request_log.requests();
+    assert_eq!(requests.len(), 3, "expected exactly three requests");
+    let first = requests[0].body_json().to_string();
+    let after_compact = requests[2].body_json().to_string();
+    for body in [&first, &after_compact] {
+        assert_eq!(body.matches("<pinned_context>").count(), 1, "body: {body}");
+        assert!(body_contains_text(body, &pin.text), "body: {body}");
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn pinned_context_is_restored_on_resume() -> Result<()> {
+    skip_if_no_network!(Ok(()));
+
+    let server = start_mock_server().await;
+    let request_log = mount_sse_sequence(
+        &server,
+        vec![
+            sse(vec![
+                ev_assistant_message("m1", FIRST_REPLY),
+                ev_completed("r1"),
+            ]),
+            sse(vec![ev_completed("r2")]),
+        ],
+    )
+    .await;
+
+    let mut builder = test_codex();
+    let initial = builder.build(&server).await?;
+    let home = Arc::clone(&initial.home);
+    let rollout_path = initial
+        .session_configured
+        .rollout_path
+        .clone()
+        .expect("rollout path");
+    initial.submit_turn("hello world").await?;
+    let kept = initial
+        .codex
+        .pin_context("The prod DB is read-only; never run migrations.")
+        .await?;
+    let dropped = initial.codex.pin_context("Use the staging bucket.").await?;
+    initial.codex.unpin_context(&dropped.id).await;
+    initial.codex.submit(Op::Shutdown).await?;
+    wait_for_event(&initial.codex, |event| {
+        matches!(event, EventMsg::ShutdownComplete)
+    })
+    .await;
+
+    let resumed = builder.resume(&server, home, rollout_path).await?;
+    assert_eq!(resumed.codex.pinned_context().await, vec![kept.clone()]);
+    let next = resumed.codex.pin_context("Ship on Fridays.").await?;
+    assert_eq!(next.id, "pin-3");
+    resumed.submit_turn(THIRD_USER_MSG).await?;
+
+    let requests = request_log.requests();
+    assert_eq!(requests.len(), 2, "expected exactly two requests");
+    let after_resume = requests[1].body_json().to_string();
+    assert!(
+        body_contains_text(&after_resume, &kept.text),
+        "body: {after_resume}"
+    );
+    Ok(())
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn manual_compact_emits_api_and_local_token_usage_events() {
     skip_if_no_network!();
@@ -33,6 +33,7 @@ mod additional_context;
 mod agent_execution;
 mod agent_websocket;
 mod agents_md;
+mod anthropic_messages;
 mod apply_patch_cli;
 #[cfg(not(target_os = "windows"))]
 mod approvals;
@@ -391,5 +391,6 @@ fn test_remote_model(slug: &str, priority: i32) -> ModelInfo {
         auto_review_model_override: None,
         tool_mode: None,
         multi_agent_version: None,
+        tokenizer: None,
     }
 }
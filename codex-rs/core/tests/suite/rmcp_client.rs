@@ -1765,6 +1765,7 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
                 auto_review_model_override: None,
                 tool_mode: None,
                 multi_agent_version: None,
+                tokenizer: None,
             }],
         },
     )
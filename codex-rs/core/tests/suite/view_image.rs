@@ -1388,6 +1388,7 @@ async fn view_image_tool_returns_unsupported_message_for_text_only_model() -> an
         comp_hash: None,
         effective_context_window_percent: 95,
         experimental_supported_tools: Vec::new(),
+        tokenizer: None,
     };
     mount_models_once(
         &server,
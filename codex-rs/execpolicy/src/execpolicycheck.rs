@@ -1,9 +1,11 @@
 use std::fs;
+use std::path::Path;
 use std::path::PathBuf;
 
 use anyhow::Context;
 use anyhow::Result;
 use clap::Parser;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use serde::Serialize;
 
 use crate::Decision;
@@ -28,6 +30,15 @@ pub struct ExecPolicyCheckCommand {
     #[arg(long)]
     pub resolve_host_executables: bool,
 
+    /// Working directory for rules with `path_args`. Defaults to the current
+    /// directory.
+    #[arg(long, value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    /// Writable root for rules with `path_args = "writable_roots"` (repeatable).
+    #[arg(long = "writable-root", value_name = "DIR")]
+    pub writable_roots: Vec<PathBuf>,
+
     /// Command tokens to check against the policy.
     #[arg(
         value_name = "COMMAND",
@@ -45,9 +56,11 @@ impl ExecPolicyCheckCommand {
         let matched_rules = policy.matches_for_command_with_options(
             &self.command,
             /*heuristics_fallback*/ None,
-            &MatchOptions {
-                resolve_host_executables: self.resolve_host_executables,
-            },
+            &match_options(
+                self.resolve_host_executables,
+                self.cwd.as_deref(),
+                &self.writable_roots,
+            )?,
         );
 
         let json = format_matches_json(&matched_rules, self.pretty)?;
@@ -85,6 +98,32 @@ pub fn load_policies(policy_paths: &[PathBuf]) -> Result<Policy> {
     Ok(parser.build())
 }
 
+/// Builds match options for the CLI, resolving relative paths against the
+/// current directory.
+pub(crate) fn match_options(
+    resolve_host_executables: bool,
+    cwd: Option<&Path>,
+    writable_roots: &[PathBuf],
+) -> Result<MatchOptions> {
+    let cwd = match cwd {
+        Some(cwd) => AbsolutePathBuf::relative_to_current_dir(cwd),
+        None => AbsolutePathBuf::current_dir(),
+    }
+    .context("failed to resolve working directory")?;
+    let writable_roots = writable_roots
+        .iter()
+        .map(|root| {
+            AbsolutePathBuf::relative_to_current_dir(root)
+                .with_context(|| format!("failed to resolve writable root {}", root.display()))
+        })
+        .collect::<Result<_>>()?;
+    Ok(MatchOptions {
+        resolve_host_executables,
+        cwd: Some(cwd),
+        writable_roots,
+    })
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ExecPolicyCheckOutput<'a> {
@@ -0,0 +1,376 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use clap::Parser;
+use serde::Serialize;
+use shlex::try_join;
+
+use crate::ArgvRule;
+use crate::Decision;
+use crate::MatchOptions;
+use crate::Policy;
+use crate::PrefixPattern;
+use crate::PrefixRule;
+use crate::RuleRef;
+use crate::execpolicycheck::load_policies;
+use crate::execpolicycheck::match_options;
+use crate::policy::render_pattern_token;
+
+/// Arguments for running a corpus of commands against one or more execpolicy files.
+#[derive(Debug, Parser, Clone)]
+pub struct ExecPolicyTestCommand {
+    /// Paths to execpolicy rule files to evaluate (repeatable).
+    #[arg(short = 'r', long = "rules", value_name = "PATH", required = true)]
+    pub rules: Vec<PathBuf>,
+
+    /// Resolve absolute program paths against basename rules, gated by any
+    /// `host_executable()` definitions in the loaded policy files.
+    #[arg(long)]
+    pub resolve_host_executables: bool,
+
+    /// Working directory for rules with `path_args`. Defaults to the current
+    /// directory.
+    #[arg(long, value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    /// Writable root for rules with `path_args = "writable_roots"` (repeatable).
+    #[arg(long = "writable-root", value_name = "DIR")]
+    pub writable_roots: Vec<PathBuf>,
+
+    /// Print the report as JSON instead of text.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Also fail when a loaded rule matched none of the commands.
+    #[arg(long)]
+    pub fail_on_unused_rules: bool,
+
+    /// File of expected outcomes and commands, one per line:
+    /// `<allow|prompt|forbidden|unmatched> <command>`. Blank lines and lines
+    /// starting with `#` are ignored.
+    #[arg(value_name = "CASES")]
+    pub cases: PathBuf,
+}
+
+impl ExecPolicyTestCommand {
+    /// Load the policies, evaluate every case, print the report, and fail if
+    /// any case did not produce its expected outcome.
+    pub fn run(&self) -> Result<()> {
+        let policy = load_policies(&self.rules)?;
+        let cases = load_test_cases(&self.cases)?;
+        let report = run_policy_tests(
+            &policy,
+            &cases,
+            &match_options(
+                self.resolve_host_executables,
+                self.cwd.as_deref(),
+                &self.writable_roots,
+            )?,
+        );
+
+        if self.json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{}", format_report_text(&report));
+        }
+
+        if !report.failures.is_empty() {
+            bail!(
+                "{} of {} cases did not match their expected outcome",
+                report.failures.len(),
+                report.total
+            );
+        }
+        let unused_rules = report.unused_rules().count();
+        if self.fail_on_unused_rules && unused_rules > 0 {
+            bail!("{unused_rules} rules matched none of the commands");
+        }
+        Ok(())
+    }
+}
+
+/// What a test case expects the policy to do with its command.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PolicyOutcome {
+    Allow,
+    Prompt,
+    Forbidden,
+    /// No rule matched, so the command falls through to heuristics.
+    Unmatched,
+}
+
+impl PolicyOutcome {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "unmatched" => Ok(Self::Unmatched),
+            other => Ok(Decision::parse(other)?.into()),
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Prompt => "prompt",
+            Self::Forbidden => "forbidden",
+            Self::Unmatched => "unmatched",
+        }
+    }
+}
+
+impl From<Decision> for PolicyOutcome {
+    fn from(decision: Decision) -> Self {
+        match decision {
+            Decision::Allow => Self::Allow,
+            Decision::Prompt => Self::Prompt,
+            Decision::Forbidden => Self::Forbidden,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PolicyTestCase {
+    /// 1-based line number in the cases file.
+    pub line: usize,
+    pub expected: PolicyOutcome,
+    pub command: Vec<String>,
+}
+
+pub fn load_test_cases(path: &Path) -> Result<Vec<PolicyTestCase>> {
+    let contents = fs::read_to_string(path)
+        .with_context(|| format!("failed to read test cases at {}", path.display()))?;
+    parse_test_cases(&contents)
+        .with_context(|| format!("failed to parse test cases at {}", path.display()))
+}
+
+pub fn parse_test_cases(contents: &str) -> Result<Vec<PolicyTestCase>> {
+    let mut cases = Vec::new();
+    for (index, raw_line) in contents.lines().enumerate() {
+        let line = index + 1;
+        let trimmed = raw_line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        let (expected, command) = trimmed
+            .split_once(char::is_whitespace)
+            .with_context(|| format!("line {line}: expected `<outcome> <command>`"))?;
+        let expected =
+            PolicyOutcome::parse(expected).with_context(|| format!("line {line}: bad outcome"))?;
+        let command = shlex::split(command)
+            .filter(|command| !command.is_empty())
+            .with_context(|| format!("line {line}: unable to tokenize command"))?;
+        cases.push(PolicyTestCase {
+            line,
+            expected,
+            command,
+        });
+    }
+    Ok(cases)
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyTestReport {
+    pub total: usize,
+    pub passed: usize,
+    pub failures: Vec<PolicyTestFailure>,
+    /// Commands no rule matched, whether or not the case expected that.
+    pub unmatched: Vec<PolicyTestUnmatched>,
+    pub rules: Vec<RuleCoverage>,
+}
+
+impl PolicyTestReport {
+    pub fn unused_rules(&self) -> impl Iterator<Item = &RuleCoverage> {
+        self.rules.iter().filter(|rule| rule.hits == 0)
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyTestFailure {
+    pub line: usize,
+    pub command: String,
+    pub expected: PolicyOutcome,
+    pub actual: PolicyOutcome,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyTestUnmatched {
+    pub line: usize,
+    pub command: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleCoverage {
+    pub rule: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<Decision>,
+    pub hits: usize,
+}
+
+/// Evaluates every case against the policy and counts how many cases each
+/// rule matched.
+pub fn run_policy_tests(
+    policy: &Policy,
+    cases: &[PolicyTestCase],
+    options: &MatchOptions,
+) -> PolicyTestReport {
+    let mut programs = policy.rules().keys().collect::<Vec<_>>();
+    programs.sort();
+    let mut coverage = programs
+        .into_iter()
+        .flat_map(|program| policy.rules().get_vec(program).into_iter().flatten())
+        .map(|rule| (Arc::clone(rule), 0))
+        .collect::<Vec<(RuleRef, usize)>>();
+
+    let mut report = PolicyTestReport {
+        total: cases.len(),
+        passed: 0,
+        failures: Vec::new(),
+        unmatched: Vec::new(),
+        rules: Vec::new(),
+    };
+    for case in cases {
+        let matched_rules = policy.matched_rules_for_command(&case.command, options);
+        for (rule, _) in &matched_rules {
+            if let Some((_, hits)) = coverage
+                .iter_mut()
+                .find(|(candidate, _)| Arc::ptr_eq(candidate, rule))
+            {
+                *hits += 1;
+            }
+        }
+
+        let actual = matched_rules
+            .iter()
+            .map(|(_, rule_match)| rule_match.decision())
+            .max()
+            .map_or(PolicyOutcome::Unmatched, PolicyOutcome::from);
+        let command = render_command(&case.command);
+        if actual == PolicyOutcome::Unmatched {
+            report.unmatched.push(PolicyTestUnmatched {
+                line: case.line,
+                command: command.clone(),
+            });
+        }
+        if actual == case.expected {
+            report.passed += 1;
+        } else {
+            report.failures.push(PolicyTestFailure {
+                line: case.line,
+                command,
+                expected: case.expected,
+                actual,
+            });
+        }
+    }
+
+    report.rules = coverage
+        .into_iter()
+        .map(|(rule, hits)| {
+            let (rule, decision) = describe_rule(&rule);
+            RuleCoverage {
+                rule,
+                decision,
+                hits,
+            }
+        })
+        .collect();
+    report
+}
+
+pub fn format_report_text(report: &PolicyTestReport) -> String {
+    let mut out = String::new();
+    for failure in &report.failures {
+        let _ = writeln!(
+            out,
+            "FAIL line {}: `{}`: expected {}, got {}",
+            failure.line,
+            failure.command,
+            failure.expected.as_str(),
+            failure.actual.as_str()
+        );
+    }
+    for unmatched in &report.unmatched {
+        let _ = writeln!(
+            out,
+            "UNMATCHED line {}: `{}` falls through to heuristics",
+            unmatched.line, unmatched.command
+        );
+    }
+    if !report.failures.is_empty() || !report.unmatched.is_empty() {
+        out.push('\n');
+    }
+
+    out.push_str("Rule hits:\n");
+    for rule in &report.rules {
+        let decision = rule
+            .decision
+            .map_or("-", |decision| PolicyOutcome::from(decision).as_str());
+        let _ = writeln!(out, "{:>6}  {decision:<9}  {}", rule.hits, rule.rule);
+    }
+
+    let _ = writeln!(
+        out,
+        "\n{} cases: {} passed, {} failed, {} unmatched; {} of {} rules never matched",
+        report.total,
+        report.passed,
+        report.failures.len(),
+        report.unmatched.len(),
+        report.unused_rules().count(),
+        report.rules.len()
+    );
+    out
+}
+
+fn describe_rule(rule: &RuleRef) -> (String, Option<Decision>) {
+    if let Some(prefix_rule) = rule.as_any().downcast_ref::<PrefixRule>() {
+        return (
+            render_pattern(&prefix_rule.pattern),
+            Some(prefix_rule.decision),
+        );
+    }
+    if let Some(argv_rule) = rule.as_any().downcast_ref::<ArgvRule>() {
+        let mut description = render_pattern(&argv_rule.pattern);
+        let conditions = &argv_rule.conditions;
+        if !conditions.when_flags.is_empty() {
+            let _ = write!(description, " when [{}]", conditions.when_flags.join("|"));
+        }
+        if !conditions.unless_flags.is_empty() {
+            let _ = write!(
+                description,
+                " unless [{}]",
+                conditions.unless_flags.join("|")
+            );
+        }
+        if let Some(scope) = conditions.path_args {
+            let _ = write!(description, " paths in {}", scope.as_policy_string());
+        }
+        return (description, Some(argv_rule.decision));
+    }
+    (format!("{rule:?}"), None)
+}
+
+fn render_pattern(pattern: &PrefixPattern) -> String {
+    std::iter::once(pattern.first.to_string())
+        .chain(pattern.rest.iter().map(render_pattern_token))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn render_command(command: &[String]) -> String {
+    try_join(command.iter().map(String::as_str)).unwrap_or_else(|_| command.join(" "))
+}
+
+#[cfg(test)]
+#[path = "execpolicytest_tests.rs"]
+mod tests;
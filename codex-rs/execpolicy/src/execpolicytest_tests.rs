@@ -0,0 +1,106 @@
+use super::*;
+use crate::PolicyParser;
+use pretty_assertions::assert_eq;
+
+fn policy(source: &str) -> Policy {
+    let mut parser = PolicyParser::new();
+    parser
+        .parse("test.rules", source)
+        .expect("policy should parse");
+    parser.build()
+}
+
+#[test]
+fn parses_cases_and_skips_comments() {
+    let cases = parse_test_cases(
+        "# shared corpus\n\nallow git status\nunmatched  cargo build --release\nforbidden rm -rf '/tmp/a b'\n",
+    )
+    .expect("cases should parse");
+
+    assert_eq!(
+        cases,
+        vec![
+            PolicyTestCase {
+                line: 3,
+                expected: PolicyOutcome::Allow,
+                command: vec!["git".to_string(), "status".to_string()],
+            },
+            PolicyTestCase {
+                line: 4,
+                expected: PolicyOutcome::Unmatched,
+                command: vec![
+                    "cargo".to_string(),
+                    "build".to_string(),
+                    "--release".to_string(),
+                ],
+            },
+            PolicyTestCase {
+                line: 5,
+                expected: PolicyOutcome::Forbidden,
+                command: vec!["rm".to_string(), "-rf".to_string(), "/tmp/a b".to_string()],
+            },
+        ]
+    );
+}
+
+#[test]
+fn rejects_unknown_outcome() {
+    let err = parse_test_cases("maybe git status\n").expect_err("outcome should be rejected");
+
+    assert!(err.to_string().contains("line 1"), "{err:#}");
+}
+
+#[test]
+fn reports_mismatches_fallthrough_and_rule_hits() {
+    let policy = policy(
+        r#"
+prefix_rule(pattern = ["git", ["status", "diff"]])
+prefix_rule(pattern = ["git", "push"], decision = "prompt")
+prefix_rule(pattern = ["rm", "-rf"], decision = "forbidden")
+"#,
+    );
+    let cases = parse_test_cases(
+        "allow git status\nallow git diff\nallow git push\nunmatched cargo build\nallow ls\n",
+    )
+    .expect("cases should parse");
+
+    let report = run_policy_tests(&policy, &cases, &MatchOptions::default());
+
+    assert_eq!(report.total, 5);
+    assert_eq!(report.passed, 3);
+    assert_eq!(
+        report
+            .failures
+            .iter()
+            .map(|failure| (failure.line, failure.expected, failure.actual))
+            .collect::<Vec<_>>(),
+        vec![
+            (3, PolicyOutcome::Allow, PolicyOutcome::Prompt),
+            (5, PolicyOutcome::Allow, PolicyOutcome::Unmatched),
+        ]
+    );
+    assert_eq!(
+        report
+            .unmatched
+            .iter()
+            .map(|unmatched| unmatched.command.as_str())
+            .collect::<Vec<_>>(),
+        vec!["cargo build", "ls"]
+    );
+    assert_eq!(
+        report
+            .rules
+            .iter()
+            .map(|rule| (rule.rule.as_str(), rule.decision, rule.hits))
+            .collect::<Vec<_>>(),
+        vec![
+            ("git [status|diff]", Some(Decision::Allow), 2),
+            ("git push", Some(Decision::Prompt), 1),
+            ("rm -rf", Some(Decision::Forbidden), 0),
+        ]
+    );
+    assert!(
+        format_report_text(&report)
+            .ends_with("5 cases: 3 passed, 2 failed, 2 unmatched; 1 of 3 rules never matched\n")
+    );
+}
@@ -2,6 +2,7 @@ pub(crate) mod amend;
 pub(crate) mod decision;
 pub(crate) mod error;
 pub(crate) mod execpolicycheck;
+pub(crate) mod execpolicytest;
 mod executable_name;
 pub(crate) mod parser;
 pub(crate) mod policy;
@@ -18,11 +19,15 @@ pub use error::Result;
 pub use error::TextPosition;
 pub use error::TextRange;
 pub use execpolicycheck::ExecPolicyCheckCommand;
+pub use execpolicytest::ExecPolicyTestCommand;
 pub use parser::PolicyParser;
 pub use policy::Evaluation;
 pub use policy::MatchOptions;
 pub use policy::Policy;
+pub use rule::ArgvConditions;
+pub use rule::ArgvRule;
 pub use rule::NetworkRuleProtocol;
+pub use rule::PathArgsScope;
 pub use rule::PatternToken;
 pub use rule::PrefixPattern;
 pub use rule::PrefixRule;
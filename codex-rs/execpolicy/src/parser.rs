@@ -8,7 +8,10 @@ use starlark::eval::Evaluator;
 use starlark::starlark_module;
 use starlark::syntax::AstModule;
 use starlark::syntax::Dialect;
+use starlark::values::Heap;
 use starlark::values::Value;
+use starlark::values::dict::AllocDict;
+use starlark::values::dict::DictRef;
 use starlark::values::list::ListRef;
 use starlark::values::list::UnpackList;
 use starlark::values::none::NoneType;
@@ -26,12 +29,17 @@ use crate::error::TextPosition;
 use crate::error::TextRange;
 use crate::executable_name::executable_lookup_key;
 use crate::executable_name::executable_path_lookup_key;
+use crate::rule::ArgvConditions;
+use crate::rule::ArgvRule;
 use crate::rule::NetworkRule;
 use crate::rule::NetworkRuleProtocol;
+use crate::rule::PathArgsScope;
 use crate::rule::PatternToken;
 use crate::rule::PrefixPattern;
 use crate::rule::PrefixRule;
 use crate::rule::RuleRef;
+use crate::rule::TokenGlob;
+use crate::rule::TokenRegex;
 use crate::rule::validate_match_examples;
 use crate::rule::validate_not_match_examples;
 
@@ -208,14 +216,53 @@ fn parse_pattern_token<'v>(value: Value<'v>) -> Result<PatternToken> {
             [single] => Ok(PatternToken::Single(single.clone())),
             _ => Ok(PatternToken::Alts(tokens)),
         }
+    } else if let Some(dict) = DictRef::from_value(value) {
+        parse_matcher_token(&dict)
     } else {
         Err(Error::InvalidPattern(format!(
-            "pattern element must be a string or list of strings (got {})",
+            "pattern element must be a string, list of strings, glob(), or regex() (got {})",
             value.get_type()
         )))
     }
 }
 
+/// Parses the `{"glob": ...}` / `{"regex": ...}` values built by the `glob()`
+/// and `regex()` builtins.
+fn parse_matcher_token(dict: &DictRef) -> Result<PatternToken> {
+    let mut entries = dict.iter();
+    let (Some((kind, source)), None) = (entries.next(), entries.next()) else {
+        return Err(Error::InvalidPattern(
+            "pattern matcher must have exactly one of `glob` or `regex`".to_string(),
+        ));
+    };
+    let source = source.unpack_str().ok_or_else(|| {
+        Error::InvalidPattern(format!(
+            "pattern matcher source must be a string (got {})",
+            source.get_type()
+        ))
+    })?;
+    match kind.unpack_str() {
+        Some("glob") => TokenGlob::new(source).map(PatternToken::Glob),
+        Some("regex") => TokenRegex::new(source).map(PatternToken::Regex),
+        _ => Err(Error::InvalidPattern(format!(
+            "pattern matcher must be `glob` or `regex` (got {kind})"
+        ))),
+    }
+}
+
+fn parse_flags(flags: Option<UnpackList<String>>, field: &str) -> Result<Vec<String>> {
+    let flags = flags.map(|flags| flags.items).unwrap_or_default();
+    if let Some(flag) = flags
+        .iter()
+        .find(|flag| !flag.starts_with('-') || flag.trim_start_matches('-').is_empty())
+    {
+        return Err(Error::InvalidRule(format!(
+            "{field} entries must be flags starting with `-` (got {flag:?})"
+        )));
+    }
+    Ok(flags)
+}
+
 fn parse_examples<'v>(examples: UnpackList<Value<'v>>) -> Result<Vec<Vec<String>>> {
     examples.items.into_iter().map(parse_example).collect()
 }
@@ -352,6 +399,9 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         r#match: Option<UnpackList<Value<'v>>>,
         not_match: Option<UnpackList<Value<'v>>>,
         justification: Option<&'v str>,
+        when_flags: Option<UnpackList<String>>,
+        unless_flags: Option<UnpackList<String>>,
+        path_args: Option<&'v str>,
         eval: &mut Evaluator<'v, '_, '_>,
     ) -> anyhow::Result<NoneType> {
         let decision = match decision {
@@ -368,6 +418,11 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         };
 
         let pattern_tokens = parse_pattern(pattern)?;
+        let conditions = ArgvConditions {
+            when_flags: parse_flags(when_flags, "when_flags")?,
+            unless_flags: parse_flags(unless_flags, "unless_flags")?,
+            path_args: path_args.map(PathArgsScope::parse).transpose()?,
+        };
 
         let matches: Vec<Vec<String>> =
             r#match.map(parse_examples).transpose()?.unwrap_or_default();
@@ -385,20 +440,37 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
             .split_first()
             .ok_or_else(|| Error::InvalidPattern("pattern cannot be empty".to_string()))?;
 
+        if matches!(first_token, PatternToken::Glob(_) | PatternToken::Regex(_)) {
+            return Err(Error::InvalidPattern(
+                "the first pattern element must be a literal program name".to_string(),
+            )
+            .into());
+        }
+
         let rest: Arc<[PatternToken]> = remaining_tokens.to_vec().into();
 
         let rules: Vec<RuleRef> = first_token
             .alternatives()
             .iter()
             .map(|head| {
-                Arc::new(PrefixRule {
-                    pattern: PrefixPattern {
-                        first: Arc::from(head.as_str()),
-                        rest: rest.clone(),
-                    },
-                    decision,
-                    justification: justification.clone(),
-                }) as RuleRef
+                let pattern = PrefixPattern {
+                    first: Arc::from(head.as_str()),
+                    rest: rest.clone(),
+                };
+                if conditions.is_empty() {
+                    Arc::new(PrefixRule {
+                        pattern,
+                        decision,
+                        justification: justification.clone(),
+                    }) as RuleRef
+                } else {
+                    Arc::new(ArgvRule {
+                        pattern,
+                        conditions: conditions.clone(),
+                        decision,
+                        justification: justification.clone(),
+                    }) as RuleRef
+                }
             })
             .collect();
 
@@ -407,6 +479,19 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
         Ok(NoneType)
     }
 
+    /// Pattern element matching any token that the shell-style glob matches.
+    fn glob<'v>(pattern: &'v str, heap: &'v Heap) -> anyhow::Result<Value<'v>> {
+        TokenGlob::new(pattern)?;
+        Ok(heap.alloc(AllocDict([("glob", pattern)])))
+    }
+
+    /// Pattern element matching any token that the regular expression matches
+    /// in full.
+    fn regex<'v>(pattern: &'v str, heap: &'v Heap) -> anyhow::Result<Value<'v>> {
+        TokenRegex::new(pattern)?;
+        Ok(heap.alloc(AllocDict([("regex", pattern)])))
+    }
+
     fn network_rule<'v>(
         host: &'v str,
         protocol: &'v str,
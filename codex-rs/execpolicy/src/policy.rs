@@ -22,6 +22,11 @@ type HeuristicsFallback<'a> = Option<&'a dyn Fn(&[String]) -> Decision>;
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct MatchOptions {
     pub resolve_host_executables: bool,
+    /// Directory the command runs in. Rules with `path_args` never match
+    /// without it.
+    pub cwd: Option<AbsolutePathBuf>,
+    /// Roots the command may write to, for `path_args = "writable_roots"`.
+    pub writable_roots: Vec<AbsolutePathBuf>,
 }
 
 #[derive(Clone, Debug)]
@@ -272,15 +277,10 @@ impl Policy {
         options: &MatchOptions,
     ) -> Vec<RuleMatch> {
         let matched_rules = self
-            .match_exact_rules(cmd)
-            .filter(|matched_rules| !matched_rules.is_empty())
-            .or_else(|| {
-                options
-                    .resolve_host_executables
-                    .then(|| self.match_host_executable_rules(cmd))
-                    .filter(|matched_rules| !matched_rules.is_empty())
-            })
-            .unwrap_or_default();
+            .matched_rules_for_command(cmd, options)
+            .into_iter()
+            .map(|(_rule, rule_match)| rule_match)
+            .collect::<Vec<_>>();
 
         if matched_rules.is_empty()
             && let Some(heuristics_fallback) = heuristics_fallback
@@ -294,17 +294,51 @@ impl Policy {
         }
     }
 
-    fn match_exact_rules(&self, cmd: &[String]) -> Option<Vec<RuleMatch>> {
+    /// Returns each rule that matches the given command alongside its match,
+    /// without a heuristics fallback.
+    pub fn matched_rules_for_command(
+        &self,
+        cmd: &[String],
+        options: &MatchOptions,
+    ) -> Vec<(RuleRef, RuleMatch)> {
+        self.match_exact_rules(cmd, options)
+            .filter(|matched_rules| !matched_rules.is_empty())
+            .or_else(|| {
+                options
+                    .resolve_host_executables
+                    .then(|| self.match_host_executable_rules(cmd, options))
+                    .filter(|matched_rules| !matched_rules.is_empty())
+            })
+            .unwrap_or_default()
+    }
+
+    fn match_exact_rules(
+        &self,
+        cmd: &[String],
+        options: &MatchOptions,
+    ) -> Option<Vec<(RuleRef, RuleMatch)>> {
         let first = cmd.first()?;
         Some(
             self.rules_by_program
                 .get_vec(first)
-                .map(|rules| rules.iter().filter_map(|rule| rule.matches(cmd)).collect())
+                .map(|rules| {
+                    rules
+                        .iter()
+                        .filter_map(|rule| {
+                            rule.matches_with_options(cmd, options)
+                                .map(|rule_match| (Arc::clone(rule), rule_match))
+                        })
+                        .collect()
+                })
                 .unwrap_or_default(),
         )
     }
 
-    fn match_host_executable_rules(&self, cmd: &[String]) -> Vec<RuleMatch> {
+    fn match_host_executable_rules(
+        &self,
+        cmd: &[String],
+        options: &MatchOptions,
+    ) -> Vec<(RuleRef, RuleMatch)> {
         let Some(first) = cmd.first() else {
             return Vec::new();
         };
@@ -328,8 +362,12 @@ impl Policy {
             .collect::<Vec<_>>();
         rules
             .iter()
-            .filter_map(|rule| rule.matches(&basename_command))
-            .map(|rule_match| rule_match.with_resolved_program(&program))
+            .filter_map(|rule| {
+                rule.matches_with_options(&basename_command, options)
+                    .map(|rule_match| {
+                        (Arc::clone(rule), rule_match.with_resolved_program(&program))
+                    })
+            })
             .collect()
     }
 }
@@ -339,10 +377,12 @@ fn upsert_domain(entries: &mut Vec<String>, host: &str) {
     entries.push(host.to_string());
 }
 
-fn render_pattern_token(token: &PatternToken) -> String {
+pub(crate) fn render_pattern_token(token: &PatternToken) -> String {
     match token {
         PatternToken::Single(value) => value.clone(),
         PatternToken::Alts(alternatives) => format!("[{}]", alternatives.join("|")),
+        PatternToken::Glob(glob) => glob.source().to_string(),
+        PatternToken::Regex(regex) => format!("/{}/", regex.source()),
     }
 }
 
@@ -4,6 +4,9 @@ use crate::error::Result;
 use crate::policy::MatchOptions;
 use crate::policy::Policy;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use globset::Glob;
+use globset::GlobMatcher;
+use regex::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use shlex::try_join;
@@ -11,11 +14,14 @@ use std::any::Any;
 use std::fmt::Debug;
 use std::sync::Arc;
 
-/// Matches a single command token, either a fixed string or one of several allowed alternatives.
+/// Matches a single command token, either a fixed string, one of several allowed alternatives,
+/// or a glob or regular expression over the whole token.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum PatternToken {
     Single(String),
     Alts(Vec<String>),
+    Glob(TokenGlob),
+    Regex(TokenRegex),
 }
 
 impl PatternToken {
@@ -23,17 +29,82 @@ impl PatternToken {
         match self {
             Self::Single(expected) => expected == token,
             Self::Alts(alternatives) => alternatives.iter().any(|alt| alt == token),
+            Self::Glob(glob) => glob.matcher.is_match(token),
+            Self::Regex(regex) => regex.regex.is_match(token),
         }
     }
 
+    /// Literal alternatives for this token; empty for glob and regex tokens.
     pub fn alternatives(&self) -> &[String] {
         match self {
             Self::Single(expected) => std::slice::from_ref(expected),
             Self::Alts(alternatives) => alternatives,
+            Self::Glob(_) | Self::Regex(_) => &[],
         }
     }
 }
 
+/// Shell-style glob (`*`, `?`, `[...]`, `{a,b}`) matched against a whole token.
+#[derive(Clone, Debug)]
+pub struct TokenGlob {
+    source: String,
+    matcher: GlobMatcher,
+}
+
+impl TokenGlob {
+    pub fn new(source: &str) -> Result<Self> {
+        let matcher = Glob::new(source)
+            .map_err(|err| Error::InvalidPattern(format!("invalid glob `{source}`: {err}")))?
+            .compile_matcher();
+        Ok(Self {
+            source: source.to_string(),
+            matcher,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl PartialEq for TokenGlob {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for TokenGlob {}
+
+/// Regular expression that must match a whole token.
+#[derive(Clone, Debug)]
+pub struct TokenRegex {
+    source: String,
+    regex: Regex,
+}
+
+impl TokenRegex {
+    pub fn new(source: &str) -> Result<Self> {
+        let regex = Regex::new(&format!("^(?:{source})$"))
+            .map_err(|err| Error::InvalidPattern(format!("invalid regex `{source}`: {err}")))?;
+        Ok(Self {
+            source: source.to_string(),
+            regex,
+        })
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl PartialEq for TokenRegex {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Eq for TokenRegex {}
+
 /// Prefix matcher for commands with support for alternative match tokens.
 /// First token is fixed since we key by the first token in policy.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -114,6 +185,60 @@ pub struct PrefixRule {
     pub justification: Option<String>,
 }
 
+/// Prefix rule that also constrains the rest of argv: which flags may appear
+/// anywhere in the command and where positional path arguments may point.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ArgvRule {
+    pub pattern: PrefixPattern,
+    pub conditions: ArgvConditions,
+    pub decision: Decision,
+    pub justification: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ArgvConditions {
+    /// The rule only matches when at least one of these flags appears.
+    pub when_flags: Vec<String>,
+    /// The rule only matches when none of these flags appear.
+    pub unless_flags: Vec<String>,
+    /// The rule only matches when every positional argument after the prefix
+    /// resolves to a path under these roots.
+    pub path_args: Option<PathArgsScope>,
+}
+
+impl ArgvConditions {
+    pub fn is_empty(&self) -> bool {
+        self.when_flags.is_empty() && self.unless_flags.is_empty() && self.path_args.is_none()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PathArgsScope {
+    /// Paths must stay under the command's working directory.
+    Cwd,
+    /// Paths must stay under one of the sandbox's writable roots.
+    WritableRoots,
+}
+
+impl PathArgsScope {
+    pub fn parse(raw: &str) -> Result<Self> {
+        match raw {
+            "cwd" => Ok(Self::Cwd),
+            "writable_roots" => Ok(Self::WritableRoots),
+            other => Err(Error::InvalidRule(format!(
+                "path_args must be one of cwd, writable_roots (got {other})"
+            ))),
+        }
+    }
+
+    pub fn as_policy_string(self) -> &'static str {
+        match self {
+            Self::Cwd => "cwd",
+            Self::WritableRoots => "writable_roots",
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum NetworkRuleProtocol {
     Http,
@@ -216,6 +341,12 @@ pub trait Rule: Any + Debug + Send + Sync {
 
     fn matches(&self, cmd: &[String]) -> Option<RuleMatch>;
 
+    /// Like [`Rule::matches`], for rules whose conditions depend on where the
+    /// command runs.
+    fn matches_with_options(&self, cmd: &[String], _options: &MatchOptions) -> Option<RuleMatch> {
+        self.matches(cmd)
+    }
+
     fn as_any(&self) -> &dyn Any;
 }
 
@@ -242,6 +373,130 @@ impl Rule for PrefixRule {
     }
 }
 
+impl Rule for ArgvRule {
+    fn program(&self) -> &str {
+        self.pattern.first.as_ref()
+    }
+
+    fn matches(&self, cmd: &[String]) -> Option<RuleMatch> {
+        self.matches_with_options(cmd, &MatchOptions::default())
+    }
+
+    fn matches_with_options(&self, cmd: &[String], options: &MatchOptions) -> Option<RuleMatch> {
+        let matched_prefix = self.pattern.matches_prefix(cmd)?;
+        let argv = &cmd[1..];
+        if !self.conditions.when_flags.is_empty()
+            && !self
+                .conditions
+                .when_flags
+                .iter()
+                .any(|flag| argv_has_flag(argv, flag))
+        {
+            return None;
+        }
+        if self
+            .conditions
+            .unless_flags
+            .iter()
+            .any(|flag| argv_has_flag(argv, flag))
+        {
+            return None;
+        }
+        if let Some(scope) = self.conditions.path_args
+            && !path_args_within_scope(&cmd[matched_prefix.len()..], scope, options)
+        {
+            return None;
+        }
+
+        Some(RuleMatch::PrefixRuleMatch {
+            matched_prefix,
+            decision: self.decision,
+            resolved_program: None,
+            justification: self.justification.clone(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Reports whether `flag` appears among the options in `argv`, stopping at
+/// `--`. Long flags also match `--flag=value`, and single-letter short flags
+/// also match inside bundles such as `-rf`.
+fn argv_has_flag(argv: &[String], flag: &str) -> bool {
+    argv.iter()
+        .take_while(|token| token.as_str() != "--")
+        .any(|token| token_has_flag(token, flag))
+}
+
+fn token_has_flag(token: &str, flag: &str) -> bool {
+    if token == flag {
+        return true;
+    }
+    if flag.starts_with("--") {
+        return token
+            .strip_prefix(flag)
+            .is_some_and(|value| value.starts_with('='));
+    }
+    match (flag.strip_prefix('-'), token.strip_prefix('-')) {
+        (Some(short), Some(bundle)) if short.chars().count() == 1 && !bundle.starts_with('-') => {
+            bundle.contains(short)
+        }
+        _ => false,
+    }
+}
+
+/// Resolves every positional argument against the command's cwd and checks
+/// that it stays under the roots for `scope`. Resolution is lexical, so `..`
+/// is accounted for but symlinks are not followed.
+fn path_args_within_scope(args: &[String], scope: PathArgsScope, options: &MatchOptions) -> bool {
+    let Some(cwd) = options.cwd.as_ref() else {
+        return false;
+    };
+    let roots = match scope {
+        PathArgsScope::Cwd => std::slice::from_ref(cwd),
+        PathArgsScope::WritableRoots => options.writable_roots.as_slice(),
+    };
+
+    let mut after_separator = false;
+    for arg in args {
+        if !after_separator {
+            if arg == "--" {
+                after_separator = true;
+                continue;
+            }
+            if arg.starts_with('-') && arg != "-" {
+                continue;
+            }
+        }
+        let path = cwd.join(arg);
+        if !roots
+            .iter()
+            .any(|root| path.as_path().starts_with(root.as_path()))
+        {
+            return false;
+        }
+    }
+    true
+}
+
+/// Examples are evaluated as if run from a fixed workspace directory that is
+/// also the only writable root, so `path_args` rules can be exercised.
+fn example_match_options() -> MatchOptions {
+    let cwd = AbsolutePathBuf::from_absolute_path(EXAMPLE_CWD).ok();
+    MatchOptions {
+        resolve_host_executables: true,
+        writable_roots: cwd.iter().cloned().collect(),
+        cwd,
+    }
+}
+
+#[cfg(windows)]
+const EXAMPLE_CWD: &str = r"C:\workspace";
+#[cfg(not(windows))]
+const EXAMPLE_CWD: &str = "/workspace";
+
 /// Count how many rules match each provided example and error if any example is unmatched.
 pub(crate) fn validate_match_examples(
     policy: &Policy,
@@ -249,9 +504,7 @@ pub(crate) fn validate_match_examples(
     matches: &[Vec<String>],
 ) -> Result<()> {
     let mut unmatched_examples = Vec::new();
-    let options = MatchOptions {
-        resolve_host_executables: true,
-    };
+    let options = example_match_options();
 
     for example in matches {
         if !policy
@@ -284,9 +537,7 @@ pub(crate) fn validate_not_match_examples(
     _rules: &[RuleRef],
     not_matches: &[Vec<String>],
 ) -> Result<()> {
-    let options = MatchOptions {
-        resolve_host_executables: true,
-    };
+    let options = example_match_options();
 
     for example in not_matches {
         if let Some(rule) = policy
@@ -786,6 +786,7 @@ host_executable(name = "git", paths = ["{git_path_literal}"])
         &allow_all,
         &MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         },
     );
     assert_eq!(
@@ -843,6 +844,7 @@ host_executable(name = "git", paths = [])
         &allow_all,
         &MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         },
     );
     assert_eq!(
@@ -878,6 +880,7 @@ host_executable(name = "git", paths = ["{allowed_git_literal}"])
         &allow_all,
         &MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         },
     );
     assert_eq!(
@@ -908,6 +911,7 @@ prefix_rule(pattern = ["git"], decision = "prompt")
         &allow_all,
         &MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         },
     );
     assert_eq!(
@@ -945,6 +949,7 @@ host_executable(name = "git", paths = ["{git_path_literal}"])
         &allow_all,
         &MatchOptions {
             resolve_host_executables: true,
+            ..Default::default()
         },
     );
     assert_eq!(
@@ -961,3 +966,150 @@ host_executable(name = "git", paths = ["{git_path_literal}"])
     );
     Ok(())
 }
+
+#[test]
+fn flag_conditions_apply_anywhere_in_argv() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["git", "push"],
+    unless_flags = ["--force", "-f"],
+    match = ["git push origin main", "git push origin -- -f"],
+    not_match = ["git push --force", "git push origin main -f", "git push --force=true"],
+)
+prefix_rule(
+    pattern = ["rm"],
+    decision = "forbidden",
+    when_flags = ["-r"],
+    match = ["rm -r build", "rm -rf build", "rm build -fr"],
+    not_match = ["rm build", "rm --recursive build"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    assert_eq!(
+        policy
+            .check(&tokens(&["git", "push", "-f", "origin"]), &prompt_all)
+            .decision,
+        Decision::Prompt
+    );
+    assert_eq!(
+        policy
+            .check(&tokens(&["rm", "-v", "-fr", "build"]), &allow_all)
+            .decision,
+        Decision::Forbidden
+    );
+    assert_eq!(
+        policy.check(&tokens(&["rm", "build"]), &allow_all),
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::HeuristicsRuleMatch {
+                command: tokens(&["rm", "build"]),
+                decision: Decision::Allow,
+            }],
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn glob_and_regex_tokens_match_whole_tokens() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["cargo", ["build", "test"], glob("--package=codex-*")],
+    match = ["cargo test --package=codex-core"],
+    not_match = ["cargo test --package=serde", "cargo test"],
+)
+prefix_rule(
+    pattern = ["git", "checkout", regex("v[0-9]+\\.[0-9]+")],
+    match = ["git checkout v1.2"],
+    not_match = ["git checkout v1.2-rc", "git checkout main"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let evaluation = policy.check(
+        &tokens(&["cargo", "build", "--package=codex-tui", "--release"]),
+        &prompt_all,
+    );
+    assert_eq!(
+        evaluation.matched_rules,
+        vec![RuleMatch::PrefixRuleMatch {
+            matched_prefix: tokens(&["cargo", "build", "--package=codex-tui"]),
+            decision: Decision::Allow,
+            resolved_program: None,
+            justification: None,
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn pattern_rejects_invalid_matchers() {
+    let mut parser = PolicyParser::new();
+    let err = parser
+        .parse(
+            "test.rules",
+            r#"prefix_rule(pattern = [glob("g*"), "status"])"#,
+        )
+        .expect_err("glob program should fail");
+    assert!(err.to_string().contains("literal program name"), "{err}");
+
+    let err = parser
+        .parse(
+            "test.rules",
+            r#"prefix_rule(pattern = ["git", regex("(")])"#,
+        )
+        .expect_err("invalid regex should fail");
+    assert!(err.to_string().contains("invalid regex"), "{err}");
+
+    let err = parser
+        .parse(
+            "test.rules",
+            r#"prefix_rule(pattern = ["git"], unless_flags = ["force"])"#,
+        )
+        .expect_err("flag without dash should fail");
+    assert!(err.to_string().contains("unless_flags"), "{err}");
+}
+
+#[test]
+fn path_args_rules_require_paths_under_cwd_or_writable_roots() -> Result<()> {
+    let policy_src = r#"
+prefix_rule(
+    pattern = ["rm"],
+    path_args = "cwd",
+    match = ["rm notes.txt", "rm -f build/out.o", "rm -- -odd-name"],
+    not_match = ["rm ../notes.txt", "rm /etc/passwd", "rm build/../../x"],
+)
+prefix_rule(
+    pattern = ["cp"],
+    path_args = "writable_roots",
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let workspace = host_absolute_path(&["workspace"]);
+    let tmp = host_absolute_path(&["tmp"]);
+    let options = MatchOptions {
+        cwd: Some(absolute_path(&workspace)),
+        writable_roots: vec![absolute_path(&workspace), absolute_path(&tmp)],
+        ..Default::default()
+    };
+    let matched = |cmd: &[&str], options: &MatchOptions| {
+        !policy
+            .matches_for_command_with_options(&tokens(cmd), None, options)
+            .is_empty()
+    };
+
+    assert!(matched(&["rm", "src/main.rs"], &options));
+    assert!(!matched(&["rm", "src/main.rs"], &MatchOptions::default()));
+    assert!(matched(&["cp", "a.txt", tmp.as_str()], &options));
+    let etc = host_absolute_path(&["etc"]);
+    assert!(!matched(&["cp", "a.txt", etc.as_str()], &options));
+    Ok(())
+}
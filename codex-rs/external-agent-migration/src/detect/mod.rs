@@ -120,9 +120,11 @@ impl ExternalAgentConfigService {
             }
         }
 
-        let mcp_source_path = self
-            .source
-            .mcp_source_path(self.source_root(scope), self.source_config_dir(scope));
+        let mcp_source_path = self.source.mcp_source_path(
+            self.source_root(scope),
+            self.source_config_dir(scope),
+            source_settings.clone(),
+        );
         let migrated_mcp = self.build_mcp_config(scope, settings.clone())?;
         let mut mcp_server_names = migrated_mcp_server_names(&migrated_mcp);
         if !is_empty_toml_table(&migrated_mcp) {
@@ -386,7 +388,9 @@ impl ExternalAgentConfigService {
                     item_type: ExternalAgentConfigMigrationItemType::Sessions,
                     description: format!(
                         "Migrate recent sessions from {}",
-                        self.external_agent_home.join("projects").display()
+                        self.source
+                            .sessions_root(&self.external_agent_home)
+                            .display()
                     ),
                     cwd: None,
                     details: Some(MigrationDetails {
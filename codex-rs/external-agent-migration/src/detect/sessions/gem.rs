@@ -0,0 +1,103 @@
+use super::common::SessionFileCandidate;
+use super::common::detect_recent_sessions;
+use crate::GemSource;
+use crate::sessions::ExternalAgentSessionMigration;
+use serde_json::Value as JsonValue;
+use sha2::Digest;
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+pub fn detect_recent_gem_sessions(
+    external_agent_home: &Path,
+    codex_home: &Path,
+) -> io::Result<Vec<ExternalAgentSessionMigration>> {
+    let sessions_root = external_agent_home.join(GemSource::SESSIONS_DIR);
+    if !sessions_root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let trusted_project_roots = trusted_project_roots_by_hash(external_agent_home);
+    let mut candidates = Vec::new();
+    for project_entry in fs::read_dir(sessions_root)? {
+        let Ok(project_entry) = project_entry else {
+            continue;
+        };
+        let project_storage = project_entry.path();
+        if !project_storage.is_dir() {
+            continue;
+        }
+        let fallback_cwd = gem_project_cwd(&project_storage, &trusted_project_roots);
+        for path in gem_chat_files(&project_storage.join("chats")) {
+            candidates.push(SessionFileCandidate {
+                path,
+                fallback_cwd: fallback_cwd.clone(),
+            });
+        }
+    }
+    detect_recent_sessions(codex_home, candidates, /*require_existing_cwd*/ false)
+}
+
+fn gem_chat_files(chats_root: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(chats_root) else {
+        return Vec::new();
+    };
+    let mut files = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.extension().and_then(|extension| extension.to_str()) == Some("json")
+                && path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("session-"))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    files
+}
+
+/// Resolves the project a chat directory belongs to.
+///
+/// Newer releases write the project path next to the chats; older ones name the directory
+/// after the SHA-256 of the project path, which can only be reversed for trusted folders.
+fn gem_project_cwd(
+    project_storage: &Path,
+    trusted_project_roots: &HashMap<String, PathBuf>,
+) -> Option<PathBuf> {
+    if let Ok(project_root) = fs::read_to_string(project_storage.join(GemSource::PROJECT_ROOT_FILE))
+    {
+        let project_root = project_root.trim();
+        if !project_root.is_empty() {
+            return Some(PathBuf::from(project_root));
+        }
+    }
+    let project_hash = project_storage.file_name()?.to_str()?;
+    trusted_project_roots.get(project_hash).cloned()
+}
+
+fn trusted_project_roots_by_hash(external_agent_home: &Path) -> HashMap<String, PathBuf> {
+    let Ok(raw) = fs::read_to_string(external_agent_home.join(GemSource::TRUSTED_FOLDERS_FILE))
+    else {
+        return HashMap::new();
+    };
+    let Ok(JsonValue::Object(folders)) = serde_json::from_str::<JsonValue>(&raw) else {
+        return HashMap::new();
+    };
+    folders
+        .keys()
+        .map(|folder| (gem_project_hash(folder), PathBuf::from(folder)))
+        .collect()
+}
+
+fn gem_project_hash(project_root: &str) -> String {
+    format!("{:x}", Sha256::digest(project_root.as_bytes()))
+}
+
+#[cfg(test)]
+#[path = "gem_tests.rs"]
+mod tests;
@@ -0,0 +1,181 @@
+use super::*;
+use crate::sessions::SessionMetadataMode;
+use crate::sessions::prepare_validated_session_import_with_metadata_mode;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::RolloutItem;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+#[test]
+fn detects_gem_chat_with_recorded_project_root() {
+    let root = TempDir::new().expect("tempdir");
+    let project_root = root.path().join("workspace");
+    fs::create_dir_all(&project_root).expect("project root");
+    let external_agent_home = root.path().join(".external");
+    let project_storage = external_agent_home.join("tmp/workspace");
+    fs::create_dir_all(&project_storage).expect("project storage");
+    fs::write(
+        project_storage.join(GemSource::PROJECT_ROOT_FILE),
+        format!("{}\n", project_root.display()),
+    )
+    .expect("project root file");
+    let chat = write_chat(&project_storage, "session-2026-06-03T12-00-a1", None);
+    fs::write(project_storage.join("chats/notes.json"), "{}").expect("unrelated file");
+
+    assert_eq!(
+        detect_recent_gem_sessions(&external_agent_home, root.path()).expect("detect sessions"),
+        vec![ExternalAgentSessionMigration {
+            path: chat,
+            cwd: project_root,
+            title: Some("first request".to_string()),
+        }]
+    );
+}
+
+#[test]
+fn resolves_hashed_project_storage_from_trusted_folders() {
+    let root = TempDir::new().expect("tempdir");
+    let project_root = root.path().join("workspace");
+    fs::create_dir_all(&project_root).expect("project root");
+    let external_agent_home = root.path().join(".external");
+    let project_key = project_root.display().to_string();
+    let project_storage = external_agent_home
+        .join(GemSource::SESSIONS_DIR)
+        .join(gem_project_hash(&project_key));
+    let chat = write_chat(
+        &project_storage,
+        "session-2026-06-03T12-00-b2",
+        Some("Fix the flaky test"),
+    );
+    let untrusted_chat = write_chat(
+        &external_agent_home
+            .join("tmp")
+            .join(gem_project_hash("/elsewhere")),
+        "session-2026-06-03T12-00-c3",
+        None,
+    );
+    fs::write(
+        external_agent_home.join(GemSource::TRUSTED_FOLDERS_FILE),
+        serde_json::json!({ project_key: "TRUST_FOLDER" }).to_string(),
+    )
+    .expect("trusted folders");
+
+    let sessions =
+        detect_recent_gem_sessions(&external_agent_home, root.path()).expect("detect sessions");
+
+    assert_eq!(
+        sessions,
+        vec![ExternalAgentSessionMigration {
+            path: chat,
+            cwd: project_root,
+            title: Some("Fix the flaky test".to_string()),
+        }]
+    );
+    assert!(
+        !sessions
+            .iter()
+            .any(|session| session.path == untrusted_chat)
+    );
+}
+
+#[test]
+fn imports_gem_chat_with_tool_calls() {
+    let root = TempDir::new().expect("tempdir");
+    let project_storage = root.path().join(".external/tmp/workspace");
+    let chat = write_chat(&project_storage, "session-2026-06-03T12-00-d4", None);
+
+    let pending = prepare_validated_session_import_with_metadata_mode(
+        root.path(),
+        ExternalAgentSessionMigration {
+            path: chat,
+            cwd: root.path().to_path_buf(),
+            title: None,
+        },
+        SessionMetadataMode::MigrationFallback,
+    )
+    .expect("prepare session")
+    .expect("pending import");
+
+    assert_eq!(pending.session.cwd, root.path());
+    assert_eq!(
+        pending.session.first_user_message.as_deref(),
+        Some("first request")
+    );
+    assert_eq!(
+        response_texts(&pending.session.rollout_items),
+        vec![
+            "first request".to_string(),
+            "Checking the status.\n\n\
+             [external_agent_tool_call: run_shell_command]\n\
+             command: git status --short\n\
+             [/external_agent_tool_call]\n\n\
+             [external_agent_tool_result]\n\
+             M src/lib.rs\n\
+             [/external_agent_tool_result]"
+                .to_string(),
+        ]
+    );
+}
+
+fn write_chat(project_storage: &Path, name: &str, summary: Option<&str>) -> PathBuf {
+    let chat = project_storage.join("chats").join(format!("{name}.json"));
+    fs::create_dir_all(chat.parent().expect("chat parent")).expect("chats directory");
+    let mut document = serde_json::json!({
+        "sessionId": name,
+        "projectHash": "hash",
+        "startTime": "2026-06-03T12:00:00.000Z",
+        "lastUpdated": "2026-06-03T12:01:00.000Z",
+        "messages": [
+            {
+                "id": "m1",
+                "timestamp": "2026-06-03T12:00:00.000Z",
+                "type": "user",
+                "content": [{ "text": "first request" }],
+            },
+            {
+                "id": "m2",
+                "timestamp": "2026-06-03T12:00:30.000Z",
+                "type": "info",
+                "content": "Switched model",
+            },
+            {
+                "id": "m3",
+                "timestamp": "2026-06-03T12:01:00.000Z",
+                "type": "gemini",
+                "content": "Checking the status.",
+                "thoughts": [{ "subject": "Plan", "description": "hidden" }],
+                "toolCalls": [{
+                    "id": "call-1",
+                    "name": "run_shell_command",
+                    "args": { "command": "git status --short" },
+                    "status": "success",
+                    "resultDisplay": "M src/lib.rs",
+                }],
+            },
+        ],
+    });
+    if let Some(summary) = summary {
+        document["summary"] = serde_json::json!(summary);
+    }
+    fs::write(&chat, document.to_string()).expect("chat");
+    chat
+}
+
+fn response_texts(items: &[RolloutItem]) -> Vec<String> {
+    items
+        .iter()
+        .filter_map(|item| match item {
+            RolloutItem::ResponseItem(ResponseItem::Message { content, .. }) => {
+                Some(content.iter().filter_map(|content| match content {
+                    ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                        Some(text.clone())
+                    }
+                    _ => None,
+                }))
+            }
+            _ => None,
+        })
+        .flatten()
+        .collect()
+}
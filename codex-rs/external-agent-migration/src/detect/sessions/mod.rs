@@ -2,8 +2,12 @@ mod cla;
 mod common;
 mod connectors_cla;
 mod cur;
+mod gem;
+mod opc;
 
 pub use cla::detect_recent_cla_sessions;
 pub use connectors_cla::ImportedSessionConnectorAttribution;
 pub use connectors_cla::detect_imported_cla_session_connectors;
 pub use cur::detect_recent_cur_sessions;
+pub use gem::detect_recent_gem_sessions;
+pub use opc::detect_recent_opc_sessions;
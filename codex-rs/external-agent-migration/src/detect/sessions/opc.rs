@@ -0,0 +1,45 @@
+use super::common::SessionFileCandidate;
+use super::common::detect_recent_sessions;
+use crate::OpcSource;
+use crate::sessions::ExternalAgentSessionMigration;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub fn detect_recent_opc_sessions(
+    external_agent_home: &Path,
+    codex_home: &Path,
+) -> io::Result<Vec<ExternalAgentSessionMigration>> {
+    let sessions_root = OpcSource::session_storage_root(external_agent_home).join("session");
+    if !sessions_root.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut candidates = Vec::new();
+    for project_entry in fs::read_dir(sessions_root)? {
+        let Ok(project_entry) = project_entry else {
+            continue;
+        };
+        let Ok(session_entries) = fs::read_dir(project_entry.path()) else {
+            continue;
+        };
+        let mut paths = session_entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path.extension().and_then(|extension| extension.to_str()) == Some("json")
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+        candidates.extend(paths.into_iter().map(|path| SessionFileCandidate {
+            path,
+            fallback_cwd: None,
+        }));
+    }
+    detect_recent_sessions(codex_home, candidates, /*require_existing_cwd*/ true)
+}
+
+#[cfg(test)]
+#[path = "opc_tests.rs"]
+mod tests;
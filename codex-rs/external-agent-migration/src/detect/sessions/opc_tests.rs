@@ -0,0 +1,177 @@
+use super::*;
+use crate::sessions::prepare_validated_session_import;
+use pretty_assertions::assert_eq;
+use sha2::Digest;
+use sha2::Sha256;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+const CREATED_MS: i64 = 1_780_488_000_000;
+
+#[test]
+fn detects_opc_sessions_with_embedded_directory() {
+    let root = TempDir::new().expect("tempdir");
+    let project_root = root.path().join("workspace");
+    fs::create_dir_all(&project_root).expect("project root");
+    let external_agent_home = root.path().join(".config/opencode");
+    let storage = OpcSource::session_storage_root(&external_agent_home);
+    let session = write_session(&storage, "ses_a", &project_root, "Fix the flaky test", None);
+    write_session(
+        &storage,
+        "ses_b",
+        &project_root,
+        "New session - 2026-06-03T12:00:00.000Z",
+        None,
+    );
+    write_session(
+        &storage,
+        "ses_child",
+        &project_root,
+        "Child session - 2026-06-03T12:00:00.000Z",
+        Some("ses_a"),
+    );
+    write_session(
+        &storage,
+        "ses_gone",
+        &root.path().join("deleted"),
+        "Deleted project",
+        None,
+    );
+
+    let mut sessions =
+        detect_recent_opc_sessions(&external_agent_home, root.path()).expect("detect sessions");
+    sessions.sort_by(|left, right| left.path.cmp(&right.path));
+
+    assert_eq!(
+        sessions,
+        vec![
+            ExternalAgentSessionMigration {
+                path: session,
+                cwd: project_root.clone(),
+                title: Some("Fix the flaky test".to_string()),
+            },
+            ExternalAgentSessionMigration {
+                path: storage.join("session/project-1/ses_b.json"),
+                cwd: project_root,
+                title: Some("first request".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn imports_opc_session_parts_and_hashes_every_file() {
+    let root = TempDir::new().expect("tempdir");
+    let project_root = root.path().join("workspace");
+    fs::create_dir_all(&project_root).expect("project root");
+    let storage = root.path().join(".local/share/opencode/storage");
+    let session = write_session(&storage, "ses_a", &project_root, "Fix the flaky test", None);
+
+    let pending = prepare_validated_session_import(
+        root.path(),
+        ExternalAgentSessionMigration {
+            path: session.clone(),
+            cwd: project_root.clone(),
+            title: None,
+        },
+    )
+    .expect("prepare session")
+    .expect("pending import");
+
+    assert_eq!(pending.session.cwd, project_root);
+    assert_eq!(pending.session.title.as_deref(), Some("Fix the flaky test"));
+    assert_eq!(
+        pending.session.first_user_message.as_deref(),
+        Some("first request")
+    );
+    let mut hasher = Sha256::new();
+    for path in [
+        session,
+        storage.join("message/ses_a/msg_1.json"),
+        storage.join("part/msg_1/prt_1.json"),
+        storage.join("part/msg_1/prt_2.json"),
+        storage.join("message/ses_a/msg_2.json"),
+        storage.join("part/msg_2/prt_1.json"),
+        storage.join("part/msg_2/prt_2.json"),
+    ] {
+        hasher.update(fs::read(path).expect("read session file"));
+    }
+    assert_eq!(
+        pending.source_content_sha256,
+        format!("{:x}", hasher.finalize())
+    );
+}
+
+fn write_session(
+    storage: &Path,
+    session_id: &str,
+    directory: &Path,
+    title: &str,
+    parent_id: Option<&str>,
+) -> PathBuf {
+    let session = storage
+        .join("session/project-1")
+        .join(format!("{session_id}.json"));
+    write_json(
+        &session,
+        serde_json::json!({
+            "id": session_id,
+            "projectID": "project-1",
+            "directory": directory,
+            "parentID": parent_id,
+            "title": title,
+            "version": "0.15.0",
+            "time": { "created": CREATED_MS, "updated": CREATED_MS + 60_000 },
+        }),
+    );
+    let message_root = storage.join("message").join(session_id);
+    write_json(
+        &message_root.join("msg_1.json"),
+        serde_json::json!({
+            "id": "msg_1",
+            "sessionID": session_id,
+            "role": "user",
+            "time": { "created": CREATED_MS },
+        }),
+    );
+    write_json(
+        &message_root.join("msg_2.json"),
+        serde_json::json!({
+            "id": "msg_2",
+            "sessionID": session_id,
+            "role": "assistant",
+            "time": { "created": CREATED_MS + 30_000 },
+        }),
+    );
+    let part_root = storage.join("part");
+    write_json(
+        &part_root.join("msg_1/prt_1.json"),
+        serde_json::json!({ "type": "text", "text": "first request" }),
+    );
+    write_json(
+        &part_root.join("msg_1/prt_2.json"),
+        serde_json::json!({ "type": "text", "text": "<file>...</file>", "synthetic": true }),
+    );
+    write_json(
+        &part_root.join("msg_2/prt_1.json"),
+        serde_json::json!({ "type": "reasoning", "text": "hidden" }),
+    );
+    write_json(
+        &part_root.join("msg_2/prt_2.json"),
+        serde_json::json!({
+            "type": "tool",
+            "tool": "bash",
+            "state": {
+                "status": "completed",
+                "input": { "command": "cargo test" },
+                "output": "ok",
+            },
+        }),
+    );
+    session
+}
+
+fn write_json(path: &Path, value: serde_json::Value) {
+    fs::create_dir_all(path.parent().expect("json parent")).expect("json directory");
+    fs::write(path, value.to_string()).expect("write json");
+}
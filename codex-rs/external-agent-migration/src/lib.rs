@@ -49,6 +49,7 @@ pub use hooks_cur::import_hooks_cur;
 use mcp::EXTERNAL_AGENT_MCP_CONFIG_FILE;
 pub use mcp::build_mcp_config_from_external;
 pub use mcp::build_mcp_config_from_json_file;
+pub(crate) use mcp::build_mcp_config_from_servers;
 #[cfg(test)]
 use mcp::external_agent_project_config_file;
 #[cfg(test)]
@@ -72,7 +73,9 @@ pub use service::PluginsMigration;
 pub use service::record_import_error;
 pub(crate) use source::ClaSource;
 pub(crate) use source::CurSource;
+pub(crate) use source::GemSource;
 pub(crate) use source::InstructionSourceGroup;
+pub(crate) use source::OpcSource;
 #[cfg(test)]
 use subagents::FrontmatterValue;
 #[cfg(test)]
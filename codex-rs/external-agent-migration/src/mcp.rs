@@ -31,6 +31,12 @@ pub fn build_mcp_config_from_json_file(source_file: &Path) -> io::Result<TomlVal
     build_mcp_config(mcp_servers, /*settings*/ None)
 }
 
+pub(crate) fn build_mcp_config_from_servers(
+    mcp_servers: BTreeMap<String, JsonValue>,
+) -> io::Result<TomlValue> {
+    build_mcp_config(mcp_servers, /*settings*/ None)
+}
+
 fn build_mcp_config(
     mcp_servers: BTreeMap<String, JsonValue>,
     settings: Option<&JsonValue>,
@@ -222,6 +228,12 @@ fn mcp_server_toml_table(
         if let Some(env) = server_config.get("env").and_then(JsonValue::as_object) {
             append_env_config(&mut table, env)?;
         }
+        if let Some(cwd) = server_config.get("cwd").and_then(json_string) {
+            if contains_env_placeholder(&cwd) {
+                return None;
+            }
+            table.insert("cwd".to_string(), TomlValue::String(cwd));
+        }
     } else if let Some(url) = server_config.get("url").and_then(json_string) {
         if !matches!(
             transport_type,
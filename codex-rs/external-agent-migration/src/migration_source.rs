@@ -1,9 +1,14 @@
 use crate::ClaSource;
 use crate::CurSource;
+use crate::GemSource;
+use crate::OpcSource;
 use crate::RewriteProfile;
 use crate::detect::plugins;
 use crate::detect::sessions::detect_recent_cla_sessions;
 use crate::detect::sessions::detect_recent_cur_sessions;
+use crate::detect::sessions::detect_recent_gem_sessions;
+use crate::detect::sessions::detect_recent_opc_sessions;
+use crate::import_subagents_with_rewrite_profile;
 use crate::sessions::ExternalAgentSessionMigration;
 use crate::sessions::SessionMetadataMode;
 use serde_json::Value as JsonValue;
@@ -47,38 +52,65 @@ pub(super) enum ExternalAgentSource {
     #[default]
     Cla,
     Cur,
+    Gem,
+    Opc,
 }
 
 impl ExternalAgentSource {
     pub(super) fn from_migration_source(migration_source: Option<&str>) -> Self {
-        if migration_source
-            .is_some_and(|source| source.eq_ignore_ascii_case(CurSource::MIGRATION_SOURCE))
-        {
+        let Some(migration_source) = migration_source else {
+            return Self::Cla;
+        };
+        if migration_source.eq_ignore_ascii_case(CurSource::MIGRATION_SOURCE) {
             Self::Cur
+        } else if migration_source.eq_ignore_ascii_case(GemSource::MIGRATION_SOURCE)
+            || migration_source.eq_ignore_ascii_case(GemSource::MIGRATION_SOURCE_ALIAS)
+        {
+            Self::Gem
+        } else if migration_source.eq_ignore_ascii_case(OpcSource::MIGRATION_SOURCE) {
+            Self::Opc
         } else {
             Self::Cla
         }
     }
 
+    /// Returns the repository-relative directory that holds source configuration.
     pub(super) fn config_dir(self) -> &'static str {
         match self {
             Self::Cla => ClaSource::CONFIG_DIR,
             Self::Cur => CurSource::CONFIG_DIR,
+            Self::Gem => GemSource::CONFIG_DIR,
+            Self::Opc => OpcSource::CONFIG_DIR,
+        }
+    }
+
+    /// Returns the home-relative directory that holds user-level source configuration.
+    pub(super) fn home_config_dir(self) -> &'static str {
+        match self {
+            Self::Cla | Self::Cur | Self::Gem => self.config_dir(),
+            Self::Opc => OpcSource::HOME_CONFIG_DIR,
         }
     }
 
     pub(super) fn supports_memory(self) -> bool {
         match self {
             Self::Cla => true,
-            Self::Cur => false,
+            Self::Cur | Self::Gem | Self::Opc => false,
         }
     }
 
-    pub(super) fn settings_file_name(self, scope: &MigrationScope) -> &'static str {
+    pub(super) fn settings_path(self, scope: &MigrationScope, source_config_dir: &Path) -> PathBuf {
         match (self, scope) {
-            (Self::Cla, _) => ClaSource::SETTINGS_FILE,
-            (Self::Cur, MigrationScope::Home) => CurSource::HOME_CONFIG_FILE,
-            (Self::Cur, MigrationScope::Repository { .. }) => CurSource::PROJECT_CONFIG_FILE,
+            (Self::Cla, _) => source_config_dir.join(ClaSource::SETTINGS_FILE),
+            (Self::Cur, MigrationScope::Home) => {
+                source_config_dir.join(CurSource::HOME_CONFIG_FILE)
+            }
+            (Self::Cur, MigrationScope::Repository { .. }) => {
+                source_config_dir.join(CurSource::PROJECT_CONFIG_FILE)
+            }
+            (Self::Gem, _) => source_config_dir.join(GemSource::SETTINGS_FILE),
+            (Self::Opc, MigrationScope::Home) => source_config_dir.join(OpcSource::CONFIG_FILE),
+            (Self::Opc, MigrationScope::Repository { root }) => root.join(OpcSource::CONFIG_FILE),
         }
     }
 
@@ -90,6 +122,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::effective_settings(source_settings),
             Self::Cur => CurSource::effective_settings(source_config_dir, source_settings),
+            Self::Gem => GemSource::effective_settings(source_settings),
+            Self::Opc => OpcSource::effective_settings(source_settings),
         }
     }
 
@@ -97,6 +131,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::build_config(settings),
             Self::Cur => CurSource::build_config(settings),
+            Self::Gem => GemSource::build_config(settings),
+            Self::Opc => OpcSource::build_config(settings),
         }
     }
 
@@ -107,7 +143,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => Ok(plugins::detect_cla_plugins(&context)),
             Self::Cur if context.repo_root.is_none() => plugins::detect_cur_plugins(&context),
-            Self::Cur => Ok(None),
+            Self::Cur | Self::Gem | Self::Opc => Ok(None),
         }
     }
 
@@ -115,6 +151,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => plugins::can_detect_cla_plugins(settings),
             Self::Cur => true,
+            Self::Gem | Self::Opc => false,
         }
     }
 
@@ -126,20 +163,38 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => detect_recent_cla_sessions(external_agent_home, codex_home),
             Self::Cur => detect_recent_cur_sessions(external_agent_home, codex_home),
+            Self::Gem => detect_recent_gem_sessions(external_agent_home, codex_home),
+            Self::Opc => detect_recent_opc_sessions(external_agent_home, codex_home),
+        }
+    }
+
+    /// Returns the directory that contains every session file this source can import.
+    pub(super) fn sessions_root(self, external_agent_home: &Path) -> PathBuf {
+        match self {
+            Self::Cla | Self::Cur => external_agent_home.join("projects"),
+            Self::Gem => external_agent_home.join(GemSource::SESSIONS_DIR),
+            Self::Opc => OpcSource::session_storage_root(external_agent_home).join("session"),
+        }
+    }
+
+    pub(super) fn session_file_extension(self) -> &'static str {
+        match self {
+            Self::Cla | Self::Cur => "jsonl",
+            Self::Gem | Self::Opc => "json",
         }
     }
 
     pub(super) fn session_metadata_mode(self) -> SessionMetadataMode {
         match self {
-            Self::Cla => SessionMetadataMode::Embedded,
-            Self::Cur => SessionMetadataMode::MigrationFallback,
+            Self::Cla | Self::Opc => SessionMetadataMode::Embedded,
+            Self::Cur | Self::Gem => SessionMetadataMode::MigrationFallback,
         }
     }
 
     pub(super) fn connector_metadata_roots(self, external_agent_home: &Path) -> Vec<PathBuf> {
         match self {
             Self::Cla => ClaSource::connector_metadata_roots(external_agent_home),
-            Self::Cur => Vec::new(),
+            Self::Cur | Self::Gem | Self::Opc => Vec::new(),
         }
     }
 
@@ -161,6 +216,7 @@ impl ExternalAgentSource {
                 })
                 .unwrap_or_default()),
             Self::Cur => source_cur::marketplace_import_sources(external_agent_home),
+            Self::Gem | Self::Opc => Ok(BTreeMap::new()),
         }
     }
 
@@ -174,6 +230,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::build_mcp_config(source_root, external_agent_home, settings),
             Self::Cur => CurSource::build_mcp_config(source_config_dir),
+            Self::Gem => GemSource::build_mcp_config(settings),
+            Self::Opc => OpcSource::build_mcp_config(settings),
         }
     }
 
@@ -181,10 +239,21 @@ impl ExternalAgentSource {
         self,
         source_root: PathBuf,
         source_config_dir: PathBuf,
+        source_settings: PathBuf,
     ) -> PathBuf {
         match self {
             Self::Cla => source_root,
             Self::Cur => source_config_dir.join("mcp.json"),
+            Self::Gem | Self::Opc => source_settings,
+        }
+    }
+
+    /// Whether MCP servers are declared in the scope's own settings file, so repository
+    /// migrations must not fall back to home settings.
+    pub(super) fn declares_mcp_servers_in_settings(self) -> bool {
+        match self {
+            Self::Cla | Self::Cur => false,
+            Self::Gem | Self::Opc => true,
         }
     }
 
@@ -195,6 +264,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::repo_instruction_source_groups(repo_root),
             Self::Cur => CurSource::repo_instruction_source_groups(repo_root),
+            Self::Gem => GemSource::repo_instruction_source_groups(repo_root),
+            Self::Opc => OpcSource::repo_instruction_source_groups(repo_root),
         }
     }
 
@@ -205,6 +276,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::home_instruction_sources(external_agent_home),
             Self::Cur => Ok(Vec::new()),
+            Self::Gem => GemSource::home_instruction_sources(external_agent_home),
+            Self::Opc => OpcSource::home_instruction_sources(external_agent_home),
         }
     }
 
@@ -212,6 +285,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::read_instruction_source(path),
             Self::Cur => CurSource::read_instruction_source(path),
+            Self::Gem => GemSource::read_instruction_source(path),
+            Self::Opc => OpcSource::read_instruction_source(path),
         }
     }
 
@@ -223,6 +298,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => source_cla::import_source_commands(source_commands, target_skills),
             Self::Cur => source_cur::import_source_commands(source_commands, target_skills),
+            Self::Gem | Self::Opc => Ok(Vec::new()),
         }
     }
 
@@ -234,6 +310,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => source_cla::count_missing_source_commands(source_commands, target_skills),
             Self::Cur => source_cur::count_missing_source_commands(source_commands, target_skills),
+            Self::Gem | Self::Opc => Ok(0),
         }
     }
 
@@ -245,6 +322,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => source_cla::missing_source_command_names(source_commands, target_skills),
             Self::Cur => source_cur::missing_source_command_names(source_commands, target_skills),
+            Self::Gem | Self::Opc => Ok(Vec::new()),
         }
     }
 
@@ -256,6 +334,11 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::import_subagents(source_agents, target_agents),
             Self::Cur => CurSource::import_subagents(source_agents, target_agents),
+            Self::Gem | Self::Opc => import_subagents_with_rewrite_profile(
+                source_agents,
+                target_agents,
+                self.rewrite_profile(),
+            ),
         }
     }
 
@@ -267,6 +350,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::hook_event_names(source_dir, target_hooks),
             Self::Cur => CurSource::hook_event_names(source_dir, target_hooks),
+            Self::Gem | Self::Opc => Ok(Vec::new()),
         }
     }
 
@@ -274,6 +358,7 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => ClaSource::import_hooks(source_dir, target_hooks),
             Self::Cur => CurSource::import_hooks(source_dir, target_hooks),
+            Self::Gem | Self::Opc => Ok(false),
         }
     }
 
@@ -281,6 +366,8 @@ impl ExternalAgentSource {
         match self {
             Self::Cla => source_cla::REWRITE_PROFILE,
             Self::Cur => source_cur::REWRITE_PROFILE,
+            Self::Gem => GemSource::REWRITE_PROFILE,
+            Self::Opc => OpcSource::REWRITE_PROFILE,
         }
     }
 }
@@ -127,7 +127,9 @@ impl ExternalAgentConfigService {
     }
 
     pub fn external_agent_session_source_path(&self, path: &Path) -> io::Result<Option<PathBuf>> {
-        if path.extension().and_then(|value| value.to_str()) != Some("jsonl") {
+        if path.extension().and_then(|value| value.to_str())
+            != Some(self.source.session_file_extension())
+        {
             return Ok(None);
         }
         let path = match fs::canonicalize(path) {
@@ -135,12 +137,13 @@ impl ExternalAgentConfigService {
             Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
             Err(err) => return Err(err),
         };
-        let projects_root = match fs::canonicalize(self.external_agent_home.join("projects")) {
-            Ok(projects_root) => projects_root,
-            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
-            Err(err) => return Err(err),
-        };
-        Ok(path.starts_with(projects_root).then_some(path))
+        let sessions_root =
+            match fs::canonicalize(self.source.sessions_root(&self.external_agent_home)) {
+                Ok(sessions_root) => sessions_root,
+                Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+                Err(err) => return Err(err),
+            };
+        Ok(path.starts_with(sessions_root).then_some(path))
     }
 
     pub async fn import(
@@ -407,8 +410,8 @@ impl ExternalAgentConfigService {
     }
 
     pub(crate) fn source_settings(&self, scope: &MigrationScope) -> PathBuf {
-        self.source_config_dir(scope)
-            .join(self.source.settings_file_name(scope))
+        self.source
+            .settings_path(scope, &self.source_config_dir(scope))
     }
 
     pub(crate) fn effective_source_settings(
@@ -451,7 +454,10 @@ impl ExternalAgentConfigService {
         scope: &MigrationScope,
         source_settings: Option<JsonValue>,
     ) -> io::Result<Option<JsonValue>> {
-        if !scope.is_home() && source_settings.is_none() {
+        if !scope.is_home()
+            && source_settings.is_none()
+            && !self.source.declares_mcp_servers_in_settings()
+        {
             let home_scope = MigrationScope::home();
             let home_settings = self.source_settings(&home_scope);
             match self.effective_source_settings(&home_scope) {
@@ -721,10 +727,10 @@ impl ExternalAgentConfigService {
 
 fn default_external_agent_home(source: ExternalAgentSource) -> PathBuf {
     if let Some(home) = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE")) {
-        return PathBuf::from(home).join(source.config_dir());
+        return PathBuf::from(home).join(source.home_config_dir());
     }
 
-    PathBuf::from(source.config_dir())
+    PathBuf::from(source.home_config_dir())
 }
 
 pub(crate) fn configured_marketplace_plugins(
@@ -77,3 +77,6 @@ mod memory;
 
 #[path = "service_tests/plugins.rs"]
 mod plugins;
+
+#[path = "service_tests/sources.rs"]
+mod sources;
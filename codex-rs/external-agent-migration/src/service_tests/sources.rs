@@ -0,0 +1,169 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn migration_item(
+    item_type: ExternalAgentConfigMigrationItemType,
+    cwd: Option<PathBuf>,
+) -> ExternalAgentConfigMigrationItem {
+    ExternalAgentConfigMigrationItem {
+        item_type,
+        description: String::new(),
+        cwd,
+        details: None,
+    }
+}
+
+#[test]
+fn selects_additional_sources_by_migration_source() {
+    assert_eq!(
+        ExternalAgentSource::from_migration_source(Some("gemini-cli")),
+        ExternalAgentSource::Gem
+    );
+    assert_eq!(
+        ExternalAgentSource::from_migration_source(Some("Gemini")),
+        ExternalAgentSource::Gem
+    );
+    assert_eq!(
+        ExternalAgentSource::from_migration_source(Some("opencode")),
+        ExternalAgentSource::Opc
+    );
+    assert_eq!(
+        ExternalAgentSource::from_migration_source(Some("aider")),
+        ExternalAgentSource::Cla
+    );
+}
+
+#[tokio::test]
+async fn import_home_migrates_gemini_settings_mcp_servers_and_context() {
+    let root = TempDir::new().expect("create tempdir");
+    let external_agent_home = root.path().join(".gemini");
+    let codex_home = root.path().join(".codex");
+    fs::create_dir_all(&external_agent_home).expect("create gemini home");
+    fs::write(
+        external_agent_home.join("settings.json"),
+        serde_json::json!({
+            "general": { "vimMode": true },
+            "mcpServers": { "docs": { "command": "docs-server" } },
+        })
+        .to_string(),
+    )
+    .expect("write settings");
+    fs::write(
+        external_agent_home.join("GEMINI.md"),
+        "Gemini CLI reads GEMINI.md first.\n\n## Gemini Added Memories\n- Use pnpm.",
+    )
+    .expect("write context");
+    let mut service = service_for_paths(external_agent_home, codex_home.clone());
+    service.source = ExternalAgentSource::Gem;
+
+    service
+        .import(vec![
+            migration_item(ExternalAgentConfigMigrationItemType::Config, None),
+            migration_item(ExternalAgentConfigMigrationItemType::McpServerConfig, None),
+            migration_item(ExternalAgentConfigMigrationItemType::AgentsMd, None),
+        ])
+        .await;
+
+    let config: TomlValue =
+        toml::from_str(&fs::read_to_string(codex_home.join("config.toml")).expect("read config"))
+            .expect("parse config");
+    let expected: TomlValue = toml::from_str(
+        r#"
+[tui]
+vim_mode_default = true
+
+[mcp_servers.docs]
+command = "docs-server"
+"#,
+    )
+    .expect("parse expected config");
+    assert_eq!(config, expected);
+    assert_eq!(
+        fs::read_to_string(codex_home.join("AGENTS.md")).expect("read agents"),
+        "Codex reads AGENTS.md first.\n\n## Gemini Added Memories\n- Use pnpm."
+    );
+}
+
+#[tokio::test]
+async fn detect_repo_mcp_for_opencode_reads_only_the_repo_config() {
+    let root = TempDir::new().expect("create tempdir");
+    let external_agent_home = root.path().join(".config/opencode");
+    let repo_root = root.path().join("repo");
+    let other_repo_root = root.path().join("other-repo");
+    fs::create_dir_all(&external_agent_home).expect("create opencode home");
+    fs::create_dir_all(repo_root.join(".git")).expect("create git dir");
+    fs::create_dir_all(other_repo_root.join(".git")).expect("create other git dir");
+    fs::write(
+        external_agent_home.join("opencode.json"),
+        serde_json::json!({
+            "mcp": { "home": { "type": "local", "command": ["home-server"] } },
+        })
+        .to_string(),
+    )
+    .expect("write home config");
+    fs::write(
+        repo_root.join("opencode.json"),
+        serde_json::json!({
+            "mcp": { "docs": { "type": "local", "command": ["docs-server"] } },
+        })
+        .to_string(),
+    )
+    .expect("write repo config");
+    let mut service = service_for_paths(external_agent_home, root.path().join(".codex"));
+    service.source = ExternalAgentSource::Opc;
+
+    let items = service
+        .detect(ExternalAgentConfigDetectOptions {
+            include_home: false,
+            include_memory: false,
+            cwds: Some(vec![repo_root.clone(), other_repo_root]),
+        })
+        .await
+        .expect("detect");
+
+    assert_eq!(
+        items,
+        vec![ExternalAgentConfigMigrationItem {
+            item_type: ExternalAgentConfigMigrationItemType::McpServerConfig,
+            description: format!(
+                "Migrate MCP servers from {} into {}",
+                repo_root.join("opencode.json").display(),
+                repo_root.join(".codex").join("config.toml").display()
+            ),
+            cwd: Some(repo_root),
+            details: Some(MigrationDetails {
+                mcp_servers: vec![NamedMigration {
+                    name: "docs".to_string(),
+                }],
+                ..Default::default()
+            }),
+        }]
+    );
+}
+
+#[test]
+fn accepts_session_paths_under_the_source_session_root() {
+    let root = TempDir::new().expect("create tempdir");
+    let external_agent_home = root.path().join(".gemini");
+    let chat = external_agent_home.join("tmp/workspace/chats/session-a.json");
+    fs::create_dir_all(chat.parent().expect("chat parent")).expect("create chats");
+    fs::write(&chat, "{}").expect("write chat");
+    let transcript = external_agent_home.join("projects/workspace/session.jsonl");
+    fs::create_dir_all(transcript.parent().expect("transcript parent")).expect("create projects");
+    fs::write(&transcript, "{}").expect("write transcript");
+    let mut service = service_for_paths(external_agent_home, root.path().join(".codex"));
+    service.source = ExternalAgentSource::Gem;
+
+    assert_eq!(
+        service
+            .external_agent_session_source_path(&chat)
+            .expect("resolve chat"),
+        Some(fs::canonicalize(&chat).expect("canonical chat"))
+    );
+    assert_eq!(
+        service
+            .external_agent_session_source_path(&transcript)
+            .expect("resolve transcript"),
+        None
+    );
+}
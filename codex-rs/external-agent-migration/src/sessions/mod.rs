@@ -3,6 +3,8 @@
 mod export;
 pub(crate) mod ledger;
 pub(crate) mod records;
+mod records_gem;
+mod records_opc;
 mod title;
 
 use codex_protocol::protocol::RolloutItem;
@@ -15,6 +17,8 @@ pub use crate::detect::sessions::ImportedSessionConnectorAttribution;
 pub use crate::detect::sessions::detect_imported_cla_session_connectors;
 pub use crate::detect::sessions::detect_recent_cla_sessions;
 pub use crate::detect::sessions::detect_recent_cur_sessions;
+pub use crate::detect::sessions::detect_recent_gem_sessions;
+pub use crate::detect::sessions::detect_recent_opc_sessions;
 use export::load_session_for_import_with_content_sha256;
 pub use ledger::CompletedExternalAgentSessionImport;
 pub use ledger::ImportedConnectorCandidate;
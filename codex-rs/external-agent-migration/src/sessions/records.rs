@@ -1,6 +1,10 @@
 use super::ConversationMessage;
 use super::ExternalAgentSessionMigration;
 use super::MessageRole;
+use super::records_gem::is_gem_session;
+use super::records_gem::read_gem_session;
+use super::records_opc::is_opc_session;
+use super::records_opc::read_opc_session;
 use super::title::IMPORTED_SESSION_FALLBACK_TITLE;
 use super::title::SessionTitleCandidates;
 use super::title::fallback_title_from_user_message;
@@ -9,6 +13,7 @@ use serde_json::Value as JsonValue;
 use sha2::Digest;
 use sha2::Sha256;
 use std::collections::BTreeSet;
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::BufRead;
@@ -20,6 +25,7 @@ const NOTE_MAX_LEN: usize = 2_000;
 const TOOL_RESULT_MAX_LEN: usize = 4_000;
 const EXTERNAL_AGENT_TOOL_CALL_TAG: &str = "external_agent_tool_call";
 const EXTERNAL_AGENT_TOOL_RESULT_TAG: &str = "external_agent_tool_result";
+const SESSION_DOCUMENT_EXTENSION: &str = "json";
 
 pub struct SessionSummary {
     pub latest_timestamp: i64,
@@ -43,6 +49,9 @@ pub(crate) fn summarize_session_with_cwd(
     path: &Path,
     fallback_cwd: Option<&Path>,
 ) -> io::Result<Option<SessionSummary>> {
+    if let Some(parsed) = read_session_document(path, fallback_cwd)? {
+        return Ok(summarize_parsed_session(path, parsed));
+    }
     let file = File::open(path)?;
     let fallback_timestamp = fallback_cwd.and_then(|_| file_modified_at_seconds(&file));
     let reader = BufReader::new(file);
@@ -122,6 +131,9 @@ pub(super) fn read_session_import_with_cwd(
     path: &Path,
     fallback_cwd: Option<&Path>,
 ) -> io::Result<ParsedSessionImport> {
+    if let Some(parsed) = read_session_document(path, fallback_cwd)? {
+        return Ok(parsed);
+    }
     let file = File::open(path)?;
     let fallback_timestamp = fallback_cwd.and_then(|_| file_modified_at_seconds(&file));
     let mut reader = BufReader::new(file);
@@ -181,6 +193,72 @@ pub(super) fn read_session_import_with_cwd(
     })
 }
 
+/// Reads sources that store each session as one JSON document instead of JSONL records.
+///
+/// Returns `None` for JSONL transcripts. Unrecognized documents parse as empty sessions so
+/// detection and import skip them.
+fn read_session_document(
+    path: &Path,
+    fallback_cwd: Option<&Path>,
+) -> io::Result<Option<ParsedSessionImport>> {
+    if path.extension().and_then(|extension| extension.to_str()) != Some(SESSION_DOCUMENT_EXTENSION)
+    {
+        return Ok(None);
+    }
+    let raw = fs::read(path)?;
+    let document = serde_json::from_slice::<JsonValue>(&raw).unwrap_or(JsonValue::Null);
+    if is_opc_session(&document) {
+        return read_opc_session(path, &raw, &document).map(Some);
+    }
+    let content_sha256 = format!("{:x}", Sha256::digest(&raw));
+    if is_gem_session(&document) {
+        return Ok(Some(read_gem_session(
+            &document,
+            content_sha256,
+            fallback_cwd,
+        )));
+    }
+    Ok(Some(ParsedSessionImport {
+        cwd: None,
+        custom_title: None,
+        ai_title: None,
+        messages: Vec::new(),
+        content_sha256,
+        attributed_mcp_server_ids: BTreeSet::new(),
+    }))
+}
+
+fn summarize_parsed_session(path: &Path, parsed: ParsedSessionImport) -> Option<SessionSummary> {
+    let cwd = parsed.cwd?;
+    let latest_timestamp = parsed
+        .messages
+        .iter()
+        .filter_map(|message| message.timestamp)
+        .max()?;
+    let mut user_messages = parsed
+        .messages
+        .iter()
+        .filter(|message| message.role == MessageRole::User)
+        .peekable();
+    let saw_user_message = user_messages.peek().is_some();
+    let fallback_title = user_messages
+        .find_map(|message| fallback_title_from_user_message(&message.text))
+        .or_else(|| saw_user_message.then(|| IMPORTED_SESSION_FALLBACK_TITLE.to_string()));
+    Some(SessionSummary {
+        latest_timestamp,
+        migration: ExternalAgentSessionMigration {
+            path: path.to_path_buf(),
+            cwd,
+            title: SessionTitleCandidates {
+                custom_title: parsed.custom_title,
+                ai_title: parsed.ai_title,
+                fallback_title,
+            }
+            .select(),
+        },
+    })
+}
+
 fn custom_title_from_record(record: &JsonValue) -> Option<&str> {
     title_from_record(record, "custom-title", "customTitle")
 }
@@ -351,7 +429,7 @@ fn content_blocks(content: &JsonValue) -> Vec<JsonValue> {
         .unwrap_or_default()
 }
 
-fn tool_call_note(block: &JsonValue) -> String {
+pub(super) fn tool_call_note(block: &JsonValue) -> String {
     let name = block
         .get("name")
         .and_then(JsonValue::as_str)
@@ -387,7 +465,7 @@ fn tool_call_note(block: &JsonValue) -> String {
     lines.join("\n")
 }
 
-fn tool_result_note(block: &JsonValue) -> String {
+pub(super) fn tool_result_note(block: &JsonValue) -> String {
     let label = if block.get("is_error").and_then(JsonValue::as_bool) == Some(true) {
         format!("[{EXTERNAL_AGENT_TOOL_RESULT_TAG}: error]")
     } else {
@@ -417,7 +495,7 @@ fn tool_result_text(content: Option<&JsonValue>) -> String {
     }
 }
 
-fn parse_timestamp(timestamp: &str) -> Option<i64> {
+pub(super) fn parse_timestamp(timestamp: &str) -> Option<i64> {
     chrono::DateTime::parse_from_rfc3339(timestamp)
         .ok()
         .map(|value| value.timestamp())
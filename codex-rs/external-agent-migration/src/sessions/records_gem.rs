@@ -0,0 +1,129 @@
+use super::ConversationMessage;
+use super::MessageRole;
+use super::records::ParsedSessionImport;
+use super::records::parse_timestamp;
+use super::records::tool_call_note;
+use super::records::tool_result_note;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+/// Returns whether a session document is a Gemini CLI `ConversationRecord`.
+pub(super) fn is_gem_session(document: &JsonValue) -> bool {
+    document.get("sessionId").is_some_and(JsonValue::is_string)
+        && document.get("messages").is_some_and(JsonValue::is_array)
+}
+
+/// Reads a Gemini CLI chat file. The records only carry a project hash, so the project path
+/// always comes from the detected migration.
+pub(super) fn read_gem_session(
+    document: &JsonValue,
+    content_sha256: String,
+    fallback_cwd: Option<&Path>,
+) -> ParsedSessionImport {
+    let ai_title = document
+        .get("summary")
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|summary| !summary.is_empty())
+        .map(ToOwned::to_owned);
+    let messages = document
+        .get("messages")
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(gem_message)
+        .collect();
+    ParsedSessionImport {
+        cwd: fallback_cwd.map(Path::to_path_buf),
+        custom_title: None,
+        ai_title,
+        messages,
+        content_sha256,
+        attributed_mcp_server_ids: BTreeSet::new(),
+    }
+}
+
+fn gem_message(record: &JsonValue) -> Option<ConversationMessage> {
+    let role = match record.get("type").and_then(JsonValue::as_str)? {
+        "user" => MessageRole::User,
+        "gemini" => MessageRole::Assistant,
+        _ => return None,
+    };
+    let mut parts = vec![content_text(record.get("content"))];
+    if role == MessageRole::Assistant {
+        for call in record
+            .get("toolCalls")
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+        {
+            parts.push(tool_call_note(&serde_json::json!({
+                "name": call.get("name"),
+                "input": call.get("args"),
+            })));
+            parts.push(tool_result_note(&serde_json::json!({
+                "is_error": call.get("status").and_then(JsonValue::as_str) == Some("error"),
+                "content": tool_call_result_text(call),
+            })));
+        }
+    }
+    let text = parts
+        .into_iter()
+        .filter(|part| !part.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    if text.is_empty() {
+        return None;
+    }
+    Some(ConversationMessage {
+        role,
+        text,
+        timestamp: record
+            .get("timestamp")
+            .and_then(JsonValue::as_str)
+            .and_then(parse_timestamp),
+    })
+}
+
+/// Flattens Gemini `PartListUnion` content, dropping thoughts and non-text parts.
+fn content_text(content: Option<&JsonValue>) -> String {
+    match content {
+        Some(JsonValue::String(text)) => text.clone(),
+        Some(JsonValue::Array(parts)) => parts
+            .iter()
+            .map(|part| content_text(Some(part)))
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Some(part @ JsonValue::Object(_)) => {
+            if part.get("thought").and_then(JsonValue::as_bool) == Some(true) {
+                return String::new();
+            }
+            part.get("text")
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default()
+                .to_string()
+        }
+        _ => String::new(),
+    }
+}
+
+fn tool_call_result_text(call: &JsonValue) -> String {
+    if let Some(display) = call.get("resultDisplay").and_then(JsonValue::as_str) {
+        return display.to_string();
+    }
+    call.get("result")
+        .and_then(JsonValue::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|part| part.get("functionResponse")?.get("response"))
+        .filter_map(|response| {
+            response
+                .get("output")
+                .or_else(|| response.get("error"))
+                .and_then(JsonValue::as_str)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,177 @@
+use super::ConversationMessage;
+use super::MessageRole;
+use super::records::ParsedSessionImport;
+use super::records::tool_call_note;
+use super::records::tool_result_note;
+use serde_json::Value as JsonValue;
+use sha2::Digest;
+use sha2::Sha256;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+const DEFAULT_TITLE_PREFIXES: [&str; 2] = ["New session - ", "Child session - "];
+
+/// Returns whether a session document is an opencode session info file.
+pub(super) fn is_opc_session(document: &JsonValue) -> bool {
+    document.get("projectID").is_some_and(JsonValue::is_string)
+        && document.get("directory").is_some_and(JsonValue::is_string)
+}
+
+/// Reads an opencode session from `storage/session/<project>/<session>.json`.
+///
+/// Messages and their parts live in sibling `storage/message` and `storage/part` trees; every
+/// file that contributes to the import is folded into the content hash. Child sessions spawned
+/// by subagents are not imported on their own.
+pub(super) fn read_opc_session(
+    path: &Path,
+    raw: &[u8],
+    document: &JsonValue,
+) -> io::Result<ParsedSessionImport> {
+    let mut hasher = Sha256::new();
+    hasher.update(raw);
+    let ai_title = document
+        .get("title")
+        .and_then(JsonValue::as_str)
+        .map(str::trim)
+        .filter(|title| {
+            !title.is_empty()
+                && !DEFAULT_TITLE_PREFIXES
+                    .iter()
+                    .any(|prefix| title.starts_with(prefix))
+        })
+        .map(ToOwned::to_owned);
+    let is_child_session = document
+        .get("parentID")
+        .is_some_and(|parent_id| !parent_id.is_null());
+    let storage_root = path.parent().and_then(Path::parent).and_then(Path::parent);
+    let session_id = document
+        .get("id")
+        .and_then(JsonValue::as_str)
+        .map(ToOwned::to_owned)
+        .or_else(|| file_stem(path));
+
+    let mut messages = Vec::new();
+    if let (false, Some(storage_root), Some(session_id)) =
+        (is_child_session, storage_root, session_id)
+    {
+        for message_path in sorted_json_files(&storage_root.join("message").join(&session_id))? {
+            let raw = fs::read(&message_path)?;
+            hasher.update(&raw);
+            let Ok(message) = serde_json::from_slice::<JsonValue>(&raw) else {
+                continue;
+            };
+            let Some(message_id) = message
+                .get("id")
+                .and_then(JsonValue::as_str)
+                .map(ToOwned::to_owned)
+                .or_else(|| file_stem(&message_path))
+            else {
+                continue;
+            };
+            let mut parts = Vec::new();
+            for part_path in sorted_json_files(&storage_root.join("part").join(message_id))? {
+                let raw = fs::read(&part_path)?;
+                hasher.update(&raw);
+                if let Ok(part) = serde_json::from_slice::<JsonValue>(&raw)
+                    && let Some(text) = part_text(&part)
+                {
+                    parts.push(text);
+                }
+            }
+            if let Some(message) = opc_message(&message, parts) {
+                messages.push(message);
+            }
+        }
+    }
+
+    Ok(ParsedSessionImport {
+        cwd: document
+            .get("directory")
+            .and_then(JsonValue::as_str)
+            .map(PathBuf::from),
+        custom_title: None,
+        ai_title,
+        messages,
+        content_sha256: format!("{:x}", hasher.finalize()),
+        attributed_mcp_server_ids: BTreeSet::new(),
+    })
+}
+
+fn opc_message(message: &JsonValue, parts: Vec<String>) -> Option<ConversationMessage> {
+    let role = match message.get("role").and_then(JsonValue::as_str)? {
+        "user" => MessageRole::User,
+        "assistant" => MessageRole::Assistant,
+        _ => return None,
+    };
+    let text = parts.join("\n\n");
+    if text.trim().is_empty() {
+        return None;
+    }
+    Some(ConversationMessage {
+        role,
+        text,
+        timestamp: message
+            .get("time")
+            .and_then(|time| time.get("created"))
+            .and_then(JsonValue::as_i64)
+            .map(|created_ms| created_ms / 1_000),
+    })
+}
+
+fn part_text(part: &JsonValue) -> Option<String> {
+    match part.get("type").and_then(JsonValue::as_str)? {
+        "text" => {
+            if part.get("synthetic").and_then(JsonValue::as_bool) == Some(true) {
+                return None;
+            }
+            part.get("text")
+                .and_then(JsonValue::as_str)
+                .filter(|text| !text.trim().is_empty())
+                .map(ToOwned::to_owned)
+        }
+        "tool" => {
+            let state = part.get("state");
+            let status = state
+                .and_then(|state| state.get("status"))
+                .and_then(JsonValue::as_str);
+            let output = state.and_then(|state| state.get("output").or_else(|| state.get("error")));
+            let call = tool_call_note(&serde_json::json!({
+                "name": part.get("tool"),
+                "input": state.and_then(|state| state.get("input")),
+            }));
+            let result = tool_result_note(&serde_json::json!({
+                "is_error": status == Some("error"),
+                "content": output,
+            }));
+            Some(format!("{call}\n\n{result}"))
+        }
+        _ => None,
+    }
+}
+
+fn sorted_json_files(directory: &Path) -> io::Result<Vec<PathBuf>> {
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let mut files = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path.extension().and_then(|extension| extension.to_str()) == Some("json")
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    Ok(files)
+}
+
+fn file_stem(path: &Path) -> Option<String> {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .map(ToOwned::to_owned)
+}
@@ -0,0 +1,263 @@
+use super::InstructionSourceGroup;
+use super::build_config;
+use super::is_non_empty_text_file;
+use super::read_json_file;
+use crate::RewriteProfile;
+use crate::build_mcp_config_from_servers;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use toml::Value as TomlValue;
+
+pub struct GemSource;
+
+impl GemSource {
+    pub const CONFIG_DIR: &'static str = ".gemini";
+    pub const CONFIG_MD: &'static str = "GEMINI.md";
+    pub const MIGRATION_SOURCE: &'static str = "gemini-cli";
+    pub const MIGRATION_SOURCE_ALIAS: &'static str = "gemini";
+    pub const SETTINGS_FILE: &'static str = "settings.json";
+    pub const SESSIONS_DIR: &'static str = "tmp";
+    pub const PROJECT_ROOT_FILE: &'static str = ".project_root";
+    pub const TRUSTED_FOLDERS_FILE: &'static str = "trustedFolders.json";
+    pub const REWRITE_PROFILE: RewriteProfile =
+        RewriteProfile::new(Self::CONFIG_MD, &["gemini cli", "gemini-cli", "gemini_cli"]);
+
+    pub fn effective_settings(source_settings: &Path) -> io::Result<Option<JsonValue>> {
+        read_json_file(source_settings)
+    }
+
+    pub fn build_config(settings: &JsonValue) -> io::Result<TomlValue> {
+        build_config(settings, Self::append_config)
+    }
+
+    /// Maps Gemini CLI settings onto Codex config keys.
+    ///
+    /// Both the nested settings layout and the older flat layout are accepted; the nested
+    /// key wins when both are present.
+    pub fn append_config(
+        root: &mut toml::map::Map<String, TomlValue>,
+        settings: &serde_json::Map<String, JsonValue>,
+    ) {
+        if setting(settings, &["tools", "sandbox"], "sandbox").is_some_and(is_enabled_sandbox) {
+            root.insert(
+                "sandbox_mode".to_string(),
+                TomlValue::String("workspace-write".to_string()),
+            );
+        }
+        if setting(
+            settings,
+            &["general", "disableAutoUpdate"],
+            "disableAutoUpdate",
+        )
+        .and_then(JsonValue::as_bool)
+            == Some(true)
+        {
+            root.insert(
+                "check_for_update_on_startup".to_string(),
+                TomlValue::Boolean(false),
+            );
+        }
+        if setting(
+            settings,
+            &["privacy", "usageStatisticsEnabled"],
+            "usageStatisticsEnabled",
+        )
+        .and_then(JsonValue::as_bool)
+            == Some(false)
+        {
+            insert_nested(root, "analytics", "enabled", TomlValue::Boolean(false));
+        }
+        if setting(settings, &["general", "vimMode"], "vimMode").and_then(JsonValue::as_bool)
+            == Some(true)
+        {
+            insert_nested(root, "tui", "vim_mode_default", TomlValue::Boolean(true));
+        }
+        if setting(settings, &["ui", "hideTips"], "hideTips").and_then(JsonValue::as_bool)
+            == Some(true)
+        {
+            insert_nested(root, "tui", "show_tooltips", TomlValue::Boolean(false));
+        }
+    }
+
+    pub fn build_mcp_config(settings: Option<&JsonValue>) -> io::Result<TomlValue> {
+        let Some(settings) = settings.and_then(JsonValue::as_object) else {
+            return Ok(TomlValue::Table(Default::default()));
+        };
+        let Some(mcp_servers) = settings.get("mcpServers").and_then(JsonValue::as_object) else {
+            return Ok(TomlValue::Table(Default::default()));
+        };
+        let allowed = setting(settings, &["mcp", "allowed"], "allowMCPServers").map(string_set);
+        let excluded = setting(settings, &["mcp", "excluded"], "excludeMCPServers")
+            .map(string_set)
+            .unwrap_or_default();
+
+        let servers = mcp_servers
+            .iter()
+            .filter(|(name, _)| {
+                allowed
+                    .as_ref()
+                    .is_none_or(|allowed| allowed.contains(name.as_str()))
+                    && !excluded.contains(name.as_str())
+            })
+            .filter_map(|(name, server)| {
+                normalized_mcp_server(server.as_object()?).map(|server| (name.clone(), server))
+            })
+            .collect::<BTreeMap<_, _>>();
+        build_mcp_config_from_servers(servers)
+    }
+
+    pub fn repo_instruction_source_groups(
+        repo_root: &Path,
+    ) -> io::Result<Vec<InstructionSourceGroup>> {
+        let source = repo_root.join(Self::CONFIG_MD);
+        Ok(is_non_empty_text_file(&source)?
+            .then(|| InstructionSourceGroup {
+                scope: repo_root.to_path_buf(),
+                sources: vec![source],
+            })
+            .into_iter()
+            .collect())
+    }
+
+    /// Returns the global context file, which also holds memories saved with `/memory add`.
+    pub fn home_instruction_sources(external_agent_home: &Path) -> io::Result<Vec<PathBuf>> {
+        let path = external_agent_home.join(Self::CONFIG_MD);
+        Ok(is_non_empty_text_file(&path)?
+            .then_some(path)
+            .into_iter()
+            .collect())
+    }
+
+    pub fn read_instruction_source(path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+}
+
+fn setting<'a>(
+    settings: &'a serde_json::Map<String, JsonValue>,
+    nested_path: &[&str],
+    legacy_key: &str,
+) -> Option<&'a JsonValue> {
+    nested_path
+        .split_first()
+        .and_then(|(first, rest)| {
+            rest.iter()
+                .try_fold(settings.get(*first)?, |value, key| value.get(key))
+        })
+        .or_else(|| settings.get(legacy_key))
+}
+
+fn is_enabled_sandbox(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Bool(enabled) => *enabled,
+        JsonValue::String(command) => !command.trim().is_empty() && command != "false",
+        JsonValue::Object(_) => true,
+        JsonValue::Null | JsonValue::Number(_) | JsonValue::Array(_) => false,
+    }
+}
+
+fn insert_nested(
+    root: &mut toml::map::Map<String, TomlValue>,
+    table: &str,
+    key: &str,
+    value: TomlValue,
+) {
+    if let TomlValue::Table(table) = root
+        .entry(table.to_string())
+        .or_insert_with(|| TomlValue::Table(Default::default()))
+    {
+        table.insert(key.to_string(), value);
+    }
+}
+
+fn string_set(value: &JsonValue) -> BTreeSet<String> {
+    value
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(JsonValue::as_str)
+        .map(ToOwned::to_owned)
+        .collect()
+}
+
+/// Rewrites a Gemini CLI server entry into the shape shared by the other MCP sources.
+///
+/// `httpUrl` selects streamable HTTP, while a bare `url` is an SSE endpoint that Codex cannot
+/// connect to, so those servers are skipped.
+fn normalized_mcp_server(server: &serde_json::Map<String, JsonValue>) -> Option<JsonValue> {
+    let mut normalized = serde_json::Map::new();
+    if let Some(command) = server.get("command") {
+        normalized.insert("command".to_string(), command.clone());
+        if let Some(args) = server.get("args").and_then(JsonValue::as_array) {
+            normalized.insert(
+                "args".to_string(),
+                JsonValue::Array(args.iter().map(normalized_env_reference).collect()),
+            );
+        }
+        if let Some(env) = server.get("env").and_then(JsonValue::as_object) {
+            normalized.insert(
+                "env".to_string(),
+                JsonValue::Object(
+                    env.iter()
+                        .map(|(key, value)| (key.clone(), normalized_env_reference(value)))
+                        .collect(),
+                ),
+            );
+        }
+        if let Some(cwd) = server.get("cwd") {
+            normalized.insert("cwd".to_string(), cwd.clone());
+        }
+    } else if let Some(url) = server.get("httpUrl") {
+        normalized.insert("type".to_string(), JsonValue::String("http".to_string()));
+        normalized.insert("url".to_string(), url.clone());
+        if let Some(headers) = server.get("headers").and_then(JsonValue::as_object) {
+            normalized.insert(
+                "headers".to_string(),
+                JsonValue::Object(
+                    headers
+                        .iter()
+                        .map(|(key, value)| (key.clone(), normalized_env_reference(value)))
+                        .collect(),
+                ),
+            );
+        }
+    } else {
+        return None;
+    }
+    Some(JsonValue::Object(normalized))
+}
+
+/// Gemini CLI expands both `$VAR` and `${VAR}`; the shared MCP mapping only understands the
+/// braced form.
+fn normalized_env_reference(value: &JsonValue) -> JsonValue {
+    let Some(text) = value.as_str() else {
+        return value.clone();
+    };
+    let mut normalized = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(character) = chars.next() {
+        normalized.push(character);
+        if character != '$'
+            || !chars
+                .peek()
+                .is_some_and(|next| *next == '_' || next.is_ascii_alphabetic())
+        {
+            continue;
+        }
+        normalized.push('{');
+        while let Some(next) = chars.next_if(|next| *next == '_' || next.is_ascii_alphanumeric()) {
+            normalized.push(next);
+        }
+        normalized.push('}');
+    }
+    JsonValue::String(normalized)
+}
+
+#[cfg(test)]
+#[path = "gem_tests.rs"]
+mod tests;
@@ -0,0 +1,134 @@
+use super::*;
+use pretty_assertions::assert_eq;
+
+fn toml(raw: &str) -> TomlValue {
+    toml::from_str(raw).expect("parse expected toml")
+}
+
+#[test]
+fn maps_nested_and_legacy_settings_to_codex_config() {
+    let nested = serde_json::json!({
+        "general": { "vimMode": true, "disableAutoUpdate": true },
+        "privacy": { "usageStatisticsEnabled": false },
+        "tools": { "sandbox": "docker" },
+        "ui": { "hideTips": true },
+    });
+    let legacy = serde_json::json!({
+        "vimMode": true,
+        "disableAutoUpdate": true,
+        "usageStatisticsEnabled": false,
+        "sandbox": true,
+        "hideTips": true,
+    });
+    let expected = toml(
+        r#"
+sandbox_mode = "workspace-write"
+check_for_update_on_startup = false
+
+[analytics]
+enabled = false
+
+[tui]
+vim_mode_default = true
+show_tooltips = false
+"#,
+    );
+
+    assert_eq!(
+        GemSource::build_config(&nested).expect("nested config"),
+        expected
+    );
+    assert_eq!(
+        GemSource::build_config(&legacy).expect("legacy config"),
+        expected
+    );
+}
+
+#[test]
+fn disabled_sandbox_is_not_migrated() {
+    let settings = serde_json::json!({
+        "tools": { "sandbox": false },
+        "sandbox": true,
+    });
+
+    assert_eq!(
+        GemSource::build_config(&settings).expect("config"),
+        TomlValue::Table(Default::default())
+    );
+}
+
+#[test]
+fn migrates_stdio_and_streamable_http_mcp_servers() {
+    let settings = serde_json::json!({
+        "mcpServers": {
+            "local": {
+                "command": "npx",
+                "args": ["-y", "@acme/mcp"],
+                "cwd": "/srv/tools",
+                "env": { "API_KEY": "$API_KEY", "REGION": "eu" },
+            },
+            "remote": {
+                "httpUrl": "https://mcp.example.com/mcp",
+                "headers": { "Authorization": "Bearer $ACME_TOKEN" },
+            },
+            "events": { "url": "https://mcp.example.com/sse" },
+            "excluded": { "command": "excluded-server" },
+        },
+        "mcp": { "excluded": ["excluded"] },
+    });
+
+    assert_eq!(
+        GemSource::build_mcp_config(Some(&settings)).expect("mcp config"),
+        toml(
+            r#"
+[mcp_servers.local]
+command = "npx"
+args = ["-y", "@acme/mcp"]
+cwd = "/srv/tools"
+env_vars = ["API_KEY"]
+
+[mcp_servers.local.env]
+REGION = "eu"
+
+[mcp_servers.remote]
+url = "https://mcp.example.com/mcp"
+bearer_token_env_var = "ACME_TOKEN"
+"#
+        )
+    );
+}
+
+#[test]
+fn allow_list_limits_migrated_mcp_servers() {
+    let settings = serde_json::json!({
+        "mcpServers": {
+            "first": { "command": "first-server" },
+            "second": { "command": "second-server" },
+        },
+        "allowMCPServers": ["second"],
+    });
+
+    assert_eq!(
+        GemSource::build_mcp_config(Some(&settings)).expect("mcp config"),
+        toml(
+            r#"
+[mcp_servers.second]
+command = "second-server"
+"#
+        )
+    );
+}
+
+#[test]
+fn skips_servers_with_embedded_env_references() {
+    let settings = serde_json::json!({
+        "mcpServers": {
+            "local": { "command": "server", "args": ["--root=$HOME/work"] },
+        },
+    });
+
+    assert_eq!(
+        GemSource::build_mcp_config(Some(&settings)).expect("mcp config"),
+        TomlValue::Table(Default::default())
+    );
+}
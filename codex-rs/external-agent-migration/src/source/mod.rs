@@ -1,5 +1,7 @@
 mod cla;
 mod cur;
+mod gem;
+mod opc;
 
 use crate::invalid_data_error;
 use serde_json::Value as JsonValue;
@@ -11,6 +13,8 @@ use toml::Value as TomlValue;
 
 pub use cla::ClaSource;
 pub use cur::CurSource;
+pub use gem::GemSource;
+pub use opc::OpcSource;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct InstructionSourceGroup {
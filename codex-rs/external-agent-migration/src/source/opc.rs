@@ -0,0 +1,187 @@
+use super::InstructionSourceGroup;
+use super::build_config;
+use super::is_non_empty_text_file;
+use super::read_json_file;
+use crate::RewriteProfile;
+use crate::build_mcp_config_from_servers;
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use toml::Value as TomlValue;
+
+pub struct OpcSource;
+
+impl OpcSource {
+    pub const CONFIG_DIR: &'static str = ".opencode";
+    pub const HOME_CONFIG_DIR: &'static str = ".config/opencode";
+    pub const MIGRATION_SOURCE: &'static str = "opencode";
+    pub const CONFIG_FILE: &'static str = "opencode.json";
+    pub const CONFIG_MD: &'static str = "AGENTS.md";
+    pub const DATA_DIR: &'static str = ".local/share/opencode";
+    pub const REWRITE_PROFILE: RewriteProfile = RewriteProfile::new(Self::CONFIG_MD, &["opencode"]);
+
+    pub fn effective_settings(source_settings: &Path) -> io::Result<Option<JsonValue>> {
+        read_json_file(source_settings)
+    }
+
+    pub fn build_config(settings: &JsonValue) -> io::Result<TomlValue> {
+        build_config(settings, Self::append_config)
+    }
+
+    pub fn append_config(
+        root: &mut toml::map::Map<String, TomlValue>,
+        settings: &serde_json::Map<String, JsonValue>,
+    ) {
+        if settings.get("autoupdate").and_then(JsonValue::as_bool) == Some(false) {
+            root.insert(
+                "check_for_update_on_startup".to_string(),
+                TomlValue::Boolean(false),
+            );
+        }
+    }
+
+    pub fn build_mcp_config(settings: Option<&JsonValue>) -> io::Result<TomlValue> {
+        let servers = settings
+            .and_then(|settings| settings.get("mcp"))
+            .and_then(JsonValue::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, server)| {
+                normalized_mcp_server(server.as_object()?).map(|server| (name.clone(), server))
+            })
+            .collect::<BTreeMap<_, _>>();
+        build_mcp_config_from_servers(servers)
+    }
+
+    /// Collects the extra instruction files a project lists in `opencode.json`.
+    ///
+    /// `AGENTS.md` itself is already read natively, and glob entries are skipped because
+    /// they cannot be resolved to a stable set of files.
+    pub fn repo_instruction_source_groups(
+        repo_root: &Path,
+    ) -> io::Result<Vec<InstructionSourceGroup>> {
+        let Some(settings) = read_json_file(&repo_root.join(Self::CONFIG_FILE))? else {
+            return Ok(Vec::new());
+        };
+        let mut sources = Vec::new();
+        for instruction in settings
+            .get("instructions")
+            .and_then(JsonValue::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(JsonValue::as_str)
+        {
+            let relative = Path::new(instruction);
+            if relative.is_absolute()
+                || instruction.contains(['*', '?', '['])
+                || relative == Path::new(Self::CONFIG_MD)
+            {
+                continue;
+            }
+            let source = repo_root.join(relative);
+            if is_non_empty_text_file(&source)? && !sources.contains(&source) {
+                sources.push(source);
+            }
+        }
+        Ok((!sources.is_empty())
+            .then(|| InstructionSourceGroup {
+                scope: repo_root.to_path_buf(),
+                sources,
+            })
+            .into_iter()
+            .collect())
+    }
+
+    pub fn home_instruction_sources(external_agent_home: &Path) -> io::Result<Vec<PathBuf>> {
+        let path = external_agent_home.join(Self::CONFIG_MD);
+        Ok(is_non_empty_text_file(&path)?
+            .then_some(path)
+            .into_iter()
+            .collect())
+    }
+
+    pub fn read_instruction_source(path: &Path) -> io::Result<String> {
+        fs::read_to_string(path)
+    }
+
+    /// Returns the session storage root that sits next to the home config directory.
+    pub fn session_storage_root(external_agent_home: &Path) -> PathBuf {
+        external_agent_home
+            .parent()
+            .and_then(Path::parent)
+            .unwrap_or_else(|| Path::new("."))
+            .join(Self::DATA_DIR)
+            .join("storage")
+    }
+}
+
+/// Rewrites an opencode `local`/`remote` server entry into the shape shared by the other MCP
+/// sources. Entries that read values from files are skipped.
+fn normalized_mcp_server(server: &serde_json::Map<String, JsonValue>) -> Option<JsonValue> {
+    let mut normalized = serde_json::Map::new();
+    if let Some(enabled) = server.get("enabled") {
+        normalized.insert("enabled".to_string(), enabled.clone());
+    }
+    match server.get("type").and_then(JsonValue::as_str) {
+        Some("local") => {
+            let (command, args) = server.get("command")?.as_array()?.split_first()?;
+            normalized.insert("command".to_string(), normalized_substitution(command)?);
+            normalized.insert(
+                "args".to_string(),
+                JsonValue::Array(
+                    args.iter()
+                        .map(normalized_substitution)
+                        .collect::<Option<_>>()?,
+                ),
+            );
+            if let Some(environment) = server.get("environment").and_then(JsonValue::as_object) {
+                normalized.insert(
+                    "env".to_string(),
+                    normalized_object(environment).map(JsonValue::Object)?,
+                );
+            }
+        }
+        Some("remote") => {
+            normalized.insert("type".to_string(), JsonValue::String("http".to_string()));
+            normalized.insert(
+                "url".to_string(),
+                normalized_substitution(server.get("url")?)?,
+            );
+            if let Some(headers) = server.get("headers").and_then(JsonValue::as_object) {
+                normalized.insert(
+                    "headers".to_string(),
+                    normalized_object(headers).map(JsonValue::Object)?,
+                );
+            }
+        }
+        _ => return None,
+    }
+    Some(JsonValue::Object(normalized))
+}
+
+fn normalized_object(
+    object: &serde_json::Map<String, JsonValue>,
+) -> Option<serde_json::Map<String, JsonValue>> {
+    object
+        .iter()
+        .map(|(key, value)| Some((key.clone(), normalized_substitution(value)?)))
+        .collect()
+}
+
+/// Converts opencode `{env:VAR}` substitutions to `${VAR}` and rejects `{file:...}` ones.
+fn normalized_substitution(value: &JsonValue) -> Option<JsonValue> {
+    let Some(text) = value.as_str() else {
+        return Some(value.clone());
+    };
+    if text.contains("{file:") {
+        return None;
+    }
+    Some(JsonValue::String(text.replace("{env:", "${")))
+}
+
+#[cfg(test)]
+#[path = "opc_tests.rs"]
+mod tests;
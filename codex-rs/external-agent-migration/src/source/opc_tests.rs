@@ -0,0 +1,112 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+
+fn toml(raw: &str) -> TomlValue {
+    toml::from_str(raw).expect("parse expected toml")
+}
+
+#[test]
+fn maps_disabled_autoupdate_to_update_check() {
+    let settings = serde_json::json!({
+        "$schema": "https://opencode.ai/config.json",
+        "autoupdate": false,
+        "share": "disabled",
+    });
+
+    assert_eq!(
+        OpcSource::build_config(&settings).expect("config"),
+        toml("check_for_update_on_startup = false")
+    );
+}
+
+#[test]
+fn migrates_local_and_remote_mcp_servers() {
+    let settings = serde_json::json!({
+        "mcp": {
+            "local": {
+                "type": "local",
+                "command": ["npx", "-y", "@acme/mcp"],
+                "environment": { "API_KEY": "{env:API_KEY}", "REGION": "eu" },
+            },
+            "remote": {
+                "type": "remote",
+                "url": "https://mcp.example.com/mcp",
+                "headers": { "Authorization": "Bearer {env:ACME_TOKEN}" },
+            },
+            "disabled": {
+                "type": "local",
+                "command": ["disabled-server"],
+                "enabled": false,
+            },
+            "secret-file": {
+                "type": "remote",
+                "url": "https://mcp.example.com/mcp",
+                "headers": { "X-Api-Key": "{file:~/.secrets/acme}" },
+            },
+        },
+    });
+
+    assert_eq!(
+        OpcSource::build_mcp_config(Some(&settings)).expect("mcp config"),
+        toml(
+            r#"
+[mcp_servers.local]
+command = "npx"
+args = ["-y", "@acme/mcp"]
+env_vars = ["API_KEY"]
+
+[mcp_servers.local.env]
+REGION = "eu"
+
+[mcp_servers.remote]
+url = "https://mcp.example.com/mcp"
+bearer_token_env_var = "ACME_TOKEN"
+"#
+        )
+    );
+}
+
+#[test]
+fn collects_listed_repo_instruction_files() {
+    let root = TempDir::new().expect("tempdir");
+    fs::create_dir_all(root.path().join("docs")).expect("docs dir");
+    fs::write(root.path().join("CONTRIBUTING.md"), "Run the tests.").expect("contributing");
+    fs::write(root.path().join("docs/style.md"), "Prefer small diffs.").expect("style");
+    fs::write(root.path().join("AGENTS.md"), "Already native.").expect("agents");
+    fs::write(root.path().join("empty.md"), "\n").expect("empty");
+    fs::write(
+        root.path().join(OpcSource::CONFIG_FILE),
+        serde_json::json!({
+            "instructions": [
+                "CONTRIBUTING.md",
+                "docs/style.md",
+                "docs/*.md",
+                "AGENTS.md",
+                "empty.md",
+                "missing.md",
+            ],
+        })
+        .to_string(),
+    )
+    .expect("config");
+
+    assert_eq!(
+        OpcSource::repo_instruction_source_groups(root.path()).expect("instruction groups"),
+        vec![InstructionSourceGroup {
+            scope: root.path().to_path_buf(),
+            sources: vec![
+                root.path().join("CONTRIBUTING.md"),
+                root.path().join("docs/style.md"),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn session_storage_sits_under_the_user_data_dir() {
+    assert_eq!(
+        OpcSource::session_storage_root(Path::new("/home/dev/.config/opencode")),
+        PathBuf::from("/home/dev/.local/share/opencode/storage")
+    );
+}
@@ -244,6 +244,16 @@ pub enum Feature {
     RealtimeConversation,
     /// Prevent idle system sleep while a turn is actively running.
     PreventIdleSleep,
+    /// Snapshot the workspace before each turn so file changes can be undone.
+    WorkspaceCheckpoints,
+    /// Expose the `pin_context` tool so the model can pin facts that must survive compaction.
+    PinContextTool,
+    /// Let spawned sub-agents work in an isolated git worktree and merge their changes back.
+    AgentWorktrees,
+    /// Let shell commands request stored secrets as environment variables, redacted from output.
+    CommandSecrets,
+    /// Let unified exec yield on output patterns or idleness and render terminal screens.
+    InteractiveExec,
     /// Enable remote compaction v2 over the normal Responses API.
     RemoteCompactionV2,
     /// Use Agent Identity for ChatGPT-authenticated sessions.
@@ -1357,6 +1367,56 @@ pub const FEATURES: &[FeatureSpec] = &[
         },
         default_enabled: false,
     },
+    FeatureSpec {
+        id: Feature::WorkspaceCheckpoints,
+        key: "checkpoints",
+        stage: Stage::Experimental {
+            name: "Checkpoints",
+            menu_description: "Snapshot the workspace before each turn so /undo can restore files the agent changed.",
+            announcement: "",
+        },
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::PinContextTool,
+        key: "pin_context_tool",
+        stage: Stage::Experimental {
+            name: "Model pinning",
+            menu_description: "Let the model pin facts that must survive compaction, alongside your own /pin items.",
+            announcement: "",
+        },
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::AgentWorktrees,
+        key: "agent_worktrees",
+        stage: Stage::Experimental {
+            name: "Agent worktrees",
+            menu_description: "Let sub-agents work in an isolated copy of the workspace and merge their changes back when they finish.",
+            announcement: "",
+        },
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::CommandSecrets,
+        key: "command_secrets",
+        stage: Stage::Experimental {
+            name: "Command secrets",
+            menu_description: "Let commands receive secrets stored with `codex secrets` as environment variables, with their values hidden from the model.",
+            announcement: "",
+        },
+        default_enabled: false,
+    },
+    FeatureSpec {
+        id: Feature::InteractiveExec,
+        key: "interactive_exec",
+        stage: Stage::Experimental {
+            name: "Interactive exec",
+            menu_description: "Let commands return as soon as their output matches a pattern or goes quiet, and show full-screen programs as a rendered terminal screen.",
+            announcement: "",
+        },
+        default_enabled: false,
+    },
     FeatureSpec {
         id: Feature::WorkspaceOwnerUsageNudge,
         key: "workspace_owner_usage_nudge",
@@ -57,9 +57,9 @@ impl GitBaselineDiff {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct GitBaselineFileEntry {
-    oid: ObjectId,
-    mode: EntryMode,
+pub(crate) struct GitBaselineFileEntry {
+    pub(crate) oid: ObjectId,
+    pub(crate) mode: EntryMode,
 }
 
 /// Replaces any existing `.git` metadata in `root` with a fresh one-commit baseline.
@@ -159,7 +159,7 @@ fn write_index_from_head(root: &Path) -> anyhow::Result<()> {
         .context("write git baseline index from HEAD")
 }
 
-fn codex_signature() -> gix::actor::Signature {
+pub(crate) fn codex_signature() -> gix::actor::Signature {
     gix::actor::Signature {
         name: "Codex".into(),
         email: "noreply@openai.com".into(),
@@ -236,7 +236,7 @@ fn head_file_entries(
     Ok(entries)
 }
 
-fn collect_tree_entries(
+pub(crate) fn collect_tree_entries(
     repo: &gix::Repository,
     tree: gix::Tree<'_>,
     prefix: PathBuf,
@@ -313,7 +313,7 @@ fn collect_current_entries(
     Ok(())
 }
 
-fn blob_oid(repo: &gix::Repository, bytes: &[u8]) -> anyhow::Result<ObjectId> {
+pub(crate) fn blob_oid(repo: &gix::Repository, bytes: &[u8]) -> anyhow::Result<ObjectId> {
     gix::objs::compute_hash(repo.object_hash(), gix::objs::Kind::Blob, bytes)
         .context("compute git baseline blob oid")
 }
@@ -459,7 +459,7 @@ fn mode_label(mode: EntryMode) -> &'static str {
 }
 
 #[cfg(unix)]
-fn file_mode(path: &Path, default: EntryKind) -> anyhow::Result<EntryMode> {
+pub(crate) fn file_mode(path: &Path, default: EntryKind) -> anyhow::Result<EntryMode> {
     use std::os::unix::fs::PermissionsExt;
 
     let mode = fs::metadata(path)?.permissions().mode();
@@ -471,7 +471,7 @@ fn file_mode(path: &Path, default: EntryKind) -> anyhow::Result<EntryMode> {
 }
 
 #[cfg(not(unix))]
-fn file_mode(_path: &Path, default: EntryKind) -> anyhow::Result<EntryMode> {
+pub(crate) fn file_mode(_path: &Path, default: EntryKind) -> anyhow::Result<EntryMode> {
     Ok(default.into())
 }
 
@@ -488,14 +488,14 @@ fn os_str_to_bstring(value: &OsStr) -> gix::bstr::BString {
 }
 
 #[cfg(unix)]
-fn path_to_bytes(path: &Path) -> Vec<u8> {
+pub(crate) fn path_to_bytes(path: &Path) -> Vec<u8> {
     use std::os::unix::ffi::OsStrExt;
 
     path.as_os_str().as_bytes().to_vec()
 }
 
 #[cfg(not(unix))]
-fn path_to_bytes(path: &Path) -> Vec<u8> {
+pub(crate) fn path_to_bytes(path: &Path) -> Vec<u8> {
     path.to_string_lossy().as_bytes().to_vec()
 }
 
@@ -512,7 +512,7 @@ fn bstr_to_path(value: &gix::bstr::BStr) -> PathBuf {
     }
 }
 
-fn relative_slash_path(root: &Path, path: &Path) -> anyhow::Result<String> {
+pub(crate) fn relative_slash_path(root: &Path, path: &Path) -> anyhow::Result<String> {
     path.strip_prefix(root)
         .with_context(|| format!("strip {} from {}", root.display(), path.display()))
         .map(path_to_slash_string)
@@ -0,0 +1,677 @@
+use anyhow::Context;
+use anyhow::bail;
+use codex_protocol::ThreadId;
+use gix::hash::ObjectId;
+use gix::objs::Tree;
+use gix::objs::tree::Entry;
+use gix::objs::tree::EntryKind;
+use gix::refs::transaction::PreviousValue;
+use ignore::WalkBuilder;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+use tokio::task;
+
+use crate::baseline::GitBaselineFileEntry;
+use crate::baseline::blob_oid;
+use crate::baseline::codex_signature;
+use crate::baseline::collect_tree_entries;
+use crate::baseline::file_mode;
+use crate::baseline::path_to_bytes;
+use crate::baseline::relative_slash_path;
+
+/// Directory under `CODEX_HOME` holding one checkpoint store per thread.
+pub const CHECKPOINTS_SUBDIR: &str = "checkpoints";
+
+/// Ref holding the newest checkpoint; older checkpoints are its first-parent chain.
+const CHECKPOINT_REF: &str = "refs/codex/checkpoints";
+const TURN_ID_TRAILER: &str = "Turn-Id: ";
+const WORKSPACE_TRAILER: &str = "Workspace: ";
+/// Files larger than this are left out of checkpoints and never touched by restores.
+const MAX_CHECKPOINT_FILE_BYTES: u64 = 10 * 1024 * 1024;
+/// Workspaces with more files than this are not checkpointed.
+const MAX_CHECKPOINT_FILES: usize = 20_000;
+/// Checkpoints kept per store when it is compacted.
+const MAX_CHECKPOINTS: usize = 20;
+/// Checkpoints allowed past [`MAX_CHECKPOINTS`] before compacting, so the rewrite runs once every
+/// few turns rather than on every turn.
+const CHECKPOINT_COMPACTION_SLACK: usize = 10;
+/// File stats recorded by the newest snapshot, stored next to the shadow repository.
+const STAT_CACHE_FILE: &str = "codex-stat-cache.json";
+/// Files modified this close to the previous snapshot are re-read even when their stats match,
+/// because coarse filesystem timestamps cannot tell an edit made right after that snapshot apart.
+const RACY_MTIME_WINDOW: Duration = Duration::from_secs(2);
+
+/// Workspace snapshot taken before a turn started.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceCheckpoint {
+    pub turn_id: String,
+    pub workspace: PathBuf,
+    /// Unix timestamp (seconds) when the snapshot was taken.
+    pub created_at: i64,
+}
+
+/// Files changed by restoring a [`WorkspaceCheckpoint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceCheckpointRestore {
+    pub checkpoint: WorkspaceCheckpoint,
+    /// Workspace-relative paths rewritten with their checkpointed contents.
+    pub restored: Vec<String>,
+    /// Workspace-relative paths deleted because they did not exist at the checkpoint.
+    pub removed: Vec<String>,
+}
+
+/// Shadow repository holding the workspace checkpoints for one thread.
+pub fn workspace_checkpoint_store(codex_home: &Path, thread_id: ThreadId) -> PathBuf {
+    codex_home
+        .join(CHECKPOINTS_SUBDIR)
+        .join(thread_id.to_string())
+}
+
+/// Snapshots `workspace` into the shadow repository at `store` as the checkpoint for `turn_id`.
+///
+/// The store is a bare repository owned by Codex, so the workspace's own `.git` directory and
+/// index are never read or written. Gitignored files, `.git` directories, and files over 10 MiB
+/// are left out of the snapshot. Files whose size and modification time match the previous
+/// snapshot are not read again. Only the 20 most recent checkpoints are guaranteed to be kept;
+/// older ones are dropped once the store grows past its compaction threshold.
+pub async fn create_workspace_checkpoint(
+    store: &Path,
+    turn_id: &str,
+    workspace: &Path,
+) -> anyhow::Result<WorkspaceCheckpoint> {
+    let store = store.to_path_buf();
+    let turn_id = turn_id.to_string();
+    let workspace = workspace.to_path_buf();
+    task::spawn_blocking(move || create_workspace_checkpoint_sync(&store, &turn_id, &workspace))
+        .await?
+}
+
+/// Returns the checkpoints in `store`, newest first.
+pub async fn list_workspace_checkpoints(store: &Path) -> anyhow::Result<Vec<WorkspaceCheckpoint>> {
+    let store = store.to_path_buf();
+    task::spawn_blocking(move || {
+        if !store.exists() {
+            return Ok(Vec::new());
+        }
+        let repo = open_store(&store)?;
+        checkpoint_chain(&repo).map(|chain| {
+            chain
+                .into_iter()
+                .map(|(_, checkpoint)| checkpoint)
+                .collect()
+        })
+    })
+    .await?
+}
+
+/// Deletes the checkpoint store at `store`, if there is one.
+pub async fn remove_workspace_checkpoints(store: &Path) -> anyhow::Result<()> {
+    let store = store.to_path_buf();
+    task::spawn_blocking(move || match fs::remove_dir_all(&store) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(()),
+        Err(err) => {
+            Err(err).with_context(|| format!("remove checkpoint store {}", store.display()))
+        }
+    })
+    .await?
+}
+
+/// Restores the workspace to the checkpoint taken before `turn_id` started.
+///
+/// Files that differ from the checkpoint are rewritten and files created since are removed. The
+/// restored checkpoint and every newer one are dropped, so restoring the newest checkpoint
+/// repeatedly steps back one turn at a time.
+pub async fn restore_workspace_checkpoint(
+    store: &Path,
+    turn_id: &str,
+) -> anyhow::Result<WorkspaceCheckpointRestore> {
+    let store = store.to_path_buf();
+    let turn_id = turn_id.to_string();
+    task::spawn_blocking(move || {
+        restore_workspace_checkpoint_sync(&store, &turn_id, /*apply*/ true)
+    })
+    .await?
+}
+
+/// Reports what [`restore_workspace_checkpoint`] would rewrite and remove for `turn_id`, without
+/// touching the workspace or the store.
+pub async fn preview_workspace_checkpoint_restore(
+    store: &Path,
+    turn_id: &str,
+) -> anyhow::Result<WorkspaceCheckpointRestore> {
+    let store = store.to_path_buf();
+    let turn_id = turn_id.to_string();
+    task::spawn_blocking(move || {
+        restore_workspace_checkpoint_sync(&store, &turn_id, /*apply*/ false)
+    })
+    .await?
+}
+
+fn create_workspace_checkpoint_sync(
+    store: &Path,
+    turn_id: &str,
+    workspace: &Path,
+) -> anyhow::Result<WorkspaceCheckpoint> {
+    let repo = if store.exists() {
+        open_store(store)?
+    } else {
+        fs::create_dir_all(store)
+            .with_context(|| format!("create checkpoint store {}", store.display()))?;
+        gix::init_bare(store)
+            .with_context(|| format!("init checkpoint store {}", store.display()))?
+    };
+
+    let stat_cache = StatCache::load(store, workspace);
+    let (entries, stat_cache) =
+        workspace_entries(&repo, workspace, /*write_blobs*/ true, &stat_cache)?;
+    let tree_id = write_entries_tree(&repo, &entries)?;
+    let parent = checkpoint_head(&repo)?;
+    let signature = codex_signature();
+    let created_at = signature.time.seconds;
+    let mut time = gix::date::parse::TimeBuf::default();
+    let signature_ref = signature.to_ref(&mut time);
+    repo.commit_as(
+        signature_ref,
+        signature_ref,
+        CHECKPOINT_REF,
+        checkpoint_message(turn_id, workspace),
+        tree_id,
+        parent,
+    )
+    .context("commit workspace checkpoint")?;
+    stat_cache.save(store)?;
+
+    let chain = checkpoint_chain(&repo)?;
+    drop(repo);
+    if chain.len() > MAX_CHECKPOINTS + CHECKPOINT_COMPACTION_SLACK {
+        compact_store(store, chain)?;
+    }
+
+    Ok(WorkspaceCheckpoint {
+        turn_id: turn_id.to_string(),
+        workspace: workspace.to_path_buf(),
+        created_at,
+    })
+}
+
+/// Rewrites the store with only its newest [`MAX_CHECKPOINTS`] checkpoints, so objects that only
+/// older checkpoints referenced are deleted from disk.
+fn compact_store(store: &Path, chain: Vec<(ObjectId, WorkspaceCheckpoint)>) -> anyhow::Result<()> {
+    let file_name = store
+        .file_name()
+        .context("checkpoint store has no file name")?
+        .to_string_lossy();
+    let compacted = store.with_file_name(format!("{file_name}.compact"));
+    let stale = store.with_file_name(format!("{file_name}.stale"));
+    for leftover in [&compacted, &stale] {
+        if leftover.exists() {
+            fs::remove_dir_all(leftover)
+                .with_context(|| format!("remove {}", leftover.display()))?;
+        }
+    }
+
+    {
+        let repo = open_store(store)?;
+        fs::create_dir_all(&compacted)
+            .with_context(|| format!("create checkpoint store {}", compacted.display()))?;
+        let compacted_repo = gix::init_bare(&compacted)
+            .with_context(|| format!("init checkpoint store {}", compacted.display()))?;
+        let mut copied_blobs = HashSet::new();
+        let mut parent = None;
+        for (commit_id, checkpoint) in chain.into_iter().take(MAX_CHECKPOINTS).rev() {
+            let tree = repo
+                .find_commit(commit_id)
+                .context("load checkpoint commit")?
+                .tree()
+                .context("load checkpoint tree")?;
+            let mut entries = BTreeMap::new();
+            collect_tree_entries(&repo, tree, PathBuf::new(), &mut entries)?;
+            for entry in entries.values() {
+                if copied_blobs.insert(entry.oid) {
+                    let contents = repo
+                        .find_blob(entry.oid)
+                        .context("load checkpoint blob")?
+                        .take_data();
+                    compacted_repo
+                        .write_blob(contents)
+                        .context("copy checkpoint blob")?;
+                }
+            }
+            let tree_id = write_entries_tree(&compacted_repo, &entries)?;
+            let mut signature = codex_signature();
+            signature.time.seconds = checkpoint.created_at;
+            let mut time = gix::date::parse::TimeBuf::default();
+            let signature_ref = signature.to_ref(&mut time);
+            let commit_id = compacted_repo
+                .commit_as(
+                    signature_ref,
+                    signature_ref,
+                    CHECKPOINT_REF,
+                    checkpoint_message(&checkpoint.turn_id, &checkpoint.workspace),
+                    tree_id,
+                    parent,
+                )
+                .context("copy checkpoint commit")?
+                .detach();
+            parent = Some(commit_id);
+        }
+    }
+    // The stat cache only names blobs of the newest checkpoint, which the copy kept.
+    let stat_cache = store.join(STAT_CACHE_FILE);
+    if stat_cache.exists() {
+        fs::copy(&stat_cache, compacted.join(STAT_CACHE_FILE))
+            .context("copy checkpoint stat cache")?;
+    }
+
+    fs::rename(store, &stale).with_context(|| format!("move aside {}", store.display()))?;
+    fs::rename(&compacted, store)
+        .with_context(|| format!("replace checkpoint store {}", store.display()))?;
+    fs::remove_dir_all(&stale).with_context(|| format!("remove {}", stale.display()))
+}
+
+/// Computes the files restoring `turn_id` changes, and changes them when `apply` is set.
+fn restore_workspace_checkpoint_sync(
+    store: &Path,
+    turn_id: &str,
+    apply: bool,
+) -> anyhow::Result<WorkspaceCheckpointRestore> {
+    if !store.exists() {
+        bail!("no checkpoint exists for turn {turn_id}");
+    }
+    let repo = open_store(store)?;
+    let mut chain = checkpoint_chain(&repo)?
+        .into_iter()
+        .skip_while(|(_, checkpoint)| checkpoint.turn_id != turn_id);
+    let Some((commit_id, checkpoint)) = chain.next() else {
+        bail!("no checkpoint exists for turn {turn_id}");
+    };
+    let parent_id = chain.next().map(|(parent_id, _)| parent_id);
+    let workspace = checkpoint.workspace.clone();
+
+    let tree = repo
+        .find_commit(commit_id)
+        .context("load checkpoint commit")?
+        .tree()
+        .context("load checkpoint tree")?;
+    let mut target = BTreeMap::new();
+    collect_tree_entries(&repo, tree, PathBuf::new(), &mut target)?;
+    let stat_cache = StatCache::load(store, &workspace);
+    let (current, _) =
+        workspace_entries(&repo, &workspace, /*write_blobs*/ false, &stat_cache)?;
+
+    let removed = current
+        .keys()
+        .filter(|path| !target.contains_key(*path))
+        .cloned()
+        .collect::<Vec<_>>();
+    let restored = target
+        .iter()
+        .filter(|(path, entry)| current.get(*path) != Some(*entry))
+        .map(|(path, _)| path.clone())
+        .collect::<Vec<_>>();
+    if !apply {
+        return Ok(WorkspaceCheckpointRestore {
+            checkpoint,
+            restored,
+            removed,
+        });
+    }
+
+    // Remove first so a checkpointed file can replace a directory created since.
+    for path in &removed {
+        remove_workspace_file(&workspace, path)?;
+    }
+    for path in &restored {
+        write_workspace_file(&repo, &workspace, path, &target[path])?;
+    }
+
+    match parent_id {
+        Some(parent_id) => {
+            repo.reference(
+                CHECKPOINT_REF,
+                parent_id,
+                PreviousValue::Any,
+                format!("restore checkpoint for turn {turn_id}"),
+            )
+            .context("move checkpoint ref")?;
+        }
+        None => {
+            repo.find_reference(CHECKPOINT_REF)
+                .context("load checkpoint ref")?
+                .delete()
+                .context("delete checkpoint ref")?;
+        }
+    }
+
+    Ok(WorkspaceCheckpointRestore {
+        checkpoint,
+        restored,
+        removed,
+    })
+}
+
+fn open_store(store: &Path) -> anyhow::Result<gix::Repository> {
+    gix::open(store).with_context(|| format!("open checkpoint store {}", store.display()))
+}
+
+fn checkpoint_head(repo: &gix::Repository) -> anyhow::Result<Option<ObjectId>> {
+    Ok(repo
+        .try_find_reference(CHECKPOINT_REF)
+        .context("load checkpoint ref")?
+        .and_then(|reference| reference.target().try_id().map(ToOwned::to_owned)))
+}
+
+/// Walks the checkpoint history from newest to oldest.
+fn checkpoint_chain(
+    repo: &gix::Repository,
+) -> anyhow::Result<Vec<(ObjectId, WorkspaceCheckpoint)>> {
+    let mut chain = Vec::new();
+    let mut next = checkpoint_head(repo)?;
+    while let Some(commit_id) = next {
+        let commit = repo
+            .find_commit(commit_id)
+            .context("load checkpoint commit")?;
+        let message = commit.message_raw().context("read checkpoint message")?;
+        let created_at = commit.time().context("read checkpoint time")?.seconds;
+        chain.push((
+            commit_id,
+            parse_checkpoint_message(&message.to_string(), created_at)?,
+        ));
+        next = commit.parent_ids().next().map(gix::Id::detach);
+    }
+    Ok(chain)
+}
+
+fn checkpoint_message(turn_id: &str, workspace: &Path) -> String {
+    format!(
+        "Codex checkpoint before turn {turn_id}\n\n{TURN_ID_TRAILER}{turn_id}\n{WORKSPACE_TRAILER}{}\n",
+        workspace.display()
+    )
+}
+
+fn parse_checkpoint_message(message: &str, created_at: i64) -> anyhow::Result<WorkspaceCheckpoint> {
+    let trailer = |prefix: &str| {
+        message
+            .lines()
+            .find_map(|line| line.strip_prefix(prefix))
+            .map(str::to_string)
+    };
+    let turn_id = trailer(TURN_ID_TRAILER).context("checkpoint is missing its turn id")?;
+    let workspace = trailer(WORKSPACE_TRAILER).context("checkpoint is missing its workspace")?;
+    Ok(WorkspaceCheckpoint {
+        turn_id,
+        workspace: PathBuf::from(workspace),
+        created_at,
+    })
+}
+
+/// Size and modification time of the files seen by the newest snapshot, with their blob ids.
+#[derive(Default, Serialize, Deserialize)]
+struct StatCache {
+    workspace: PathBuf,
+    /// Time since the Unix epoch at which the snapshot started walking the workspace.
+    taken_at: Duration,
+    files: HashMap<String, CachedFileStat>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedFileStat {
+    len: u64,
+    modified: Duration,
+    oid: String,
+}
+
+impl StatCache {
+    /// Reads the cache left by the previous snapshot of `workspace`. A missing, unreadable, or
+    /// foreign cache is treated as empty.
+    fn load(store: &Path, workspace: &Path) -> Self {
+        fs::read(store.join(STAT_CACHE_FILE))
+            .ok()
+            .and_then(|bytes| serde_json::from_slice::<StatCache>(&bytes).ok())
+            .filter(|cache| cache.workspace == workspace)
+            .unwrap_or_default()
+    }
+
+    fn save(&self, store: &Path) -> anyhow::Result<()> {
+        let path = store.join(STAT_CACHE_FILE);
+        let bytes = serde_json::to_vec(self).context("serialize checkpoint stat cache")?;
+        fs::write(&path, bytes).with_context(|| format!("write {}", path.display()))
+    }
+
+    /// Returns the blob id recorded for `relative_path` when its stats show it is unchanged.
+    fn unchanged_oid(&self, relative_path: &str, len: u64, modified: Duration) -> Option<ObjectId> {
+        let cached = self.files.get(relative_path)?;
+        if cached.len != len
+            || cached.modified != modified
+            || modified.saturating_add(RACY_MTIME_WINDOW) >= self.taken_at
+        {
+            return None;
+        }
+        ObjectId::from_hex(cached.oid.as_bytes()).ok()
+    }
+}
+
+fn since_epoch(time: SystemTime) -> Option<Duration> {
+    time.duration_since(SystemTime::UNIX_EPOCH).ok()
+}
+
+/// Hashes the files a checkpoint covers, keyed by workspace-relative slash path.
+///
+/// Files that `stat_cache` shows as unchanged reuse their recorded blob id without being read.
+/// Also returns the stat cache describing this walk.
+fn workspace_entries(
+    repo: &gix::Repository,
+    workspace: &Path,
+    write_blobs: bool,
+    stat_cache: &StatCache,
+) -> anyhow::Result<(BTreeMap<String, GitBaselineFileEntry>, StatCache)> {
+    let mut entries = BTreeMap::new();
+    let mut next_stat_cache = StatCache {
+        workspace: workspace.to_path_buf(),
+        taken_at: since_epoch(SystemTime::now()).unwrap_or_default(),
+        files: HashMap::new(),
+    };
+    let walker = WalkBuilder::new(workspace)
+        .hidden(false)
+        .follow_links(false)
+        .require_git(true)
+        .filter_entry(|entry| entry.file_name() != OsStr::new(".git"))
+        .build();
+    for entry in walker {
+        // Unreadable entries are skipped by both snapshots and restores.
+        let Ok(entry) = entry else {
+            continue;
+        };
+        let Some(file_type) = entry.file_type() else {
+            continue;
+        };
+        let path = entry.path();
+        let relative_path = relative_slash_path(workspace, path)?;
+        let mut file_stat = None;
+        let (cached_oid, bytes, mode) = if file_type.is_symlink() {
+            let target =
+                fs::read_link(path).with_context(|| format!("read symlink {}", path.display()))?;
+            (None, path_to_bytes(&target), EntryKind::Link.into())
+        } else if file_type.is_file() {
+            let metadata = entry
+                .metadata()
+                .with_context(|| format!("stat {}", path.display()))?;
+            if metadata.len() > MAX_CHECKPOINT_FILE_BYTES {
+                continue;
+            }
+            file_stat = metadata
+                .modified()
+                .ok()
+                .and_then(since_epoch)
+                .map(|modified| (metadata.len(), modified));
+            let cached_oid = file_stat.and_then(|(len, modified)| {
+                stat_cache.unchanged_oid(&relative_path, len, modified)
+            });
+            let bytes = match cached_oid {
+                Some(_) => Vec::new(),
+                None => fs::read(path).with_context(|| format!("read {}", path.display()))?,
+            };
+            (cached_oid, bytes, file_mode(path, EntryKind::Blob)?)
+        } else {
+            continue;
+        };
+
+        if entries.len() >= MAX_CHECKPOINT_FILES {
+            bail!(
+                "{} has more than {MAX_CHECKPOINT_FILES} files to checkpoint",
+                workspace.display()
+            );
+        }
+        let oid = match cached_oid {
+            Some(oid) => oid,
+            None if write_blobs => repo
+                .write_blob(bytes)
+                .with_context(|| format!("write blob {}", path.display()))?
+                .detach(),
+            None => blob_oid(repo, &bytes)?,
+        };
+        record_file_stat(&mut next_stat_cache, &relative_path, file_stat, oid);
+        entries.insert(relative_path, GitBaselineFileEntry { oid, mode });
+    }
+    Ok((entries, next_stat_cache))
+}
+
+fn record_file_stat(
+    stat_cache: &mut StatCache,
+    relative_path: &str,
+    file_stat: Option<(u64, Duration)>,
+    oid: ObjectId,
+) {
+    if let Some((len, modified)) = file_stat {
+        stat_cache.files.insert(
+            relative_path.to_string(),
+            CachedFileStat {
+                len,
+                modified,
+                oid: oid.to_hex().to_string(),
+            },
+        );
+    }
+}
+
+#[derive(Default)]
+struct TreeNode {
+    files: Vec<Entry>,
+    dirs: BTreeMap<String, TreeNode>,
+}
+
+fn write_entries_tree(
+    repo: &gix::Repository,
+    entries: &BTreeMap<String, GitBaselineFileEntry>,
+) -> anyhow::Result<ObjectId> {
+    let mut root = TreeNode::default();
+    for (path, entry) in entries {
+        let (dirs, file_name) = path.rsplit_once('/').unwrap_or(("", path.as_str()));
+        let mut node = &mut root;
+        for dir in dirs.split('/').filter(|dir| !dir.is_empty()) {
+            node = node.dirs.entry(dir.to_string()).or_default();
+        }
+        node.files.push(Entry {
+            mode: entry.mode,
+            filename: file_name.into(),
+            oid: entry.oid,
+        });
+    }
+    write_tree_node(repo, root)
+}
+
+fn write_tree_node(repo: &gix::Repository, node: TreeNode) -> anyhow::Result<ObjectId> {
+    let mut entries = node.files;
+    for (name, child) in node.dirs {
+        entries.push(Entry {
+            mode: EntryKind::Tree.into(),
+            filename: name.into(),
+            oid: write_tree_node(repo, child)?,
+        });
+    }
+    entries.sort();
+    repo.write_object(&Tree { entries })
+        .context("write checkpoint tree")
+        .map(gix::Id::detach)
+}
+
+fn write_workspace_file(
+    repo: &gix::Repository,
+    workspace: &Path,
+    relative_path: &str,
+    entry: &GitBaselineFileEntry,
+) -> anyhow::Result<()> {
+    let path = workspace.join(relative_path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    if fs::symlink_metadata(&path).is_ok() {
+        fs::remove_file(&path).with_context(|| format!("remove {}", path.display()))?;
+    }
+
+    let contents = repo
+        .find_blob(entry.oid)
+        .with_context(|| format!("load checkpoint content for {relative_path}"))?
+        .take_data();
+    if entry.mode.kind() == EntryKind::Link {
+        return restore_symlink(&contents, &path);
+    }
+    fs::write(&path, contents).with_context(|| format!("write {}", path.display()))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = if entry.mode.kind() == EntryKind::BlobExecutable {
+            0o755
+        } else {
+            0o644
+        };
+        fs::set_permissions(&path, fs::Permissions::from_mode(mode))
+            .with_context(|| format!("chmod {}", path.display()))?;
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn restore_symlink(target: &[u8], path: &Path) -> anyhow::Result<()> {
+    use std::os::unix::ffi::OsStrExt;
+
+    std::os::unix::fs::symlink(OsStr::from_bytes(target), path)
+        .with_context(|| format!("create symlink {}", path.display()))
+}
+
+#[cfg(windows)]
+fn restore_symlink(target: &[u8], path: &Path) -> anyhow::Result<()> {
+    let target = PathBuf::from(String::from_utf8_lossy(target).into_owned());
+    std::os::windows::fs::symlink_file(target, path)
+        .with_context(|| format!("create symlink {}", path.display()))
+}
+
+/// Deletes a file and any directories left empty by the deletion.
+fn remove_workspace_file(workspace: &Path, relative_path: &str) -> anyhow::Result<()> {
+    let path = workspace.join(relative_path);
+    fs::remove_file(&path).with_context(|| format!("remove {}", path.display()))?;
+    let mut dir = path.parent();
+    while let Some(parent) = dir
+        && parent != workspace
+        && fs::remove_dir(parent).is_ok()
+    {
+        dir = parent.parent();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "checkpoint_tests.rs"]
+mod tests;
@@ -0,0 +1,284 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn turn_ids(checkpoints: &[WorkspaceCheckpoint]) -> Vec<&str> {
+    checkpoints
+        .iter()
+        .map(|checkpoint| checkpoint.turn_id.as_str())
+        .collect()
+}
+
+fn git(root: &Path, args: &[&str]) {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args(args)
+        .output()
+        .expect("run git command");
+    assert!(
+        output.status.success(),
+        "git command failed: {args:?}\nstderr:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[tokio::test]
+async fn restore_reverts_edits_and_removes_new_files() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(workspace.join("src")).expect("create workspace");
+    fs::write(workspace.join("README.md"), "original").expect("write readme");
+    fs::write(workspace.join("src/lib.rs"), "fn main() {}").expect("write lib");
+
+    create_workspace_checkpoint(&store, "turn-1", &workspace)
+        .await
+        .expect("create checkpoint");
+
+    fs::write(workspace.join("README.md"), "edited").expect("edit readme");
+    fs::remove_file(workspace.join("src/lib.rs")).expect("delete lib");
+    fs::create_dir_all(workspace.join("generated/nested")).expect("create generated");
+    fs::write(workspace.join("generated/nested/out.txt"), "new").expect("write new file");
+
+    let restore = restore_workspace_checkpoint(&store, "turn-1")
+        .await
+        .expect("restore checkpoint");
+
+    assert_eq!(restore.checkpoint.turn_id, "turn-1");
+    assert_eq!(restore.checkpoint.workspace, workspace);
+    assert_eq!(
+        restore.restored,
+        vec!["README.md".to_string(), "src/lib.rs".to_string()]
+    );
+    assert_eq!(
+        restore.removed,
+        vec!["generated/nested/out.txt".to_string()]
+    );
+    assert_eq!(
+        fs::read_to_string(workspace.join("README.md")).expect("read readme"),
+        "original"
+    );
+    assert_eq!(
+        fs::read_to_string(workspace.join("src/lib.rs")).expect("read lib"),
+        "fn main() {}"
+    );
+    assert!(!workspace.join("generated").exists());
+    assert_eq!(
+        list_workspace_checkpoints(&store)
+            .await
+            .expect("list checkpoints"),
+        Vec::new()
+    );
+}
+
+#[tokio::test]
+async fn preview_reports_changes_without_touching_the_workspace() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    fs::write(workspace.join("README.md"), "original").expect("write readme");
+
+    create_workspace_checkpoint(&store, "turn-1", &workspace)
+        .await
+        .expect("create checkpoint");
+
+    fs::write(workspace.join("README.md"), "edited").expect("edit readme");
+    fs::write(workspace.join("notes.txt"), "new").expect("write new file");
+
+    let preview = preview_workspace_checkpoint_restore(&store, "turn-1")
+        .await
+        .expect("preview restore");
+
+    assert_eq!(preview.restored, vec!["README.md".to_string()]);
+    assert_eq!(preview.removed, vec!["notes.txt".to_string()]);
+    assert_eq!(
+        fs::read_to_string(workspace.join("README.md")).expect("read readme"),
+        "edited"
+    );
+    assert!(workspace.join("notes.txt").exists());
+    assert_eq!(
+        turn_ids(
+            &list_workspace_checkpoints(&store)
+                .await
+                .expect("list checkpoints")
+        ),
+        vec!["turn-1"]
+    );
+    assert_eq!(
+        restore_workspace_checkpoint(&store, "turn-1")
+            .await
+            .expect("restore checkpoint"),
+        preview
+    );
+}
+
+#[tokio::test]
+async fn restore_drops_the_restored_checkpoint_and_newer_ones() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    for (turn_id, contents) in [("turn-1", "one"), ("turn-2", "two"), ("turn-3", "three")] {
+        fs::write(workspace.join("notes.txt"), contents).expect("write notes");
+        create_workspace_checkpoint(&store, turn_id, &workspace)
+            .await
+            .expect("create checkpoint");
+    }
+    fs::write(workspace.join("notes.txt"), "four").expect("write notes");
+
+    let checkpoints = list_workspace_checkpoints(&store)
+        .await
+        .expect("list checkpoints");
+    assert_eq!(turn_ids(&checkpoints), vec!["turn-3", "turn-2", "turn-1"]);
+
+    restore_workspace_checkpoint(&store, "turn-2")
+        .await
+        .expect("restore checkpoint");
+
+    assert_eq!(
+        fs::read_to_string(workspace.join("notes.txt")).expect("read notes"),
+        "two"
+    );
+    let checkpoints = list_workspace_checkpoints(&store)
+        .await
+        .expect("list checkpoints");
+    assert_eq!(turn_ids(&checkpoints), vec!["turn-1"]);
+    let err = restore_workspace_checkpoint(&store, "turn-3")
+        .await
+        .expect_err("dropped checkpoint should not restore");
+    assert!(err.to_string().contains("turn-3"), "{err:#}");
+}
+
+#[tokio::test]
+async fn restore_leaves_git_metadata_and_ignored_files_alone() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    git(&workspace, &["init", "--quiet"]);
+    fs::write(workspace.join(".gitignore"), "*.log\n").expect("write gitignore");
+    fs::write(workspace.join("tracked.txt"), "committed").expect("write tracked");
+    git(&workspace, &["add", "."]);
+    let index_before = fs::read(workspace.join(".git/index")).expect("read index");
+
+    create_workspace_checkpoint(&store, "turn-1", &workspace)
+        .await
+        .expect("create checkpoint");
+    fs::write(workspace.join("tracked.txt"), "edited").expect("edit tracked");
+    fs::write(workspace.join("build.log"), "ignored").expect("write ignored");
+
+    let restore = restore_workspace_checkpoint(&store, "turn-1")
+        .await
+        .expect("restore checkpoint");
+
+    assert_eq!(restore.restored, vec!["tracked.txt".to_string()]);
+    assert_eq!(restore.removed, Vec::<String>::new());
+    assert_eq!(
+        fs::read_to_string(workspace.join("tracked.txt")).expect("read tracked"),
+        "committed"
+    );
+    assert!(workspace.join("build.log").exists());
+    assert_eq!(
+        fs::read(workspace.join(".git/index")).expect("read index"),
+        index_before
+    );
+}
+
+#[tokio::test]
+async fn compaction_keeps_the_newest_checkpoints() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    let turns = MAX_CHECKPOINTS + CHECKPOINT_COMPACTION_SLACK + 1;
+    for turn in 0..turns {
+        fs::write(workspace.join("notes.txt"), format!("turn {turn}")).expect("write notes");
+        create_workspace_checkpoint(&store, &format!("turn-{turn}"), &workspace)
+            .await
+            .expect("create checkpoint");
+    }
+
+    let checkpoints = list_workspace_checkpoints(&store)
+        .await
+        .expect("list checkpoints");
+    let expected = (turns - MAX_CHECKPOINTS..turns)
+        .rev()
+        .map(|turn| format!("turn-{turn}"))
+        .collect::<Vec<_>>();
+    assert_eq!(turn_ids(&checkpoints), expected);
+
+    let oldest = format!("turn-{}", turns - MAX_CHECKPOINTS);
+    restore_workspace_checkpoint(&store, &oldest)
+        .await
+        .expect("restore oldest kept checkpoint");
+    assert_eq!(
+        fs::read_to_string(workspace.join("notes.txt")).expect("read notes"),
+        format!("turn {}", turns - MAX_CHECKPOINTS)
+    );
+}
+
+#[test]
+fn stat_cache_ignores_files_modified_near_the_snapshot() {
+    let oid = ObjectId::empty_blob(gix::hash::Kind::Sha1);
+    let cache = StatCache {
+        workspace: PathBuf::from("/workspace"),
+        taken_at: Duration::from_secs(100),
+        files: HashMap::from([
+            (
+                "old.txt".to_string(),
+                CachedFileStat {
+                    len: 3,
+                    modified: Duration::from_secs(90),
+                    oid: oid.to_hex().to_string(),
+                },
+            ),
+            (
+                "racy.txt".to_string(),
+                CachedFileStat {
+                    len: 3,
+                    modified: Duration::from_secs(99),
+                    oid: oid.to_hex().to_string(),
+                },
+            ),
+        ]),
+    };
+
+    assert_eq!(
+        cache.unchanged_oid("old.txt", /*len*/ 3, Duration::from_secs(90)),
+        Some(oid)
+    );
+    assert_eq!(
+        cache.unchanged_oid("old.txt", /*len*/ 4, Duration::from_secs(90)),
+        None
+    );
+    assert_eq!(
+        cache.unchanged_oid("old.txt", /*len*/ 3, Duration::from_secs(91)),
+        None
+    );
+    assert_eq!(
+        cache.unchanged_oid("racy.txt", /*len*/ 3, Duration::from_secs(99)),
+        None
+    );
+}
+
+#[tokio::test]
+async fn remove_deletes_the_store() {
+    let home = TempDir::new().expect("tempdir");
+    let store = home.path().join("store");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    create_workspace_checkpoint(&store, "turn-1", &workspace)
+        .await
+        .expect("create checkpoint");
+
+    remove_workspace_checkpoints(&store)
+        .await
+        .expect("remove checkpoints");
+    remove_workspace_checkpoints(&store)
+        .await
+        .expect("removing a missing store succeeds");
+
+    assert!(!store.exists());
+}
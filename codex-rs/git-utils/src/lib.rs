@@ -1,11 +1,13 @@
 mod apply;
 mod baseline;
 mod branch;
+mod checkpoint;
 mod errors;
 mod fsmonitor;
 mod info;
 mod operations;
 mod platform;
+mod worktree;
 
 pub use apply::ApplyGitRequest;
 pub use apply::ApplyGitResult;
@@ -20,6 +22,15 @@ pub use baseline::diff_since_latest_init;
 pub use baseline::ensure_git_baseline_repository;
 pub use baseline::reset_git_repository;
 pub use branch::merge_base_with_head;
+pub use checkpoint::CHECKPOINTS_SUBDIR;
+pub use checkpoint::WorkspaceCheckpoint;
+pub use checkpoint::WorkspaceCheckpointRestore;
+pub use checkpoint::create_workspace_checkpoint;
+pub use checkpoint::list_workspace_checkpoints;
+pub use checkpoint::preview_workspace_checkpoint_restore;
+pub use checkpoint::remove_workspace_checkpoints;
+pub use checkpoint::restore_workspace_checkpoint;
+pub use checkpoint::workspace_checkpoint_store;
 pub use codex_protocol::protocol::GitSha;
 pub use errors::GitToolingError;
 pub use fsmonitor::FsmonitorOverride;
@@ -43,3 +54,13 @@ pub use info::local_git_branches;
 pub use info::recent_commits;
 pub use info::resolve_root_git_project_for_trust;
 pub use platform::create_symlink;
+pub use worktree::ISOLATED_WORKTREES_SUBDIR;
+pub use worktree::IsolatedWorktree;
+pub use worktree::IsolatedWorktreeKind;
+pub use worktree::create_isolated_worktree;
+pub use worktree::discard_isolated_worktree_changes;
+pub use worktree::find_isolated_worktree;
+pub use worktree::isolated_worktree_diff;
+pub use worktree::merge_isolated_worktree;
+pub use worktree::remove_isolated_worktree;
+pub use worktree::select_diff_hunks;
@@ -0,0 +1,587 @@
+//! Isolated checkouts that let a sub-agent edit files without touching the source workspace.
+//!
+//! A checkout is a detached `git worktree` of the source repository, or a copy of the source
+//! directory with a repository of its own when the source is not tracked by git. Either way it
+//! starts with the source's uncommitted and untracked files. The per-worktree ref [`BASE_REF`]
+//! records the state that has already been merged or discarded, so [`isolated_worktree_diff`]
+//! only reports new work. Snapshots go through a temporary index, so the checkout's own `HEAD`
+//! and index stay under the agent's control.
+
+use anyhow::Context;
+use anyhow::bail;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fs;
+use std::io::ErrorKind;
+use std::io::Write;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use tokio::task;
+use walkdir::WalkDir;
+
+use crate::GitToolingError;
+use crate::operations::resolve_head;
+use crate::operations::resolve_repository_root;
+use crate::operations::run_git_for_status;
+use crate::operations::run_git_for_stdout;
+use crate::operations::run_git_for_untrimmed_stdout;
+use crate::platform::create_symlink;
+
+/// Directory under `CODEX_HOME` that holds isolated worktrees.
+pub const ISOLATED_WORKTREES_SUBDIR: &str = "worktrees";
+const METADATA_FILE: &str = "worktree.json";
+const CHECKOUT_DIR: &str = "checkout";
+/// Commit holding the checkout state that was last merged back or discarded.
+const BASE_REF: &str = "refs/worktree/codex-base";
+const BASE_COMMIT_MESSAGE: &str = "Codex isolated worktree base";
+/// Directories with more files than this are not copied.
+const MAX_COPY_FILES: usize = 20_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IsolatedWorktreeKind {
+    /// A detached `git worktree` that shares the source repository's objects.
+    GitWorktree,
+    /// A copy of a directory that is not inside a git repository.
+    Copy,
+}
+
+/// A checkout created by [`create_isolated_worktree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolatedWorktree {
+    /// Directory holding the checkout and its metadata.
+    pub root: PathBuf,
+    /// Repository root, or copied directory, that changes are merged back into.
+    pub source_root: PathBuf,
+    pub kind: IsolatedWorktreeKind,
+}
+
+#[derive(Serialize, Deserialize)]
+struct WorktreeMetadata {
+    source_root: PathBuf,
+    kind: IsolatedWorktreeKind,
+}
+
+impl IsolatedWorktree {
+    /// Directory the agent works in.
+    pub fn checkout(&self) -> PathBuf {
+        self.root.join(CHECKOUT_DIR)
+    }
+
+    /// Maps a path inside the source workspace to the same path inside the checkout.
+    pub fn checkout_path(&self, source_path: &Path) -> Option<PathBuf> {
+        let source_path = fs::canonicalize(source_path).unwrap_or_else(|_| source_path.into());
+        source_path
+            .strip_prefix(&self.source_root)
+            .ok()
+            .map(|relative| self.checkout().join(relative))
+    }
+}
+
+/// Creates an isolated checkout of the workspace containing `source` under `root`.
+///
+/// `root` must not exist yet. When `source` is inside a git repository the checkout covers the
+/// whole repository; otherwise it is a copy of `source` itself.
+pub async fn create_isolated_worktree(
+    root: &Path,
+    source: &Path,
+) -> anyhow::Result<IsolatedWorktree> {
+    let root = root.to_path_buf();
+    let source = source.to_path_buf();
+    task::spawn_blocking(move || create_isolated_worktree_sync(&root, &source)).await?
+}
+
+/// Returns the isolated worktree whose checkout contains `path`, if any.
+///
+/// `worktrees_dir` is the directory the worktree roots were created in.
+pub async fn find_isolated_worktree(
+    worktrees_dir: &Path,
+    path: &Path,
+) -> anyhow::Result<Option<IsolatedWorktree>> {
+    let Ok(relative) = path.strip_prefix(worktrees_dir) else {
+        return Ok(None);
+    };
+    let mut components = relative.components();
+    let (Some(Component::Normal(id)), Some(Component::Normal(dir))) =
+        (components.next(), components.next())
+    else {
+        return Ok(None);
+    };
+    if dir != CHECKOUT_DIR {
+        return Ok(None);
+    }
+    let root = worktrees_dir.join(id);
+    task::spawn_blocking(move || {
+        if !root.join(METADATA_FILE).exists() {
+            return Ok(None);
+        }
+        read_metadata(&root).map(Some)
+    })
+    .await?
+}
+
+/// Returns the checkout's changes since it was created or last merged or discarded, as a
+/// `git diff` relative to [`IsolatedWorktree::source_root`].
+pub async fn isolated_worktree_diff(worktree: &IsolatedWorktree) -> anyhow::Result<String> {
+    let checkout = worktree.checkout();
+    task::spawn_blocking(move || {
+        let tree = snapshot_tree(&checkout)?;
+        diff_against_base(&checkout, &tree)
+    })
+    .await?
+}
+
+/// Applies the checkout's pending changes to the source workspace and returns the applied diff.
+///
+/// `selection` narrows the changes as described for [`select_diff_hunks`]; `None` merges
+/// everything. Either way the changes are no longer pending afterwards, so changes that were
+/// left out are not offered again. Nothing is applied when the patch does not apply cleanly.
+pub async fn merge_isolated_worktree(
+    worktree: &IsolatedWorktree,
+    selection: Option<HashMap<String, Option<Vec<usize>>>>,
+) -> anyhow::Result<String> {
+    let worktree = worktree.clone();
+    task::spawn_blocking(move || {
+        let checkout = worktree.checkout();
+        let tree = snapshot_tree(&checkout)?;
+        let diff = diff_against_base(&checkout, &tree)?;
+        let diff = match selection {
+            Some(selection) => select_diff_hunks(&diff, &selection),
+            None => diff,
+        };
+        if !diff.is_empty() {
+            apply_patch(&worktree.source_root, &diff)?;
+        }
+        record_base(&checkout, &tree)?;
+        Ok(diff)
+    })
+    .await?
+}
+
+/// Reverts the checkout to the state it had when it was created or last merged or discarded.
+pub async fn discard_isolated_worktree_changes(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let checkout = worktree.checkout();
+    task::spawn_blocking(move || discard_changes_sync(&checkout)).await?
+}
+
+/// Deletes the checkout and unregisters it from the source repository.
+pub async fn remove_isolated_worktree(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let worktree = worktree.clone();
+    task::spawn_blocking(move || remove_isolated_worktree_sync(&worktree)).await?
+}
+
+/// Keeps only the selected files and hunks of a diff returned by [`isolated_worktree_diff`].
+///
+/// `selection` is keyed by the file path shown in the diff. A `None` entry keeps the whole file,
+/// and a list of zero-based indices keeps only those `@@` hunks. Files that are not listed are
+/// dropped.
+pub fn select_diff_hunks(diff: &str, selection: &HashMap<String, Option<Vec<usize>>>) -> String {
+    let mut selected = String::new();
+    for file in split_diff_files(diff) {
+        match selection.get(&file.path) {
+            None => {}
+            Some(None) => selected.push_str(file.text),
+            Some(Some(indices)) => {
+                let hunks = file
+                    .hunks
+                    .iter()
+                    .enumerate()
+                    .filter(|(index, _)| indices.contains(index))
+                    .map(|(_, hunk)| *hunk)
+                    .collect::<Vec<_>>();
+                if hunks.is_empty() {
+                    continue;
+                }
+                selected.push_str(file.header);
+                for hunk in hunks {
+                    selected.push_str(hunk);
+                }
+            }
+        }
+    }
+    selected
+}
+
+fn create_isolated_worktree_sync(root: &Path, source: &Path) -> anyhow::Result<IsolatedWorktree> {
+    if root.exists() {
+        bail!("isolated worktree {} already exists", root.display());
+    }
+    let worktree = match resolve_repository_root(source) {
+        Ok(repo_root) => IsolatedWorktree {
+            root: root.to_path_buf(),
+            source_root: fs::canonicalize(&repo_root).unwrap_or(repo_root),
+            kind: IsolatedWorktreeKind::GitWorktree,
+        },
+        Err(GitToolingError::GitCommand { .. }) => IsolatedWorktree {
+            root: root.to_path_buf(),
+            source_root: fs::canonicalize(source)
+                .with_context(|| format!("resolve {}", source.display()))?,
+            kind: IsolatedWorktreeKind::Copy,
+        },
+        Err(err) => return Err(err.into()),
+    };
+    fs::create_dir_all(root).with_context(|| format!("create {}", root.display()))?;
+    let populated = write_metadata(&worktree).and_then(|()| match worktree.kind {
+        IsolatedWorktreeKind::GitWorktree => populate_git_worktree(&worktree),
+        IsolatedWorktreeKind::Copy => populate_copy(&worktree),
+    });
+    if let Err(err) = populated {
+        let _ = remove_isolated_worktree_sync(&worktree);
+        return Err(err);
+    }
+    Ok(worktree)
+}
+
+fn populate_git_worktree(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let source = worktree.source_root.as_path();
+    let checkout = worktree.checkout();
+    run_git_for_status(
+        source,
+        [
+            OsString::from("worktree"),
+            OsString::from("add"),
+            OsString::from("--detach"),
+            OsString::from("--quiet"),
+            checkout.clone().into_os_string(),
+            OsString::from("HEAD"),
+        ],
+        /*env*/ None,
+    )?;
+
+    // Bring over the uncommitted state so the agent starts from what the user sees.
+    let tracked_changes = run_git_for_untrimmed_stdout(
+        source,
+        [
+            "diff",
+            "--binary",
+            "--full-index",
+            "--no-color",
+            "--no-ext-diff",
+            "HEAD",
+        ],
+        /*env*/ None,
+    )?;
+    if !tracked_changes.is_empty() {
+        apply_patch(&checkout, &tracked_changes)?;
+    }
+    let untracked = run_git_for_untrimmed_stdout(
+        source,
+        ["ls-files", "--others", "--exclude-standard", "-z"],
+        /*env*/ None,
+    )?;
+    for relative in untracked.split('\0').filter(|path| !path.is_empty()) {
+        copy_entry(&source.join(relative), &checkout.join(relative))?;
+    }
+
+    let tree = snapshot_tree(&checkout)?;
+    record_base(&checkout, &tree)
+}
+
+fn populate_copy(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let checkout = worktree.checkout();
+    let mut copied = 0usize;
+    let entries = WalkDir::new(&worktree.source_root)
+        .follow_links(false)
+        .into_iter()
+        // The worktrees directory may itself live inside the copied directory.
+        .filter_entry(|entry| entry.path() != worktree.root);
+    for entry in entries {
+        let entry = entry?;
+        let relative = entry.path().strip_prefix(&worktree.source_root)?;
+        let destination = checkout.join(relative);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&destination)
+                .with_context(|| format!("create {}", destination.display()))?;
+            continue;
+        }
+        copied += 1;
+        if copied > MAX_COPY_FILES {
+            bail!(
+                "{} has more than {MAX_COPY_FILES} files and is too large to copy",
+                worktree.source_root.display()
+            );
+        }
+        copy_entry(entry.path(), &destination)?;
+    }
+    run_git_for_status(&checkout, ["init", "--quiet"], /*env*/ None)?;
+    let tree = snapshot_tree(&checkout)?;
+    record_base(&checkout, &tree)
+}
+
+fn copy_entry(source: &Path, destination: &Path) -> anyhow::Result<()> {
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent).with_context(|| format!("create {}", parent.display()))?;
+    }
+    let metadata =
+        fs::symlink_metadata(source).with_context(|| format!("stat {}", source.display()))?;
+    if metadata.file_type().is_symlink() {
+        let target =
+            fs::read_link(source).with_context(|| format!("read link {}", source.display()))?;
+        create_symlink(source, &target, destination)?;
+    } else {
+        fs::copy(source, destination)
+            .with_context(|| format!("copy {} to {}", source.display(), destination.display()))?;
+    }
+    Ok(())
+}
+
+/// Writes the checkout's current files, minus ignored ones, as a tree object.
+fn snapshot_tree(checkout: &Path) -> anyhow::Result<String> {
+    let index_dir = tempfile::tempdir().context("create snapshot index directory")?;
+    let env = [(
+        OsString::from("GIT_INDEX_FILE"),
+        index_dir.path().join("index").into_os_string(),
+    )];
+    if base_commit(checkout)?.is_some() {
+        // Starting from the base keeps `git add` from rehashing unchanged files.
+        run_git_for_status(checkout, ["read-tree", BASE_REF], Some(&env))?;
+    }
+    run_git_for_status(checkout, ["add", "--all", "."], Some(&env))?;
+    Ok(run_git_for_stdout(checkout, ["write-tree"], Some(&env))?)
+}
+
+fn base_commit(checkout: &Path) -> anyhow::Result<Option<String>> {
+    match run_git_for_stdout(
+        checkout,
+        [
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            "refs/worktree/codex-base^{commit}",
+        ],
+        /*env*/ None,
+    ) {
+        Ok(commit) => Ok(Some(commit)),
+        Err(GitToolingError::GitCommand { .. }) => Ok(None),
+        Err(err) => Err(err.into()),
+    }
+}
+
+fn record_base(checkout: &Path, tree: &str) -> anyhow::Result<()> {
+    let parent = match base_commit(checkout)? {
+        Some(base) => Some(base),
+        None => resolve_head(checkout)?,
+    };
+    let mut args = vec![
+        "commit-tree".to_string(),
+        tree.to_string(),
+        "-m".to_string(),
+        BASE_COMMIT_MESSAGE.to_string(),
+    ];
+    if let Some(parent) = parent {
+        args.push("-p".to_string());
+        args.push(parent);
+    }
+    let env = [
+        ("GIT_AUTHOR_NAME", "Codex"),
+        ("GIT_AUTHOR_EMAIL", "noreply@openai.com"),
+        ("GIT_COMMITTER_NAME", "Codex"),
+        ("GIT_COMMITTER_EMAIL", "noreply@openai.com"),
+    ]
+    .map(|(key, value)| (OsString::from(key), OsString::from(value)));
+    let commit = run_git_for_stdout(checkout, args, Some(&env))?;
+    run_git_for_status(checkout, ["update-ref", BASE_REF, commit.as_str()], None)?;
+    Ok(())
+}
+
+fn diff_against_base(checkout: &Path, tree: &str) -> anyhow::Result<String> {
+    Ok(run_git_for_untrimmed_stdout(
+        checkout,
+        [
+            "-c",
+            "core.quotePath=false",
+            "diff",
+            "--binary",
+            "--full-index",
+            "--no-renames",
+            "--no-color",
+            "--no-ext-diff",
+            "--no-textconv",
+            BASE_REF,
+            tree,
+        ],
+        /*env*/ None,
+    )?)
+}
+
+fn apply_patch(dir: &Path, patch: &str) -> anyhow::Result<()> {
+    let mut patch_file = tempfile::NamedTempFile::new().context("create patch file")?;
+    patch_file
+        .write_all(patch.as_bytes())
+        .context("write patch file")?;
+    run_git_for_status(
+        dir,
+        [
+            OsString::from("apply"),
+            OsString::from("--whitespace=nowarn"),
+            patch_file.path().as_os_str().to_owned(),
+        ],
+        /*env*/ None,
+    )?;
+    Ok(())
+}
+
+fn discard_changes_sync(checkout: &Path) -> anyhow::Result<()> {
+    let Some(base) = base_commit(checkout)? else {
+        bail!("{} has no isolated worktree base", checkout.display());
+    };
+    let tree = snapshot_tree(checkout)?;
+    let added = run_git_for_untrimmed_stdout(
+        checkout,
+        [
+            "diff",
+            "--name-only",
+            "-z",
+            "--no-renames",
+            "--diff-filter=A",
+            base.as_str(),
+            tree.as_str(),
+        ],
+        /*env*/ None,
+    )?;
+    for relative in added.split('\0').filter(|path| !path.is_empty()) {
+        let path = checkout.join(relative);
+        match fs::remove_file(&path) {
+            Ok(()) => {}
+            Err(err) if err.kind() == ErrorKind::NotFound => {}
+            Err(err) => return Err(err).with_context(|| format!("remove {}", path.display())),
+        }
+    }
+
+    let index_dir = tempfile::tempdir().context("create restore index directory")?;
+    let env = [(
+        OsString::from("GIT_INDEX_FILE"),
+        index_dir.path().join("index").into_os_string(),
+    )];
+    run_git_for_status(checkout, ["read-tree", BASE_REF], Some(&env))?;
+    run_git_for_status(checkout, ["checkout-index", "--all", "--force"], Some(&env))?;
+    Ok(())
+}
+
+fn remove_isolated_worktree_sync(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let checkout = worktree.checkout();
+    let is_git_worktree = worktree.kind == IsolatedWorktreeKind::GitWorktree;
+    if is_git_worktree && checkout.exists() {
+        // Falls back to deleting the directory when the source repository is gone.
+        let _ = run_git_for_status(
+            &worktree.source_root,
+            [
+                OsString::from("worktree"),
+                OsString::from("remove"),
+                OsString::from("--force"),
+                checkout.into_os_string(),
+            ],
+            /*env*/ None,
+        );
+    }
+    match fs::remove_dir_all(&worktree.root) {
+        Ok(()) => {}
+        Err(err) if err.kind() == ErrorKind::NotFound => {}
+        Err(err) => {
+            return Err(err).with_context(|| format!("remove {}", worktree.root.display()));
+        }
+    }
+    if is_git_worktree {
+        let _ = run_git_for_status(&worktree.source_root, ["worktree", "prune"], None);
+    }
+    Ok(())
+}
+
+fn write_metadata(worktree: &IsolatedWorktree) -> anyhow::Result<()> {
+    let metadata = WorktreeMetadata {
+        source_root: worktree.source_root.clone(),
+        kind: worktree.kind,
+    };
+    let path = worktree.root.join(METADATA_FILE);
+    fs::write(&path, serde_json::to_vec_pretty(&metadata)?)
+        .with_context(|| format!("write {}", path.display()))
+}
+
+fn read_metadata(root: &Path) -> anyhow::Result<IsolatedWorktree> {
+    let path = root.join(METADATA_FILE);
+    let contents = fs::read(&path).with_context(|| format!("read {}", path.display()))?;
+    let metadata: WorktreeMetadata =
+        serde_json::from_slice(&contents).with_context(|| format!("parse {}", path.display()))?;
+    Ok(IsolatedWorktree {
+        root: root.to_path_buf(),
+        source_root: metadata.source_root,
+        kind: metadata.kind,
+    })
+}
+
+struct DiffFile<'a> {
+    path: String,
+    /// The whole file section, header included.
+    text: &'a str,
+    /// Everything before the first `@@` hunk.
+    header: &'a str,
+    hunks: Vec<&'a str>,
+}
+
+fn split_diff_files(diff: &str) -> Vec<DiffFile<'_>> {
+    // Byte offsets of each file start and each hunk start, in order.
+    let mut files: Vec<(usize, Vec<usize>)> = Vec::new();
+    let mut offset = 0;
+    for line in diff.split_inclusive('\n') {
+        if line.starts_with("diff --git ") {
+            files.push((offset, Vec::new()));
+        } else if line.starts_with("@@ ")
+            && let Some((_, hunk_starts)) = files.last_mut()
+        {
+            hunk_starts.push(offset);
+        }
+        offset += line.len();
+    }
+
+    let mut parsed = Vec::with_capacity(files.len());
+    for (index, (start, hunk_starts)) in files.iter().enumerate() {
+        let end = files.get(index + 1).map_or(diff.len(), |(next, _)| *next);
+        let text = &diff[*start..end];
+        let header_end = hunk_starts.first().copied().unwrap_or(end);
+        let header = &diff[*start..header_end];
+        let hunks = hunk_starts
+            .iter()
+            .enumerate()
+            .map(|(hunk_index, hunk_start)| {
+                let hunk_end = hunk_starts.get(hunk_index + 1).copied().unwrap_or(end);
+                &diff[*hunk_start..hunk_end]
+            })
+            .collect();
+        parsed.push(DiffFile {
+            path: diff_file_path(header),
+            text,
+            header,
+            hunks,
+        });
+    }
+    parsed
+}
+
+fn diff_file_path(header: &str) -> String {
+    let mut old_path = None;
+    for line in header.lines() {
+        if let Some(path) = line.strip_prefix("+++ b/") {
+            return path.trim_end_matches('\t').to_string();
+        }
+        if let Some(path) = line.strip_prefix("--- a/") {
+            old_path = Some(path.trim_end_matches('\t').to_string());
+        }
+    }
+    if let Some(path) = old_path {
+        return path;
+    }
+    // Binary and mode-only changes have no `---`/`+++` lines.
+    header
+        .lines()
+        .next()
+        .and_then(|line| line.rsplit_once(" b/"))
+        .map(|(_, path)| path.to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+#[path = "worktree_tests.rs"]
+mod tests;
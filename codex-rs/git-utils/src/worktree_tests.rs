@@ -0,0 +1,218 @@
+use super::*;
+use pretty_assertions::assert_eq;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(root: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .current_dir(root)
+        .args([
+            "-c",
+            "user.name=Tester",
+            "-c",
+            "user.email=test@example.com",
+        ])
+        .args(args)
+        .output()
+        .expect("run git command");
+    assert!(
+        output.status.success(),
+        "git command failed: {args:?}\nstderr:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("utf8 stdout")
+}
+
+fn init_repo(root: &Path) {
+    fs::create_dir_all(root.join("src")).expect("create repo");
+    git(root, &["init", "--quiet"]);
+    fs::write(root.join("README.md"), "one\ntwo\nthree\n").expect("write readme");
+    let lib = (1..=12)
+        .map(|line| format!("line {line}\n"))
+        .collect::<String>();
+    fs::write(root.join("src/lib.rs"), lib).expect("write lib");
+    git(root, &["add", "."]);
+    git(root, &["commit", "--quiet", "-m", "initial"]);
+}
+
+#[tokio::test]
+async fn git_worktree_starts_from_uncommitted_state_and_merges_selected_hunks() {
+    let home = TempDir::new().expect("tempdir");
+    let repo = home.path().join("repo");
+    init_repo(&repo);
+    fs::write(repo.join("README.md"), "one\ntwo\nthree\nfour\n").expect("edit readme");
+    fs::write(repo.join("notes.txt"), "untracked\n").expect("write untracked");
+    let worktrees = home.path().join(ISOLATED_WORKTREES_SUBDIR);
+
+    let worktree = create_isolated_worktree(&worktrees.join("child"), &repo.join("src"))
+        .await
+        .expect("create worktree");
+
+    assert_eq!(worktree.kind, IsolatedWorktreeKind::GitWorktree);
+    let checkout = worktree.checkout();
+    assert_eq!(
+        worktree.checkout_path(&repo.join("src")),
+        Some(checkout.join("src"))
+    );
+    assert_eq!(
+        fs::read_to_string(checkout.join("README.md")).expect("read readme"),
+        "one\ntwo\nthree\nfour\n"
+    );
+    assert_eq!(
+        fs::read_to_string(checkout.join("notes.txt")).expect("read notes"),
+        "untracked\n"
+    );
+    assert_eq!(
+        isolated_worktree_diff(&worktree).await.expect("diff"),
+        String::new()
+    );
+    assert_eq!(
+        find_isolated_worktree(&worktrees, &checkout.join("src"))
+            .await
+            .expect("find worktree"),
+        Some(worktree.clone())
+    );
+
+    let lib = fs::read_to_string(checkout.join("src/lib.rs")).expect("read lib");
+    fs::write(
+        checkout.join("src/lib.rs"),
+        lib.replace("line 2\n", "line two\n")
+            .replace("line 11\n", "line eleven\n"),
+    )
+    .expect("edit lib");
+    fs::write(checkout.join("added.txt"), "new\n").expect("write added");
+
+    let diff = isolated_worktree_diff(&worktree).await.expect("diff");
+    assert!(diff.contains("+++ b/added.txt"), "{diff}");
+    assert!(diff.contains("+line two"), "{diff}");
+    assert!(diff.contains("+line eleven"), "{diff}");
+
+    let selection = HashMap::from([("src/lib.rs".to_string(), Some(vec![1]))]);
+    let merged = merge_isolated_worktree(&worktree, Some(selection))
+        .await
+        .expect("merge");
+
+    assert!(!merged.contains("added.txt"), "{merged}");
+    let merged_lib = fs::read_to_string(repo.join("src/lib.rs")).expect("read merged lib");
+    assert!(merged_lib.contains("line eleven\n"));
+    assert!(merged_lib.contains("line 2\n"));
+    assert!(!repo.join("added.txt").exists());
+    assert_eq!(
+        isolated_worktree_diff(&worktree).await.expect("diff"),
+        String::new()
+    );
+}
+
+#[tokio::test]
+async fn discard_restores_base_and_remove_unregisters_worktree() {
+    let home = TempDir::new().expect("tempdir");
+    let repo = home.path().join("repo");
+    init_repo(&repo);
+    let worktrees = home.path().join(ISOLATED_WORKTREES_SUBDIR);
+    let worktree = create_isolated_worktree(&worktrees.join("child"), &repo)
+        .await
+        .expect("create worktree");
+    let checkout = worktree.checkout();
+
+    fs::write(checkout.join("README.md"), "changed\n").expect("edit readme");
+    fs::create_dir_all(checkout.join("generated")).expect("create generated");
+    fs::write(checkout.join("generated/out.txt"), "new\n").expect("write generated");
+
+    discard_isolated_worktree_changes(&worktree)
+        .await
+        .expect("discard");
+
+    assert_eq!(
+        fs::read_to_string(checkout.join("README.md")).expect("read readme"),
+        "one\ntwo\nthree\n"
+    );
+    assert!(!checkout.join("generated/out.txt").exists());
+    assert_eq!(
+        isolated_worktree_diff(&worktree).await.expect("diff"),
+        String::new()
+    );
+
+    remove_isolated_worktree(&worktree).await.expect("remove");
+
+    assert!(!worktree.root.exists());
+    let listed = git(&repo, &["worktree", "list", "--porcelain"]);
+    assert!(!listed.contains("child"), "{listed}");
+}
+
+#[tokio::test]
+async fn copy_of_plain_directory_merges_back() {
+    let home = TempDir::new().expect("tempdir");
+    let workspace = home.path().join("workspace");
+    fs::create_dir_all(&workspace).expect("create workspace");
+    fs::write(workspace.join("draft.txt"), "draft\n").expect("write draft");
+    let worktrees = home.path().join(ISOLATED_WORKTREES_SUBDIR);
+
+    let worktree = create_isolated_worktree(&worktrees.join("child"), &workspace)
+        .await
+        .expect("create worktree");
+
+    assert_eq!(worktree.kind, IsolatedWorktreeKind::Copy);
+    fs::write(worktree.checkout().join("draft.txt"), "final\n").expect("edit draft");
+    fs::write(worktree.checkout().join("extra.txt"), "extra\n").expect("write extra");
+
+    merge_isolated_worktree(&worktree, /*selection*/ None)
+        .await
+        .expect("merge");
+
+    assert_eq!(
+        fs::read_to_string(workspace.join("draft.txt")).expect("read draft"),
+        "final\n"
+    );
+    assert_eq!(
+        fs::read_to_string(workspace.join("extra.txt")).expect("read extra"),
+        "extra\n"
+    );
+    assert!(!workspace.join(".git").exists());
+}
+
+#[test]
+fn select_diff_hunks_keeps_whole_files_and_listed_hunks() {
+    let diff = "diff --git a/a.txt b/a.txt\n\
+                index 1..2 100644\n\
+                --- a/a.txt\n\
+                +++ b/a.txt\n\
+                @@ -1 +1 @@\n\
+                -a\n\
+                +A\n\
+                @@ -9 +9 @@\n\
+                -i\n\
+                +I\n\
+                diff --git a/gone.txt b/gone.txt\n\
+                deleted file mode 100644\n\
+                --- a/gone.txt\n\
+                +++ /dev/null\n\
+                @@ -1 +0,0 @@\n\
+                -gone\n\
+                diff --git a/skip.txt b/skip.txt\n\
+                --- a/skip.txt\n\
+                +++ b/skip.txt\n\
+                @@ -1 +1 @@\n\
+                -s\n\
+                +S\n";
+    let selection = HashMap::from([
+        ("a.txt".to_string(), Some(vec![1])),
+        ("gone.txt".to_string(), None),
+    ]);
+
+    assert_eq!(
+        select_diff_hunks(diff, &selection),
+        "diff --git a/a.txt b/a.txt\n\
+         index 1..2 100644\n\
+         --- a/a.txt\n\
+         +++ b/a.txt\n\
+         @@ -9 +9 @@\n\
+         -i\n\
+         +I\n\
+         diff --git a/gone.txt b/gone.txt\n\
+         deleted file mode 100644\n\
+         --- a/gone.txt\n\
+         +++ /dev/null\n\
+         @@ -1 +0,0 @@\n\
+         -gone\n"
+    );
+}
@@ -62,7 +62,12 @@ mod tests {
                 pre_tool_use: vec![MatcherGroup {
                     matcher: None,
                     hooks: vec![
-                        HookHandlerConfig::Prompt {},
+                        HookHandlerConfig::Prompt {
+                            prompt: "check".to_string(),
+                            model: None,
+                            timeout_sec: None,
+                            status_message: None,
+                        },
                         HookHandlerConfig::Command {
                             command: "echo hi".to_string(),
                             command_windows: None,
@@ -75,7 +80,12 @@ mod tests {
                 }],
                 session_start: vec![MatcherGroup {
                     matcher: None,
-                    hooks: vec![HookHandlerConfig::Agent {}],
+                    hooks: vec![HookHandlerConfig::Agent {
+                        prompt: "verify".to_string(),
+                        model: None,
+                        timeout_sec: None,
+                        status_message: None,
+                    }],
                 }],
                 ..Default::default()
             },
@@ -0,0 +1,196 @@
+use std::future::Future;
+use std::path::Path;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use codex_protocol::ThreadId;
+use tracing::Span;
+
+use super::ConfiguredHandler;
+use super::command_runner::CommandRunResult;
+use super::dispatcher::hook_event_name_label;
+use super::dispatcher::hook_execution_mode_label;
+use super::dispatcher::hook_handler_type_label;
+use super::dispatcher::hook_scope_label;
+use super::dispatcher::hook_source_label;
+use super::dispatcher::scope_for_event;
+use super::prompt_runner::PromptHookDecision;
+use super::prompt_runner::command_output_for_decision;
+use super::prompt_runner::model_run_result;
+use super::prompt_runner::parse_decision;
+use super::prompt_runner::prompt_hook_instructions;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
+use codex_protocol::protocol::HookHandlerType;
+
+pub(crate) const AGENT_HOOK_DEFAULT_TIMEOUT_SEC: u64 = 300;
+
+/// Events whose outcome an `agent` hook may decide.
+pub(crate) const AGENT_HOOK_EVENTS: [HookEventName; 2] =
+    [HookEventName::Stop, HookEventName::PostToolUse];
+
+/// One sub-agent run requested by an `agent` hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentHookRequest {
+    pub event_name: HookEventName,
+    /// Model slug configured on the hook, if any. Evaluators fall back to the
+    /// session model when unset.
+    pub model: Option<String>,
+    /// Initial user message for the sub-agent: the decision contract, the
+    /// configured task, and the hook event payload.
+    pub input: String,
+    /// Evaluators must stop the sub-agent and return an error once this
+    /// elapses.
+    pub timeout: Duration,
+    pub cwd: PathBuf,
+}
+
+/// Result of an `agent` hook sub-agent run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentHookResponse {
+    /// Thread id of the spawned sub-agent, when spawning succeeded.
+    pub thread_id: Option<ThreadId>,
+    /// Final sub-agent message, or why no verdict was produced.
+    pub result: Result<String, String>,
+}
+
+pub type AgentHookEvaluatorFuture<'a> =
+    Pin<Box<dyn Future<Output = AgentHookResponse> + Send + 'a>>;
+
+/// Runs `agent` hook tasks in a short-lived, read-only sub-agent.
+///
+/// The hooks crate owns the decision contract and parsing; callers own
+/// spawning, sandboxing, and tearing down the sub-agent.
+pub trait AgentHookEvaluator: Send + Sync + 'static {
+    fn evaluate(&self, request: AgentHookRequest) -> AgentHookEvaluatorFuture<'_>;
+}
+
+impl<E: AgentHookEvaluator + ?Sized> AgentHookEvaluator for Arc<E> {
+    fn evaluate(&self, request: AgentHookRequest) -> AgentHookEvaluatorFuture<'_> {
+        Box::pin(async move { (**self).evaluate(request).await })
+    }
+}
+
+impl<F, Fut> AgentHookEvaluator for F
+where
+    F: Fn(AgentHookRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = AgentHookResponse> + Send + 'static,
+{
+    fn evaluate(&self, request: AgentHookRequest) -> AgentHookEvaluatorFuture<'_> {
+        Box::pin((self)(request))
+    }
+}
+
+#[tracing::instrument(
+    name = "codex.hooks.agent",
+    level = "trace",
+    skip_all,
+    fields(
+        hook.event_name = hook_event_name_label(handler.event_name),
+        hook.handler_type = hook_handler_type_label(HookHandlerType::Agent),
+        hook.execution_mode = hook_execution_mode_label(HookExecutionMode::Sync),
+        hook.scope = hook_scope_label(scope_for_event(handler.event_name)),
+        hook.source = hook_source_label(handler.source),
+        hook.display_order = handler.display_order,
+        hook.configured_order = configured_order,
+        hook.timeout_sec = handler.timeout_sec,
+        hook.agent_outcome = tracing::field::Empty,
+    )
+)]
+pub(crate) async fn run_agent(
+    evaluator: Option<&dyn AgentHookEvaluator>,
+    handler: &ConfiguredHandler,
+    prompt: &str,
+    model: Option<&str>,
+    configured_order: usize,
+    input_json: &str,
+    cwd: &Path,
+) -> (CommandRunResult, Option<ThreadId>) {
+    let started_at = chrono::Utc::now().timestamp();
+    let started = Instant::now();
+
+    let Some(evaluator) = evaluator else {
+        return finish_agent_run(
+            started_at,
+            started,
+            /*thread_id*/ None,
+            Err("agent hooks are not available in this session".to_string()),
+            "unavailable",
+        );
+    };
+
+    let request = AgentHookRequest {
+        event_name: handler.event_name,
+        model: model.map(str::to_string),
+        input: agent_hook_input(handler.event_name, prompt, input_json),
+        timeout: Duration::from_secs(handler.timeout_sec),
+        cwd: cwd.to_path_buf(),
+    };
+    let AgentHookResponse { thread_id, result } = evaluator.evaluate(request).await;
+    match result {
+        Ok(response) => match parse_agent_decision(&response) {
+            Some(decision) => finish_agent_run(
+                started_at,
+                started,
+                thread_id,
+                Ok(command_output_for_decision(handler.event_name, decision).to_string()),
+                "completed",
+            ),
+            None => finish_agent_run(
+                started_at,
+                started,
+                thread_id,
+                Err("agent hook returned an invalid decision".to_string()),
+                "invalid_decision",
+            ),
+        },
+        Err(err) => finish_agent_run(
+            started_at,
+            started,
+            thread_id,
+            Err(format!("agent hook failed: {err}")),
+            "agent_error",
+        ),
+    }
+}
+
+fn finish_agent_run(
+    started_at: i64,
+    started: Instant,
+    thread_id: Option<ThreadId>,
+    stdout: Result<String, String>,
+    outcome: &'static str,
+) -> (CommandRunResult, Option<ThreadId>) {
+    Span::current().record("hook.agent_outcome", outcome);
+    (model_run_result(started_at, started, stdout), thread_id)
+}
+
+fn agent_hook_input(event_name: HookEventName, prompt: &str, input_json: &str) -> String {
+    format!(
+        "{}\n\
+         You are running as a read-only verification sub-agent: inspect files and run \
+         read-only commands as needed, but do not modify the workspace. Finish with a final \
+         message that contains only the JSON object.\n\n\
+         <hook_prompt>\n{prompt}\n</hook_prompt>\n\n<hook_event>\n{input_json}\n</hook_event>",
+        prompt_hook_instructions(event_name)
+    )
+}
+
+/// Agents cannot be held to a response schema, so accept a trailing JSON
+/// object after any narration.
+fn parse_agent_decision(response: &str) -> Option<PromptHookDecision> {
+    parse_decision(response).or_else(|| {
+        let trimmed = response.trim().trim_end_matches("```").trim_end();
+        trimmed
+            .match_indices('{')
+            .rev()
+            .find_map(|(start, _)| parse_decision(&trimmed[start..]))
+    })
+}
+
+#[cfg(test)]
+#[path = "agent_runner_tests.rs"]
+mod tests;
@@ -0,0 +1,131 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
+use codex_protocol::protocol::HookSource;
+use codex_utils_absolute_path::test_support::PathBufExt;
+use codex_utils_absolute_path::test_support::test_path_buf;
+use pretty_assertions::assert_eq;
+
+use super::AgentHookEvaluator;
+use super::AgentHookRequest;
+use super::AgentHookResponse;
+use super::parse_agent_decision;
+use super::run_agent;
+use crate::engine::ConfiguredHandler;
+use crate::engine::HandlerKind;
+use crate::engine::output_parser;
+use crate::engine::prompt_runner::PromptHookDecision;
+use crate::engine::prompt_runner::PromptHookDecisionKind;
+
+fn agent_handler() -> ConfiguredHandler {
+    ConfiguredHandler {
+        event_name: HookEventName::Stop,
+        matcher: None,
+        kind: HandlerKind::Agent {
+            prompt: "Verify the tests pass.".to_string(),
+            model: None,
+        },
+        command: String::new(),
+        timeout_sec: 120,
+        execution_mode: HookExecutionMode::Sync,
+        status_message: None,
+        additional_context_limit: Default::default(),
+        source_path: test_path_buf("/tmp/hooks.json").abs(),
+        source: HookSource::User,
+        display_order: 0,
+        env: HashMap::new(),
+    }
+}
+
+#[test]
+fn parse_agent_decision_accepts_trailing_json_after_narration() {
+    assert_eq!(
+        parse_agent_decision(
+            "I ran `cargo test` and two tests failed.\n\n```json\n{\"decision\":\"deny\",\"reason\":\"tests fail\"}\n```"
+        ),
+        Some(PromptHookDecision {
+            decision: PromptHookDecisionKind::Deny,
+            reason: Some("tests fail".to_string()),
+            additional_context: None,
+        })
+    );
+    assert_eq!(parse_agent_decision("All good."), None);
+}
+
+#[tokio::test]
+async fn run_agent_reports_sub_agent_thread_and_verdict() {
+    let thread_id = ThreadId::new();
+    let seen = Arc::new(Mutex::new(Vec::<AgentHookRequest>::new()));
+    let evaluator: Arc<dyn AgentHookEvaluator> = Arc::new({
+        let seen = Arc::clone(&seen);
+        move |request: AgentHookRequest| {
+            seen.lock().expect("lock requests").push(request);
+            async move {
+                AgentHookResponse {
+                    thread_id: Some(thread_id),
+                    result: Ok(r#"{"decision":"deny","reason":"tests fail"}"#.to_string()),
+                }
+            }
+        }
+    });
+    let handler = agent_handler();
+
+    let (result, agent_thread_id) = run_agent(
+        Some(evaluator.as_ref()),
+        &handler,
+        "Verify the tests pass.",
+        /*model*/ None,
+        /*configured_order*/ 0,
+        r#"{"hook_event_name":"Stop"}"#,
+        test_path_buf("/tmp").as_path(),
+    )
+    .await;
+
+    assert_eq!(agent_thread_id, Some(thread_id));
+    assert_eq!(result.error, None);
+    let parsed = output_parser::parse_stop(&result.stdout).expect("translated output should parse");
+    assert!(parsed.should_block);
+    assert_eq!(parsed.reason, Some("tests fail".to_string()));
+
+    let requests = seen.lock().expect("lock requests");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].timeout, Duration::from_secs(120));
+    assert!(requests[0].input.contains("Verify the tests pass."));
+    assert!(requests[0].input.contains(r#"{"hook_event_name":"Stop"}"#));
+}
+
+#[tokio::test]
+async fn run_agent_keeps_thread_id_when_sub_agent_fails() {
+    let thread_id = ThreadId::new();
+    let evaluator: Arc<dyn AgentHookEvaluator> =
+        Arc::new(move |_request: AgentHookRequest| async move {
+            AgentHookResponse {
+                thread_id: Some(thread_id),
+                result: Err("timed out after 120s".to_string()),
+            }
+        });
+    let handler = agent_handler();
+
+    let (result, agent_thread_id) = run_agent(
+        Some(evaluator.as_ref()),
+        &handler,
+        "Verify the tests pass.",
+        /*model*/ None,
+        /*configured_order*/ 0,
+        "{}",
+        test_path_buf("/tmp").as_path(),
+    )
+    .await;
+
+    assert_eq!(agent_thread_id, Some(thread_id));
+    assert_eq!(result.exit_code, None);
+    assert_eq!(
+        result.error,
+        Some("agent hook failed: timed out after 120s".to_string())
+    );
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use codex_protocol::protocol::HookCompletedEvent;
+
+/// Receives completion events for `async` hooks.
+///
+/// Async hooks finish after the event that started them has returned, so their
+/// completions cannot travel back in the event outcome.
+pub trait AsyncHookCompletionSink: Send + Sync + 'static {
+    fn hook_completed(&self, completed: HookCompletedEvent);
+}
+
+impl<S: AsyncHookCompletionSink + ?Sized> AsyncHookCompletionSink for Arc<S> {
+    fn hook_completed(&self, completed: HookCompletedEvent) {
+        (**self).hook_completed(completed);
+    }
+}
+
+impl<F> AsyncHookCompletionSink for F
+where
+    F: Fn(HookCompletedEvent) + Send + Sync + 'static,
+{
+    fn hook_completed(&self, completed: HookCompletedEvent) {
+        (self)(completed);
+    }
+}
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::fs;
 
 use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
 use codex_protocol::protocol::HookSource;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use pretty_assertions::assert_eq;
@@ -10,6 +11,7 @@ use tempfile::tempdir;
 use super::CommandShell;
 use super::ConfiguredHandler;
 use super::run_command;
+use crate::engine::HandlerKind;
 
 #[tokio::test]
 async fn cmd_shell_runs_quoted_hook_command_path() {
@@ -27,8 +29,10 @@ async fn cmd_shell_runs_quoted_hook_command_path() {
     let handler = ConfiguredHandler {
         event_name: HookEventName::SessionStart,
         matcher: None,
+        kind: HandlerKind::Command,
         command: format!(r#""{}" notify"#, hook_path.display()),
         timeout_sec: 10,
+        execution_mode: HookExecutionMode::Sync,
         status_message: None,
         additional_context_limit: Default::default(),
         source_path,
@@ -23,7 +23,12 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use super::ConfiguredHandler;
+use super::HandlerKind;
 use super::HookListEntry;
+use super::agent_runner::AGENT_HOOK_DEFAULT_TIMEOUT_SEC;
+use super::agent_runner::AGENT_HOOK_EVENTS;
+use super::dispatcher::hook_event_name_label;
+use super::prompt_runner::PROMPT_HOOK_DEFAULT_TIMEOUT_SEC;
 use crate::config_rules::hook_states_from_stack;
 use crate::events::common::matcher_pattern_for_event;
 use crate::events::common::validate_matcher_pattern;
@@ -31,7 +36,7 @@ use crate::events::session_end::SESSION_END_DEFAULT_TIMEOUT_SEC;
 use crate::events::session_end::SESSION_END_MAX_TIMEOUT_SEC;
 use crate::output_spill::AdditionalContextLimit;
 use crate::output_spill::DEFAULT_HOOK_OUTPUT_TOKEN_LIMIT;
-use codex_protocol::protocol::HookHandlerType;
+use codex_protocol::protocol::HookExecutionMode;
 use codex_protocol::protocol::HookSource;
 use codex_protocol::protocol::HookTrustStatus;
 
@@ -463,6 +468,8 @@ fn append_matcher_groups(
             continue;
         }
         for (handler_index, handler) in group.hooks.iter().cloned().enumerate() {
+            // TODO(abhinav): replace this positional suffix with a durable hook id.
+            let key = crate::hook_key(&source.key_source, event_name, group_index, handler_index);
             match handler {
                 HookHandlerConfig::Command {
                     command,
@@ -477,14 +484,6 @@ fn append_matcher_groups(
                     } else {
                         command
                     };
-                    if r#async && event_name != codex_protocol::protocol::HookEventName::SessionEnd
-                    {
-                        warnings.push(format!(
-                            "skipping async hook in {}: async hooks are not supported yet",
-                            source.path.display()
-                        ));
-                        continue;
-                    }
                     if command.trim().is_empty() {
                         warnings.push(format!(
                             "skipping empty hook command in {}",
@@ -498,12 +497,11 @@ fn append_matcher_groups(
                         source.path.as_path(),
                         warnings,
                     );
-                    if r#async {
-                        warnings.push(format!(
-                            "running async SessionEnd hook synchronously in {}",
-                            source.path.display()
-                        ));
-                    }
+                    let execution_mode = if r#async {
+                        HookExecutionMode::Async
+                    } else {
+                        HookExecutionMode::Sync
+                    };
                     let additional_context_limit = if matches!(
                         event_name,
                         codex_protocol::protocol::HookEventName::PreToolUse
@@ -537,44 +535,20 @@ fn append_matcher_groups(
                     let command = source.env.iter().fold(command, |command, (key, value)| {
                         command.replace(&format!("${{{key}}}"), value)
                     });
-                    // TODO(abhinav): replace this positional suffix with a durable hook id.
-                    let key =
-                        crate::hook_key(&source.key_source, event_name, group_index, handler_index);
-                    let state = source.hook_states.get(&key);
-                    let enabled = hook_enabled(source.is_managed, state);
-                    let trusted_hash = hook_trusted_hash(source.is_managed, state);
-                    let trust_status =
-                        hook_trust_status(source.is_managed, &current_hash, trusted_hash);
-                    hook_entries.push(HookListEntry {
+                    register_handler(
+                        handlers,
+                        hook_entries,
+                        display_order,
+                        source,
                         key,
-                        event_name,
-                        handler_type: HookHandlerType::Command,
-                        matcher: matcher.map(ToOwned::to_owned),
-                        command: Some(command.clone()),
-                        timeout_sec,
-                        status_message: status_message.clone(),
-                        additional_context_limit,
-                        source_path: source.path.clone(),
-                        source: source.source,
-                        plugin_id: source.plugin_id.clone(),
-                        display_order: *display_order,
-                        enabled,
-                        is_managed: source.is_managed,
                         current_hash,
-                        trust_status,
-                    });
-                    if enabled
-                        && (source.bypass_hook_trust
-                            || matches!(
-                                trust_status,
-                                HookTrustStatus::Managed | HookTrustStatus::Trusted
-                            ))
-                    {
-                        handlers.push(ConfiguredHandler {
+                        ConfiguredHandler {
                             event_name,
                             matcher: matcher.map(ToOwned::to_owned),
+                            kind: HandlerKind::Command,
                             command,
                             timeout_sec,
+                            execution_mode,
                             status_message,
                             additional_context_limit: AdditionalContextLimit::from_config(
                                 additional_context_limit,
@@ -583,25 +557,174 @@ fn append_matcher_groups(
                             source: source.source,
                             display_order: *display_order,
                             env: source.env.clone(),
-                        });
+                        },
+                        additional_context_limit,
+                    );
+                }
+                HookHandlerConfig::Prompt {
+                    prompt,
+                    model,
+                    timeout_sec,
+                    status_message,
+                } => {
+                    if prompt.trim().is_empty() {
+                        warnings.push(format!(
+                            "skipping empty hook prompt in {}",
+                            source.path.display()
+                        ));
+                        continue;
                     }
-                    *display_order += 1;
+                    let timeout_sec = normalize_command_hook(
+                        event_name,
+                        Some(timeout_sec.unwrap_or(PROMPT_HOOK_DEFAULT_TIMEOUT_SEC)),
+                        source.path.as_path(),
+                        warnings,
+                    );
+                    let normalized_handler = HookHandlerConfig::Prompt {
+                        prompt: prompt.clone(),
+                        model: model.clone(),
+                        timeout_sec: Some(timeout_sec),
+                        status_message: status_message.clone(),
+                    };
+                    let current_hash =
+                        command_hook_hash(event_name, matcher, &group, normalized_handler);
+                    register_handler(
+                        handlers,
+                        hook_entries,
+                        display_order,
+                        source,
+                        key,
+                        current_hash,
+                        ConfiguredHandler {
+                            event_name,
+                            matcher: matcher.map(ToOwned::to_owned),
+                            kind: HandlerKind::Prompt { prompt, model },
+                            command: String::new(),
+                            timeout_sec,
+                            execution_mode: HookExecutionMode::Sync,
+                            status_message,
+                            additional_context_limit: AdditionalContextLimit::default(),
+                            source_path: source.path.clone(),
+                            source: source.source,
+                            display_order: *display_order,
+                            env: source.env.clone(),
+                        },
+                        /*additional_context_limit*/ None,
+                    );
+                }
+                HookHandlerConfig::Agent {
+                    prompt,
+                    model,
+                    timeout_sec,
+                    status_message,
+                } => {
+                    if !AGENT_HOOK_EVENTS.contains(&event_name) {
+                        warnings.push(format!(
+                            "skipping agent hook for {} in {}: agent hooks only support Stop and PostToolUse",
+                            hook_event_name_label(event_name),
+                            source.path.display()
+                        ));
+                        continue;
+                    }
+                    if prompt.trim().is_empty() {
+                        warnings.push(format!(
+                            "skipping empty hook prompt in {}",
+                            source.path.display()
+                        ));
+                        continue;
+                    }
+                    let timeout_sec = normalize_command_hook(
+                        event_name,
+                        Some(timeout_sec.unwrap_or(AGENT_HOOK_DEFAULT_TIMEOUT_SEC)),
+                        source.path.as_path(),
+                        warnings,
+                    );
+                    let normalized_handler = HookHandlerConfig::Agent {
+                        prompt: prompt.clone(),
+                        model: model.clone(),
+                        timeout_sec: Some(timeout_sec),
+                        status_message: status_message.clone(),
+                    };
+                    let current_hash =
+                        command_hook_hash(event_name, matcher, &group, normalized_handler);
+                    register_handler(
+                        handlers,
+                        hook_entries,
+                        display_order,
+                        source,
+                        key,
+                        current_hash,
+                        ConfiguredHandler {
+                            event_name,
+                            matcher: matcher.map(ToOwned::to_owned),
+                            kind: HandlerKind::Agent { prompt, model },
+                            command: String::new(),
+                            timeout_sec,
+                            execution_mode: HookExecutionMode::Sync,
+                            status_message,
+                            additional_context_limit: AdditionalContextLimit::default(),
+                            source_path: source.path.clone(),
+                            source: source.source,
+                            display_order: *display_order,
+                            env: source.env.clone(),
+                        },
+                        /*additional_context_limit*/ None,
+                    );
                 }
-                HookHandlerConfig::Prompt {} => warnings.push(format!(
-                    "skipping prompt hook in {}: prompt hooks are not supported yet",
-                    source.path.display()
-                )),
-                HookHandlerConfig::Agent {} => warnings.push(format!(
-                    "skipping agent hook in {}: agent hooks are not supported yet",
-                    source.path.display()
-                )),
             }
         }
     }
 }
 
-/// Normalizes command-hook timeouts. SessionEnd defaults to one second and is capped at three
-/// seconds; all other command hooks keep the standard ten-minute default.
+/// Records the hook in the listing and, when it is enabled and trusted, in the
+/// runnable handler set.
+#[allow(clippy::too_many_arguments)]
+fn register_handler(
+    handlers: &mut Vec<ConfiguredHandler>,
+    hook_entries: &mut Vec<HookListEntry>,
+    display_order: &mut i64,
+    source: &HookHandlerSource<'_>,
+    key: String,
+    current_hash: String,
+    handler: ConfiguredHandler,
+    additional_context_limit: Option<usize>,
+) {
+    let state = source.hook_states.get(&key);
+    let enabled = hook_enabled(source.is_managed, state);
+    let trusted_hash = hook_trusted_hash(source.is_managed, state);
+    let trust_status = hook_trust_status(source.is_managed, &current_hash, trusted_hash);
+    hook_entries.push(HookListEntry {
+        key,
+        event_name: handler.event_name,
+        handler_type: handler.handler_type(),
+        matcher: handler.matcher.clone(),
+        command: matches!(handler.kind, HandlerKind::Command).then(|| handler.command.clone()),
+        timeout_sec: handler.timeout_sec,
+        status_message: handler.status_message.clone(),
+        additional_context_limit,
+        source_path: source.path.clone(),
+        source: source.source,
+        plugin_id: source.plugin_id.clone(),
+        display_order: *display_order,
+        enabled,
+        is_managed: source.is_managed,
+        current_hash,
+        trust_status,
+    });
+    if enabled
+        && (source.bypass_hook_trust
+            || matches!(
+                trust_status,
+                HookTrustStatus::Managed | HookTrustStatus::Trusted
+            ))
+    {
+        handlers.push(handler);
+    }
+    *display_order += 1;
+}
+
+/// Normalizes hook timeouts. SessionEnd defaults to one second and is capped at three
+/// seconds; all other hooks keep the standard ten-minute default.
 fn normalize_command_hook(
     event_name: codex_protocol::protocol::HookEventName,
     timeout_sec: Option<u64>,
@@ -724,6 +847,7 @@ mod tests {
     use codex_config::HookEventsToml;
     use codex_config::RequirementSource;
     use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookExecutionMode;
     use codex_protocol::protocol::HookSource;
     use codex_utils_absolute_path::AbsolutePathBuf;
     use codex_utils_absolute_path::test_support::PathBufExt;
@@ -731,6 +855,7 @@ mod tests {
     use pretty_assertions::assert_eq;
 
     use super::ConfiguredHandler;
+    use super::HandlerKind;
     use super::HookListEntry;
     use super::append_matcher_groups;
     use crate::output_spill::AdditionalContextLimit;
@@ -739,6 +864,7 @@ mod tests {
     use codex_config::HookStateToml;
     use codex_config::MatcherGroup;
     use codex_config::TomlValue;
+    use codex_protocol::protocol::HookHandlerType;
     use codex_protocol::protocol::HookTrustStatus;
 
     fn source_path() -> AbsolutePathBuf {
@@ -957,8 +1083,10 @@ mod tests {
             vec![ConfiguredHandler {
                 event_name: HookEventName::UserPromptSubmit,
                 matcher: None,
+                kind: HandlerKind::Command,
                 command: "echo hello".to_string(),
                 timeout_sec: 600,
+                execution_mode: HookExecutionMode::Sync,
                 status_message: None,
                 additional_context_limit: Default::default(),
                 source_path: source_path.clone(),
@@ -993,8 +1121,10 @@ mod tests {
             vec![ConfiguredHandler {
                 event_name: HookEventName::PreToolUse,
                 matcher: Some("^Bash$".to_string()),
+                kind: HandlerKind::Command,
                 command: "echo hello".to_string(),
                 timeout_sec: 600,
+                execution_mode: HookExecutionMode::Sync,
                 status_message: None,
                 additional_context_limit: Default::default(),
                 source_path: source_path.clone(),
@@ -1005,6 +1135,134 @@ mod tests {
         );
     }
 
+    #[test]
+    fn prompt_handler_is_discovered_without_command() {
+        let mut handlers = Vec::new();
+        let mut hook_entries = Vec::new();
+        let mut warnings = Vec::new();
+        let mut display_order = 0;
+        let source_path = source_path();
+        let hook_states = std::collections::HashMap::new();
+
+        append_matcher_groups(
+            &mut handlers,
+            &mut hook_entries,
+            &mut warnings,
+            &mut display_order,
+            &hook_handler_source(&source_path, &hook_states),
+            HookEventName::PreToolUse,
+            vec![MatcherGroup {
+                matcher: Some("^apply_patch$".to_string()),
+                hooks: vec![
+                    HookHandlerConfig::Prompt {
+                        prompt: "Deny edits to migrations/.".to_string(),
+                        model: Some("gpt-test-mini".to_string()),
+                        timeout_sec: None,
+                        status_message: Some("checking policy".to_string()),
+                    },
+                    HookHandlerConfig::Prompt {
+                        prompt: "  ".to_string(),
+                        model: None,
+                        timeout_sec: None,
+                        status_message: None,
+                    },
+                ],
+            }],
+        );
+
+        assert_eq!(
+            warnings,
+            vec![format!(
+                "skipping empty hook prompt in {}",
+                source_path.display()
+            )]
+        );
+        assert_eq!(
+            handlers,
+            vec![ConfiguredHandler {
+                event_name: HookEventName::PreToolUse,
+                matcher: Some("^apply_patch$".to_string()),
+                kind: HandlerKind::Prompt {
+                    prompt: "Deny edits to migrations/.".to_string(),
+                    model: Some("gpt-test-mini".to_string()),
+                },
+                command: String::new(),
+                timeout_sec: 30,
+                execution_mode: HookExecutionMode::Sync,
+                status_message: Some("checking policy".to_string()),
+                additional_context_limit: Default::default(),
+                source_path: source_path.clone(),
+                source: hook_source(),
+                display_order: 0,
+                env: std::collections::HashMap::new(),
+            }]
+        );
+        assert_eq!(hook_entries.len(), 1);
+        assert_eq!(hook_entries[0].handler_type, HookHandlerType::Prompt);
+        assert_eq!(hook_entries[0].command, None);
+    }
+
+    #[test]
+    fn agent_handler_is_limited_to_supported_events() {
+        let mut handlers = Vec::new();
+        let mut hook_entries = Vec::new();
+        let mut warnings = Vec::new();
+        let mut display_order = 0;
+        let source_path = source_path();
+        let hook_states = std::collections::HashMap::new();
+        let agent_group = || MatcherGroup {
+            matcher: None,
+            hooks: vec![HookHandlerConfig::Agent {
+                prompt: "Verify the tests pass.".to_string(),
+                model: None,
+                timeout_sec: None,
+                status_message: None,
+            }],
+        };
+
+        for event_name in [HookEventName::PreToolUse, HookEventName::Stop] {
+            append_matcher_groups(
+                &mut handlers,
+                &mut hook_entries,
+                &mut warnings,
+                &mut display_order,
+                &hook_handler_source(&source_path, &hook_states),
+                event_name,
+                vec![agent_group()],
+            );
+        }
+
+        assert_eq!(
+            warnings,
+            vec![format!(
+                "skipping agent hook for PreToolUse in {}: agent hooks only support Stop and PostToolUse",
+                source_path.display()
+            )]
+        );
+        assert_eq!(
+            handlers,
+            vec![ConfiguredHandler {
+                event_name: HookEventName::Stop,
+                matcher: None,
+                kind: HandlerKind::Agent {
+                    prompt: "Verify the tests pass.".to_string(),
+                    model: None,
+                },
+                command: String::new(),
+                timeout_sec: 300,
+                execution_mode: HookExecutionMode::Sync,
+                status_message: None,
+                additional_context_limit: Default::default(),
+                source_path: source_path.clone(),
+                source: hook_source(),
+                display_order: 0,
+                env: std::collections::HashMap::new(),
+            }]
+        );
+        assert_eq!(hook_entries.len(), 1);
+        assert_eq!(hook_entries[0].handler_type, HookHandlerType::Agent);
+    }
+
     #[test]
     fn session_end_normalizes_timeout() {
         let mut handlers = Vec::new();
@@ -1072,18 +1330,19 @@ mod tests {
                 .collect::<Vec<_>>(),
             vec![Some("other"), Some("other")]
         );
+        assert_eq!(
+            handlers
+                .iter()
+                .map(|handler| handler.execution_mode)
+                .collect::<Vec<_>>(),
+            vec![HookExecutionMode::Sync, HookExecutionMode::Async]
+        );
         assert_eq!(
             warnings,
-            vec![
-                format!(
-                    "clamping SessionEnd hook timeout to 3s in {}",
-                    source_path.display()
-                ),
-                format!(
-                    "running async SessionEnd hook synchronously in {}",
-                    source_path.display()
-                ),
-            ]
+            vec![format!(
+                "clamping SessionEnd hook timeout to 3s in {}",
+                source_path.display()
+            )]
         );
     }
 
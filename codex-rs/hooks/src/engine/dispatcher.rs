@@ -3,6 +3,7 @@ use std::path::Path;
 use futures::StreamExt;
 use futures::stream::FuturesUnordered;
 
+use codex_protocol::ThreadId;
 use codex_protocol::protocol::HookCompletedEvent;
 use codex_protocol::protocol::HookEventName;
 use codex_protocol::protocol::HookExecutionMode;
@@ -11,10 +12,15 @@ use codex_protocol::protocol::HookRunStatus;
 use codex_protocol::protocol::HookRunSummary;
 use codex_protocol::protocol::HookScope;
 
-use super::CommandShell;
 use super::ConfiguredHandler;
+use super::HandlerKind;
+use super::HandlerRuntime;
+use super::agent_runner::run_agent;
+use super::async_runner::AsyncHookCompletionSink;
 use super::command_runner::CommandRunResult;
 use super::command_runner::run_command;
+use super::prompt_runner::run_prompt;
+use crate::events::common::hook_completed_for_tool_use;
 use crate::events::common::matches_matcher;
 
 #[derive(Debug)]
@@ -72,8 +78,8 @@ pub(crate) fn running_summary(handler: &ConfiguredHandler) -> HookRunSummary {
     HookRunSummary {
         id: handler.run_id(),
         event_name: handler.event_name,
-        handler_type: HookHandlerType::Command,
-        execution_mode: HookExecutionMode::Sync,
+        handler_type: handler.handler_type(),
+        execution_mode: handler.execution_mode,
         scope: scope_for_event(handler.event_name),
         source_path: handler.source_path.clone(),
         source: handler.source,
@@ -84,24 +90,57 @@ pub(crate) fn running_summary(handler: &ConfiguredHandler) -> HookRunSummary {
         completed_at: None,
         duration_ms: None,
         entries: Vec::new(),
+        agent_thread_id: None,
     }
 }
 
-pub(crate) async fn execute_handlers<T>(
-    shell: &CommandShell,
+/// Runs the selected handlers and returns the parsed results of the `Sync`
+/// ones in configured order.
+///
+/// `Async` handlers are detached: each keeps its own timeout, its decisions are
+/// discarded, and its completion is reported through the runtime's
+/// [`AsyncHookCompletionSink`] once it finishes. `SessionEnd` is the exception:
+/// the process is about to exit, so its async handlers run inline and are
+/// returned with the rest.
+pub(crate) async fn execute_handlers<T: 'static>(
+    runtime: &HandlerRuntime,
     handlers: Vec<ConfiguredHandler>,
     input_json: String,
     cwd: &Path,
     turn_id: Option<String>,
+    tool_use_id: Option<&str>,
     parse: fn(&ConfiguredHandler, CommandRunResult, Option<String>) -> ParsedHandler<T>,
 ) -> Vec<ParsedHandler<T>> {
     let mut pending = FuturesUnordered::new();
     for (configured_order, handler) in handlers.into_iter().enumerate() {
         let input_json = input_json.clone();
         let turn_id = turn_id.clone();
+        if handler.execution_mode == HookExecutionMode::Async
+            && handler.event_name != HookEventName::SessionEnd
+        {
+            let runtime = runtime.clone();
+            let cwd = cwd.to_path_buf();
+            let tool_use_id = tool_use_id.map(str::to_string);
+            tokio::spawn(async move {
+                let (result, agent_thread_id) =
+                    run_handler(&runtime, &handler, configured_order, &input_json, &cwd).await;
+                let mut completed = parse(&handler, result, turn_id).completed;
+                completed.run.agent_thread_id = agent_thread_id;
+                if let Some(tool_use_id) = tool_use_id.as_deref() {
+                    completed = hook_completed_for_tool_use(completed, tool_use_id);
+                }
+                if let Some(sink) = runtime.async_completion_sink.as_ref() {
+                    sink.hook_completed(completed);
+                }
+            });
+            continue;
+        }
         pending.push(async move {
-            let result = run_command(shell, &handler, configured_order, &input_json, cwd).await;
-            (configured_order, parse(&handler, result, turn_id))
+            let (result, agent_thread_id) =
+                run_handler(runtime, &handler, configured_order, &input_json, cwd).await;
+            let mut parsed = parse(&handler, result, turn_id);
+            parsed.completed.run.agent_thread_id = agent_thread_id;
+            (configured_order, parsed)
         });
     }
 
@@ -116,6 +155,46 @@ pub(crate) async fn execute_handlers<T>(
     completed.into_iter().map(|(_, parsed)| parsed).collect()
 }
 
+async fn run_handler(
+    runtime: &HandlerRuntime,
+    handler: &ConfiguredHandler,
+    configured_order: usize,
+    input_json: &str,
+    cwd: &Path,
+) -> (CommandRunResult, Option<ThreadId>) {
+    match &handler.kind {
+        HandlerKind::Command => (
+            run_command(&runtime.shell, handler, configured_order, input_json, cwd).await,
+            None,
+        ),
+        HandlerKind::Prompt { prompt, model } => (
+            run_prompt(
+                runtime.prompt_evaluator.as_deref(),
+                handler,
+                prompt,
+                model.as_deref(),
+                configured_order,
+                input_json,
+                cwd,
+            )
+            .await,
+            None,
+        ),
+        HandlerKind::Agent { prompt, model } => {
+            run_agent(
+                runtime.agent_evaluator.as_deref(),
+                handler,
+                prompt,
+                model.as_deref(),
+                configured_order,
+                input_json,
+                cwd,
+            )
+            .await
+        }
+    }
+}
+
 pub(crate) fn completed_summary(
     handler: &ConfiguredHandler,
     run_result: &CommandRunResult,
@@ -125,8 +204,8 @@ pub(crate) fn completed_summary(
     HookRunSummary {
         id: handler.run_id(),
         event_name: handler.event_name,
-        handler_type: HookHandlerType::Command,
-        execution_mode: HookExecutionMode::Sync,
+        handler_type: handler.handler_type(),
+        execution_mode: handler.execution_mode,
         scope: scope_for_event(handler.event_name),
         source_path: handler.source_path.clone(),
         source: handler.source,
@@ -137,6 +216,7 @@ pub(crate) fn completed_summary(
         completed_at: Some(run_result.completed_at),
         duration_ms: Some(run_result.duration_ms),
         entries,
+        agent_thread_id: None,
     }
 }
 
@@ -214,15 +294,35 @@ pub(crate) fn hook_source_label(source: codex_protocol::protocol::HookSource) ->
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::atomic::Ordering;
+
+    use codex_protocol::protocol::HookCompletedEvent;
     use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookExecutionMode;
+    use codex_protocol::protocol::HookRunStatus;
     use codex_protocol::protocol::HookSource;
     use codex_utils_absolute_path::test_support::PathBufExt;
     use codex_utils_absolute_path::test_support::test_path_buf;
     use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc;
+    use tokio::sync::oneshot;
 
+    use super::CommandRunResult;
     use super::ConfiguredHandler;
+    use super::HandlerKind;
+    use super::HandlerRuntime;
+    use super::ParsedHandler;
+    use super::completed_summary;
+    use super::execute_handlers;
     use super::select_handlers;
     use super::select_handlers_for_matcher_inputs;
+    use crate::engine::CommandShell;
+    use crate::engine::async_runner::AsyncHookCompletionSink;
+    use crate::engine::prompt_runner::PromptHookEvaluator;
+    use crate::engine::prompt_runner::PromptHookRequest;
 
     fn make_handler(
         event_name: HookEventName,
@@ -233,8 +333,10 @@ mod tests {
         ConfiguredHandler {
             event_name,
             matcher: matcher.map(str::to_owned),
+            kind: HandlerKind::Command,
             command: command.to_string(),
             timeout_sec: 5,
+            execution_mode: HookExecutionMode::Sync,
             status_message: None,
             additional_context_limit: Default::default(),
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -505,4 +607,163 @@ mod tests {
         assert_eq!(selected[1].command, "second");
         assert_eq!(selected[2].command, "third");
     }
+
+    fn prompt_handler(
+        model: &str,
+        execution_mode: HookExecutionMode,
+        display_order: i64,
+    ) -> ConfiguredHandler {
+        ConfiguredHandler {
+            kind: HandlerKind::Prompt {
+                prompt: "Record this tool call.".to_string(),
+                model: Some(model.to_string()),
+            },
+            command: String::new(),
+            execution_mode,
+            ..make_handler(
+                HookEventName::PostToolUse,
+                /*matcher*/ None,
+                "",
+                display_order,
+            )
+        }
+    }
+
+    fn parse_status(
+        handler: &ConfiguredHandler,
+        run_result: CommandRunResult,
+        turn_id: Option<String>,
+    ) -> ParsedHandler<()> {
+        let status = if run_result.error.is_some() {
+            HookRunStatus::Failed
+        } else {
+            HookRunStatus::Completed
+        };
+        ParsedHandler {
+            completed: HookCompletedEvent {
+                turn_id,
+                run: completed_summary(handler, &run_result, status, Vec::new()),
+            },
+            data: (),
+            completion_order: 0,
+        }
+    }
+
+    #[tokio::test]
+    async fn async_handlers_do_not_delay_sync_results_and_report_through_sink() {
+        let (release_tx, release_rx) = oneshot::channel::<()>();
+        let release_rx = Arc::new(Mutex::new(Some(release_rx)));
+        let evaluator: Arc<dyn PromptHookEvaluator> =
+            Arc::new(move |request: PromptHookRequest| {
+                let release_rx = if request.model.as_deref() == Some("slow") {
+                    release_rx.lock().expect("lock release").take()
+                } else {
+                    None
+                };
+                async move {
+                    if let Some(release_rx) = release_rx {
+                        let _ = release_rx.await;
+                    }
+                    Ok::<_, String>(r#"{"decision":"allow"}"#.to_string())
+                }
+            });
+        let (completed_tx, mut completed_rx) = mpsc::unbounded_channel();
+        let sink: Arc<dyn AsyncHookCompletionSink> =
+            Arc::new(move |completed: HookCompletedEvent| {
+                let _ = completed_tx.send(completed);
+            });
+        let runtime = HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: Some(evaluator),
+            agent_evaluator: None,
+            async_completion_sink: Some(sink),
+        };
+
+        let results = execute_handlers(
+            &runtime,
+            vec![
+                prompt_handler("slow", HookExecutionMode::Async, /*display_order*/ 0),
+                prompt_handler("fast", HookExecutionMode::Sync, /*display_order*/ 1),
+            ],
+            "{}".to_string(),
+            test_path_buf("/tmp").as_path(),
+            Some("turn-1".to_string()),
+            Some("tool-1"),
+            parse_status,
+        )
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].completed.run.display_order, 1);
+        assert_eq!(
+            results[0].completed.run.execution_mode,
+            HookExecutionMode::Sync
+        );
+        assert!(completed_rx.try_recv().is_err());
+
+        release_tx.send(()).expect("release async handler");
+        let completed = completed_rx.recv().await.expect("async completion");
+        assert_eq!(completed.turn_id.as_deref(), Some("turn-1"));
+        assert_eq!(completed.run.display_order, 0);
+        assert_eq!(completed.run.execution_mode, HookExecutionMode::Async);
+        assert_eq!(completed.run.status, HookRunStatus::Completed);
+        assert!(completed.run.id.ends_with(":tool-1"));
+    }
+
+    #[tokio::test]
+    async fn async_session_end_handlers_finish_before_returning() {
+        let finished = Arc::new(AtomicBool::new(false));
+        let evaluator: Arc<dyn PromptHookEvaluator> = {
+            let finished = Arc::clone(&finished);
+            Arc::new(move |_request: PromptHookRequest| {
+                let finished = Arc::clone(&finished);
+                async move {
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                    finished.store(true, Ordering::SeqCst);
+                    Ok::<_, String>("{}".to_string())
+                }
+            })
+        };
+        let (completed_tx, mut completed_rx) = mpsc::unbounded_channel();
+        let sink: Arc<dyn AsyncHookCompletionSink> =
+            Arc::new(move |completed: HookCompletedEvent| {
+                let _ = completed_tx.send(completed);
+            });
+        let runtime = HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: Some(evaluator),
+            agent_evaluator: None,
+            async_completion_sink: Some(sink),
+        };
+        let handler = ConfiguredHandler {
+            event_name: HookEventName::SessionEnd,
+            ..prompt_handler("slow", HookExecutionMode::Async, /*display_order*/ 0)
+        };
+
+        let results = execute_handlers(
+            &runtime,
+            vec![handler],
+            "{}".to_string(),
+            test_path_buf("/tmp").as_path(),
+            Some("turn-1".to_string()),
+            /*tool_use_id*/ None,
+            parse_status,
+        )
+        .await;
+
+        assert!(finished.load(Ordering::SeqCst));
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].completed.run.execution_mode,
+            HookExecutionMode::Async
+        );
+        assert_eq!(results[0].completed.run.status, HookRunStatus::Completed);
+        assert!(completed_rx.try_recv().is_err());
+    }
 }
@@ -1,7 +1,10 @@
+pub(crate) mod agent_runner;
+pub(crate) mod async_runner;
 pub(crate) mod command_runner;
 pub(crate) mod discovery;
 pub(crate) mod dispatcher;
 pub(crate) mod output_parser;
+pub(crate) mod prompt_runner;
 pub(crate) mod schema_loader;
 
 use crate::events::compact::PostCompactRequest;
@@ -24,16 +27,21 @@ use crate::events::user_prompt_submit::UserPromptSubmitOutcome;
 use crate::events::user_prompt_submit::UserPromptSubmitRequest;
 use crate::output_spill::AdditionalContextLimit;
 use crate::output_spill::HookOutputSpiller;
+use agent_runner::AgentHookEvaluator;
+use async_runner::AsyncHookCompletionSink;
 use codex_config::ConfigLayerStack;
 use codex_plugin::PluginHookSource;
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
 use codex_protocol::protocol::HookHandlerType;
 use codex_protocol::protocol::HookRunSummary;
 use codex_protocol::protocol::HookSource;
 use codex_protocol::protocol::HookTrustStatus;
 use codex_utils_absolute_path::AbsolutePathBuf;
+use prompt_runner::PromptHookEvaluator;
 use std::collections::HashMap;
+use std::sync::Arc;
 
 #[derive(Debug, Clone)]
 pub(crate) struct CommandShell {
@@ -41,12 +49,38 @@ pub(crate) struct CommandShell {
     pub args: Vec<String>,
 }
 
+/// Everything a dispatcher needs to execute the handler kinds it may select.
+#[derive(Clone)]
+pub(crate) struct HandlerRuntime {
+    pub shell: CommandShell,
+    pub prompt_evaluator: Option<Arc<dyn PromptHookEvaluator>>,
+    pub agent_evaluator: Option<Arc<dyn AgentHookEvaluator>>,
+    pub async_completion_sink: Option<Arc<dyn AsyncHookCompletionSink>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum HandlerKind {
+    Command,
+    Prompt {
+        prompt: String,
+        model: Option<String>,
+    },
+    Agent {
+        prompt: String,
+        model: Option<String>,
+    },
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) struct ConfiguredHandler {
     pub event_name: codex_protocol::protocol::HookEventName,
     pub matcher: Option<String>,
+    pub kind: HandlerKind,
+    /// Shell command for [`HandlerKind::Command`] handlers; empty for other kinds.
     pub command: String,
     pub timeout_sec: u64,
+    /// `Async` handlers run in the background and never influence the event outcome.
+    pub execution_mode: HookExecutionMode,
     pub status_message: Option<String>,
     pub additional_context_limit: AdditionalContextLimit,
     pub source_path: AbsolutePathBuf,
@@ -56,6 +90,14 @@ pub(crate) struct ConfiguredHandler {
 }
 
 impl ConfiguredHandler {
+    pub fn handler_type(&self) -> HookHandlerType {
+        match self.kind {
+            HandlerKind::Command => HookHandlerType::Command,
+            HandlerKind::Prompt { .. } => HookHandlerType::Prompt,
+            HandlerKind::Agent { .. } => HookHandlerType::Agent,
+        }
+    }
+
     pub fn run_id(&self) -> String {
         format!(
             "{}:{}:{}",
@@ -106,7 +148,7 @@ pub struct HookListEntry {
 pub(crate) struct ClaudeHooksEngine {
     handlers: Vec<ConfiguredHandler>,
     warnings: Vec<String>,
-    shell: CommandShell,
+    runtime: HandlerRuntime,
     output_spiller: HookOutputSpiller,
 }
 
@@ -117,13 +159,13 @@ impl ClaudeHooksEngine {
         config_layer_stack: Option<&ConfigLayerStack>,
         plugin_hook_sources: Vec<PluginHookSource>,
         plugin_hook_load_warnings: Vec<String>,
-        shell: CommandShell,
+        runtime: HandlerRuntime,
     ) -> Self {
         if !enabled {
             return Self {
                 handlers: Vec::new(),
                 warnings: Vec::new(),
-                shell,
+                runtime,
                 output_spiller: HookOutputSpiller::new(),
             };
         }
@@ -138,7 +180,7 @@ impl ClaudeHooksEngine {
         Self {
             handlers: discovered.handlers,
             warnings: discovered.warnings,
-            shell,
+            runtime,
             output_spiller: HookOutputSpiller::new(),
         }
     }
@@ -179,7 +221,7 @@ impl ClaudeHooksEngine {
     ) -> SessionStartOutcome {
         crate::events::session_start::run(
             &self.handlers,
-            &self.shell,
+            &self.runtime,
             &self.output_spiller,
             request,
             turn_id,
@@ -188,15 +230,20 @@ impl ClaudeHooksEngine {
     }
 
     pub(crate) async fn run_pre_tool_use(&self, request: PreToolUseRequest) -> PreToolUseOutcome {
-        crate::events::pre_tool_use::run(&self.handlers, &self.shell, &self.output_spiller, request)
-            .await
+        crate::events::pre_tool_use::run(
+            &self.handlers,
+            &self.runtime,
+            &self.output_spiller,
+            request,
+        )
+        .await
     }
 
     pub(crate) async fn run_permission_request(
         &self,
         request: PermissionRequestRequest,
     ) -> PermissionRequestOutcome {
-        crate::events::permission_request::run(&self.handlers, &self.shell, request).await
+        crate::events::permission_request::run(&self.handlers, &self.runtime, request).await
     }
 
     pub(crate) async fn run_post_tool_use(
@@ -206,7 +253,7 @@ impl ClaudeHooksEngine {
         let session_id = request.session_id;
         let mut outcome = crate::events::post_tool_use::run(
             &self.handlers,
-            &self.shell,
+            &self.runtime,
             &self.output_spiller,
             request,
         )
@@ -222,7 +269,7 @@ impl ClaudeHooksEngine {
     }
 
     pub(crate) async fn run_pre_compact(&self, request: PreCompactRequest) -> PreCompactOutcome {
-        crate::events::compact::run_pre(&self.handlers, &self.shell, request).await
+        crate::events::compact::run_pre(&self.handlers, &self.runtime, request).await
     }
 
     pub(crate) fn preview_post_compact(&self, request: &PostCompactRequest) -> Vec<HookRunSummary> {
@@ -233,7 +280,7 @@ impl ClaudeHooksEngine {
         &self,
         request: PostCompactRequest,
     ) -> StatelessHookOutcome {
-        crate::events::compact::run_post(&self.handlers, &self.shell, request).await
+        crate::events::compact::run_post(&self.handlers, &self.runtime, request).await
     }
 
     pub(crate) fn preview_user_prompt_submit(
@@ -249,7 +296,7 @@ impl ClaudeHooksEngine {
     ) -> UserPromptSubmitOutcome {
         crate::events::user_prompt_submit::run(
             &self.handlers,
-            &self.shell,
+            &self.runtime,
             &self.output_spiller,
             request,
         )
@@ -265,12 +312,12 @@ impl ClaudeHooksEngine {
     }
 
     pub(crate) async fn run_session_end(&self, request: SessionEndRequest) -> SessionEndOutcome {
-        crate::events::session_end::run(&self.handlers, &self.shell, request).await
+        crate::events::session_end::run(&self.handlers, &self.runtime, request).await
     }
 
     pub(crate) async fn run_stop(&self, request: StopRequest) -> StopOutcome {
         let session_id = request.session_id;
-        let mut outcome = crate::events::stop::run(&self.handlers, &self.shell, request).await;
+        let mut outcome = crate::events::stop::run(&self.handlers, &self.runtime, request).await;
         outcome.continuation_fragments = self
             .maybe_spill_prompt_fragments(session_id, outcome.continuation_fragments)
             .await;
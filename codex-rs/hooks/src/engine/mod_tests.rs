@@ -30,6 +30,7 @@ use tempfile::tempdir;
 
 use super::ClaudeHooksEngine;
 use super::CommandShell;
+use super::HandlerRuntime;
 use crate::events::pre_tool_use::PreToolUseRequest;
 
 fn cwd() -> AbsolutePathBuf {
@@ -202,9 +203,14 @@ with Path(r"{log_path}").open("a", encoding="utf-8") as handle:
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -223,6 +229,9 @@ with Path(r"{log_path}").open("a", encoding="utf-8") as handle:
         plugin_hook_load_warnings: Vec::new(),
         shell_program: None,
         shell_args: Vec::new(),
+        prompt_hook_evaluator: None,
+        agent_hook_evaluator: None,
+        async_hook_completion_sink: None,
     });
     assert!(listed.hooks[0].is_managed);
     let cwd = cwd();
@@ -309,9 +318,14 @@ async fn requirements_managed_hooks_execute_windows_command_override() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -389,9 +403,14 @@ fn unknown_requirement_source_hooks_stay_managed() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -472,9 +491,14 @@ fn user_disablement_filters_non_managed_hooks_but_not_managed_hooks() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -538,9 +562,14 @@ fn user_disablement_does_not_filter_managed_layer_hooks() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -700,9 +729,14 @@ fn requirements_managed_hooks_load_when_managed_dir_is_missing() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -756,9 +790,14 @@ fn allow_managed_hooks_only_false_keeps_unmanaged_hooks() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -810,9 +849,14 @@ fn allow_managed_hooks_only_in_config_toml_does_not_enable_policy() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -880,9 +924,14 @@ fn allow_managed_hooks_only_skips_unmanaged_json_and_toml_hooks() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -919,9 +968,14 @@ fn allow_managed_hooks_only_skips_unmanaged_plugin_hooks() {
         Some(&config_layer_stack),
         plugin_hook_sources,
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -991,9 +1045,14 @@ fn allow_managed_hooks_only_keeps_managed_requirement_and_config_layer_hooks() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1101,9 +1160,14 @@ fn discovers_hooks_from_json_and_toml_in_the_same_layer() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1196,9 +1260,14 @@ fn profile_user_layers_load_shared_hooks_json_once() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1270,9 +1339,14 @@ fn malformed_hooks_json_is_reported_as_startup_warning() {
         Some(&config_layer_stack),
         Vec::new(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1342,9 +1416,14 @@ print(json.dumps({
         Some(&config_layer_stack),
         plugin_hook_sources.clone(),
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1373,6 +1452,9 @@ print(json.dumps({
         plugin_hook_load_warnings: Vec::new(),
         shell_program: None,
         shell_args: Vec::new(),
+        prompt_hook_evaluator: None,
+        agent_hook_evaluator: None,
+        async_hook_completion_sink: None,
     });
     assert_eq!(
         listed.hooks[0].plugin_id.as_deref(),
@@ -1462,9 +1544,14 @@ fn plugin_hook_sources_expand_plugin_placeholders() {
         Some(&config_layer_stack),
         plugin_hook_sources,
         Vec::new(),
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -1506,9 +1593,14 @@ fn plugin_hook_load_warnings_are_startup_warnings() {
         /*config_layer_stack*/ None,
         Vec::new(),
         vec!["failed plugin hook".to_string()],
-        CommandShell {
-            program: String::new(),
-            args: Vec::new(),
+        HandlerRuntime {
+            shell: CommandShell {
+                program: String::new(),
+                args: Vec::new(),
+            },
+            prompt_evaluator: None,
+            agent_evaluator: None,
+            async_completion_sink: None,
         },
     );
 
@@ -0,0 +1,359 @@
+use std::future::Future;
+use std::path::Path;
+use std::path::PathBuf;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
+use tokio::time::timeout;
+use tracing::Span;
+
+use super::ConfiguredHandler;
+use super::command_runner::CommandRunResult;
+use super::dispatcher::hook_event_name_label;
+use super::dispatcher::hook_execution_mode_label;
+use super::dispatcher::hook_handler_type_label;
+use super::dispatcher::hook_scope_label;
+use super::dispatcher::hook_source_label;
+use super::dispatcher::scope_for_event;
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
+use codex_protocol::protocol::HookHandlerType;
+
+pub(crate) const PROMPT_HOOK_DEFAULT_TIMEOUT_SEC: u64 = 30;
+
+const DEFAULT_DENY_REASON: &str = "Denied by prompt hook";
+
+/// One model evaluation requested by a `prompt` hook.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PromptHookRequest {
+    pub event_name: HookEventName,
+    /// Model slug configured on the hook, if any. Evaluators fall back to the
+    /// session model when unset.
+    pub model: Option<String>,
+    /// Developer instructions describing the decision contract.
+    pub instructions: String,
+    /// User message containing the configured prompt and the hook event payload.
+    pub input: String,
+    /// JSON schema the model response must satisfy.
+    pub output_schema: Value,
+    pub cwd: PathBuf,
+}
+
+pub type PromptHookEvaluatorFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<String, String>> + Send + 'a>>;
+
+/// Sends prompt-hook requests to a model and returns the raw response text.
+///
+/// The hooks crate owns the decision contract and parsing; callers only need
+/// to provide model access.
+pub trait PromptHookEvaluator: Send + Sync + 'static {
+    fn evaluate(&self, request: PromptHookRequest) -> PromptHookEvaluatorFuture<'_>;
+}
+
+impl<E: PromptHookEvaluator + ?Sized> PromptHookEvaluator for Arc<E> {
+    fn evaluate(&self, request: PromptHookRequest) -> PromptHookEvaluatorFuture<'_> {
+        Box::pin(async move { (**self).evaluate(request).await })
+    }
+}
+
+impl<F, Fut> PromptHookEvaluator for F
+where
+    F: Fn(PromptHookRequest) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<String, String>> + Send + 'static,
+{
+    fn evaluate(&self, request: PromptHookRequest) -> PromptHookEvaluatorFuture<'_> {
+        Box::pin((self)(request))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PromptHookDecision {
+    pub decision: PromptHookDecisionKind,
+    #[serde(default)]
+    pub reason: Option<String>,
+    #[serde(default)]
+    pub additional_context: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum PromptHookDecisionKind {
+    Allow,
+    Deny,
+}
+
+#[tracing::instrument(
+    name = "codex.hooks.prompt",
+    level = "trace",
+    skip_all,
+    fields(
+        hook.event_name = hook_event_name_label(handler.event_name),
+        hook.handler_type = hook_handler_type_label(HookHandlerType::Prompt),
+        hook.execution_mode = hook_execution_mode_label(HookExecutionMode::Sync),
+        hook.scope = hook_scope_label(scope_for_event(handler.event_name)),
+        hook.source = hook_source_label(handler.source),
+        hook.display_order = handler.display_order,
+        hook.configured_order = configured_order,
+        hook.timeout_sec = handler.timeout_sec,
+        hook.prompt_outcome = tracing::field::Empty,
+    )
+)]
+pub(crate) async fn run_prompt(
+    evaluator: Option<&dyn PromptHookEvaluator>,
+    handler: &ConfiguredHandler,
+    prompt: &str,
+    model: Option<&str>,
+    configured_order: usize,
+    input_json: &str,
+    cwd: &Path,
+) -> CommandRunResult {
+    let started_at = chrono::Utc::now().timestamp();
+    let started = Instant::now();
+
+    let Some(evaluator) = evaluator else {
+        return finish_prompt_run(
+            started_at,
+            started,
+            Err("prompt hooks are not available in this session".to_string()),
+            "unavailable",
+        );
+    };
+
+    let request = PromptHookRequest {
+        event_name: handler.event_name,
+        model: model.map(str::to_string),
+        instructions: prompt_hook_instructions(handler.event_name),
+        input: format!(
+            "<hook_prompt>\n{prompt}\n</hook_prompt>\n\n<hook_event>\n{input_json}\n</hook_event>"
+        ),
+        output_schema: prompt_hook_output_schema(),
+        cwd: cwd.to_path_buf(),
+    };
+    let timeout_duration = Duration::from_secs(handler.timeout_sec);
+    match timeout(timeout_duration, evaluator.evaluate(request)).await {
+        Ok(Ok(response)) => match parse_decision(&response) {
+            Some(decision) => finish_prompt_run(
+                started_at,
+                started,
+                Ok(command_output_for_decision(handler.event_name, decision).to_string()),
+                "completed",
+            ),
+            None => finish_prompt_run(
+                started_at,
+                started,
+                Err("prompt hook returned an invalid decision".to_string()),
+                "invalid_decision",
+            ),
+        },
+        Ok(Err(err)) => finish_prompt_run(
+            started_at,
+            started,
+            Err(format!("prompt hook evaluation failed: {err}")),
+            "evaluation_error",
+        ),
+        Err(_) => finish_prompt_run(
+            started_at,
+            started,
+            Err(format!("hook timed out after {}s", handler.timeout_sec)),
+            "timeout",
+        ),
+    }
+}
+
+fn finish_prompt_run(
+    started_at: i64,
+    started: Instant,
+    stdout: Result<String, String>,
+    outcome: &'static str,
+) -> CommandRunResult {
+    Span::current().record("hook.prompt_outcome", outcome);
+    model_run_result(started_at, started, stdout)
+}
+
+/// Packages a model-evaluated hook result like a command run: decisions become
+/// stdout with exit code 0 and failures become errors.
+pub(super) fn model_run_result(
+    started_at: i64,
+    started: Instant,
+    stdout: Result<String, String>,
+) -> CommandRunResult {
+    let (exit_code, stdout, error) = match stdout {
+        Ok(stdout) => (Some(0), stdout, None),
+        Err(error) => (None, String::new(), Some(error)),
+    };
+    CommandRunResult {
+        started_at,
+        completed_at: chrono::Utc::now().timestamp(),
+        duration_ms: started.elapsed().as_millis().try_into().unwrap_or(i64::MAX),
+        exit_code,
+        stdout,
+        stderr: String::new(),
+        error,
+    }
+}
+
+/// Parses the model response, tolerating a fenced JSON block for models that
+/// ignore the structured-output schema.
+pub(crate) fn parse_decision(response: &str) -> Option<PromptHookDecision> {
+    let trimmed = response.trim();
+    let unfenced = trimmed
+        .strip_prefix("```json")
+        .or_else(|| trimmed.strip_prefix("```"))
+        .and_then(|body| body.strip_suffix("```"))
+        .unwrap_or(trimmed);
+    serde_json::from_str(unfenced.trim()).ok()
+}
+
+fn prompt_hook_output_schema() -> Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "decision": {
+                "type": "string",
+                "enum": ["allow", "deny"],
+            },
+            "reason": {
+                "type": ["string", "null"],
+            },
+            "additionalContext": {
+                "type": ["string", "null"],
+            },
+        },
+        "required": ["decision", "reason", "additionalContext"],
+        "additionalProperties": false,
+    })
+}
+
+pub(super) fn prompt_hook_instructions(event_name: HookEventName) -> String {
+    let deny_meaning = match event_name {
+        HookEventName::PreToolUse => "`deny` blocks the tool call and shows `reason` to the agent.",
+        HookEventName::PermissionRequest => {
+            "`allow` approves the request without asking the user; `deny` rejects it with `reason`."
+        }
+        HookEventName::PostToolUse => {
+            "`deny` tells the agent the tool result is unacceptable, using `reason` as feedback."
+        }
+        HookEventName::UserPromptSubmit => {
+            "`deny` rejects the user prompt before the agent sees it, with `reason` as the explanation."
+        }
+        HookEventName::Stop | HookEventName::SubagentStop => {
+            "`deny` prevents the agent from stopping and sends `reason` back as the next instruction."
+        }
+        HookEventName::SessionStart | HookEventName::SubagentStart => {
+            "`deny` stops the session from starting, with `reason` as the explanation."
+        }
+        HookEventName::PreCompact | HookEventName::PostCompact | HookEventName::SessionEnd => {
+            "This event cannot be denied; always answer `allow`."
+        }
+    };
+    format!(
+        "You are a policy hook for a coding agent. Evaluate the {} event in <hook_event> \
+         against the policy in <hook_prompt>.\n\
+         Respond only with a JSON object containing `decision` (\"allow\" or \"deny\"), \
+         `reason` (string or null), and `additionalContext` (string or null).\n\
+         {deny_meaning}\n\
+         `additionalContext`, when set, is added to the agent's context. Prefer `allow` when \
+         the policy does not apply.",
+        hook_event_name_label(event_name)
+    )
+}
+
+/// Translates a prompt-hook decision into the JSON a command hook would print
+/// for the same event, so both handler kinds share one output parser.
+pub(crate) fn command_output_for_decision(
+    event_name: HookEventName,
+    decision: PromptHookDecision,
+) -> Value {
+    let PromptHookDecision {
+        decision,
+        reason,
+        additional_context,
+    } = decision;
+    let reason = reason.filter(|reason| !reason.trim().is_empty());
+    let additional_context = additional_context.filter(|context| !context.trim().is_empty());
+    let denied = decision == PromptHookDecisionKind::Deny;
+    let deny_reason = || {
+        reason
+            .clone()
+            .unwrap_or_else(|| DEFAULT_DENY_REASON.to_string())
+    };
+    let event_label = hook_event_name_label(event_name);
+
+    let mut output = serde_json::Map::new();
+    match event_name {
+        HookEventName::PreToolUse => {
+            let mut hook_specific = serde_json::Map::new();
+            hook_specific.insert("hookEventName".to_string(), json!(event_label));
+            if denied {
+                hook_specific.insert("permissionDecision".to_string(), json!("deny"));
+                hook_specific.insert("permissionDecisionReason".to_string(), json!(deny_reason()));
+            }
+            if let Some(additional_context) = additional_context {
+                hook_specific.insert("additionalContext".to_string(), json!(additional_context));
+            }
+            output.insert(
+                "hookSpecificOutput".to_string(),
+                Value::Object(hook_specific),
+            );
+        }
+        HookEventName::PermissionRequest => {
+            let decision = if denied {
+                json!({ "behavior": "deny", "message": deny_reason() })
+            } else {
+                json!({ "behavior": "allow" })
+            };
+            output.insert(
+                "hookSpecificOutput".to_string(),
+                json!({ "hookEventName": event_label, "decision": decision }),
+            );
+        }
+        HookEventName::PostToolUse | HookEventName::UserPromptSubmit => {
+            if denied {
+                output.insert("decision".to_string(), json!("block"));
+                output.insert("reason".to_string(), json!(deny_reason()));
+            }
+            if let Some(additional_context) = additional_context {
+                output.insert(
+                    "hookSpecificOutput".to_string(),
+                    json!({
+                        "hookEventName": event_label,
+                        "additionalContext": additional_context,
+                    }),
+                );
+            }
+        }
+        HookEventName::Stop | HookEventName::SubagentStop => {
+            if denied {
+                output.insert("decision".to_string(), json!("block"));
+                output.insert("reason".to_string(), json!(deny_reason()));
+            }
+        }
+        HookEventName::SessionStart | HookEventName::SubagentStart => {
+            if denied {
+                output.insert("continue".to_string(), json!(false));
+                output.insert("stopReason".to_string(), json!(deny_reason()));
+            }
+            if let Some(additional_context) = additional_context {
+                output.insert(
+                    "hookSpecificOutput".to_string(),
+                    json!({
+                        "hookEventName": event_label,
+                        "additionalContext": additional_context,
+                    }),
+                );
+            }
+        }
+        HookEventName::PreCompact | HookEventName::PostCompact | HookEventName::SessionEnd => {}
+    }
+    Value::Object(output)
+}
+
+#[cfg(test)]
+#[path = "prompt_runner_tests.rs"]
+mod tests;
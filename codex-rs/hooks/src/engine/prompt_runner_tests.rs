@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
+use codex_protocol::protocol::HookSource;
+use codex_utils_absolute_path::test_support::PathBufExt;
+use codex_utils_absolute_path::test_support::test_path_buf;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::PromptHookDecision;
+use super::PromptHookDecisionKind;
+use super::PromptHookEvaluator;
+use super::PromptHookRequest;
+use super::command_output_for_decision;
+use super::parse_decision;
+use super::run_prompt;
+use crate::engine::ConfiguredHandler;
+use crate::engine::HandlerKind;
+use crate::engine::output_parser;
+
+fn prompt_handler(event_name: HookEventName) -> ConfiguredHandler {
+    ConfiguredHandler {
+        event_name,
+        matcher: None,
+        kind: HandlerKind::Prompt {
+            prompt: "Deny edits to migrations/.".to_string(),
+            model: Some("gpt-test-mini".to_string()),
+        },
+        command: String::new(),
+        timeout_sec: 5,
+        execution_mode: HookExecutionMode::Sync,
+        status_message: None,
+        additional_context_limit: Default::default(),
+        source_path: test_path_buf("/tmp/hooks.json").abs(),
+        source: HookSource::User,
+        display_order: 0,
+        env: HashMap::new(),
+    }
+}
+
+fn deny(reason: Option<&str>) -> PromptHookDecision {
+    PromptHookDecision {
+        decision: PromptHookDecisionKind::Deny,
+        reason: reason.map(str::to_string),
+        additional_context: None,
+    }
+}
+
+#[test]
+fn parse_decision_accepts_fenced_json() {
+    assert_eq!(
+        parse_decision("```json\n{\"decision\":\"deny\",\"reason\":\"no\"}\n```"),
+        Some(deny(Some("no")))
+    );
+    assert_eq!(parse_decision("allow"), None);
+}
+
+#[test]
+fn pre_tool_use_deny_maps_to_blocking_command_output() {
+    let output = command_output_for_decision(
+        HookEventName::PreToolUse,
+        deny(Some("migrations are read-only")),
+    );
+
+    let parsed = output_parser::parse_pre_tool_use(&output.to_string())
+        .expect("translated output should parse");
+    assert_eq!(parsed.invalid_reason, None);
+    assert_eq!(
+        parsed.block_reason,
+        Some("migrations are read-only".to_string())
+    );
+}
+
+#[test]
+fn pre_tool_use_allow_only_forwards_additional_context() {
+    let output = command_output_for_decision(
+        HookEventName::PreToolUse,
+        PromptHookDecision {
+            decision: PromptHookDecisionKind::Allow,
+            reason: Some("fine".to_string()),
+            additional_context: Some("the user asked for this".to_string()),
+        },
+    );
+
+    assert_eq!(
+        output,
+        json!({
+            "hookSpecificOutput": {
+                "hookEventName": "PreToolUse",
+                "additionalContext": "the user asked for this",
+            }
+        })
+    );
+    let parsed = output_parser::parse_pre_tool_use(&output.to_string())
+        .expect("translated output should parse");
+    assert_eq!(parsed.invalid_reason, None);
+    assert_eq!(parsed.block_reason, None);
+}
+
+#[test]
+fn stop_deny_without_reason_uses_default_reason() {
+    let output = command_output_for_decision(HookEventName::Stop, deny(/*reason*/ None));
+
+    let parsed =
+        output_parser::parse_stop(&output.to_string()).expect("translated output should parse");
+    assert!(parsed.should_block);
+    assert_eq!(parsed.reason, Some("Denied by prompt hook".to_string()));
+}
+
+#[test]
+fn permission_request_deny_carries_message() {
+    let output = command_output_for_decision(
+        HookEventName::PermissionRequest,
+        deny(Some("network access is not allowed")),
+    );
+
+    let parsed = output_parser::parse_permission_request(&output.to_string())
+        .expect("translated output should parse");
+    assert_eq!(
+        parsed.decision,
+        Some(output_parser::PermissionRequestDecision::Deny {
+            message: "network access is not allowed".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn run_prompt_sends_payload_and_translates_response() {
+    let seen = Arc::new(Mutex::new(Vec::<PromptHookRequest>::new()));
+    let evaluator: Arc<dyn PromptHookEvaluator> = Arc::new({
+        let seen = Arc::clone(&seen);
+        move |request: PromptHookRequest| {
+            seen.lock().expect("lock requests").push(request);
+            async { Ok::<_, String>(r#"{"decision":"deny","reason":"no migrations"}"#.to_string()) }
+        }
+    });
+    let handler = prompt_handler(HookEventName::PreToolUse);
+
+    let result = run_prompt(
+        Some(evaluator.as_ref()),
+        &handler,
+        "Deny edits to migrations/.",
+        Some("gpt-test-mini"),
+        /*configured_order*/ 0,
+        r#"{"tool_name":"apply_patch"}"#,
+        test_path_buf("/tmp").as_path(),
+    )
+    .await;
+
+    assert_eq!(result.exit_code, Some(0));
+    assert_eq!(result.error, None);
+    let parsed =
+        output_parser::parse_pre_tool_use(&result.stdout).expect("translated output should parse");
+    assert_eq!(parsed.block_reason, Some("no migrations".to_string()));
+
+    let requests = seen.lock().expect("lock requests");
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].model, Some("gpt-test-mini".to_string()));
+    assert!(requests[0].input.contains("Deny edits to migrations/."));
+    assert!(requests[0].input.contains(r#"{"tool_name":"apply_patch"}"#));
+}
+
+#[tokio::test]
+async fn run_prompt_without_evaluator_fails() {
+    let handler = prompt_handler(HookEventName::Stop);
+
+    let result = run_prompt(
+        /*evaluator*/ None,
+        &handler,
+        "Deny edits to migrations/.",
+        /*model*/ None,
+        /*configured_order*/ 0,
+        "{}",
+        test_path_buf("/tmp").as_path(),
+    )
+    .await;
+
+    assert_eq!(result.exit_code, None);
+    assert_eq!(
+        result.error,
+        Some("prompt hooks are not available in this session".to_string())
+    );
+}
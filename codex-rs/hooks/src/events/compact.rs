@@ -10,8 +10,8 @@ use codex_protocol::protocol::HookRunSummary;
 use codex_utils_absolute_path::AbsolutePathBuf;
 
 use super::common;
-use crate::engine::CommandShell;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HandlerRuntime;
 use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
 use crate::engine::output_parser;
@@ -71,7 +71,7 @@ pub(crate) fn preview_pre(
 
 pub(crate) async fn run_pre(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    runtime: &HandlerRuntime,
     request: PreCompactRequest,
 ) -> PreCompactOutcome {
     let matched = dispatcher::select_handlers(
@@ -103,11 +103,12 @@ pub(crate) async fn run_pre(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        runtime,
         matched,
         input_json,
         request.cwd.as_path(),
         Some(request.turn_id),
+        /*tool_use_id*/ None,
         parse_pre_completed,
     )
     .await;
@@ -153,7 +154,7 @@ pub(crate) fn preview_post(
 
 pub(crate) async fn run_post(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    runtime: &HandlerRuntime,
     request: PostCompactRequest,
 ) -> StatelessHookOutcome {
     let matched = dispatcher::select_handlers(
@@ -185,11 +186,12 @@ pub(crate) async fn run_post(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        runtime,
         matched,
         input_json,
         request.cwd.as_path(),
         Some(request.turn_id),
+        /*tool_use_id*/ None,
         parse_post_completed,
     )
     .await;
@@ -419,6 +421,7 @@ fn parse_completed(
 mod tests {
     use codex_protocol::ThreadId;
     use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookExecutionMode;
     use codex_protocol::protocol::HookOutputEntry;
     use codex_protocol::protocol::HookOutputEntryKind;
     use codex_protocol::protocol::HookRunStatus;
@@ -432,6 +435,7 @@ mod tests {
     use super::post_command_input_json;
     use super::pre_command_input_json;
     use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
     use crate::engine::command_runner::CommandRunResult;
 
     #[test]
@@ -597,8 +601,10 @@ mod tests {
         ConfiguredHandler {
             event_name,
             matcher: None,
+            kind: HandlerKind::Command,
             command: "python3 compact_hook.py".to_string(),
             timeout_sec: 5,
+            execution_mode: HookExecutionMode::Sync,
             status_message: Some("running compact hook".to_string()),
             additional_context_limit: Default::default(),
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -11,8 +11,8 @@ use codex_utils_absolute_path::AbsolutePathBuf;
 use serde_json::Value;
 
 use super::common;
-use crate::engine::CommandShell;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HandlerRuntime;
 use crate::engine::command_runner::CommandRunResult;
 use crate::engine::dispatcher;
 use crate::engine::output_parser;
@@ -71,7 +71,7 @@ pub(crate) fn preview(
 
 pub(crate) async fn run(
     handlers: &[ConfiguredHandler],
-    shell: &CommandShell,
+    runtime: &HandlerRuntime,
     output_spiller: &HookOutputSpiller,
     request: PostToolUseRequest,
 ) -> PostToolUseOutcome {
@@ -105,11 +105,12 @@ pub(crate) async fn run(
     };
 
     let results = dispatcher::execute_handlers(
-        shell,
+        runtime,
         matched,
         input_json,
         request.cwd.as_path(),
         Some(request.turn_id.clone()),
+        Some(&request.tool_use_id),
         parse_completed,
     )
     .await;
@@ -320,6 +321,7 @@ fn serialization_failure_outcome(hook_events: Vec<HookCompletedEvent>) -> PostTo
 mod tests {
     use codex_protocol::ThreadId;
     use codex_protocol::protocol::HookEventName;
+    use codex_protocol::protocol::HookExecutionMode;
     use codex_protocol::protocol::HookOutputEntry;
     use codex_protocol::protocol::HookOutputEntryKind;
     use codex_protocol::protocol::HookRunStatus;
@@ -333,6 +335,7 @@ mod tests {
     use super::parse_completed;
     use super::preview;
     use crate::engine::ConfiguredHandler;
+    use crate::engine::HandlerKind;
     use crate::engine::command_runner::CommandRunResult;
     use crate::events::common;
     use crate::output_spill::AdditionalContext;
@@ -576,8 +579,10 @@ mod tests {
         ConfiguredHandler {
             event_name: HookEventName::PostToolUse,
             matcher: Some("^Bash$".to_string()),
+            kind: HandlerKind::Command,
             command: "python3 post_tool_use_hook.py".to_string(),
             timeout_sec: 5,
+            execution_mode: HookExecutionMode::Sync,
             status_message: Some("running post tool use hook".to_string()),
             additional_context_limit: Default::default(),
             source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use codex_protocol::protocol::HookEventName;
+use codex_protocol::protocol::HookExecutionMode;
 use codex_protocol::protocol::HookRunStatus;
 use codex_protocol::protocol::HookSource;
 use codex_utils_absolute_path::test_support::PathBufExt;
@@ -10,6 +11,7 @@ use pretty_assertions::assert_eq;
 use super::parse_completed;
 use super::preview;
 use crate::engine::ConfiguredHandler;
+use crate::engine::HandlerKind;
 use crate::engine::command_runner::CommandRunResult;
 
 #[test]
@@ -62,8 +64,10 @@ fn handler(matcher: Option<&str>) -> ConfiguredHandler {
     ConfiguredHandler {
         event_name: HookEventName::SessionEnd,
         matcher: matcher.map(str::to_string),
+        kind: HandlerKind::Command,
         command: "echo hook".to_string(),
         timeout_sec: 2,
+        execution_mode: HookExecutionMode::Sync,
         status_message: None,
         additional_context_limit: Default::default(),
         source_path: test_path_buf("/tmp/hooks.json").abs(),
@@ -14,6 +14,14 @@ pub use config_rules::hook_states_from_stack;
 pub use declarations::PluginHookDeclaration;
 pub use declarations::plugin_hook_declarations;
 pub use engine::HookListEntry;
+pub use engine::agent_runner::AgentHookEvaluator;
+pub use engine::agent_runner::AgentHookEvaluatorFuture;
+pub use engine::agent_runner::AgentHookRequest;
+pub use engine::agent_runner::AgentHookResponse;
+pub use engine::async_runner::AsyncHookCompletionSink;
+pub use engine::prompt_runner::PromptHookEvaluator;
+pub use engine::prompt_runner::PromptHookEvaluatorFuture;
+pub use engine::prompt_runner::PromptHookRequest;
 pub use events::common::SubagentHookContext;
 /// Hook event names as they appear in hooks JSON and config files.
 pub const HOOK_EVENT_NAMES: [&str; 11] = [
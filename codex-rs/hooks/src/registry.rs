@@ -1,10 +1,16 @@
+use std::sync::Arc;
+
 use codex_config::ConfigLayerStack;
 use codex_plugin::PluginHookSource;
 use tokio::process::Command;
 
 use crate::engine::ClaudeHooksEngine;
 use crate::engine::CommandShell;
+use crate::engine::HandlerRuntime;
 use crate::engine::HookListEntry;
+use crate::engine::agent_runner::AgentHookEvaluator;
+use crate::engine::async_runner::AsyncHookCompletionSink;
+use crate::engine::prompt_runner::PromptHookEvaluator;
 use crate::events::compact::PostCompactRequest;
 use crate::events::compact::PreCompactOutcome;
 use crate::events::compact::PreCompactRequest;
@@ -38,6 +44,15 @@ pub struct HooksConfig {
     pub plugin_hook_load_warnings: Vec<String>,
     pub shell_program: Option<String>,
     pub shell_args: Vec<String>,
+    /// Model access for `prompt` hooks. Prompt hooks fail with an error entry
+    /// when this is unset.
+    pub prompt_hook_evaluator: Option<Arc<dyn PromptHookEvaluator>>,
+    /// Sub-agent access for `agent` hooks. Agent hooks fail with an error entry
+    /// when this is unset.
+    pub agent_hook_evaluator: Option<Arc<dyn AgentHookEvaluator>>,
+    /// Receives completion events for `async` hooks once they finish in the
+    /// background. Async runs are still executed but go unreported when unset.
+    pub async_hook_completion_sink: Option<Arc<dyn AsyncHookCompletionSink>>,
 }
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
@@ -72,9 +87,14 @@ impl Hooks {
             config.config_layer_stack.as_ref(),
             config.plugin_hook_sources,
             config.plugin_hook_load_warnings,
-            CommandShell {
-                program: config.shell_program.unwrap_or_default(),
-                args: config.shell_args,
+            HandlerRuntime {
+                shell: CommandShell {
+                    program: config.shell_program.unwrap_or_default(),
+                    args: config.shell_args,
+                },
+                prompt_evaluator: config.prompt_hook_evaluator,
+                agent_evaluator: config.agent_hook_evaluator,
+                async_completion_sink: config.async_hook_completion_sink,
             },
         );
         Self {
@@ -242,6 +242,40 @@ pub(crate) fn create_tool_for_codex_tool_call_reply_param() -> Tool {
     .with_raw_output_schema(codex_tool_output_schema())
 }
 
+/// Number of sessions returned by `codex-list-sessions` when `limit` is omitted.
+pub(crate) const DEFAULT_LIST_SESSIONS_LIMIT: usize = 20;
+
+/// Arguments accepted by the `codex-list-sessions` tool-call.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[schemars(deny_unknown_fields)]
+pub struct CodexListSessionsParam {
+    /// Maximum number of sessions to return, newest first. Defaults to 20.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+}
+
+/// Builds a `Tool` definition for the `codex-list-sessions` tool-call.
+pub(crate) fn create_tool_for_codex_list_sessions_param() -> Tool {
+    let schema = SchemaSettings::draft2019_09()
+        .with(|s| {
+            s.inline_subschemas = true;
+            s.option_add_null_type = false;
+        })
+        .into_generator()
+        .into_root_schema_for::<CodexListSessionsParam>();
+
+    let input_schema =
+        create_tool_input_schema(schema, "Codex list sessions tool schema should serialize");
+
+    Tool::new(
+        "codex-list-sessions",
+        "List live and stored Codex sessions with the resource URIs of their transcripts, diffs, and final messages.",
+        input_schema,
+    )
+    .with_title("Codex Sessions")
+}
+
 fn create_tool_input_schema(
     schema: schemars::schema::RootSchema,
     panic_message: &str,
@@ -432,4 +466,28 @@ mod tests {
         });
         assert_eq!(expected_tool_json, tool_json);
     }
+
+    #[test]
+    fn verify_codex_list_sessions_tool_json_schema() {
+        let tool = create_tool_for_codex_list_sessions_param();
+        let tool_json = serde_json::to_value(&tool).expect("tool serializes");
+        let expected_tool_json = serde_json::json!({
+          "description": "List live and stored Codex sessions with the resource URIs of their transcripts, diffs, and final messages.",
+          "inputSchema": {
+            "additionalProperties": false,
+            "properties": {
+              "limit": {
+                "description": "Maximum number of sessions to return, newest first. Defaults to 20.",
+                "format": "uint",
+                "minimum": 0.0,
+                "type": "integer"
+              }
+            },
+            "type": "object"
+          },
+          "name": "codex-list-sessions",
+          "title": "Codex Sessions"
+        });
+        assert_eq!(expected_tool_json, tool_json);
+    }
 }
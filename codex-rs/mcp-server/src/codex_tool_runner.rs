@@ -7,8 +7,10 @@ use std::sync::Arc;
 
 use crate::exec_approval::handle_exec_approval_request;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::outgoing_message::OutgoingNotification;
 use crate::outgoing_message::OutgoingNotificationMeta;
 use crate::patch_approval::handle_patch_approval_request;
+use crate::session_resources::SessionResources;
 use codex_core::CodexThread;
 use codex_core::NewThread;
 use codex_core::ThreadManager;
@@ -19,7 +21,9 @@ use codex_protocol::protocol::ApplyPatchApprovalRequestEvent;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecApprovalRequestEvent;
+use codex_protocol::protocol::ExecCommandEndEvent;
 use codex_protocol::protocol::Op;
+use codex_protocol::protocol::PatchApplyEndEvent;
 use codex_protocol::protocol::Submission;
 use codex_protocol::protocol::TurnCompleteEvent;
 use codex_protocol::user_input::UserInput;
@@ -50,10 +54,81 @@ pub(crate) fn create_call_tool_result_with_thread_id(
     result
 }
 
+/// Sends MCP `notifications/progress` for a `tools/call` whose request carried
+/// a `_meta.progressToken`.
+pub(crate) struct ProgressNotifier {
+    outgoing: Arc<OutgoingMessageSender>,
+    progress_token: Option<serde_json::Value>,
+    progress: u64,
+}
+
+impl ProgressNotifier {
+    pub(crate) fn new(
+        outgoing: Arc<OutgoingMessageSender>,
+        progress_token: Option<serde_json::Value>,
+    ) -> Self {
+        Self {
+            outgoing,
+            progress_token,
+            progress: 0,
+        }
+    }
+
+    async fn notify(&mut self, msg: &EventMsg) {
+        let Some(progress_token) = self.progress_token.as_ref() else {
+            return;
+        };
+        let Some(message) = progress_message(msg) else {
+            return;
+        };
+        self.progress += 1;
+        self.outgoing
+            .send_notification(OutgoingNotification {
+                method: "notifications/progress".to_string(),
+                params: Some(json!({
+                    "progressToken": progress_token,
+                    "progress": self.progress,
+                    "message": message,
+                })),
+            })
+            .await;
+    }
+}
+
+/// Summarizes the events worth reporting as progress: finished commands and
+/// applied patches.
+fn progress_message(msg: &EventMsg) -> Option<String> {
+    match msg {
+        EventMsg::ExecCommandEnd(ExecCommandEndEvent {
+            command, exit_code, ..
+        }) => Some(command_progress_message(command, *exit_code)),
+        EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+            success: true,
+            changes,
+            ..
+        }) => {
+            let mut paths = changes
+                .keys()
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            paths.sort();
+            Some(format!("Changed {}", paths.join(", ")))
+        }
+        _ => None,
+    }
+}
+
+fn command_progress_message(command: &[String], exit_code: i32) -> String {
+    let command =
+        shlex::try_join(command.iter().map(String::as_str)).unwrap_or_else(|_| command.join(" "));
+    format!("Ran `{command}` (exit code {exit_code})")
+}
+
 /// Run a complete Codex session and stream events back to the client.
 ///
 /// On completion (success or error) the function sends the appropriate
 /// `tools/call` response so the LLM can continue the conversation.
+#[allow(clippy::too_many_arguments)]
 pub async fn run_codex_tool_session(
     id: RequestId,
     initial_prompt: String,
@@ -61,6 +136,8 @@ pub async fn run_codex_tool_session(
     outgoing: Arc<OutgoingMessageSender>,
     thread_manager: Arc<ThreadManager>,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    session_resources: Arc<SessionResources>,
+    progress: ProgressNotifier,
 ) {
     let NewThread {
         thread_id,
@@ -92,6 +169,10 @@ pub async fn run_codex_tool_session(
         )
         .await;
 
+    session_resources
+        .record_prompt(thread_id, &initial_prompt)
+        .await;
+
     // Use the original MCP request ID as the `sub_id` for the Codex submission so that
     // any events emitted for this tool-call can be correlated with the
     // originating `tools/call` request.
@@ -136,10 +217,13 @@ pub async fn run_codex_tool_session(
         outgoing,
         id,
         running_requests_id_to_codex_uuid,
+        session_resources,
+        progress,
     )
     .await;
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn run_codex_tool_session_reply(
     thread_id: ThreadId,
     thread: Arc<CodexThread>,
@@ -147,11 +231,14 @@ pub async fn run_codex_tool_session_reply(
     request_id: RequestId,
     prompt: String,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    session_resources: Arc<SessionResources>,
+    progress: ProgressNotifier,
 ) {
     running_requests_id_to_codex_uuid
         .lock()
         .await
         .insert(request_id.clone(), thread_id);
+    session_resources.record_prompt(thread_id, &prompt).await;
     if let Err(e) = thread
         .submit(Op::UserInput {
             items: vec![UserInput::Text {
@@ -187,6 +274,8 @@ pub async fn run_codex_tool_session_reply(
         outgoing,
         request_id,
         running_requests_id_to_codex_uuid,
+        session_resources,
+        progress,
     )
     .await;
 }
@@ -197,6 +286,8 @@ async fn run_codex_tool_session_inner(
     outgoing: Arc<OutgoingMessageSender>,
     request_id: RequestId,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    session_resources: Arc<SessionResources>,
+    mut progress: ProgressNotifier,
 ) {
     let request_id_str = request_id.to_string();
 
@@ -214,6 +305,8 @@ async fn run_codex_tool_session_inner(
                         }),
                     )
                     .await;
+                session_resources.observe_event(thread_id, &event.msg).await;
+                progress.notify(&event.msg).await;
 
                 match event.msg {
                     EventMsg::ExecApprovalRequest(ev) => {
@@ -321,8 +414,8 @@ async fn run_codex_tool_session_inner(
                     EventMsg::SessionConfigured(_) => {
                         tracing::error!("unexpected SessionConfigured event");
                     }
-                    EventMsg::ThreadGoalUpdated(_) => {
-                        // Ignore thread goal metadata updates in MCP tool runner.
+                    EventMsg::ThreadGoalUpdated(_) | EventMsg::PinnedContextUpdated(_) => {
+                        // Ignore thread goal and pin metadata updates in MCP tool runner.
                     }
                     EventMsg::McpStartupUpdate(_) | EventMsg::McpStartupComplete(_) => {
                         // Ignored in MCP tool runner.
@@ -418,6 +511,8 @@ async fn run_codex_tool_session_inner(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use codex_protocol::protocol::FileChange;
+    use codex_protocol::protocol::PatchApplyStatus;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -436,4 +531,46 @@ mod tests {
             }))
         );
     }
+
+    #[test]
+    fn progress_messages_cover_commands_and_patches() {
+        assert_eq!(
+            command_progress_message(
+                &[
+                    "cargo".to_string(),
+                    "test".to_string(),
+                    "codex core".to_string()
+                ],
+                /*exit_code*/ 101,
+            ),
+            "Ran `cargo test 'codex core'` (exit code 101)"
+        );
+
+        let patch_end = EventMsg::PatchApplyEnd(PatchApplyEndEvent {
+            call_id: "call-2".to_string(),
+            turn_id: "turn-1".to_string(),
+            stdout: String::new(),
+            stderr: String::new(),
+            success: true,
+            changes: HashMap::from([
+                (
+                    std::path::PathBuf::from("src/main.rs"),
+                    FileChange::Delete {
+                        content: String::new(),
+                    },
+                ),
+                (
+                    std::path::PathBuf::from("README.md"),
+                    FileChange::Delete {
+                        content: String::new(),
+                    },
+                ),
+            ]),
+            status: PatchApplyStatus::Completed,
+        });
+        assert_eq!(
+            progress_message(&patch_end),
+            Some("Changed README.md, src/main.rs".to_string())
+        );
+    }
 }
@@ -27,16 +27,24 @@ use rmcp::model::JsonRpcNotification;
 use rmcp::model::JsonRpcRequest;
 use rmcp::model::JsonRpcResponse;
 use rmcp::model::RequestId;
+use rmcp::model::RequestParamsMeta;
 use rmcp::model::ServerCapabilities;
 use serde_json::json;
 use tokio::sync::Mutex;
 use tokio::task;
 
+use crate::codex_tool_config::CodexListSessionsParam;
 use crate::codex_tool_config::CodexToolCallParam;
 use crate::codex_tool_config::CodexToolCallReplyParam;
+use crate::codex_tool_config::DEFAULT_LIST_SESSIONS_LIMIT;
+use crate::codex_tool_config::create_tool_for_codex_list_sessions_param;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_param;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_reply_param;
+use crate::codex_tool_runner::ProgressNotifier;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::session_resources::SessionResources;
+use crate::session_resources::parse_session_resource_uri;
+use crate::session_resources::session_resource_descriptors;
 
 pub(crate) struct MessageProcessor {
     outgoing: Arc<OutgoingMessageSender>,
@@ -44,6 +52,9 @@ pub(crate) struct MessageProcessor {
     arg0_paths: Arg0DispatchPaths,
     thread_manager: Arc<ThreadManager>,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
+    config: Arc<Config>,
+    state_db: Option<StateDbHandle>,
+    session_resources: Arc<SessionResources>,
 }
 
 impl MessageProcessor {
@@ -88,12 +99,16 @@ impl MessageProcessor {
             /*attestation_provider*/ None,
             /*external_time_provider*/ None,
         ));
+        let session_resources = Arc::new(SessionResources::new(Arc::clone(&outgoing)));
         Self {
             outgoing,
             initialized: false,
             arg0_paths,
             thread_manager,
             running_requests_id_to_codex_uuid: Arc::new(Mutex::new(HashMap::new())),
+            config,
+            state_db,
+            session_resources,
         }
     }
 
@@ -109,19 +124,19 @@ impl MessageProcessor {
                 self.handle_ping(request_id).await;
             }
             ClientRequest::ListResourcesRequest(params) => {
-                self.handle_list_resources(params.params);
+                self.handle_list_resources(request_id, params.params).await;
             }
             ClientRequest::ListResourceTemplatesRequest(params) => {
                 self.handle_list_resource_templates(params.params);
             }
             ClientRequest::ReadResourceRequest(params) => {
-                self.handle_read_resource(params.params);
+                self.handle_read_resource(request_id, params.params).await;
             }
             ClientRequest::SubscribeRequest(params) => {
-                self.handle_subscribe(params.params);
+                self.handle_subscribe(request_id, params.params).await;
             }
             ClientRequest::UnsubscribeRequest(params) => {
-                self.handle_unsubscribe(params.params);
+                self.handle_unsubscribe(request_id, params.params).await;
             }
             ClientRequest::ListPromptsRequest(params) => {
                 self.handle_list_prompts(params.params);
@@ -257,6 +272,9 @@ impl MessageProcessor {
         let capabilities = ServerCapabilities::builder()
             .enable_tools()
             .enable_tool_list_changed()
+            .enable_resources()
+            .enable_resources_subscribe()
+            .enable_resources_list_changed()
             .build();
         let result = InitializeResult::new(capabilities)
             .with_protocol_version(params.protocol_version.clone())
@@ -290,24 +308,100 @@ impl MessageProcessor {
         self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_list_resources(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_resources(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("resources/list -> params: {:?}", params);
+        let sessions = self
+            .session_resources
+            .list_sessions(
+                self.config.as_ref(),
+                self.state_db.clone(),
+                DEFAULT_LIST_SESSIONS_LIMIT,
+            )
+            .await;
+        self.outgoing
+            .send_response(
+                id,
+                json!({ "resources": session_resource_descriptors(&sessions) }),
+            )
+            .await;
     }
 
     fn handle_list_resource_templates(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
         tracing::info!("resources/templates/list -> params: {:?}", params);
     }
 
-    fn handle_read_resource(&self, params: rmcp::model::ReadResourceRequestParams) {
+    async fn handle_read_resource(
+        &self,
+        id: RequestId,
+        params: rmcp::model::ReadResourceRequestParams,
+    ) {
         tracing::info!("resources/read -> params: {:?}", params);
+        let uri = params.uri;
+        let Some((thread_id, kind)) = parse_session_resource_uri(&uri) else {
+            self.outgoing
+                .send_error(
+                    id,
+                    ErrorData::resource_not_found(
+                        format!("unknown resource: {uri}"),
+                        Some(json!({ "uri": uri })),
+                    ),
+                )
+                .await;
+            return;
+        };
+        match self
+            .session_resources
+            .read(
+                self.config.as_ref(),
+                self.state_db.as_ref(),
+                thread_id,
+                kind,
+            )
+            .await
+        {
+            Ok(text) => {
+                self.outgoing
+                    .send_response(
+                        id,
+                        json!({
+                            "contents": [{
+                                "uri": uri,
+                                "mimeType": kind.mime_type(),
+                                "text": text,
+                            }],
+                        }),
+                    )
+                    .await;
+            }
+            Err(message) => {
+                self.outgoing
+                    .send_error(
+                        id,
+                        ErrorData::resource_not_found(message, Some(json!({ "uri": uri }))),
+                    )
+                    .await;
+            }
+        }
     }
 
-    fn handle_subscribe(&self, params: rmcp::model::SubscribeRequestParams) {
+    async fn handle_subscribe(&self, id: RequestId, params: rmcp::model::SubscribeRequestParams) {
         tracing::info!("resources/subscribe -> params: {:?}", params);
+        self.session_resources.subscribe(params.uri);
+        self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_unsubscribe(&self, params: rmcp::model::UnsubscribeRequestParams) {
+    async fn handle_unsubscribe(
+        &self,
+        id: RequestId,
+        params: rmcp::model::UnsubscribeRequestParams,
+    ) {
         tracing::info!("resources/unsubscribe -> params: {:?}", params);
+        self.session_resources.unsubscribe(&params.uri);
+        self.outgoing.send_response(id, json!({})).await;
     }
 
     fn handle_list_prompts(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
@@ -329,6 +423,7 @@ impl MessageProcessor {
             tools: vec![
                 create_tool_for_codex_tool_call_param(),
                 create_tool_for_codex_tool_call_reply_param(),
+                create_tool_for_codex_list_sessions_param(),
             ],
             next_cursor: None,
         };
@@ -338,16 +433,23 @@ impl MessageProcessor {
 
     async fn handle_call_tool(&self, id: RequestId, params: CallToolRequestParams) {
         tracing::info!("tools/call -> params: {:?}", params);
+        let progress = ProgressNotifier::new(
+            Arc::clone(&self.outgoing),
+            params
+                .meta()
+                .and_then(|meta| meta.0.get("progressToken").cloned()),
+        );
         let CallToolRequestParams {
             name, arguments, ..
         } = params;
 
         match name.as_ref() {
-            "codex" => self.handle_tool_call_codex(id, arguments).await,
+            "codex" => self.handle_tool_call_codex(id, arguments, progress).await,
             "codex-reply" => {
-                self.handle_tool_call_codex_session_reply(id, arguments)
+                self.handle_tool_call_codex_session_reply(id, arguments, progress)
                     .await
             }
+            "codex-list-sessions" => self.handle_tool_call_list_sessions(id, arguments).await,
             _ => {
                 let result = CallToolResult::error(vec![rmcp::model::Content::text(format!(
                     "Unknown tool '{name}'"
@@ -361,6 +463,7 @@ impl MessageProcessor {
         &self,
         id: RequestId,
         arguments: Option<rmcp::model::JsonObject>,
+        progress: ProgressNotifier,
     ) {
         let arguments = arguments.map(serde_json::Value::Object);
         let (initial_prompt, config): (String, Config) = match arguments {
@@ -396,6 +499,7 @@ impl MessageProcessor {
         let outgoing = self.outgoing.clone();
         let thread_manager = self.thread_manager.clone();
         let running_requests_id_to_codex_uuid = self.running_requests_id_to_codex_uuid.clone();
+        let session_resources = Arc::clone(&self.session_resources);
 
         // Spawn an async task to handle the Codex session so that we do not
         // block the synchronous message-processing loop.
@@ -408,6 +512,8 @@ impl MessageProcessor {
                 outgoing,
                 thread_manager,
                 running_requests_id_to_codex_uuid,
+                session_resources,
+                progress,
             )
             .await;
         });
@@ -417,6 +523,7 @@ impl MessageProcessor {
         &self,
         request_id: RequestId,
         arguments: Option<rmcp::model::JsonObject>,
+        progress: ProgressNotifier,
     ) {
         let arguments = arguments.map(serde_json::Value::Object);
         tracing::info!("tools/call -> params: {:?}", arguments);
@@ -481,6 +588,7 @@ impl MessageProcessor {
         tokio::spawn({
             let outgoing = outgoing.clone();
             let running_requests_id_to_codex_uuid = running_requests_id_to_codex_uuid.clone();
+            let session_resources = Arc::clone(&self.session_resources);
 
             async move {
                 crate::codex_tool_runner::run_codex_tool_session_reply(
@@ -490,12 +598,50 @@ impl MessageProcessor {
                     request_id,
                     prompt,
                     running_requests_id_to_codex_uuid,
+                    session_resources,
+                    progress,
                 )
                 .await;
             }
         });
     }
 
+    async fn handle_tool_call_list_sessions(
+        &self,
+        id: RequestId,
+        arguments: Option<rmcp::model::JsonObject>,
+    ) {
+        let params = match arguments {
+            Some(arguments) => match serde_json::from_value::<CodexListSessionsParam>(
+                serde_json::Value::Object(arguments),
+            ) {
+                Ok(params) => params,
+                Err(e) => {
+                    let result = CallToolResult::error(vec![rmcp::model::Content::text(format!(
+                        "Failed to parse arguments for codex-list-sessions: {e}"
+                    ))]);
+                    self.outgoing.send_response(id, result).await;
+                    return;
+                }
+            },
+            None => CodexListSessionsParam::default(),
+        };
+        let sessions = self
+            .session_resources
+            .list_sessions(
+                self.config.as_ref(),
+                self.state_db.clone(),
+                params.limit.unwrap_or(DEFAULT_LIST_SESSIONS_LIMIT),
+            )
+            .await;
+        let structured_content = json!({ "sessions": sessions });
+        let mut result = CallToolResult::success(vec![rmcp::model::Content::text(
+            structured_content.to_string(),
+        )]);
+        result.structured_content = Some(structured_content);
+        self.outgoing.send_response(id, result).await;
+    }
+
     fn handle_set_level(&self, params: rmcp::model::SetLevelRequestParams) {
         tracing::info!("logging/setLevel -> params: {:?}", params);
     }
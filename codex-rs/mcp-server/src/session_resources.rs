@@ -0,0 +1,517 @@
+//! MCP resources describing Codex sessions run through this server.
+//!
+//! Sessions started by the `codex` tool are tracked live while this process
+//! runs. Sessions persisted by earlier server processes are read back from
+//! their rollout files. Every session exposes its transcript and final
+//! message; live sessions also expose the diff of their latest turn.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::Mutex as StdMutex;
+
+use codex_core::RolloutRecorder;
+use codex_core::SortDirection;
+use codex_core::StateDbHandle;
+use codex_core::ThreadSortKey;
+use codex_core::config::Config;
+use codex_core::find_thread_path_by_id_str;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::AgentMessageEvent;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::TurnCompleteEvent;
+use codex_protocol::protocol::TurnDiffEvent;
+use codex_protocol::protocol::UserMessageEvent;
+use serde::Serialize;
+use serde_json::json;
+
+use crate::outgoing_message::OutgoingMessageSender;
+use crate::outgoing_message::OutgoingNotification;
+
+const SESSION_RESOURCE_URI_PREFIX: &str = "codex://sessions/";
+const PREVIEW_MAX_CHARS: usize = 80;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SessionResourceKind {
+    Transcript,
+    Diff,
+    FinalMessage,
+}
+
+impl SessionResourceKind {
+    const ALL: [Self; 3] = [Self::Transcript, Self::Diff, Self::FinalMessage];
+
+    fn path_segment(self) -> &'static str {
+        match self {
+            Self::Transcript => "transcript",
+            Self::Diff => "diff",
+            Self::FinalMessage => "final-message",
+        }
+    }
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Transcript => "Transcript",
+            Self::Diff => "Diff",
+            Self::FinalMessage => "Final message",
+        }
+    }
+
+    pub(crate) fn mime_type(self) -> &'static str {
+        match self {
+            Self::Transcript => "text/markdown",
+            Self::Diff => "text/x-diff",
+            Self::FinalMessage => "text/plain",
+        }
+    }
+}
+
+pub(crate) fn session_resource_uri(thread_id: ThreadId, kind: SessionResourceKind) -> String {
+    format!(
+        "{SESSION_RESOURCE_URI_PREFIX}{thread_id}/{}",
+        kind.path_segment()
+    )
+}
+
+pub(crate) fn parse_session_resource_uri(uri: &str) -> Option<(ThreadId, SessionResourceKind)> {
+    let (thread_id, segment) = uri
+        .strip_prefix(SESSION_RESOURCE_URI_PREFIX)?
+        .split_once('/')?;
+    let kind = SessionResourceKind::ALL
+        .into_iter()
+        .find(|kind| kind.path_segment() == segment)?;
+    Some((ThreadId::from_string(thread_id).ok()?, kind))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) enum SessionStatus {
+    /// A `codex` or `codex-reply` call is in progress.
+    Running,
+    /// Loaded in this server and waiting for `codex-reply`.
+    Idle,
+    /// Persisted by an earlier server process.
+    Stored,
+}
+
+/// One entry of the `codex-list-sessions` result.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SessionSummary {
+    pub thread_id: ThreadId,
+    pub status: SessionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+    pub resources: Vec<String>,
+}
+
+impl SessionSummary {
+    fn new(thread_id: ThreadId, status: SessionStatus, preview: Option<String>) -> Self {
+        let resources = SessionResourceKind::ALL
+            .into_iter()
+            .filter(|kind| status != SessionStatus::Stored || *kind != SessionResourceKind::Diff)
+            .map(|kind| session_resource_uri(thread_id, kind))
+            .collect();
+        Self {
+            thread_id,
+            status,
+            preview,
+            resources,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Speaker {
+    User,
+    Codex,
+}
+
+#[derive(Debug, Default)]
+struct SessionTranscript {
+    entries: Vec<(Speaker, String)>,
+}
+
+impl SessionTranscript {
+    fn push(&mut self, speaker: Speaker, text: &str) {
+        if !text.trim().is_empty() {
+            self.entries.push((speaker, text.to_string()));
+        }
+    }
+
+    fn last_codex_message(&self) -> Option<String> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(speaker, _)| *speaker == Speaker::Codex)
+            .map(|(_, text)| text.clone())
+    }
+
+    fn render(&self) -> String {
+        self.entries
+            .iter()
+            .map(|(speaker, text)| {
+                let heading = match speaker {
+                    Speaker::User => "User",
+                    Speaker::Codex => "Codex",
+                };
+                format!("## {heading}\n\n{}\n", text.trim_end())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[derive(Debug)]
+struct LiveSession {
+    running: bool,
+    transcript: SessionTranscript,
+    diff: Option<String>,
+    final_message: Option<String>,
+}
+
+#[derive(Default)]
+struct SessionResourcesState {
+    sessions: HashMap<ThreadId, LiveSession>,
+    subscriptions: HashSet<String>,
+}
+
+/// Live session state and resource subscriptions for one MCP connection.
+pub(crate) struct SessionResources {
+    state: StdMutex<SessionResourcesState>,
+    outgoing: Arc<OutgoingMessageSender>,
+}
+
+impl SessionResources {
+    pub(crate) fn new(outgoing: Arc<OutgoingMessageSender>) -> Self {
+        Self {
+            state: StdMutex::new(SessionResourcesState::default()),
+            outgoing,
+        }
+    }
+
+    /// Records a prompt sent to a session by `codex` or `codex-reply`.
+    pub(crate) async fn record_prompt(&self, thread_id: ThreadId, prompt: &str) {
+        let (is_new, updated) = {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            let is_new = !state.sessions.contains_key(&thread_id);
+            let session = state
+                .sessions
+                .entry(thread_id)
+                .or_insert_with(|| LiveSession {
+                    running: true,
+                    transcript: SessionTranscript::default(),
+                    diff: None,
+                    final_message: None,
+                });
+            session.running = true;
+            session.transcript.push(Speaker::User, prompt);
+            (
+                is_new,
+                state.subscribed(thread_id, &[SessionResourceKind::Transcript]),
+            )
+        };
+        if is_new {
+            self.send_notification("notifications/resources/list_changed", /*params*/ None)
+                .await;
+        }
+        self.notify_updated(updated).await;
+    }
+
+    /// Folds a session event into the live resources and notifies subscribers
+    /// of the resources it changed.
+    pub(crate) async fn observe_event(&self, thread_id: ThreadId, msg: &EventMsg) {
+        let updated = {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            let Some(session) = state.sessions.get_mut(&thread_id) else {
+                return;
+            };
+            let changed: &[SessionResourceKind] = match msg {
+                EventMsg::AgentMessage(AgentMessageEvent { message, .. }) => {
+                    session.transcript.push(Speaker::Codex, message);
+                    &[SessionResourceKind::Transcript]
+                }
+                EventMsg::TurnDiff(TurnDiffEvent { unified_diff }) => {
+                    session.diff = Some(unified_diff.clone());
+                    &[SessionResourceKind::Diff]
+                }
+                EventMsg::TurnComplete(TurnCompleteEvent {
+                    last_agent_message, ..
+                }) => {
+                    session.running = false;
+                    session.final_message = last_agent_message.clone();
+                    &[SessionResourceKind::FinalMessage]
+                }
+                EventMsg::Error(_) | EventMsg::TurnAborted(_) => {
+                    session.running = false;
+                    &[]
+                }
+                _ => &[],
+            };
+            state.subscribed(thread_id, changed)
+        };
+        self.notify_updated(updated).await;
+    }
+
+    pub(crate) fn subscribe(&self, uri: String) {
+        if let Ok(mut state) = self.state.lock() {
+            state.subscriptions.insert(uri);
+        }
+    }
+
+    pub(crate) fn unsubscribe(&self, uri: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.subscriptions.remove(uri);
+        }
+    }
+
+    /// Lists live sessions first, then sessions persisted by earlier runs.
+    pub(crate) async fn list_sessions(
+        &self,
+        config: &Config,
+        state_db: Option<StateDbHandle>,
+        limit: usize,
+    ) -> Vec<SessionSummary> {
+        let mut summaries = self.live_summaries();
+        if summaries.len() >= limit {
+            summaries.truncate(limit);
+            return summaries;
+        }
+        let page = RolloutRecorder::list_threads(
+            state_db,
+            config,
+            limit,
+            /*cursor*/ None,
+            ThreadSortKey::UpdatedAt,
+            SortDirection::Desc,
+            &[SessionSource::Mcp],
+            /*model_providers*/ None,
+            /*cwd_filters*/ None,
+            &config.model_provider_id,
+            /*search_term*/ None,
+        )
+        .await;
+        let items = match page {
+            Ok(page) => page.items,
+            Err(err) => {
+                tracing::warn!("failed to list stored Codex sessions: {err}");
+                Vec::new()
+            }
+        };
+        let live_ids = summaries
+            .iter()
+            .map(|summary| summary.thread_id)
+            .collect::<HashSet<_>>();
+        let stored = items.into_iter().filter_map(|item| {
+            let thread_id = item.thread_id?;
+            (!live_ids.contains(&thread_id)).then(|| {
+                SessionSummary::new(
+                    thread_id,
+                    SessionStatus::Stored,
+                    item.preview.or(item.first_user_message).map(preview),
+                )
+            })
+        });
+        summaries.extend(stored);
+        summaries.truncate(limit);
+        summaries
+    }
+
+    /// Reads one session resource, preferring live state over the rollout.
+    pub(crate) async fn read(
+        &self,
+        config: &Config,
+        state_db: Option<&StateDbHandle>,
+        thread_id: ThreadId,
+        kind: SessionResourceKind,
+    ) -> Result<String, String> {
+        if let Some(text) = self.read_live(thread_id, kind) {
+            return Ok(text);
+        }
+        if kind == SessionResourceKind::Diff {
+            return Err(format!(
+                "the diff is only available for sessions running in this server: {thread_id}"
+            ));
+        }
+        let path = find_thread_path_by_id_str(
+            &config.codex_home,
+            &thread_id.to_string(),
+            state_db.map(Arc::as_ref),
+        )
+        .await
+        .map_err(|err| format!("failed to locate session {thread_id}: {err}"))?
+        .ok_or_else(|| format!("session not found: {thread_id}"))?;
+        let history = RolloutRecorder::get_rollout_history(&path)
+            .await
+            .map_err(|err| format!("failed to read session {thread_id}: {err}"))?;
+        let mut transcript = SessionTranscript::default();
+        for item in history.get_rollout_items() {
+            match item {
+                RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                    message, ..
+                })) => transcript.push(Speaker::User, message),
+                RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                    message,
+                    ..
+                })) => transcript.push(Speaker::Codex, message),
+                _ => {}
+            }
+        }
+        Ok(match kind {
+            SessionResourceKind::Transcript => transcript.render(),
+            SessionResourceKind::FinalMessage | SessionResourceKind::Diff => {
+                transcript.last_codex_message().unwrap_or_default()
+            }
+        })
+    }
+
+    fn read_live(&self, thread_id: ThreadId, kind: SessionResourceKind) -> Option<String> {
+        let state = self.state.lock().ok()?;
+        let session = state.sessions.get(&thread_id)?;
+        Some(match kind {
+            SessionResourceKind::Transcript => session.transcript.render(),
+            SessionResourceKind::Diff => session.diff.clone().unwrap_or_default(),
+            SessionResourceKind::FinalMessage => session.final_message.clone().unwrap_or_default(),
+        })
+    }
+
+    fn live_summaries(&self) -> Vec<SessionSummary> {
+        let Ok(state) = self.state.lock() else {
+            return Vec::new();
+        };
+        let mut summaries = state
+            .sessions
+            .iter()
+            .map(|(thread_id, session)| {
+                let status = if session.running {
+                    SessionStatus::Running
+                } else {
+                    SessionStatus::Idle
+                };
+                let first_prompt = session
+                    .transcript
+                    .entries
+                    .first()
+                    .map(|(_, text)| preview(text.clone()));
+                SessionSummary::new(*thread_id, status, first_prompt)
+            })
+            .collect::<Vec<_>>();
+        // Thread ids are UUIDv7, so their string order is creation order.
+        summaries.sort_by_key(|summary| std::cmp::Reverse(summary.thread_id.to_string()));
+        summaries
+    }
+
+    async fn notify_updated(&self, uris: Vec<String>) {
+        for uri in uris {
+            self.send_notification(
+                "notifications/resources/updated",
+                Some(json!({ "uri": uri })),
+            )
+            .await;
+        }
+    }
+
+    async fn send_notification(&self, method: &str, params: Option<serde_json::Value>) {
+        self.outgoing
+            .send_notification(OutgoingNotification {
+                method: method.to_string(),
+                params,
+            })
+            .await;
+    }
+}
+
+impl SessionResourcesState {
+    fn subscribed(&self, thread_id: ThreadId, kinds: &[SessionResourceKind]) -> Vec<String> {
+        kinds
+            .iter()
+            .map(|kind| session_resource_uri(thread_id, *kind))
+            .filter(|uri| self.subscriptions.contains(uri))
+            .collect()
+    }
+}
+
+/// Builds the `resources/list` entries for the given sessions.
+pub(crate) fn session_resource_descriptors(sessions: &[SessionSummary]) -> Vec<serde_json::Value> {
+    sessions
+        .iter()
+        .flat_map(|session| {
+            session.resources.iter().filter_map(|uri| {
+                let (_, kind) = parse_session_resource_uri(uri)?;
+                let label = session.preview.as_deref().unwrap_or("Codex session");
+                Some(json!({
+                    "uri": uri,
+                    "name": format!("{} {}", session.thread_id, kind.path_segment()),
+                    "title": format!("{}: {label}", kind.title()),
+                    "mimeType": kind.mime_type(),
+                }))
+            })
+        })
+        .collect()
+}
+
+fn preview(text: String) -> String {
+    let first_line = text.lines().next().unwrap_or_default().trim();
+    if first_line.chars().count() <= PREVIEW_MAX_CHARS {
+        return first_line.to_string();
+    }
+    let mut truncated = first_line
+        .chars()
+        .take(PREVIEW_MAX_CHARS)
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn session_resource_uris_round_trip() {
+        let thread_id = ThreadId::new();
+        for kind in SessionResourceKind::ALL {
+            assert_eq!(
+                parse_session_resource_uri(&session_resource_uri(thread_id, kind)),
+                Some((thread_id, kind))
+            );
+        }
+        assert_eq!(
+            parse_session_resource_uri(&format!("codex://sessions/{thread_id}/unknown")),
+            None
+        );
+    }
+
+    #[test]
+    fn stored_sessions_do_not_advertise_diffs() {
+        let thread_id = ThreadId::new();
+        assert_eq!(
+            SessionSummary::new(thread_id, SessionStatus::Stored, None).resources,
+            vec![
+                session_resource_uri(thread_id, SessionResourceKind::Transcript),
+                session_resource_uri(thread_id, SessionResourceKind::FinalMessage),
+            ]
+        );
+    }
+
+    #[test]
+    fn transcript_renders_speakers_in_order() {
+        let mut transcript = SessionTranscript::default();
+        transcript.push(Speaker::User, "fix the build");
+        transcript.push(Speaker::Codex, "   ");
+        transcript.push(Speaker::Codex, "Done.\n");
+
+        assert_eq!(
+            transcript.render(),
+            "## User\n\nfix the build\n\n## Codex\n\nDone.\n"
+        );
+        assert_eq!(transcript.last_codex_message(), Some("Done.\n".to_string()));
+    }
+}
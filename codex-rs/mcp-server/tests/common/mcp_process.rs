@@ -10,6 +10,7 @@ use tokio::process::ChildStdin;
 use tokio::process::ChildStdout;
 
 use anyhow::Context;
+use codex_mcp_server::CodexListSessionsParam;
 use codex_mcp_server::CodexToolCallParam;
 use codex_terminal_detection::user_agent;
 
@@ -160,6 +161,10 @@ impl McpProcess {
             result,
             json!({
                 "capabilities": {
+                    "resources": {
+                        "listChanged": true,
+                        "subscribe": true
+                    },
                     "tools": {
                         "listChanged": true
                     },
@@ -203,6 +208,49 @@ impl McpProcess {
         .await
     }
 
+    /// Sends a `codex` tool call whose `_meta.progressToken` asks the server
+    /// for `notifications/progress`.
+    pub async fn send_codex_tool_call_with_progress_token(
+        &mut self,
+        params: CodexToolCallParam,
+        progress_token: serde_json::Value,
+    ) -> anyhow::Result<i64> {
+        let mut request_params =
+            serde_json::to_value(CallToolRequestParams::new("codex").with_arguments(
+                match serde_json::to_value(params)? {
+                    serde_json::Value::Object(map) => map,
+                    _ => unreachable!("params serialize to object"),
+                },
+            ))?;
+        request_params["_meta"] = json!({ "progressToken": progress_token });
+        self.send_request("tools/call", Some(request_params)).await
+    }
+
+    pub async fn send_list_sessions_tool_call(
+        &mut self,
+        params: CodexListSessionsParam,
+    ) -> anyhow::Result<i64> {
+        let list_sessions_params = CallToolRequestParams::new("codex-list-sessions")
+            .with_arguments(match serde_json::to_value(params)? {
+                serde_json::Value::Object(map) => map,
+                _ => unreachable!("params serialize to object"),
+            });
+        self.send_request(
+            "tools/call",
+            Some(serde_json::to_value(list_sessions_params)?),
+        )
+        .await
+    }
+
+    pub async fn send_list_resources_request(&mut self) -> anyhow::Result<i64> {
+        self.send_request("resources/list", /*params*/ None).await
+    }
+
+    pub async fn send_read_resource_request(&mut self, uri: &str) -> anyhow::Result<i64> {
+        self.send_request("resources/read", Some(json!({ "uri": uri })))
+            .await
+    }
+
     async fn send_request(
         &mut self,
         method: &str,
@@ -308,6 +356,36 @@ impl McpProcess {
         }
     }
 
+    /// Reads messages until a notification with the given method is observed,
+    /// skipping other notifications.
+    pub async fn read_stream_until_notification_message(
+        &mut self,
+        method: &str,
+    ) -> anyhow::Result<JsonRpcNotification<CustomNotification>> {
+        eprintln!("in read_stream_until_notification_message({method})");
+
+        loop {
+            let message = self.read_jsonrpc_message().await?;
+            match message {
+                JsonRpcMessage::Notification(notification) => {
+                    if notification.notification.method == method {
+                        return Ok(notification);
+                    }
+                    eprintln!("ignoring notification: {notification:?}");
+                }
+                JsonRpcMessage::Request(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Request: {message:?}");
+                }
+                JsonRpcMessage::Error(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Error: {message:?}");
+                }
+                JsonRpcMessage::Response(_) => {
+                    anyhow::bail!("unexpected JSONRPCMessage::Response: {message:?}");
+                }
+            }
+        }
+    }
+
     /// Reads notifications until a legacy TurnComplete event is observed:
     /// Method "codex/event" with params.msg.type == "task_complete".
     pub async fn read_stream_until_legacy_task_complete_notification(
@@ -0,0 +1,254 @@
+use std::env;
+use std::path::Path;
+use std::time::Duration;
+
+use codex_core::spawn::CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR;
+use codex_mcp_server::CodexListSessionsParam;
+use codex_mcp_server::CodexToolCallParam;
+use pretty_assertions::assert_eq;
+use rmcp::model::RequestId;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::Instant;
+use tokio::time::timeout;
+
+use mcp_test_support::McpProcess;
+use mcp_test_support::create_final_assistant_message_sse_response;
+use mcp_test_support::create_mock_responses_server;
+use mcp_test_support::create_shell_command_sse_response;
+
+// Windows CI can spend tens of seconds in session startup before the first
+// mock model request is sent.
+const DEFAULT_READ_TIMEOUT: Duration = Duration::from_secs(60);
+
+const PROMPT: &str = "say hello";
+const FINAL_MESSAGE: &str = "Said hello!";
+
+/// Drives a `codex` tool call that carries a progress token, then reads the
+/// session back through `resources/list`, `resources/read`, and
+/// `codex-list-sessions`, first from the server that ran it and then from a
+/// second server that only sees the persisted rollout.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn test_session_resources_and_progress_notifications() {
+    if env::var(CODEX_SANDBOX_NETWORK_DISABLED_ENV_VAR).is_ok() {
+        println!(
+            "Skipping test because it cannot execute when network is disabled in a Codex sandbox."
+        );
+        return;
+    }
+
+    session_resources_and_progress_notifications()
+        .await
+        .expect("session resources and progress should be served over MCP");
+}
+
+async fn session_resources_and_progress_notifications() -> anyhow::Result<()> {
+    let workdir = TempDir::new()?;
+    let shell_command = vec!["echo".to_string(), "hello".to_string()];
+    let server = create_mock_responses_server(vec![
+        create_shell_command_sse_response(
+            shell_command,
+            Some(workdir.path()),
+            Some(10_000),
+            "call-echo",
+        )?,
+        create_final_assistant_message_sse_response(FINAL_MESSAGE)?,
+    ])
+    .await;
+    let codex_home = TempDir::new()?;
+    create_config_toml(codex_home.path(), &server.uri())?;
+    let mut mcp_process = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp_process.initialize()).await??;
+
+    let codex_request_id = mcp_process
+        .send_codex_tool_call_with_progress_token(
+            CodexToolCallParam {
+                prompt: PROMPT.to_string(),
+                cwd: Some(workdir.path().to_string_lossy().into_owned()),
+                ..Default::default()
+            },
+            json!("progress-1"),
+        )
+        .await?;
+
+    let progress = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_notification_message("notifications/progress"),
+    )
+    .await??;
+    let params = progress
+        .notification
+        .params
+        .expect("progress notification should have params");
+    assert_eq!(params["progressToken"], json!("progress-1"));
+    assert_eq!(params["progress"], json!(1));
+    let message = params["message"]
+        .as_str()
+        .expect("progress notification should have a message");
+    assert!(
+        message.starts_with("Ran `") && message.contains("echo hello"),
+        "unexpected progress message: {message}"
+    );
+    assert!(
+        message.ends_with("(exit code 0)"),
+        "unexpected progress message: {message}"
+    );
+
+    let codex_response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(codex_request_id)),
+    )
+    .await??;
+    let thread_id = codex_response.result["structuredContent"]["threadId"]
+        .as_str()
+        .expect("codex response should include a thread id")
+        .to_string();
+    let transcript_uri = format!("codex://sessions/{thread_id}/transcript");
+    let diff_uri = format!("codex://sessions/{thread_id}/diff");
+    let final_message_uri = format!("codex://sessions/{thread_id}/final-message");
+
+    // The session is live in this server, so its diff is advertised too.
+    let resources = list_resources(&mut mcp_process).await?;
+    assert_eq!(
+        resources
+            .iter()
+            .map(|resource| resource["uri"].clone())
+            .collect::<Vec<_>>(),
+        vec![
+            json!(transcript_uri),
+            json!(diff_uri),
+            json!(final_message_uri),
+        ]
+    );
+    assert_eq!(resources[0]["mimeType"], json!("text/markdown"));
+    assert_eq!(
+        resources[0]["title"],
+        json!(format!("Transcript: {PROMPT}"))
+    );
+
+    assert_eq!(
+        read_resource(&mut mcp_process, &transcript_uri).await?,
+        json!([{
+            "uri": transcript_uri,
+            "mimeType": "text/markdown",
+            "text": format!("## User\n\n{PROMPT}\n\n## Codex\n\n{FINAL_MESSAGE}\n"),
+        }])
+    );
+    assert_eq!(
+        read_resource(&mut mcp_process, &final_message_uri).await?,
+        json!([{
+            "uri": final_message_uri,
+            "mimeType": "text/plain",
+            "text": FINAL_MESSAGE,
+        }])
+    );
+
+    assert_eq!(
+        list_sessions(&mut mcp_process).await?,
+        json!([{
+            "threadId": thread_id,
+            "status": "idle",
+            "preview": PROMPT,
+            "resources": [transcript_uri, diff_uri, final_message_uri],
+        }])
+    );
+
+    // A second server on the same CODEX_HOME only has the rollout. Keep the
+    // first one alive so its rollout writer can finish flushing.
+    let mut restarted = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, restarted.initialize()).await??;
+
+    let deadline = Instant::now() + DEFAULT_READ_TIMEOUT;
+    let stored = loop {
+        let sessions = list_sessions(&mut restarted).await?;
+        if let Some(session) = sessions
+            .as_array()
+            .and_then(|sessions| sessions.iter().find(|s| s["threadId"] == json!(thread_id)))
+        {
+            break session.clone();
+        }
+        anyhow::ensure!(
+            Instant::now() < deadline,
+            "stored session {thread_id} was never listed: {sessions}"
+        );
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+    assert_eq!(stored["status"], json!("stored"));
+    assert_eq!(
+        stored["resources"],
+        json!([transcript_uri, final_message_uri])
+    );
+
+    assert_eq!(
+        read_resource(&mut restarted, &final_message_uri).await?,
+        json!([{
+            "uri": final_message_uri,
+            "mimeType": "text/plain",
+            "text": FINAL_MESSAGE,
+        }])
+    );
+
+    Ok(())
+}
+
+async fn list_resources(mcp_process: &mut McpProcess) -> anyhow::Result<Vec<serde_json::Value>> {
+    let request_id = mcp_process.send_list_resources_request().await?;
+    let response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(request_id)),
+    )
+    .await??;
+    Ok(response.result["resources"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+async fn read_resource(
+    mcp_process: &mut McpProcess,
+    uri: &str,
+) -> anyhow::Result<serde_json::Value> {
+    let request_id = mcp_process.send_read_resource_request(uri).await?;
+    let response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(request_id)),
+    )
+    .await??;
+    Ok(response.result["contents"].clone())
+}
+
+async fn list_sessions(mcp_process: &mut McpProcess) -> anyhow::Result<serde_json::Value> {
+    let request_id = mcp_process
+        .send_list_sessions_tool_call(CodexListSessionsParam::default())
+        .await?;
+    let response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(request_id)),
+    )
+    .await??;
+    Ok(response.result["structuredContent"]["sessions"].clone())
+}
+
+/// Create a Codex config that uses the mock server as the model provider.
+fn create_config_toml(codex_home: &Path, server_uri: &str) -> std::io::Result<()> {
+    let config_toml = codex_home.join("config.toml");
+    std::fs::write(
+        config_toml,
+        format!(
+            r#"
+model = "mock-model"
+approval_policy = "never"
+sandbox_mode = "danger-full-access"
+
+model_provider = "mock_provider"
+
+[model_providers.mock_provider]
+name = "Mock provider for test"
+base_url = "{server_uri}/v1"
+wire_api = "responses"
+request_max_retries = 0
+stream_max_retries = 0
+"#
+        ),
+    )
+}
@@ -14,6 +14,7 @@ use codex_login::auth::AgentIdentityAuth;
 use codex_login::auth::AgentIdentityAuthError;
 use codex_login::auth::AgentIdentityAuthPolicy;
 use codex_model_provider_info::ModelProviderInfo;
+use codex_model_provider_info::WireApi;
 use codex_protocol::error::CodexErr;
 use codex_protocol::protocol::SessionSource;
 use http::HeaderMap;
@@ -153,6 +154,21 @@ impl AuthProvider for AuthManagerAuthProvider {
 // Some providers are meant to send no auth headers. Examples include local OSS
 // providers and custom test providers with `requires_openai_auth = false`.
 #[derive(Clone, Debug)]
+/// The Anthropic Messages API takes API keys in `x-api-key` instead of a
+/// bearer token.
+#[derive(Clone)]
+struct AnthropicApiKeyAuthProvider {
+    api_key: String,
+}
+
+impl AuthProvider for AnthropicApiKeyAuthProvider {
+    fn add_auth_headers(&self, headers: &mut HeaderMap) {
+        if let Ok(header) = HeaderValue::from_str(&self.api_key) {
+            let _ = headers.insert("x-api-key", header);
+        }
+    }
+}
+
 struct UnauthenticatedAuthProvider;
 
 impl AuthProvider for UnauthenticatedAuthProvider {
@@ -186,6 +202,12 @@ pub(crate) fn resolve_provider_auth(
         ));
     }
 
+    if provider.wire_api == WireApi::Anthropic
+        && let Some(api_key) = provider.api_key()?
+    {
+        return Ok(Arc::new(AnthropicApiKeyAuthProvider { api_key }));
+    }
+
     if let Some(auth) = bearer_auth_for_provider(provider)? {
         return Ok(Arc::new(auth));
     }
@@ -320,7 +342,6 @@ mod tests {
     use codex_login::auth::AgentIdentityAuthRecord;
     use codex_login::auth::BedrockApiKeyAuth;
     use codex_login::auth::login_with_chatgpt_auth_tokens;
-    use codex_model_provider_info::WireApi;
     use codex_model_provider_info::create_oss_provider_with_base_url;
     use codex_protocol::account::PlanType;
     use http::header::AUTHORIZATION;
@@ -462,6 +483,17 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn anthropic_api_key_auth_uses_x_api_key_header() {
+        let auth = AnthropicApiKeyAuthProvider {
+            api_key: "sk-ant-test".to_string(),
+        };
+
+        let mut expected = HeaderMap::new();
+        expected.insert("x-api-key", HeaderValue::from_static("sk-ant-test"));
+        assert_eq!(auth.to_auth_headers(), expected);
+    }
+
     #[test]
     fn openai_provider_rejects_bedrock_api_key_auth() {
         let provider = ModelProviderInfo::create_openai_provider(/*base_url*/ None);
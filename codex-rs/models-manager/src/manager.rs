@@ -628,6 +628,9 @@ pub(crate) fn construct_model_info_from_candidates(
         ModelInfo {
             slug: model.to_string(),
             used_fallback_model_metadata: false,
+            tokenizer: remote
+                .tokenizer
+                .or_else(|| model_info::tokenizer_for_slug(model)),
             ..remote
         }
     } else {
@@ -5,6 +5,7 @@ use codex_protocol::openai_models::ModelInfo;
 use codex_protocol::openai_models::ModelInstructionsVariables;
 use codex_protocol::openai_models::ModelMessages;
 use codex_protocol::openai_models::ModelVisibility;
+use codex_protocol::openai_models::TokenizerEncoding;
 use codex_protocol::openai_models::TruncationMode;
 use codex_protocol::openai_models::TruncationPolicyConfig;
 use codex_protocol::openai_models::WebSearchToolType;
@@ -21,6 +22,10 @@ const LOCAL_FRIENDLY_TEMPLATE: &str =
 const LOCAL_PRAGMATIC_TEMPLATE: &str = "You are a deeply pragmatic, effective software engineer.";
 const PERSONALITY_PLACEHOLDER: &str = "{{ personality }}";
 const PERSONALITY_SECTION_HEADER: &str = "# Personality";
+const O200K_BASE_SLUG_PREFIXES: &[&str] = &[
+    "gpt-5", "gpt-4o", "gpt-4.1", "gpt-4.5", "o1", "o3", "o4", "codex-",
+];
+const CL100K_BASE_SLUG_PREFIXES: &[&str] = &["gpt-4", "gpt-3.5"];
 
 pub fn with_config_overrides(mut model: ModelInfo, config: &ModelsManagerConfig) -> ModelInfo {
     if let Some(context_window) = config.model_context_window {
@@ -164,6 +169,28 @@ pub fn model_info_from_slug(slug: &str) -> ModelInfo {
         auto_review_model_override: None,
         tool_mode: None,
         multi_agent_version: None,
+        tokenizer: tokenizer_for_slug(slug),
+    }
+}
+
+/// Returns the tokenizer for well-known OpenAI model families, ignoring a
+/// leading provider namespace such as `openai/`.
+///
+/// Models outside these families keep byte-length token estimates.
+pub fn tokenizer_for_slug(slug: &str) -> Option<TokenizerEncoding> {
+    let slug = slug.rsplit_once('/').map_or(slug, |(_, suffix)| suffix);
+    if O200K_BASE_SLUG_PREFIXES
+        .iter()
+        .any(|prefix| slug.starts_with(prefix))
+    {
+        Some(TokenizerEncoding::O200kBase)
+    } else if CL100K_BASE_SLUG_PREFIXES
+        .iter()
+        .any(|prefix| slug.starts_with(prefix))
+    {
+        Some(TokenizerEncoding::Cl100kBase)
+    } else {
+        None
     }
 }
 
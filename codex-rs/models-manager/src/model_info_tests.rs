@@ -254,3 +254,21 @@ fn model_context_window_uses_model_value_without_override() {
 
     assert_eq!(updated, model);
 }
+
+#[test]
+fn tokenizer_for_slug_resolves_known_model_families() {
+    assert_eq!(
+        tokenizer_for_slug("gpt-5.4-mini"),
+        Some(TokenizerEncoding::O200kBase)
+    );
+    assert_eq!(
+        tokenizer_for_slug("openai/gpt-4o"),
+        Some(TokenizerEncoding::O200kBase)
+    );
+    assert_eq!(
+        tokenizer_for_slug("gpt-4-turbo"),
+        Some(TokenizerEncoding::Cl100kBase)
+    );
+    assert_eq!(tokenizer_for_slug("llama3.1:8b"), None);
+    assert_eq!(model_info_from_slug("unknown-model").tokenizer, None);
+}
@@ -102,6 +102,20 @@ pub enum OtelExporter {
         protocol: OtelHttpProtocol,
         tls: Option<OtelTlsConfig>,
     },
+    /// Appends OTLP-JSON export requests to rotating per-signal files under
+    /// `directory`, so telemetry can be inspected without a collector.
+    OtlpFile {
+        directory: PathBuf,
+        max_bytes: u64,
+        max_files: usize,
+    },
+    /// Serves collected metrics in the Prometheus text format on
+    /// `listen_addr`.
+    ///
+    /// This is intended for metrics only.
+    Prometheus {
+        listen_addr: String,
+    },
 }
 
 #[cfg(test)]
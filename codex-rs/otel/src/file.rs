@@ -0,0 +1,328 @@
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::transform::common::tonic::ResourceAttributesWithSchema;
+use opentelemetry_proto::transform::logs::tonic::group_logs_by_resource_and_scope;
+use opentelemetry_proto::transform::trace::tonic::group_spans_by_resource_and_scope;
+use opentelemetry_sdk::Resource;
+use opentelemetry_sdk::error::OTelSdkError;
+use opentelemetry_sdk::error::OTelSdkResult;
+use opentelemetry_sdk::logs::LogBatch;
+use opentelemetry_sdk::logs::LogExporter;
+use opentelemetry_sdk::metrics::Temporality;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
+use opentelemetry_sdk::trace::SpanData;
+use opentelemetry_sdk::trace::SpanExporter;
+use serde::Serialize;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Size at which a signal file is rotated when the config does not say otherwise.
+pub const DEFAULT_OTLP_FILE_MAX_BYTES: u64 = 10 * 1024 * 1024;
+/// Number of rotated files kept per signal when the config does not say otherwise.
+pub const DEFAULT_OTLP_FILE_MAX_FILES: usize = 5;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum FileSignal {
+    Logs,
+    Traces,
+    Metrics,
+}
+
+impl FileSignal {
+    fn file_name(self) -> &'static str {
+        match self {
+            Self::Logs => "logs.jsonl",
+            Self::Traces => "traces.jsonl",
+            Self::Metrics => "metrics.jsonl",
+        }
+    }
+}
+
+/// Append-only JSON lines file that rolls over to `<name>.1`, `<name>.2`, ...
+/// once the next line would push it past `max_bytes`.
+#[derive(Debug)]
+pub(crate) struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    state: Mutex<Option<OpenFile>>,
+}
+
+#[derive(Debug)]
+struct OpenFile {
+    file: File,
+    len: u64,
+}
+
+impl RotatingFile {
+    pub(crate) fn new(
+        directory: &Path,
+        signal: FileSignal,
+        max_bytes: u64,
+        max_files: usize,
+    ) -> Self {
+        Self {
+            path: directory.join(signal.file_name()),
+            max_bytes,
+            max_files,
+            state: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn write_json_line(&self, value: &impl Serialize) -> OTelSdkResult {
+        let mut line = serde_json::to_vec(value)
+            .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))?;
+        line.push(b'\n');
+        self.write_line(&line)
+            .map_err(|err| OTelSdkError::InternalFailure(err.to_string()))
+    }
+
+    fn write_line(&self, line: &[u8]) -> io::Result<()> {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        if state.is_none() {
+            *state = Some(self.open()?);
+        }
+        let needs_rotation = state.as_ref().is_some_and(|open| {
+            open.len > 0 && open.len.saturating_add(line.len() as u64) > self.max_bytes
+        });
+        if needs_rotation {
+            *state = None;
+            self.rotate()?;
+            *state = Some(self.open()?);
+        }
+        let Some(open) = state.as_mut() else {
+            return Ok(());
+        };
+        open.file.write_all(line)?;
+        open.len = open.len.saturating_add(line.len() as u64);
+        Ok(())
+    }
+
+    fn open(&self) -> io::Result<OpenFile> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        let len = file.metadata()?.len();
+        Ok(OpenFile { file, len })
+    }
+
+    fn rotate(&self) -> io::Result<()> {
+        if self.max_files == 0 {
+            return remove_if_exists(&self.path);
+        }
+        remove_if_exists(&self.rotated_path(self.max_files))?;
+        for index in (1..self.max_files).rev() {
+            rename_if_exists(&self.rotated_path(index), &self.rotated_path(index + 1))?;
+        }
+        rename_if_exists(&self.path, &self.rotated_path(1))
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{index}"));
+        PathBuf::from(path)
+    }
+
+    fn flush(&self) -> OTelSdkResult {
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match state.as_mut() {
+            Some(open) => open
+                .file
+                .flush()
+                .map_err(|err| OTelSdkError::InternalFailure(err.to_string())),
+            None => Ok(()),
+        }
+    }
+}
+
+fn remove_if_exists(path: &Path) -> io::Result<()> {
+    match fs::remove_file(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+fn rename_if_exists(from: &Path, to: &Path) -> io::Result<()> {
+    match fs::rename(from, to) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+        result => result,
+    }
+}
+
+/// Writes each span batch as one `ExportTraceServiceRequest` JSON line.
+#[derive(Debug)]
+pub(crate) struct FileSpanExporter {
+    file: RotatingFile,
+    resource: ResourceAttributesWithSchema,
+}
+
+impl FileSpanExporter {
+    pub(crate) fn new(file: RotatingFile) -> Self {
+        Self {
+            file,
+            resource: ResourceAttributesWithSchema::default(),
+        }
+    }
+}
+
+impl SpanExporter for FileSpanExporter {
+    async fn export(&self, batch: Vec<SpanData>) -> OTelSdkResult {
+        let request = ExportTraceServiceRequest {
+            resource_spans: group_spans_by_resource_and_scope(batch, &self.resource),
+        };
+        self.file.write_json_line(&request)
+    }
+
+    fn force_flush(&mut self) -> OTelSdkResult {
+        self.file.flush()
+    }
+
+    fn shutdown_with_timeout(&mut self, _timeout: Duration) -> OTelSdkResult {
+        self.file.flush()
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.resource = resource.into();
+    }
+}
+
+/// Writes each log batch as one `ExportLogsServiceRequest` JSON line.
+#[derive(Debug)]
+pub(crate) struct FileLogExporter {
+    file: RotatingFile,
+    resource: ResourceAttributesWithSchema,
+}
+
+impl FileLogExporter {
+    pub(crate) fn new(file: RotatingFile) -> Self {
+        Self {
+            file,
+            resource: ResourceAttributesWithSchema::default(),
+        }
+    }
+}
+
+impl LogExporter for FileLogExporter {
+    async fn export(&self, batch: LogBatch<'_>) -> OTelSdkResult {
+        let request = ExportLogsServiceRequest {
+            resource_logs: group_logs_by_resource_and_scope(batch, &self.resource),
+        };
+        self.file.write_json_line(&request)
+    }
+
+    fn shutdown_with_timeout(&self, _timeout: Duration) -> OTelSdkResult {
+        self.file.flush()
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.resource = resource.into();
+    }
+}
+
+/// Writes each periodic metrics collection as one `ExportMetricsServiceRequest`
+/// JSON line.
+#[derive(Debug)]
+pub(crate) struct FileMetricExporter {
+    file: RotatingFile,
+    temporality: Temporality,
+}
+
+impl FileMetricExporter {
+    pub(crate) fn new(file: RotatingFile, temporality: Temporality) -> Self {
+        Self { file, temporality }
+    }
+}
+
+impl PushMetricExporter for FileMetricExporter {
+    async fn export(&self, metrics: &ResourceMetrics) -> OTelSdkResult {
+        let request = ExportMetricsServiceRequest::from(metrics);
+        if request
+            .resource_metrics
+            .iter()
+            .all(|resource| resource.scope_metrics.is_empty())
+        {
+            return Ok(());
+        }
+        self.file.write_json_line(&request)
+    }
+
+    fn force_flush(&self) -> OTelSdkResult {
+        self.file.flush()
+    }
+
+    fn shutdown_with_timeout(&self, _timeout: Duration) -> OTelSdkResult {
+        self.file.flush()
+    }
+
+    fn temporality(&self) -> Temporality {
+        self.temporality
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    #[test]
+    fn rotating_file_rolls_over_and_keeps_max_files() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let file = RotatingFile::new(
+            dir.path(),
+            FileSignal::Traces,
+            /*max_bytes*/ 16,
+            /*max_files*/ 2,
+        );
+
+        for index in 0..4 {
+            file.write_json_line(&json!({ "line": index }))
+                .expect("write line");
+        }
+
+        let read = |name: &str| fs::read_to_string(dir.path().join(name)).expect("read file");
+        assert_eq!(read("traces.jsonl"), "{\"line\":3}\n");
+        assert_eq!(read("traces.jsonl.1"), "{\"line\":2}\n");
+        assert_eq!(read("traces.jsonl.2"), "{\"line\":1}\n");
+        assert!(!dir.path().join("traces.jsonl.3").exists());
+    }
+
+    #[test]
+    fn rotating_file_appends_to_existing_file_until_limit() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        fs::write(dir.path().join("logs.jsonl"), "{\"line\":0}\n").expect("seed file");
+        let file = RotatingFile::new(
+            dir.path(),
+            FileSignal::Logs,
+            /*max_bytes*/ 32,
+            /*max_files*/ 1,
+        );
+
+        file.write_json_line(&json!({ "line": 1 }))
+            .expect("write line");
+
+        assert_eq!(
+            fs::read_to_string(dir.path().join("logs.jsonl")).expect("read file"),
+            "{\"line\":0}\n{\"line\":1}\n"
+        );
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod config;
 mod events;
+mod file;
 pub(crate) mod metrics;
 pub(crate) mod provider;
 pub(crate) mod trace_context;
@@ -19,6 +20,8 @@ pub use crate::config::OtelTlsConfig;
 pub use crate::config::StatsigMetricsSettings;
 pub use crate::config::validate_span_attributes;
 pub use crate::events::session_telemetry::AuthEnvTelemetryMetadata;
+pub use crate::file::DEFAULT_OTLP_FILE_MAX_BYTES;
+pub use crate::file::DEFAULT_OTLP_FILE_MAX_FILES;
 pub use crate::events::session_telemetry::SessionTelemetry;
 pub use crate::events::session_telemetry::SessionTelemetryMetadata;
 pub use crate::metrics::runtime_metrics::RuntimeMetricTotals;
@@ -1,5 +1,8 @@
 use crate::config::OtelExporter;
 use crate::config::OtelHttpProtocol;
+use crate::file::FileMetricExporter;
+use crate::file::FileSignal;
+use crate::file::RotatingFile;
 use crate::metrics::MetricsError;
 use crate::metrics::Result;
 use crate::metrics::config::MetricsConfig;
@@ -40,6 +43,7 @@ use std::sync::Mutex;
 use std::sync::Weak;
 use std::time::Duration;
 use tracing::debug;
+use tracing::warn;
 
 const ENV_ATTRIBUTE: &str = "env";
 const METER_NAME: &str = "codex";
@@ -320,6 +324,22 @@ impl MetricsClient {
             MetricsExporter::InMemory(exporter) => {
                 build_provider(resource, exporter, export_interval, runtime_reader.clone())
             }
+            MetricsExporter::Otlp(OtelExporter::OtlpFile {
+                directory,
+                max_bytes,
+                max_files,
+            }) => {
+                debug!(
+                    "Using OTLP file exporter for metrics: {}",
+                    directory.display()
+                );
+                let file = RotatingFile::new(&directory, FileSignal::Metrics, max_bytes, max_files);
+                let exporter = FileMetricExporter::new(file, Temporality::Delta);
+                build_provider(resource, exporter, export_interval, runtime_reader.clone())
+            }
+            MetricsExporter::Otlp(OtelExporter::Prometheus { listen_addr }) => {
+                build_prometheus_provider(resource, &listen_addr, runtime_reader.clone())
+            }
             MetricsExporter::Otlp(exporter) => {
                 let exporter = build_otlp_metric_exporter(exporter, Temporality::Delta)?;
                 build_provider(resource, exporter, export_interval, runtime_reader.clone())
@@ -504,6 +524,37 @@ where
     (provider, meter)
 }
 
+/// Builds a provider whose only export path is the Prometheus scrape endpoint.
+///
+/// Prometheus expects cumulative counters, so the scrape reader keeps
+/// cumulative state while the optional runtime reader stays delta-based.
+fn build_prometheus_provider(
+    resource: Resource,
+    listen_addr: &str,
+    runtime_reader: Option<Arc<ManualReader>>,
+) -> (SdkMeterProvider, Meter) {
+    let scrape_reader = Arc::new(
+        ManualReader::builder()
+            .with_temporality(Temporality::Cumulative)
+            .build(),
+    );
+    let mut provider_builder = SdkMeterProvider::builder()
+        .with_resource(resource)
+        .with_reader(SharedManualReader::new(Arc::clone(&scrape_reader)));
+    if let Some(reader) = runtime_reader {
+        provider_builder = provider_builder.with_reader(SharedManualReader::new(reader));
+    }
+    let provider = provider_builder.build();
+    let meter = provider.meter(METER_NAME);
+
+    // Another process may already own the port, e.g. a TUI started next to a
+    // long-running app-server. Keep recording so runtime snapshots still work.
+    if let Err(err) = crate::metrics::prometheus::serve(listen_addr, scrape_reader) {
+        warn!("failed to serve Prometheus metrics on {listen_addr}: {err}");
+    }
+    (provider, meter)
+}
+
 fn build_otlp_metric_exporter(
     exporter: OtelExporter,
     temporality: Temporality,
@@ -577,5 +628,8 @@ fn build_otlp_metric_exporter(
                 .build()
                 .map_err(|source| MetricsError::ExporterBuild { source })
         }
+        OtelExporter::OtlpFile { .. } | OtelExporter::Prometheus { .. } => {
+            unreachable!("file and prometheus metrics exporters are built without OTLP")
+        }
     }
 }
@@ -3,6 +3,7 @@ mod config;
 mod error;
 pub(crate) mod names;
 mod process;
+pub(crate) mod prometheus;
 pub(crate) mod runtime_metrics;
 pub(crate) mod tags;
 pub(crate) mod timer;
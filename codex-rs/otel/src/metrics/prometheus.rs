@@ -0,0 +1,323 @@
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::metrics::ManualReader;
+use opentelemetry_sdk::metrics::data::AggregatedMetrics;
+use opentelemetry_sdk::metrics::data::Metric;
+use opentelemetry_sdk::metrics::data::MetricData;
+use opentelemetry_sdk::metrics::data::ResourceMetrics;
+use opentelemetry_sdk::metrics::reader::MetricReader;
+use std::fmt::Display;
+use std::fmt::Write as _;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::net::Shutdown;
+use std::net::SocketAddr;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::Instant;
+use tracing::debug;
+use tracing::warn;
+
+const METRICS_PATH: &str = "/metrics";
+const CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+/// Time a client has to send the whole request head.
+const REQUEST_DEADLINE: Duration = Duration::from_secs(5);
+/// Request heads larger than this are answered with 431 without being read further.
+const MAX_REQUEST_HEAD_BYTES: usize = 8 * 1024;
+/// Most bytes of an oversized request read and dropped before closing.
+const MAX_DISCARDED_REQUEST_BYTES: usize = 64 * 1024;
+/// Connections beyond this many in flight are closed without a response.
+const MAX_CONCURRENT_CONNECTIONS: usize = 8;
+
+/// Binds `listen_addr` and serves `GET /metrics` from `reader` on a background
+/// thread for the rest of the process. Each connection is handled on its own
+/// thread so a slow client cannot hold up other scrapes.
+pub(crate) fn serve(listen_addr: &str, reader: Arc<ManualReader>) -> io::Result<SocketAddr> {
+    let listener = TcpListener::bind(listen_addr)?;
+    let local_addr = listener.local_addr()?;
+    debug!("Serving Prometheus metrics on http://{local_addr}{METRICS_PATH}");
+    std::thread::Builder::new()
+        .name("codex-otel-prometheus".to_string())
+        .spawn(move || {
+            let in_flight = Arc::new(AtomicUsize::new(0));
+            for stream in listener.incoming() {
+                match stream {
+                    Ok(stream) => spawn_connection(stream, &reader, &in_flight),
+                    Err(err) => warn!("Prometheus listener accept failed: {err}"),
+                }
+            }
+        })?;
+    Ok(local_addr)
+}
+
+fn spawn_connection(stream: TcpStream, reader: &Arc<ManualReader>, in_flight: &Arc<AtomicUsize>) {
+    if in_flight.fetch_add(1, Ordering::AcqRel) >= MAX_CONCURRENT_CONNECTIONS {
+        in_flight.fetch_sub(1, Ordering::AcqRel);
+        debug!("Prometheus scrape dropped: too many connections in flight");
+        return;
+    }
+    let reader = Arc::clone(reader);
+    let connection_in_flight = Arc::clone(in_flight);
+    let spawned = std::thread::Builder::new()
+        .name("codex-otel-prometheus-conn".to_string())
+        .spawn(move || {
+            if let Err(err) = handle_connection(stream, &reader) {
+                debug!("Prometheus scrape failed: {err}");
+            }
+            connection_in_flight.fetch_sub(1, Ordering::AcqRel);
+        });
+    if let Err(err) = spawned {
+        in_flight.fetch_sub(1, Ordering::AcqRel);
+        warn!("Prometheus connection thread failed to start: {err}");
+    }
+}
+
+fn handle_connection(stream: TcpStream, reader: &ManualReader) -> io::Result<()> {
+    let head = read_request_head(&stream)?;
+    let request_line = head
+        .as_deref()
+        .map(String::from_utf8_lossy)
+        .unwrap_or_default();
+    let request_line = request_line.lines().next().unwrap_or_default();
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or_default();
+    let path = path.split('?').next().unwrap_or_default();
+
+    let (status, body) = if head.is_none() {
+        ("431 Request Header Fields Too Large", String::new())
+    } else if method != "GET" {
+        ("405 Method Not Allowed", String::new())
+    } else if path != METRICS_PATH {
+        ("404 Not Found", String::new())
+    } else {
+        let mut snapshot = ResourceMetrics::default();
+        match reader.collect(&mut snapshot) {
+            Ok(()) => ("200 OK", render(&snapshot)),
+            Err(err) => ("503 Service Unavailable", format!("{err}\n")),
+        }
+    };
+
+    stream.set_write_timeout(Some(REQUEST_DEADLINE))?;
+    let mut stream = &stream;
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {CONTENT_TYPE}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    if head.is_none() {
+        // Closing with unread request bytes resets the connection, which can
+        // discard the response before the client reads it.
+        stream.shutdown(Shutdown::Write)?;
+        discard_request(stream)?;
+    }
+    Ok(())
+}
+
+/// Reads the request line and headers within [`REQUEST_DEADLINE`] in total.
+/// Returns `None` when the head exceeds [`MAX_REQUEST_HEAD_BYTES`].
+fn read_request_head(stream: &TcpStream) -> io::Result<Option<Vec<u8>>> {
+    let deadline = Instant::now() + REQUEST_DEADLINE;
+    let mut head = Vec::new();
+    let mut buf = [0_u8; 1024];
+    while !is_complete_head(&head) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                "request head not received in time",
+            ));
+        }
+        stream.set_read_timeout(Some(remaining))?;
+        let read = (&*stream).read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..read]);
+        if head.len() > MAX_REQUEST_HEAD_BYTES {
+            return Ok(None);
+        }
+    }
+    Ok(Some(head))
+}
+
+/// Reads and drops what is left of an oversized request, bounded like the head.
+fn discard_request(stream: &TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(REQUEST_DEADLINE))?;
+    let mut buf = [0_u8; 1024];
+    let mut discarded = 0;
+    while discarded < MAX_DISCARDED_REQUEST_BYTES {
+        let read = (&*stream).read(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        discarded += read;
+    }
+    Ok(())
+}
+
+fn is_complete_head(head: &[u8]) -> bool {
+    head.windows(4).any(|window| window == b"\r\n\r\n")
+        || head.windows(2).any(|window| window == b"\n\n")
+}
+
+/// Renders a cumulative metrics snapshot in the Prometheus text exposition
+/// format.
+pub(crate) fn render(snapshot: &ResourceMetrics) -> String {
+    let mut out = String::new();
+    for metric in snapshot
+        .scope_metrics()
+        .flat_map(opentelemetry_sdk::metrics::data::ScopeMetrics::metrics)
+    {
+        match metric.data() {
+            AggregatedMetrics::F64(data) => render_metric(&mut out, metric, data),
+            AggregatedMetrics::U64(data) => render_metric(&mut out, metric, data),
+            AggregatedMetrics::I64(data) => render_metric(&mut out, metric, data),
+        }
+    }
+    out
+}
+
+fn render_metric<T: Display + Copy>(out: &mut String, metric: &Metric, data: &MetricData<T>) {
+    let base_name = sanitize_name(metric.name());
+    match data {
+        MetricData::Gauge(gauge) => {
+            write_header(out, &base_name, metric.description(), "gauge");
+            for point in gauge.data_points() {
+                write_sample(out, &base_name, point.attributes(), None, point.value());
+            }
+        }
+        MetricData::Sum(sum) => {
+            let (name, kind) = if sum.is_monotonic() {
+                (format!("{base_name}_total"), "counter")
+            } else {
+                (base_name, "gauge")
+            };
+            write_header(out, &name, metric.description(), kind);
+            for point in sum.data_points() {
+                write_sample(out, &name, point.attributes(), None, point.value());
+            }
+        }
+        MetricData::Histogram(histogram) => {
+            write_header(out, &base_name, metric.description(), "histogram");
+            let bucket_name = format!("{base_name}_bucket");
+            for point in histogram.data_points() {
+                let mut cumulative = 0_u64;
+                for (bound, count) in point.bounds().zip(point.bucket_counts()) {
+                    cumulative = cumulative.saturating_add(count);
+                    write_sample(
+                        out,
+                        &bucket_name,
+                        point.attributes(),
+                        Some(&bound.to_string()),
+                        cumulative,
+                    );
+                }
+                write_sample(
+                    out,
+                    &bucket_name,
+                    point.attributes(),
+                    Some("+Inf"),
+                    point.count(),
+                );
+                write_sample(
+                    out,
+                    &format!("{base_name}_sum"),
+                    point.attributes(),
+                    None,
+                    point.sum(),
+                );
+                write_sample(
+                    out,
+                    &format!("{base_name}_count"),
+                    point.attributes(),
+                    None,
+                    point.count(),
+                );
+            }
+        }
+        // Codex does not record exponential histograms.
+        MetricData::ExponentialHistogram(_) => {}
+    }
+}
+
+fn write_header(out: &mut String, name: &str, description: &str, kind: &str) {
+    if !description.is_empty() {
+        let description = description.replace('\\', "\\\\").replace('\n', "\\n");
+        let _ = writeln!(out, "# HELP {name} {description}");
+    }
+    let _ = writeln!(out, "# TYPE {name} {kind}");
+}
+
+fn write_sample<'a>(
+    out: &mut String,
+    name: &str,
+    attributes: impl Iterator<Item = &'a KeyValue>,
+    le: Option<&str>,
+    value: impl Display,
+) {
+    let mut labels = attributes
+        .map(|kv| {
+            format!(
+                "{}=\"{}\"",
+                sanitize_name(kv.key.as_str()),
+                escape_label_value(kv.value.as_str().as_ref())
+            )
+        })
+        .collect::<Vec<_>>();
+    if let Some(le) = le {
+        labels.push(format!("le=\"{le}\""));
+    }
+    if labels.is_empty() {
+        let _ = writeln!(out, "{name} {value}");
+    } else {
+        let _ = writeln!(out, "{name}{{{}}} {value}", labels.join(","));
+    }
+}
+
+fn sanitize_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '_' || ch == ':' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if sanitized.starts_with(|ch: char| ch.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn sanitize_name_replaces_invalid_characters() {
+        assert_eq!(sanitize_name("codex.tool.call"), "codex_tool_call");
+        assert_eq!(sanitize_name("1st-metric"), "_1st_metric");
+    }
+
+    #[test]
+    fn escape_label_value_escapes_quotes_and_newlines() {
+        assert_eq!(escape_label_value("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+}
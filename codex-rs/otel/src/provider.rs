@@ -2,6 +2,10 @@ use crate::config::OtelExporter;
 use crate::config::OtelHttpProtocol;
 use crate::config::OtelSettings;
 use crate::config::StatsigMetricsSettings;
+use crate::file::FileLogExporter;
+use crate::file::FileSignal;
+use crate::file::FileSpanExporter;
+use crate::file::RotatingFile;
 use crate::metrics::MetricsClient;
 use crate::metrics::MetricsConfig;
 use crate::targets::is_log_export_target;
@@ -95,6 +99,11 @@ impl OtelProvider {
         if trace_enabled {
             crate::config::validate_span_attributes(&settings.span_attributes)?;
         }
+        if matches!(settings.exporter, OtelExporter::Prometheus { .. })
+            || matches!(settings.trace_exporter, OtelExporter::Prometheus { .. })
+        {
+            return Err("the prometheus exporter only supports metrics".into());
+        }
         crate::trace_context::validate_tracestate_entries(&settings.tracestate)?;
 
         let metrics = if matches!(metric_exporter, OtelExporter::None) {
@@ -355,6 +364,19 @@ fn build_logger(
 
             builder = builder.with_batch_exporter(exporter);
         }
+        OtelExporter::OtlpFile {
+            directory,
+            max_bytes,
+            max_files,
+        } => {
+            debug!("Using OTLP file exporter: {}", directory.display());
+
+            let file = RotatingFile::new(&directory, FileSignal::Logs, max_bytes, max_files);
+            builder = builder.with_batch_exporter(FileLogExporter::new(file));
+        }
+        OtelExporter::Prometheus { .. } => {
+            unreachable!("prometheus exporter should be rejected for non-metrics signals")
+        }
     }
 
     Ok(builder.build())
@@ -447,6 +469,26 @@ fn build_tracer_provider(
 
             exporter_builder.build()?
         }
+        OtelExporter::OtlpFile {
+            directory,
+            max_bytes,
+            max_files,
+        } => {
+            debug!(
+                "Using OTLP file exporter for traces: {}",
+                directory.display()
+            );
+
+            let file = RotatingFile::new(&directory, FileSignal::Traces, max_bytes, max_files);
+            let processor = BatchSpanProcessor::builder(FileSpanExporter::new(file)).build();
+
+            return Ok(tracer_provider_builder(resource, span_attributes)
+                .with_span_processor(processor)
+                .build());
+        }
+        OtelExporter::Prometheus { .. } => {
+            unreachable!("prometheus exporter should be rejected for non-metrics signals")
+        }
     };
 
     let processor = BatchSpanProcessor::builder(span_exporter).build();
@@ -0,0 +1,152 @@
+use codex_otel::MetricsClient;
+use codex_otel::MetricsConfig;
+use codex_otel::OtelExporter;
+use codex_otel::OtelProvider;
+use codex_otel::OtelSettings;
+use codex_otel::Result;
+use std::collections::BTreeMap;
+use std::io::Read as _;
+use std::io::Write as _;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[test]
+fn otlp_file_exporter_writes_metrics_as_otlp_json_lines() -> Result<()> {
+    let codex_home = tempfile::tempdir().expect("tempdir");
+    let directory = codex_home.path().join("otel");
+    let metrics = MetricsClient::new(MetricsConfig::otlp(
+        "test",
+        "codex-cli",
+        env!("CARGO_PKG_VERSION"),
+        OtelExporter::OtlpFile {
+            directory: directory.clone(),
+            max_bytes: 1024 * 1024,
+            max_files: 1,
+        },
+    ))?;
+
+    metrics.counter("codex.file_export", /*inc*/ 3, &[("tool", "shell")])?;
+    metrics.shutdown()?;
+
+    let contents =
+        std::fs::read_to_string(directory.join("metrics.jsonl")).expect("metrics file written");
+    let line = contents.lines().last().expect("at least one export line");
+    let request: serde_json::Value = serde_json::from_str(line).expect("line is JSON");
+    let metric = &request["resourceMetrics"][0]["scopeMetrics"][0]["metrics"][0];
+    assert_eq!(metric["name"], "codex.file_export");
+    assert_eq!(metric["sum"]["isMonotonic"], true);
+    Ok(())
+}
+
+#[test]
+fn prometheus_exporter_serves_cumulative_metrics() -> Result<()> {
+    let listen_addr = {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        listener.local_addr().expect("local_addr")
+    };
+    let metrics = MetricsClient::new(MetricsConfig::otlp(
+        "test",
+        "codex-app-server",
+        env!("CARGO_PKG_VERSION"),
+        OtelExporter::Prometheus {
+            listen_addr: listen_addr.to_string(),
+        },
+    ))?;
+
+    metrics.counter("codex.scrape_test", /*inc*/ 1, &[("tool", "shell")])?;
+    metrics.counter("codex.scrape_test", /*inc*/ 2, &[("tool", "shell")])?;
+
+    let body = scrape(listen_addr, "/metrics");
+    assert!(body.starts_with("HTTP/1.1 200 OK"), "response: {body}");
+    assert!(
+        body.contains("# TYPE codex_scrape_test_total counter"),
+        "response: {body}"
+    );
+    assert!(
+        body.contains("codex_scrape_test_total{tool=\"shell\"} 3"),
+        "response: {body}"
+    );
+
+    let not_found = scrape(listen_addr, "/other");
+    assert!(
+        not_found.starts_with("HTTP/1.1 404 Not Found"),
+        "response: {not_found}"
+    );
+    Ok(())
+}
+
+#[test]
+fn prometheus_exporter_serves_scrapes_while_a_client_stalls() -> Result<()> {
+    let listen_addr = {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind");
+        listener.local_addr().expect("local_addr")
+    };
+    let _metrics = MetricsClient::new(MetricsConfig::otlp(
+        "test",
+        "codex-app-server",
+        env!("CARGO_PKG_VERSION"),
+        OtelExporter::Prometheus {
+            listen_addr: listen_addr.to_string(),
+        },
+    ))?;
+
+    let mut stalled = TcpStream::connect(listen_addr).expect("connect");
+    write!(stalled, "GET /metrics HTTP/1.1\r\n").expect("write partial request");
+
+    let body = scrape(listen_addr, "/metrics");
+    assert!(body.starts_with("HTTP/1.1 200 OK"), "response: {body}");
+
+    let mut oversized = TcpStream::connect(listen_addr).expect("connect");
+    oversized
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .expect("set_read_timeout");
+    write!(
+        oversized,
+        "GET /metrics HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+        "a".repeat(16 * 1024)
+    )
+    .expect("write request");
+    let mut response = String::new();
+    let _ = oversized.read_to_string(&mut response);
+    assert!(
+        response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"),
+        "response: {response}"
+    );
+    Ok(())
+}
+
+#[test]
+fn otel_provider_rejects_prometheus_for_traces() {
+    let result = OtelProvider::from(&OtelSettings {
+        environment: "test".to_string(),
+        service_name: "codex-cli".to_string(),
+        service_version: env!("CARGO_PKG_VERSION").to_string(),
+        codex_home: PathBuf::from("."),
+        exporter: OtelExporter::None,
+        trace_exporter: OtelExporter::Prometheus {
+            listen_addr: "127.0.0.1:0".to_string(),
+        },
+        metrics_exporter: OtelExporter::None,
+        runtime_metrics: false,
+        span_attributes: BTreeMap::new(),
+        tracestate: BTreeMap::new(),
+    });
+
+    let err = result
+        .err()
+        .expect("prometheus trace exporter should be rejected");
+    assert!(err.to_string().contains("only supports metrics"));
+}
+
+fn scrape(addr: std::net::SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(addr).expect("connect");
+    stream
+        .set_read_timeout(Some(Duration::from_secs(2)))
+        .expect("set_read_timeout");
+    write!(stream, "GET {path} HTTP/1.1\r\nHost: {addr}\r\n\r\n").expect("write request");
+    let mut response = String::new();
+    stream.read_to_string(&mut response).expect("read response");
+    response
+}
@@ -1,3 +1,4 @@
+mod local_exporters;
 mod manager_metrics;
 mod otel_export_routing_policy;
 mod otlp_http_loopback;
@@ -198,6 +198,7 @@ impl CommandExecutionItem {
             duration: self.duration.unwrap_or_default(),
             formatted_output: self.formatted_output.clone().unwrap_or_default(),
             status,
+            redacted: self.redacted,
         }))
     }
 }
@@ -494,6 +495,7 @@ impl McpToolCallItem {
             plugin_id: self.plugin_id.clone(),
             duration: self.duration?,
             result,
+            redacted: self.redacted,
         }))
     }
 }
@@ -1815,6 +1815,10 @@ pub struct ShellCommandToolCallParams {
     pub additional_permissions: Option<AdditionalPermissionProfile>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub justification: Option<String>,
+    /// Names of stored secrets to expose to the command as environment variables.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub secrets: Option<Vec<String>>,
 }
 
 /// Responses API compatible content items that can be returned by a tool call.
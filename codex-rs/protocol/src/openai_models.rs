@@ -299,6 +299,14 @@ pub enum WebSearchToolType {
     TextAndImage,
 }
 
+/// BPE vocabulary used to count tokens locally for a model family.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, TS, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TokenizerEncoding {
+    O200kBase,
+    Cl100kBase,
+}
+
 /// Server-provided truncation policy metadata for a model.
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, TS, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -449,6 +457,14 @@ pub struct ModelInfo {
         deserialize_with = "deserialize_optional_model_selector"
     )]
     pub multi_agent_version: Option<MultiAgentVersion>,
+    /// Tokenizer used for local context-window accounting. When omitted,
+    /// token counts fall back to byte-length estimates.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "deserialize_optional_model_selector"
+    )]
+    pub tokenizer: Option<TokenizerEncoding>,
 }
 
 impl ModelInfo {
@@ -739,6 +755,7 @@ mod tests {
             auto_review_model_override: None,
             tool_mode: None,
             multi_agent_version: None,
+            tokenizer: None,
         }
     }
 
@@ -1165,6 +1182,32 @@ mod tests {
         assert!(!object.contains_key("tool_mode"));
     }
 
+    #[test]
+    fn model_info_deserializes_known_tokenizer_and_ignores_unknown() {
+        let mut value =
+            serde_json::to_value(test_model(/*spec*/ None)).expect("serialize test model");
+        let object = value
+            .as_object_mut()
+            .expect("model info should be an object");
+        object.insert(
+            "tokenizer".to_string(),
+            serde_json::Value::String("o200k_base".to_string()),
+        );
+        let model =
+            serde_json::from_value::<ModelInfo>(value.clone()).expect("deserialize model info");
+        assert_eq!(model.tokenizer, Some(TokenizerEncoding::O200kBase));
+
+        let object = value
+            .as_object_mut()
+            .expect("model info should be an object");
+        object.insert(
+            "tokenizer".to_string(),
+            serde_json::Value::String("future_encoding".to_string()),
+        );
+        let model = serde_json::from_value::<ModelInfo>(value).expect("deserialize model info");
+        assert_eq!(model.tokenizer, None);
+    }
+
     #[test]
     fn model_info_treats_unknown_multi_agent_version_as_omitted() {
         let mut value =
@@ -121,6 +121,8 @@ pub const CONTEXT_WINDOW_OPEN_TAG: &str = "<context_window>";
 pub const CONTEXT_WINDOW_CLOSE_TAG: &str = "</context_window>";
 pub const CONTEXT_WINDOW_GUIDANCE_OPEN_TAG: &str = "<context_window_guidance>";
 pub const CONTEXT_WINDOW_GUIDANCE_CLOSE_TAG: &str = "</context_window_guidance>";
+pub const PINNED_CONTEXT_OPEN_TAG: &str = "<pinned_context>";
+pub const PINNED_CONTEXT_CLOSE_TAG: &str = "</pinned_context>";
 pub const USER_MESSAGE_BEGIN: &str = "## My request for Codex:";
 
 /// Removes the model-context prefix from a user message before displaying it.
@@ -1368,6 +1370,9 @@ pub enum EventMsg {
     /// Updated long-running goal metadata for the thread.
     ThreadGoalUpdated(ThreadGoalUpdatedEvent),
 
+    /// The thread's full set of pinned context items after a pin or unpin.
+    PinnedContextUpdated(PinnedContextUpdatedEvent),
+
     /// Incremental MCP startup progress updates.
     McpStartupUpdate(McpStartupUpdateEvent),
 
@@ -1603,6 +1608,10 @@ pub struct HookRunSummary {
     #[ts(type = "number | null")]
     pub duration_ms: Option<i64>,
     pub entries: Vec<HookOutputEntry>,
+    /// Thread spawned to evaluate an `agent` hook, kept so the run can be inspected later.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub agent_thread_id: Option<ThreadId>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
@@ -2460,6 +2469,9 @@ pub struct McpToolCallEndEvent {
     pub duration: Duration,
     /// Result of the tool call. Note this could be an error.
     pub result: Result<CallToolResult, String>,
+    /// Whether secrets were masked out of the result.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redacted: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
@@ -3597,6 +3609,9 @@ pub struct ExecCommandEndEvent {
     pub formatted_output: String,
     /// Completion status for this command execution.
     pub status: ExecCommandStatus,
+    /// Whether secrets were masked out of the output.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub redacted: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
@@ -4100,6 +4115,41 @@ pub struct ThreadGoalUpdatedEvent {
     pub goal: ThreadGoal,
 }
 
+/// Who pinned an item to a thread's context.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum PinnedContextSource {
+    User,
+    Model,
+}
+
+impl PinnedContextSource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Model => "model",
+        }
+    }
+}
+
+/// Text pinned to a thread's context so it survives compaction.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct PinnedContextItem {
+    /// Thread-unique id, e.g. `pin-1`.
+    pub id: String,
+    pub text: String,
+    pub source: PinnedContextSource,
+    /// Approximate tokens this pin costs against the budget.
+    pub tokens: usize,
+}
+
+/// Snapshot of every pin in a thread, recorded after each pin or unpin so
+/// resumed and forked threads can rebuild them from the rollout.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct PinnedContextUpdatedEvent {
+    pub items: Vec<PinnedContextItem>,
+}
+
 /// User's decision in response to an ExecApprovalRequest.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Display, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
@@ -4118,6 +4168,10 @@ pub enum ReviewDecision {
     /// remainder of the session.
     ApprovedForSession,
 
+    /// User approved only some of the proposed file changes. Files that are not
+    /// listed were rejected and must not be written.
+    ApprovedSelectedChanges { changes: Vec<SelectedFileChange> },
+
     /// User chose to persist a network policy rule (allow/deny) for future
     /// requests to the same host.
     NetworkPolicyAmendment {
@@ -4145,6 +4199,11 @@ impl Default for ReviewDecision {
 }
 
 impl ReviewDecision {
+    /// Rejection used when a partial approval comes back for a request that is
+    /// not an `apply_patch` change. Only patches can be approved in part.
+    pub const SELECTED_CHANGES_REJECTION: &str =
+        "approving selected changes only applies to file edits";
+
     pub fn denied(rejection: impl Into<String>) -> Self {
         Self::Denied {
             rejection: rejection.into(),
@@ -4158,6 +4217,7 @@ impl ReviewDecision {
             ReviewDecision::Approved => "approved",
             ReviewDecision::ApprovedExecpolicyAmendment { .. } => "approved_with_amendment",
             ReviewDecision::ApprovedForSession => "approved_for_session",
+            ReviewDecision::ApprovedSelectedChanges { .. } => "approved_selected_changes",
             ReviewDecision::NetworkPolicyAmendment {
                 network_policy_amendment,
             } => match network_policy_amendment.action {
@@ -4187,6 +4247,18 @@ pub enum FileChange {
     },
 }
 
+/// A file the user accepted from a proposed patch, optionally narrowed to some
+/// of its hunks.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct SelectedFileChange {
+    pub path: PathBuf,
+    /// Zero-based indices of the accepted `@@` hunks in the file's unified
+    /// diff. `None` accepts every change to the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub hunks: Option<Vec<u32>>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct Chunk {
     /// 1-based line index of the first line in the original file
@@ -5306,6 +5378,7 @@ mod tests {
                 result: None,
                 error: None,
                 duration: None,
+                redacted: false,
             }),
         };
 
@@ -5426,6 +5499,7 @@ mod tests {
                 }),
                 error: None,
                 duration: Some(Duration::from_millis(42)),
+                redacted: false,
             }),
         };
 
@@ -5476,6 +5550,7 @@ mod tests {
                 exit_code: None,
                 duration: None,
                 formatted_output: None,
+                redacted: false,
             }),
         };
         let completed = ItemCompletedEvent {
@@ -5499,6 +5574,7 @@ mod tests {
                 exit_code: Some(0),
                 duration: Some(Duration::from_millis(5)),
                 formatted_output: Some("done\n".into()),
+                redacted: false,
             }),
         };
 
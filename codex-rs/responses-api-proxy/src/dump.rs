@@ -8,16 +8,20 @@ use std::time::SystemTime;
 use std::time::UNIX_EPOCH;
 
 use reqwest::header::HeaderMap;
+use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value;
 use tiny_http::Header;
 use tiny_http::Method;
 
 const AUTHORIZATION_HEADER_NAME: &str = "authorization";
-const REDACTED_HEADER_VALUE: &str = "[REDACTED]";
+pub(crate) const REDACTED_HEADER_VALUE: &str = "[REDACTED]";
 
 pub(crate) struct ExchangeDumper {
     dump_dir: PathBuf,
+    /// Start time and pid of this proxy, so the sequence numbers of separate
+    /// runs recording into the same directory never interleave when sorted.
+    run_prefix: String,
     next_sequence: AtomicU64,
 }
 
@@ -27,6 +31,7 @@ impl ExchangeDumper {
 
         Ok(Self {
             dump_dir,
+            run_prefix: format!("{:013}-{}", unix_time_ms(), std::process::id()),
             next_sequence: AtomicU64::new(1),
         })
     }
@@ -39,10 +44,7 @@ impl ExchangeDumper {
         body: &[u8],
     ) -> io::Result<ExchangeDump> {
         let sequence = self.next_sequence.fetch_add(1, Ordering::Relaxed);
-        let timestamp_ms = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .map_or(0, |duration| duration.as_millis());
-        let prefix = format!("{sequence:06}-{timestamp_ms}");
+        let prefix = format!("{}-{sequence:06}-{}", self.run_prefix, unix_time_ms());
 
         let request_path = self.dump_dir.join(format!("{prefix}-request.json"));
         let response_path = self.dump_dir.join(format!("{prefix}-response.json"));
@@ -133,25 +135,25 @@ impl<R> Drop for ResponseBodyDump<R> {
     }
 }
 
-#[derive(Serialize)]
-struct RequestDump {
-    method: String,
-    url: String,
-    headers: Vec<HeaderDump>,
-    body: Value,
+#[derive(Serialize, Deserialize)]
+pub(crate) struct RequestDump {
+    pub(crate) method: String,
+    pub(crate) url: String,
+    pub(crate) headers: Vec<HeaderDump>,
+    pub(crate) body: Value,
 }
 
-#[derive(Serialize)]
-struct ResponseDump {
-    status: u16,
-    headers: Vec<HeaderDump>,
-    body: Value,
+#[derive(Serialize, Deserialize)]
+pub(crate) struct ResponseDump {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<HeaderDump>,
+    pub(crate) body: Value,
 }
 
-#[derive(Debug, Serialize)]
-struct HeaderDump {
-    name: String,
-    value: String,
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct HeaderDump {
+    pub(crate) name: String,
+    pub(crate) value: String,
 }
 
 impl From<&Header> for HeaderDump {
@@ -183,12 +185,18 @@ impl From<(&reqwest::header::HeaderName, &reqwest::header::HeaderValue)> for Hea
     }
 }
 
+fn unix_time_ms() -> u128 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_millis())
+}
+
 fn should_redact_header(name: &str) -> bool {
     name.eq_ignore_ascii_case(AUTHORIZATION_HEADER_NAME)
         || name.to_ascii_lowercase().contains("cookie")
 }
 
-fn dump_body(body: &[u8]) -> Value {
+pub(crate) fn dump_body(body: &[u8]) -> Value {
     serde_json::from_slice(body)
         .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(body).into_owned()))
 }
@@ -30,8 +30,12 @@ use tiny_http::StatusCode;
 
 mod dump;
 mod read_api_key;
+mod replay;
 use dump::ExchangeDumper;
 use read_api_key::read_auth_header_from_stdin;
+use replay::ReplayStore;
+use replay::RequestMatcher;
+use replay::replay_request;
 
 /// CLI arguments for the proxy.
 #[derive(Debug, Clone, Parser)]
@@ -56,6 +60,25 @@ pub struct Args {
     /// Directory where request/response dumps should be written as JSON.
     #[arg(long, value_name = "DIR")]
     pub dump_dir: Option<PathBuf>,
+
+    /// Serve responses recorded with --dump-dir from this directory instead of
+    /// forwarding upstream. No API key is read in this mode.
+    #[arg(long, value_name = "DIR", conflicts_with = "dump_dir")]
+    pub replay_dir: Option<PathBuf>,
+
+    /// JSON pointer into the request body to ignore when matching recordings.
+    /// A `*` segment matches any key or array index. May be repeated.
+    #[arg(
+        long = "replay-ignore",
+        value_name = "POINTER",
+        requires = "replay_dir"
+    )]
+    pub replay_ignore: Vec<String>,
+
+    /// Regex whose matches in request body strings are masked when matching
+    /// recordings. May be repeated.
+    #[arg(long = "replay-mask", value_name = "REGEX", requires = "replay_dir")]
+    pub replay_mask: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -69,29 +92,14 @@ struct ForwardConfig {
     host_header: HeaderValue,
 }
 
+type RequestHandler = Arc<dyn Fn(Request) -> Result<()> + Send + Sync>;
+
 /// Entry point for the library main, for parity with other crates.
 pub fn run_main(args: Args) -> Result<()> {
-    let auth_header = read_auth_header_from_stdin()?;
-
-    let upstream_url = Url::parse(&args.upstream_url).context("parsing --upstream-url")?;
-    let host = match (upstream_url.host_str(), upstream_url.port()) {
-        (Some(host), Some(port)) => format!("{host}:{port}"),
-        (Some(host), None) => host.to_string(),
-        _ => return Err(anyhow!("upstream URL must include a host")),
+    let handler = match args.replay_dir.as_deref() {
+        Some(replay_dir) => replay_handler(replay_dir, &args)?,
+        None => forward_handler(&args)?,
     };
-    let host_header =
-        HeaderValue::from_str(&host).context("constructing Host header from upstream URL")?;
-
-    let forward_config = Arc::new(ForwardConfig {
-        upstream_url,
-        host_header,
-    });
-    let dump_dir = args
-        .dump_dir
-        .map(ExchangeDumper::new)
-        .transpose()
-        .context("creating --dump-dir")?
-        .map(Arc::new);
 
     let (listener, bound_addr) = bind_listener(args.port)?;
     if let Some(path) = args.server_info.as_ref() {
@@ -99,35 +107,20 @@ pub fn run_main(args: Args) -> Result<()> {
     }
     let server = Server::from_listener(listener, None)
         .map_err(|err| anyhow!("creating HTTP server: {err}"))?;
-    let client = Arc::new(
-        Client::builder()
-            // Disable reqwest's 30s default so long-lived response streams keep flowing.
-            .timeout(None::<Duration>)
-            .build()
-            .context("building reqwest client")?,
-    );
 
     eprintln!("responses-api-proxy listening on {bound_addr}");
 
     let http_shutdown = args.http_shutdown;
     for request in server.incoming_requests() {
-        let client = client.clone();
-        let forward_config = forward_config.clone();
-        let dump_dir = dump_dir.clone();
+        let handler = handler.clone();
         std::thread::spawn(move || {
             if http_shutdown && request.method() == &Method::Get && request.url() == "/shutdown" {
                 let _ = request.respond(Response::new_empty(StatusCode(200)));
                 std::process::exit(0);
             }
 
-            if let Err(e) = forward_request(
-                &client,
-                auth_header,
-                &forward_config,
-                dump_dir.as_deref(),
-                request,
-            ) {
-                eprintln!("forwarding error: {e}");
+            if let Err(e) = handler(request) {
+                eprintln!("request error: {e}");
             }
         });
     }
@@ -135,6 +128,57 @@ pub fn run_main(args: Args) -> Result<()> {
     Err(anyhow!("server stopped unexpectedly"))
 }
 
+fn forward_handler(args: &Args) -> Result<RequestHandler> {
+    let auth_header = read_auth_header_from_stdin()?;
+
+    let upstream_url = Url::parse(&args.upstream_url).context("parsing --upstream-url")?;
+    let host = match (upstream_url.host_str(), upstream_url.port()) {
+        (Some(host), Some(port)) => format!("{host}:{port}"),
+        (Some(host), None) => host.to_string(),
+        _ => return Err(anyhow!("upstream URL must include a host")),
+    };
+    let host_header =
+        HeaderValue::from_str(&host).context("constructing Host header from upstream URL")?;
+
+    let forward_config = ForwardConfig {
+        upstream_url,
+        host_header,
+    };
+    let dump_dir = args
+        .dump_dir
+        .clone()
+        .map(ExchangeDumper::new)
+        .transpose()
+        .context("creating --dump-dir")?;
+    let client = Client::builder()
+        // Disable reqwest's 30s default so long-lived response streams keep flowing.
+        .timeout(None::<Duration>)
+        .build()
+        .context("building reqwest client")?;
+
+    Ok(Arc::new(move |request| {
+        forward_request(
+            &client,
+            auth_header,
+            &forward_config,
+            dump_dir.as_ref(),
+            request,
+        )
+    }))
+}
+
+fn replay_handler(replay_dir: &Path, args: &Args) -> Result<RequestHandler> {
+    let matcher = RequestMatcher::new(&args.replay_ignore, &args.replay_mask)?;
+    let store = ReplayStore::load(replay_dir, matcher)?;
+    eprintln!(
+        "responses-api-proxy replaying {} recorded responses from {}",
+        store.len(),
+        replay_dir.display()
+    );
+
+    Ok(Arc::new(move |request| replay_request(&store, request)))
+}
+
 fn bind_listener(port: Option<u16>) -> Result<(TcpListener, SocketAddr)> {
     let addr = SocketAddr::from(([127, 0, 0, 1], port.unwrap_or(0)));
     let listener = TcpListener::bind(addr).with_context(|| format!("failed to bind {addr}"))?;
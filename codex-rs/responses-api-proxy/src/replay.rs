@@ -0,0 +1,474 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::Path;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use anyhow::Context;
+use anyhow::Result;
+use regex_lite::Regex;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use tiny_http::Header;
+use tiny_http::Method;
+use tiny_http::Request;
+use tiny_http::Response;
+use tiny_http::StatusCode;
+
+use crate::dump::HeaderDump;
+use crate::dump::REDACTED_HEADER_VALUE;
+use crate::dump::RequestDump;
+use crate::dump::ResponseDump;
+use crate::dump::dump_body;
+
+const REQUEST_DUMP_SUFFIX: &str = "-request.json";
+const RESPONSE_DUMP_SUFFIX: &str = "-response.json";
+const MASKED_VALUE: &str = "[MASKED]";
+
+/// Request body fields that differ between otherwise identical Codex runs.
+pub(crate) const DEFAULT_IGNORED_FIELDS: &[&str] = &["/prompt_cache_key", "/client_metadata"];
+
+/// Normalizes request bodies so recordings match despite volatile fields.
+pub(crate) struct RequestMatcher {
+    ignored_fields: Vec<Vec<String>>,
+    masks: Vec<Regex>,
+}
+
+impl RequestMatcher {
+    /// `ignored_fields` are JSON pointers into the request body where a `*`
+    /// segment matches any object key or array index. `masks` are regexes
+    /// whose matches inside string values are replaced before comparison.
+    pub(crate) fn new(ignored_fields: &[String], masks: &[String]) -> Result<Self> {
+        let ignored_fields = DEFAULT_IGNORED_FIELDS
+            .iter()
+            .copied()
+            .chain(ignored_fields.iter().map(String::as_str))
+            .map(parse_pointer)
+            .collect::<Result<Vec<_>>>()?;
+        let masks = masks
+            .iter()
+            .map(|mask| Regex::new(mask).with_context(|| format!("parsing --replay-mask `{mask}`")))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            ignored_fields,
+            masks,
+        })
+    }
+
+    /// Canonical JSON for `body` with ignored fields removed, masks applied,
+    /// and object keys sorted.
+    pub(crate) fn canonical_body(&self, body: &Value) -> String {
+        let mut body = body.clone();
+        for pointer in &self.ignored_fields {
+            remove_pointer(&mut body, pointer);
+        }
+        let body = self.normalize(body);
+        serde_json::to_string(&body).unwrap_or_default()
+    }
+
+    fn normalize(&self, value: Value) -> Value {
+        match value {
+            Value::String(text) => Value::String(self.masks.iter().fold(text, |text, mask| {
+                mask.replace_all(&text, MASKED_VALUE).into_owned()
+            })),
+            Value::Array(items) => {
+                Value::Array(items.into_iter().map(|item| self.normalize(item)).collect())
+            }
+            Value::Object(object) => {
+                let mut entries = object.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+                Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, self.normalize(value)))
+                        .collect::<Map<_, _>>(),
+                )
+            }
+            other => other,
+        }
+    }
+}
+
+fn parse_pointer(pointer: &str) -> Result<Vec<String>> {
+    let Some(rest) = pointer.strip_prefix('/') else {
+        anyhow::bail!("--replay-ignore `{pointer}` must be a JSON pointer starting with `/`");
+    };
+    Ok(rest
+        .split('/')
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn remove_pointer(value: &mut Value, pointer: &[String]) {
+    let [segment, rest @ ..] = pointer else {
+        return;
+    };
+    match value {
+        Value::Object(object) if rest.is_empty() => {
+            if segment == "*" {
+                object.clear();
+            } else {
+                object.remove(segment);
+            }
+        }
+        Value::Object(object) => {
+            if segment == "*" {
+                object
+                    .values_mut()
+                    .for_each(|child| remove_pointer(child, rest));
+            } else if let Some(child) = object.get_mut(segment) {
+                remove_pointer(child, rest);
+            }
+        }
+        Value::Array(items) if rest.is_empty() => {
+            if segment == "*" {
+                items.clear();
+            } else if let Ok(index) = segment.parse::<usize>()
+                && index < items.len()
+            {
+                items.remove(index);
+            }
+        }
+        Value::Array(items) => {
+            if segment == "*" {
+                items
+                    .iter_mut()
+                    .for_each(|child| remove_pointer(child, rest));
+            } else if let Some(child) = segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get_mut(index))
+            {
+                remove_pointer(child, rest);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Short stable hash of a canonical request body, used in logs and errors.
+pub(crate) fn fingerprint(canonical_body: &str) -> String {
+    // FNV-1a keeps fingerprints stable across builds, unlike `DefaultHasher`.
+    let hash = canonical_body
+        .bytes()
+        .fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+    format!("{hash:016x}")
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct RecordedResponse {
+    pub(crate) status: u16,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+struct Recordings {
+    responses: Vec<RecordedResponse>,
+    next: usize,
+}
+
+/// Responses recorded with `--dump-dir`, keyed by canonical request body.
+///
+/// Identical requests are answered in recording order; once a request's
+/// recordings are used up, the last one keeps being served.
+pub(crate) struct ReplayStore {
+    matcher: RequestMatcher,
+    recordings: Mutex<HashMap<String, Recordings>>,
+}
+
+impl ReplayStore {
+    pub(crate) fn load(replay_dir: &Path, matcher: RequestMatcher) -> Result<Self> {
+        let mut request_paths = fs::read_dir(replay_dir)
+            .with_context(|| format!("reading --replay-dir {}", replay_dir.display()))?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?
+            .into_iter()
+            .filter(|path| path.to_string_lossy().ends_with(REQUEST_DUMP_SUFFIX))
+            .collect::<Vec<_>>();
+        request_paths.sort();
+
+        let mut recordings = HashMap::<String, Recordings>::new();
+        for request_path in request_paths {
+            let response_path = response_path_for(&request_path);
+            if !response_path.exists() {
+                eprintln!(
+                    "responses-api-proxy: skipping {} without a recorded response",
+                    request_path.display()
+                );
+                continue;
+            }
+            let request: RequestDump = read_json(&request_path)?;
+            let response: ResponseDump = read_json(&response_path)?;
+            recordings
+                .entry(matcher.canonical_body(&request.body))
+                .or_insert_with(|| Recordings {
+                    responses: Vec::new(),
+                    next: 0,
+                })
+                .responses
+                .push(recorded_response(response));
+        }
+
+        Ok(Self {
+            matcher,
+            recordings: Mutex::new(recordings),
+        })
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.recordings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .values()
+            .map(|recordings| recordings.responses.len())
+            .sum()
+    }
+
+    /// Returns the next recorded response for `body`, or the request
+    /// fingerprint when nothing matches.
+    pub(crate) fn next_response(&self, body: &[u8]) -> Result<RecordedResponse, String> {
+        let canonical_body = self.matcher.canonical_body(&dump_body(body));
+        let mut recordings = self
+            .recordings
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let Some(recordings) = recordings.get_mut(&canonical_body) else {
+            return Err(fingerprint(&canonical_body));
+        };
+        let index = recordings.next.min(recordings.responses.len() - 1);
+        recordings.next += 1;
+        Ok(recordings.responses[index].clone())
+    }
+}
+
+fn response_path_for(request_path: &Path) -> PathBuf {
+    let request_path = request_path.to_string_lossy();
+    let prefix = request_path
+        .strip_suffix(REQUEST_DUMP_SUFFIX)
+        .unwrap_or(&request_path);
+    PathBuf::from(format!("{prefix}{RESPONSE_DUMP_SUFFIX}"))
+}
+
+fn read_json<T: serde::de::DeserializeOwned>(path: &Path) -> Result<T> {
+    let bytes = fs::read(path).with_context(|| format!("reading {}", path.display()))?;
+    serde_json::from_slice(&bytes).with_context(|| format!("parsing {}", path.display()))
+}
+
+fn recorded_response(response: ResponseDump) -> RecordedResponse {
+    let body = match response.body {
+        Value::String(text) => text.into_bytes(),
+        other => serde_json::to_vec(&other).unwrap_or_default(),
+    };
+    let headers = response
+        .headers
+        .into_iter()
+        .filter(|HeaderDump { name, value }| {
+            value != REDACTED_HEADER_VALUE
+                && !matches!(
+                    name.to_ascii_lowercase().as_str(),
+                    "content-length" | "transfer-encoding" | "connection" | "trailer" | "upgrade"
+                )
+        })
+        .map(|HeaderDump { name, value }| (name, value))
+        .collect();
+    RecordedResponse {
+        status: response.status,
+        headers,
+        body,
+    }
+}
+
+pub(crate) fn replay_request(store: &ReplayStore, mut req: Request) -> Result<()> {
+    if req.method() != &Method::Post || req.url() != "/v1/responses" {
+        let _ = req.respond(Response::new_empty(StatusCode(403)));
+        return Ok(());
+    }
+
+    let mut body = Vec::new();
+    req.as_reader().read_to_end(&mut body)?;
+
+    let response = match store.next_response(&body) {
+        Ok(recorded) => {
+            let headers = recorded
+                .headers
+                .iter()
+                .filter_map(|(name, value)| {
+                    Header::from_bytes(name.as_bytes(), value.as_bytes()).ok()
+                })
+                .collect();
+            let body_len = recorded.body.len();
+            Response::new(
+                StatusCode(recorded.status),
+                headers,
+                io::Cursor::new(recorded.body),
+                Some(body_len),
+                None,
+            )
+        }
+        Err(fingerprint) => {
+            eprintln!("responses-api-proxy: no recording matches request {fingerprint}");
+            let body = json!({
+                "error": {
+                    "type": "replay_miss",
+                    "message": format!("no recorded response matches request {fingerprint}"),
+                }
+            })
+            .to_string()
+            .into_bytes();
+            let body_len = body.len();
+            Response::new(
+                StatusCode(404),
+                Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                    .into_iter()
+                    .collect(),
+                io::Cursor::new(body),
+                Some(body_len),
+                None,
+            )
+        }
+    };
+    let _ = req.respond(response);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+    use std::io::Read;
+
+    use pretty_assertions::assert_eq;
+    use reqwest::header::CONTENT_TYPE;
+    use reqwest::header::HeaderMap;
+    use reqwest::header::HeaderValue;
+    use serde_json::json;
+    use tempfile::TempDir;
+    use tiny_http::Method;
+
+    use super::RecordedResponse;
+    use super::ReplayStore;
+    use super::RequestMatcher;
+    use crate::dump::ExchangeDumper;
+
+    #[test]
+    fn matcher_ignores_volatile_fields_and_masks_strings() {
+        let matcher = RequestMatcher::new(
+            &["/input/*/id".to_string()],
+            &[r"/tmp/[A-Za-z0-9.]+".to_string()],
+        )
+        .expect("matcher");
+
+        let recorded = json!({
+            "model": "gpt-5.4",
+            "prompt_cache_key": "thread-1",
+            "input": [{"id": "msg_1", "text": "cwd is /tmp/.tmpAbc"}],
+        });
+        let replayed = json!({
+            "input": [{"text": "cwd is /tmp/.tmpXyz", "id": "msg_2"}],
+            "prompt_cache_key": "thread-2",
+            "model": "gpt-5.4",
+        });
+
+        assert_eq!(
+            matcher.canonical_body(&recorded),
+            matcher.canonical_body(&replayed)
+        );
+        assert_eq!(
+            matcher.canonical_body(&replayed),
+            r#"{"input":[{"text":"cwd is [MASKED]"}],"model":"gpt-5.4"}"#
+        );
+    }
+
+    #[test]
+    fn store_replays_dumped_exchanges_in_order() {
+        let dump_dir = TempDir::new().expect("create test dump dir");
+        let dumper = ExchangeDumper::new(dump_dir.path().to_path_buf()).expect("create dumper");
+        for response_body in ["data: first\n\n", "data: second\n\n"] {
+            record_exchange(&dumper, response_body);
+        }
+
+        let store = ReplayStore::load(
+            dump_dir.path(),
+            RequestMatcher::new(&[], &[]).expect("matcher"),
+        )
+        .expect("load store");
+        let response = |body: &str| RecordedResponse {
+            status: 200,
+            headers: vec![("content-type".to_string(), "text/event-stream".to_string())],
+            body: body.as_bytes().to_vec(),
+        };
+
+        assert_eq!(store.len(), 2);
+        assert_eq!(
+            store.next_response(br#"{ "model": "m" }"#),
+            Ok(response("data: first\n\n"))
+        );
+        assert_eq!(
+            store.next_response(br#"{"model":"m"}"#),
+            Ok(response("data: second\n\n"))
+        );
+        assert_eq!(
+            store.next_response(br#"{"model":"m"}"#),
+            Ok(response("data: second\n\n"))
+        );
+        assert!(store.next_response(br#"{"model":"other"}"#).is_err());
+    }
+
+    #[test]
+    fn store_replays_later_recording_runs_after_earlier_ones() {
+        let dump_dir = TempDir::new().expect("create test dump dir");
+        let first_run =
+            ExchangeDumper::new(dump_dir.path().to_path_buf()).expect("create first dumper");
+        record_exchange(&first_run, "data: first\n\n");
+        record_exchange(&first_run, "data: second\n\n");
+        // Run prefixes have millisecond resolution.
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let second_run =
+            ExchangeDumper::new(dump_dir.path().to_path_buf()).expect("create second dumper");
+        record_exchange(&second_run, "data: third\n\n");
+
+        let store = ReplayStore::load(
+            dump_dir.path(),
+            RequestMatcher::new(&[], &[]).expect("matcher"),
+        )
+        .expect("load store");
+        let bodies = (0..3)
+            .map(|_| {
+                store
+                    .next_response(br#"{"model":"m"}"#)
+                    .map(|response| String::from_utf8_lossy(&response.body).into_owned())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(store.len(), 3);
+        assert_eq!(
+            bodies,
+            vec![
+                Ok("data: first\n\n".to_string()),
+                Ok("data: second\n\n".to_string()),
+                Ok("data: third\n\n".to_string()),
+            ]
+        );
+    }
+
+    fn record_exchange(dumper: &ExchangeDumper, response_body: &str) {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+        let mut sink = Vec::new();
+        dumper
+            .dump_request(&Method::Post, "/v1/responses", &[], br#"{"model":"m"}"#)
+            .expect("dump request")
+            .tee_response_body(
+                /*status*/ 200,
+                &headers,
+                Cursor::new(response_body.as_bytes().to_vec()),
+            )
+            .read_to_end(&mut sink)
+            .expect("read response body");
+    }
+}
@@ -22,7 +22,13 @@ use crate::logging_client_handler::LoggingClientHandler;
 use crate::rmcp_client::Elicitation;
 use crate::rmcp_client::ElicitationPauseState;
 use crate::rmcp_client::ElicitationResponse;
+use crate::rmcp_client::ListRoots;
+use crate::rmcp_client::McpRoot;
+use crate::rmcp_client::SamplingRequest;
+use crate::rmcp_client::SamplingResponse;
 use crate::rmcp_client::SendElicitation;
+use crate::rmcp_client::SendSampling;
+use crate::rmcp_client::ServerRequestHandlers;
 
 const MCP_PROGRESS_TOKEN_META_KEY: &str = "progressToken";
 const OPENAI_FORM_METHOD: &str = "openai/form";
@@ -41,6 +47,8 @@ pub(crate) struct ElicitationClientService {
     handler: LoggingClientHandler,
     supports_openai_form: bool,
     send_elicitation: Arc<SendElicitation>,
+    send_sampling: Option<Arc<SendSampling>>,
+    list_roots: Option<Arc<ListRoots>>,
     pause_state: ElicitationPauseState,
 }
 
@@ -48,6 +56,7 @@ impl ElicitationClientService {
     pub(crate) fn new(
         client_info: ClientInfo,
         send_elicitation: SendElicitation,
+        server_requests: ServerRequestHandlers,
         pause_state: ElicitationPauseState,
     ) -> Self {
         let supports_openai_form = client_info
@@ -60,9 +69,12 @@ impl ElicitationClientService {
             handler: LoggingClientHandler::new(
                 client_info,
                 clone_send_elicitation(Arc::clone(&send_elicitation)),
+                server_requests.on_notification,
             ),
             supports_openai_form,
             send_elicitation,
+            send_sampling: server_requests.send_sampling.map(Arc::new),
+            list_roots: server_requests.list_roots.map(Arc::new),
             pause_state,
         }
     }
@@ -79,6 +91,20 @@ impl ElicitationClientService {
             .await
             .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
     }
+
+    async fn create_message(
+        &self,
+        send_sampling: &SendSampling,
+        request: SamplingRequest,
+        context: RequestContext<RoleClient>,
+    ) -> Result<SamplingResponse, rmcp::ErrorData> {
+        // Sampling waits on user approval and a model turn, so it must not
+        // count against the timeout of the tool call that triggered it.
+        let _pause = self.pause_state.enter();
+        send_sampling(context.id, request)
+            .await
+            .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+    }
 }
 
 fn clone_send_elicitation(send_elicitation: Arc<SendElicitation>) -> SendElicitation {
@@ -110,6 +136,20 @@ impl Service<RoleClient> for ElicitationClientService {
                     response,
                 )?))
             }
+            ServerRequest::CreateMessageRequest(request) => {
+                let Some(send_sampling) = self.send_sampling.as_deref() else {
+                    return Err(unsupported_request("sampling/createMessage"));
+                };
+                let request = sampling_request(request.params)?;
+                let response = self.create_message(send_sampling, request, context).await?;
+                Ok(ClientResult::CustomResult(create_message_result(response)?))
+            }
+            ServerRequest::ListRootsRequest(_) => {
+                let Some(list_roots) = self.list_roots.as_deref() else {
+                    return Err(unsupported_request("roots/list"));
+                };
+                Ok(ClientResult::CustomResult(list_roots_result(list_roots())?))
+            }
             request => {
                 <LoggingClientHandler as Service<RoleClient>>::handle_request(
                     &self.handler,
@@ -204,6 +244,53 @@ fn elicitation_response_result(
         .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
 }
 
+fn unsupported_request(method: &str) -> rmcp::ErrorData {
+    rmcp::ErrorData::new(
+        rmcp::model::ErrorCode::METHOD_NOT_FOUND,
+        format!("{method} is not supported by this client"),
+        None,
+    )
+}
+
+fn sampling_request(params: impl Serialize) -> Result<SamplingRequest, rmcp::ErrorData> {
+    serde_json::to_value(params)
+        .and_then(serde_json::from_value)
+        .map_err(|err| rmcp::ErrorData::invalid_params(err.to_string(), None))
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CreateMessageResultJson {
+    role: &'static str,
+    content: Value,
+    model: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_reason: Option<String>,
+}
+
+fn create_message_result(response: SamplingResponse) -> Result<CustomResult, rmcp::ErrorData> {
+    let SamplingResponse {
+        model,
+        text,
+        stop_reason,
+    } = response;
+    let result = CreateMessageResultJson {
+        role: "assistant",
+        content: serde_json::json!({ "type": "text", "text": text }),
+        model,
+        stop_reason,
+    };
+    serde_json::to_value(result)
+        .map(CustomResult)
+        .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+}
+
+fn list_roots_result(roots: Vec<McpRoot>) -> Result<CustomResult, rmcp::ErrorData> {
+    serde_json::to_value(serde_json::json!({ "roots": roots }))
+        .map(CustomResult)
+        .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -215,6 +302,7 @@ mod tests {
     use serde_json::json;
 
     use super::*;
+    use crate::rmcp_client::SamplingRole;
 
     #[test]
     fn restore_context_meta_adds_elicitation_meta_and_removes_progress_token() {
@@ -300,6 +388,85 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sampling_request_reads_text_and_array_content() {
+        let request = sampling_request(json!({
+            "messages": [
+                { "role": "user", "content": { "type": "text", "text": "Summarize" } },
+                {
+                    "role": "assistant",
+                    "content": [
+                        { "type": "text", "text": "First" },
+                        { "type": "image", "data": "AAAA", "mimeType": "image/png" },
+                        { "type": "text", "text": "Second" }
+                    ]
+                }
+            ],
+            "systemPrompt": "Be brief.",
+            "maxTokens": 128,
+        }))
+        .expect("valid sampling request");
+
+        assert_eq!(request.system_prompt.as_deref(), Some("Be brief."));
+        assert_eq!(request.max_tokens, 128);
+        assert_eq!(
+            request
+                .messages
+                .iter()
+                .map(|message| (message.role, message.text()))
+                .collect::<Vec<_>>(),
+            vec![
+                (SamplingRole::User, "Summarize".to_string()),
+                (SamplingRole::Assistant, "First\nSecond".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn create_message_result_serializes_text_response() {
+        let result = create_message_result(SamplingResponse {
+            model: "gpt-5".to_string(),
+            text: "Done.".to_string(),
+            stop_reason: Some("endTurn".to_string()),
+        })
+        .expect("sampling response should serialize");
+
+        assert_eq!(
+            result.0,
+            json!({
+                "role": "assistant",
+                "content": { "type": "text", "text": "Done." },
+                "model": "gpt-5",
+                "stopReason": "endTurn",
+            })
+        );
+    }
+
+    #[test]
+    fn list_roots_result_omits_missing_names() {
+        let result = list_roots_result(vec![
+            McpRoot {
+                uri: "file:///repo".to_string(),
+                name: Some("repo".to_string()),
+            },
+            McpRoot {
+                uri: "file:///tmp/scratch".to_string(),
+                name: None,
+            },
+        ])
+        .expect("roots should serialize");
+
+        assert_eq!(
+            result.0,
+            json!({
+                "roots": [
+                    { "uri": "file:///repo", "name": "repo" },
+                    { "uri": "file:///tmp/scratch" },
+                ]
+            })
+        );
+    }
+
     fn form_request(meta: Option<Meta>) -> CreateElicitationRequestParams {
         CreateElicitationRequestParams::FormElicitationParams {
             meta,
@@ -37,9 +37,19 @@ pub use perform_oauth_login::perform_oauth_login_silent;
 pub use rmcp::model::ElicitationAction;
 pub use rmcp_client::Elicitation;
 pub use rmcp_client::ElicitationResponse;
+pub use rmcp_client::ListRoots;
 pub use rmcp_client::ListToolsWithConnectorIdResult;
+pub use rmcp_client::McpRoot;
+pub use rmcp_client::McpServerNotification;
+pub use rmcp_client::OnServerNotification;
 pub use rmcp_client::RmcpClient;
+pub use rmcp_client::SamplingMessage;
+pub use rmcp_client::SamplingRequest;
+pub use rmcp_client::SamplingResponse;
+pub use rmcp_client::SamplingRole;
 pub use rmcp_client::SendElicitation;
+pub use rmcp_client::SendSampling;
+pub use rmcp_client::ServerRequestHandlers;
 pub use rmcp_client::ToolWithConnectorId;
 pub use startup_error::is_authentication_required_error;
 pub use stdio_server_launcher::ExecutorStdioServerLauncher;
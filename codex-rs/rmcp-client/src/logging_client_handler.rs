@@ -18,19 +18,33 @@ use tracing::info;
 use tracing::warn;
 
 use crate::rmcp_client::Elicitation;
+use crate::rmcp_client::McpServerNotification;
+use crate::rmcp_client::OnServerNotification;
 use crate::rmcp_client::SendElicitation;
 
 #[derive(Clone)]
 pub(crate) struct LoggingClientHandler {
     client_info: ClientInfo,
     send_elicitation: Arc<SendElicitation>,
+    on_notification: Option<Arc<OnServerNotification>>,
 }
 
 impl LoggingClientHandler {
-    pub(crate) fn new(client_info: ClientInfo, send_elicitation: SendElicitation) -> Self {
+    pub(crate) fn new(
+        client_info: ClientInfo,
+        send_elicitation: SendElicitation,
+        on_notification: Option<OnServerNotification>,
+    ) -> Self {
         Self {
             client_info,
             send_elicitation: Arc::new(send_elicitation),
+            on_notification: on_notification.map(Arc::new),
+        }
+    }
+
+    fn notify(&self, notification: McpServerNotification) {
+        if let Some(on_notification) = self.on_notification.as_deref() {
+            on_notification(notification);
         }
     }
 }
@@ -75,18 +89,22 @@ impl ClientHandler for LoggingClientHandler {
         _context: NotificationContext<RoleClient>,
     ) {
         info!("MCP server resource updated (uri: {})", params.uri);
+        self.notify(McpServerNotification::ResourceUpdated { uri: params.uri });
     }
 
     async fn on_resource_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server resource list changed");
+        self.notify(McpServerNotification::ResourceListChanged);
     }
 
     async fn on_tool_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server tool list changed");
+        self.notify(McpServerNotification::ToolListChanged);
     }
 
     async fn on_prompt_list_changed(&self, _context: NotificationContext<RoleClient>) {
         info!("MCP server prompt list changed");
+        self.notify(McpServerNotification::PromptListChanged);
     }
 
     fn get_info(&self) -> ClientInfo {
@@ -31,8 +31,12 @@ use rmcp::model::CustomNotification;
 use rmcp::model::CustomRequest;
 use rmcp::model::ElicitationAction;
 use rmcp::model::Extensions;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::InitializeRequestParams;
 use rmcp::model::InitializeResult;
+use rmcp::model::JsonObject;
+use rmcp::model::ListPromptsResult;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
@@ -42,6 +46,7 @@ use rmcp::model::ReadResourceResult;
 use rmcp::model::RequestId;
 use rmcp::model::RequestParamsMeta;
 use rmcp::model::ServerResult;
+use rmcp::model::SubscribeRequestParams;
 use rmcp::model::Tool;
 use rmcp::service::RoleClient;
 use rmcp::service::RunningService;
@@ -309,6 +314,105 @@ pub type SendElicitation = Box<
     dyn Fn(RequestId, Elicitation) -> BoxFuture<'static, Result<ElicitationResponse>> + Send + Sync,
 >;
 
+/// Message role in an MCP `sampling/createMessage` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SamplingRole {
+    User,
+    Assistant,
+}
+
+/// One conversation message in an MCP `sampling/createMessage` request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SamplingMessage {
+    pub role: SamplingRole,
+    /// A single content block or, in newer protocol versions, an array of blocks.
+    pub content: Value,
+}
+
+impl SamplingMessage {
+    /// Concatenates the text blocks of this message. Non-text blocks are ignored.
+    pub fn text(&self) -> String {
+        let blocks = match &self.content {
+            Value::Array(blocks) => blocks.iter().collect::<Vec<_>>(),
+            block => vec![block],
+        };
+        blocks
+            .into_iter()
+            .filter(|block| block.get("type").and_then(Value::as_str) == Some("text"))
+            .filter_map(|block| block.get("text").and_then(Value::as_str))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// A server-initiated request asking the client to run a model completion.
+/// https://modelcontextprotocol.io/specification/2025-06-18/client/sampling
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SamplingRequest {
+    pub messages: Vec<SamplingMessage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    pub max_tokens: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model_preferences: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SamplingResponse {
+    pub model: String,
+    pub text: String,
+    pub stop_reason: Option<String>,
+}
+
+/// Interface for answering `sampling/createMessage` requests.
+pub type SendSampling = Box<
+    dyn Fn(RequestId, SamplingRequest) -> BoxFuture<'static, Result<SamplingResponse>>
+        + Send
+        + Sync,
+>;
+
+/// A workspace root advertised to MCP servers through `roots/list`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct McpRoot {
+    pub uri: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// Returns the roots to report for a `roots/list` request.
+pub type ListRoots = Box<dyn Fn() -> Vec<McpRoot> + Send + Sync>;
+
+/// A change announced by an MCP server through a notification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum McpServerNotification {
+    /// `notifications/resources/updated` for a subscribed resource.
+    ResourceUpdated {
+        uri: String,
+    },
+    ResourceListChanged,
+    ToolListChanged,
+    PromptListChanged,
+}
+
+/// Receives change notifications sent by an MCP server.
+pub type OnServerNotification = Box<dyn Fn(McpServerNotification) + Send + Sync>;
+
+/// Optional handlers for server-to-client requests beyond elicitation. Requests
+/// without a handler are answered with an error; notifications without a
+/// handler are only logged.
+#[derive(Default)]
+pub struct ServerRequestHandlers {
+    pub send_sampling: Option<SendSampling>,
+    pub list_roots: Option<ListRoots>,
+    pub on_notification: Option<OnServerNotification>,
+}
+
 pub struct ToolWithConnectorId {
     pub tool: Tool,
     pub connector_id: Option<String>,
@@ -432,10 +536,12 @@ impl RmcpClient {
         params: InitializeRequestParams,
         timeout: Option<Duration>,
         send_elicitation: SendElicitation,
+        server_requests: ServerRequestHandlers,
     ) -> Result<InitializeResult> {
         let client_service = ElicitationClientService::new(
             params.clone(),
             send_elicitation,
+            server_requests,
             self.elicitation_pause_state.clone(),
         );
         let pending_transport = {
@@ -602,6 +708,67 @@ impl RmcpClient {
         Ok(result)
     }
 
+    pub async fn list_prompts(
+        &self,
+        params: Option<PaginatedRequestParams>,
+        timeout: Option<Duration>,
+    ) -> Result<ListPromptsResult> {
+        self.refresh_oauth_if_needed().await?;
+        let result = self
+            .run_service_operation("prompts/list", timeout, move |service| {
+                let params = params.clone();
+                async move { service.list_prompts(params).await }.boxed()
+            })
+            .await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
+    pub async fn get_prompt(
+        &self,
+        name: String,
+        arguments: Option<JsonObject>,
+        timeout: Option<Duration>,
+    ) -> Result<GetPromptResult> {
+        self.refresh_oauth_if_needed().await?;
+        let mut params = GetPromptRequestParams::new(name);
+        params.arguments = arguments;
+        let result = self
+            .run_service_operation("prompts/get", timeout, move |service| {
+                let params = params.clone();
+                async move { service.get_prompt(params).await }.boxed()
+            })
+            .await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
+    /// Ask the server to send `notifications/resources/updated` when `uri` changes.
+    pub async fn subscribe_resource(&self, uri: String, timeout: Option<Duration>) -> Result<()> {
+        self.refresh_oauth_if_needed().await?;
+        let params = SubscribeRequestParams::new(uri);
+        self.run_service_operation("resources/subscribe", timeout, move |service| {
+            let params = params.clone();
+            async move { service.subscribe(params).await }.boxed()
+        })
+        .await?;
+        self.persist_oauth_tokens().await;
+        Ok(())
+    }
+
+    /// Tell the server that the result of `roots/list` has changed.
+    pub async fn notify_roots_list_changed(&self) -> Result<()> {
+        self.refresh_oauth_if_needed().await?;
+        self.run_service_operation(
+            "notifications/roots/list_changed",
+            /*timeout*/ None,
+            move |service| async move { service.notify_roots_list_changed().await }.boxed(),
+        )
+        .await?;
+        self.persist_oauth_tokens().await;
+        Ok(())
+    }
+
     pub async fn call_tool(
         &self,
         name: String,
@@ -13,6 +13,7 @@ use codex_rmcp_client::ElicitationAction;
 use codex_rmcp_client::ElicitationResponse;
 use codex_rmcp_client::LocalStdioServerLauncher;
 use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::ServerRequestHandlers;
 use futures::FutureExt as _;
 use rmcp::model::ClientCapabilities;
 use rmcp::model::Implementation;
@@ -150,6 +151,7 @@ async fn shutdown_kills_initialized_stdio_server_with_in_flight_operation() -> R
                 }
                 .boxed()
             }),
+            ServerRequestHandlers::default(),
         )
         .await?;
 
@@ -26,6 +26,7 @@ use codex_exec_server::RemoteExecServerConnectArgs;
 use codex_rmcp_client::ElicitationAction;
 use codex_rmcp_client::ElicitationResponse;
 use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::ServerRequestHandlers;
 use codex_utils_cargo_bin::CargoBinError;
 use futures::FutureExt as _;
 use pretty_assertions::assert_eq;
@@ -120,6 +121,7 @@ pub(crate) async fn initialize_client(client: &RmcpClient) -> anyhow::Result<()>
                 }
                 .boxed()
             }),
+            ServerRequestHandlers::default(),
         )
         .await?;
     Ok(())
@@ -158,6 +160,7 @@ pub(crate) async fn create_remote_client(
                 }
                 .boxed()
             }),
+            ServerRequestHandlers::default(),
         )
         .await?;
 
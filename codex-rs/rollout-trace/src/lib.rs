@@ -17,6 +17,7 @@ mod protocol_event;
 mod raw_event;
 mod reducer;
 mod thread;
+mod timeline;
 mod tool_dispatch;
 mod writer;
 
@@ -64,6 +65,14 @@ pub use thread::CODEX_ROLLOUT_TRACE_ROOT_ENV;
 pub use thread::ThreadStartedTraceMetadata;
 /// No-op-capable handle for recording one thread in a rollout bundle.
 pub use thread::ThreadTraceContext;
+/// Conventional file name for an exported Chrome trace-event timeline.
+pub use timeline::CHROME_TRACE_FILE_NAME;
+/// Conventional file name for an exported static HTML report.
+pub use timeline::HTML_REPORT_FILE_NAME;
+/// Project a reduced trace into Chrome trace-event / Perfetto JSON.
+pub use timeline::chrome_trace_events;
+/// Render a reduced trace as a self-contained static HTML report.
+pub use timeline::html_report;
 /// Request data for the canonical Codex tool boundary.
 pub use tool_dispatch::ToolDispatchInvocation;
 /// Tool input observed at the registry boundary.
@@ -82,6 +82,7 @@ fn exec_command_trace_payloads_use_inferred_native_cwd() -> anyhow::Result<()> {
         duration: Duration::from_millis(250),
         formatted_output: "output".to_string(),
         status: ExecCommandStatus::Completed,
+        redacted: false,
     });
 
     let Some(ToolRuntimeTraceEvent::Started { payload, .. }) = tool_runtime_trace_event(&begin)
@@ -0,0 +1,828 @@
+//! Timeline projections of a reduced `RolloutTrace`.
+//!
+//! The reducer output is a graph; these projections flatten it into timed
+//! spans grouped by thread and lane so a slow session can be inspected as a
+//! timeline. Two renderings are provided: Chrome trace-event JSON, which loads
+//! in Perfetto or `chrome://tracing`, and a self-contained static HTML report.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::model::AgentOrigin;
+use crate::model::AgentThread;
+use crate::model::AgentThreadId;
+use crate::model::ExecutionStatus;
+use crate::model::ExecutionWindow;
+use crate::model::RolloutTrace;
+use crate::model::TerminalRequest;
+use crate::model::TokenUsage;
+use crate::model::ToolCall;
+use crate::model::ToolCallKind;
+use crate::model::ToolCallSummary;
+
+/// Conventional file name for an exported Chrome trace-event timeline.
+pub const CHROME_TRACE_FILE_NAME: &str = "timeline.json";
+/// Conventional file name for an exported static HTML report.
+pub const HTML_REPORT_FILE_NAME: &str = "report.html";
+
+const MAX_LABEL_CHARS: usize = 80;
+const SLOWEST_SPAN_COUNT: usize = 20;
+
+/// Row within a thread's track group. Lanes are ordered as declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Lane {
+    Turn,
+    Inference,
+    Tool,
+    CodeCell,
+    Compaction,
+}
+
+impl Lane {
+    fn label(self) -> &'static str {
+        match self {
+            Lane::Turn => "turns",
+            Lane::Inference => "inference",
+            Lane::Tool => "tools",
+            Lane::CodeCell => "code cells",
+            Lane::Compaction => "compaction",
+        }
+    }
+}
+
+/// One timed object on the timeline.
+#[derive(Debug, Clone)]
+struct Span {
+    thread_id: AgentThreadId,
+    lane: Lane,
+    /// Concurrent spans in the same lane are packed into numbered rows.
+    row: usize,
+    name: String,
+    start_ms: i64,
+    end_ms: i64,
+    /// `false` when the trace ended before the object did.
+    finished: bool,
+    status: ExecutionStatus,
+    usage: Option<TokenUsage>,
+    args: Map<String, Value>,
+}
+
+impl Span {
+    fn duration_ms(&self) -> i64 {
+        self.end_ms - self.start_ms
+    }
+}
+
+/// Instant marker, such as a compaction checkpoint being installed.
+#[derive(Debug, Clone)]
+struct Marker {
+    thread_id: AgentThreadId,
+    lane: Lane,
+    name: String,
+    at_ms: i64,
+    args: Map<String, Value>,
+}
+
+struct Timeline<'a> {
+    trace: &'a RolloutTrace,
+    /// Threads in display order: root first, then children by start time.
+    threads: Vec<&'a AgentThread>,
+    spans: Vec<Span>,
+    markers: Vec<Marker>,
+    end_ms: i64,
+}
+
+impl<'a> Timeline<'a> {
+    fn new(trace: &'a RolloutTrace) -> Self {
+        let mut threads = trace.threads.values().collect::<Vec<_>>();
+        threads.sort_by_key(|thread| {
+            (
+                thread.thread_id != trace.root_thread_id,
+                thread.execution.started_at_unix_ms,
+                thread.execution.started_seq,
+            )
+        });
+
+        let end_ms = trace
+            .ended_at_unix_ms
+            .unwrap_or_else(|| latest_timestamp(trace));
+        let mut timeline = Self {
+            trace,
+            threads,
+            spans: Vec::new(),
+            markers: Vec::new(),
+            end_ms,
+        };
+        timeline.collect();
+        timeline.assign_rows();
+        timeline
+    }
+
+    fn collect(&mut self) {
+        let trace = self.trace;
+        for turn in trace.codex_turns.values() {
+            self.push_span(
+                &turn.thread_id,
+                Lane::Turn,
+                "turn".to_string(),
+                &turn.execution,
+                /*usage*/ None,
+                object_args([("codex_turn_id", turn.codex_turn_id.as_str())]),
+            );
+        }
+        for inference in trace.inference_calls.values() {
+            let mut args = object_args([
+                ("inference_call_id", inference.inference_call_id.as_str()),
+                ("model", inference.model.as_str()),
+                ("provider", inference.provider_name.as_str()),
+            ]);
+            args.insert(
+                "request_items".to_string(),
+                json!(inference.request_item_ids.len()),
+            );
+            args.insert(
+                "response_items".to_string(),
+                json!(inference.response_item_ids.len()),
+            );
+            self.push_span(
+                &inference.thread_id,
+                Lane::Inference,
+                format!("inference {}", inference.model),
+                &inference.execution,
+                inference.usage.clone(),
+                args,
+            );
+        }
+        for tool in trace.tool_calls.values() {
+            let mut args = object_args([("tool_call_id", tool.tool_call_id.as_str())]);
+            if let Some(call_id) = &tool.model_visible_call_id {
+                args.insert("call_id".to_string(), json!(call_id));
+            }
+            self.push_span(
+                &tool.thread_id,
+                Lane::Tool,
+                tool_label(trace, tool),
+                &tool.execution,
+                /*usage*/ None,
+                args,
+            );
+        }
+        for cell in trace.code_cells.values() {
+            let first_line = cell.source_js.lines().next().unwrap_or_default();
+            self.push_span(
+                &cell.thread_id,
+                Lane::CodeCell,
+                truncate(&format!("exec {first_line}")),
+                &cell.execution,
+                /*usage*/ None,
+                object_args([
+                    ("code_cell_id", cell.code_cell_id.as_str()),
+                    ("source", cell.source_js.as_str()),
+                ]),
+            );
+        }
+        for request in trace.compaction_requests.values() {
+            self.push_span(
+                &request.thread_id,
+                Lane::Compaction,
+                format!("compaction {}", request.model),
+                &request.execution,
+                /*usage*/ None,
+                object_args([
+                    ("compaction_id", request.compaction_id.as_str()),
+                    ("model", request.model.as_str()),
+                ]),
+            );
+        }
+        for compaction in trace.compactions.values() {
+            let mut args = object_args([("compaction_id", compaction.compaction_id.as_str())]);
+            args.insert(
+                "input_items".to_string(),
+                json!(compaction.input_item_ids.len()),
+            );
+            args.insert(
+                "replacement_items".to_string(),
+                json!(compaction.replacement_item_ids.len()),
+            );
+            self.markers.push(Marker {
+                thread_id: compaction.thread_id.clone(),
+                lane: Lane::Compaction,
+                name: "compaction installed".to_string(),
+                at_ms: compaction.installed_at_unix_ms,
+                args,
+            });
+        }
+
+        self.spans
+            .sort_by_key(|span| (span.start_ms, span.lane, std::cmp::Reverse(span.end_ms)));
+    }
+
+    fn push_span(
+        &mut self,
+        thread_id: &AgentThreadId,
+        lane: Lane,
+        name: String,
+        execution: &ExecutionWindow,
+        usage: Option<TokenUsage>,
+        args: Map<String, Value>,
+    ) {
+        let end_ms = execution
+            .ended_at_unix_ms
+            .unwrap_or(self.end_ms)
+            .max(execution.started_at_unix_ms);
+        self.spans.push(Span {
+            thread_id: thread_id.clone(),
+            lane,
+            row: 0,
+            name,
+            start_ms: execution.started_at_unix_ms,
+            end_ms,
+            finished: execution.ended_at_unix_ms.is_some(),
+            status: execution.status.clone(),
+            usage,
+            args,
+        });
+    }
+
+    /// Packs overlapping spans of one thread lane into separate rows so each
+    /// row holds non-overlapping spans. Spans must already be sorted by start.
+    fn assign_rows(&mut self) {
+        let mut row_ends = BTreeMap::<(AgentThreadId, Lane), Vec<i64>>::new();
+        for span in &mut self.spans {
+            let ends = row_ends
+                .entry((span.thread_id.clone(), span.lane))
+                .or_default();
+            let row = match ends.iter().position(|end| *end <= span.start_ms) {
+                Some(row) => row,
+                None => {
+                    ends.push(span.end_ms);
+                    ends.len() - 1
+                }
+            };
+            ends[row] = span.end_ms;
+            span.row = row;
+        }
+    }
+
+    fn thread_label(&self, thread_id: &str) -> String {
+        let Some(thread) = self.trace.threads.get(thread_id) else {
+            return thread_id.to_string();
+        };
+        match &thread.nickname {
+            Some(nickname) => format!("{} ({nickname})", thread.agent_path),
+            None => thread.agent_path.clone(),
+        }
+    }
+
+    /// Threads in display order, followed by any thread that owns spans or
+    /// markers without a reduced `AgentThread`.
+    fn thread_ids(&self) -> Vec<AgentThreadId> {
+        let mut thread_ids = self
+            .threads
+            .iter()
+            .map(|thread| thread.thread_id.clone())
+            .collect::<Vec<_>>();
+        let owners = self
+            .spans
+            .iter()
+            .map(|span| &span.thread_id)
+            .chain(self.markers.iter().map(|marker| &marker.thread_id));
+        for thread_id in owners {
+            if !thread_ids.contains(thread_id) {
+                thread_ids.push(thread_id.clone());
+            }
+        }
+        thread_ids
+    }
+
+    /// Sorted distinct `(lane, row)` pairs used by one thread.
+    fn rows_for_thread(&self, thread_id: &str) -> Vec<(Lane, usize)> {
+        let mut rows = self
+            .spans
+            .iter()
+            .filter(|span| span.thread_id == thread_id)
+            .map(|span| (span.lane, span.row))
+            .chain(
+                self.markers
+                    .iter()
+                    .filter(|marker| marker.thread_id == thread_id)
+                    .map(|marker| (marker.lane, 0)),
+            )
+            .collect::<Vec<_>>();
+        rows.sort();
+        rows.dedup();
+        rows
+    }
+}
+
+/// Projects a reduced trace into Chrome trace-event JSON.
+///
+/// Each agent thread becomes a process and each lane (turns, inference, tools,
+/// code cells, compaction) a thread track within it. Timestamps are relative to
+/// the rollout start. Token usage is attached to inference spans and also
+/// emitted as cumulative counters per agent thread.
+pub fn chrome_trace_events(trace: &RolloutTrace) -> Value {
+    let timeline = Timeline::new(trace);
+    let origin_ms = trace.started_at_unix_ms;
+    let to_us = |unix_ms: i64| (unix_ms - origin_ms) * 1000;
+    let mut events = Vec::new();
+
+    let thread_ids = timeline.thread_ids();
+    let pids = thread_ids
+        .iter()
+        .enumerate()
+        .map(|(index, thread_id)| (thread_id.as_str(), index + 1))
+        .collect::<BTreeMap<_, _>>();
+    for thread_id in &thread_ids {
+        let pid = pids[thread_id.as_str()];
+        events.push(json!({
+            "ph": "M",
+            "name": "process_name",
+            "pid": pid,
+            "args": {"name": timeline.thread_label(thread_id)},
+        }));
+        events.push(json!({
+            "ph": "M",
+            "name": "process_sort_index",
+            "pid": pid,
+            "args": {"sort_index": pid},
+        }));
+        for (lane, row) in timeline.rows_for_thread(thread_id) {
+            let tid = chrome_tid(lane, row);
+            events.push(json!({
+                "ph": "M",
+                "name": "thread_name",
+                "pid": pid,
+                "tid": tid,
+                "args": {"name": row_label(lane, row)},
+            }));
+            events.push(json!({
+                "ph": "M",
+                "name": "thread_sort_index",
+                "pid": pid,
+                "tid": tid,
+                "args": {"sort_index": tid},
+            }));
+        }
+    }
+
+    let mut token_totals = BTreeMap::<&str, TokenUsage>::new();
+    for span in &timeline.spans {
+        let pid = pids[span.thread_id.as_str()];
+        let mut args = span.args.clone();
+        args.insert("status".to_string(), json!(status_label(&span.status)));
+        if !span.finished {
+            args.insert("unfinished".to_string(), json!(true));
+        }
+        if let Some(usage) = &span.usage {
+            args.insert("usage".to_string(), json!(usage));
+        }
+        events.push(json!({
+            "ph": "X",
+            "name": span.name,
+            "cat": span.lane.label(),
+            "pid": pid,
+            "tid": chrome_tid(span.lane, span.row),
+            "ts": to_us(span.start_ms),
+            "dur": span.duration_ms() * 1000,
+            "args": args,
+        }));
+
+        if let Some(usage) = &span.usage {
+            let total = token_totals
+                .entry(span.thread_id.as_str())
+                .or_insert_with(|| sum_usage(std::iter::empty()));
+            add_usage(total, usage);
+            events.push(json!({
+                "ph": "C",
+                "name": "tokens",
+                "pid": pid,
+                "ts": to_us(span.end_ms),
+                "args": {
+                    "input": total.input_tokens,
+                    "cached_input": total.cached_input_tokens,
+                    "output": total.output_tokens,
+                    "reasoning_output": total.reasoning_output_tokens,
+                },
+            }));
+        }
+    }
+
+    for marker in &timeline.markers {
+        events.push(json!({
+            "ph": "i",
+            "s": "t",
+            "name": marker.name,
+            "cat": marker.lane.label(),
+            "pid": pids[marker.thread_id.as_str()],
+            "tid": chrome_tid(marker.lane, /*row*/ 0),
+            "ts": to_us(marker.at_ms),
+            "args": marker.args,
+        }));
+    }
+
+    json!({
+        "displayTimeUnit": "ms",
+        "otherData": {
+            "trace_id": trace.trace_id,
+            "rollout_id": trace.rollout_id,
+            "status": trace.status,
+        },
+        "traceEvents": events,
+    })
+}
+
+/// Renders a reduced trace as a self-contained static HTML report.
+///
+/// The report has no scripts or external assets: a summary of time and token
+/// use per thread, the slowest spans, and a timeline drawn with positioned
+/// blocks whose tooltips carry span details.
+pub fn html_report(trace: &RolloutTrace) -> String {
+    let timeline = Timeline::new(trace);
+    let origin_ms = trace.started_at_unix_ms;
+    let total_ms = (timeline.end_ms - origin_ms).max(1);
+    let mut html = String::new();
+
+    let title = format!("Codex rollout {}", trace.rollout_id);
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{REPORT_STYLE}</style>\n</head>\n<body>\n<h1>{}</h1>\n",
+        escape_html(&title),
+        escape_html(&title),
+    );
+
+    let total_usage = sum_usage(timeline.spans.iter().filter_map(|span| span.usage.as_ref()));
+    let _ = write!(
+        html,
+        "<p class=\"meta\">trace <code>{}</code> &middot; status {} &middot; {} &middot; \
+         {} inference calls &middot; {} tool calls &middot; {} input tokens ({} cached) \
+         &middot; {} output tokens</p>\n",
+        escape_html(&trace.trace_id),
+        escape_html(&serde_label(&trace.status)),
+        format_duration(total_ms),
+        trace.inference_calls.len(),
+        trace.tool_calls.len(),
+        total_usage.input_tokens,
+        total_usage.cached_input_tokens,
+        total_usage.output_tokens,
+    );
+
+    html.push_str(
+        "<h2>Threads</h2>\n<table>\n<tr><th>thread</th><th>origin</th><th>wall time</th>\
+         <th>inference</th><th>tools</th><th>code cells</th><th>input tokens</th>\
+         <th>cached</th><th>output tokens</th></tr>\n",
+    );
+    for thread_id in timeline.thread_ids() {
+        let spans = timeline
+            .spans
+            .iter()
+            .filter(|span| span.thread_id == thread_id)
+            .collect::<Vec<_>>();
+        let lane_time = |lane: Lane| {
+            let matching = spans
+                .iter()
+                .filter(|span| span.lane == lane)
+                .collect::<Vec<_>>();
+            let duration_ms = matching
+                .iter()
+                .map(|span| span.end_ms - span.start_ms)
+                .sum();
+            format!("{} in {}", format_duration(duration_ms), matching.len())
+        };
+        let usage = sum_usage(spans.iter().filter_map(|span| span.usage.as_ref()));
+        let (origin, wall_ms) = match trace.threads.get(&thread_id) {
+            Some(thread) => (
+                match &thread.origin {
+                    AgentOrigin::Root => "root".to_string(),
+                    AgentOrigin::Spawned { agent_role, .. } => format!("spawned {agent_role}"),
+                },
+                thread
+                    .execution
+                    .ended_at_unix_ms
+                    .unwrap_or(timeline.end_ms)
+                    .saturating_sub(thread.execution.started_at_unix_ms),
+            ),
+            None => ("unknown".to_string(), 0),
+        };
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td>\
+             <td>{}</td><td>{}</td><td>{}</td></tr>",
+            escape_html(&timeline.thread_label(&thread_id)),
+            escape_html(&origin),
+            format_duration(wall_ms),
+            lane_time(Lane::Inference),
+            lane_time(Lane::Tool),
+            lane_time(Lane::CodeCell),
+            usage.input_tokens,
+            usage.cached_input_tokens,
+            usage.output_tokens,
+        );
+    }
+    html.push_str("</table>\n");
+
+    html.push_str(
+        "<h2>Slowest spans</h2>\n<table>\n<tr><th>span</th><th>kind</th><th>thread</th>\
+         <th>start</th><th>duration</th><th>status</th></tr>\n",
+    );
+    let mut slowest = timeline
+        .spans
+        .iter()
+        .filter(|span| span.lane != Lane::Turn)
+        .collect::<Vec<_>>();
+    slowest.sort_by_key(|span| std::cmp::Reverse(span.duration_ms()));
+    for span in slowest.into_iter().take(SLOWEST_SPAN_COUNT) {
+        let _ = writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>+{}</td><td>{}</td><td>{}</td></tr>",
+            escape_html(&span.name),
+            span.lane.label(),
+            escape_html(&timeline.thread_label(&span.thread_id)),
+            format_duration(span.start_ms - origin_ms),
+            format_duration(span.duration_ms()),
+            span_status_label(span),
+        );
+    }
+    html.push_str("</table>\n");
+
+    html.push_str("<h2>Timeline</h2>\n<div class=\"timeline\">\n");
+    let percent = |unix_ms: i64| (unix_ms - origin_ms) as f64 * 100.0 / total_ms as f64;
+    for thread_id in timeline.thread_ids() {
+        let _ = writeln!(
+            html,
+            "<div class=\"thread\">{}</div>",
+            escape_html(&timeline.thread_label(&thread_id))
+        );
+        for (lane, row) in timeline.rows_for_thread(&thread_id) {
+            let _ = write!(
+                html,
+                "<div class=\"row\"><div class=\"label\">{}</div><div class=\"track\">",
+                escape_html(&row_label(lane, row))
+            );
+            for span in timeline
+                .spans
+                .iter()
+                .filter(|span| span.thread_id == thread_id && span.lane == lane && span.row == row)
+            {
+                let _ = write!(
+                    html,
+                    "<div class=\"span {} {}\" style=\"left:{:.3}%;width:{:.3}%\" \
+                     title=\"{}\"></div>",
+                    lane_class(lane),
+                    status_label(&span.status),
+                    percent(span.start_ms),
+                    (percent(span.end_ms) - percent(span.start_ms)).max(0.05),
+                    escape_html(&span_tooltip(span, origin_ms)),
+                );
+            }
+            for marker in timeline
+                .markers
+                .iter()
+                .filter(|marker| marker.thread_id == thread_id && marker.lane == lane && row == 0)
+            {
+                let _ = write!(
+                    html,
+                    "<div class=\"marker\" style=\"left:{:.3}%\" title=\"{} at +{}\"></div>",
+                    percent(marker.at_ms),
+                    escape_html(&marker.name),
+                    format_duration(marker.at_ms - origin_ms),
+                );
+            }
+            html.push_str("</div></div>\n");
+        }
+    }
+    html.push_str("</div>\n</body>\n</html>\n");
+    html
+}
+
+const REPORT_STYLE: &str = "\
+body{font:14px system-ui,sans-serif;margin:24px;color:#1f2328}\
+h1{font-size:20px}h2{font-size:16px;margin-top:28px}\
+.meta{color:#57606a}\
+table{border-collapse:collapse}\
+th,td{border:1px solid #d0d7de;padding:4px 8px;text-align:left}\
+th{background:#f6f8fa}\
+.thread{font-weight:600;margin-top:12px}\
+.row{display:flex;align-items:center;height:18px;margin:2px 0}\
+.label{width:120px;flex:none;color:#57606a;font-size:12px}\
+.track{position:relative;flex:1;height:14px;background:#f6f8fa}\
+.span{position:absolute;top:0;height:14px;min-width:1px;border-radius:2px}\
+.span.failed,.span.aborted,.span.cancelled{outline:1px solid #cf222e}\
+.span.running{opacity:.5}\
+.marker{position:absolute;top:-2px;width:2px;height:18px;background:#cf222e}\
+.turn{background:#afb8c1}.inference{background:#0969da}.tool{background:#1a7f37}\
+.code-cell{background:#8250df}.compaction{background:#bf8700}";
+
+fn tool_label(trace: &RolloutTrace, tool: &ToolCall) -> String {
+    let kind = match &tool.kind {
+        ToolCallKind::ExecCommand => "exec_command".to_string(),
+        ToolCallKind::WriteStdin => "write_stdin".to_string(),
+        ToolCallKind::ApplyPatch => "apply_patch".to_string(),
+        ToolCallKind::Mcp { server, tool } => format!("mcp {server}/{tool}"),
+        ToolCallKind::Web => "web".to_string(),
+        ToolCallKind::ImageGeneration => "image_generation".to_string(),
+        ToolCallKind::SpawnAgent => "spawn_agent".to_string(),
+        ToolCallKind::AssignAgentTask => "assign_agent_task".to_string(),
+        ToolCallKind::SendMessage => "send_message".to_string(),
+        ToolCallKind::WaitAgent => "wait_agent".to_string(),
+        ToolCallKind::CloseAgent => "close_agent".to_string(),
+        ToolCallKind::Other { name } => name.clone(),
+    };
+    let detail = match &tool.summary {
+        ToolCallSummary::Terminal { operation_id } => trace
+            .terminal_operations
+            .get(operation_id)
+            .map(|operation| match &operation.request {
+                TerminalRequest::ExecCommand {
+                    display_command, ..
+                } => display_command.clone(),
+                TerminalRequest::WriteStdin { stdin, .. } if stdin.is_empty() => "poll".to_string(),
+                TerminalRequest::WriteStdin { stdin, .. } => format!("{stdin:?}"),
+            }),
+        ToolCallSummary::Agent {
+            target_agent_path, ..
+        } => Some(target_agent_path.clone()),
+        ToolCallSummary::WaitAgent {
+            target_agent_path, ..
+        } => target_agent_path.clone(),
+        ToolCallSummary::Generic { label, .. } if *label != kind => Some(label.clone()),
+        ToolCallSummary::Generic { .. } => None,
+    };
+    match detail {
+        Some(detail) => truncate(&format!("{kind}: {detail}")),
+        None => kind,
+    }
+}
+
+fn object_args<const N: usize>(entries: [(&str, &str); N]) -> Map<String, Value> {
+    entries
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), json!(value)))
+        .collect()
+}
+
+/// Latest timestamp recorded anywhere in a trace that has not ended yet.
+fn latest_timestamp(trace: &RolloutTrace) -> i64 {
+    let windows = trace
+        .threads
+        .values()
+        .map(|thread| &thread.execution)
+        .chain(trace.codex_turns.values().map(|turn| &turn.execution))
+        .chain(trace.inference_calls.values().map(|call| &call.execution))
+        .chain(trace.tool_calls.values().map(|call| &call.execution))
+        .chain(trace.code_cells.values().map(|cell| &cell.execution))
+        .chain(
+            trace
+                .compaction_requests
+                .values()
+                .map(|request| &request.execution),
+        );
+    windows
+        .flat_map(|window| [Some(window.started_at_unix_ms), window.ended_at_unix_ms])
+        .flatten()
+        .chain(
+            trace
+                .compactions
+                .values()
+                .map(|compaction| compaction.installed_at_unix_ms),
+        )
+        .fold(trace.started_at_unix_ms, i64::max)
+}
+
+fn chrome_tid(lane: Lane, row: usize) -> usize {
+    (lane as usize + 1) * 100 + row
+}
+
+fn row_label(lane: Lane, row: usize) -> String {
+    if row == 0 {
+        lane.label().to_string()
+    } else {
+        format!("{} #{}", lane.label(), row + 1)
+    }
+}
+
+fn lane_class(lane: Lane) -> &'static str {
+    match lane {
+        Lane::Turn => "turn",
+        Lane::Inference => "inference",
+        Lane::Tool => "tool",
+        Lane::CodeCell => "code-cell",
+        Lane::Compaction => "compaction",
+    }
+}
+
+fn status_label(status: &ExecutionStatus) -> &'static str {
+    match status {
+        ExecutionStatus::Running => "running",
+        ExecutionStatus::Completed => "completed",
+        ExecutionStatus::Failed => "failed",
+        ExecutionStatus::Cancelled => "cancelled",
+        ExecutionStatus::Aborted => "aborted",
+    }
+}
+
+fn span_status_label(span: &Span) -> String {
+    if span.finished {
+        status_label(&span.status).to_string()
+    } else {
+        format!("{} (unfinished)", status_label(&span.status))
+    }
+}
+
+fn span_tooltip(span: &Span, origin_ms: i64) -> String {
+    let mut tooltip = format!(
+        "{}\n+{} for {} ({})",
+        span.name,
+        format_duration(span.start_ms - origin_ms),
+        format_duration(span.duration_ms()),
+        span_status_label(span),
+    );
+    if let Some(usage) = &span.usage {
+        let _ = write!(
+            tooltip,
+            "\n{} input tokens ({} cached), {} output tokens ({} reasoning)",
+            usage.input_tokens,
+            usage.cached_input_tokens,
+            usage.output_tokens,
+            usage.reasoning_output_tokens,
+        );
+    }
+    tooltip
+}
+
+fn serde_label(value: &impl serde::Serialize) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(label)) => label,
+        _ => String::new(),
+    }
+}
+
+fn sum_usage<'a>(usages: impl Iterator<Item = &'a TokenUsage>) -> TokenUsage {
+    let mut total = TokenUsage {
+        input_tokens: 0,
+        cached_input_tokens: 0,
+        cache_write_input_tokens: 0,
+        output_tokens: 0,
+        reasoning_output_tokens: 0,
+    };
+    for usage in usages {
+        add_usage(&mut total, usage);
+    }
+    total
+}
+
+fn add_usage(total: &mut TokenUsage, usage: &TokenUsage) {
+    total.input_tokens += usage.input_tokens;
+    total.cached_input_tokens += usage.cached_input_tokens;
+    total.cache_write_input_tokens += usage.cache_write_input_tokens;
+    total.output_tokens += usage.output_tokens;
+    total.reasoning_output_tokens += usage.reasoning_output_tokens;
+}
+
+fn format_duration(duration_ms: i64) -> String {
+    if duration_ms < 1000 {
+        format!("{duration_ms}ms")
+    } else if duration_ms < 60_000 {
+        format!("{:.1}s", duration_ms as f64 / 1000.0)
+    } else {
+        format!(
+            "{}m{:02}s",
+            duration_ms / 60_000,
+            (duration_ms % 60_000) / 1000
+        )
+    }
+}
+
+fn truncate(text: &str) -> String {
+    if text.chars().count() <= MAX_LABEL_CHARS {
+        return text.to_string();
+    }
+    let mut truncated = text.chars().take(MAX_LABEL_CHARS - 1).collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            '\n' => escaped.push_str("&#10;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[path = "timeline_tests.rs"]
+mod tests;
@@ -0,0 +1,275 @@
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+
+use super::chrome_trace_events;
+use super::html_report;
+use crate::model::AgentOrigin;
+use crate::model::AgentThread;
+use crate::model::ExecutionStatus;
+use crate::model::ExecutionWindow;
+use crate::model::InferenceCall;
+use crate::model::RolloutTrace;
+use crate::model::TokenUsage;
+use crate::model::ToolCall;
+use crate::model::ToolCallKind;
+use crate::model::ToolCallRequester;
+use crate::model::ToolCallSummary;
+
+const ROOT: &str = "thread-root";
+const CHILD: &str = "thread-child";
+
+fn window(started_at_unix_ms: i64, ended_at_unix_ms: Option<i64>) -> ExecutionWindow {
+    ExecutionWindow {
+        started_at_unix_ms,
+        started_seq: 1,
+        ended_at_unix_ms,
+        ended_seq: ended_at_unix_ms.map(|_| 2),
+        status: match ended_at_unix_ms {
+            Some(_) => ExecutionStatus::Completed,
+            None => ExecutionStatus::Running,
+        },
+    }
+}
+
+fn thread(thread_id: &str, agent_path: &str, origin: AgentOrigin, started_at: i64) -> AgentThread {
+    AgentThread {
+        thread_id: thread_id.to_string(),
+        agent_path: agent_path.to_string(),
+        nickname: None,
+        origin,
+        execution: window(started_at, Some(5_000)),
+        default_model: None,
+        conversation_item_ids: Vec::new(),
+    }
+}
+
+fn inference(id: &str, thread_id: &str, start: i64, end: i64, input_tokens: u64) -> InferenceCall {
+    InferenceCall {
+        inference_call_id: id.to_string(),
+        thread_id: thread_id.to_string(),
+        codex_turn_id: "turn-1".to_string(),
+        execution: window(start, Some(end)),
+        model: "gpt-test".to_string(),
+        provider_name: "test-provider".to_string(),
+        response_id: None,
+        upstream_request_id: None,
+        request_item_ids: Vec::new(),
+        response_item_ids: Vec::new(),
+        tool_call_ids_started_by_response: Vec::new(),
+        usage: Some(TokenUsage {
+            input_tokens,
+            cached_input_tokens: 0,
+            cache_write_input_tokens: 0,
+            output_tokens: 10,
+            reasoning_output_tokens: 0,
+        }),
+        raw_request_payload_id: format!("{id}-request"),
+        raw_response_payload_id: None,
+    }
+}
+
+fn tool(id: &str, label: &str, start: i64, end: Option<i64>) -> ToolCall {
+    ToolCall {
+        tool_call_id: id.to_string(),
+        mcp_call_id: None,
+        model_visible_call_id: None,
+        code_mode_runtime_tool_id: None,
+        thread_id: ROOT.to_string(),
+        started_by_codex_turn_id: None,
+        execution: window(start, end),
+        requester: ToolCallRequester::Model,
+        kind: ToolCallKind::Other {
+            name: "shell".to_string(),
+        },
+        model_visible_call_item_ids: Vec::new(),
+        model_visible_output_item_ids: Vec::new(),
+        terminal_operation_id: None,
+        summary: ToolCallSummary::Generic {
+            label: label.to_string(),
+            input_preview: None,
+            output_preview: None,
+        },
+        raw_invocation_payload_id: None,
+        raw_result_payload_id: None,
+        raw_runtime_payload_ids: Vec::new(),
+    }
+}
+
+/// Root thread with two inference calls and overlapping tools, plus a child
+/// thread spawned later. The trace is still running, so open spans end at the
+/// latest observed timestamp.
+fn sample_trace() -> RolloutTrace {
+    let mut trace = RolloutTrace::new(
+        /*schema_version*/ 1,
+        "trace-1".to_string(),
+        "rollout-1".to_string(),
+        ROOT.to_string(),
+        /*started_at_unix_ms*/ 1_000,
+    );
+    trace.threads.insert(
+        CHILD.to_string(),
+        thread(
+            CHILD,
+            "/root/reviewer",
+            AgentOrigin::Spawned {
+                parent_thread_id: ROOT.to_string(),
+                spawn_edge_id: "edge-1".to_string(),
+                task_name: "reviewer".to_string(),
+                agent_role: "worker".to_string(),
+            },
+            /*started_at*/ 2_000,
+        ),
+    );
+    trace.threads.insert(
+        ROOT.to_string(),
+        thread(ROOT, "/root", AgentOrigin::Root, /*started_at*/ 1_000),
+    );
+    for call in [
+        inference("inference-1", ROOT, 1_000, 1_500, 100),
+        inference("inference-2", ROOT, 3_000, 3_200, 150),
+        inference("inference-3", CHILD, 2_000, 2_400, 40),
+    ] {
+        trace
+            .inference_calls
+            .insert(call.inference_call_id.clone(), call);
+    }
+    for call in [
+        tool("tool-1", "cargo test", 1_500, Some(2_500)),
+        tool("tool-2", "<script>", 1_600, Some(1_700)),
+        tool("tool-3", "sleep", 2_600, None),
+    ] {
+        trace.tool_calls.insert(call.tool_call_id.clone(), call);
+    }
+    trace
+}
+
+fn events_with_phase<'a>(timeline: &'a Value, phase: &str) -> Vec<&'a Value> {
+    timeline["traceEvents"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter(|event| event["ph"] == phase)
+        .collect()
+}
+
+#[test]
+fn chrome_trace_events_group_spans_into_thread_and_lane_tracks() {
+    let timeline = chrome_trace_events(&sample_trace());
+
+    let names = events_with_phase(&timeline, "M")
+        .into_iter()
+        .filter(|event| event["name"] == "process_name" || event["name"] == "thread_name")
+        .map(|event| {
+            (
+                event["pid"].clone(),
+                event["tid"].clone(),
+                event["args"]["name"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        names,
+        vec![
+            (json!(1), Value::Null, json!("/root")),
+            (json!(1), json!(200), json!("inference")),
+            (json!(1), json!(300), json!("tools")),
+            (json!(1), json!(301), json!("tools #2")),
+            (json!(2), Value::Null, json!("/root/reviewer")),
+            (json!(2), json!(200), json!("inference")),
+        ]
+    );
+
+    let spans = events_with_phase(&timeline, "X")
+        .into_iter()
+        .map(|event| {
+            (
+                event["name"].clone(),
+                event["pid"].clone(),
+                event["tid"].clone(),
+                event["ts"].clone(),
+                event["dur"].clone(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        spans,
+        vec![
+            (
+                json!("inference gpt-test"),
+                json!(1),
+                json!(200),
+                json!(0),
+                json!(500_000)
+            ),
+            (
+                json!("shell: cargo test"),
+                json!(1),
+                json!(300),
+                json!(500_000),
+                json!(1_000_000)
+            ),
+            (
+                json!("shell: <script>"),
+                json!(1),
+                json!(301),
+                json!(600_000),
+                json!(100_000)
+            ),
+            (
+                json!("inference gpt-test"),
+                json!(2),
+                json!(200),
+                json!(1_000_000),
+                json!(400_000)
+            ),
+            (
+                json!("shell: sleep"),
+                json!(1),
+                json!(300),
+                json!(1_600_000),
+                json!(2_400_000)
+            ),
+            (
+                json!("inference gpt-test"),
+                json!(1),
+                json!(200),
+                json!(2_000_000),
+                json!(200_000)
+            ),
+        ]
+    );
+    let unfinished = events_with_phase(&timeline, "X")
+        .into_iter()
+        .filter(|event| event["args"]["unfinished"] == true)
+        .map(|event| event["args"]["tool_call_id"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(unfinished, vec![json!("tool-3")]);
+
+    let root_token_totals = events_with_phase(&timeline, "C")
+        .into_iter()
+        .filter(|event| event["pid"] == 1)
+        .map(|event| event["args"]["input"].clone())
+        .collect::<Vec<_>>();
+    assert_eq!(root_token_totals, vec![json!(100), json!(250)]);
+}
+
+#[test]
+fn html_report_summarizes_threads_and_escapes_labels() {
+    let report = html_report(&sample_trace());
+
+    assert!(report.starts_with("<!DOCTYPE html>"));
+    assert!(!report.contains("<script"));
+    assert!(report.contains("shell: &lt;script&gt;"));
+    assert!(report.contains(
+        "<tr><td>/root</td><td>root</td><td>4.0s</td><td>700ms in 2</td>\
+         <td>3.5s in 3</td><td>0ms in 0</td><td>250</td><td>0</td><td>20</td></tr>"
+    ));
+    assert!(report.contains(
+        "<tr><td>/root/reviewer</td><td>spawned worker</td><td>3.0s</td><td>400ms in 1</td>"
+    ));
+    assert!(report.contains(
+        "<tr><td>shell: sleep</td><td>tools</td><td>/root</td><td>+1.6s</td><td>2.4s</td>\
+         <td>running (unfinished)</td></tr>"
+    ));
+}
@@ -73,7 +73,10 @@ pub use recorder::RolloutRecorderParams;
 pub use recorder::append_rollout_item_to_path;
 pub use reverse_jsonl_scanner::ReverseJsonlScanner;
 pub use reverse_jsonl_scanner::ScanOutcome;
+pub use search::RolloutSearchMatches;
 pub use search::first_rollout_content_match_snippet;
+pub use search::rollout_item_search_text;
+pub use search::search_match_snippet;
 pub use search::search_rollout_matches;
 pub use search::search_rollout_paths;
 pub use session_index::append_thread_name;
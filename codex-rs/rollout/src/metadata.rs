@@ -3,6 +3,7 @@ use crate::SESSIONS_SUBDIR;
 use crate::compression;
 use crate::list::parse_timestamp_uuid_from_filename;
 use crate::recorder::RolloutRecorder;
+use crate::search::rollout_item_search_text;
 use crate::state_db::normalize_cwd_for_state_db;
 use chrono::DateTime;
 use chrono::NaiveDateTime;
@@ -24,6 +25,7 @@ use codex_state::DB_METRIC_BACKFILL_DURATION_MS;
 use codex_state::ExtractionOutcome;
 use codex_state::ThreadMetadataBuilder;
 use codex_state::apply_rollout_item;
+use std::collections::HashSet;
 use std::path::Path;
 use std::path::PathBuf;
 use tracing::info;
@@ -99,6 +101,15 @@ pub async fn extract_metadata_from_rollout(
     rollout_path: &Path,
     default_provider: &str,
 ) -> anyhow::Result<ExtractionOutcome> {
+    extract_metadata_and_items(rollout_path, default_provider)
+        .await
+        .map(|(outcome, _items)| outcome)
+}
+
+async fn extract_metadata_and_items(
+    rollout_path: &Path,
+    default_provider: &str,
+) -> anyhow::Result<(ExtractionOutcome, Vec<RolloutItem>)> {
     let (items, _thread_id, parse_errors) =
         RolloutRecorder::load_rollout_items(rollout_path).await?;
     if items.is_empty() {
@@ -121,20 +132,24 @@ pub async fn extract_metadata_from_rollout(
         metadata.updated_at = updated_at;
         metadata.recency_at = updated_at;
     }
-    Ok(ExtractionOutcome {
-        metadata,
-        memory_mode: items.iter().rev().find_map(|item| match item {
-            RolloutItem::SessionMeta(meta_line) => meta_line.meta.memory_mode.clone(),
-            RolloutItem::ResponseItem(_)
-            | RolloutItem::InterAgentCommunication(_)
-            | RolloutItem::InterAgentCommunicationMetadata { .. }
-            | RolloutItem::Compacted(_)
-            | RolloutItem::TurnContext(_)
-            | RolloutItem::WorldState(_)
-            | RolloutItem::EventMsg(_) => None,
-        }),
-        parse_errors,
-    })
+    let memory_mode = items.iter().rev().find_map(|item| match item {
+        RolloutItem::SessionMeta(meta_line) => meta_line.meta.memory_mode.clone(),
+        RolloutItem::ResponseItem(_)
+        | RolloutItem::InterAgentCommunication(_)
+        | RolloutItem::InterAgentCommunicationMetadata { .. }
+        | RolloutItem::Compacted(_)
+        | RolloutItem::TurnContext(_)
+        | RolloutItem::WorldState(_)
+        | RolloutItem::EventMsg(_) => None,
+    });
+    Ok((
+        ExtractionOutcome {
+            metadata,
+            memory_mode,
+            parse_errors,
+        },
+        items,
+    ))
 }
 
 pub(crate) async fn backfill_sessions(
@@ -252,8 +267,10 @@ pub(crate) async fn backfill_sessions_with_lease(
     for batch in rollout_paths.chunks(BACKFILL_BATCH_SIZE) {
         for rollout in batch {
             stats.scanned = stats.scanned.saturating_add(1);
-            match extract_metadata_from_rollout(&rollout.path, default_provider).await {
-                Ok(outcome) => {
+            // Taken before the read so search text appended while it runs is not dropped.
+            let search_watermark = runtime.thread_search_watermark().await;
+            match extract_metadata_and_items(&rollout.path, default_provider).await {
+                Ok((outcome, items)) => {
                     if outcome.parse_errors > 0
                         && let Some(ref metric_client) = metric_client
                     {
@@ -297,6 +314,24 @@ pub(crate) async fn backfill_sessions_with_lease(
                             );
                             continue;
                         }
+                        // A thread that changed during the read is left for the search backfill.
+                        let search_indexed = match search_watermark {
+                            Ok(watermark) => runtime
+                                .replace_thread_search_text_if_unchanged(
+                                    metadata.id,
+                                    &rollout_search_texts(&items),
+                                    watermark,
+                                )
+                                .await
+                                .map(|_| ()),
+                            Err(err) => Err(err),
+                        };
+                        if let Err(err) = search_indexed {
+                            warn!(
+                                "failed to index search text for {}: {err}",
+                                rollout.path.display()
+                            );
+                        }
                         stats.upserted = stats.upserted.saturating_add(1);
                     }
                 }
@@ -362,6 +397,92 @@ pub(crate) async fn backfill_sessions_with_lease(
     }
 }
 
+/// Indexes the conversation text of threads whose metadata was backfilled before
+/// the search index existed.
+///
+/// Unreadable rollouts are indexed with no text so they do not stall the pass. A thread
+/// that a live writer appends to while its rollout is read is retried, and left for the
+/// next pass if it keeps changing.
+pub(crate) async fn backfill_thread_search(runtime: &codex_state::StateRuntime) {
+    let mut indexed = 0usize;
+    let mut busy = HashSet::new();
+    loop {
+        let threads = match runtime
+            .threads_missing_search_index(BACKFILL_BATCH_SIZE.saturating_add(busy.len()))
+            .await
+        {
+            Ok(threads) => threads,
+            Err(err) => {
+                warn!("failed to list threads missing from the search index: {err}");
+                return;
+            }
+        };
+        let threads = threads
+            .into_iter()
+            .filter(|(thread_id, _)| !busy.contains(thread_id))
+            .collect::<Vec<_>>();
+        if threads.is_empty() {
+            break;
+        }
+        for (thread_id, rollout_path) in threads {
+            match index_thread_search_text(runtime, thread_id, rollout_path.as_path()).await {
+                Ok(true) => indexed = indexed.saturating_add(1),
+                Ok(false) => {
+                    busy.insert(thread_id);
+                }
+                Err(err) => {
+                    warn!(
+                        "failed to index search text for {}: {err}",
+                        rollout_path.display()
+                    );
+                    return;
+                }
+            }
+        }
+    }
+    if indexed > 0 || !busy.is_empty() {
+        info!(
+            "state db search backfill indexed={indexed} deferred={}",
+            busy.len()
+        );
+    }
+}
+
+/// Replaces the indexed text of one thread with its rollout, unless a live writer keeps
+/// appending to it. Returns whether the thread was indexed.
+async fn index_thread_search_text(
+    runtime: &codex_state::StateRuntime,
+    thread_id: ThreadId,
+    rollout_path: &Path,
+) -> anyhow::Result<bool> {
+    const ATTEMPTS: usize = 3;
+    for _ in 0..ATTEMPTS {
+        // Taken before the read so appends racing with it are detected on replace.
+        let watermark = runtime.thread_search_watermark().await?;
+        let texts = match RolloutRecorder::load_rollout_items(rollout_path).await {
+            Ok((items, _thread_id, _parse_errors)) => rollout_search_texts(&items),
+            Err(err) => {
+                warn!(
+                    "failed to read rollout {} for search indexing: {err}",
+                    rollout_path.display()
+                );
+                Vec::new()
+            }
+        };
+        if runtime
+            .replace_thread_search_text_if_unchanged(thread_id, &texts, watermark)
+            .await?
+        {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn rollout_search_texts(items: &[RolloutItem]) -> Vec<String> {
+    items.iter().filter_map(rollout_item_search_text).collect()
+}
+
 #[derive(Debug, Clone)]
 struct BackfillRolloutPath {
     watermark: String,
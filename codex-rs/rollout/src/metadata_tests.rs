@@ -7,6 +7,7 @@ use chrono::Timelike;
 use chrono::Utc;
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::CompactedItem;
+use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::GitInfo;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::protocol::RolloutLine;
@@ -14,6 +15,7 @@ use codex_protocol::protocol::SessionMeta;
 use codex_protocol::protocol::SessionMetaLine;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::ThreadHistoryMode;
+use codex_protocol::protocol::UserMessageEvent;
 use codex_state::BackfillStatus;
 use codex_state::ThreadMetadataBuilder;
 use pretty_assertions::assert_eq;
@@ -424,6 +426,61 @@ async fn backfill_sessions_normalizes_cwd_before_upsert() {
     assert_eq!(stored.cwd, normalize_cwd_for_state_db(&session_cwd));
 }
 
+#[tokio::test]
+async fn backfill_sessions_indexes_conversation_text() {
+    let dir = tempdir().expect("tempdir");
+    let codex_home = dir.path().to_path_buf();
+    let thread_uuid = Uuid::new_v4();
+    let rollout_path = write_rollout_in_sessions(
+        codex_home.as_path(),
+        "2026-01-27T12-34-56",
+        "2026-01-27T12:34:56Z",
+        thread_uuid,
+        /*git*/ None,
+    );
+    let user_message = RolloutLine {
+        timestamp: "2026-01-27T12:35:00Z".to_string(),
+        ordinal: None,
+        item: RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: "please refactor the needle module".to_string(),
+            ..Default::default()
+        })),
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .append(true)
+        .open(&rollout_path)
+        .expect("open rollout");
+    writeln!(
+        file,
+        "{}",
+        serde_json::to_string(&user_message).expect("serialize user message")
+    )
+    .expect("append user message");
+
+    let runtime = codex_state::StateRuntime::init(codex_home.clone(), "test-provider".to_string())
+        .await
+        .expect("initialize runtime");
+
+    backfill_sessions(runtime.as_ref(), codex_home.as_path(), "test-provider").await;
+
+    assert!(
+        runtime
+            .thread_search_index_complete(/*archived*/ false)
+            .await
+            .expect("read index state")
+    );
+    let hits = runtime
+        .search_thread_text("Needle", /*archived*/ false, /*limit*/ 10)
+        .await
+        .expect("search index");
+    assert_eq!(
+        hits.iter()
+            .map(|hit| (hit.thread_id.to_string(), hit.text.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(thread_uuid.to_string(), "please refactor the needle module")]
+    );
+}
+
 fn write_rollout_in_sessions(
     codex_home: &Path,
     filename_ts: &str,
@@ -244,13 +244,20 @@ fn case_insensitive_literal_regex(search_term: impl AsRef<str>) -> io::Result<Re
         .map_err(io::Error::other)
 }
 
+/// Excerpt of `text` around the first case-insensitive occurrence of `search_term`.
+pub fn search_match_snippet(text: &str, search_term: &str) -> Option<String> {
+    let search_term = case_insensitive_literal_regex(search_term).ok()?;
+    excerpt_around_match(text, &search_term)
+}
+
 fn content_match_snippet(jsonl_line: &str, search_term: &Regex) -> Option<String> {
     let rollout_line = serde_json::from_str::<RolloutLine>(jsonl_line.trim()).ok()?;
-    let text = conversation_text_from_item(&rollout_line.item)?;
+    let text = rollout_item_search_text(&rollout_line.item)?;
     excerpt_around_match(text.as_str(), search_term)
 }
 
-fn conversation_text_from_item(item: &RolloutItem) -> Option<String> {
+/// User or assistant message text that rollout search matches against, if `item` has any.
+pub fn rollout_item_search_text(item: &RolloutItem) -> Option<String> {
     match item {
         RolloutItem::EventMsg(EventMsg::UserMessage(user)) => {
             let text = strip_user_message_prefix(user.message.as_str());
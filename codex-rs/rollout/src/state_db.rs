@@ -134,6 +134,12 @@ async fn try_init_with_roots_inner(
         runtime.close().await;
         return Err(err);
     }
+    // Threads backfilled before the search index existed are indexed in the background;
+    // thread search falls back to scanning rollouts until the index is complete.
+    let search_runtime = Arc::clone(&runtime);
+    tokio::spawn(async move {
+        metadata::backfill_thread_search(search_runtime.as_ref()).await;
+    });
     Ok(runtime)
 }
 
@@ -18,6 +18,8 @@ mod sanitizer;
 
 pub use local::LocalSecretsBackend;
 pub use local::LocalSecretsNamespace;
+pub use sanitizer::RedactionOptions;
+pub use sanitizer::SecretRedactor;
 pub use sanitizer::redact_secrets;
 
 const KEYRING_SERVICE: &str = "codex";
@@ -0,0 +1,14 @@
+-- Conversation text of every thread, one row per user or assistant message.
+-- The trigram tokenizer gives case-insensitive substring matching, which is
+-- what rollout search has always offered.
+CREATE VIRTUAL TABLE thread_search USING fts5(
+    thread_id UNINDEXED,
+    text,
+    tokenize = 'trigram'
+);
+
+-- Threads whose whole rollout has been indexed into thread_search. Threads
+-- missing here only have the messages appended since this binary started.
+CREATE TABLE thread_search_indexed (
+    thread_id TEXT PRIMARY KEY
+);
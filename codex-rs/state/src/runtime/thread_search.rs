@@ -0,0 +1,311 @@
+use super::*;
+
+/// The trigram tokenizer cannot match terms shorter than this many characters.
+const TRIGRAM_CHARS: usize = 3;
+
+/// The best-ranked message of a thread that matched a text search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadSearchHit {
+    pub thread_id: ThreadId,
+    pub rollout_path: PathBuf,
+    /// Text of the matching message.
+    pub text: String,
+}
+
+impl StateRuntime {
+    /// Add conversation text from newly written rollout items to the search index.
+    ///
+    /// On failure the thread is marked unindexed, so the search backfill rebuilds its text
+    /// from the rollout instead of the index silently missing these messages.
+    pub async fn append_thread_search_text(
+        &self,
+        thread_id: ThreadId,
+        texts: &[String],
+    ) -> anyhow::Result<()> {
+        if texts.is_empty() {
+            return Ok(());
+        }
+        let thread_id = thread_id.to_string();
+        let appended = async {
+            let mut tx = self.pool.begin().await?;
+            insert_thread_search_text(&mut tx, thread_id.as_str(), texts).await?;
+            tx.commit().await?;
+            anyhow::Ok(())
+        }
+        .await;
+        if let Err(err) = appended {
+            let unmarked = sqlx::query("DELETE FROM thread_search_indexed WHERE thread_id = ?")
+                .bind(thread_id.as_str())
+                .execute(self.pool.as_ref())
+                .await;
+            return Err(match unmarked {
+                Ok(_) => err,
+                Err(unmark_err) => err.context(format!(
+                    "also failed to mark the thread unindexed: {unmark_err}"
+                )),
+            });
+        }
+        Ok(())
+    }
+
+    /// Replace the indexed text of a thread with its whole history and mark it indexed.
+    pub async fn replace_thread_search_text(
+        &self,
+        thread_id: ThreadId,
+        texts: &[String],
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        replace_thread_search_text_in(&mut tx, thread_id.to_string().as_str(), texts).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Newest position in the search index. Take it before reading a rollout whose text will be
+    /// passed to [`Self::replace_thread_search_text_if_unchanged`].
+    pub async fn thread_search_watermark(&self) -> anyhow::Result<i64> {
+        let watermark = sqlx::query_scalar::<_, i64>(
+            "SELECT rowid FROM thread_search ORDER BY rowid DESC LIMIT 1",
+        )
+        .fetch_optional(self.pool.as_ref())
+        .await?;
+        Ok(watermark.unwrap_or(0))
+    }
+
+    /// Like [`Self::replace_thread_search_text`], but leaves the thread untouched and returns
+    /// `false` when a live writer appended to it after `watermark` was taken. `texts` came from
+    /// a rollout read that may predate those messages, so replacing would drop them.
+    pub async fn replace_thread_search_text_if_unchanged(
+        &self,
+        thread_id: ThreadId,
+        texts: &[String],
+        watermark: i64,
+    ) -> anyhow::Result<bool> {
+        let thread_id = thread_id.to_string();
+        // Take the write lock up front so no append can land between the check and the replace.
+        let mut tx = self.pool.begin_with("BEGIN IMMEDIATE").await?;
+        let appended = sqlx::query_scalar::<_, i64>(
+            "SELECT EXISTS (SELECT 1 FROM thread_search WHERE rowid > ? AND thread_id = ?)",
+        )
+        .bind(watermark)
+        .bind(thread_id.as_str())
+        .fetch_one(&mut *tx)
+        .await?;
+        if appended != 0 {
+            return Ok(false);
+        }
+        replace_thread_search_text_in(&mut tx, thread_id.as_str(), texts).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Threads with metadata whose rollout has not been indexed yet, oldest first.
+    pub async fn threads_missing_search_index(
+        &self,
+        limit: usize,
+    ) -> anyhow::Result<Vec<(ThreadId, PathBuf)>> {
+        let rows = sqlx::query(
+            r#"
+SELECT threads.id, threads.rollout_path
+FROM threads
+LEFT JOIN thread_search_indexed AS indexed ON indexed.thread_id = threads.id
+WHERE indexed.thread_id IS NULL
+ORDER BY threads.created_at_ms ASC, threads.id ASC
+LIMIT ?
+            "#,
+        )
+        .bind(i64::try_from(limit).unwrap_or(i64::MAX))
+        .fetch_all(self.pool.as_ref())
+        .await?;
+        rows.into_iter()
+            .map(|row| {
+                Ok((
+                    ThreadId::try_from(row.try_get::<String, _>("id")?)?,
+                    PathBuf::from(row.try_get::<String, _>("rollout_path")?),
+                ))
+            })
+            .collect()
+    }
+
+    /// Whether every archived (or unarchived) thread has been indexed, so that
+    /// [`Self::search_thread_text`] sees the same threads as a rollout scan would.
+    pub async fn thread_search_index_complete(&self, archived: bool) -> anyhow::Result<bool> {
+        let missing = sqlx::query_scalar::<_, i64>(
+            r#"
+SELECT EXISTS (
+    SELECT 1
+    FROM threads
+    LEFT JOIN thread_search_indexed AS indexed ON indexed.thread_id = threads.id
+    WHERE threads.archived = ?
+      AND indexed.thread_id IS NULL
+)
+            "#,
+        )
+        .bind(archived)
+        .fetch_one(self.pool.as_ref())
+        .await?;
+        Ok(missing == 0)
+    }
+
+    /// Search indexed conversation text for a case-insensitive substring.
+    ///
+    /// Returns the best-ranked message of each matching thread, best match first, for at most
+    /// `limit` threads. Terms too short for the trigram index have no rank, so their threads
+    /// are ordered by their first matching message.
+    pub async fn search_thread_text(
+        &self,
+        search_term: &str,
+        archived: bool,
+        limit: usize,
+    ) -> anyhow::Result<Vec<ThreadSearchHit>> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+SELECT thread_id, rollout_path, text, MIN(rank) AS best_rank
+FROM (
+    SELECT
+        thread_search.thread_id AS thread_id,
+        threads.rollout_path AS rollout_path,
+        thread_search.text AS text,
+        "#,
+        );
+        if search_term.chars().count() >= TRIGRAM_CHARS {
+            builder.push(
+                r#"thread_search.rank AS rank
+    FROM thread_search
+    JOIN threads ON threads.id = thread_search.thread_id
+    WHERE threads.archived = "#,
+            );
+            builder.push_bind(archived);
+            builder.push(" AND thread_search MATCH ");
+            builder.push_bind(fts_phrase(search_term));
+        } else {
+            // Trigram MATCH never finds shorter terms, but LIKE still works as a scan.
+            builder.push(
+                r#"thread_search.rowid AS rank
+    FROM thread_search
+    JOIN threads ON threads.id = thread_search.thread_id
+    WHERE threads.archived = "#,
+            );
+            builder.push_bind(archived);
+            builder.push(" AND thread_search.text LIKE ");
+            builder.push_bind(like_pattern(search_term));
+            builder.push(" ESCAPE '\\'");
+        }
+        // With a single MIN() aggregate, SQLite takes the other columns from the row holding
+        // the minimum, i.e. the best-ranked message of each thread.
+        builder.push(
+            r#"
+)
+GROUP BY thread_id
+ORDER BY best_rank ASC, thread_id ASC
+LIMIT "#,
+        );
+        builder.push_bind(i64::try_from(limit).unwrap_or(i64::MAX));
+        let rows = builder.build().fetch_all(self.pool.as_ref()).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(ThreadSearchHit {
+                    thread_id: ThreadId::try_from(row.try_get::<String, _>("thread_id")?)?,
+                    rollout_path: PathBuf::from(row.try_get::<String, _>("rollout_path")?),
+                    text: row.try_get("text")?,
+                })
+            })
+            .collect()
+    }
+
+    /// Whether a single indexed message of the thread contains every word of `search_term`.
+    ///
+    /// Words are runs of alphanumeric characters, so markup around them in the indexed
+    /// text does not matter. Returns `None` when the answer is unknown: the thread has
+    /// not been fully indexed, or no word of the term is long enough to look up.
+    pub async fn thread_search_text_may_match(
+        &self,
+        thread_id: ThreadId,
+        search_term: &str,
+    ) -> anyhow::Result<Option<bool>> {
+        let words = search_term
+            .split(|ch: char| !ch.is_alphanumeric())
+            .filter(|word| word.chars().count() >= TRIGRAM_CHARS)
+            .map(fts_phrase)
+            .collect::<Vec<_>>();
+        if words.is_empty() {
+            return Ok(None);
+        }
+        let thread_id = thread_id.to_string();
+        let indexed = sqlx::query_scalar::<_, i64>(
+            "SELECT EXISTS (SELECT 1 FROM thread_search_indexed WHERE thread_id = ?)",
+        )
+        .bind(thread_id.as_str())
+        .fetch_one(self.pool.as_ref())
+        .await?;
+        if indexed == 0 {
+            return Ok(None);
+        }
+        let matched = sqlx::query_scalar::<_, i64>(
+            r#"
+SELECT EXISTS (
+    SELECT 1
+    FROM thread_search
+    WHERE thread_search MATCH ?
+      AND thread_id = ?
+)
+            "#,
+        )
+        .bind(words.join(" AND "))
+        .bind(thread_id.as_str())
+        .fetch_one(self.pool.as_ref())
+        .await?;
+        Ok(Some(matched != 0))
+    }
+}
+
+async fn replace_thread_search_text_in(
+    conn: &mut SqliteConnection,
+    thread_id: &str,
+    texts: &[String],
+) -> anyhow::Result<()> {
+    sqlx::query("DELETE FROM thread_search WHERE thread_id = ?")
+        .bind(thread_id)
+        .execute(&mut *conn)
+        .await?;
+    insert_thread_search_text(conn, thread_id, texts).await?;
+    sqlx::query(
+        "INSERT INTO thread_search_indexed (thread_id) VALUES (?) ON CONFLICT(thread_id) DO NOTHING",
+    )
+    .bind(thread_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+async fn insert_thread_search_text(
+    conn: &mut SqliteConnection,
+    thread_id: &str,
+    texts: &[String],
+) -> anyhow::Result<()> {
+    for text in texts {
+        sqlx::query("INSERT INTO thread_search (thread_id, text) VALUES (?, ?)")
+            .bind(thread_id)
+            .bind(text.as_str())
+            .execute(&mut *conn)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Quote a term as an FTS5 phrase so its punctuation is not read as query syntax.
+fn fts_phrase(term: &str) -> String {
+    format!("\"{}\"", term.replace('"', "\"\""))
+}
+
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+#[cfg(test)]
+#[path = "thread_search_tests.rs"]
+mod tests;
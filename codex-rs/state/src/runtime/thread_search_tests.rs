@@ -0,0 +1,303 @@
+use super::*;
+use crate::runtime::test_support::test_thread_metadata;
+use crate::runtime::test_support::unique_temp_dir;
+use pretty_assertions::assert_eq;
+
+async fn runtime_with_threads(
+    thread_ids: &[(&str, bool)],
+) -> anyhow::Result<(Arc<StateRuntime>, Vec<ThreadId>)> {
+    let codex_home = unique_temp_dir();
+    let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string()).await?;
+    let mut ids = Vec::new();
+    for (thread_id, archived) in thread_ids {
+        let thread_id = ThreadId::from_string(thread_id)?;
+        let mut metadata = test_thread_metadata(&codex_home, thread_id, codex_home.clone());
+        if *archived {
+            metadata.archived_at = Some(metadata.updated_at);
+        }
+        runtime.upsert_thread(&metadata).await?;
+        ids.push(thread_id);
+    }
+    Ok((runtime, ids))
+}
+
+#[tokio::test]
+async fn search_returns_best_ranked_message_per_thread() -> anyhow::Result<()> {
+    let (runtime, ids) = runtime_with_threads(&[
+        ("00000000-0000-0000-0000-000000000001", false),
+        ("00000000-0000-0000-0000-000000000002", false),
+        ("00000000-0000-0000-0000-000000000003", true),
+    ])
+    .await?;
+    runtime
+        .replace_thread_search_text(
+            ids[0],
+            &[
+                "set up the Rollout recorder".to_string(),
+                "rollout rollout rollout".to_string(),
+            ],
+        )
+        .await?;
+    runtime
+        .replace_thread_search_text(ids[1], &["nothing relevant here".to_string()])
+        .await?;
+    runtime
+        .replace_thread_search_text(ids[2], &["archived rollout notes".to_string()])
+        .await?;
+
+    let hits = runtime
+        .search_thread_text("ROLLOUT", /*archived*/ false, /*limit*/ 10)
+        .await?;
+    assert_eq!(
+        hits.iter()
+            .map(|hit| (hit.thread_id, hit.text.as_str()))
+            .collect::<Vec<_>>(),
+        vec![(ids[0], "rollout rollout rollout")]
+    );
+
+    let hits = runtime
+        .search_thread_text("rollout", /*archived*/ true, /*limit*/ 10)
+        .await?;
+    assert_eq!(
+        hits.iter().map(|hit| hit.thread_id).collect::<Vec<_>>(),
+        vec![ids[2]]
+    );
+
+    let hits = runtime
+        .search_thread_text("re", /*archived*/ false, /*limit*/ 10)
+        .await?;
+    assert_eq!(
+        hits.iter().map(|hit| hit.thread_id).collect::<Vec<_>>(),
+        vec![ids[0], ids[1]]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn search_orders_threads_by_best_rank_and_honors_limit() -> anyhow::Result<()> {
+    let (runtime, ids) = runtime_with_threads(&[
+        ("00000000-0000-0000-0000-000000000001", false),
+        ("00000000-0000-0000-0000-000000000002", false),
+        ("00000000-0000-0000-0000-000000000003", false),
+    ])
+    .await?;
+    let once_in_long_message =
+        "a long message that mentions the needle only once, among many other words";
+    runtime
+        .replace_thread_search_text(ids[0], &[once_in_long_message.to_string()])
+        .await?;
+    runtime
+        .replace_thread_search_text(
+            ids[1],
+            &[
+                "needle plus a much longer tail of unrelated words here".to_string(),
+                "needle needle needle".to_string(),
+            ],
+        )
+        .await?;
+    runtime
+        .replace_thread_search_text(ids[2], &["short needle".to_string()])
+        .await?;
+
+    let hits = runtime
+        .search_thread_text("needle", /*archived*/ false, /*limit*/ 10)
+        .await?;
+    assert_eq!(
+        hits.iter()
+            .map(|hit| (hit.thread_id, hit.text.as_str()))
+            .collect::<Vec<_>>(),
+        vec![
+            (ids[1], "needle needle needle"),
+            (ids[2], "short needle"),
+            (ids[0], once_in_long_message),
+        ]
+    );
+
+    let hits = runtime
+        .search_thread_text("needle", /*archived*/ false, /*limit*/ 2)
+        .await?;
+    assert_eq!(
+        hits.iter().map(|hit| hit.thread_id).collect::<Vec<_>>(),
+        vec![ids[1], ids[2]]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn conditional_replace_keeps_text_appended_after_watermark() -> anyhow::Result<()> {
+    let (runtime, ids) = runtime_with_threads(&[
+        ("00000000-0000-0000-0000-000000000001", false),
+        ("00000000-0000-0000-0000-000000000002", false),
+    ])
+    .await?;
+    let watermark = runtime.thread_search_watermark().await?;
+    runtime
+        .append_thread_search_text(ids[0], &["appended while backfilling".to_string()])
+        .await?;
+
+    assert!(
+        !runtime
+            .replace_thread_search_text_if_unchanged(
+                ids[0],
+                &["stale rollout text".to_string()],
+                watermark,
+            )
+            .await?
+    );
+    assert!(
+        runtime
+            .replace_thread_search_text_if_unchanged(
+                ids[1],
+                &["untouched rollout text".to_string()],
+                watermark,
+            )
+            .await?
+    );
+
+    assert_eq!(
+        runtime
+            .search_thread_text("backfilling", /*archived*/ false, /*limit*/ 10)
+            .await?
+            .iter()
+            .map(|hit| hit.thread_id)
+            .collect::<Vec<_>>(),
+        vec![ids[0]]
+    );
+    assert_eq!(
+        runtime.threads_missing_search_index(/*limit*/ 10).await?,
+        vec![(
+            ids[0],
+            runtime
+                .codex_home()
+                .join(format!("rollout-{}.jsonl", ids[0]))
+        )]
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn index_is_complete_once_every_thread_is_replaced() -> anyhow::Result<()> {
+    let (runtime, ids) = runtime_with_threads(&[
+        ("00000000-0000-0000-0000-000000000001", false),
+        ("00000000-0000-0000-0000-000000000002", false),
+    ])
+    .await?;
+    runtime
+        .append_thread_search_text(ids[0], &["appended while live".to_string()])
+        .await?;
+
+    assert!(
+        !runtime
+            .thread_search_index_complete(/*archived*/ false)
+            .await?
+    );
+    assert_eq!(
+        runtime.threads_missing_search_index(/*limit*/ 10).await?,
+        ids.iter()
+            .map(|id| (
+                *id,
+                runtime.codex_home().join(format!("rollout-{id}.jsonl"))
+            ))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        runtime
+            .thread_search_text_may_match(ids[0], "appended")
+            .await?,
+        None
+    );
+
+    for id in &ids {
+        runtime
+            .replace_thread_search_text(*id, &["full history text".to_string()])
+            .await?;
+    }
+
+    assert!(
+        runtime
+            .thread_search_index_complete(/*archived*/ false)
+            .await?
+    );
+    assert_eq!(
+        runtime.threads_missing_search_index(/*limit*/ 10).await?,
+        Vec::new()
+    );
+    assert_eq!(
+        runtime
+            .thread_search_text_may_match(ids[0], "appended")
+            .await?,
+        Some(false)
+    );
+    assert_eq!(
+        runtime
+            .thread_search_text_may_match(ids[0], "history full")
+            .await?,
+        Some(true)
+    );
+    assert_eq!(
+        runtime.thread_search_text_may_match(ids[0], "a b").await?,
+        None
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn deleting_a_thread_drops_its_search_text() -> anyhow::Result<()> {
+    let (runtime, ids) =
+        runtime_with_threads(&[("00000000-0000-0000-0000-000000000001", false)]).await?;
+    runtime
+        .replace_thread_search_text(ids[0], &["searchable words".to_string()])
+        .await?;
+
+    runtime.delete_thread(ids[0]).await?;
+
+    assert_eq!(
+        runtime
+            .search_thread_text("searchable", /*archived*/ false, /*limit*/ 10)
+            .await?,
+        Vec::new()
+    );
+    let indexed = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM thread_search_indexed")
+        .fetch_one(runtime.pool.as_ref())
+        .await?;
+    assert_eq!(indexed, 0);
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_append_marks_the_thread_for_reindexing() -> anyhow::Result<()> {
+    let (runtime, ids) =
+        runtime_with_threads(&[("00000000-0000-0000-0000-000000000001", false)]).await?;
+    runtime.replace_thread_search_text(ids[0], &[]).await?;
+    assert!(
+        runtime
+            .thread_search_index_complete(/*archived*/ false)
+            .await?
+    );
+
+    sqlx::query("DROP TABLE thread_search")
+        .execute(runtime.pool.as_ref())
+        .await?;
+    assert!(
+        runtime
+            .append_thread_search_text(ids[0], &["lost message".to_string()])
+            .await
+            .is_err()
+    );
+
+    assert!(
+        !runtime
+            .thread_search_index_complete(/*archived*/ false)
+            .await?
+    );
+    assert_eq!(
+        runtime.threads_missing_search_index(/*limit*/ 10).await?,
+        vec![(
+            ids[0],
+            runtime
+                .codex_home()
+                .join(format!("rollout-{}.jsonl", ids[0]))
+        )]
+    );
+    Ok(())
+}
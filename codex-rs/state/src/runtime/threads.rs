@@ -1046,6 +1046,14 @@ ON CONFLICT(id) DO UPDATE SET
                 .bind(thread_id_string)
                 .execute(&mut *tx)
                 .await?;
+            sqlx::query("DELETE FROM thread_search WHERE thread_id = ?")
+                .bind(thread_id_string)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("DELETE FROM thread_search_indexed WHERE thread_id = ?")
+                .bind(thread_id_string)
+                .execute(&mut *tx)
+                .await?;
         }
         for thread_id_string in &thread_id_strings {
             sqlx::query(
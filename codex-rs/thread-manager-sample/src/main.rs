@@ -241,6 +241,7 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         project_doc_max_bytes: 32 * 1024,
         project_doc_fallback_filenames: Vec::new(),
         tool_output_token_limit: None,
+        pinned_context_token_budget: 2_000,
         agents_enabled: true,
         agent_max_threads: Some(6),
         agent_default_subagent_model: None,
@@ -257,6 +258,8 @@ fn new_config(model: Option<String>, arg0_paths: Arg0DispatchPaths) -> anyhow::R
         config_lock_toml: None,
         codex_home,
         history: History::default(),
+        redaction: None,
+        command_secrets: Vec::new(),
         ephemeral: true,
         extra_config: None,
         file_opener: UriBasedFileOpener::VsCode,
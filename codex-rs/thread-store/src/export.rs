@@ -0,0 +1,843 @@
+//! Renders stored threads as shareable transcripts.
+//!
+//! Export materializes history the same way `thread/read` does: legacy threads replay their
+//! rollout through [`ThreadHistoryBuilder`], and paginated threads read the turn and item
+//! projection through [`ThreadStore::list_turns`] and [`ThreadStore::list_items`]. The resulting
+//! items are flattened into a small transcript model that is independent of the rollout format,
+//! which is what the JSON export serializes and what the Markdown and HTML renderers walk.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use chrono::Utc;
+use codex_app_server_protocol::CommandExecutionStatus;
+use codex_app_server_protocol::DynamicToolCallOutputContentItem;
+use codex_app_server_protocol::DynamicToolCallStatus;
+use codex_app_server_protocol::McpToolCallStatus;
+use codex_app_server_protocol::PatchApplyStatus;
+use codex_app_server_protocol::PatchChangeKind;
+use codex_app_server_protocol::ThreadExportFormat;
+use codex_app_server_protocol::ThreadHistoryBuilder;
+use codex_app_server_protocol::ThreadItem;
+use codex_app_server_protocol::TurnStatus;
+use codex_app_server_protocol::UserInput;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::ThreadHistoryMode;
+use codex_rollout::is_persisted_rollout_item;
+use pulldown_cmark::Event;
+use pulldown_cmark::Options;
+use pulldown_cmark::Parser;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+
+use crate::ListItemsParams;
+use crate::ListTurnsParams;
+use crate::LoadThreadHistoryParams;
+use crate::ReadThreadParams;
+use crate::SortDirection;
+use crate::StoredThread;
+use crate::StoredTurnItemsView;
+use crate::StoredTurnStatus;
+use crate::ThreadStore;
+use crate::ThreadStoreError;
+use crate::ThreadStoreResult;
+
+/// Version of the transcript schema written by [`ThreadExportFormat::Json`].
+pub const THREAD_TRANSCRIPT_SCHEMA_VERSION: u32 = 1;
+
+const HISTORY_PAGE_SIZE: usize = 200;
+
+/// Parameters for [`export_thread`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportThreadParams {
+    /// Thread to export. Archived threads are included.
+    pub thread_id: ThreadId,
+    /// Output format.
+    pub format: ThreadExportFormat,
+    /// Whether command output and tool call results are included.
+    pub include_tool_output: bool,
+    /// Whether likely secrets are masked in every exported string.
+    pub redact_secrets: bool,
+}
+
+/// Reads a thread from `store` and renders it in the requested format.
+pub async fn export_thread(
+    store: &dyn ThreadStore,
+    params: ExportThreadParams,
+) -> ThreadStoreResult<String> {
+    let thread = store
+        .read_thread(ReadThreadParams {
+            thread_id: params.thread_id,
+            include_archived: true,
+            include_history: false,
+        })
+        .await?;
+    let turns = match thread.history_mode {
+        ThreadHistoryMode::Legacy => {
+            let history = store
+                .load_history(LoadThreadHistoryParams {
+                    thread_id: params.thread_id,
+                    include_archived: true,
+                })
+                .await?;
+            legacy_turns(&history.items)
+        }
+        ThreadHistoryMode::Paginated => paginated_turns(store, params.thread_id).await?,
+    };
+    render_thread_export(&thread, turns, &params)
+}
+
+/// One turn of materialized history, independent of the thread's history mode.
+#[derive(Clone, Debug)]
+struct ExportTurn {
+    id: String,
+    status: TurnStatus,
+    error: Option<String>,
+    items: Vec<ThreadItem>,
+}
+
+fn legacy_turns(items: &[RolloutItem]) -> Vec<ExportTurn> {
+    let mut builder = ThreadHistoryBuilder::new();
+    for item in items {
+        if is_persisted_rollout_item(item, ThreadHistoryMode::Legacy) {
+            builder.handle_rollout_item(item);
+        }
+    }
+    builder
+        .finish()
+        .into_iter()
+        .map(|turn| ExportTurn {
+            id: turn.id,
+            status: turn.status,
+            error: turn.error.map(|error| error.message),
+            items: turn.items,
+        })
+        .collect()
+}
+
+async fn paginated_turns(
+    store: &dyn ThreadStore,
+    thread_id: ThreadId,
+) -> ThreadStoreResult<Vec<ExportTurn>> {
+    let mut turns = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = store
+            .list_turns(ListTurnsParams {
+                thread_id,
+                include_archived: true,
+                cursor,
+                page_size: HISTORY_PAGE_SIZE,
+                sort_direction: SortDirection::Asc,
+                items_view: StoredTurnItemsView::NotLoaded,
+            })
+            .await?;
+        turns.extend(page.turns);
+        let Some(next_cursor) = page.next_cursor else {
+            break;
+        };
+        cursor = Some(next_cursor);
+    }
+
+    let mut items_by_turn = HashMap::<String, Vec<ThreadItem>>::new();
+    let mut cursor = None;
+    loop {
+        let page = store
+            .list_items(ListItemsParams {
+                thread_id,
+                turn_id: None,
+                include_archived: true,
+                cursor,
+                page_size: HISTORY_PAGE_SIZE,
+                sort_direction: SortDirection::Asc,
+            })
+            .await?;
+        for item in page.items {
+            let parsed = serde_json::from_slice::<ThreadItem>(&item.item_json).map_err(|err| {
+                ThreadStoreError::Internal {
+                    message: format!(
+                        "failed to deserialize stored thread item {}: {err}",
+                        item.item_id
+                    ),
+                }
+            })?;
+            items_by_turn.entry(item.turn_id).or_default().push(parsed);
+        }
+        let Some(next_cursor) = page.next_cursor else {
+            break;
+        };
+        cursor = Some(next_cursor);
+    }
+
+    Ok(turns
+        .into_iter()
+        .map(|turn| ExportTurn {
+            items: items_by_turn.remove(&turn.turn_id).unwrap_or_default(),
+            status: match turn.status {
+                StoredTurnStatus::Completed => TurnStatus::Completed,
+                StoredTurnStatus::Interrupted => TurnStatus::Interrupted,
+                StoredTurnStatus::Failed => TurnStatus::Failed,
+                StoredTurnStatus::InProgress => TurnStatus::InProgress,
+            },
+            error: turn.error.map(|error| error.message),
+            id: turn.turn_id,
+        })
+        .collect())
+}
+
+fn render_thread_export(
+    thread: &StoredThread,
+    turns: Vec<ExportTurn>,
+    params: &ExportThreadParams,
+) -> ThreadStoreResult<String> {
+    let transcript = ThreadTranscript::new(thread, turns, params);
+    match params.format {
+        ThreadExportFormat::Markdown => Ok(render_markdown(&transcript)),
+        ThreadExportFormat::Html => Ok(render_html(&transcript)),
+        ThreadExportFormat::Json => serde_json::to_string_pretty(&transcript)
+            .map(|json| json + "\n")
+            .map_err(|err| ThreadStoreError::Internal {
+                message: format!("failed to serialize thread transcript: {err}"),
+            }),
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum TranscriptStatus {
+    InProgress,
+    Completed,
+    Interrupted,
+    Failed,
+    Declined,
+}
+
+impl TranscriptStatus {
+    fn label(self) -> &'static str {
+        match self {
+            Self::InProgress => "in progress",
+            Self::Completed => "completed",
+            Self::Interrupted => "interrupted",
+            Self::Failed => "failed",
+            Self::Declined => "declined",
+        }
+    }
+}
+
+impl From<TurnStatus> for TranscriptStatus {
+    fn from(status: TurnStatus) -> Self {
+        match status {
+            TurnStatus::Completed => Self::Completed,
+            TurnStatus::Interrupted => Self::Interrupted,
+            TurnStatus::Failed => Self::Failed,
+            TurnStatus::InProgress => Self::InProgress,
+        }
+    }
+}
+
+impl From<CommandExecutionStatus> for TranscriptStatus {
+    fn from(status: CommandExecutionStatus) -> Self {
+        match status {
+            CommandExecutionStatus::InProgress => Self::InProgress,
+            CommandExecutionStatus::Completed => Self::Completed,
+            CommandExecutionStatus::Failed => Self::Failed,
+            CommandExecutionStatus::Declined => Self::Declined,
+        }
+    }
+}
+
+impl From<PatchApplyStatus> for TranscriptStatus {
+    fn from(status: PatchApplyStatus) -> Self {
+        match status {
+            PatchApplyStatus::InProgress => Self::InProgress,
+            PatchApplyStatus::Completed => Self::Completed,
+            PatchApplyStatus::Failed => Self::Failed,
+            PatchApplyStatus::Declined => Self::Declined,
+        }
+    }
+}
+
+impl From<McpToolCallStatus> for TranscriptStatus {
+    fn from(status: McpToolCallStatus) -> Self {
+        match status {
+            McpToolCallStatus::InProgress => Self::InProgress,
+            McpToolCallStatus::Completed => Self::Completed,
+            McpToolCallStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+impl From<DynamicToolCallStatus> for TranscriptStatus {
+    fn from(status: DynamicToolCallStatus) -> Self {
+        match status {
+            DynamicToolCallStatus::InProgress => Self::InProgress,
+            DynamicToolCallStatus::Completed => Self::Completed,
+            DynamicToolCallStatus::Failed => Self::Failed,
+        }
+    }
+}
+
+/// Export-facing view of a thread. This is the JSON schema, so field changes need a
+/// [`THREAD_TRANSCRIPT_SCHEMA_VERSION`] bump.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ThreadTranscript {
+    schema_version: u32,
+    thread_id: String,
+    title: String,
+    cwd: String,
+    model: Option<String>,
+    created_at: String,
+    updated_at: String,
+    turns: Vec<TranscriptTurn>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TranscriptTurn {
+    id: String,
+    status: TranscriptStatus,
+    error: Option<String>,
+    entries: Vec<TranscriptEntry>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum TranscriptEntry {
+    #[serde(rename_all = "camelCase")]
+    UserMessage {
+        text: String,
+        attachments: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    AgentMessage {
+        text: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Plan {
+        text: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    Reasoning {
+        summary: Vec<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    Command {
+        command: String,
+        cwd: String,
+        status: TranscriptStatus,
+        exit_code: Option<i32>,
+        duration_ms: Option<i64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    FileChange {
+        status: TranscriptStatus,
+        changes: Vec<TranscriptFileChange>,
+    },
+    #[serde(rename_all = "camelCase")]
+    ToolCall {
+        tool: String,
+        status: TranscriptStatus,
+        arguments: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        output: Option<String>,
+    },
+    #[serde(rename_all = "camelCase")]
+    WebSearch {
+        query: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ReviewStarted {
+        text: String,
+    },
+    #[serde(rename_all = "camelCase")]
+    ReviewFindings {
+        text: String,
+    },
+    Compaction,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+enum TranscriptChangeKind {
+    Add,
+    Delete,
+    Update,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TranscriptFileChange {
+    path: String,
+    kind: TranscriptChangeKind,
+    move_path: Option<String>,
+    diff: String,
+}
+
+impl ThreadTranscript {
+    fn new(thread: &StoredThread, turns: Vec<ExportTurn>, params: &ExportThreadParams) -> Self {
+        let text = |value: String| {
+            if params.redact_secrets {
+                codex_secrets::redact_secrets(value)
+            } else {
+                value
+            }
+        };
+        let title = thread
+            .name
+            .clone()
+            .filter(|name| !name.trim().is_empty())
+            .unwrap_or_else(|| format!("Codex session {}", thread.thread_id));
+        let turns = turns
+            .into_iter()
+            .map(|turn| TranscriptTurn {
+                id: turn.id,
+                status: turn.status.into(),
+                error: turn.error.map(text),
+                entries: turn
+                    .items
+                    .into_iter()
+                    .filter_map(|item| transcript_entry(item, params.include_tool_output, &text))
+                    .collect(),
+            })
+            .collect();
+        Self {
+            schema_version: THREAD_TRANSCRIPT_SCHEMA_VERSION,
+            thread_id: thread.thread_id.to_string(),
+            title: text(title),
+            cwd: thread.cwd.display().to_string(),
+            model: thread.model.clone(),
+            created_at: format_timestamp(thread.created_at),
+            updated_at: format_timestamp(thread.updated_at),
+            turns,
+        }
+    }
+}
+
+fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, /*use_z*/ true)
+}
+
+/// Maps one materialized item to a transcript entry. Items that only matter to a live client
+/// (image views, sleeps, sub-agent bookkeeping, hook prompts) are dropped.
+fn transcript_entry(
+    item: ThreadItem,
+    include_tool_output: bool,
+    text: &dyn Fn(String) -> String,
+) -> Option<TranscriptEntry> {
+    let entry = match item {
+        ThreadItem::UserMessage { content, .. } => {
+            let mut parts = Vec::new();
+            let mut attachments = Vec::new();
+            for input in content {
+                match input {
+                    UserInput::Text { text: part, .. } => parts.push(part),
+                    UserInput::Image { url, .. } => attachments.push(attachment_url(url, "image")),
+                    UserInput::LocalImage { path, .. } | UserInput::LocalAudio { path } => {
+                        attachments.push(path.display().to_string());
+                    }
+                    UserInput::Audio { url } => attachments.push(attachment_url(url, "audio")),
+                    UserInput::Skill { name, .. } => attachments.push(format!("skill {name}")),
+                    UserInput::Mention { name, path } => {
+                        attachments.push(format!("{name} ({path})"));
+                    }
+                }
+            }
+            TranscriptEntry::UserMessage {
+                text: text(parts.join("\n\n")),
+                attachments,
+            }
+        }
+        ThreadItem::AgentMessage { text: message, .. } => TranscriptEntry::AgentMessage {
+            text: text(message),
+        },
+        ThreadItem::Plan { text: plan, .. } => TranscriptEntry::Plan { text: text(plan) },
+        ThreadItem::Reasoning { summary, .. } => {
+            if summary.is_empty() {
+                return None;
+            }
+            TranscriptEntry::Reasoning {
+                summary: summary.into_iter().map(text).collect(),
+            }
+        }
+        ThreadItem::CommandExecution {
+            command,
+            cwd,
+            status,
+            aggregated_output,
+            exit_code,
+            duration_ms,
+            ..
+        } => TranscriptEntry::Command {
+            command: text(command),
+            cwd: cwd.into_string(),
+            status: status.into(),
+            exit_code,
+            duration_ms,
+            output: aggregated_output.filter(|_| include_tool_output).map(text),
+        },
+        ThreadItem::FileChange {
+            changes, status, ..
+        } => TranscriptEntry::FileChange {
+            status: status.into(),
+            changes: changes
+                .into_iter()
+                .map(|change| {
+                    let (kind, move_path) = match change.kind {
+                        PatchChangeKind::Add => (TranscriptChangeKind::Add, None),
+                        PatchChangeKind::Delete => (TranscriptChangeKind::Delete, None),
+                        PatchChangeKind::Update { move_path } => (
+                            TranscriptChangeKind::Update,
+                            move_path.map(|path| path.display().to_string()),
+                        ),
+                    };
+                    TranscriptFileChange {
+                        path: change.path,
+                        kind,
+                        move_path,
+                        diff: text(change.diff),
+                    }
+                })
+                .collect(),
+        },
+        ThreadItem::McpToolCall {
+            server,
+            tool,
+            status,
+            arguments,
+            result,
+            error,
+            ..
+        } => {
+            let output = match (result, error) {
+                (_, Some(error)) => Some(error.message),
+                (Some(result), None) => Some(
+                    result
+                        .content
+                        .iter()
+                        .map(mcp_content_text)
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+                (None, None) => None,
+            };
+            TranscriptEntry::ToolCall {
+                tool: format!("{server}.{tool}"),
+                status: status.into(),
+                arguments: tool_arguments(&arguments).map(text),
+                output: output.filter(|_| include_tool_output).map(text),
+            }
+        }
+        ThreadItem::DynamicToolCall {
+            namespace,
+            tool,
+            arguments,
+            status,
+            content_items,
+            ..
+        } => {
+            let output = content_items.map(|items| {
+                items
+                    .into_iter()
+                    .map(|item| match item {
+                        DynamicToolCallOutputContentItem::InputText { text: part } => part,
+                        DynamicToolCallOutputContentItem::InputImage { .. } => {
+                            "[image]".to_string()
+                        }
+                        DynamicToolCallOutputContentItem::InputAudio { .. } => {
+                            "[audio]".to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            });
+            TranscriptEntry::ToolCall {
+                tool: match namespace {
+                    Some(namespace) => format!("{namespace}.{tool}"),
+                    None => tool,
+                },
+                status: status.into(),
+                arguments: tool_arguments(&arguments).map(text),
+                output: output.filter(|_| include_tool_output).map(text),
+            }
+        }
+        ThreadItem::WebSearch(search) => TranscriptEntry::WebSearch {
+            query: text(search.query),
+        },
+        ThreadItem::EnteredReviewMode { review, .. } => {
+            TranscriptEntry::ReviewStarted { text: text(review) }
+        }
+        ThreadItem::ExitedReviewMode { review, .. } => {
+            TranscriptEntry::ReviewFindings { text: text(review) }
+        }
+        ThreadItem::ContextCompaction { .. } => TranscriptEntry::Compaction,
+        ThreadItem::HookPrompt { .. }
+        | ThreadItem::CollabAgentToolCall { .. }
+        | ThreadItem::SubAgentActivity { .. }
+        | ThreadItem::ImageView { .. }
+        | ThreadItem::Sleep(_)
+        | ThreadItem::ImageGeneration(_) => return None,
+    };
+    Some(entry)
+}
+
+/// Inline `data:` URLs are megabytes of base64 that nobody wants in a transcript.
+fn attachment_url(url: String, kind: &str) -> String {
+    if url.starts_with("data:") {
+        format!("[inline {kind}]")
+    } else {
+        url
+    }
+}
+
+fn mcp_content_text(content: &JsonValue) -> String {
+    match content.get("text").and_then(JsonValue::as_str) {
+        Some(text) if content.get("type").and_then(JsonValue::as_str) == Some("text") => {
+            text.to_string()
+        }
+        _ => content.to_string(),
+    }
+}
+
+fn tool_arguments(arguments: &JsonValue) -> Option<String> {
+    match arguments {
+        JsonValue::Null => None,
+        JsonValue::Object(map) if map.is_empty() => None,
+        arguments => serde_json::to_string_pretty(arguments).ok(),
+    }
+}
+
+fn render_markdown(transcript: &ThreadTranscript) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# {}", transcript.title);
+    out.push('\n');
+    let _ = writeln!(out, "- Session: `{}`", transcript.thread_id);
+    let _ = writeln!(out, "- Working directory: `{}`", transcript.cwd);
+    if let Some(model) = transcript.model.as_deref() {
+        let _ = writeln!(out, "- Model: `{model}`");
+    }
+    let _ = writeln!(out, "- Created: {}", transcript.created_at);
+    let _ = writeln!(out, "- Updated: {}", transcript.updated_at);
+
+    for (index, turn) in transcript.turns.iter().enumerate() {
+        let _ = write!(out, "\n## Turn {}\n", index + 1);
+        if turn.status != TranscriptStatus::Completed {
+            let _ = write!(out, "\n_Turn {}._\n", turn.status.label());
+        }
+        if let Some(error) = turn.error.as_deref() {
+            let _ = write!(out, "\n**Error:** {error}\n");
+        }
+        for entry in &turn.entries {
+            out.push('\n');
+            render_markdown_entry(&mut out, entry);
+        }
+    }
+    out
+}
+
+fn render_markdown_entry(out: &mut String, entry: &TranscriptEntry) {
+    match entry {
+        TranscriptEntry::UserMessage { text, attachments } => {
+            let _ = writeln!(out, "### User\n\n{}", text.trim_end());
+            if !attachments.is_empty() {
+                let attachments = attachments
+                    .iter()
+                    .map(|attachment| inline_code(attachment))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let _ = writeln!(out, "\nAttachments: {attachments}");
+            }
+        }
+        TranscriptEntry::AgentMessage { text } => {
+            let _ = writeln!(out, "### Codex\n\n{}", text.trim_end());
+        }
+        TranscriptEntry::Plan { text } => {
+            let _ = writeln!(out, "### Plan\n\n{}", text.trim_end());
+        }
+        TranscriptEntry::Reasoning { summary } => {
+            out.push_str("> **Reasoning**\n");
+            for paragraph in summary {
+                out.push_str(">\n");
+                for line in paragraph.trim_end().lines() {
+                    let _ = writeln!(out, "> {line}");
+                }
+            }
+        }
+        TranscriptEntry::Command {
+            command,
+            status,
+            exit_code,
+            duration_ms,
+            output,
+            ..
+        } => {
+            let mut details = Vec::new();
+            if *status != TranscriptStatus::Completed {
+                details.push(status.label().to_string());
+            }
+            if let Some(exit_code) = exit_code {
+                details.push(format!("exit code {exit_code}"));
+            }
+            if let Some(duration_ms) = duration_ms {
+                details.push(format_duration(*duration_ms));
+            }
+            out.push_str("**Command**");
+            if !details.is_empty() {
+                let _ = write!(out, " ({})", details.join(", "));
+            }
+            out.push_str("\n\n");
+            push_code_block(out, "sh", command);
+            if let Some(output) = output.as_deref().filter(|output| !output.trim().is_empty()) {
+                out.push('\n');
+                push_code_block(out, "text", output);
+            }
+        }
+        TranscriptEntry::FileChange { status, changes } => {
+            out.push_str("**File changes**");
+            if *status != TranscriptStatus::Completed {
+                let _ = write!(out, " ({})", status.label());
+            }
+            out.push('\n');
+            for change in changes {
+                let action = match (&change.kind, change.move_path.as_deref()) {
+                    (TranscriptChangeKind::Add, _) => "added".to_string(),
+                    (TranscriptChangeKind::Delete, _) => "deleted".to_string(),
+                    (TranscriptChangeKind::Update, Some(move_path)) => {
+                        format!("moved to {}", inline_code(move_path))
+                    }
+                    (TranscriptChangeKind::Update, None) => "updated".to_string(),
+                };
+                let _ = writeln!(out, "\n{} {action}", inline_code(&change.path));
+                if !change.diff.trim().is_empty() {
+                    out.push('\n');
+                    push_code_block(out, "diff", &change.diff);
+                }
+            }
+        }
+        TranscriptEntry::ToolCall {
+            tool,
+            status,
+            arguments,
+            output,
+        } => {
+            let _ = write!(out, "**Tool call** {}", inline_code(tool));
+            if *status != TranscriptStatus::Completed {
+                let _ = write!(out, " ({})", status.label());
+            }
+            out.push('\n');
+            if let Some(arguments) = arguments.as_deref() {
+                out.push('\n');
+                push_code_block(out, "json", arguments);
+            }
+            if let Some(output) = output.as_deref().filter(|output| !output.trim().is_empty()) {
+                out.push('\n');
+                push_code_block(out, "text", output);
+            }
+        }
+        TranscriptEntry::WebSearch { query } => {
+            let _ = writeln!(out, "**Web search:** {query}");
+        }
+        TranscriptEntry::ReviewStarted { text } => {
+            let _ = writeln!(out, "### Review started\n\n{}", text.trim_end());
+        }
+        TranscriptEntry::ReviewFindings { text } => {
+            let _ = writeln!(out, "### Review findings\n\n{}", text.trim_end());
+        }
+        TranscriptEntry::Compaction => out.push_str("_Context compacted._\n"),
+    }
+}
+
+/// Writes a fenced block whose fence is longer than any backtick run inside `body`, so
+/// command output that itself contains Markdown fences cannot close the block early.
+fn push_code_block(out: &mut String, language: &str, body: &str) {
+    let fence = "`".repeat(longest_backtick_run(body).max(2) + 1);
+    let body = body.trim_end_matches('\n');
+    let _ = write!(out, "{fence}{language}\n{body}\n{fence}\n");
+}
+
+fn inline_code(text: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(text) + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    let mut longest = 0;
+    let mut current = 0;
+    for ch in text.chars() {
+        if ch == '`' {
+            current += 1;
+            longest = longest.max(current);
+        } else {
+            current = 0;
+        }
+    }
+    longest
+}
+
+fn format_duration(duration_ms: i64) -> String {
+    if duration_ms < 1_000 {
+        format!("{duration_ms}ms")
+    } else {
+        format!("{:.1}s", duration_ms as f64 / 1_000.0)
+    }
+}
+
+const HTML_STYLE: &str = "body{font-family:-apple-system,BlinkMacSystemFont,\"Segoe UI\",sans-serif;\
+max-width:56rem;margin:2rem auto;padding:0 1rem;line-height:1.5;color:#1f2328}\
+h1{font-size:1.6rem}h2{border-bottom:1px solid #d0d7de;padding-bottom:.3rem;margin-top:2rem}\
+h3{font-size:1rem}pre{background:#f6f8fa;padding:.75rem;overflow-x:auto;border-radius:6px}\
+code{font-family:ui-monospace,SFMono-Regular,Menlo,monospace;font-size:.875rem}\
+blockquote{color:#59636e;border-left:.25rem solid #d0d7de;margin:0;padding:0 1rem}";
+
+/// Renders the Markdown transcript as a single self-contained page. Raw HTML in messages is
+/// shown as text and the page's content security policy forbids scripts, so an exported
+/// transcript is safe to open even when the conversation contains markup.
+fn render_html(transcript: &ThreadTranscript) -> String {
+    let markdown = render_markdown(transcript);
+    let parser = Parser::new_ext(
+        &markdown,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    )
+    .map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        event => event,
+    });
+    let mut body = String::new();
+    pulldown_cmark::html::push_html(&mut body, parser);
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta http-equiv=\"Content-Security-Policy\" content=\"default-src 'none'; \
+         style-src 'unsafe-inline'; img-src https: data:\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        escape_html(&transcript.title)
+    )
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+#[path = "export_tests.rs"]
+mod tests;
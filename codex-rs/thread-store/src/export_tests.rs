@@ -0,0 +1,240 @@
+use chrono::TimeZone;
+use codex_app_server_protocol::CommandExecutionSource;
+use codex_app_server_protocol::FileUpdateChange;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::protocol::AgentMessageEvent;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::SessionSource;
+use codex_protocol::protocol::ThreadMemoryMode;
+use codex_protocol::protocol::UserMessageEvent;
+use codex_utils_path_uri::LegacyAppPathString;
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+use super::*;
+use crate::AppendThreadItemsParams;
+use crate::CreateThreadParams;
+use crate::InMemoryThreadStore;
+use crate::ThreadMetadataPatch;
+use crate::ThreadPersistenceMetadata;
+use crate::UpdateThreadMetadataParams;
+
+async fn create_thread(store: &InMemoryThreadStore) -> ThreadId {
+    let thread_id = ThreadId::default();
+    store
+        .create_thread(CreateThreadParams {
+            session_id: thread_id.into(),
+            thread_id,
+            extra_config: None,
+            forked_from_id: None,
+            parent_thread_id: None,
+            source: SessionSource::Exec,
+            thread_source: None,
+            originator: "test_originator".to_string(),
+            base_instructions: BaseInstructions::default(),
+            dynamic_tools: Vec::new(),
+            selected_capability_roots: Vec::new(),
+            multi_agent_version: None,
+            history_mode: ThreadHistoryMode::Legacy,
+            subagent_history_start_ordinal: None,
+            initial_window_id: uuid::Uuid::now_v7().to_string(),
+            metadata: ThreadPersistenceMetadata {
+                cwd: None,
+                model_provider: "test-provider".to_string(),
+                memory_mode: ThreadMemoryMode::Enabled,
+            },
+        })
+        .await
+        .expect("create thread");
+    let timestamp = Utc
+        .with_ymd_and_hms(2026, 3, 1, 12, 0, 0)
+        .single()
+        .expect("valid timestamp");
+    store
+        .update_thread_metadata(UpdateThreadMetadataParams {
+            thread_id,
+            patch: ThreadMetadataPatch {
+                name: Some(Some("Fix <flaky> test".to_string())),
+                model: Some("gpt-test".to_string()),
+                cwd: Some("/workspace".into()),
+                created_at: Some(timestamp),
+                updated_at: Some(timestamp),
+                ..Default::default()
+            },
+            include_archived: true,
+        })
+        .await
+        .expect("update metadata");
+    thread_id
+}
+
+fn params(thread_id: ThreadId, format: ThreadExportFormat) -> ExportThreadParams {
+    ExportThreadParams {
+        thread_id,
+        format,
+        include_tool_output: true,
+        redact_secrets: false,
+    }
+}
+
+fn tool_turn() -> ExportTurn {
+    ExportTurn {
+        id: "turn-1".to_string(),
+        status: TurnStatus::Interrupted,
+        error: None,
+        items: vec![
+            ThreadItem::Reasoning {
+                id: "reasoning-1".to_string(),
+                summary: vec!["Check the test output.".to_string()],
+                content: vec!["raw chain of thought".to_string()],
+            },
+            ThreadItem::CommandExecution {
+                id: "command-1".to_string(),
+                command: "cat README.md".to_string(),
+                cwd: LegacyAppPathString::from_string("/workspace"),
+                process_id: None,
+                source: CommandExecutionSource::Agent,
+                status: CommandExecutionStatus::Completed,
+                command_actions: Vec::new(),
+                aggregated_output: Some("```rust\nfn main() {}\n```\n".to_string()),
+                exit_code: Some(0),
+                duration_ms: Some(1_400),
+            },
+            ThreadItem::FileChange {
+                id: "patch-1".to_string(),
+                changes: vec![FileUpdateChange {
+                    path: "src/lib.rs".to_string(),
+                    kind: PatchChangeKind::Update { move_path: None },
+                    diff: "@@ -1 +1 @@\n-old\n+new\n".to_string(),
+                }],
+                status: PatchApplyStatus::Completed,
+            },
+            ThreadItem::ExitedReviewMode {
+                id: "review-1".to_string(),
+                review: "- [P1] Handle the empty case".to_string(),
+            },
+        ],
+    }
+}
+
+async fn render_turn(format: ThreadExportFormat, include_tool_output: bool) -> String {
+    let store = InMemoryThreadStore::default();
+    let thread_id = create_thread(&store).await;
+    let thread = store
+        .read_thread(ReadThreadParams {
+            thread_id,
+            include_archived: true,
+            include_history: false,
+        })
+        .await
+        .expect("read thread");
+    render_thread_export(
+        &thread,
+        vec![tool_turn()],
+        &ExportThreadParams {
+            include_tool_output,
+            ..params(thread_id, format)
+        },
+    )
+    .expect("render export")
+}
+
+#[tokio::test]
+async fn markdown_renders_tool_output_in_fences_that_cannot_be_closed_early() {
+    let markdown = render_turn(
+        ThreadExportFormat::Markdown,
+        /*include_tool_output*/ true,
+    )
+    .await;
+    let body = markdown
+        .split_once("\n## Turn 1\n")
+        .map(|(_, body)| body)
+        .expect("turn heading");
+
+    assert!(markdown.starts_with("# Fix <flaky> test\n\n- Session: `"));
+    assert!(markdown.contains("- Model: `gpt-test`\n- Created: 2026-03-01T12:00:00Z\n"));
+    assert_eq!(
+        body,
+        "\n_Turn interrupted._\n\
+         \n> **Reasoning**\n>\n> Check the test output.\n\
+         \n**Command** (exit code 0, 1.4s)\n\n```sh\ncat README.md\n```\n\
+         \n````text\n```rust\nfn main() {}\n```\n````\n\
+         \n**File changes**\n\n`src/lib.rs` updated\n\n```diff\n@@ -1 +1 @@\n-old\n+new\n```\n\
+         \n### Review findings\n\n- [P1] Handle the empty case\n"
+    );
+}
+
+#[tokio::test]
+async fn html_export_omits_tool_output_and_escapes_markup() {
+    let html = render_turn(ThreadExportFormat::Html, /*include_tool_output*/ false).await;
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("<title>Fix &lt;flaky&gt; test</title>"));
+    assert!(html.contains("<h1>Fix &lt;flaky&gt; test</h1>"));
+    assert!(html.contains("default-src 'none'"));
+    assert!(html.contains("<code class=\"language-sh\">cat README.md\n</code>"));
+    assert!(!html.contains("fn main"));
+    assert!(!html.contains("raw chain of thought"));
+}
+
+#[tokio::test]
+async fn json_export_materializes_legacy_history_and_redacts_secrets() {
+    let store = InMemoryThreadStore::default();
+    let thread_id = create_thread(&store).await;
+    store
+        .append_items(AppendThreadItemsParams {
+            thread_id,
+            items: vec![
+                RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                    message: "Use api_key=sk-abcdefghijklmnopqrstuvwxyz to deploy".to_string(),
+                    ..Default::default()
+                })),
+                RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                    message: "Deployed.".to_string(),
+                    phase: None,
+                    memory_citation: None,
+                })),
+            ],
+        })
+        .await
+        .expect("append items");
+
+    let exported = export_thread(
+        &store,
+        ExportThreadParams {
+            redact_secrets: true,
+            ..params(thread_id, ThreadExportFormat::Json)
+        },
+    )
+    .await
+    .expect("export thread");
+    let mut transcript: JsonValue = serde_json::from_str(&exported).expect("valid json");
+    let turn_id = transcript["turns"][0]["id"].take();
+
+    assert!(turn_id.is_string());
+    assert_eq!(
+        transcript,
+        json!({
+            "schemaVersion": 1,
+            "threadId": thread_id.to_string(),
+            "title": "Fix <flaky> test",
+            "cwd": "/workspace",
+            "model": "gpt-test",
+            "createdAt": "2026-03-01T12:00:00Z",
+            "updatedAt": "2026-03-01T12:00:00Z",
+            "turns": [{
+                "id": null,
+                "status": "completed",
+                "error": null,
+                "entries": [
+                    {
+                        "type": "userMessage",
+                        "text": "Use api_key=[REDACTED_SECRET] to deploy",
+                        "attachments": []
+                    },
+                    { "type": "agentMessage", "text": "Deployed." }
+                ]
+            }]
+        })
+    );
+}
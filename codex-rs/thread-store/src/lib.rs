@@ -5,6 +5,7 @@
 //! any other backing store.
 
 mod error;
+mod export;
 mod in_memory;
 mod live_thread;
 mod local;
@@ -14,6 +15,9 @@ mod types;
 
 pub use error::ThreadStoreError;
 pub use error::ThreadStoreResult;
+pub use export::ExportThreadParams;
+pub use export::THREAD_TRANSCRIPT_SCHEMA_VERSION;
+pub use export::export_thread;
 pub use in_memory::InMemoryThreadStore;
 pub use in_memory::InMemoryThreadStoreCalls;
 pub use live_thread::LiveThread;
@@ -3,6 +3,7 @@ use codex_rollout::find_thread_path_by_id_str;
 
 use super::LocalThreadStore;
 use super::helpers::matching_rollout_file_name;
+use super::helpers::remove_thread_checkpoints;
 use super::helpers::scoped_rollout_path;
 use crate::ArchiveThreadParams;
 use crate::ThreadStoreError;
@@ -57,6 +58,7 @@ pub(super) async fn archive_thread(
             .mark_archived(thread_id, archived_path.as_path(), Utc::now())
             .await;
     }
+    remove_thread_checkpoints(store, thread_id).await;
     Ok(())
 }
 
@@ -124,6 +126,24 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn archive_thread_removes_workspace_checkpoints() {
+        let home = TempDir::new().expect("temp dir");
+        let store = LocalThreadStore::new(test_config(home.path()), /*state_db*/ None);
+        let uuid = Uuid::from_u128(203);
+        let thread_id = ThreadId::from_string(&uuid.to_string()).expect("valid thread id");
+        write_session_file(home.path(), "2025-01-03T12-00-00", uuid).expect("session file");
+        let checkpoints = codex_git_utils::workspace_checkpoint_store(home.path(), thread_id);
+        std::fs::create_dir_all(checkpoints.join("objects")).expect("create checkpoint store");
+
+        store
+            .archive_thread(ArchiveThreadParams { thread_id })
+            .await
+            .expect("archive thread");
+
+        assert!(!checkpoints.exists());
+    }
+
     #[tokio::test]
     async fn archive_thread_updates_sqlite_metadata_when_present() {
         let home = TempDir::new().expect("temp dir");
@@ -3,18 +3,26 @@
 //! Existing rollout files are deleted before this operation reports success. A rollout file that
 //! vanishes after discovery counts as already deleted. The app-server deletes main state DB rows
 //! after every associated rollout is removed; this module deletes local history projection rows.
+//! Threads that ran in an isolated worktree (sub-agents spawned with `isolation: worktree`) have
+//! the worktree removed as well, and the thread's workspace checkpoints are deleted.
 
 use std::io::ErrorKind;
 use std::path::Path;
 
+use codex_git_utils::ISOLATED_WORKTREES_SUBDIR;
+use codex_git_utils::find_isolated_worktree;
+use codex_git_utils::remove_isolated_worktree;
 use codex_rollout::ARCHIVED_SESSIONS_SUBDIR;
 use codex_rollout::SESSIONS_SUBDIR;
 use codex_rollout::find_archived_thread_path_by_id_str;
 use codex_rollout::find_thread_path_by_id_str;
+use codex_rollout::read_session_meta_line;
 use codex_rollout::remove_thread_name_entries;
+use tracing::warn;
 
 use super::LocalThreadStore;
 use super::helpers::matching_rollout_file_name;
+use super::helpers::remove_thread_checkpoints;
 use super::helpers::scoped_rollout_path;
 use crate::DeleteThreadParams;
 use crate::ThreadStoreError;
@@ -67,9 +75,13 @@ pub(super) async fn delete_thread(
     // replacements out while we find paths and clean up rollout files and history rows.
     store.live_recorders.lock().await.remove(&thread_id);
     let found_rollout_path = !rollout_paths.is_empty();
+    if let Some(rollout_path) = rollout_paths.first() {
+        remove_thread_worktree(store, rollout_path.as_path()).await;
+    }
     for rollout_path in rollout_paths {
         delete_rollout_file(store, rollout_path.as_path(), thread_id)?;
     }
+    remove_thread_checkpoints(store, thread_id).await;
     remove_thread_name_entries(store.config.codex_home.as_path(), thread_id)
         .await
         .map_err(|err| ThreadStoreError::Internal {
@@ -86,6 +98,32 @@ pub(super) async fn delete_thread(
     Ok(())
 }
 
+/// Removes the isolated worktree the thread ran in, if any. Failures are logged and do not
+/// block deleting the thread.
+async fn remove_thread_worktree(store: &LocalThreadStore, rollout_path: &Path) {
+    let Ok(meta_line) = read_session_meta_line(rollout_path).await else {
+        return;
+    };
+    let worktrees_dir = store.config.codex_home.join(ISOLATED_WORKTREES_SUBDIR);
+    let worktree = match find_isolated_worktree(&worktrees_dir, &meta_line.meta.cwd).await {
+        Ok(Some(worktree)) => worktree,
+        Ok(None) => return,
+        Err(err) => {
+            warn!(
+                "failed to look up isolated worktree for {}: {err:#}",
+                meta_line.meta.cwd.display()
+            );
+            return;
+        }
+    };
+    if let Err(err) = remove_isolated_worktree(&worktree).await {
+        warn!(
+            "failed to remove isolated worktree {}: {err:#}",
+            worktree.root.display()
+        );
+    }
+}
+
 fn delete_rollout_file(
     store: &LocalThreadStore,
     rollout_path: &Path,
@@ -182,6 +220,39 @@ mod tests {
         assert!(!compressed_path.exists());
     }
 
+    #[tokio::test]
+    async fn delete_thread_removes_isolated_worktree() {
+        let home = TempDir::new().expect("temp dir");
+        let store = LocalThreadStore::new(test_config(home.path()), /*state_db*/ None);
+        let workspace = home.path().join("workspace");
+        std::fs::create_dir_all(&workspace).expect("create workspace");
+        std::fs::write(workspace.join("notes.txt"), "notes").expect("write notes");
+        let worktree = codex_git_utils::create_isolated_worktree(
+            &home.path().join(ISOLATED_WORKTREES_SUBDIR).join("child"),
+            &workspace,
+        )
+        .await
+        .expect("create worktree");
+        let uuid = Uuid::from_u128(307);
+        let path =
+            write_session_file(home.path(), "2025-01-03T12-00-00", uuid).expect("session file");
+        let rollout = std::fs::read_to_string(&path).expect("read rollout");
+        let (meta, rest) = rollout.split_once('\n').expect("session meta line");
+        let mut meta: serde_json::Value = serde_json::from_str(meta).expect("parse session meta");
+        meta["payload"]["cwd"] = serde_json::json!(worktree.checkout());
+        std::fs::write(&path, format!("{meta}\n{rest}")).expect("rewrite rollout");
+
+        let thread_id = ThreadId::from_string(&uuid.to_string()).expect("valid thread id");
+        store
+            .delete_thread(DeleteThreadParams { thread_id })
+            .await
+            .expect("delete thread");
+
+        assert!(!path.exists());
+        assert!(!worktree.root.exists());
+        assert!(workspace.join("notes.txt").exists());
+    }
+
     #[tokio::test]
     async fn delete_rollout_file_treats_vanished_path_as_already_deleted() {
         let home = TempDir::new().expect("temp dir");
@@ -10,6 +10,8 @@ use std::time::SystemTime;
 use chrono::DateTime;
 use chrono::Utc;
 use codex_git_utils::GitSha;
+use codex_git_utils::remove_workspace_checkpoints;
+use codex_git_utils::workspace_checkpoint_store;
 use codex_protocol::ThreadId;
 use codex_protocol::models::PermissionProfile;
 use codex_protocol::protocol::AskForApproval;
@@ -22,6 +24,7 @@ use codex_rollout::ARCHIVED_SESSIONS_SUBDIR;
 use codex_rollout::ThreadItem;
 use codex_rollout::find_thread_names_by_ids;
 use codex_state::ThreadMetadata;
+use tracing::warn;
 
 use super::LocalThreadStore;
 use crate::StoredThread;
@@ -101,6 +104,15 @@ pub(super) fn touch_modified_time(path: &Path) -> std::io::Result<()> {
     OpenOptions::new().append(true).open(path)?.set_times(times)
 }
 
+/// Deletes the workspace checkpoints recorded for a thread. Failures are logged and do not block
+/// archiving or deleting the thread.
+pub(super) async fn remove_thread_checkpoints(store: &LocalThreadStore, thread_id: ThreadId) {
+    let checkpoints = workspace_checkpoint_store(store.config.codex_home.as_path(), thread_id);
+    if let Err(err) = remove_workspace_checkpoints(&checkpoints).await {
+        warn!("failed to remove checkpoints for thread {thread_id}: {err:#}");
+    }
+}
+
 pub(super) fn stored_thread_from_rollout_item(
     item: ThreadItem,
     archived: bool,
@@ -8,6 +8,7 @@ use codex_rollout::RolloutConfig;
 use codex_rollout::RolloutRecorder;
 use codex_rollout::RolloutRecorderParams;
 use codex_rollout::persisted_rollout_items;
+use codex_rollout::rollout_item_search_text;
 use tracing::warn;
 
 use super::LocalThreadStore;
@@ -33,7 +34,14 @@ pub(super) async fn create_thread(
     let recorder = create_thread::create_thread(store, params).await?;
     store
         .insert_live_recorder(thread_id, recorder, history_mode)
-        .await
+        .await?;
+    // A new thread has no history yet, so appends alone keep its search text complete.
+    if let Some(state_db) = store.state_db().await
+        && let Err(err) = state_db.replace_thread_search_text(thread_id, &[]).await
+    {
+        warn!("failed to start search index for thread {thread_id}: {err}");
+    }
+    Ok(())
 }
 
 pub(super) async fn resume_thread(
@@ -286,12 +294,14 @@ async fn write_and_project(
     let _live_writer_guard = store.live_writer_locks.lock(thread_id).await;
     let (recorder, history_mode) = live_writer_parts(store, thread_id).await?;
     let sync_rollout_path = matches!(&write_op, RolloutWriteOp::Persist | RolloutWriteOp::Flush);
+    let mut search_texts = Vec::new();
     let write_op = match write_op {
         RolloutWriteOp::AppendItems(items) => {
             let items = persisted_rollout_items(items.as_slice(), history_mode);
             if items.is_empty() {
                 return Ok(());
             }
+            search_texts = items.iter().filter_map(rollout_item_search_text).collect();
             RolloutWriteOp::AppendItems(items)
         }
         RolloutWriteOp::Persist => RolloutWriteOp::Persist,
@@ -314,6 +324,16 @@ async fn write_and_project(
             warn!("failed to project durable rollout for {thread_id}: {err}");
         }
     }
+    if !search_texts.is_empty()
+        && let Some(state_db) = store.state_db().await
+        && let Err(err) = state_db
+            .append_thread_search_text(thread_id, &search_texts)
+            .await
+    {
+        warn!(
+            "failed to index search text for thread {thread_id}; it will be re-indexed from its rollout: {err}"
+        );
+    }
     if sync_rollout_path {
         sync_materialized_rollout_path(store, thread_id, recorder.rollout_path()).await?;
     }
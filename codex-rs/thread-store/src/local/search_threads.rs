@@ -1,10 +1,15 @@
 use std::collections::HashMap;
+use std::path::PathBuf;
 
 use codex_install_context::InstallContext;
 use codex_rollout::RolloutConfig;
+use codex_rollout::RolloutSearchMatches;
+use codex_rollout::StateDbHandle;
 use codex_rollout::first_rollout_content_match_snippet;
 use codex_rollout::parse_cursor;
+use codex_rollout::search_match_snippet;
 use codex_rollout::search_rollout_matches;
+use tracing::warn;
 
 use super::LocalThreadStore;
 use super::helpers::resolve_thread_names;
@@ -24,11 +29,64 @@ use crate::ThreadStoreResult;
 #[path = "search_threads_tests.rs"]
 mod tests;
 
+/// Prefix of cursors that page through index results by their position in relevance order.
+const RANK_CURSOR_PREFIX: &str = "rank:";
+
 struct ThreadSearchItem {
     item: codex_rollout::ThreadItem,
     snippet: String,
 }
 
+enum SearchCursor {
+    /// Position of the next result in the search index's relevance order.
+    Rank(usize),
+    /// Position in the requested thread listing order.
+    Listing(codex_rollout::Cursor),
+}
+
+enum MatchingRollouts {
+    /// Search index hits, most relevant thread first, fetched up to `limit`.
+    Ranked {
+        hits: Vec<(PathBuf, Option<String>)>,
+        offset: usize,
+        limit: usize,
+    },
+    /// Rollout file matches in no particular order.
+    Scanned(RolloutSearchMatches),
+}
+
+/// Walks the thread listing that search results are drawn from.
+struct ThreadListingScan<'a> {
+    store: &'a LocalThreadStore,
+    state_db: Option<StateDbHandle>,
+    rollout_config: RolloutConfig,
+    params: ListThreadsParams,
+    sort_key: codex_rollout::ThreadSortKey,
+    sort_direction: codex_rollout::SortDirection,
+}
+
+impl ThreadListingScan<'_> {
+    async fn page(
+        &self,
+        cursor: Option<&codex_rollout::Cursor>,
+    ) -> ThreadStoreResult<codex_rollout::ThreadsPage> {
+        list_rollout_threads(
+            self.state_db.clone(),
+            &self.rollout_config,
+            self.store.config.default_model_provider_id.as_str(),
+            &self.params,
+            cursor,
+            self.sort_key,
+            self.sort_direction,
+        )
+        .await
+    }
+}
+
+/// Searches thread contents.
+///
+/// Results served from the search index are ordered by relevance and paged with a rank cursor.
+/// Results from a rollout file scan follow the requested sort order.
 pub(super) async fn search_threads(
     store: &LocalThreadStore,
     params: SearchThreadsParams,
@@ -42,11 +100,7 @@ pub(super) async fn search_threads(
     let cursor = params
         .cursor
         .as_deref()
-        .map(|cursor| {
-            parse_cursor(cursor).ok_or_else(|| ThreadStoreError::InvalidRequest {
-                message: format!("invalid cursor: {cursor}"),
-            })
-        })
+        .map(parse_search_cursor)
         .transpose()?;
     let sort_key = match params.sort_key {
         ThreadSortKey::CreatedAt => codex_rollout::ThreadSortKey::CreatedAt,
@@ -58,70 +112,147 @@ pub(super) async fn search_threads(
         SortDirection::Desc => codex_rollout::SortDirection::Desc,
     };
     let state_db = store.state_db().await;
-    let rollout_config = RolloutConfig {
-        codex_home: store.config.codex_home.clone(),
-        sqlite_home: store.config.sqlite_home.clone(),
-        cwd: store.config.codex_home.clone(),
-        model_provider_id: store.config.default_model_provider_id.clone(),
-        generate_memories: false,
+    let scan = ThreadListingScan {
+        store,
+        state_db: state_db.clone(),
+        rollout_config: RolloutConfig {
+            codex_home: store.config.codex_home.clone(),
+            sqlite_home: store.config.sqlite_home.clone(),
+            cwd: store.config.codex_home.clone(),
+            model_provider_id: store.config.default_model_provider_id.clone(),
+            generate_memories: false,
+        },
+        params: ListThreadsParams {
+            page_size: params.page_size.saturating_mul(8).clamp(256, 2048),
+            cursor: None,
+            sort_key: params.sort_key,
+            sort_direction: params.sort_direction,
+            allowed_sources: params.allowed_sources.clone(),
+            model_providers: None,
+            cwd_filters: None,
+            archived: params.archived,
+            search_term: None,
+            relation_filter: None,
+            use_state_db_only: state_db.is_some(),
+        },
+        sort_key,
+        sort_direction,
     };
-    let rg_command = InstallContext::current().rg_command();
-    let matching_rollouts = search_rollout_matches(
-        rg_command.as_path(),
-        store.config.codex_home.as_path(),
-        params.archived,
-        search_term,
-    )
-    .await
-    .map_err(|err| ThreadStoreError::Internal {
-        message: format!("failed to search rollout contents: {err}"),
-    })?;
-    if matching_rollouts.is_empty() {
-        return Ok(ThreadSearchPage {
-            items: Vec::new(),
-            next_cursor: None,
-        });
+    // A listing cursor came from a rollout scan, so later pages keep scanning.
+    let rank_offset = match &cursor {
+        Some(SearchCursor::Rank(offset)) => Some(*offset),
+        Some(SearchCursor::Listing(_)) => None,
+        None => Some(0),
+    };
+    let matching_rollouts =
+        matching_rollouts(store, state_db.as_ref(), &params, rank_offset).await?;
+
+    let (matching_items, next_cursor) = match (matching_rollouts, cursor) {
+        (
+            MatchingRollouts::Ranked {
+                hits,
+                offset,
+                limit,
+            },
+            _,
+        ) => ranked_search_page(&scan, &params, hits, offset, limit).await?,
+        (MatchingRollouts::Scanned(_), Some(SearchCursor::Rank(_))) => {
+            return Err(ThreadStoreError::InvalidRequest {
+                message: "search cursor is no longer valid; restart the search".to_string(),
+            });
+        }
+        (MatchingRollouts::Scanned(matches), cursor) => {
+            let page_cursor = match cursor {
+                Some(SearchCursor::Listing(cursor)) => Some(cursor),
+                Some(SearchCursor::Rank(_)) | None => None,
+            };
+            scan_search_page(&scan, &params, page_cursor, matches).await?
+        }
+    };
+
+    let mut items = matching_items
+        .into_iter()
+        .filter_map(|item| {
+            stored_thread_from_rollout_item(
+                item.item,
+                params.archived,
+                store.config.default_model_provider_id.as_str(),
+            )
+            .map(|thread| StoredThreadSearchResult {
+                thread,
+                snippet: item.snippet,
+            })
+        })
+        .collect::<Vec<_>>();
+    set_thread_search_result_names(store, &mut items).await;
+
+    Ok(ThreadSearchPage { items, next_cursor })
+}
+
+/// Returns the index hits from `offset` that pass the listing filters, in relevance order.
+async fn ranked_search_page(
+    scan: &ThreadListingScan<'_>,
+    params: &SearchThreadsParams,
+    hits: Vec<(PathBuf, Option<String>)>,
+    offset: usize,
+    limit: usize,
+) -> ThreadStoreResult<(Vec<ThreadSearchItem>, Option<String>)> {
+    let more_in_index = hits.len() >= limit;
+    let mut remaining_hits = hits
+        .into_iter()
+        .enumerate()
+        .skip(offset)
+        .map(|(rank, (path, snippet))| (path, (rank, snippet)))
+        .collect::<HashMap<_, _>>();
+    let mut ranked_items = Vec::new();
+    let mut page_cursor = None;
+    while !remaining_hits.is_empty() {
+        let page = scan.page(page_cursor.as_ref()).await?;
+        for item in page.items {
+            let logical_path = codex_rollout::plain_rollout_path(item.path.as_path());
+            let Some((rank, snippet)) = remaining_hits.remove(logical_path.as_path()) else {
+                continue;
+            };
+            let Some(snippet) = resolve_snippet(&item, snippet, &params.search_term).await? else {
+                continue;
+            };
+            ranked_items.push((rank, ThreadSearchItem { item, snippet }));
+        }
+        page_cursor = page.next_cursor;
+        if page_cursor.is_none() {
+            break;
+        }
     }
+    ranked_items.sort_by_key(|(rank, _)| *rank);
+
+    // Hits dropped by the listing filters still move the cursor past their rank.
+    let next_offset = ranked_items
+        .get(params.page_size)
+        .map(|(rank, _)| *rank)
+        .or(more_in_index.then_some(limit));
+    ranked_items.truncate(params.page_size);
+    Ok((
+        ranked_items.into_iter().map(|(_, item)| item).collect(),
+        next_offset.map(|offset| format!("{RANK_CURSOR_PREFIX}{offset}")),
+    ))
+}
+
+/// Walks the thread listing from `page_cursor` and keeps the threads found by a rollout scan.
+async fn scan_search_page(
+    scan: &ThreadListingScan<'_>,
+    params: &SearchThreadsParams,
+    mut page_cursor: Option<codex_rollout::Cursor>,
+    mut remaining_rollouts: RolloutSearchMatches,
+) -> ThreadStoreResult<(Vec<ThreadSearchItem>, Option<String>)> {
     let mut matching_items = Vec::new();
-    let mut page_cursor = cursor;
-    let scan_page_size = params.page_size.saturating_mul(8).clamp(256, 2048);
-    let scan_params = ListThreadsParams {
-        page_size: scan_page_size,
-        cursor: None,
-        sort_key: params.sort_key,
-        sort_direction: params.sort_direction,
-        allowed_sources: params.allowed_sources.clone(),
-        model_providers: None,
-        cwd_filters: None,
-        archived: params.archived,
-        search_term: None,
-        relation_filter: None,
-        use_state_db_only: state_db.is_some(),
-    };
-    let mut remaining_rollouts = matching_rollouts;
-
-    loop {
-        let page = list_rollout_threads(
-            state_db.clone(),
-            &rollout_config,
-            store.config.default_model_provider_id.as_str(),
-            &scan_params,
-            page_cursor.as_ref(),
-            sort_key,
-            sort_direction,
-        )
-        .await?;
+    while !remaining_rollouts.is_empty() {
+        let page = scan.page(page_cursor.as_ref()).await?;
         for item in page.items {
             let logical_path = codex_rollout::plain_rollout_path(item.path.as_path());
-            let Some(snippet) = (match remaining_rollouts.remove(logical_path.as_path()) {
-                Some(Some(snippet)) => Some(snippet),
-                Some(None) => first_rollout_content_match_snippet(item.path.as_path(), search_term)
-                    .await
-                    .map_err(|err| ThreadStoreError::Internal {
-                        message: format!("failed to read rollout search match: {err}"),
-                    })?,
-                None => None,
-            }) else {
+            let Some(snippet) = remaining_rollouts.remove(logical_path.as_path()) else {
+                continue;
+            };
+            let Some(snippet) = resolve_snippet(&item, snippet, &params.search_term).await? else {
                 continue;
             };
             matching_items.push(ThreadSearchItem { item, snippet });
@@ -130,10 +261,7 @@ pub(super) async fn search_threads(
             }
         }
         page_cursor = page.next_cursor;
-        if matching_items.len() > params.page_size
-            || remaining_rollouts.is_empty()
-            || page_cursor.is_none()
-        {
+        if matching_items.len() > params.page_size || page_cursor.is_none() {
             break;
         }
     }
@@ -150,24 +278,90 @@ pub(super) async fn search_threads(
     .as_ref()
     .and_then(|cursor| serde_json::to_value(cursor).ok())
     .and_then(|value| value.as_str().map(str::to_owned));
+    Ok((matching_items, next_cursor))
+}
 
-    let mut items = matching_items
-        .into_iter()
-        .filter_map(|item| {
-            stored_thread_from_rollout_item(
-                item.item,
-                params.archived,
-                store.config.default_model_provider_id.as_str(),
-            )
-            .map(|thread| StoredThreadSearchResult {
-                thread,
-                snippet: item.snippet,
-            })
-        })
-        .collect::<Vec<_>>();
-    set_thread_search_result_names(store, &mut items).await;
+/// Returns the snippet for a matched thread, reading it from the rollout when the match did not
+/// carry one. `None` means the rollout no longer contains the search term.
+async fn resolve_snippet(
+    item: &codex_rollout::ThreadItem,
+    snippet: Option<String>,
+    search_term: &str,
+) -> ThreadStoreResult<Option<String>> {
+    match snippet {
+        Some(snippet) => Ok(Some(snippet)),
+        None => first_rollout_content_match_snippet(item.path.as_path(), search_term)
+            .await
+            .map_err(|err| ThreadStoreError::Internal {
+                message: format!("failed to read rollout search match: {err}"),
+            }),
+    }
+}
 
-    Ok(ThreadSearchPage { items, next_cursor })
+fn parse_search_cursor(cursor: &str) -> ThreadStoreResult<SearchCursor> {
+    let parsed = match cursor.strip_prefix(RANK_CURSOR_PREFIX) {
+        Some(offset) => offset.parse().ok().map(SearchCursor::Rank),
+        None => parse_cursor(cursor).map(SearchCursor::Listing),
+    };
+    parsed.ok_or_else(|| ThreadStoreError::InvalidRequest {
+        message: format!("invalid cursor: {cursor}"),
+    })
+}
+
+/// Finds rollouts whose conversation text contains the search term.
+///
+/// With a `rank_offset`, once every thread in scope is indexed, returns the most relevant threads
+/// from the SQLite search index, enough to fill the page that starts at that offset. Otherwise
+/// scans rollout files.
+async fn matching_rollouts(
+    store: &LocalThreadStore,
+    state_db: Option<&StateDbHandle>,
+    params: &SearchThreadsParams,
+    rank_offset: Option<usize>,
+) -> ThreadStoreResult<MatchingRollouts> {
+    let search_term = params.search_term.as_str();
+    if let (Some(state_db), Some(offset)) = (state_db, rank_offset) {
+        // One extra hit tells whether the index holds another page.
+        let limit = offset.saturating_add(params.page_size).saturating_add(1);
+        match state_db.thread_search_index_complete(params.archived).await {
+            Ok(true) => match state_db
+                .search_thread_text(search_term, params.archived, limit)
+                .await
+            {
+                Ok(hits) => {
+                    let hits = hits
+                        .into_iter()
+                        .map(|hit| {
+                            (
+                                codex_rollout::plain_rollout_path(hit.rollout_path.as_path()),
+                                search_match_snippet(&hit.text, search_term),
+                            )
+                        })
+                        .collect();
+                    return Ok(MatchingRollouts::Ranked {
+                        hits,
+                        offset,
+                        limit,
+                    });
+                }
+                Err(err) => warn!("failed to search thread index: {err}"),
+            },
+            Ok(false) => {}
+            Err(err) => warn!("failed to read thread search index state: {err}"),
+        }
+    }
+    let rg_command = InstallContext::current().rg_command();
+    search_rollout_matches(
+        rg_command.as_path(),
+        store.config.codex_home.as_path(),
+        params.archived,
+        search_term,
+    )
+    .await
+    .map(MatchingRollouts::Scanned)
+    .map_err(|err| ThreadStoreError::Internal {
+        message: format!("failed to search rollout contents: {err}"),
+    })
 }
 
 fn cursor_from_thread_search_item(
@@ -2,8 +2,10 @@ use codex_protocol::ThreadId;
 use codex_rollout::ThreadItem;
 use pretty_assertions::assert_eq;
 
+use super::SearchCursor;
 use super::ThreadSearchItem;
 use super::cursor_from_thread_search_item;
+use super::parse_search_cursor;
 use crate::ThreadSortKey;
 
 #[test]
@@ -27,3 +29,17 @@ fn recency_cursor_includes_thread_id_tie_breaker() {
         format!("\"2026-01-27T12:34:56Z|{thread_id}\"")
     );
 }
+
+#[test]
+fn rank_cursor_parses_offset_and_rejects_garbage() {
+    assert!(matches!(
+        parse_search_cursor("rank:40"),
+        Ok(SearchCursor::Rank(40))
+    ));
+    assert!(matches!(
+        parse_search_cursor("2026-01-27T12:34:56Z"),
+        Ok(SearchCursor::Listing(_))
+    ));
+    assert!(parse_search_cursor("rank:-1").is_err());
+    assert!(parse_search_cursor("rank:").is_err());
+}
@@ -11,6 +11,7 @@ use pulldown_cmark::TagEnd;
 use serde::Deserialize;
 use serde::Serialize;
 use sqlx::Row;
+use tracing::warn;
 
 use super::super::LocalThreadStore;
 use super::read::CursorScope;
@@ -73,6 +74,12 @@ pub(in crate::local) async fn search_thread_occurrences(
     let next_rollout_ordinal = cursor
         .as_ref()
         .map_or(0, |cursor| cursor.next_rollout_ordinal);
+    if !thread_text_may_match(store, params.thread_id, params.search_term.as_str()).await {
+        return Ok(ThreadOccurrenceSearchPage {
+            items: Vec::new(),
+            next_cursor: None,
+        });
+    }
     let matcher = LiteralMatcher::new(params.search_term.as_str());
     let pool = store.thread_history_db().await?;
     let mut rows = sqlx::query(
@@ -180,6 +187,30 @@ ORDER BY rollout_ordinal ASC
     })
 }
 
+/// Uses the state search index to skip threads that cannot contain `search_term`.
+///
+/// Anything other than a definite miss falls through to the full scan.
+async fn thread_text_may_match(
+    store: &LocalThreadStore,
+    thread_id: ThreadId,
+    search_term: &str,
+) -> bool {
+    let Some(state_db) = store.state_db().await else {
+        return true;
+    };
+    match state_db
+        .thread_search_text_may_match(thread_id, search_term)
+        .await
+    {
+        Ok(Some(may_match)) => may_match,
+        Ok(None) => true,
+        Err(err) => {
+            warn!("failed to check thread search index for {thread_id}: {err}");
+            true
+        }
+    }
+}
+
 fn candidate_row(row: sqlx::sqlite::SqliteRow) -> ThreadStoreResult<CandidateRow> {
     let rollout_ordinal = row.try_get::<i64, _>("rollout_ordinal")?;
     let turn_rollout_ordinal = row.try_get::<i64, _>("turn_rollout_ordinal")?;
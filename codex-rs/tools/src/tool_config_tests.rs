@@ -50,6 +50,7 @@ fn model_with_shell_type(shell_type: ConfigShellToolType) -> ModelInfo {
         auto_review_model_override: None,
         tool_mode: None,
         multi_agent_version: None,
+        tokenizer: None,
     }
 }
 
@@ -110,6 +110,7 @@ use codex_app_server_protocol::McpAuthStatus;
 use codex_app_server_protocol::McpServerStatus;
 use codex_app_server_protocol::McpServerStatusDetail;
 use codex_app_server_protocol::MergeStrategy;
+use codex_app_server_protocol::PinnedItemSource;
 use codex_app_server_protocol::PluginInstallParams;
 use codex_app_server_protocol::PluginInstallResponse;
 use codex_app_server_protocol::PluginListMarketplaceKind;
@@ -127,6 +128,7 @@ use codex_app_server_protocol::ServerRequest;
 use codex_app_server_protocol::SkillErrorInfo;
 use codex_app_server_protocol::SkillsListParams;
 use codex_app_server_protocol::SkillsListResponse;
+use codex_app_server_protocol::ThreadExportFormat;
 use codex_app_server_protocol::ThreadItem;
 use codex_app_server_protocol::ThreadLoadedListParams;
 use codex_app_server_protocol::ThreadMemoryMode;
@@ -629,6 +631,10 @@ pub(crate) struct App {
     /// so shutdown events from other threads still take the normal failover path.
     pending_shutdown_exit_thread_id: Option<ThreadId>,
 
+    /// Turn whose checkpoint `/undo` was about to restore when it warned that the restore
+    /// deletes files. Running `/undo` again for the same checkpoint goes ahead.
+    pending_undo_confirmation: Option<String>,
+
     windows_sandbox: WindowsSandboxState,
 
     thread_event_channels: HashMap<ThreadId, ThreadEventChannel>,
@@ -1196,6 +1202,7 @@ See the Codex keymap documentation for supported actions and examples."
             app_server_target,
             pending_update_action: None,
             pending_shutdown_exit_thread_id: None,
+            pending_undo_confirmation: None,
             windows_sandbox: WindowsSandboxState::default(),
             thread_event_channels: HashMap::new(),
             thread_event_listener_tasks: HashMap::new(),
@@ -19,6 +19,11 @@ use codex_app_server_protocol::MarketplaceRemoveParams;
 use codex_app_server_protocol::MarketplaceRemoveResponse;
 use codex_app_server_protocol::MarketplaceUpgradeParams;
 use codex_app_server_protocol::MarketplaceUpgradeResponse;
+use codex_app_server_protocol::McpServerPrompt;
+use codex_app_server_protocol::McpServerPromptGetParams;
+use codex_app_server_protocol::McpServerPromptGetResponse;
+use codex_app_server_protocol::McpServerPromptListParams;
+use codex_app_server_protocol::McpServerPromptListResponse;
 
 use codex_app_server_protocol::RequestId;
 
@@ -56,6 +61,39 @@ impl App {
         });
     }
 
+    pub(super) fn fetch_mcp_prompts(&mut self, app_server: &AppServerSession, thread_id: ThreadId) {
+        let request_handle = app_server.request_handle();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_mcp_prompts(request_handle, thread_id)
+                .await
+                .map_err(|err| err.to_string());
+            app_event_tx.send(AppEvent::McpPromptsLoaded { thread_id, result });
+        });
+    }
+
+    pub(super) fn run_mcp_prompt(
+        &mut self,
+        app_server: &AppServerSession,
+        thread_id: ThreadId,
+        server: String,
+        name: String,
+        arguments: BTreeMap<String, String>,
+    ) {
+        let request_handle = app_server.request_handle();
+        let app_event_tx = self.app_event_tx.clone();
+        tokio::spawn(async move {
+            let result = fetch_mcp_prompt_text(request_handle, thread_id, server, name.clone(), arguments)
+                .await
+                .map_err(|err| err.to_string());
+            app_event_tx.send(AppEvent::McpPromptLoaded {
+                thread_id,
+                name,
+                result,
+            });
+        });
+    }
+
     fn mcp_inventory_request_thread_id(&self, thread_id: Option<ThreadId>) -> Option<ThreadId> {
         thread_id.filter(|thread_id| {
             self.active_thread_id == Some(*thread_id)
@@ -774,6 +812,63 @@ pub(super) async fn fetch_all_mcp_server_statuses(
     Ok(statuses)
 }
 
+async fn fetch_mcp_prompts(
+    request_handle: AppServerRequestHandle,
+    thread_id: ThreadId,
+) -> Result<Vec<McpServerPrompt>> {
+    let request_id = RequestId::String(format!("mcp-prompt-list-{}", Uuid::new_v4()));
+    let response: McpServerPromptListResponse = request_handle
+        .request_typed(ClientRequest::McpServerPromptList {
+            request_id,
+            params: McpServerPromptListParams {
+                thread_id: thread_id.to_string(),
+            },
+        })
+        .await
+        .wrap_err("mcpServer/prompt/list failed in TUI")?;
+    Ok(response.data)
+}
+
+async fn fetch_mcp_prompt_text(
+    request_handle: AppServerRequestHandle,
+    thread_id: ThreadId,
+    server: String,
+    name: String,
+    arguments: BTreeMap<String, String>,
+) -> Result<String> {
+    let request_id = RequestId::String(format!("mcp-prompt-get-{}", Uuid::new_v4()));
+    let response: McpServerPromptGetResponse = request_handle
+        .request_typed(ClientRequest::McpServerPromptGet {
+            request_id,
+            params: McpServerPromptGetParams {
+                thread_id: thread_id.to_string(),
+                server,
+                name,
+                arguments,
+            },
+        })
+        .await
+        .wrap_err("mcpServer/prompt/get failed in TUI")?;
+    Ok(mcp_prompt_messages_text(&response.messages))
+}
+
+/// Flatten MCP `PromptMessage` values into the text submitted to the model.
+///
+/// Text blocks and embedded text resources are kept; images and resource links are skipped.
+fn mcp_prompt_messages_text(messages: &[serde_json::Value]) -> String {
+    messages
+        .iter()
+        .filter_map(|message| {
+            let content = message.get("content")?;
+            content
+                .get("text")
+                .or_else(|| content.get("resource")?.get("text"))
+                .and_then(serde_json::Value::as_str)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
 pub(super) async fn fetch_account_rate_limits(
     request_handle: AppServerRequestHandle,
 ) -> Result<GetAccountRateLimitsResponse> {
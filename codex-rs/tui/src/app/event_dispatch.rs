@@ -812,6 +812,34 @@ impl App {
             } => {
                 self.handle_mcp_inventory_result(result, detail, thread_id);
             }
+            AppEvent::FetchMcpPrompts { thread_id } => {
+                self.fetch_mcp_prompts(app_server, thread_id);
+            }
+            AppEvent::McpPromptsLoaded { thread_id, result } => {
+                if Some(thread_id) == self.current_displayed_thread_id() {
+                    match result {
+                        Ok(prompts) => self.chat_widget.set_mcp_prompts(prompts),
+                        Err(err) => tracing::warn!("failed to list MCP prompts: {err}"),
+                    }
+                }
+            }
+            AppEvent::RunMcpPrompt {
+                thread_id,
+                server,
+                name,
+                arguments,
+            } => {
+                self.run_mcp_prompt(app_server, thread_id, server, name, arguments);
+            }
+            AppEvent::McpPromptLoaded {
+                thread_id,
+                name,
+                result,
+            } => {
+                if Some(thread_id) == self.current_displayed_thread_id() {
+                    self.chat_widget.on_mcp_prompt_loaded(name, result);
+                }
+            }
             AppEvent::SkillsListLoaded { result } => {
                 self.handle_skills_list_result(
                     result.map_err(|err| color_eyre::eyre::eyre!(err)),
@@ -465,6 +465,7 @@ mod tests {
                 completed_at: None,
                 duration_ms: None,
                 entries: Vec::new(),
+                agent_thread_id: None,
             },
         })
     }
@@ -497,6 +498,7 @@ mod tests {
                         text: "prompt blocked".to_string(),
                     },
                 ],
+                agent_thread_id: None,
             },
         })
     }
@@ -758,6 +758,56 @@ impl App {
                     .await?;
                 Ok(true)
             }
+            AppCommand::UndoLastTurn => {
+                self.undo_last_turn(app_server, thread_id).await;
+                Ok(true)
+            }
+            AppCommand::PinContext { text } => {
+                self.pin_context(
+                    app_server,
+                    thread_id,
+                    Some(text.to_string()),
+                    /*item_id*/ None,
+                )
+                .await;
+                Ok(true)
+            }
+            AppCommand::PinThreadItem { item_id } => {
+                self.pin_context(
+                    app_server,
+                    thread_id,
+                    /*text*/ None,
+                    Some(item_id.to_string()),
+                )
+                .await;
+                Ok(true)
+            }
+            AppCommand::UnpinContext { pin_id } => {
+                match app_server
+                    .thread_items_unpin(thread_id, pin_id.to_string())
+                    .await
+                {
+                    Ok(response) if response.item.is_some() => self
+                        .chat_widget
+                        .add_info_message(format!("Unpinned {pin_id}."), /*hint*/ None),
+                    Ok(_) => self
+                        .chat_widget
+                        .add_error_message(format!("No pinned item with id {pin_id}.")),
+                    Err(err) => self
+                        .chat_widget
+                        .add_error_message(format!("Failed to unpin: {err:#}")),
+                }
+                Ok(true)
+            }
+            AppCommand::ListPinnedContext => {
+                self.list_pinned_context(app_server, thread_id).await;
+                Ok(true)
+            }
+            AppCommand::ExportThread { format, path } => {
+                self.export_thread(app_server, thread_id, *format, path.clone())
+                    .await;
+                Ok(true)
+            }
             AppCommand::RunUserShellCommand { command } => {
                 app_server
                     .thread_shell_command(thread_id, command.to_string())
@@ -783,6 +833,183 @@ impl App {
         }
     }
 
+    /// Pins `text`, or the text of the thread item `item_id`, to the thread's context.
+    async fn pin_context(
+        &mut self,
+        app_server: &mut AppServerSession,
+        thread_id: ThreadId,
+        text: Option<String>,
+        item_id: Option<String>,
+    ) {
+        match app_server.thread_items_pin(thread_id, text, item_id).await {
+            Ok(response) => self.chat_widget.add_info_message(
+                format!("Pinned as {}.", response.item.id),
+                Some("Pinned items are re-injected after every compaction.".to_string()),
+            ),
+            Err(err) => self
+                .chat_widget
+                .add_error_message(format!("Failed to pin: {err:#}")),
+        }
+    }
+
+    /// Shows the thread's pinned items and how much of the pin budget they use.
+    async fn list_pinned_context(
+        &mut self,
+        app_server: &mut AppServerSession,
+        thread_id: ThreadId,
+    ) {
+        let response = match app_server.thread_items_pinned_list(thread_id).await {
+            Ok(response) => response,
+            Err(err) => {
+                self.chat_widget
+                    .add_error_message(format!("Failed to list pinned items: {err:#}"));
+                return;
+            }
+        };
+        if response.data.is_empty() {
+            self.chat_widget.add_info_message(
+                "Nothing is pinned.".to_string(),
+                Some("Use /pin <text> to keep text in context across compactions.".to_string()),
+            );
+            return;
+        }
+        let used = response.data.iter().map(|item| item.tokens).sum::<i64>();
+        let lines = response
+            .data
+            .iter()
+            .map(|item| {
+                let source = match item.source {
+                    PinnedItemSource::User => "user",
+                    PinnedItemSource::Model => "model",
+                };
+                format!(
+                    "{} ({source}, {} tokens): {}",
+                    item.id, item.tokens, item.text
+                )
+            })
+            .collect::<Vec<_>>();
+        self.chat_widget.add_info_message(
+            lines.join("\n"),
+            Some(format!(
+                "{used} of {} pinned tokens used. Remove a pin with /unpin <id>.",
+                response.token_budget
+            )),
+        );
+    }
+
+    /// Writes a transcript of the thread to `path`, resolved against the session cwd, or to
+    /// `codex-<thread id>.<ext>` in the cwd when no path was given.
+    async fn export_thread(
+        &mut self,
+        app_server: &mut AppServerSession,
+        thread_id: ThreadId,
+        format: ThreadExportFormat,
+        path: Option<PathBuf>,
+    ) {
+        let response = match app_server
+            .thread_export(
+                thread_id, format, /*omit_tool_output*/ false, /*redact_secrets*/ false,
+            )
+            .await
+        {
+            Ok(response) => response,
+            Err(err) => {
+                self.chat_widget
+                    .add_error_message(format!("Failed to export session: {err:#}"));
+                return;
+            }
+        };
+        let path = path.unwrap_or_else(|| {
+            PathBuf::from(format!("codex-{thread_id}.{}", format.extension()))
+        });
+        let path = self.config.cwd.to_path_buf().join(path);
+        match tokio::fs::write(&path, response.content).await {
+            Ok(()) => self.chat_widget.add_info_message(
+                format!("Exported session to {}.", path.display()),
+                Some("Use `codex export` to redact secrets or omit tool output.".to_string()),
+            ),
+            Err(err) => self
+                .chat_widget
+                .add_error_message(format!("Failed to write {}: {err}", path.display())),
+        }
+    }
+
+    /// Restores workspace files to the checkpoint taken before the most recent turn.
+    async fn undo_last_turn(&mut self, app_server: &mut AppServerSession, thread_id: ThreadId) {
+        let checkpoint = match app_server.thread_checkpoint_list(thread_id).await {
+            Ok(checkpoints) => checkpoints.into_iter().next(),
+            Err(err) => {
+                self.chat_widget
+                    .add_error_message(format!("Failed to list checkpoints: {err:#}"));
+                return;
+            }
+        };
+        let Some(checkpoint) = checkpoint else {
+            self.chat_widget.add_info_message(
+                "No checkpoints to undo.".to_string(),
+                Some(
+                    "Enable Checkpoints in /experimental to snapshot files before each turn."
+                        .to_string(),
+                ),
+            );
+            return;
+        };
+        let confirmed = self
+            .pending_undo_confirmation
+            .take()
+            .is_some_and(|turn_id| turn_id == checkpoint.turn_id);
+        if !confirmed {
+            match app_server
+                .thread_checkpoint_restore(
+                    thread_id,
+                    checkpoint.turn_id.clone(),
+                    /*dry_run*/ true,
+                )
+                .await
+            {
+                Ok(preview) if !preview.removed_paths.is_empty() => {
+                    let files = preview
+                        .removed_paths
+                        .iter()
+                        .map(|path| format!("  {path}"))
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    self.chat_widget.add_info_message(
+                        format!(
+                            "Undoing the last turn deletes {} file(s) created since it started:\n{files}",
+                            preview.removed_paths.len()
+                        ),
+                        Some("Run /undo again to restore and delete them.".to_string()),
+                    );
+                    self.pending_undo_confirmation = Some(checkpoint.turn_id);
+                    return;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    self.chat_widget
+                        .add_error_message(format!("Failed to undo the last turn: {err:#}"));
+                    return;
+                }
+            }
+        }
+        match app_server
+            .thread_checkpoint_restore(thread_id, checkpoint.turn_id, /*dry_run*/ false)
+            .await
+        {
+            Ok(response) => self.chat_widget.add_info_message(
+                format!(
+                    "Restored files to before the last turn: {} rewritten, {} removed.",
+                    response.restored_paths.len(),
+                    response.removed_paths.len()
+                ),
+                Some("The conversation history is unchanged.".to_string()),
+            ),
+            Err(err) => self
+                .chat_widget
+                .add_error_message(format!("Failed to undo the last turn: {err:#}")),
+        }
+    }
+
     fn turn_permissions_override_from_config(
         config: &Config,
         active_permission_profile: Option<&ActivePermissionProfile>,
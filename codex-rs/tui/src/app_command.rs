@@ -6,6 +6,7 @@ use codex_app_server_protocol::FileChangeApprovalDecision;
 use codex_app_server_protocol::McpServerElicitationAction;
 use codex_app_server_protocol::RequestId as AppServerRequestId;
 use codex_app_server_protocol::ReviewTarget;
+use codex_app_server_protocol::ThreadExportFormat;
 use codex_app_server_protocol::ToolRequestUserInputResponse;
 use codex_app_server_protocol::UserInput;
 use codex_config::types::ApprovalsReviewer;
@@ -26,6 +27,21 @@ use serde_json::Value;
 pub(crate) enum AppCommand {
     Interrupt,
     CleanBackgroundTerminals,
+    UndoLastTurn,
+    PinContext {
+        text: String,
+    },
+    PinThreadItem {
+        item_id: String,
+    },
+    UnpinContext {
+        pin_id: String,
+    },
+    ListPinnedContext,
+    ExportThread {
+        format: ThreadExportFormat,
+        path: Option<PathBuf>,
+    },
     RunUserShellCommand {
         command: String,
     },
@@ -108,6 +124,30 @@ impl AppCommand {
         Self::CleanBackgroundTerminals
     }
 
+    pub(crate) fn undo_last_turn() -> Self {
+        Self::UndoLastTurn
+    }
+
+    pub(crate) fn pin_context(text: String) -> Self {
+        Self::PinContext { text }
+    }
+
+    pub(crate) fn pin_thread_item(item_id: String) -> Self {
+        Self::PinThreadItem { item_id }
+    }
+
+    pub(crate) fn unpin_context(pin_id: String) -> Self {
+        Self::UnpinContext { pin_id }
+    }
+
+    pub(crate) fn list_pinned_context() -> Self {
+        Self::ListPinnedContext
+    }
+
+    pub(crate) fn export_thread(format: ThreadExportFormat, path: Option<PathBuf>) -> Self {
+        Self::ExportThread { format, path }
+    }
+
     pub(crate) fn run_user_shell_command(command: String) -> Self {
         Self::RunUserShellCommand { command }
     }
@@ -8,6 +8,7 @@
 //! Exit is modelled explicitly via `AppEvent::Exit(ExitMode)` so callers can request shutdown-first
 //! quits without reaching into the app loop or coupling to shutdown/exit sequencing.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -21,6 +22,7 @@ use codex_app_server_protocol::GetAccountTokenUsageResponse;
 use codex_app_server_protocol::MarketplaceAddResponse;
 use codex_app_server_protocol::MarketplaceRemoveResponse;
 use codex_app_server_protocol::MarketplaceUpgradeResponse;
+use codex_app_server_protocol::McpServerPrompt;
 use codex_app_server_protocol::McpServerStatus;
 use codex_app_server_protocol::McpServerStatusDetail;
 use codex_app_server_protocol::PluginInstallResponse;
@@ -710,6 +712,32 @@ pub(crate) enum AppEvent {
         thread_id: Option<ThreadId>,
     },
 
+    /// Fetch the prompts published by a thread's MCP servers.
+    FetchMcpPrompts {
+        thread_id: ThreadId,
+    },
+
+    /// Result of listing MCP prompts for a thread.
+    McpPromptsLoaded {
+        thread_id: ThreadId,
+        result: Result<Vec<McpServerPrompt>, String>,
+    },
+
+    /// Render an MCP prompt so its text can be submitted as a user message.
+    RunMcpPrompt {
+        thread_id: ThreadId,
+        server: String,
+        name: String,
+        arguments: BTreeMap<String, String>,
+    },
+
+    /// Result of rendering an MCP prompt, flattened to its text content.
+    McpPromptLoaded {
+        thread_id: ThreadId,
+        name: String,
+        result: Result<String, String>,
+    },
+
     /// Result of the startup skills refresh that runs after the first frame is scheduled.
     ///
     /// This event is startup-only. Interactive skills refreshes are handled synchronously through the app
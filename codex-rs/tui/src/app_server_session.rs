@@ -57,10 +57,18 @@ use codex_app_server_protocol::ThreadArchiveParams;
 use codex_app_server_protocol::ThreadArchiveResponse;
 use codex_app_server_protocol::ThreadBackgroundTerminalsCleanParams;
 use codex_app_server_protocol::ThreadBackgroundTerminalsCleanResponse;
+use codex_app_server_protocol::ThreadCheckpoint;
+use codex_app_server_protocol::ThreadCheckpointListParams;
+use codex_app_server_protocol::ThreadCheckpointListResponse;
+use codex_app_server_protocol::ThreadCheckpointRestoreParams;
+use codex_app_server_protocol::ThreadCheckpointRestoreResponse;
 use codex_app_server_protocol::ThreadCompactStartParams;
 use codex_app_server_protocol::ThreadCompactStartResponse;
 use codex_app_server_protocol::ThreadDeleteParams;
 use codex_app_server_protocol::ThreadDeleteResponse;
+use codex_app_server_protocol::ThreadExportFormat;
+use codex_app_server_protocol::ThreadExportParams;
+use codex_app_server_protocol::ThreadExportResponse;
 use codex_app_server_protocol::ThreadForkParams;
 use codex_app_server_protocol::ThreadForkResponse;
 use codex_app_server_protocol::ThreadGoalClearParams;
@@ -72,6 +80,12 @@ use codex_app_server_protocol::ThreadGoalSetResponse;
 use codex_app_server_protocol::ThreadGoalStatus;
 use codex_app_server_protocol::ThreadInjectItemsParams;
 use codex_app_server_protocol::ThreadInjectItemsResponse;
+use codex_app_server_protocol::ThreadItemsPinParams;
+use codex_app_server_protocol::ThreadItemsPinResponse;
+use codex_app_server_protocol::ThreadItemsPinnedListParams;
+use codex_app_server_protocol::ThreadItemsPinnedListResponse;
+use codex_app_server_protocol::ThreadItemsUnpinParams;
+use codex_app_server_protocol::ThreadItemsUnpinResponse;
 use codex_app_server_protocol::ThreadListParams;
 use codex_app_server_protocol::ThreadListResponse;
 use codex_app_server_protocol::ThreadLoadedListParams;
@@ -826,6 +840,28 @@ impl AppServerSession {
         Ok(response.thread)
     }
 
+    pub(crate) async fn thread_export(
+        &mut self,
+        thread_id: ThreadId,
+        format: ThreadExportFormat,
+        omit_tool_output: bool,
+        redact_secrets: bool,
+    ) -> Result<ThreadExportResponse> {
+        let request_id = self.next_request_id();
+        self.client
+            .request_typed(ClientRequest::ThreadExport {
+                request_id,
+                params: ThreadExportParams {
+                    thread_id: thread_id.to_string(),
+                    format,
+                    omit_tool_output,
+                    redact_secrets,
+                },
+            })
+            .await
+            .wrap_err("failed to export session")
+    }
+
     pub(crate) async fn thread_metadata_update_branch(
         &mut self,
         thread_id: ThreadId,
@@ -1212,6 +1248,98 @@ impl AppServerSession {
         Ok(())
     }
 
+    pub(crate) async fn thread_checkpoint_list(
+        &mut self,
+        thread_id: ThreadId,
+    ) -> Result<Vec<ThreadCheckpoint>> {
+        let request_id = self.next_request_id();
+        let response: ThreadCheckpointListResponse = self
+            .client
+            .request_typed(ClientRequest::ThreadCheckpointList {
+                request_id,
+                params: ThreadCheckpointListParams {
+                    thread_id: thread_id.to_string(),
+                },
+            })
+            .await
+            .wrap_err("thread/checkpoint/list failed in TUI")?;
+        Ok(response.data)
+    }
+
+    pub(crate) async fn thread_checkpoint_restore(
+        &mut self,
+        thread_id: ThreadId,
+        turn_id: String,
+        dry_run: bool,
+    ) -> Result<ThreadCheckpointRestoreResponse> {
+        let request_id = self.next_request_id();
+        self.client
+            .request_typed(ClientRequest::ThreadCheckpointRestore {
+                request_id,
+                params: ThreadCheckpointRestoreParams {
+                    thread_id: thread_id.to_string(),
+                    turn_id,
+                    dry_run: Some(dry_run),
+                },
+            })
+            .await
+            .wrap_err("thread/checkpoint/restore failed in TUI")
+    }
+
+    pub(crate) async fn thread_items_pin(
+        &mut self,
+        thread_id: ThreadId,
+        text: Option<String>,
+        item_id: Option<String>,
+    ) -> Result<ThreadItemsPinResponse> {
+        let request_id = self.next_request_id();
+        self.client
+            .request_typed(ClientRequest::ThreadItemsPin {
+                request_id,
+                params: ThreadItemsPinParams {
+                    thread_id: thread_id.to_string(),
+                    text,
+                    item_id,
+                },
+            })
+            .await
+            .wrap_err("thread/items/pin failed in TUI")
+    }
+
+    pub(crate) async fn thread_items_unpin(
+        &mut self,
+        thread_id: ThreadId,
+        pin_id: String,
+    ) -> Result<ThreadItemsUnpinResponse> {
+        let request_id = self.next_request_id();
+        self.client
+            .request_typed(ClientRequest::ThreadItemsUnpin {
+                request_id,
+                params: ThreadItemsUnpinParams {
+                    thread_id: thread_id.to_string(),
+                    pin_id,
+                },
+            })
+            .await
+            .wrap_err("thread/items/unpin failed in TUI")
+    }
+
+    pub(crate) async fn thread_items_pinned_list(
+        &mut self,
+        thread_id: ThreadId,
+    ) -> Result<ThreadItemsPinnedListResponse> {
+        let request_id = self.next_request_id();
+        self.client
+            .request_typed(ClientRequest::ThreadItemsPinnedList {
+                request_id,
+                params: ThreadItemsPinnedListParams {
+                    thread_id: thread_id.to_string(),
+                },
+            })
+            .await
+            .wrap_err("thread/items/pinned/list failed in TUI")
+    }
+
     pub(crate) async fn review_start(
         &mut self,
         thread_id: ThreadId,
@@ -13,6 +13,9 @@
 
 use std::collections::HashMap;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 
 use crate::app::app_server_requests::ResolvedAppServerRequest;
 #[cfg(test)]
@@ -24,8 +27,11 @@ use crate::bottom_pane::CancellationEvent;
 use crate::bottom_pane::list_selection_view::ListSelectionView;
 use crate::bottom_pane::list_selection_view::SelectionItem;
 use crate::bottom_pane::list_selection_view::SelectionViewParams;
+use crate::bottom_pane::multi_select_picker::MultiSelectItem;
+use crate::bottom_pane::multi_select_picker::MultiSelectPicker;
 use crate::bottom_pane::popup_consts::accept_cancel_hint_line;
 use crate::diff_model::FileChange;
+use crate::diff_render::display_path_for;
 use crate::exec_command::strip_bash_lc_and_escape;
 use crate::history_cell;
 use crate::history_cell::ReviewDecision;
@@ -41,6 +47,7 @@ use crate::render::renderable::Renderable;
 use codex_app_server_protocol::AdditionalPermissionProfile;
 use codex_app_server_protocol::CommandExecutionApprovalDecision;
 use codex_app_server_protocol::FileChangeApprovalDecision;
+use codex_app_server_protocol::FileChangeSelection;
 use codex_app_server_protocol::FileSystemAccessMode;
 use codex_app_server_protocol::FileSystemPath;
 use codex_app_server_protocol::FileSystemSandboxEntry;
@@ -179,6 +186,14 @@ pub(crate) struct ApprovalOverlay {
     features: Features,
     approval_keymap: ApprovalKeymap,
     list_keymap: ListKeymap,
+    /// Per-file/per-hunk picker shown while the user chooses which edits to keep.
+    change_picker: Option<FileChangePicker>,
+}
+
+/// Picker for accepting part of a patch, plus whether the user confirmed it.
+struct FileChangePicker {
+    picker: MultiSelectPicker,
+    confirmed: Arc<AtomicBool>,
 }
 
 impl ApprovalOverlay {
@@ -200,6 +215,7 @@ impl ApprovalOverlay {
             features,
             approval_keymap,
             list_keymap,
+            change_picker: None,
         };
         view.set_current(request);
         view
@@ -314,6 +330,16 @@ impl ApprovalOverlay {
         let Some(option) = self.options.get(actual_idx) else {
             return;
         };
+        if matches!(option.decision, ApprovalDecision::ChooseFileChanges) {
+            if let Some(ApprovalRequest::ApplyPatch(request)) = self.current_request.as_ref() {
+                self.change_picker = Some(file_change_picker(
+                    request,
+                    self.app_event_tx.clone(),
+                    self.list_keymap.clone(),
+                ));
+            }
+            return;
+        }
         if let Some(request) = self.current_request.as_ref() {
             match (request, &option.decision) {
                 (ApprovalRequest::Exec(request), ApprovalDecision::Command(decision)) => {
@@ -475,6 +501,7 @@ impl ApprovalOverlay {
     }
 
     fn advance_queue(&mut self) {
+        self.change_picker = None;
         if let Some(next) = self.queue.pop() {
             self.set_current(next);
         } else {
@@ -516,6 +543,7 @@ impl ApprovalOverlay {
                 }
             }
         }
+        self.change_picker = None;
         self.queue.clear();
         self.done = true;
     }
@@ -564,6 +592,18 @@ impl ApprovalOverlay {
 
 impl BottomPaneView for ApprovalOverlay {
     fn handle_key_event(&mut self, key_event: KeyEvent) {
+        if let Some(change_picker) = self.change_picker.as_mut() {
+            change_picker.picker.handle_key_event(key_event);
+            if change_picker.picker.is_complete() {
+                let confirmed = change_picker.confirmed.load(Ordering::Relaxed);
+                self.change_picker = None;
+                if confirmed {
+                    self.current_complete = true;
+                    self.advance_queue();
+                }
+            }
+            return;
+        }
         if self.try_handle_shortcut(&key_event) {
             return;
         }
@@ -601,15 +641,24 @@ impl BottomPaneView for ApprovalOverlay {
 
 impl Renderable for ApprovalOverlay {
     fn desired_height(&self, width: u16) -> u16 {
-        self.list.desired_height(width)
+        match &self.change_picker {
+            Some(change_picker) => change_picker.picker.desired_height(width),
+            None => self.list.desired_height(width),
+        }
     }
 
     fn render(&self, area: Rect, buf: &mut Buffer) {
-        self.list.render(area, buf);
+        match &self.change_picker {
+            Some(change_picker) => change_picker.picker.render(area, buf),
+            None => self.list.render(area, buf),
+        }
     }
 
     fn cursor_pos(&self, area: Rect) -> Option<(u16, u16)> {
-        self.list.cursor_pos(area)
+        match &self.change_picker {
+            Some(change_picker) => change_picker.picker.cursor_pos(area),
+            None => self.list.cursor_pos(area),
+        }
     }
 }
 
@@ -784,6 +833,8 @@ fn build_header(request: &ApprovalRequest) -> Box<dyn Renderable> {
 enum ApprovalDecision {
     Command(CommandExecutionApprovalDecision),
     FileChange(FileChangeApprovalDecision),
+    /// Opens a picker for accepting only some files or hunks of a patch.
+    ChooseFileChanges,
     Permissions(PermissionsDecision),
     McpElicitation(McpServerElicitationAction),
 }
@@ -1019,6 +1070,11 @@ fn patch_options(keymap: &ApprovalKeymap) -> Vec<ApprovalOption> {
             decision: ApprovalDecision::FileChange(FileChangeApprovalDecision::AcceptForSession),
             shortcuts: keymap.approve_for_session.clone(),
         },
+        ApprovalOption {
+            label: "Yes, but only the changes I select".to_string(),
+            decision: ApprovalDecision::ChooseFileChanges,
+            shortcuts: vec![key_hint::plain(KeyCode::Char('s'))],
+        },
         ApprovalOption {
             label: "No, and tell Codex what to do differently".to_string(),
             decision: ApprovalDecision::FileChange(FileChangeApprovalDecision::Cancel),
@@ -1027,6 +1083,119 @@ fn patch_options(keymap: &ApprovalKeymap) -> Vec<ApprovalOption> {
     ]
 }
 
+/// Builds a picker with one entry per hunk of each updated file and one entry per added,
+/// deleted, or renamed file. Confirming sends the checked entries as an `acceptSelected`
+/// decision.
+fn file_change_picker(
+    request: &ApplyPatchApprovalRequest,
+    app_event_tx: AppEventSender,
+    list_keymap: ListKeymap,
+) -> FileChangePicker {
+    let mut changes: Vec<(&PathBuf, &FileChange)> = request.changes.iter().collect();
+    changes.sort_by_key(|(path, _)| *path);
+
+    let mut items = Vec::new();
+    let mut targets: Vec<(String, Option<u32>)> = Vec::new();
+    for (path, change) in changes {
+        let display_path = display_path_for(path, request.cwd.as_path());
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| display_path.clone());
+        let hunk_headers = match change {
+            FileChange::Update { unified_diff, .. } => diffy::Patch::from_str(unified_diff)
+                .map(|patch| {
+                    patch
+                        .hunks()
+                        .iter()
+                        .map(|hunk| {
+                            let old = hunk.old_range();
+                            let new = hunk.new_range();
+                            format!(
+                                "@@ -{},{} +{},{} @@",
+                                old.start(),
+                                old.len(),
+                                new.start(),
+                                new.len()
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default(),
+            FileChange::Add { .. } | FileChange::Delete { .. } => Vec::new(),
+        };
+        if hunk_headers.is_empty() {
+            let description = match change {
+                FileChange::Add { .. } => format!("new file: {display_path}"),
+                FileChange::Delete { .. } => format!("delete: {display_path}"),
+                FileChange::Update { .. } => display_path.clone(),
+            };
+            items.push(MultiSelectItem {
+                id: targets.len().to_string(),
+                name: file_name,
+                description: Some(description),
+                enabled: true,
+                ..Default::default()
+            });
+            targets.push((path.to_string_lossy().into_owned(), None));
+            continue;
+        }
+        for (index, header) in hunk_headers.into_iter().enumerate() {
+            items.push(MultiSelectItem {
+                id: targets.len().to_string(),
+                name: format!("{file_name} #{}", index + 1),
+                description: Some(format!("{display_path} {header}")),
+                enabled: true,
+                ..Default::default()
+            });
+            targets.push((
+                path.to_string_lossy().into_owned(),
+                u32::try_from(index).ok(),
+            ));
+        }
+    }
+
+    let thread_id = request.thread_id;
+    let id = request.id.clone();
+    let confirmed = Arc::new(AtomicBool::new(false));
+    let on_confirm_confirmed = Arc::clone(&confirmed);
+    let picker = MultiSelectPicker::builder(
+        "Select the changes to apply".to_string(),
+        Some("Unchecked changes are rejected and reported back to Codex.".to_string()),
+        app_event_tx,
+    )
+    .items(items)
+    .list_keymap(list_keymap)
+    .on_confirm(move |selected_ids, tx| {
+        let mut changes: Vec<FileChangeSelection> = Vec::new();
+        for (idx, (path, hunk)) in targets.iter().enumerate() {
+            if !selected_ids.contains(&idx.to_string()) {
+                continue;
+            }
+            match changes.last_mut() {
+                Some(last) if last.path == *path => {
+                    if let (Some(hunks), Some(hunk)) = (last.hunks.as_mut(), hunk) {
+                        hunks.push(*hunk);
+                    }
+                }
+                _ => changes.push(FileChangeSelection {
+                    path: path.clone(),
+                    hunks: hunk.map(|hunk| vec![hunk]),
+                }),
+            }
+        }
+        on_confirm_confirmed.store(true, Ordering::Relaxed);
+        tx.patch_approval(
+            thread_id,
+            id.clone(),
+            FileChangeApprovalDecision::AcceptSelected { changes },
+        );
+    })
+    .build();
+
+    FileChangePicker { picker, confirmed }
+}
+
 fn permissions_options(keymap: &ApprovalKeymap) -> Vec<ApprovalOption> {
     let deny_shortcuts = keymap
         .deny
@@ -2088,6 +2257,108 @@ mod tests {
         assert!(!rendered.contains("$ apply_patch"));
     }
 
+    #[test]
+    fn choosing_patch_changes_accepts_only_checked_hunks() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut changes = HashMap::new();
+        changes.insert(
+            PathBuf::from("/tmp/new.txt"),
+            FileChange::Add {
+                content: "hello\n".to_string(),
+            },
+        );
+        changes.insert(
+            PathBuf::from("/tmp/notes.txt"),
+            FileChange::Update {
+                unified_diff: "--- a/notes.txt\n+++ b/notes.txt\n@@ -1,1 +1,1 @@\n-one\n+uno\n@@ -9,1 +9,1 @@\n-nine\n+nueve\n".to_string(),
+                move_path: None,
+            },
+        );
+        let request = ApprovalRequest::ApplyPatch(ApplyPatchApprovalRequest {
+            thread_id: ThreadId::new(),
+            thread_label: None,
+            id: "patch".to_string(),
+            reason: None,
+            cwd: absolute_path("/tmp"),
+            changes,
+        });
+        let mut view = make_overlay(request, tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        let rendered = render_overlay_lines(&view, /*width*/ 120);
+        assert!(
+            rendered.contains("Select the changes to apply"),
+            "{rendered}"
+        );
+        assert!(rendered.contains("notes.txt @@ -9,1 +9,1 @@"), "{rendered}");
+
+        // Uncheck the first hunk of notes.txt, which sorts after new.txt.
+        view.handle_key_event(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert!(view.is_complete());
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::SubmitThreadOp {
+                op: Op::PatchApproval { decision: d, .. },
+                ..
+            } = ev
+            {
+                decision = Some(d);
+            }
+        }
+        assert_eq!(
+            decision,
+            Some(FileChangeApprovalDecision::AcceptSelected {
+                changes: vec![
+                    FileChangeSelection {
+                        path: "/tmp/new.txt".to_string(),
+                        hunks: None,
+                    },
+                    FileChangeSelection {
+                        path: "/tmp/notes.txt".to_string(),
+                        hunks: Some(vec![1]),
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn closing_the_change_picker_returns_to_the_patch_options() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut changes = HashMap::new();
+        changes.insert(
+            PathBuf::from("/tmp/new.txt"),
+            FileChange::Add {
+                content: "hello\n".to_string(),
+            },
+        );
+        let request = ApprovalRequest::ApplyPatch(ApplyPatchApprovalRequest {
+            thread_id: ThreadId::new(),
+            thread_label: None,
+            id: "patch".to_string(),
+            reason: None,
+            cwd: absolute_path("/tmp"),
+            changes,
+        });
+        let mut view = make_overlay(request, tx, Features::with_defaults());
+
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE));
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        assert!(!view.is_complete());
+        assert!(rx.try_recv().is_err());
+        let rendered = render_overlay_lines(&view, /*width*/ 120);
+        assert!(
+            rendered.contains("Would you like to make the following edits?"),
+            "{rendered}"
+        );
+    }
+
     #[test]
     fn network_exec_prompt_title_includes_host() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
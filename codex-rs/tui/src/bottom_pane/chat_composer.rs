@@ -241,6 +241,7 @@ use super::prompt_args::parse_slash_name;
 use super::skill_popup::MentionItem;
 use super::skill_popup::SkillPopup;
 use super::slash_commands::BuiltinCommandFlags;
+use super::slash_commands::McpPromptCommand;
 use super::slash_commands::ServiceTierCommand;
 use super::slash_commands::SlashCommandItem;
 use crate::bottom_pane::paste_burst::FlushResult;
@@ -341,6 +342,11 @@ pub enum InputResult {
     Command(SlashCommand),
     /// A bare model service-tier command parsed by the composer.
     ServiceTierCommand(ServiceTierCommand),
+    /// An MCP prompt command and its trimmed, unparsed argument text.
+    McpPromptCommand {
+        command: McpPromptCommand,
+        args: String,
+    },
     /// An inline slash command and its trimmed argument text.
     ///
     /// The `TextElement` ranges are rebased into the argument string, while any pending local
@@ -484,6 +490,7 @@ pub(crate) struct ChatComposer {
     token_activity_command_enabled: bool,
     service_tier_commands_enabled: bool,
     service_tier_commands: Vec<ServiceTierCommand>,
+    mcp_prompt_commands: Vec<McpPromptCommand>,
     mentions_v2_enabled: bool,
     goal_command_enabled: bool,
     personality_command_enabled: bool,
@@ -549,6 +556,7 @@ impl ChatComposer {
             self.draft.is_bash_mode,
             self.builtin_command_flags(),
             &self.service_tier_commands,
+            &self.mcp_prompt_commands,
         )
     }
 
@@ -661,6 +669,7 @@ impl ChatComposer {
             token_activity_command_enabled: false,
             service_tier_commands_enabled: false,
             service_tier_commands: Vec::new(),
+            mcp_prompt_commands: Vec::new(),
             mentions_v2_enabled: false,
             goal_command_enabled: false,
             personality_command_enabled: false,
@@ -809,6 +818,11 @@ impl ChatComposer {
         self.sync_popups();
     }
 
+    pub fn set_mcp_prompt_commands(&mut self, commands: Vec<McpPromptCommand>) {
+        self.mcp_prompt_commands = commands;
+        self.sync_popups();
+    }
+
     pub fn set_goal_command_enabled(&mut self, enabled: bool) {
         self.goal_command_enabled = enabled;
     }
@@ -2948,6 +2962,7 @@ impl ChatComposer {
                 | InputResult::Queued { .. }
                 | InputResult::Command(_)
                 | InputResult::ServiceTierCommand(_)
+                | InputResult::McpPromptCommand { .. }
                 | InputResult::CommandWithArgs(_, _, _)
         ) {
             self.draft.textarea.enter_vim_normal_mode();
@@ -3133,6 +3148,10 @@ impl ChatComposer {
         Some(match command {
             SlashCommandItem::Builtin(cmd) => InputResult::Command(cmd),
             SlashCommandItem::ServiceTier(command) => InputResult::ServiceTierCommand(command),
+            SlashCommandItem::McpPrompt(command) => InputResult::McpPromptCommand {
+                command,
+                args: String::new(),
+            },
         })
     }
 
@@ -3157,14 +3176,22 @@ impl ChatComposer {
         );
         let trimmed_rest = inline_command.rest.trim();
         args_elements = Self::trim_text_elements(inline_command.rest, trimmed_rest, args_elements);
-        let SlashCommandItem::Builtin(cmd) = command else {
-            return None;
-        };
-        Some(InputResult::CommandWithArgs(
-            cmd,
-            trimmed_rest.to_string(),
-            args_elements,
-        ))
+        match command {
+            SlashCommandItem::Builtin(cmd) => Some(InputResult::CommandWithArgs(
+                cmd,
+                trimmed_rest.to_string(),
+                args_elements,
+            )),
+            SlashCommandItem::McpPrompt(command) => {
+                self.draft.textarea.set_text_clearing_elements("");
+                self.draft.is_bash_mode = false;
+                Some(InputResult::McpPromptCommand {
+                    command,
+                    args: trimmed_rest.to_string(),
+                })
+            }
+            SlashCommandItem::ServiceTier(_) => None,
+        }
     }
 
     /// Expand pending placeholders and extract normalized inline-command args.
@@ -8914,6 +8941,9 @@ mod tests {
                 Some(CommandItem::ServiceTier(command)) => {
                     panic!("expected model command, got service tier {command:?}")
                 }
+                Some(CommandItem::McpPrompt(command)) => {
+                    panic!("expected model command, got MCP prompt {command:?}")
+                }
                 None => panic!("no selected command for '/mo'"),
             },
             _ => panic!("slash popup not active after typing '/mo'"),
@@ -8996,6 +9026,9 @@ mod tests {
                 Some(CommandItem::ServiceTier(command)) => {
                     panic!("expected resume command, got service tier {command:?}")
                 }
+                Some(CommandItem::McpPrompt(command)) => {
+                    panic!("expected resume command, got MCP prompt {command:?}")
+                }
                 None => panic!("no selected command for '/res'"),
             },
             _ => panic!("slash popup not active after typing '/res'"),
@@ -9050,6 +9083,9 @@ mod tests {
                 Some(CommandItem::ServiceTier(command)) => {
                     panic!("expected pets command, got service tier {command:?}")
                 }
+                Some(CommandItem::McpPrompt(command)) => {
+                    panic!("expected pets command, got MCP prompt {command:?}")
+                }
                 None => panic!("no selected command for '/pet'"),
             },
             _ => panic!("slash popup not active after typing '/pet'"),
@@ -9104,6 +9140,9 @@ mod tests {
                 Some(CommandItem::ServiceTier(command)) => {
                     panic!("expected btw command, got service tier {command:?}")
                 }
+                Some(CommandItem::McpPrompt(command)) => {
+                    panic!("expected btw command, got MCP prompt {command:?}")
+                }
                 None => panic!("no selected command for '/bt'"),
             },
             _ => panic!("slash popup not active after typing '/bt'"),
@@ -9158,6 +9197,9 @@ mod tests {
                 Some(CommandItem::ServiceTier(command)) => {
                     panic!("expected side command, got service tier {command:?}")
                 }
+                Some(CommandItem::McpPrompt(command)) => {
+                    panic!("expected side command, got MCP prompt {command:?}")
+                }
                 None => panic!("no selected command for '/si'"),
             },
             _ => panic!("slash popup not active after typing '/si'"),
@@ -9196,6 +9238,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mcp_prompt_slash_command_dispatches_with_inline_args() {
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            /*has_input_focus*/ true,
+            sender,
+            /*enhanced_keys_supported*/ false,
+            "Ask Codex to do anything".to_string(),
+            /*disable_paste_burst*/ false,
+        );
+        let command = McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: "summarize".to_string(),
+            name: "mcp__docs__summarize".to_string(),
+            description: "Summarize a page".to_string(),
+            arguments: vec!["url".to_string()],
+        };
+        composer.set_mcp_prompt_commands(vec![command.clone()]);
+        composer.set_text_content(
+            "/mcp__docs__summarize https://example.com ".to_string(),
+            Vec::new(),
+            Vec::new(),
+        );
+
+        let (result, _needs_redraw) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        assert_eq!(
+            result,
+            InputResult::McpPromptCommand {
+                command,
+                args: "https://example.com".to_string(),
+            }
+        );
+        assert!(composer.draft.textarea.text().is_empty());
+    }
+
     fn flush_after_paste_burst(composer: &mut ChatComposer) -> bool {
         std::thread::sleep(PasteBurst::recommended_active_flush_delay());
         composer.flush_paste_burst_if_due()
@@ -9256,6 +9336,9 @@ mod tests {
             InputResult::ServiceTierCommand(command) => {
                 panic!("expected init command, got service tier {command:?}")
             }
+            InputResult::McpPromptCommand { command, .. } => {
+                panic!("expected init command, got MCP prompt {command:?}")
+            }
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch, but composer submitted literal text: {text}")
             }
@@ -9766,6 +9849,9 @@ mod tests {
             InputResult::ServiceTierCommand(command) => {
                 panic!("expected diff command, got service tier {command:?}")
             }
+            InputResult::McpPromptCommand { command, .. } => {
+                panic!("expected diff command, got MCP prompt {command:?}")
+            }
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch after Tab completion, got literal submit: {text}")
             }
@@ -9966,6 +10052,9 @@ mod tests {
             InputResult::ServiceTierCommand(command) => {
                 panic!("expected mention command, got service tier {command:?}")
             }
+            InputResult::McpPromptCommand { command, .. } => {
+                panic!("expected mention command, got MCP prompt {command:?}")
+            }
             InputResult::Submitted { text, .. } => {
                 panic!("expected command dispatch, but composer submitted literal text: {text}")
             }
@@ -10,6 +10,7 @@ use super::selection_popup_common::GenericDisplayRow;
 use super::selection_popup_common::measure_rows_height_with_col_width_mode;
 use super::selection_popup_common::render_rows_with_col_width_mode;
 use super::slash_commands::BuiltinCommandFlags;
+use super::slash_commands::McpPromptCommand;
 use super::slash_commands::ServiceTierCommand;
 use super::slash_commands::SlashCommandItem;
 use super::slash_commands::commands_for_input;
@@ -31,6 +32,7 @@ const COMMAND_COLUMN_WIDTH: ColumnWidthConfig = ColumnWidthConfig::new(
 pub(crate) enum CommandItem {
     Builtin(SlashCommand),
     ServiceTier(ServiceTierCommand),
+    McpPrompt(McpPromptCommand),
 }
 
 pub(crate) struct CommandPopup {
@@ -72,15 +74,21 @@ impl CommandPopup {
     pub(crate) fn new(
         flags: CommandPopupFlags,
         service_tier_commands: Vec<ServiceTierCommand>,
+        mcp_prompt_commands: Vec<McpPromptCommand>,
     ) -> Self {
         // Keep built-in availability in sync with the composer.
-        let commands = commands_for_input(flags.into(), &service_tier_commands)
+        let commands = commands_for_input(
+            flags.into(),
+            &service_tier_commands,
+            &mcp_prompt_commands,
+        )
             .into_iter()
             .filter_map(|command| match command {
                 SlashCommandItem::Builtin(cmd) => (!cmd.command().starts_with("debug")
                     && cmd != SlashCommand::Apps)
                     .then_some(CommandItem::Builtin(cmd)),
                 SlashCommandItem::ServiceTier(command) => Some(CommandItem::ServiceTier(command)),
+                SlashCommandItem::McpPrompt(command) => Some(CommandItem::McpPrompt(command)),
             })
             .collect();
         Self {
@@ -250,6 +258,7 @@ impl CommandItem {
         match self {
             Self::Builtin(cmd) => cmd.command(),
             Self::ServiceTier(command) => &command.name,
+            Self::McpPrompt(command) => &command.name,
         }
     }
 
@@ -257,6 +266,7 @@ impl CommandItem {
         match self {
             Self::Builtin(cmd) => cmd.description(),
             Self::ServiceTier(command) => &command.description,
+            Self::McpPrompt(command) => &command.description,
         }
     }
 }
@@ -285,7 +295,7 @@ mod tests {
 
     #[test]
     fn filter_includes_init_when_typing_prefix() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         // Simulate the composer line starting with '/in' so the popup filters
         // matching commands by prefix.
         popup.on_composer_text_change("/in".to_string());
@@ -295,7 +305,7 @@ mod tests {
         let matches = popup.filtered_items();
         let has_init = matches.iter().any(|item| match item {
             CommandItem::Builtin(cmd) => cmd.command() == "init",
-            CommandItem::ServiceTier(_) => false,
+            CommandItem::ServiceTier(_) | CommandItem::McpPrompt(_) => false,
         });
         assert!(
             has_init,
@@ -305,7 +315,7 @@ mod tests {
 
     #[test]
     fn selecting_init_by_exact_match() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/init".to_string());
 
         // When an exact match exists, the selected command should be that
@@ -316,13 +326,16 @@ mod tests {
             Some(CommandItem::ServiceTier(command)) => {
                 panic!("expected init command, got service tier {command:?}")
             }
+            Some(CommandItem::McpPrompt(command)) => {
+                panic!("expected init command, got MCP prompt {command:?}")
+            }
             None => panic!("expected a selected command for exact match"),
         }
     }
 
     #[test]
     fn model_is_first_suggestion_for_mo() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/mo".to_string());
         let matches = popup.filtered_items();
         match matches.first() {
@@ -330,6 +343,9 @@ mod tests {
             Some(CommandItem::ServiceTier(command)) => {
                 panic!("expected model command, got service tier {command:?}")
             }
+            Some(CommandItem::McpPrompt(command)) => {
+                panic!("expected model command, got MCP prompt {command:?}")
+            }
             None => panic!("expected at least one match for '/mo'"),
         }
     }
@@ -346,6 +362,7 @@ mod tests {
                 name: "fast".to_string(),
                 description: "Fastest inference with increased plan usage".to_string(),
             }],
+            Vec::new(),
         );
         popup.on_composer_text_change("/fa".to_string());
 
@@ -369,7 +386,7 @@ mod tests {
 
     #[test]
     fn filtered_commands_keep_presentation_order_for_prefix() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/m".to_string());
 
         let cmds: Vec<String> = popup
@@ -378,6 +395,7 @@ mod tests {
             .map(|item| match item {
                 CommandItem::Builtin(cmd) => cmd.command().to_string(),
                 CommandItem::ServiceTier(command) => command.name,
+                CommandItem::McpPrompt(command) => command.name,
             })
             .collect();
         assert_eq!(
@@ -394,7 +412,7 @@ mod tests {
     #[cfg(any(target_os = "macos", target_os = "windows"))]
     #[test]
     fn app_command_popup_snapshot() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/app".to_string());
 
         let width = 72;
@@ -413,7 +431,7 @@ mod tests {
     #[cfg(target_os = "macos")]
     #[test]
     fn default_command_popup_items_snapshot() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/".to_string());
 
         let commands = popup
@@ -432,7 +450,7 @@ mod tests {
 
     #[test]
     fn prefix_filter_limits_matches_for_ac() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/ac".to_string());
 
         let cmds: Vec<String> = popup
@@ -441,6 +459,7 @@ mod tests {
             .map(|item| match item {
                 CommandItem::Builtin(cmd) => cmd.command().to_string(),
                 CommandItem::ServiceTier(command) => command.name,
+                CommandItem::McpPrompt(command) => command.name,
             })
             .collect();
         assert!(
@@ -451,7 +470,7 @@ mod tests {
 
     #[test]
     fn changing_filter_resets_selection_after_scrolling() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/".to_string());
 
         for _ in 0..MAX_POPUP_ROWS {
@@ -483,7 +502,7 @@ mod tests {
 
     #[test]
     fn quit_hidden_in_empty_filter_but_shown_for_prefix() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/".to_string());
         let items = popup.filtered_items();
         assert!(!items.contains(&CommandItem::Builtin(SlashCommand::Quit)));
@@ -495,7 +514,7 @@ mod tests {
 
     #[test]
     fn btw_hidden_in_empty_filter_but_shown_for_prefix() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/".to_string());
         let items = popup.filtered_items();
         assert!(!items.contains(&CommandItem::Builtin(SlashCommand::Btw)));
@@ -507,7 +526,7 @@ mod tests {
 
     #[test]
     fn plan_command_hidden_when_collaboration_modes_disabled() {
-        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let mut popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         popup.on_composer_text_change("/".to_string());
 
         let cmds: Vec<String> = popup
@@ -516,6 +535,7 @@ mod tests {
             .map(|item| match item {
                 CommandItem::Builtin(cmd) => cmd.command().to_string(),
                 CommandItem::ServiceTier(command) => command.name,
+                CommandItem::McpPrompt(command) => command.name,
             })
             .collect();
         assert!(
@@ -524,6 +544,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mcp_prompt_command_uses_prompt_description() {
+        let command = McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: "summarize".to_string(),
+            name: "mcp__docs__summarize".to_string(),
+            description: "Summarize a page".to_string(),
+            arguments: Vec::new(),
+        };
+        let mut popup = CommandPopup::new(
+            CommandPopupFlags::default(),
+            Vec::new(),
+            vec![command.clone()],
+        );
+        popup.on_composer_text_change("/mcp__docs".to_string());
+
+        assert_eq!(popup.selected_item(), Some(CommandItem::McpPrompt(command)));
+        let rows = popup.rows_from_matches(popup.filtered());
+        assert_eq!(
+            rows.first().and_then(|row| row.description.as_deref()),
+            Some("Summarize a page")
+        );
+    }
+
     #[test]
     fn plan_command_visible_when_collaboration_modes_enabled() {
         let mut popup = CommandPopup::new(
@@ -539,6 +583,7 @@ mod tests {
                 side_conversation_active: false,
             },
             Vec::new(),
+            Vec::new(),
         );
         popup.on_composer_text_change("/plan".to_string());
 
@@ -547,6 +592,9 @@ mod tests {
             Some(CommandItem::ServiceTier(command)) => {
                 panic!("expected plan command, got service tier {command:?}")
             }
+            Some(CommandItem::McpPrompt(command)) => {
+                panic!("expected plan command, got MCP prompt {command:?}")
+            }
             other => panic!("expected plan to be selected for exact match, got {other:?}"),
         }
     }
@@ -566,6 +614,7 @@ mod tests {
                 side_conversation_active: false,
             },
             Vec::new(),
+            Vec::new(),
         );
         popup.on_composer_text_change("/pers".to_string());
 
@@ -575,6 +624,7 @@ mod tests {
             .map(|item| match item {
                 CommandItem::Builtin(cmd) => cmd.command().to_string(),
                 CommandItem::ServiceTier(command) => command.name,
+                CommandItem::McpPrompt(command) => command.name,
             })
             .collect();
         assert!(
@@ -598,6 +648,7 @@ mod tests {
                 side_conversation_active: false,
             },
             Vec::new(),
+            Vec::new(),
         );
         popup.on_composer_text_change("/personality".to_string());
 
@@ -606,19 +657,23 @@ mod tests {
             Some(CommandItem::ServiceTier(command)) => {
                 panic!("expected personality command, got service tier {command:?}")
             }
+            Some(CommandItem::McpPrompt(command)) => {
+                panic!("expected personality command, got MCP prompt {command:?}")
+            }
             other => panic!("expected personality to be selected for exact match, got {other:?}"),
         }
     }
 
     #[test]
     fn debug_commands_are_hidden_from_popup() {
-        let popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new());
+        let popup = CommandPopup::new(CommandPopupFlags::default(), Vec::new(), Vec::new());
         let cmds: Vec<String> = popup
             .filtered_items()
             .into_iter()
             .map(|item| match item {
                 CommandItem::Builtin(cmd) => cmd.command().to_string(),
                 CommandItem::ServiceTier(command) => command.name,
+                CommandItem::McpPrompt(command) => command.name,
             })
             .collect();
 
@@ -127,6 +127,7 @@ pub(crate) use list_selection_view::SideContentWidth;
 pub(crate) use list_selection_view::popup_content_width;
 pub(crate) use list_selection_view::side_by_side_layout_widths;
 pub(crate) use memories_settings_view::MemoriesSettingsView;
+use slash_commands::McpPromptCommand;
 use slash_commands::ServiceTierCommand;
 mod feedback_view;
 mod hooks_browser_view;
@@ -461,6 +462,11 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    pub fn set_mcp_prompt_commands(&mut self, commands: Vec<McpPromptCommand>) {
+        self.composer.set_mcp_prompt_commands(commands);
+        self.request_redraw();
+    }
+
     pub fn set_goal_command_enabled(&mut self, enabled: bool) {
         self.composer.set_goal_command_enabled(enabled);
         self.request_redraw();
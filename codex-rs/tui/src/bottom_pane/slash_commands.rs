@@ -1,4 +1,5 @@
-//! Shared helpers for filtering and matching built-in and model service-tier slash commands.
+//! Shared helpers for filtering and matching built-in, model service-tier, and MCP prompt slash
+//! commands.
 //!
 //! The same sandbox- and feature-gating rules are used by both the composer
 //! and the command popup. Centralizing them here keeps those call sites small
@@ -17,10 +18,22 @@ pub(crate) struct ServiceTierCommand {
     pub(crate) description: String,
 }
 
+/// A prompt published by an MCP server, invoked as `/<name> [args]`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct McpPromptCommand {
+    pub(crate) server: String,
+    pub(crate) prompt: String,
+    pub(crate) name: String,
+    pub(crate) description: String,
+    /// Declared argument names, in order, used to bind positional args.
+    pub(crate) arguments: Vec<String>,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) enum SlashCommandItem {
     Builtin(SlashCommand),
     ServiceTier(ServiceTierCommand),
+    McpPrompt(McpPromptCommand),
 }
 
 impl SlashCommandItem {
@@ -28,6 +41,7 @@ impl SlashCommandItem {
         match self {
             Self::Builtin(cmd) => cmd.command(),
             Self::ServiceTier(command) => &command.name,
+            Self::McpPrompt(command) => &command.name,
         }
     }
 
@@ -35,13 +49,14 @@ impl SlashCommandItem {
         match self {
             Self::Builtin(cmd) => cmd.supports_inline_args(),
             Self::ServiceTier(_) => false,
+            Self::McpPrompt(_) => true,
         }
     }
 
     pub(crate) fn available_in_side_conversation(&self) -> bool {
         match self {
             Self::Builtin(cmd) => cmd.available_in_side_conversation(),
-            Self::ServiceTier(_) => false,
+            Self::ServiceTier(_) | Self::McpPrompt(_) => false,
         }
     }
 
@@ -49,6 +64,7 @@ impl SlashCommandItem {
         match self {
             Self::Builtin(cmd) => cmd.available_during_task(),
             Self::ServiceTier(_) => true,
+            Self::McpPrompt(_) => false,
         }
     }
 }
@@ -84,6 +100,7 @@ pub(crate) fn builtins_for_input(flags: BuiltinCommandFlags) -> Vec<(&'static st
 pub(crate) fn commands_for_input(
     flags: BuiltinCommandFlags,
     service_tier_commands: &[ServiceTierCommand],
+    mcp_prompt_commands: &[McpPromptCommand],
 ) -> Vec<SlashCommandItem> {
     let mut commands = Vec::new();
     let tiers_enabled = flags.service_tier_commands_enabled;
@@ -98,6 +115,12 @@ pub(crate) fn commands_for_input(
             );
         }
     }
+    commands.extend(
+        mcp_prompt_commands
+            .iter()
+            .cloned()
+            .map(SlashCommandItem::McpPrompt),
+    );
     commands
         .into_iter()
         .filter(|cmd| !flags.side_conversation_active || cmd.available_in_side_conversation())
@@ -129,10 +152,17 @@ pub(crate) fn find_slash_command(
     name: &str,
     flags: BuiltinCommandFlags,
     service_tier_commands: &[ServiceTierCommand],
+    mcp_prompt_commands: &[McpPromptCommand],
 ) -> Option<SlashCommandItem> {
     if let Some(cmd) = find_builtin_command(name, flags) {
         return Some(SlashCommandItem::Builtin(cmd));
     }
+    if let Some(command) = mcp_prompt_commands
+        .iter()
+        .find(|command| command.name == name)
+    {
+        return Some(SlashCommandItem::McpPrompt(command.clone()));
+    }
 
     let tiers_enabled = flags.service_tier_commands_enabled;
     tiers_enabled
@@ -150,8 +180,9 @@ pub(crate) fn has_slash_command_prefix(
     name: &str,
     flags: BuiltinCommandFlags,
     service_tier_commands: &[ServiceTierCommand],
+    mcp_prompt_commands: &[McpPromptCommand],
 ) -> bool {
-    commands_for_input(flags, service_tier_commands)
+    commands_for_input(flags, service_tier_commands, mcp_prompt_commands)
         .into_iter()
         .any(|command| fuzzy_match(command.command(), name).is_some())
 }
@@ -224,7 +255,7 @@ mod tests {
             description: "fastest inference".to_string(),
         }];
 
-        assert_eq!(find_slash_command("fast", flags, &commands), None);
+        assert_eq!(find_slash_command("fast", flags, &commands, &[]), None);
     }
 
     #[test]
@@ -242,7 +273,7 @@ mod tests {
             },
         ];
 
-        let items = commands_for_input(all_enabled_flags(), &commands);
+        let items = commands_for_input(all_enabled_flags(), &commands, &[]);
         let model_idx = items
             .iter()
             .position(|item| matches!(item, SlashCommandItem::Builtin(SlashCommand::Model)))
@@ -340,8 +371,29 @@ mod tests {
         };
 
         assert_eq!(
-            find_slash_command("fast", flags, from_ref(&command)),
+            find_slash_command("fast", flags, from_ref(&command), &[]),
             Some(SlashCommandItem::ServiceTier(command))
         );
     }
+
+    #[test]
+    fn mcp_prompt_commands_follow_builtins_and_resolve_for_dispatch() {
+        let command = McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: "summarize".to_string(),
+            name: "mcp__docs__summarize".to_string(),
+            description: "Summarize a page".to_string(),
+            arguments: vec!["url".to_string()],
+        };
+
+        let items = commands_for_input(all_enabled_flags(), &[], from_ref(&command));
+        assert_eq!(
+            items.last(),
+            Some(&SlashCommandItem::McpPrompt(command.clone()))
+        );
+        assert_eq!(
+            find_slash_command("mcp__docs__summarize", all_enabled_flags(), &[], from_ref(&command)),
+            Some(SlashCommandItem::McpPrompt(command))
+        );
+    }
 }
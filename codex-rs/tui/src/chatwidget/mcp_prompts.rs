@@ -0,0 +1,227 @@
+//! MCP prompt slash commands for `ChatWidget`.
+//!
+//! Prompts published by the thread's MCP servers are listed after MCP startup settles and exposed
+//! as `/mcp__<server>__<prompt>` commands. Running one fetches the rendered prompt from the app
+//! server and submits its text as the next user message.
+
+use std::collections::BTreeMap;
+
+use super::ChatWidget;
+use super::slash_dispatch::SIDE_SLASH_COMMAND_UNAVAILABLE_HINT;
+use crate::app_event::AppEvent;
+use crate::bottom_pane::slash_commands::McpPromptCommand;
+use codex_app_server_protocol::McpServerPrompt;
+
+const MCP_PROMPT_COMMAND_PREFIX: &str = "mcp__";
+
+impl ChatWidget {
+    /// Ask the app to list prompts for the current thread once its MCP servers are ready.
+    pub(super) fn request_mcp_prompts(&self) {
+        if let Some(thread_id) = self.thread_id {
+            self.app_event_tx.send(AppEvent::FetchMcpPrompts { thread_id });
+        }
+    }
+
+    pub(crate) fn set_mcp_prompts(&mut self, prompts: Vec<McpServerPrompt>) {
+        self.mcp_prompt_commands = prompts.into_iter().map(mcp_prompt_command).collect();
+        self.bottom_pane
+            .set_mcp_prompt_commands(self.mcp_prompt_commands.clone());
+    }
+
+    pub(super) fn handle_mcp_prompt_command_dispatch(
+        &mut self,
+        command: McpPromptCommand,
+        args: String,
+    ) {
+        if self.active_side_conversation {
+            self.add_error_message(format!(
+                "'/{}' is unavailable in side conversations. {SIDE_SLASH_COMMAND_UNAVAILABLE_HINT}",
+                command.name
+            ));
+        } else if let Some(thread_id) = self.thread_id {
+            match mcp_prompt_arguments(&command, &args) {
+                Ok(arguments) => self.app_event_tx.send(AppEvent::RunMcpPrompt {
+                    thread_id,
+                    server: command.server,
+                    name: command.prompt,
+                    arguments,
+                }),
+                Err(err) => self.add_error_message(err),
+            }
+        } else {
+            self.add_error_message(format!(
+                "'/{}' is unavailable before the session starts.",
+                command.name
+            ));
+        }
+        self.bottom_pane.drain_pending_submission_state();
+        self.bottom_pane.record_pending_slash_command_history();
+    }
+
+    /// Submit a rendered MCP prompt, or report why it could not be fetched.
+    pub(crate) fn on_mcp_prompt_loaded(&mut self, name: String, result: Result<String, String>) {
+        match result {
+            Ok(text) if text.trim().is_empty() => {
+                self.add_info_message(
+                    format!("MCP prompt '{name}' returned no text."),
+                    /*hint*/ None,
+                );
+            }
+            Ok(text) => self.queue_user_message(text.into()),
+            Err(err) => {
+                self.add_error_message(format!("Failed to load MCP prompt '{name}': {err}"));
+            }
+        }
+    }
+}
+
+fn mcp_prompt_command(prompt: McpServerPrompt) -> McpPromptCommand {
+    let name = format!(
+        "{MCP_PROMPT_COMMAND_PREFIX}{}__{}",
+        sanitize_command_segment(&prompt.server),
+        sanitize_command_segment(&prompt.name)
+    );
+    let mut description = prompt
+        .description
+        .or(prompt.title)
+        .unwrap_or_else(|| format!("MCP prompt from {}", prompt.server));
+    if !prompt.arguments.is_empty() {
+        let usage = prompt
+            .arguments
+            .iter()
+            .map(|argument| {
+                if argument.required {
+                    format!("<{}>", argument.name)
+                } else {
+                    format!("[{}]", argument.name)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        description = format!("{description} {usage}");
+    }
+    McpPromptCommand {
+        server: prompt.server,
+        prompt: prompt.name,
+        name,
+        description,
+        arguments: prompt
+            .arguments
+            .into_iter()
+            .map(|argument| argument.name)
+            .collect(),
+    }
+}
+
+fn sanitize_command_segment(segment: &str) -> String {
+    segment
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Bind slash-command text to the prompt's declared arguments.
+///
+/// `key=value` tokens bind by name and the remaining tokens bind positionally. A prompt with a
+/// single argument receives the whole text so free-form input does not need quoting.
+fn mcp_prompt_arguments(
+    command: &McpPromptCommand,
+    args: &str,
+) -> Result<BTreeMap<String, String>, String> {
+    let args = args.trim();
+    let mut arguments = BTreeMap::new();
+    if args.is_empty() {
+        return Ok(arguments);
+    }
+    let is_named = |token: &str| {
+        token
+            .split_once('=')
+            .is_some_and(|(key, _)| command.arguments.iter().any(|name| name == key))
+    };
+    if let [only] = command.arguments.as_slice()
+        && !args.split_whitespace().any(is_named)
+    {
+        arguments.insert(only.clone(), args.to_string());
+        return Ok(arguments);
+    }
+
+    let mut positional = Vec::new();
+    for token in args.split_whitespace() {
+        match token.split_once('=') {
+            Some((key, value)) if is_named(token) => {
+                arguments.insert(key.to_string(), value.to_string());
+            }
+            _ => positional.push(token),
+        }
+    }
+    let mut unbound = command
+        .arguments
+        .iter()
+        .filter(|name| !arguments.contains_key(*name));
+    for value in positional {
+        let Some(name) = unbound.next() else {
+            if command.arguments.is_empty() {
+                return Err(format!("'/{}' does not take arguments.", command.name));
+            }
+            return Err(format!(
+                "Too many arguments for '/{}'. Expected: {}",
+                command.name,
+                command.arguments.join(", ")
+            ));
+        };
+        arguments.insert(name.clone(), value.to_string());
+    }
+    Ok(arguments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn command(arguments: &[&str]) -> McpPromptCommand {
+        McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: "summarize".to_string(),
+            name: "mcp__docs__summarize".to_string(),
+            description: "Summarize a page".to_string(),
+            arguments: arguments.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn single_argument_prompt_receives_whole_text() {
+        assert_eq!(
+            mcp_prompt_arguments(&command(&["topic"]), " rust async traits "),
+            Ok(BTreeMap::from([(
+                "topic".to_string(),
+                "rust async traits".to_string()
+            )]))
+        );
+    }
+
+    #[test]
+    fn named_arguments_bind_before_positional_ones() {
+        assert_eq!(
+            mcp_prompt_arguments(&command(&["url", "style"]), "style=brief https://example.com"),
+            Ok(BTreeMap::from([
+                ("style".to_string(), "brief".to_string()),
+                ("url".to_string(), "https://example.com".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn extra_positional_arguments_are_rejected() {
+        assert_eq!(
+            mcp_prompt_arguments(&command(&[]), "unexpected"),
+            Err("'/mcp__docs__summarize' does not take arguments.".to_string())
+        );
+    }
+}
@@ -207,6 +207,7 @@ impl ChatWidget {
         if self.bottom_pane.is_task_running() && mcp_startup_owned_status {
             self.restore_reasoning_status_header();
         }
+        self.request_mcp_prompts();
         self.maybe_send_next_queued_input();
         self.request_redraw();
     }
@@ -5,6 +5,11 @@
 //! dispatch step and records the staged entry once the command has been handled, so
 //! slash-command recall follows the same submitted-input rule as ordinary text.
 
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_app_server_protocol::ThreadExportFormat;
+
 use super::*;
 use crate::app_event::ThreadGoalSetMode;
 use crate::bottom_pane::prompt_args::parse_slash_name;
@@ -32,10 +37,11 @@ struct PreparedSlashCommandArgs {
 }
 
 const SIDE_STARTING_CONTEXT_LABEL: &str = "Side starting...";
-const SIDE_SLASH_COMMAND_UNAVAILABLE_HINT: &str =
+pub(super) const SIDE_SLASH_COMMAND_UNAVAILABLE_HINT: &str =
     "Press Ctrl+C to return to the main thread first.";
 const GOAL_USAGE_HINT: &str = "Example: /goal improve benchmark coverage";
 const RAW_USAGE: &str = "Usage: /raw [on|off]";
+const EXPORT_USAGE: &str = "Usage: /export [markdown|html|json] [path]";
 const USAGE_CHATGPT_LOGIN_REQUIRED: &str = "Sign in with ChatGPT to use /usage.";
 
 impl ChatWidget {
@@ -476,6 +482,21 @@ impl ChatWidget {
             SlashCommand::Stop => {
                 self.clean_background_terminals();
             }
+            SlashCommand::Undo => {
+                self.submit_op(AppCommand::undo_last_turn());
+            }
+            SlashCommand::Pin => {
+                self.submit_op(AppCommand::list_pinned_context());
+            }
+            SlashCommand::Unpin => {
+                self.add_error_message("Usage: /unpin <pin-id>".to_string());
+            }
+            SlashCommand::Export => {
+                self.submit_op(AppCommand::export_thread(
+                    ThreadExportFormat::Markdown,
+                    /*path*/ None,
+                ));
+            }
             SlashCommand::MemoryDrop => {
                 self.add_app_server_stub_message("Memory maintenance");
             }
@@ -871,6 +892,21 @@ impl ChatWidget {
                 );
                 self.request_side_conversation(parent_thread_id, Some(user_message));
             }
+            SlashCommand::Pin if !trimmed.is_empty() => match trimmed.strip_prefix("--item") {
+                Some(item_id) if item_id.starts_with(char::is_whitespace) => {
+                    self.submit_op(AppCommand::pin_thread_item(item_id.trim().to_string()));
+                }
+                _ => self.submit_op(AppCommand::pin_context(trimmed.to_string())),
+            },
+            SlashCommand::Unpin if !trimmed.is_empty() => {
+                self.submit_op(AppCommand::unpin_context(trimmed.to_string()));
+            }
+            SlashCommand::Export if !trimmed.is_empty() => match parse_export_args(trimmed) {
+                Some((format, path)) => {
+                    self.submit_op(AppCommand::export_thread(format, path));
+                }
+                None => self.add_error_message(EXPORT_USAGE.to_string()),
+            },
             SlashCommand::Review if !trimmed.is_empty() => {
                 self.submit_op(AppCommand::review(ReviewTarget::Custom {
                     instructions: args,
@@ -941,9 +977,12 @@ impl ChatWidget {
         }
 
         let service_tier_commands = self.current_model_service_tier_commands();
-        let Some(command) =
-            find_slash_command(name, self.builtin_command_flags(), &service_tier_commands)
-        else {
+        let Some(command) = find_slash_command(
+            name,
+            self.builtin_command_flags(),
+            &service_tier_commands,
+            &self.mcp_prompt_commands,
+        ) else {
             self.add_info_message(
                 format!(
                     r#"Unrecognized command '/{name}'. Type "/" for a list of supported commands."#
@@ -963,6 +1002,10 @@ impl ChatWidget {
                     self.handle_service_tier_command_dispatch(command);
                     QueueDrain::Continue
                 }
+                SlashCommandItem::McpPrompt(command) => {
+                    self.handle_mcp_prompt_command_dispatch(command, String::new());
+                    QueueDrain::Continue
+                }
             };
         }
 
@@ -976,6 +1019,10 @@ impl ChatWidget {
             });
             return QueueDrain::Stop;
         }
+        if let SlashCommandItem::McpPrompt(command) = command {
+            self.handle_mcp_prompt_command_dispatch(command, rest.trim().to_string());
+            return QueueDrain::Continue;
+        }
         let SlashCommandItem::Builtin(cmd) = command else {
             self.submit_user_message(UserMessage {
                 text,
@@ -1063,6 +1110,9 @@ impl ChatWidget {
             | SlashCommand::Diff
             | SlashCommand::App
             | SlashCommand::Rename
+            | SlashCommand::Pin
+            | SlashCommand::Unpin
+            | SlashCommand::Export
             | SlashCommand::TestApproval => QueueDrain::Continue,
             SlashCommand::Feedback
             | SlashCommand::New
@@ -1073,6 +1123,7 @@ impl ChatWidget {
             | SlashCommand::Fork
             | SlashCommand::Init
             | SlashCommand::Compact
+            | SlashCommand::Undo
             | SlashCommand::Review
             | SlashCommand::Model
             | SlashCommand::Personality
@@ -1153,3 +1204,31 @@ impl ChatWidget {
         false
     }
 }
+
+/// Parses `/export` arguments of the form `[format] [path]`. When only a path is given, the
+/// format follows its extension and falls back to Markdown.
+fn parse_export_args(args: &str) -> Option<(ThreadExportFormat, Option<PathBuf>)> {
+    let (first, rest) = args
+        .split_once(char::is_whitespace)
+        .map_or((args, ""), |(first, rest)| (first, rest.trim()));
+    if let Some(format) = parse_export_format(first) {
+        let path = (!rest.is_empty()).then(|| PathBuf::from(rest));
+        return Some((format, path));
+    }
+    let path = PathBuf::from(args);
+    let format = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(parse_export_format)
+        .unwrap_or_default();
+    (path != Path::new("")).then_some((format, Some(path)))
+}
+
+fn parse_export_format(value: &str) -> Option<ThreadExportFormat> {
+    match value.to_ascii_lowercase().as_str() {
+        "markdown" | "md" => Some(ThreadExportFormat::Markdown),
+        "html" | "htm" => Some(ThreadExportFormat::Html),
+        "json" => Some(ThreadExportFormat::Json),
+        _ => None,
+    }
+}
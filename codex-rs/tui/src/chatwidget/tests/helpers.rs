@@ -1624,6 +1624,7 @@ pub(super) fn hook_run(
         )
         .then_some(10),
         entries,
+        agent_thread_id: None,
     }
 }
 
@@ -1,5 +1,6 @@
 use super::*;
 use crate::bottom_pane::slash_commands::ServiceTierCommand;
+use codex_app_server_protocol::ThreadExportFormat;
 use pretty_assertions::assert_eq;
 use serial_test::serial;
 
@@ -2098,6 +2099,77 @@ async fn slash_stop_submits_background_terminal_cleanup() {
     );
 }
 
+#[tokio::test]
+async fn slash_undo_submits_checkpoint_restore() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.dispatch_command(SlashCommand::Undo);
+
+    assert_matches!(op_rx.try_recv(), Ok(Op::UndoLastTurn));
+}
+
+#[tokio::test]
+async fn slash_pin_submits_pin_or_list_request() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.dispatch_command_with_args(
+        SlashCommand::Pin,
+        "  the prod DB is read-only ".to_string(),
+        Vec::new(),
+    );
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::PinContext { text }) if text == "the prod DB is read-only"
+    );
+
+    chat.dispatch_command_with_args(SlashCommand::Pin, "--item msg_abc".to_string(), Vec::new());
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::PinThreadItem { item_id }) if item_id == "msg_abc"
+    );
+
+    chat.dispatch_command(SlashCommand::Pin);
+    assert_matches!(op_rx.try_recv(), Ok(Op::ListPinnedContext));
+
+    chat.dispatch_command_with_args(SlashCommand::Unpin, "pin-1".to_string(), Vec::new());
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::UnpinContext { pin_id }) if pin_id == "pin-1"
+    );
+}
+
+#[tokio::test]
+async fn slash_export_parses_format_and_path() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
+
+    chat.dispatch_command(SlashCommand::Export);
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::ExportThread {
+            format: ThreadExportFormat::Markdown,
+            path: None,
+        })
+    );
+
+    chat.dispatch_command_with_args(
+        SlashCommand::Export,
+        "html notes/session report.html".to_string(),
+        Vec::new(),
+    );
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::ExportThread { format: ThreadExportFormat::Html, path: Some(path) })
+            if path == PathBuf::from("notes/session report.html")
+    );
+
+    chat.dispatch_command_with_args(SlashCommand::Export, "out.json".to_string(), Vec::new());
+    assert_matches!(
+        op_rx.try_recv(),
+        Ok(Op::ExportThread { format: ThreadExportFormat::Json, path: Some(path) })
+            if path == PathBuf::from("out.json")
+    );
+}
+
 #[tokio::test]
 async fn slash_clear_requests_ui_clear_when_idle() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(/*model_override*/ None).await;
@@ -3974,6 +3974,7 @@ async fn user_prompt_submit_app_server_hook_notifications_render_snapshot() {
                 completed_at: None,
                 duration_ms: None,
                 entries: Vec::new(),
+                agent_thread_id: None,
             },
         }),
         /*replay_kind*/ None,
@@ -4006,6 +4007,7 @@ async fn user_prompt_submit_app_server_hook_notifications_render_snapshot() {
                         text: "prompt blocked".to_string(),
                     },
                 ],
+                agent_thread_id: None,
             },
         }),
         /*replay_kind*/ None,
@@ -4629,6 +4631,7 @@ fn hook_run_summary(
         completed_at: (status != codex_app_server_protocol::HookRunStatus::Running).then_some(2),
         duration_ms: (status != codex_app_server_protocol::HookRunStatus::Running).then_some(1),
         entries,
+        agent_thread_id: None,
     }
 }
 
@@ -24,15 +24,19 @@ use tokio_stream::StreamExt;
 pub(crate) enum ExternalAgentConfigMigrationSource {
     Cla,
     Cur,
+    Gem,
+    Opc,
 }
 
 impl ExternalAgentConfigMigrationSource {
-    pub(crate) const ALL: [Self; 2] = [Self::Cla, Self::Cur];
+    pub(crate) const ALL: [Self; 4] = [Self::Cla, Self::Cur, Self::Gem, Self::Opc];
 
     pub(crate) fn migration_source(self) -> &'static str {
         match self {
             Self::Cla => "claude-code",
             Self::Cur => "cursor",
+            Self::Gem => "gemini-cli",
+            Self::Opc => "opencode",
         }
     }
 
@@ -40,6 +44,8 @@ impl ExternalAgentConfigMigrationSource {
         match self {
             Self::Cla => "Claude Code",
             Self::Cur => "Cursor",
+            Self::Gem => "Gemini CLI",
+            Self::Opc => "opencode",
         }
     }
 }
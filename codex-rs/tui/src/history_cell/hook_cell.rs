@@ -1080,6 +1080,7 @@ mod tests {
             completed_at: None,
             duration_ms: None,
             entries: Vec::new(),
+            agent_thread_id: None,
         }
     }
 }
@@ -69,7 +69,9 @@ use cwd_prompt::CwdPromptAction;
 pub use session_archive_commands::DeleteConfirmation;
 pub use session_archive_commands::SessionArchiveAction;
 pub use session_archive_commands::SessionArchiveCommandOptions;
+pub use session_archive_commands::SessionExportOptions;
 pub use session_archive_commands::run_session_archive_command;
+pub use session_archive_commands::run_session_export_command;
 use std::fs::OpenOptions;
 use std::path::Path;
 use std::path::PathBuf;
@@ -1,4 +1,5 @@
-//! Shared implementation for `codex archive`, `codex delete`, and `codex unarchive`.
+//! Shared implementation for `codex archive`, `codex delete`, `codex unarchive`, and
+//! `codex export`.
 //!
 //! The CLI commands are thin app-server clients: resolve a user-provided UUID or exact session
 //! name, then call the corresponding app-server RPC.
@@ -17,6 +18,7 @@ use crate::legacy_core::config::resolve_bootstrap_auth_route_config;
 use crate::legacy_core::config::resolve_oss_provider;
 use crate::legacy_core::config::resolve_profile_v2_config_path;
 use codex_app_server_protocol::Thread as AppServerThread;
+use codex_app_server_protocol::ThreadExportFormat;
 use codex_app_server_protocol::ThreadListParams;
 use codex_app_server_protocol::ThreadSortKey;
 use codex_arg0::Arg0DispatchPaths;
@@ -55,6 +57,13 @@ pub struct SessionArchiveCommandOptions {
     pub explicit_remote_endpoint: Option<RemoteAppServerEndpoint>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SessionExportOptions {
+    pub format: ThreadExportFormat,
+    pub omit_tool_output: bool,
+    pub redact_secrets: bool,
+}
+
 fn success_message(
     action: SessionArchiveAction,
     session_id: ThreadId,
@@ -85,6 +94,38 @@ pub async fn run_session_archive_command(
     run_session_archive_action_with_app_server(&mut app_server, action, &target).await
 }
 
+/// Renders the transcript of a session resolved by UUID or exact name. Active sessions are
+/// searched before archived ones.
+pub async fn run_session_export_command(
+    target: String,
+    export: SessionExportOptions,
+    options: SessionArchiveCommandOptions,
+) -> Result<String> {
+    let mut app_server = start_app_server_for_archive_command(options).await?;
+    let session_id = match ThreadId::from_string(&target) {
+        Ok(session_id) => session_id,
+        Err(_) => {
+            resolve_session_name(
+                &mut app_server,
+                &target,
+                "active or archived",
+                &[false, true],
+            )
+            .await?
+            .session_id
+        }
+    };
+    let response = app_server
+        .thread_export(
+            session_id,
+            export.format,
+            export.omit_tool_output,
+            export.redact_secrets,
+        )
+        .await?;
+    Ok(response.content)
+}
+
 async fn run_session_archive_action_with_app_server(
     app_server: &mut AppServerSession,
     action: SessionArchiveAction,
@@ -149,6 +190,15 @@ async fn resolve_session_target(
         SessionArchiveAction::Delete(_) => ("active or archived", &[false, true]),
         SessionArchiveAction::Unarchive => ("archived", &[true]),
     };
+    resolve_session_name(app_server, target, search_scope, archived_values).await
+}
+
+async fn resolve_session_name(
+    app_server: &mut AppServerSession,
+    target: &str,
+    search_scope: &str,
+    archived_values: &[bool],
+) -> Result<ResolvedSessionTarget> {
     for &archived in archived_values {
         if let Some(thread) = lookup_session_by_exact_name(app_server, target, archived).await? {
             return session_target_from_app_server_thread(thread);
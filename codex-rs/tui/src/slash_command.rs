@@ -38,14 +38,18 @@ pub enum SlashCommand {
     App,
     Init,
     Compact,
+    Pin,
+    Unpin,
     Plan,
     Goal,
     Agent,
     Side,
     Btw,
     Copy,
+    Export,
     Raw,
     Diff,
+    Undo,
     Mention,
     Status,
     Usage,
@@ -87,6 +91,10 @@ impl SlashCommand {
             SlashCommand::Init => "create an AGENTS.md file with instructions for Codex",
             SlashCommand::Compact => "summarize conversation to prevent hitting the context limit",
             SlashCommand::Review => "review my current changes and find issues",
+            SlashCommand::Pin => {
+                "pin text or --item <id> so it survives compaction (no args: list pins)"
+            }
+            SlashCommand::Unpin => "remove a pinned item by id",
             SlashCommand::Rename => "rename the current thread",
             SlashCommand::Resume => "resume a saved chat",
             SlashCommand::Archive => "archive this session and exit",
@@ -96,8 +104,10 @@ impl SlashCommand {
             SlashCommand::App => "continue this session in the Desktop app",
             SlashCommand::Quit | SlashCommand::Exit => "exit Codex",
             SlashCommand::Copy => "copy last response as markdown",
+            SlashCommand::Export => "export this session as Markdown, HTML, or JSON",
             SlashCommand::Raw => "toggle raw scrollback mode for copy-friendly terminal selection",
             SlashCommand::Diff => "show git diff (including untracked files)",
+            SlashCommand::Undo => "restore files changed by the last turn",
             SlashCommand::Mention => "mention a file",
             SlashCommand::Skills => "use skills to improve how Codex performs specific tasks",
             SlashCommand::Import => "import setup, this project, and recent chats from Claude Code",
@@ -155,6 +165,9 @@ impl SlashCommand {
             self,
             SlashCommand::Review
                 | SlashCommand::Rename
+                | SlashCommand::Pin
+                | SlashCommand::Unpin
+                | SlashCommand::Export
                 | SlashCommand::Plan
                 | SlashCommand::Goal
                 | SlashCommand::Ide
@@ -201,6 +214,7 @@ impl SlashCommand {
             | SlashCommand::Memories
             | SlashCommand::Import
             | SlashCommand::Review
+            | SlashCommand::Undo
             | SlashCommand::Plan
             | SlashCommand::Clear
             | SlashCommand::Logout
@@ -214,6 +228,9 @@ impl SlashCommand {
             | SlashCommand::Copy
             | SlashCommand::Raw
             | SlashCommand::Rename
+            | SlashCommand::Pin
+            | SlashCommand::Unpin
+            | SlashCommand::Export
             | SlashCommand::Mention
             | SlashCommand::Skills
             | SlashCommand::Hooks
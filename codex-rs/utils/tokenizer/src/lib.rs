@@ -0,0 +1,70 @@
+//! Offline BPE token counting for context-window accounting.
+//!
+//! Vocabularies are embedded at build time, so counting never touches the
+//! network. Each encoding is loaded once per process on first use.
+
+use codex_protocol::openai_models::TokenizerEncoding;
+use tiktoken_rs::CoreBPE;
+
+/// Counts tokens for one [`TokenizerEncoding`].
+///
+/// Cheap to copy; all copies share the process-wide vocabulary.
+#[derive(Clone, Copy)]
+pub struct Tokenizer {
+    encoding: TokenizerEncoding,
+    bpe: &'static CoreBPE,
+}
+
+impl Tokenizer {
+    pub fn new(encoding: TokenizerEncoding) -> Self {
+        let bpe = match encoding {
+            TokenizerEncoding::O200kBase => tiktoken_rs::o200k_base_singleton(),
+            TokenizerEncoding::Cl100kBase => tiktoken_rs::cl100k_base_singleton(),
+        };
+        Self { encoding, bpe }
+    }
+
+    pub fn encoding(&self) -> TokenizerEncoding {
+        self.encoding
+    }
+
+    /// Number of tokens in `text`. Special-token markers such as
+    /// `<|endoftext|>` are counted as ordinary text.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        if text.is_empty() {
+            return 0;
+        }
+        self.bpe.encode_ordinary(text).len()
+    }
+}
+
+impl std::fmt::Debug for Tokenizer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Tokenizer")
+            .field("encoding", &self.encoding)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for Tokenizer {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoding == other.encoding
+    }
+}
+
+impl Eq for Tokenizer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn counts_tokens_for_each_encoding() {
+        for encoding in [TokenizerEncoding::O200kBase, TokenizerEncoding::Cl100kBase] {
+            let tokenizer = Tokenizer::new(encoding);
+            assert_eq!(tokenizer.count_tokens(""), 0);
+            assert_eq!(tokenizer.count_tokens("hello world"), 2);
+        }
+    }
+}